@@ -0,0 +1,208 @@
+//! Throughput of a contributor taking a round from its Start to aggregation.
+//!
+//! Each iteration runs a fresh aggregating [Contributor] over a Start followed by a
+//! valid share from every other contributor, until its receiver closes. Setup and
+//! share signing are not timed, so the numbers cover decoding, share verification,
+//! bookkeeping and aggregation.
+//!
+//! Regression guard: share verification is a pairing check per share and dominates,
+//! so the time per round should grow linearly with the quorum, in the ballpark of
+//! 1-2ms per share on a recent x86 core (~15ms at 10, ~75ms at 50, ~300ms at 200).
+//! A result several times above that, or growing faster than linearly, is a
+//! regression worth investigating.
+
+use alloy_primitives::keccak256;
+use anyhow::Result;
+use ark_bn254::Fr;
+use bn254::{Bn254, G1PublicKey, PrivateKey, PublicKey};
+use bytes::Bytes;
+use commonware_avs_node::contributor::events::ThresholdCounter;
+use commonware_avs_node::contributor::{AggregationInput, Contribute, OutboundRouter};
+use commonware_avs_node::digest::encode_message;
+use commonware_avs_node::handlers::Contributor;
+use commonware_avs_node::validation::{PayloadValidator, ValidatorFactory};
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::wire::{self, aggregation::Payload};
+use commonware_codec::ReadExt;
+use commonware_cryptography::Signer;
+use commonware_p2p::{Receiver, Recipients, Sender};
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Contributors in each benchmarked quorum
+const QUORUM_SIZES: [usize; 3] = [10, 50, 200];
+
+const ROUND: u64 = 1;
+
+fn signer(seed: u64) -> Bn254 {
+    Bn254::new(PrivateKey::from(Fr::from(seed))).expect("valid test key")
+}
+
+fn aggregation(round: u64, payload: Option<Payload>) -> wire::Aggregation<CounterTaskData> {
+    wire::Aggregation {
+        round,
+        metadata: Default::default(),
+        payload,
+    }
+}
+
+/// Payload hash of a round: the hash of its Start without the payload
+fn digest(round: u64) -> [u8; 32] {
+    keccak256(encode_message(&aggregation(round, None))).0
+}
+
+/// Validator accepting every Start, independent of chain state
+#[derive(Clone)]
+struct BenchValidator;
+
+impl PayloadValidator for BenchValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move {
+            let start: wire::Aggregation<CounterTaskData> =
+                wire::Aggregation::read(&mut std::io::Cursor::new(message))
+                    .map_err(|err| anyhow::anyhow!("undecodable message: {err}"))?;
+            Ok(digest(start.round))
+        })
+    }
+}
+
+impl ValidatorFactory for BenchValidator {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        let validator: Arc<dyn PayloadValidator> = Arc::new(self.clone());
+        Box::pin(async move { Ok(validator) })
+    }
+}
+
+#[derive(Debug)]
+struct Closed;
+
+impl fmt::Display for Closed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no frames left")
+    }
+}
+
+impl std::error::Error for Closed {}
+
+/// Receiver handing out a fixed list of frames, then closing
+#[derive(Debug)]
+struct Frames(std::vec::IntoIter<(PublicKey, Bytes)>);
+
+impl Receiver for Frames {
+    type Error = Closed;
+    type PublicKey = PublicKey;
+
+    async fn recv(&mut self) -> Result<(Self::PublicKey, Bytes), Self::Error> {
+        self.0.next().ok_or(Closed)
+    }
+}
+
+/// Sender dropping everything
+#[derive(Clone, Debug)]
+struct Discard;
+
+impl Sender for Discard {
+    type Error = Closed;
+    type PublicKey = PublicKey;
+
+    async fn send(
+        &mut self,
+        _recipients: Recipients<Self::PublicKey>,
+        _message: Bytes,
+        _priority: bool,
+    ) -> Result<Vec<Self::PublicKey>, Self::Error> {
+        Ok(Vec::new())
+    }
+}
+
+/// Quorum of `size` contributors, the first aggregating, with the frames it receives
+struct Quorum {
+    orchestrator: PublicKey,
+    aggregator: Bn254,
+    contributors: Vec<PublicKey>,
+    frames: Vec<(PublicKey, Bytes)>,
+}
+
+impl Quorum {
+    fn new(size: usize) -> Self {
+        let orchestrator = signer(1000).public_key();
+        let signers: Vec<Bn254> = (0..size as u64).map(|i| signer(2000 + i)).collect();
+        let payload = digest(ROUND);
+
+        // The Start, then a share from every contributor but the aggregator
+        let start = aggregation(ROUND, Some(Payload::Start));
+        let mut frames = vec![(orchestrator.clone(), Bytes::from(encode_message(&start)))];
+        for peer in &signers[1..] {
+            let signature = peer.sign(None, &payload);
+            let share = aggregation(ROUND, Some(Payload::Signature(signature.to_vec())));
+            frames.push((peer.public_key(), Bytes::from(encode_message(&share))));
+        }
+        Self {
+            orchestrator,
+            aggregator: signers[0].clone(),
+            contributors: signers.iter().map(|signer| signer.public_key()).collect(),
+            frames,
+        }
+    }
+
+    /// Fresh aggregator waiting for a share from every peer
+    fn contributor(&self, aggregated: &ThresholdCounter) -> Contributor {
+        // G1 keys only feed the APK, placeholders keep setup cheap
+        let g1 = G1PublicKey::create_from_g1_coordinates("0", "0").expect("placeholder g1 key");
+        let g1_map: HashMap<PublicKey, G1PublicKey> = self
+            .contributors
+            .iter()
+            .map(|key| (key.clone(), g1.clone()))
+            .collect();
+        let threshold = self.contributors.len() - 1;
+        Contributor::new(
+            self.orchestrator.clone(),
+            self.aggregator.clone(),
+            self.contributors.clone(),
+            Some(AggregationInput::new(threshold, g1_map)),
+        )
+        .with_validator_factory(Arc::new(BenchValidator))
+        .with_event_sink(Arc::new(aggregated.clone()))
+    }
+}
+
+fn receive_path(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("tokio runtime");
+    let mut group = c.benchmark_group("receive_to_aggregate");
+    group.sample_size(10);
+    for size in QUORUM_SIZES {
+        let quorum = Quorum::new(size);
+        group.throughput(Throughput::Elements(quorum.frames.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &quorum, |b, quorum| {
+            b.to_async(&runtime).iter_batched(
+                || {
+                    let aggregated = ThresholdCounter::new();
+                    let contributor = quorum.contributor(&aggregated);
+                    (
+                        aggregated,
+                        contributor,
+                        Frames(quorum.frames.clone().into_iter()),
+                    )
+                },
+                |(aggregated, contributor, frames)| async move {
+                    contributor
+                        .run(OutboundRouter::single(Discard), frames)
+                        .await
+                        .expect("contributor run");
+                    assert_eq!(aggregated.get(), 1, "round not aggregated");
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, receive_path);
+criterion_main!(benches);
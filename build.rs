@@ -0,0 +1,90 @@
+//! Generates the contract bindings in `src/bindings` from the ABIs in `contracts/abi`.
+//!
+//! Each bound contract becomes a module invoking `alloy::sol!` on its ABI, written to
+//! `OUT_DIR/bindings.rs`, so the bindings always match the checked-in ABIs. With
+//! `REGENERATE_BINDINGS=1` the ABIs are first downloaded again from
+//! `BINDINGS_ABI_URL/<Contract>.abi`; offline builds never touch the network.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Bound contracts, as `(module, contract)`, read from `contracts/abi/<contract>.abi`
+const CONTRACTS: &[(&str, &str)] = &[
+    ("avsdirectory", "AVSDirectory"),
+    ("blsapkregistry", "BLSApkRegistry"),
+    (
+        "blssigcheckoperatorstateretriever",
+        "BLSSigCheckOperatorStateRetriever",
+    ),
+    ("blssignaturechecker", "BLSSignatureChecker"),
+    ("counter", "Counter"),
+    ("delegationmanager", "DelegationManager"),
+    ("servicemanager", "ServiceManager"),
+    ("votingcontract", "VotingContract"),
+];
+
+const LINTS: &str = "#[allow(
+    non_camel_case_types,
+    non_snake_case,
+    clippy::pub_underscore_fields,
+    clippy::style,
+    clippy::empty_structs_with_brackets,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    missing_docs,
+    dead_code
+)]";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=REGENERATE_BINDINGS");
+    println!("cargo:rerun-if-env-changed=BINDINGS_ABI_URL");
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let abi_dir = manifest_dir.join("contracts").join("abi");
+    if env::var("REGENERATE_BINDINGS").is_ok_and(|value| value == "1") {
+        let base = env::var("BINDINGS_ABI_URL")
+            .expect("BINDINGS_ABI_URL must be set to regenerate bindings");
+        for (_, contract) in CONTRACTS {
+            download(&base, contract, &abi_dir);
+        }
+    }
+
+    let mut bindings = String::new();
+    for (module, contract) in CONTRACTS {
+        let abi = abi_dir.join(format!("{contract}.abi"));
+        assert!(abi.exists(), "missing abi: {}", abi.display());
+        println!("cargo:rerun-if-changed={}", abi.display());
+        writeln!(
+            bindings,
+            "{LINTS}\npub mod {module} {{\n    alloy::sol!(\n        #[sol(rpc)]\n        {contract},\n        {:?}\n    );\n}}\n",
+            abi.display().to_string()
+        )
+        .unwrap();
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings.rs");
+    fs::write(out, bindings).unwrap();
+}
+
+/// Replace the ABI of `contract` with the one published at `base`
+fn download(base: &str, contract: &str, abi_dir: &Path) {
+    let url = format!("{}/{contract}.abi", base.trim_end_matches('/'));
+    let path = abi_dir.join(format!("{contract}.abi"));
+    let status = Command::new("curl")
+        .args([
+            "--fail",
+            "--silent",
+            "--show-error",
+            "--location",
+            "--output",
+        ])
+        .arg(&path)
+        .arg(&url)
+        .status()
+        .expect("failed to run curl");
+    assert!(status.success(), "failed to download {url}");
+}
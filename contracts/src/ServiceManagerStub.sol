@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.12;
+
+/// @notice ServiceManager returning fixed values, deployed on Anvil by the integration tests
+contract ServiceManagerStub {
+    address public taskManager;
+    string public avsName;
+    string public avsVersion;
+    mapping(address => bool) public isOperatorRegistered;
+
+    constructor(address _taskManager, string memory _avsName, string memory _avsVersion, address _operator) {
+        taskManager = _taskManager;
+        avsName = _avsName;
+        avsVersion = _avsVersion;
+        isOperatorRegistered[_operator] = true;
+    }
+}
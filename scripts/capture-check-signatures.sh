@@ -0,0 +1,22 @@
+#!/usr/bin/env sh
+# Capture tests/fixtures/check_signatures_vector.hex from a chain.
+#
+# Needs `cast` and a node at HTTP_RPC, `http://localhost:8545` by default, on which
+# the orchestrator submitted a round. Takes the hash of its `increment` transaction to
+# the Counter, which passes its arguments to `checkSignatures` unchanged, and writes
+# them as `checkSignatures` calldata.
+set -eu
+
+cd "$(dirname "$0")/.."
+HTTP_RPC="${HTTP_RPC:-http://localhost:8545}"
+TX="${1:?usage: $0 <increment transaction hash>}"
+PARAMS='(bytes32,bytes,uint32,(uint32[],(uint256,uint256)[],(uint256,uint256)[],(uint256[2],uint256[2]),(uint256,uint256),uint32[],uint32[],uint32[][]))'
+
+input=$(cast tx "$TX" input --rpc-url "$HTTP_RPC" | sed 's/^0x//')
+increment=$(cast sig "increment$PARAMS" | sed 's/^0x//')
+if [ "$(printf '%.8s' "$input")" != "$increment" ]; then
+    echo "$TX is not a call to increment" >&2
+    exit 1
+fi
+check_signatures=$(cast sig "checkSignatures$PARAMS" | sed 's/^0x//')
+printf '%s%s\n' "$check_signatures" "${input#????????}" >tests/fixtures/check_signatures_vector.hex
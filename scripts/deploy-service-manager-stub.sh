@@ -0,0 +1,23 @@
+#!/usr/bin/env sh
+# Deploy the ServiceManager stub the integration tests read from.
+#
+# Needs `forge` and a node at HTTP_RPC, `http://localhost:8545` by default, with the
+# default Anvil accounts. Prints the stub address; export it as
+# SERVICE_MANAGER_ADDRESS before running `cargo test --features integration-tests`.
+set -eu
+
+cd "$(dirname "$0")/.."
+HTTP_RPC="${HTTP_RPC:-http://localhost:8545}"
+# First default Anvil account
+DEPLOYER_KEY=0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80
+
+forge create contracts/src/ServiceManagerStub.sol:ServiceManagerStub \
+    --rpc-url "$HTTP_RPC" \
+    --private-key "$DEPLOYER_KEY" \
+    --broadcast \
+    --constructor-args \
+    0x70997970C51812dc3A010C7d01b50e0d17dc79C8 \
+    commonware-avs \
+    0.1.0 \
+    0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC |
+    sed -n 's/^Deployed to: //p'
@@ -0,0 +1,12 @@
+#!/usr/bin/env sh
+# Record the interoperability transcript of the checked-out release.
+#
+# Run on every release tag and commit the resulting
+# tests/fixtures/transcripts/<version>.json on main. The test suite replays each
+# transcript of the current protocol version against the current contributor.
+# Set TRANSCRIPT_PATH to write the transcript elsewhere.
+set -eu
+
+cd "$(dirname "$0")/.."
+cargo test --lib -- --ignored --exact \
+    contributor::tests::upgrade::upgrade_tests::record_transcript
@@ -0,0 +1,209 @@
+//! JSON documents the node exposes to other tools, with a frozen encoding.
+//!
+//! Every document carries the [SCHEMA_VERSION] it was written with. Field names, enum
+//! tags and encodings are pinned by the serde attributes here and checked against the
+//! snapshots in `tests/fixtures/api`, so a change to any of them fails the tests.
+//! Bytes, hashes and signatures are lowercase hex without a `0x` prefix.
+//!
+//! # Bumping the schema version
+//!
+//! [SCHEMA_VERSION] is bumped, and the snapshots regenerated, when a released document
+//! changes in a way its readers notice:
+//!
+//! - a field is removed or renamed, or its type or encoding changes;
+//! - an enum tag or variant name changes;
+//! - a field is added to a document the node reads back, since readers of the previous
+//!   version reject unknown fields.
+//!
+//! Adding a field to a document the node only writes does not bump the version, the
+//! snapshot is updated alongside.
+
+use crate::chain::{ClockSkew, RoundCompletedOnChain};
+use crate::contributor::liveness::OrchestratorStale;
+use crate::handlers::RoundStateSummary;
+use alloy_primitives::{Address, hex};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Version of the documents written by this release
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A document was written with a schema version this release does not read
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnsupportedSchemaVersion(pub u32);
+
+impl fmt::Display for UnsupportedSchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unsupported schema version {}, expected {SCHEMA_VERSION}",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedSchemaVersion {}
+
+/// Check a document read back was written with [SCHEMA_VERSION]
+pub fn check_schema_version(version: u32) -> Result<(), UnsupportedSchemaVersion> {
+    if version != SCHEMA_VERSION {
+        return Err(UnsupportedSchemaVersion(version));
+    }
+    Ok(())
+}
+
+/// Aggregate of a round as kept by a results store, such as the file sink
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AggregateRecord {
+    /// Version of the schema the record was written with
+    pub schema_version: u32,
+    /// Round the aggregate is for
+    pub round: u64,
+    /// Payload hash the contributors signed
+    pub payload: String,
+    /// Aggregate signature as encoded on the wire
+    pub signature: String,
+    /// Indices of the signers among the ordered contributors, ascending
+    pub signers: Vec<usize>,
+}
+
+/// Event of a running contributor, as delivered to webhooks
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// Version of the schema the record was written with
+    pub schema_version: u32,
+    /// What happened
+    pub event: Event,
+}
+
+/// Kind and content of an [EventRecord], tagged by `type`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    /// The response to a round was submitted on-chain
+    RoundCompletedOnChain {
+        /// Round the response is for
+        round: u64,
+        /// Hex hash of the transaction carrying the response
+        transaction_hash: String,
+        /// Signatures collected for the round that were dropped
+        dropped_signatures: usize,
+    },
+    /// No Start was received for longer than the stale threshold
+    OrchestratorStale {
+        /// Time since the last Start, in milliseconds
+        silent_for_ms: u64,
+        /// Round of the last Start, if any was received
+        last_round: Option<u64>,
+    },
+}
+
+impl From<Event> for EventRecord {
+    fn from(event: Event) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            event,
+        }
+    }
+}
+
+impl From<&RoundCompletedOnChain> for EventRecord {
+    fn from(completed: &RoundCompletedOnChain) -> Self {
+        Event::RoundCompletedOnChain {
+            round: completed.round,
+            transaction_hash: hex::encode(completed.transaction_hash),
+            dropped_signatures: completed.dropped_signatures,
+        }
+        .into()
+    }
+}
+
+impl From<&OrchestratorStale> for EventRecord {
+    fn from(stale: &OrchestratorStale) -> Self {
+        Event::OrchestratorStale {
+            silent_for_ms: u64::try_from(stale.silent_for.as_millis()).unwrap_or(u64::MAX),
+            last_round: stale.last_round,
+        }
+        .into()
+    }
+}
+
+/// Round state of a running contributor, as served by a status endpoint
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusRecord {
+    /// Version of the schema the record was written with
+    pub schema_version: u32,
+    /// Rounds signed or being signed
+    pub signed_rounds: usize,
+    /// Rounds collecting signatures
+    pub open_rounds: usize,
+    /// Rounds started and awaiting aggregation
+    pub started_rounds: usize,
+    /// Rounds retired after completing on-chain
+    pub retired_rounds: usize,
+    /// Rounds started and not yet aggregated, expired or retired
+    pub active_rounds: usize,
+    /// Active rounds beyond which Starts are rejected
+    pub max_active_rounds: usize,
+    /// Shares held until their sender joins the contributors
+    pub held_shares: usize,
+    /// Signatures of our own being produced
+    pub pending_signatures: usize,
+    /// Rounds kept to answer sync requests
+    pub sync_rounds: usize,
+    /// Last measured skew of the local clock in milliseconds, positive when ahead, null
+    /// until measured
+    pub clock_skew_ms: Option<i64>,
+}
+
+impl StatusRecord {
+    /// Record with the last measured `skew` of the local clock
+    pub fn with_clock_skew(mut self, skew: Option<ClockSkew>) -> Self {
+        self.clock_skew_ms = skew.map(|skew| skew.offset_ms);
+        self
+    }
+}
+
+impl From<&RoundStateSummary> for StatusRecord {
+    fn from(summary: &RoundStateSummary) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            signed_rounds: summary.signed,
+            open_rounds: summary.signatures,
+            started_rounds: summary.started,
+            retired_rounds: summary.retired,
+            active_rounds: summary.active_rounds,
+            max_active_rounds: summary.max_active_rounds,
+            held_shares: summary.held_shares,
+            pending_signatures: summary.pending_signatures,
+            sync_rounds: summary.sync_rounds,
+            clock_skew_ms: None,
+        }
+    }
+}
+
+/// Transaction of a round exported for another machine to sign and submit
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalldataRecord {
+    /// Version of the schema the record was written with
+    pub schema_version: u32,
+    /// Round the transaction responds to
+    pub round: u64,
+    /// Contract called
+    pub to: String,
+    /// Hex calldata of the call
+    pub calldata: String,
+}
+
+impl CalldataRecord {
+    /// Record of the current schema calling `to` with `calldata`
+    pub fn new(round: u64, to: Address, calldata: &[u8]) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            round,
+            to: hex::encode(to),
+            calldata: hex::encode(calldata),
+        }
+    }
+}
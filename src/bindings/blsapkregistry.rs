@@ -1,7756 +0,0 @@
-///Module containing a contract's types and functions.
-/**
-
-```solidity
-library BN254 {
-    struct G1Point { uint256 X; uint256 Y; }
-    struct G2Point { uint256[2] X; uint256[2] Y; }
-}
-```*/
-#[allow(
-    non_camel_case_types,
-    non_snake_case,
-    clippy::pub_underscore_fields,
-    clippy::style,
-    clippy::empty_structs_with_brackets
-)]
-pub mod BN254 {
-
-    use alloy::sol_types as alloy_sol_types;
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**```solidity
-    struct G1Point { uint256 X; uint256 Y; }
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct G1Point {
-        #[allow(missing_docs)]
-        pub X: alloy::sol_types::private::primitives::aliases::U256,
-        #[allow(missing_docs)]
-        pub Y: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = (
-            alloy::sol_types::sol_data::Uint<256>,
-            alloy::sol_types::sol_data::Uint<256>,
-        );
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = (
-            alloy::sol_types::private::primitives::aliases::U256,
-            alloy::sol_types::private::primitives::aliases::U256,
-        );
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<G1Point> for UnderlyingRustTuple<'_> {
-            fn from(value: G1Point) -> Self {
-                (value.X, value.Y)
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for G1Point {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {
-                    X: tuple.0,
-                    Y: tuple.1,
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolValue for G1Point {
-            type SolType = Self;
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::private::SolTypeValue<Self> for G1Point {
-            #[inline]
-            fn stv_to_tokens(&self) -> <Self as alloy_sol_types::SolType>::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Uint<256> as alloy_sol_types::SolType>::tokenize(
-                        &self.X,
-                    ),
-                    <alloy::sol_types::sol_data::Uint<256> as alloy_sol_types::SolType>::tokenize(
-                        &self.Y,
-                    ),
-                )
-            }
-            #[inline]
-            fn stv_abi_encoded_size(&self) -> usize {
-                if let Some(size) = <Self as alloy_sol_types::SolType>::ENCODED_SIZE {
-                    return size;
-                }
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encoded_size(&tuple)
-            }
-            #[inline]
-            fn stv_eip712_data_word(&self) -> alloy_sol_types::Word {
-                <Self as alloy_sol_types::SolStruct>::eip712_hash_struct(self)
-            }
-            #[inline]
-            fn stv_abi_encode_packed_to(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encode_packed_to(
-                    &tuple, out,
-                )
-            }
-            #[inline]
-            fn stv_abi_packed_encoded_size(&self) -> usize {
-                if let Some(size) = <Self as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE {
-                    return size;
-                }
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_packed_encoded_size(
-                    &tuple,
-                )
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolType for G1Point {
-            type RustType = Self;
-            type Token<'a> = <UnderlyingSolTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SOL_NAME: &'static str = <Self as alloy_sol_types::SolStruct>::NAME;
-            const ENCODED_SIZE: Option<usize> =
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::ENCODED_SIZE;
-            const PACKED_ENCODED_SIZE: Option<usize> =
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE;
-            #[inline]
-            fn valid_token(token: &Self::Token<'_>) -> bool {
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::valid_token(token)
-            }
-            #[inline]
-            fn detokenize(token: Self::Token<'_>) -> Self::RustType {
-                let tuple = <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::detokenize(token);
-                <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolStruct for G1Point {
-            const NAME: &'static str = "G1Point";
-            #[inline]
-            fn eip712_root_type() -> alloy_sol_types::private::Cow<'static, str> {
-                alloy_sol_types::private::Cow::Borrowed("G1Point(uint256 X,uint256 Y)")
-            }
-            #[inline]
-            fn eip712_components()
-            -> alloy_sol_types::private::Vec<alloy_sol_types::private::Cow<'static, str>>
-            {
-                alloy_sol_types::private::Vec::new()
-            }
-            #[inline]
-            fn eip712_encode_type() -> alloy_sol_types::private::Cow<'static, str> {
-                <Self as alloy_sol_types::SolStruct>::eip712_root_type()
-            }
-            #[inline]
-            fn eip712_encode_data(&self) -> alloy_sol_types::private::Vec<u8> {
-                [
-                    <alloy::sol_types::sol_data::Uint<
-                        256,
-                    > as alloy_sol_types::SolType>::eip712_data_word(&self.X)
-                        .0,
-                    <alloy::sol_types::sol_data::Uint<
-                        256,
-                    > as alloy_sol_types::SolType>::eip712_data_word(&self.Y)
-                        .0,
-                ]
-                    .concat()
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::EventTopic for G1Point {
-            #[inline]
-            fn topic_preimage_length(rust: &Self::RustType) -> usize {
-                <alloy::sol_types::sol_data::Uint<
-                        256,
-                    > as alloy_sol_types::EventTopic>::topic_preimage_length(&rust.X)
-                    + <alloy::sol_types::sol_data::Uint<
-                        256,
-                    > as alloy_sol_types::EventTopic>::topic_preimage_length(&rust.Y)
-            }
-            #[inline]
-            fn encode_topic_preimage(
-                rust: &Self::RustType,
-                out: &mut alloy_sol_types::private::Vec<u8>,
-            ) {
-                out.reserve(<Self as alloy_sol_types::EventTopic>::topic_preimage_length(rust));
-                <alloy::sol_types::sol_data::Uint<
-                    256,
-                > as alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.X, out);
-                <alloy::sol_types::sol_data::Uint<
-                    256,
-                > as alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.Y, out);
-            }
-            #[inline]
-            fn encode_topic(rust: &Self::RustType) -> alloy_sol_types::abi::token::WordToken {
-                let mut out = alloy_sol_types::private::Vec::new();
-                <Self as alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
-                alloy_sol_types::abi::token::WordToken(alloy_sol_types::private::keccak256(out))
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**```solidity
-    struct G2Point { uint256[2] X; uint256[2] Y; }
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct G2Point {
-        #[allow(missing_docs)]
-        pub X: [alloy::sol_types::private::primitives::aliases::U256; 2usize],
-        #[allow(missing_docs)]
-        pub Y: [alloy::sol_types::private::primitives::aliases::U256; 2usize],
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = (
-            alloy::sol_types::sol_data::FixedArray<alloy::sol_types::sol_data::Uint<256>, 2usize>,
-            alloy::sol_types::sol_data::FixedArray<alloy::sol_types::sol_data::Uint<256>, 2usize>,
-        );
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = (
-            [alloy::sol_types::private::primitives::aliases::U256; 2usize],
-            [alloy::sol_types::private::primitives::aliases::U256; 2usize],
-        );
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<G2Point> for UnderlyingRustTuple<'_> {
-            fn from(value: G2Point) -> Self {
-                (value.X, value.Y)
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for G2Point {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {
-                    X: tuple.0,
-                    Y: tuple.1,
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolValue for G2Point {
-            type SolType = Self;
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::private::SolTypeValue<Self> for G2Point {
-            #[inline]
-            fn stv_to_tokens(&self) -> <Self as alloy_sol_types::SolType>::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::FixedArray<
-                        alloy::sol_types::sol_data::Uint<256>,
-                        2usize,
-                    > as alloy_sol_types::SolType>::tokenize(&self.X),
-                    <alloy::sol_types::sol_data::FixedArray<
-                        alloy::sol_types::sol_data::Uint<256>,
-                        2usize,
-                    > as alloy_sol_types::SolType>::tokenize(&self.Y),
-                )
-            }
-            #[inline]
-            fn stv_abi_encoded_size(&self) -> usize {
-                if let Some(size) = <Self as alloy_sol_types::SolType>::ENCODED_SIZE {
-                    return size;
-                }
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encoded_size(&tuple)
-            }
-            #[inline]
-            fn stv_eip712_data_word(&self) -> alloy_sol_types::Word {
-                <Self as alloy_sol_types::SolStruct>::eip712_hash_struct(self)
-            }
-            #[inline]
-            fn stv_abi_encode_packed_to(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encode_packed_to(
-                    &tuple, out,
-                )
-            }
-            #[inline]
-            fn stv_abi_packed_encoded_size(&self) -> usize {
-                if let Some(size) = <Self as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE {
-                    return size;
-                }
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_packed_encoded_size(
-                    &tuple,
-                )
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolType for G2Point {
-            type RustType = Self;
-            type Token<'a> = <UnderlyingSolTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SOL_NAME: &'static str = <Self as alloy_sol_types::SolStruct>::NAME;
-            const ENCODED_SIZE: Option<usize> =
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::ENCODED_SIZE;
-            const PACKED_ENCODED_SIZE: Option<usize> =
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE;
-            #[inline]
-            fn valid_token(token: &Self::Token<'_>) -> bool {
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::valid_token(token)
-            }
-            #[inline]
-            fn detokenize(token: Self::Token<'_>) -> Self::RustType {
-                let tuple = <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::detokenize(token);
-                <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolStruct for G2Point {
-            const NAME: &'static str = "G2Point";
-            #[inline]
-            fn eip712_root_type() -> alloy_sol_types::private::Cow<'static, str> {
-                alloy_sol_types::private::Cow::Borrowed("G2Point(uint256[2] X,uint256[2] Y)")
-            }
-            #[inline]
-            fn eip712_components()
-            -> alloy_sol_types::private::Vec<alloy_sol_types::private::Cow<'static, str>>
-            {
-                alloy_sol_types::private::Vec::new()
-            }
-            #[inline]
-            fn eip712_encode_type() -> alloy_sol_types::private::Cow<'static, str> {
-                <Self as alloy_sol_types::SolStruct>::eip712_root_type()
-            }
-            #[inline]
-            fn eip712_encode_data(&self) -> alloy_sol_types::private::Vec<u8> {
-                [
-                    <alloy::sol_types::sol_data::FixedArray<
-                        alloy::sol_types::sol_data::Uint<256>,
-                        2usize,
-                    > as alloy_sol_types::SolType>::eip712_data_word(&self.X)
-                    .0,
-                    <alloy::sol_types::sol_data::FixedArray<
-                        alloy::sol_types::sol_data::Uint<256>,
-                        2usize,
-                    > as alloy_sol_types::SolType>::eip712_data_word(&self.Y)
-                    .0,
-                ]
-                .concat()
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::EventTopic for G2Point {
-            #[inline]
-            fn topic_preimage_length(rust: &Self::RustType) -> usize {
-                <alloy::sol_types::sol_data::FixedArray<
-                    alloy::sol_types::sol_data::Uint<256>,
-                    2usize,
-                > as alloy_sol_types::EventTopic>::topic_preimage_length(&rust.X)
-                    + <alloy::sol_types::sol_data::FixedArray<
-                        alloy::sol_types::sol_data::Uint<256>,
-                        2usize,
-                    > as alloy_sol_types::EventTopic>::topic_preimage_length(
-                        &rust.Y
-                    )
-            }
-            #[inline]
-            fn encode_topic_preimage(
-                rust: &Self::RustType,
-                out: &mut alloy_sol_types::private::Vec<u8>,
-            ) {
-                out.reserve(<Self as alloy_sol_types::EventTopic>::topic_preimage_length(rust));
-                <alloy::sol_types::sol_data::FixedArray<
-                    alloy::sol_types::sol_data::Uint<256>,
-                    2usize,
-                > as alloy_sol_types::EventTopic>::encode_topic_preimage(
-                    &rust.X, out
-                );
-                <alloy::sol_types::sol_data::FixedArray<
-                    alloy::sol_types::sol_data::Uint<256>,
-                    2usize,
-                > as alloy_sol_types::EventTopic>::encode_topic_preimage(
-                    &rust.Y, out
-                );
-            }
-            #[inline]
-            fn encode_topic(rust: &Self::RustType) -> alloy_sol_types::abi::token::WordToken {
-                let mut out = alloy_sol_types::private::Vec::new();
-                <Self as alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
-                alloy_sol_types::abi::token::WordToken(alloy_sol_types::private::keccak256(out))
-            }
-        }
-    };
-    use alloy::contract as alloy_contract;
-    /**Creates a new wrapper around an on-chain [`BN254`](self) contract instance.
-
-    See the [wrapper's documentation](`BN254Instance`) for more details.*/
-    #[inline]
-    pub const fn new<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    >(
-        address: alloy_sol_types::private::Address,
-        provider: P,
-    ) -> BN254Instance<T, P, N> {
-        BN254Instance::<T, P, N>::new(address, provider)
-    }
-    /**A [`BN254`](self) instance.
-
-    Contains type-safe methods for interacting with an on-chain instance of the
-    [`BN254`](self) contract located at a given `address`, using a given
-    provider `P`.
-
-    If the contract bytecode is available (see the [`sol!`](alloy_sol_types::sol!)
-    documentation on how to provide it), the `deploy` and `deploy_builder` methods can
-    be used to deploy a new instance of the contract.
-
-    See the [module-level documentation](self) for all the available methods.*/
-    #[derive(Clone)]
-    pub struct BN254Instance<T, P, N = alloy_contract::private::Ethereum> {
-        address: alloy_sol_types::private::Address,
-        provider: P,
-        _network_transport: ::core::marker::PhantomData<(N, T)>,
-    }
-    #[automatically_derived]
-    impl<T, P, N> ::core::fmt::Debug for BN254Instance<T, P, N> {
-        #[inline]
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_tuple("BN254Instance").field(&self.address).finish()
-        }
-    }
-    /// Instantiation and getters/setters.
-    #[automatically_derived]
-    impl<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    > BN254Instance<T, P, N>
-    {
-        /**Creates a new wrapper around an on-chain [`BN254`](self) contract instance.
-
-        See the [wrapper's documentation](`BN254Instance`) for more details.*/
-        #[inline]
-        pub const fn new(address: alloy_sol_types::private::Address, provider: P) -> Self {
-            Self {
-                address,
-                provider,
-                _network_transport: ::core::marker::PhantomData,
-            }
-        }
-        /// Returns a reference to the address.
-        #[inline]
-        pub const fn address(&self) -> &alloy_sol_types::private::Address {
-            &self.address
-        }
-        /// Sets the address.
-        #[inline]
-        pub fn set_address(&mut self, address: alloy_sol_types::private::Address) {
-            self.address = address;
-        }
-        /// Sets the address and returns `self`.
-        pub fn at(mut self, address: alloy_sol_types::private::Address) -> Self {
-            self.set_address(address);
-            self
-        }
-        /// Returns a reference to the provider.
-        #[inline]
-        pub const fn provider(&self) -> &P {
-            &self.provider
-        }
-    }
-    impl<T, P: ::core::clone::Clone, N> BN254Instance<T, &P, N> {
-        /// Clones the provider and returns a new instance with the cloned provider.
-        #[inline]
-        pub fn with_cloned_provider(self) -> BN254Instance<T, P, N> {
-            BN254Instance {
-                address: self.address,
-                provider: ::core::clone::Clone::clone(&self.provider),
-                _network_transport: ::core::marker::PhantomData,
-            }
-        }
-    }
-    /// Function calls.
-    #[automatically_derived]
-    impl<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    > BN254Instance<T, P, N>
-    {
-        /// Creates a new call builder using this contract instance's provider and address.
-        ///
-        /// Note that the call can be any function call, not just those defined in this
-        /// contract. Prefer using the other methods for building type-safe contract calls.
-        pub fn call_builder<C: alloy_sol_types::SolCall>(
-            &self,
-            call: &C,
-        ) -> alloy_contract::SolCallBuilder<T, &P, C, N> {
-            alloy_contract::SolCallBuilder::new_sol(&self.provider, &self.address, call)
-        }
-    }
-    /// Event filters.
-    #[automatically_derived]
-    impl<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    > BN254Instance<T, P, N>
-    {
-        /// Creates a new event filter using this contract instance's provider and address.
-        ///
-        /// Note that the type can be any event, not just those defined in this contract.
-        /// Prefer using the other methods for building type-safe event filters.
-        pub fn event_filter<E: alloy_sol_types::SolEvent>(
-            &self,
-        ) -> alloy_contract::Event<T, &P, E, N> {
-            alloy_contract::Event::new_sol(&self.provider, &self.address)
-        }
-    }
-}
-///Module containing a contract's types and functions.
-/**
-
-```solidity
-library IBLSApkRegistryTypes {
-    struct ApkUpdate { bytes24 apkHash; uint32 updateBlockNumber; uint32 nextUpdateBlockNumber; }
-    struct PubkeyRegistrationParams { BN254.G1Point pubkeyRegistrationSignature; BN254.G1Point pubkeyG1; BN254.G2Point pubkeyG2; }
-}
-```*/
-#[allow(
-    non_camel_case_types,
-    non_snake_case,
-    clippy::pub_underscore_fields,
-    clippy::style,
-    clippy::empty_structs_with_brackets
-)]
-pub mod IBLSApkRegistryTypes {
-    use super::*;
-    use alloy::sol_types as alloy_sol_types;
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**```solidity
-    struct ApkUpdate { bytes24 apkHash; uint32 updateBlockNumber; uint32 nextUpdateBlockNumber; }
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct ApkUpdate {
-        #[allow(missing_docs)]
-        pub apkHash: alloy::sol_types::private::FixedBytes<24>,
-        #[allow(missing_docs)]
-        pub updateBlockNumber: u32,
-        #[allow(missing_docs)]
-        pub nextUpdateBlockNumber: u32,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = (
-            alloy::sol_types::sol_data::FixedBytes<24>,
-            alloy::sol_types::sol_data::Uint<32>,
-            alloy::sol_types::sol_data::Uint<32>,
-        );
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = (alloy::sol_types::private::FixedBytes<24>, u32, u32);
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<ApkUpdate> for UnderlyingRustTuple<'_> {
-            fn from(value: ApkUpdate) -> Self {
-                (
-                    value.apkHash,
-                    value.updateBlockNumber,
-                    value.nextUpdateBlockNumber,
-                )
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for ApkUpdate {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {
-                    apkHash: tuple.0,
-                    updateBlockNumber: tuple.1,
-                    nextUpdateBlockNumber: tuple.2,
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolValue for ApkUpdate {
-            type SolType = Self;
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::private::SolTypeValue<Self> for ApkUpdate {
-            #[inline]
-            fn stv_to_tokens(&self) -> <Self as alloy_sol_types::SolType>::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::FixedBytes<
-                        24,
-                    > as alloy_sol_types::SolType>::tokenize(&self.apkHash),
-                    <alloy::sol_types::sol_data::Uint<
-                        32,
-                    > as alloy_sol_types::SolType>::tokenize(&self.updateBlockNumber),
-                    <alloy::sol_types::sol_data::Uint<
-                        32,
-                    > as alloy_sol_types::SolType>::tokenize(&self.nextUpdateBlockNumber),
-                )
-            }
-            #[inline]
-            fn stv_abi_encoded_size(&self) -> usize {
-                if let Some(size) = <Self as alloy_sol_types::SolType>::ENCODED_SIZE {
-                    return size;
-                }
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encoded_size(&tuple)
-            }
-            #[inline]
-            fn stv_eip712_data_word(&self) -> alloy_sol_types::Word {
-                <Self as alloy_sol_types::SolStruct>::eip712_hash_struct(self)
-            }
-            #[inline]
-            fn stv_abi_encode_packed_to(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encode_packed_to(
-                    &tuple, out,
-                )
-            }
-            #[inline]
-            fn stv_abi_packed_encoded_size(&self) -> usize {
-                if let Some(size) = <Self as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE {
-                    return size;
-                }
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_packed_encoded_size(
-                    &tuple,
-                )
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolType for ApkUpdate {
-            type RustType = Self;
-            type Token<'a> = <UnderlyingSolTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SOL_NAME: &'static str = <Self as alloy_sol_types::SolStruct>::NAME;
-            const ENCODED_SIZE: Option<usize> =
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::ENCODED_SIZE;
-            const PACKED_ENCODED_SIZE: Option<usize> =
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE;
-            #[inline]
-            fn valid_token(token: &Self::Token<'_>) -> bool {
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::valid_token(token)
-            }
-            #[inline]
-            fn detokenize(token: Self::Token<'_>) -> Self::RustType {
-                let tuple = <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::detokenize(token);
-                <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolStruct for ApkUpdate {
-            const NAME: &'static str = "ApkUpdate";
-            #[inline]
-            fn eip712_root_type() -> alloy_sol_types::private::Cow<'static, str> {
-                alloy_sol_types::private::Cow::Borrowed(
-                    "ApkUpdate(bytes24 apkHash,uint32 updateBlockNumber,uint32 nextUpdateBlockNumber)",
-                )
-            }
-            #[inline]
-            fn eip712_components()
-            -> alloy_sol_types::private::Vec<alloy_sol_types::private::Cow<'static, str>>
-            {
-                alloy_sol_types::private::Vec::new()
-            }
-            #[inline]
-            fn eip712_encode_type() -> alloy_sol_types::private::Cow<'static, str> {
-                <Self as alloy_sol_types::SolStruct>::eip712_root_type()
-            }
-            #[inline]
-            fn eip712_encode_data(&self) -> alloy_sol_types::private::Vec<u8> {
-                [
-                    <alloy::sol_types::sol_data::FixedBytes<
-                        24,
-                    > as alloy_sol_types::SolType>::eip712_data_word(&self.apkHash)
-                        .0,
-                    <alloy::sol_types::sol_data::Uint<
-                        32,
-                    > as alloy_sol_types::SolType>::eip712_data_word(
-                            &self.updateBlockNumber,
-                        )
-                        .0,
-                    <alloy::sol_types::sol_data::Uint<
-                        32,
-                    > as alloy_sol_types::SolType>::eip712_data_word(
-                            &self.nextUpdateBlockNumber,
-                        )
-                        .0,
-                ]
-                    .concat()
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::EventTopic for ApkUpdate {
-            #[inline]
-            fn topic_preimage_length(rust: &Self::RustType) -> usize {
-                <alloy::sol_types::sol_data::FixedBytes<
-                        24,
-                    > as alloy_sol_types::EventTopic>::topic_preimage_length(
-                        &rust.apkHash,
-                    )
-                    + <alloy::sol_types::sol_data::Uint<
-                        32,
-                    > as alloy_sol_types::EventTopic>::topic_preimage_length(
-                        &rust.updateBlockNumber,
-                    )
-                    + <alloy::sol_types::sol_data::Uint<
-                        32,
-                    > as alloy_sol_types::EventTopic>::topic_preimage_length(
-                        &rust.nextUpdateBlockNumber,
-                    )
-            }
-            #[inline]
-            fn encode_topic_preimage(
-                rust: &Self::RustType,
-                out: &mut alloy_sol_types::private::Vec<u8>,
-            ) {
-                out.reserve(<Self as alloy_sol_types::EventTopic>::topic_preimage_length(rust));
-                <alloy::sol_types::sol_data::FixedBytes<
-                    24,
-                > as alloy_sol_types::EventTopic>::encode_topic_preimage(
-                    &rust.apkHash,
-                    out,
-                );
-                <alloy::sol_types::sol_data::Uint<
-                    32,
-                > as alloy_sol_types::EventTopic>::encode_topic_preimage(
-                    &rust.updateBlockNumber,
-                    out,
-                );
-                <alloy::sol_types::sol_data::Uint<
-                    32,
-                > as alloy_sol_types::EventTopic>::encode_topic_preimage(
-                    &rust.nextUpdateBlockNumber,
-                    out,
-                );
-            }
-            #[inline]
-            fn encode_topic(rust: &Self::RustType) -> alloy_sol_types::abi::token::WordToken {
-                let mut out = alloy_sol_types::private::Vec::new();
-                <Self as alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
-                alloy_sol_types::abi::token::WordToken(alloy_sol_types::private::keccak256(out))
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**```solidity
-    struct PubkeyRegistrationParams { BN254.G1Point pubkeyRegistrationSignature; BN254.G1Point pubkeyG1; BN254.G2Point pubkeyG2; }
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct PubkeyRegistrationParams {
-        #[allow(missing_docs)]
-        pub pubkeyRegistrationSignature: <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-        #[allow(missing_docs)]
-        pub pubkeyG1: <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-        #[allow(missing_docs)]
-        pub pubkeyG2: <BN254::G2Point as alloy::sol_types::SolType>::RustType,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = (BN254::G1Point, BN254::G1Point, BN254::G2Point);
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = (
-            <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-            <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-            <BN254::G2Point as alloy::sol_types::SolType>::RustType,
-        );
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<PubkeyRegistrationParams> for UnderlyingRustTuple<'_> {
-            fn from(value: PubkeyRegistrationParams) -> Self {
-                (
-                    value.pubkeyRegistrationSignature,
-                    value.pubkeyG1,
-                    value.pubkeyG2,
-                )
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for PubkeyRegistrationParams {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {
-                    pubkeyRegistrationSignature: tuple.0,
-                    pubkeyG1: tuple.1,
-                    pubkeyG2: tuple.2,
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolValue for PubkeyRegistrationParams {
-            type SolType = Self;
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::private::SolTypeValue<Self> for PubkeyRegistrationParams {
-            #[inline]
-            fn stv_to_tokens(&self) -> <Self as alloy_sol_types::SolType>::Token<'_> {
-                (
-                    <BN254::G1Point as alloy_sol_types::SolType>::tokenize(
-                        &self.pubkeyRegistrationSignature,
-                    ),
-                    <BN254::G1Point as alloy_sol_types::SolType>::tokenize(&self.pubkeyG1),
-                    <BN254::G2Point as alloy_sol_types::SolType>::tokenize(&self.pubkeyG2),
-                )
-            }
-            #[inline]
-            fn stv_abi_encoded_size(&self) -> usize {
-                if let Some(size) = <Self as alloy_sol_types::SolType>::ENCODED_SIZE {
-                    return size;
-                }
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encoded_size(&tuple)
-            }
-            #[inline]
-            fn stv_eip712_data_word(&self) -> alloy_sol_types::Word {
-                <Self as alloy_sol_types::SolStruct>::eip712_hash_struct(self)
-            }
-            #[inline]
-            fn stv_abi_encode_packed_to(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_encode_packed_to(
-                    &tuple, out,
-                )
-            }
-            #[inline]
-            fn stv_abi_packed_encoded_size(&self) -> usize {
-                if let Some(size) = <Self as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE {
-                    return size;
-                }
-                let tuple =
-                    <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(self.clone());
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::abi_packed_encoded_size(
-                    &tuple,
-                )
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolType for PubkeyRegistrationParams {
-            type RustType = Self;
-            type Token<'a> = <UnderlyingSolTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SOL_NAME: &'static str = <Self as alloy_sol_types::SolStruct>::NAME;
-            const ENCODED_SIZE: Option<usize> =
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::ENCODED_SIZE;
-            const PACKED_ENCODED_SIZE: Option<usize> =
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::PACKED_ENCODED_SIZE;
-            #[inline]
-            fn valid_token(token: &Self::Token<'_>) -> bool {
-                <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::valid_token(token)
-            }
-            #[inline]
-            fn detokenize(token: Self::Token<'_>) -> Self::RustType {
-                let tuple = <UnderlyingSolTuple<'_> as alloy_sol_types::SolType>::detokenize(token);
-                <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolStruct for PubkeyRegistrationParams {
-            const NAME: &'static str = "PubkeyRegistrationParams";
-            #[inline]
-            fn eip712_root_type() -> alloy_sol_types::private::Cow<'static, str> {
-                alloy_sol_types::private::Cow::Borrowed(
-                    "PubkeyRegistrationParams(BN254.G1Point pubkeyRegistrationSignature,BN254.G1Point pubkeyG1,BN254.G2Point pubkeyG2)",
-                )
-            }
-            #[inline]
-            fn eip712_components()
-            -> alloy_sol_types::private::Vec<alloy_sol_types::private::Cow<'static, str>>
-            {
-                let mut components = alloy_sol_types::private::Vec::with_capacity(3);
-                components.push(<BN254::G1Point as alloy_sol_types::SolStruct>::eip712_root_type());
-                components
-                    .extend(<BN254::G1Point as alloy_sol_types::SolStruct>::eip712_components());
-                components.push(<BN254::G1Point as alloy_sol_types::SolStruct>::eip712_root_type());
-                components
-                    .extend(<BN254::G1Point as alloy_sol_types::SolStruct>::eip712_components());
-                components.push(<BN254::G2Point as alloy_sol_types::SolStruct>::eip712_root_type());
-                components
-                    .extend(<BN254::G2Point as alloy_sol_types::SolStruct>::eip712_components());
-                components
-            }
-            #[inline]
-            fn eip712_encode_data(&self) -> alloy_sol_types::private::Vec<u8> {
-                [
-                    <BN254::G1Point as alloy_sol_types::SolType>::eip712_data_word(
-                        &self.pubkeyRegistrationSignature,
-                    )
-                    .0,
-                    <BN254::G1Point as alloy_sol_types::SolType>::eip712_data_word(&self.pubkeyG1)
-                        .0,
-                    <BN254::G2Point as alloy_sol_types::SolType>::eip712_data_word(&self.pubkeyG2)
-                        .0,
-                ]
-                .concat()
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::EventTopic for PubkeyRegistrationParams {
-            #[inline]
-            fn topic_preimage_length(rust: &Self::RustType) -> usize {
-                <BN254::G1Point as alloy_sol_types::EventTopic>::topic_preimage_length(
-                    &rust.pubkeyRegistrationSignature,
-                ) + <BN254::G1Point as alloy_sol_types::EventTopic>::topic_preimage_length(
-                    &rust.pubkeyG1,
-                ) + <BN254::G2Point as alloy_sol_types::EventTopic>::topic_preimage_length(
-                    &rust.pubkeyG2,
-                )
-            }
-            #[inline]
-            fn encode_topic_preimage(
-                rust: &Self::RustType,
-                out: &mut alloy_sol_types::private::Vec<u8>,
-            ) {
-                out.reserve(<Self as alloy_sol_types::EventTopic>::topic_preimage_length(rust));
-                <BN254::G1Point as alloy_sol_types::EventTopic>::encode_topic_preimage(
-                    &rust.pubkeyRegistrationSignature,
-                    out,
-                );
-                <BN254::G1Point as alloy_sol_types::EventTopic>::encode_topic_preimage(
-                    &rust.pubkeyG1,
-                    out,
-                );
-                <BN254::G2Point as alloy_sol_types::EventTopic>::encode_topic_preimage(
-                    &rust.pubkeyG2,
-                    out,
-                );
-            }
-            #[inline]
-            fn encode_topic(rust: &Self::RustType) -> alloy_sol_types::abi::token::WordToken {
-                let mut out = alloy_sol_types::private::Vec::new();
-                <Self as alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
-                alloy_sol_types::abi::token::WordToken(alloy_sol_types::private::keccak256(out))
-            }
-        }
-    };
-    use alloy::contract as alloy_contract;
-    /**Creates a new wrapper around an on-chain [`IBLSApkRegistryTypes`](self) contract instance.
-
-    See the [wrapper's documentation](`IBLSApkRegistryTypesInstance`) for more details.*/
-    #[inline]
-    pub const fn new<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    >(
-        address: alloy_sol_types::private::Address,
-        provider: P,
-    ) -> IBLSApkRegistryTypesInstance<T, P, N> {
-        IBLSApkRegistryTypesInstance::<T, P, N>::new(address, provider)
-    }
-    /**A [`IBLSApkRegistryTypes`](self) instance.
-
-    Contains type-safe methods for interacting with an on-chain instance of the
-    [`IBLSApkRegistryTypes`](self) contract located at a given `address`, using a given
-    provider `P`.
-
-    If the contract bytecode is available (see the [`sol!`](alloy_sol_types::sol!)
-    documentation on how to provide it), the `deploy` and `deploy_builder` methods can
-    be used to deploy a new instance of the contract.
-
-    See the [module-level documentation](self) for all the available methods.*/
-    #[derive(Clone)]
-    pub struct IBLSApkRegistryTypesInstance<T, P, N = alloy_contract::private::Ethereum> {
-        address: alloy_sol_types::private::Address,
-        provider: P,
-        _network_transport: ::core::marker::PhantomData<(N, T)>,
-    }
-    #[automatically_derived]
-    impl<T, P, N> ::core::fmt::Debug for IBLSApkRegistryTypesInstance<T, P, N> {
-        #[inline]
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_tuple("IBLSApkRegistryTypesInstance")
-                .field(&self.address)
-                .finish()
-        }
-    }
-    /// Instantiation and getters/setters.
-    #[automatically_derived]
-    impl<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    > IBLSApkRegistryTypesInstance<T, P, N>
-    {
-        /**Creates a new wrapper around an on-chain [`IBLSApkRegistryTypes`](self) contract instance.
-
-        See the [wrapper's documentation](`IBLSApkRegistryTypesInstance`) for more details.*/
-        #[inline]
-        pub const fn new(address: alloy_sol_types::private::Address, provider: P) -> Self {
-            Self {
-                address,
-                provider,
-                _network_transport: ::core::marker::PhantomData,
-            }
-        }
-        /// Returns a reference to the address.
-        #[inline]
-        pub const fn address(&self) -> &alloy_sol_types::private::Address {
-            &self.address
-        }
-        /// Sets the address.
-        #[inline]
-        pub fn set_address(&mut self, address: alloy_sol_types::private::Address) {
-            self.address = address;
-        }
-        /// Sets the address and returns `self`.
-        pub fn at(mut self, address: alloy_sol_types::private::Address) -> Self {
-            self.set_address(address);
-            self
-        }
-        /// Returns a reference to the provider.
-        #[inline]
-        pub const fn provider(&self) -> &P {
-            &self.provider
-        }
-    }
-    impl<T, P: ::core::clone::Clone, N> IBLSApkRegistryTypesInstance<T, &P, N> {
-        /// Clones the provider and returns a new instance with the cloned provider.
-        #[inline]
-        pub fn with_cloned_provider(self) -> IBLSApkRegistryTypesInstance<T, P, N> {
-            IBLSApkRegistryTypesInstance {
-                address: self.address,
-                provider: ::core::clone::Clone::clone(&self.provider),
-                _network_transport: ::core::marker::PhantomData,
-            }
-        }
-    }
-    /// Function calls.
-    #[automatically_derived]
-    impl<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    > IBLSApkRegistryTypesInstance<T, P, N>
-    {
-        /// Creates a new call builder using this contract instance's provider and address.
-        ///
-        /// Note that the call can be any function call, not just those defined in this
-        /// contract. Prefer using the other methods for building type-safe contract calls.
-        pub fn call_builder<C: alloy_sol_types::SolCall>(
-            &self,
-            call: &C,
-        ) -> alloy_contract::SolCallBuilder<T, &P, C, N> {
-            alloy_contract::SolCallBuilder::new_sol(&self.provider, &self.address, call)
-        }
-    }
-    /// Event filters.
-    #[automatically_derived]
-    impl<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    > IBLSApkRegistryTypesInstance<T, P, N>
-    {
-        /// Creates a new event filter using this contract instance's provider and address.
-        ///
-        /// Note that the type can be any event, not just those defined in this contract.
-        /// Prefer using the other methods for building type-safe event filters.
-        pub fn event_filter<E: alloy_sol_types::SolEvent>(
-            &self,
-        ) -> alloy_contract::Event<T, &P, E, N> {
-            alloy_contract::Event::new_sol(&self.provider, &self.address)
-        }
-    }
-}
-/**
-
-Generated by the following Solidity interface...
-```solidity
-library BN254 {
-    struct G1Point {
-        uint256 X;
-        uint256 Y;
-    }
-    struct G2Point {
-        uint256[2] X;
-        uint256[2] Y;
-    }
-}
-
-library IBLSApkRegistryTypes {
-    struct ApkUpdate {
-        bytes24 apkHash;
-        uint32 updateBlockNumber;
-        uint32 nextUpdateBlockNumber;
-    }
-    struct PubkeyRegistrationParams {
-        BN254.G1Point pubkeyRegistrationSignature;
-        BN254.G1Point pubkeyG1;
-        BN254.G2Point pubkeyG2;
-    }
-}
-
-interface BLSApkRegistry {
-    error BLSPubkeyAlreadyRegistered();
-    error BlockNumberBeforeFirstUpdate();
-    error BlockNumberNotLatest();
-    error BlockNumberTooRecent();
-    error ECAddFailed();
-    error ECMulFailed();
-    error ECPairingFailed();
-    error G2PubkeyAlreadySet();
-    error InvalidBLSSignatureOrPrivateKey();
-    error OnlyRegistryCoordinatorOwner();
-    error OperatorAlreadyRegistered();
-    error OperatorNotRegistered();
-    error QuorumAlreadyExists();
-    error QuorumDoesNotExist();
-    error ZeroPubKey();
-
-    event Initialized(uint8 version);
-    event NewG2PubkeyRegistration(address indexed operator, BN254.G2Point pubkeyG2);
-    event NewPubkeyRegistration(address indexed operator, BN254.G1Point pubkeyG1, BN254.G2Point pubkeyG2);
-    event OperatorAddedToQuorums(address operator, bytes32 operatorId, bytes quorumNumbers);
-    event OperatorRemovedFromQuorums(address operator, bytes32 operatorId, bytes quorumNumbers);
-
-    constructor(address _slashingRegistryCoordinator);
-
-    function apkHistory(uint8 quorumNumber, uint256) external view returns (bytes24 apkHash, uint32 updateBlockNumber, uint32 nextUpdateBlockNumber);
-    function currentApk(uint8 quorumNumber) external view returns (uint256 X, uint256 Y);
-    function deregisterOperator(address operator, bytes memory quorumNumbers) external;
-    function getApk(uint8 quorumNumber) external view returns (BN254.G1Point memory);
-    function getApkHashAtBlockNumberAndIndex(uint8 quorumNumber, uint32 blockNumber, uint256 index) external view returns (bytes24);
-    function getApkHistoryLength(uint8 quorumNumber) external view returns (uint32);
-    function getApkIndicesAtBlockNumber(bytes memory quorumNumbers, uint256 blockNumber) external view returns (uint32[] memory);
-    function getApkUpdateAtIndex(uint8 quorumNumber, uint256 index) external view returns (IBLSApkRegistryTypes.ApkUpdate memory);
-    function getOperatorFromPubkeyHash(bytes32 pubkeyHash) external view returns (address);
-    function getOperatorId(address operator) external view returns (bytes32);
-    function getOperatorPubkeyG2(address operator) external view returns (BN254.G2Point memory);
-    function getRegisteredPubkey(address operator) external view returns (BN254.G1Point memory, bytes32);
-    function initializeQuorum(uint8 quorumNumber) external;
-    function operatorToPubkey(address operator) external view returns (uint256 X, uint256 Y);
-    function operatorToPubkeyHash(address operator) external view returns (bytes32 operatorId);
-    function pubkeyHashToOperator(bytes32 pubkeyHash) external view returns (address operator);
-    function registerBLSPublicKey(address operator, IBLSApkRegistryTypes.PubkeyRegistrationParams memory params, BN254.G1Point memory pubkeyRegistrationMessageHash) external returns (bytes32 operatorId);
-    function registerOperator(address operator, bytes memory quorumNumbers) external;
-    function registryCoordinator() external view returns (address);
-    function verifyAndRegisterG2PubkeyForOperator(address operator, BN254.G2Point memory pubkeyG2) external;
-}
-```
-
-...which was generated by the following JSON ABI:
-```json
-[
-  {
-    "type": "constructor",
-    "inputs": [
-      {
-        "name": "_slashingRegistryCoordinator",
-        "type": "address",
-        "internalType": "contract ISlashingRegistryCoordinator"
-      }
-    ],
-    "stateMutability": "nonpayable"
-  },
-  {
-    "type": "function",
-    "name": "apkHistory",
-    "inputs": [
-      {
-        "name": "quorumNumber",
-        "type": "uint8",
-        "internalType": "uint8"
-      },
-      {
-        "name": "",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "apkHash",
-        "type": "bytes24",
-        "internalType": "bytes24"
-      },
-      {
-        "name": "updateBlockNumber",
-        "type": "uint32",
-        "internalType": "uint32"
-      },
-      {
-        "name": "nextUpdateBlockNumber",
-        "type": "uint32",
-        "internalType": "uint32"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "currentApk",
-    "inputs": [
-      {
-        "name": "quorumNumber",
-        "type": "uint8",
-        "internalType": "uint8"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "X",
-        "type": "uint256",
-        "internalType": "uint256"
-      },
-      {
-        "name": "Y",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "deregisterOperator",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      },
-      {
-        "name": "quorumNumbers",
-        "type": "bytes",
-        "internalType": "bytes"
-      }
-    ],
-    "outputs": [],
-    "stateMutability": "nonpayable"
-  },
-  {
-    "type": "function",
-    "name": "getApk",
-    "inputs": [
-      {
-        "name": "quorumNumber",
-        "type": "uint8",
-        "internalType": "uint8"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "tuple",
-        "internalType": "struct BN254.G1Point",
-        "components": [
-          {
-            "name": "X",
-            "type": "uint256",
-            "internalType": "uint256"
-          },
-          {
-            "name": "Y",
-            "type": "uint256",
-            "internalType": "uint256"
-          }
-        ]
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "getApkHashAtBlockNumberAndIndex",
-    "inputs": [
-      {
-        "name": "quorumNumber",
-        "type": "uint8",
-        "internalType": "uint8"
-      },
-      {
-        "name": "blockNumber",
-        "type": "uint32",
-        "internalType": "uint32"
-      },
-      {
-        "name": "index",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "bytes24",
-        "internalType": "bytes24"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "getApkHistoryLength",
-    "inputs": [
-      {
-        "name": "quorumNumber",
-        "type": "uint8",
-        "internalType": "uint8"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "uint32",
-        "internalType": "uint32"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "getApkIndicesAtBlockNumber",
-    "inputs": [
-      {
-        "name": "quorumNumbers",
-        "type": "bytes",
-        "internalType": "bytes"
-      },
-      {
-        "name": "blockNumber",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "uint32[]",
-        "internalType": "uint32[]"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "getApkUpdateAtIndex",
-    "inputs": [
-      {
-        "name": "quorumNumber",
-        "type": "uint8",
-        "internalType": "uint8"
-      },
-      {
-        "name": "index",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "tuple",
-        "internalType": "struct IBLSApkRegistryTypes.ApkUpdate",
-        "components": [
-          {
-            "name": "apkHash",
-            "type": "bytes24",
-            "internalType": "bytes24"
-          },
-          {
-            "name": "updateBlockNumber",
-            "type": "uint32",
-            "internalType": "uint32"
-          },
-          {
-            "name": "nextUpdateBlockNumber",
-            "type": "uint32",
-            "internalType": "uint32"
-          }
-        ]
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "getOperatorFromPubkeyHash",
-    "inputs": [
-      {
-        "name": "pubkeyHash",
-        "type": "bytes32",
-        "internalType": "bytes32"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "getOperatorId",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "bytes32",
-        "internalType": "bytes32"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "getOperatorPubkeyG2",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "tuple",
-        "internalType": "struct BN254.G2Point",
-        "components": [
-          {
-            "name": "X",
-            "type": "uint256[2]",
-            "internalType": "uint256[2]"
-          },
-          {
-            "name": "Y",
-            "type": "uint256[2]",
-            "internalType": "uint256[2]"
-          }
-        ]
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "getRegisteredPubkey",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "tuple",
-        "internalType": "struct BN254.G1Point",
-        "components": [
-          {
-            "name": "X",
-            "type": "uint256",
-            "internalType": "uint256"
-          },
-          {
-            "name": "Y",
-            "type": "uint256",
-            "internalType": "uint256"
-          }
-        ]
-      },
-      {
-        "name": "",
-        "type": "bytes32",
-        "internalType": "bytes32"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "initializeQuorum",
-    "inputs": [
-      {
-        "name": "quorumNumber",
-        "type": "uint8",
-        "internalType": "uint8"
-      }
-    ],
-    "outputs": [],
-    "stateMutability": "nonpayable"
-  },
-  {
-    "type": "function",
-    "name": "operatorToPubkey",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "X",
-        "type": "uint256",
-        "internalType": "uint256"
-      },
-      {
-        "name": "Y",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "operatorToPubkeyHash",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "operatorId",
-        "type": "bytes32",
-        "internalType": "bytes32"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "pubkeyHashToOperator",
-    "inputs": [
-      {
-        "name": "pubkeyHash",
-        "type": "bytes32",
-        "internalType": "bytes32"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "registerBLSPublicKey",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      },
-      {
-        "name": "params",
-        "type": "tuple",
-        "internalType": "struct IBLSApkRegistryTypes.PubkeyRegistrationParams",
-        "components": [
-          {
-            "name": "pubkeyRegistrationSignature",
-            "type": "tuple",
-            "internalType": "struct BN254.G1Point",
-            "components": [
-              {
-                "name": "X",
-                "type": "uint256",
-                "internalType": "uint256"
-              },
-              {
-                "name": "Y",
-                "type": "uint256",
-                "internalType": "uint256"
-              }
-            ]
-          },
-          {
-            "name": "pubkeyG1",
-            "type": "tuple",
-            "internalType": "struct BN254.G1Point",
-            "components": [
-              {
-                "name": "X",
-                "type": "uint256",
-                "internalType": "uint256"
-              },
-              {
-                "name": "Y",
-                "type": "uint256",
-                "internalType": "uint256"
-              }
-            ]
-          },
-          {
-            "name": "pubkeyG2",
-            "type": "tuple",
-            "internalType": "struct BN254.G2Point",
-            "components": [
-              {
-                "name": "X",
-                "type": "uint256[2]",
-                "internalType": "uint256[2]"
-              },
-              {
-                "name": "Y",
-                "type": "uint256[2]",
-                "internalType": "uint256[2]"
-              }
-            ]
-          }
-        ]
-      },
-      {
-        "name": "pubkeyRegistrationMessageHash",
-        "type": "tuple",
-        "internalType": "struct BN254.G1Point",
-        "components": [
-          {
-            "name": "X",
-            "type": "uint256",
-            "internalType": "uint256"
-          },
-          {
-            "name": "Y",
-            "type": "uint256",
-            "internalType": "uint256"
-          }
-        ]
-      }
-    ],
-    "outputs": [
-      {
-        "name": "operatorId",
-        "type": "bytes32",
-        "internalType": "bytes32"
-      }
-    ],
-    "stateMutability": "nonpayable"
-  },
-  {
-    "type": "function",
-    "name": "registerOperator",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      },
-      {
-        "name": "quorumNumbers",
-        "type": "bytes",
-        "internalType": "bytes"
-      }
-    ],
-    "outputs": [],
-    "stateMutability": "nonpayable"
-  },
-  {
-    "type": "function",
-    "name": "registryCoordinator",
-    "inputs": [],
-    "outputs": [
-      {
-        "name": "",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "stateMutability": "view"
-  },
-  {
-    "type": "function",
-    "name": "verifyAndRegisterG2PubkeyForOperator",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "internalType": "address"
-      },
-      {
-        "name": "pubkeyG2",
-        "type": "tuple",
-        "internalType": "struct BN254.G2Point",
-        "components": [
-          {
-            "name": "X",
-            "type": "uint256[2]",
-            "internalType": "uint256[2]"
-          },
-          {
-            "name": "Y",
-            "type": "uint256[2]",
-            "internalType": "uint256[2]"
-          }
-        ]
-      }
-    ],
-    "outputs": [],
-    "stateMutability": "nonpayable"
-  },
-  {
-    "type": "event",
-    "name": "Initialized",
-    "inputs": [
-      {
-        "name": "version",
-        "type": "uint8",
-        "indexed": false,
-        "internalType": "uint8"
-      }
-    ],
-    "anonymous": false
-  },
-  {
-    "type": "event",
-    "name": "NewG2PubkeyRegistration",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "indexed": true,
-        "internalType": "address"
-      },
-      {
-        "name": "pubkeyG2",
-        "type": "tuple",
-        "indexed": false,
-        "internalType": "struct BN254.G2Point",
-        "components": [
-          {
-            "name": "X",
-            "type": "uint256[2]",
-            "internalType": "uint256[2]"
-          },
-          {
-            "name": "Y",
-            "type": "uint256[2]",
-            "internalType": "uint256[2]"
-          }
-        ]
-      }
-    ],
-    "anonymous": false
-  },
-  {
-    "type": "event",
-    "name": "NewPubkeyRegistration",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "indexed": true,
-        "internalType": "address"
-      },
-      {
-        "name": "pubkeyG1",
-        "type": "tuple",
-        "indexed": false,
-        "internalType": "struct BN254.G1Point",
-        "components": [
-          {
-            "name": "X",
-            "type": "uint256",
-            "internalType": "uint256"
-          },
-          {
-            "name": "Y",
-            "type": "uint256",
-            "internalType": "uint256"
-          }
-        ]
-      },
-      {
-        "name": "pubkeyG2",
-        "type": "tuple",
-        "indexed": false,
-        "internalType": "struct BN254.G2Point",
-        "components": [
-          {
-            "name": "X",
-            "type": "uint256[2]",
-            "internalType": "uint256[2]"
-          },
-          {
-            "name": "Y",
-            "type": "uint256[2]",
-            "internalType": "uint256[2]"
-          }
-        ]
-      }
-    ],
-    "anonymous": false
-  },
-  {
-    "type": "event",
-    "name": "OperatorAddedToQuorums",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "indexed": false,
-        "internalType": "address"
-      },
-      {
-        "name": "operatorId",
-        "type": "bytes32",
-        "indexed": false,
-        "internalType": "bytes32"
-      },
-      {
-        "name": "quorumNumbers",
-        "type": "bytes",
-        "indexed": false,
-        "internalType": "bytes"
-      }
-    ],
-    "anonymous": false
-  },
-  {
-    "type": "event",
-    "name": "OperatorRemovedFromQuorums",
-    "inputs": [
-      {
-        "name": "operator",
-        "type": "address",
-        "indexed": false,
-        "internalType": "address"
-      },
-      {
-        "name": "operatorId",
-        "type": "bytes32",
-        "indexed": false,
-        "internalType": "bytes32"
-      },
-      {
-        "name": "quorumNumbers",
-        "type": "bytes",
-        "indexed": false,
-        "internalType": "bytes"
-      }
-    ],
-    "anonymous": false
-  },
-  {
-    "type": "error",
-    "name": "BLSPubkeyAlreadyRegistered",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "BlockNumberBeforeFirstUpdate",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "BlockNumberNotLatest",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "BlockNumberTooRecent",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "ECAddFailed",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "ECMulFailed",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "ECPairingFailed",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "G2PubkeyAlreadySet",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "InvalidBLSSignatureOrPrivateKey",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "OnlyRegistryCoordinatorOwner",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "OperatorAlreadyRegistered",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "OperatorNotRegistered",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "QuorumAlreadyExists",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "QuorumDoesNotExist",
-    "inputs": []
-  },
-  {
-    "type": "error",
-    "name": "ZeroPubKey",
-    "inputs": []
-  }
-]
-```*/
-#[allow(
-    non_camel_case_types,
-    non_snake_case,
-    clippy::pub_underscore_fields,
-    clippy::style,
-    clippy::empty_structs_with_brackets
-)]
-pub mod BLSApkRegistry {
-    use super::*;
-    use alloy::sol_types as alloy_sol_types;
-    /// The creation / init bytecode of the contract.
-    ///
-    /// ```text
-    ///0x60a060405234801561000f575f5ffd5b50604051611e9f380380611e9f83398101604081905261002e91610108565b6001600160a01b0381166080528061004461004b565b5050610135565b5f54610100900460ff16156100b65760405162461bcd60e51b815260206004820152602760248201527f496e697469616c697a61626c653a20636f6e747261637420697320696e697469604482015266616c697a696e6760c81b606482015260840160405180910390fd5b5f5460ff9081161015610106575f805460ff191660ff9081179091556040519081527f7f26b83ff96e1f2b6a682f133852f6798a09c465da95921460cefb38474024989060200160405180910390a15b565b5f60208284031215610118575f5ffd5b81516001600160a01b038116811461012e575f5ffd5b9392505050565b608051611d4461015b5f395f818161033901528181610e5e01526114620152611d445ff3fe608060405234801561000f575f5ffd5b5060043610610126575f3560e01c80636d14a987116100a9578063d1a646501161006e578063d1a6465014610408578063d5254a8c1461041b578063de29fac01461043b578063e8bb9ae61461045a578063f4e24fe514610482575f5ffd5b80636d14a987146103345780637916cea61461035b5780637ff81a871461039c578063a3db80e2146103cf578063bf79ce58146103f5575f5ffd5b806347b314e8116100ef57806347b314e8146101ff5780635f61a8841461023f578063605747d51461029957806367169911146102e757806368bccaac14610307575f5ffd5b8062a1f4cb1461012a57806313542a4e1461016a57806326d941f2146101a0578063377ed99d146101b55780633fb27952146101ec575b5f5ffd5b610150610138366004611701565b60036020525f90815260409020805460019091015482565b604080519283526020830191909152015b60405180910390f35b610192610178366004611701565b6001600160a01b03165f9081526001602052604090205490565b604051908152602001610161565b6101b36101ae36600461172c565b610495565b005b6101d76101c336600461172c565b60ff165f9081526004602052604090205490565b60405163ffffffff9091168152602001610161565b6101b36101fa3660046117b3565b610555565b61022761020d36600461185a565b5f908152600260205260409020546001600160a01b031690565b6040516001600160a01b039091168152602001610161565b61028c61024d36600461172c565b604080518082019091525f80825260208201525060ff165f90815260056020908152604091829020825180840190935280548352600101549082015290565b6040516101619190611871565b6102ac6102a7366004611888565b6105d1565b60408051825167ffffffffffffffff1916815260208084015163ffffffff908116918301919091529282015190921690820152606001610161565b6102fa6102f5366004611701565b610662565b60405161016191906118d2565b61031a6103153660046118fd565b6106f6565b60405167ffffffffffffffff199091168152602001610161565b6102277f000000000000000000000000000000000000000000000000000000000000000081565b61036e610369366004611888565b6107db565b6040805167ffffffffffffffff19909416845263ffffffff9283166020850152911690820152606001610161565b6103af6103aa366004611701565b610822565b604080518351815260209384015193810193909352820152606001610161565b6101506103dd36600461172c565b60056020525f90815260409020805460019091015482565b610192610403366004611941565b610898565b6101b361041636600461199b565b610b6d565b61042e6104293660046119d9565b610c54565b6040516101619190611a4b565b610192610449366004611701565b60016020525f908152604090205481565b61022761046836600461185a565b60026020525f90815260409020546001600160a01b031681565b6101b36104903660046117b3565b610dec565b61049d610e53565b60ff81165f90815260046020526040902054156104cd576040516310cda51760e21b815260040160405180910390fd5b60ff165f908152600460209081526040808320815160608101835284815263ffffffff4381168286019081528285018781528454600181018655948852959096209151919092018054955194518316600160e01b026001600160e01b0395909316600160c01b026001600160e01b03199096169190931c179390931791909116919091179055565b61055d610e53565b5f61056783610822565b5090506105748282610e9e565b7f73a2b7fb844724b971802ae9b15db094d4b7192df9d7350e14eb466b9b22eb4e836105b4856001600160a01b03165f9081526001602052604090205490565b846040516105c493929190611a93565b60405180910390a1505050565b604080516060810182525f808252602080830182905282840182905260ff86168252600490529190912080548390811061060d5761060d611ade565b5f91825260209182902060408051606081018252919092015467ffffffffffffffff1981841b16825263ffffffff600160c01b8204811694830194909452600160e01b90049092169082015290505b92915050565b61066a61162e565b6001600160a01b0382165f9081526006602052604090819020815160808101835291829081018260028282826020028201915b81548152602001906001019080831161069d57505050918352505060408051808201918290526020909201919060028481019182845b8154815260200190600101908083116106d3575050505050815250509050919050565b60ff83165f90815260046020526040812080548291908490811061071c5761071c611ade565b5f91825260209182902060408051606081018252919092015467ffffffffffffffff1981841b16825263ffffffff600160c01b82048116948301859052600160e01b90910481169282019290925292508516101561078d57604051633d22884160e01b815260040160405180910390fd5b604081015163ffffffff1615806107b35750806040015163ffffffff168463ffffffff16105b6107d057604051636fe02d4b60e01b815260040160405180910390fd5b5190505b9392505050565b6004602052815f5260405f2081815481106107f4575f80fd5b5f91825260209091200154604081901b925063ffffffff600160c01b820481169250600160e01b9091041683565b604080518082019091525f80825260208201526001600160a01b0382165f8181526003602090815260408083208151808301835281548152600191820154818501529484529091528120549091908061088e576040516325ec6c1f60e01b815260040160405180910390fd5b9094909350915050565b5f6108a1610e53565b5f6108cd6108b736869003860160408701611af2565b80515f9081526020918201519091526040902090565b90507fad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5810361090f57604051630cc7509160e01b815260040160405180910390fd5b6001600160a01b0385165f9081526001602052604081205414610945576040516342ee68b560e01b815260040160405180910390fd5b5f818152600260205260409020546001600160a01b03161561097a57604051634c334c9760e11b815260040160405180910390fd5b604080515f917f30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001916109d2918835916020808b0135928b01359160608c01359160808d019160c08e01918d35918e8201359101611b23565b604051602081830303815290604052805190602001205f1c6109f49190611b65565b9050610a8d610a2d610a1883610a12368a90038a0160408b01611af2565b90611085565b610a2736899003890189611af2565b906110f5565b610a35611169565b610a76610a6785610a126040805180820182525f80825260209182015281518083019092526001825260029082015290565b610a27368a90038a018a611af2565b610a88368a90038a0160808b01611bc6565b611229565b610aaa5760405163a72d026360e01b815260040160405180910390fd5b6001600160a01b0386165f9081526003602090815260408083208882013581556060890135600190910155600690915290206080860190610aeb8282611c30565b50506001600160a01b0386165f81815260016020908152604080832086905585835260029091529081902080546001600160a01b0319168317905580517fe3fb6613af2e8930cf85d47fcf6db10192224a64c6cbe8023e0eee1ba382804191610b5c919089019060808a0190611c8b565b60405180910390a250949350505050565b610b75611460565b5f610b7f83610822565b509050610b8b83611511565b610bcb81610b97611169565b6040805180820182525f808252602091820152815180830190925260018252600290820152610a8836879003870187611bc6565b610be85760405163a72d026360e01b815260040160405180910390fd5b6001600160a01b0383165f9081526006602052604090208290610c0b8282611c30565b905050826001600160a01b03167f5c4f9f28153dbf3f00e69607a59e82ad806fffb78d09f179f62432f7e9d2511a83604051610c479190611caa565b60405180910390a2505050565b60605f8367ffffffffffffffff811115610c7057610c70611745565b604051908082528060200260200182016040528015610c99578160200160208202803683370190505b5090505f5b84811015610de3575f868683818110610cb957610cb9611ade565b919091013560f81c5f818152600460205260409020549092509050801580610d19575060ff82165f9081526004602052604081208054909190610cfe57610cfe611ade565b5f91825260209091200154600160c01b900463ffffffff1686105b15610d3757604051633f4cb70f60e01b815260040160405180910390fd5b805b8015610dd85760ff83165f9081526004602052604090208790610d5d600184611cb8565b81548110610d6d57610d6d611ade565b5f91825260209091200154600160c01b900463ffffffff1611610dc657610d95600182611cb8565b858581518110610da757610da7611ade565b602002602001019063ffffffff16908163ffffffff1681525050610dd8565b80610dd081611ccb565b915050610d39565b505050600101610c9e565b50949350505050565b610df4610e53565b5f610dfe83610822565b509050610e1382610e0e83611572565b610e9e565b7ff843ecd53a563675e62107be1494fdde4a3d49aeedaf8d88c616d85346e3500e836105b4856001600160a01b03165f9081526001602052604090205490565b336001600160a01b037f00000000000000000000000000000000000000000000000000000000000000001614610e9c57604051637070f3b160e11b815260040160405180910390fd5b565b604080518082019091525f80825260208201525f5b835181101561107f575f848281518110610ecf57610ecf611ade565b0160209081015160f81c5f8181526004909252604082205490925090819003610f0b57604051637310cff560e11b815260040160405180910390fd5b60ff82165f908152600560209081526040918290208251808401909352805483526001015490820152610f3e90866110f5565b60ff83165f818152600560209081526040808320855180825586840180516001938401559085525183528184209484526004909252822093975091929091610f869085611cb8565b81548110610f9657610f96611ade565b5f918252602090912001805490915063ffffffff438116600160c01b9092041603610fd45780546001600160c01b031916604083901c17815561106f565b805463ffffffff438116600160e01b8181026001600160e01b0394851617855560ff88165f908152600460209081526040808320815160608101835267ffffffffffffffff198b16815280840196875280830185815282546001810184559286529390942093519301805495519251871690940291909516600160c01b026001600160e01b0319949094169190941c17919091179092161790555b505060019092019150610eb39050565b50505050565b604080518082019091525f80825260208201526110a0611653565b835181526020808501519082015260408082018490525f908360608460076107d05a03fa905080806110ce57fe5b50806110ed57604051632319df1960e11b815260040160405180910390fd5b505092915050565b604080518082019091525f8082526020820152611110611671565b835181526020808501518183015283516040808401919091529084015160608301525f908360808460066107d05a03fa9050808061114a57fe5b50806110ed5760405163d4b68fd760e01b815260040160405180910390fd5b61117161162e565b50604080516080810182527f198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c28183019081527f1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed6060830152815281518083019092527f275dc4a288d1afb3cbb1ac09187524c7db36395df7be3b99e673b13a075a65ec82527f1d9befcd05a5323e6da4d435f3b617cdb3af83285c2df711ef39c01571827f9d60208381019190915281019190915290565b6040805180820182528581526020808201859052825180840190935285835282018390525f9161125761168f565b5f5b600281101561140e575f61126e826006611c19565b905084826002811061128257611282611ade565b60200201515183611293835f611ce0565b600c81106112a3576112a3611ade565b60200201528482600281106112ba576112ba611ade565b602002015160200151838260016112d19190611ce0565b600c81106112e1576112e1611ade565b60200201528382600281106112f8576112f8611ade565b602002015151518361130b836002611ce0565b600c811061131b5761131b611ade565b602002015283826002811061133257611332611ade565b602002015151600160200201518361134b836003611ce0565b600c811061135b5761135b611ade565b602002015283826002811061137257611372611ade565b6020020151602001515f6002811061138c5761138c611ade565b60200201518361139d836004611ce0565b600c81106113ad576113ad611ade565b60200201528382600281106113c4576113c4611ade565b6020020151602001516001600281106113df576113df611ade565b6020020151836113f0836005611ce0565b600c811061140057611400611ade565b602002015250600101611259565b506114176116ae565b5f6020826101808560086107d05a03fa9050808061143157fe5b5080611450576040516324ccc79360e21b815260040160405180910390fd5b5051151598975050505050505050565b7f00000000000000000000000000000000000000000000000000000000000000006001600160a01b0316638da5cb5b6040518163ffffffff1660e01b8152600401602060405180830381865afa1580156114bc573d5f5f3e3d5ffd5b505050506040513d601f19601f820116820180604052508101906114e09190611cf3565b6001600160a01b0316336001600160a01b031614610e9c57604051637070f3b160e11b815260040160405180910390fd5b5f61151b82610662565b8051519091501580156115315750805160200151155b80156115405750602081015151155b801561155157506020818101510151155b61156e57604051630849e5cf60e41b815260040160405180910390fd5b5050565b604080518082019091525f8082526020820152815115801561159657506020820151155b156115b3575050604080518082019091525f808252602082015290565b6040518060400160405280835f015181526020017f30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd4784602001516115f79190611b65565b611621907f30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47611cb8565b905292915050565b919050565b60405180604001604052806116416116cc565b815260200161164e6116cc565b905290565b60405180606001604052806003906020820280368337509192915050565b60405180608001604052806004906020820280368337509192915050565b604051806101800160405280600c906020820280368337509192915050565b60405180602001604052806001906020820280368337509192915050565b60405180604001604052806002906020820280368337509192915050565b6001600160a01b03811681146116fe575f5ffd5b50565b5f60208284031215611711575f5ffd5b81356107d4816116ea565b803560ff81168114611629575f5ffd5b5f6020828403121561173c575f5ffd5b6107d48261171c565b634e487b7160e01b5f52604160045260245ffd5b6040805190810167ffffffffffffffff8111828210171561177c5761177c611745565b60405290565b604051601f8201601f1916810167ffffffffffffffff811182821017156117ab576117ab611745565b604052919050565b5f5f604083850312156117c4575f5ffd5b82356117cf816116ea565b9150602083013567ffffffffffffffff8111156117ea575f5ffd5b8301601f810185136117fa575f5ffd5b803567ffffffffffffffff81111561181457611814611745565b611827601f8201601f1916602001611782565b81815286602083850101111561183b575f5ffd5b816020840160208301375f602083830101528093505050509250929050565b5f6020828403121561186a575f5ffd5b5035919050565b81518152602080830151908201526040810161065c565b5f5f60408385031215611899575f5ffd5b6118a28361171c565b946020939093013593505050565b805f5b600281101561107f5781518452602093840193909101906001016118b3565b5f6080820190506118e48284516118b0565b60208301516118f660408401826118b0565b5092915050565b5f5f5f6060848603121561190f575f5ffd5b6119188461171c565b9250602084013563ffffffff81168114611930575f5ffd5b929592945050506040919091013590565b5f5f5f838503610160811215611955575f5ffd5b8435611960816116ea565b9350610100601f1982011215611974575f5ffd5b602085019250604061011f198201121561198c575f5ffd5b50610120840190509250925092565b5f5f82840360a08112156119ad575f5ffd5b83356119b8816116ea565b92506080601f19820112156119cb575f5ffd5b506020830190509250929050565b5f5f5f604084860312156119eb575f5ffd5b833567ffffffffffffffff811115611a01575f5ffd5b8401601f81018613611a11575f5ffd5b803567ffffffffffffffff811115611a27575f5ffd5b866020828401011115611a38575f5ffd5b6020918201979096509401359392505050565b602080825282518282018190525f918401906040840190835b81811015611a8857835163ffffffff16835260209384019390920191600101611a64565b509095945050505050565b60018060a01b0384168152826020820152606060408201525f82518060608401528060208501608085015e5f608082850101526080601f19601f830116840101915050949350505050565b634e487b7160e01b5f52603260045260245ffd5b5f6040828403128015611b03575f5ffd5b50611b0c611759565b823581526020928301359281019290925250919050565b888152876020820152866040820152856060820152604085608083013760408460c0830137610100810192909252610120820152610140019695505050505050565b5f82611b7f57634e487b7160e01b5f52601260045260245ffd5b500690565b5f82601f830112611b93575f5ffd5b611b9b611759565b806040840185811115611bac575f5ffd5b845b81811015611a88578035845260209384019301611bae565b5f6080828403128015611bd7575f5ffd5b50611be0611759565b611bea8484611b84565b8152611bf98460408501611b84565b60208201529392505050565b634e487b7160e01b5f52601160045260245ffd5b808202811582820484141761065c5761065c611c05565b815f5b6002811015611c5057813583820155602090910190600101611c33565b5050604082015f5b600281101561107f57813583820160020155602090910190600101611c58565b6040818337604080820160408401375050565b823581526020808401359082015260c081016107d46040830184611c78565b6080810161065c8284611c78565b8181038181111561065c5761065c611c05565b5f81611cd957611cd9611c05565b505f190190565b8082018082111561065c5761065c611c05565b5f60208284031215611d03575f5ffd5b81516107d4816116ea56fea26469706673582212203e7ee896d31eb9233948c100329b4d9a899a0b9610f4830999ca3e77ee79e2fa64736f6c634300081b0033
-    /// ```
-    #[rustfmt::skip]
-    #[allow(clippy::all)]
-    pub static BYTECODE: alloy_sol_types::private::Bytes = alloy_sol_types::private::Bytes::from_static(
-        b"`\xA0`@R4\x80\x15a\0\x0FW__\xFD[P`@Qa\x1E\x9F8\x03\x80a\x1E\x9F\x839\x81\x01`@\x81\x90Ra\0.\x91a\x01\x08V[`\x01`\x01`\xA0\x1B\x03\x81\x16`\x80R\x80a\0Da\0KV[PPa\x015V[_Ta\x01\0\x90\x04`\xFF\x16\x15a\0\xB6W`@QbF\x1B\xCD`\xE5\x1B\x81R` `\x04\x82\x01R`'`$\x82\x01R\x7FInitializable: contract is initi`D\x82\x01Rfalizing`\xC8\x1B`d\x82\x01R`\x84\x01`@Q\x80\x91\x03\x90\xFD[_T`\xFF\x90\x81\x16\x10\x15a\x01\x06W_\x80T`\xFF\x19\x16`\xFF\x90\x81\x17\x90\x91U`@Q\x90\x81R\x7F\x7F&\xB8?\xF9n\x1F+jh/\x138R\xF6y\x8A\t\xC4e\xDA\x95\x92\x14`\xCE\xFB8G@$\x98\x90` \x01`@Q\x80\x91\x03\x90\xA1[V[_` \x82\x84\x03\x12\x15a\x01\x18W__\xFD[\x81Q`\x01`\x01`\xA0\x1B\x03\x81\x16\x81\x14a\x01.W__\xFD[\x93\x92PPPV[`\x80Qa\x1DDa\x01[_9_\x81\x81a\x039\x01R\x81\x81a\x0E^\x01Ra\x14b\x01Ra\x1DD_\xF3\xFE`\x80`@R4\x80\x15a\0\x0FW__\xFD[P`\x046\x10a\x01&W_5`\xE0\x1C\x80cm\x14\xA9\x87\x11a\0\xA9W\x80c\xD1\xA6FP\x11a\0nW\x80c\xD1\xA6FP\x14a\x04\x08W\x80c\xD5%J\x8C\x14a\x04\x1BW\x80c\xDE)\xFA\xC0\x14a\x04;W\x80c\xE8\xBB\x9A\xE6\x14a\x04ZW\x80c\xF4\xE2O\xE5\x14a\x04\x82W__\xFD[\x80cm\x14\xA9\x87\x14a\x034W\x80cy\x16\xCE\xA6\x14a\x03[W\x80c\x7F\xF8\x1A\x87\x14a\x03\x9CW\x80c\xA3\xDB\x80\xE2\x14a\x03\xCFW\x80c\xBFy\xCEX\x14a\x03\xF5W__\xFD[\x80cG\xB3\x14\xE8\x11a\0\xEFW\x80cG\xB3\x14\xE8\x14a\x01\xFFW\x80c_a\xA8\x84\x14a\x02?W\x80c`WG\xD5\x14a\x02\x99W\x80cg\x16\x99\x11\x14a\x02\xE7W\x80ch\xBC\xCA\xAC\x14a\x03\x07W__\xFD[\x80b\xA1\xF4\xCB\x14a\x01*W\x80c\x13T*N\x14a\x01jW\x80c&\xD9A\xF2\x14a\x01\xA0W\x80c7~\xD9\x9D\x14a\x01\xB5W\x80c?\xB2yR\x14a\x01\xECW[__\xFD[a\x01Pa\x0186`\x04a\x17\x01V[`\x03` R_\x90\x81R`@\x90 \x80T`\x01\x90\x91\x01T\x82V[`@\x80Q\x92\x83R` \x83\x01\x91\x90\x91R\x01[`@Q\x80\x91\x03\x90\xF3[a\x01\x92a\x01x6`\x04a\x17\x01V[`\x01`\x01`\xA0\x1B\x03\x16_\x90\x81R`\x01` R`@\x90 T\x90V[`@Q\x90\x81R` \x01a\x01aV[a\x01\xB3a\x01\xAE6`\x04a\x17,V[a\x04\x95V[\0[a\x01\xD7a\x01\xC36`\x04a\x17,V[`\xFF\x16_\x90\x81R`\x04` R`@\x90 T\x90V[`@Qc\xFF\xFF\xFF\xFF\x90\x91\x16\x81R` \x01a\x01aV[a\x01\xB3a\x01\xFA6`\x04a\x17\xB3V[a\x05UV[a\x02'a\x02\r6`\x04a\x18ZV[_\x90\x81R`\x02` R`@\x90 T`\x01`\x01`\xA0\x1B\x03\x16\x90V[`@Q`\x01`\x01`\xA0\x1B\x03\x90\x91\x16\x81R` \x01a\x01aV[a\x02\x8Ca\x02M6`\x04a\x17,V[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01RP`\xFF\x16_\x90\x81R`\x05` \x90\x81R`@\x91\x82\x90 \x82Q\x80\x84\x01\x90\x93R\x80T\x83R`\x01\x01T\x90\x82\x01R\x90V[`@Qa\x01a\x91\x90a\x18qV[a\x02\xACa\x02\xA76`\x04a\x18\x88V[a\x05\xD1V[`@\x80Q\x82Qg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x16\x81R` \x80\x84\x01Qc\xFF\xFF\xFF\xFF\x90\x81\x16\x91\x83\x01\x91\x90\x91R\x92\x82\x01Q\x90\x92\x16\x90\x82\x01R``\x01a\x01aV[a\x02\xFAa\x02\xF56`\x04a\x17\x01V[a\x06bV[`@Qa\x01a\x91\x90a\x18\xD2V[a\x03\x1Aa\x03\x156`\x04a\x18\xFDV[a\x06\xF6V[`@Qg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x90\x91\x16\x81R` \x01a\x01aV[a\x02'\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x81V[a\x03na\x03i6`\x04a\x18\x88V[a\x07\xDBV[`@\x80Qg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x90\x94\x16\x84Rc\xFF\xFF\xFF\xFF\x92\x83\x16` \x85\x01R\x91\x16\x90\x82\x01R``\x01a\x01aV[a\x03\xAFa\x03\xAA6`\x04a\x17\x01V[a\x08\"V[`@\x80Q\x83Q\x81R` \x93\x84\x01Q\x93\x81\x01\x93\x90\x93R\x82\x01R``\x01a\x01aV[a\x01Pa\x03\xDD6`\x04a\x17,V[`\x05` R_\x90\x81R`@\x90 \x80T`\x01\x90\x91\x01T\x82V[a\x01\x92a\x04\x036`\x04a\x19AV[a\x08\x98V[a\x01\xB3a\x04\x166`\x04a\x19\x9BV[a\x0BmV[a\x04.a\x04)6`\x04a\x19\xD9V[a\x0CTV[`@Qa\x01a\x91\x90a\x1AKV[a\x01\x92a\x04I6`\x04a\x17\x01V[`\x01` R_\x90\x81R`@\x90 T\x81V[a\x02'a\x04h6`\x04a\x18ZV[`\x02` R_\x90\x81R`@\x90 T`\x01`\x01`\xA0\x1B\x03\x16\x81V[a\x01\xB3a\x04\x906`\x04a\x17\xB3V[a\r\xECV[a\x04\x9Da\x0ESV[`\xFF\x81\x16_\x90\x81R`\x04` R`@\x90 T\x15a\x04\xCDW`@Qc\x10\xCD\xA5\x17`\xE2\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\xFF\x16_\x90\x81R`\x04` \x90\x81R`@\x80\x83 \x81Q``\x81\x01\x83R\x84\x81Rc\xFF\xFF\xFF\xFFC\x81\x16\x82\x86\x01\x90\x81R\x82\x85\x01\x87\x81R\x84T`\x01\x81\x01\x86U\x94\x88R\x95\x90\x96 \x91Q\x91\x90\x92\x01\x80T\x95Q\x94Q\x83\x16`\x01`\xE0\x1B\x02`\x01`\x01`\xE0\x1B\x03\x95\x90\x93\x16`\x01`\xC0\x1B\x02`\x01`\x01`\xE0\x1B\x03\x19\x90\x96\x16\x91\x90\x93\x1C\x17\x93\x90\x93\x17\x91\x90\x91\x16\x91\x90\x91\x17\x90UV[a\x05]a\x0ESV[_a\x05g\x83a\x08\"V[P\x90Pa\x05t\x82\x82a\x0E\x9EV[\x7Fs\xA2\xB7\xFB\x84G$\xB9q\x80*\xE9\xB1]\xB0\x94\xD4\xB7\x19-\xF9\xD75\x0E\x14\xEBFk\x9B\"\xEBN\x83a\x05\xB4\x85`\x01`\x01`\xA0\x1B\x03\x16_\x90\x81R`\x01` R`@\x90 T\x90V[\x84`@Qa\x05\xC4\x93\x92\x91\x90a\x1A\x93V[`@Q\x80\x91\x03\x90\xA1PPPV[`@\x80Q``\x81\x01\x82R_\x80\x82R` \x80\x83\x01\x82\x90R\x82\x84\x01\x82\x90R`\xFF\x86\x16\x82R`\x04\x90R\x91\x90\x91 \x80T\x83\x90\x81\x10a\x06\rWa\x06\ra\x1A\xDEV[_\x91\x82R` \x91\x82\x90 `@\x80Q``\x81\x01\x82R\x91\x90\x92\x01Tg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x81\x84\x1B\x16\x82Rc\xFF\xFF\xFF\xFF`\x01`\xC0\x1B\x82\x04\x81\x16\x94\x83\x01\x94\x90\x94R`\x01`\xE0\x1B\x90\x04\x90\x92\x16\x90\x82\x01R\x90P[\x92\x91PPV[a\x06ja\x16.V[`\x01`\x01`\xA0\x1B\x03\x82\x16_\x90\x81R`\x06` R`@\x90\x81\x90 \x81Q`\x80\x81\x01\x83R\x91\x82\x90\x81\x01\x82`\x02\x82\x82\x82` \x02\x82\x01\x91[\x81T\x81R` \x01\x90`\x01\x01\x90\x80\x83\x11a\x06\x9DWPPP\x91\x83RPP`@\x80Q\x80\x82\x01\x91\x82\x90R` \x90\x92\x01\x91\x90`\x02\x84\x81\x01\x91\x82\x84[\x81T\x81R` \x01\x90`\x01\x01\x90\x80\x83\x11a\x06\xD3WPPPPP\x81RPP\x90P\x91\x90PV[`\xFF\x83\x16_\x90\x81R`\x04` R`@\x81 \x80T\x82\x91\x90\x84\x90\x81\x10a\x07\x1CWa\x07\x1Ca\x1A\xDEV[_\x91\x82R` \x91\x82\x90 `@\x80Q``\x81\x01\x82R\x91\x90\x92\x01Tg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x81\x84\x1B\x16\x82Rc\xFF\xFF\xFF\xFF`\x01`\xC0\x1B\x82\x04\x81\x16\x94\x83\x01\x85\x90R`\x01`\xE0\x1B\x90\x91\x04\x81\x16\x92\x82\x01\x92\x90\x92R\x92P\x85\x16\x10\x15a\x07\x8DW`@Qc=\"\x88A`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`@\x81\x01Qc\xFF\xFF\xFF\xFF\x16\x15\x80a\x07\xB3WP\x80`@\x01Qc\xFF\xFF\xFF\xFF\x16\x84c\xFF\xFF\xFF\xFF\x16\x10[a\x07\xD0W`@Qco\xE0-K`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[Q\x90P[\x93\x92PPPV[`\x04` R\x81_R`@_ \x81\x81T\x81\x10a\x07\xF4W_\x80\xFD[_\x91\x82R` \x90\x91 \x01T`@\x81\x90\x1B\x92Pc\xFF\xFF\xFF\xFF`\x01`\xC0\x1B\x82\x04\x81\x16\x92P`\x01`\xE0\x1B\x90\x91\x04\x16\x83V[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01R`\x01`\x01`\xA0\x1B\x03\x82\x16_\x81\x81R`\x03` \x90\x81R`@\x80\x83 \x81Q\x80\x83\x01\x83R\x81T\x81R`\x01\x91\x82\x01T\x81\x85\x01R\x94\x84R\x90\x91R\x81 T\x90\x91\x90\x80a\x08\x8EW`@Qc%\xECl\x1F`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[\x90\x94\x90\x93P\x91PPV[_a\x08\xA1a\x0ESV[_a\x08\xCDa\x08\xB76\x86\x90\x03\x86\x01`@\x87\x01a\x1A\xF2V[\x80Q_\x90\x81R` \x91\x82\x01Q\x90\x91R`@\x90 \x90V[\x90P\x7F\xAD2(\xB6v\xF7\xD3\xCDB\x84\xA5D?\x17\xF1\x96+6\xE4\x91\xB3\n@\xB2@XI\xE5\x97\xBA_\xB5\x81\x03a\t\x0FW`@Qc\x0C\xC7P\x91`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\x01`\x01`\xA0\x1B\x03\x85\x16_\x90\x81R`\x01` R`@\x81 T\x14a\tEW`@QcB\xEEh\xB5`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[_\x81\x81R`\x02` R`@\x90 T`\x01`\x01`\xA0\x1B\x03\x16\x15a\tzW`@QcL3L\x97`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`@\x80Q_\x91\x7F0dNr\xE11\xA0)\xB8PE\xB6\x81\x81X](3\xE8Hy\xB9p\x91C\xE1\xF5\x93\xF0\0\0\x01\x91a\t\xD2\x91\x885\x91` \x80\x8B\x015\x92\x8B\x015\x91``\x8C\x015\x91`\x80\x8D\x01\x91`\xC0\x8E\x01\x91\x8D5\x91\x8E\x82\x015\x91\x01a\x1B#V[`@Q` \x81\x83\x03\x03\x81R\x90`@R\x80Q\x90` \x01 _\x1Ca\t\xF4\x91\x90a\x1BeV[\x90Pa\n\x8Da\n-a\n\x18\x83a\n\x126\x8A\x90\x03\x8A\x01`@\x8B\x01a\x1A\xF2V[\x90a\x10\x85V[a\n'6\x89\x90\x03\x89\x01\x89a\x1A\xF2V[\x90a\x10\xF5V[a\n5a\x11iV[a\nva\ng\x85a\n\x12`@\x80Q\x80\x82\x01\x82R_\x80\x82R` \x91\x82\x01R\x81Q\x80\x83\x01\x90\x92R`\x01\x82R`\x02\x90\x82\x01R\x90V[a\n'6\x8A\x90\x03\x8A\x01\x8Aa\x1A\xF2V[a\n\x886\x8A\x90\x03\x8A\x01`\x80\x8B\x01a\x1B\xC6V[a\x12)V[a\n\xAAW`@Qc\xA7-\x02c`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\x01`\x01`\xA0\x1B\x03\x86\x16_\x90\x81R`\x03` \x90\x81R`@\x80\x83 \x88\x82\x015\x81U``\x89\x015`\x01\x90\x91\x01U`\x06\x90\x91R\x90 `\x80\x86\x01\x90a\n\xEB\x82\x82a\x1C0V[PP`\x01`\x01`\xA0\x1B\x03\x86\x16_\x81\x81R`\x01` \x90\x81R`@\x80\x83 \x86\x90U\x85\x83R`\x02\x90\x91R\x90\x81\x90 \x80T`\x01`\x01`\xA0\x1B\x03\x19\x16\x83\x17\x90U\x80Q\x7F\xE3\xFBf\x13\xAF.\x890\xCF\x85\xD4\x7F\xCFm\xB1\x01\x92\"Jd\xC6\xCB\xE8\x02>\x0E\xEE\x1B\xA3\x82\x80A\x91a\x0B\\\x91\x90\x89\x01\x90`\x80\x8A\x01\x90a\x1C\x8BV[`@Q\x80\x91\x03\x90\xA2P\x94\x93PPPPV[a\x0Bua\x14`V[_a\x0B\x7F\x83a\x08\"V[P\x90Pa\x0B\x8B\x83a\x15\x11V[a\x0B\xCB\x81a\x0B\x97a\x11iV[`@\x80Q\x80\x82\x01\x82R_\x80\x82R` \x91\x82\x01R\x81Q\x80\x83\x01\x90\x92R`\x01\x82R`\x02\x90\x82\x01Ra\n\x886\x87\x90\x03\x87\x01\x87a\x1B\xC6V[a\x0B\xE8W`@Qc\xA7-\x02c`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\x01`\x01`\xA0\x1B\x03\x83\x16_\x90\x81R`\x06` R`@\x90 \x82\x90a\x0C\x0B\x82\x82a\x1C0V[\x90PP\x82`\x01`\x01`\xA0\x1B\x03\x16\x7F\\O\x9F(\x15=\xBF?\0\xE6\x96\x07\xA5\x9E\x82\xAD\x80o\xFF\xB7\x8D\t\xF1y\xF6$2\xF7\xE9\xD2Q\x1A\x83`@Qa\x0CG\x91\x90a\x1C\xAAV[`@Q\x80\x91\x03\x90\xA2PPPV[``_\x83g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x0CpWa\x0Cpa\x17EV[`@Q\x90\x80\x82R\x80` \x02` \x01\x82\x01`@R\x80\x15a\x0C\x99W\x81` \x01` \x82\x02\x806\x837\x01\x90P[P\x90P_[\x84\x81\x10\x15a\r\xE3W_\x86\x86\x83\x81\x81\x10a\x0C\xB9Wa\x0C\xB9a\x1A\xDEV[\x91\x90\x91\x015`\xF8\x1C_\x81\x81R`\x04` R`@\x90 T\x90\x92P\x90P\x80\x15\x80a\r\x19WP`\xFF\x82\x16_\x90\x81R`\x04` R`@\x81 \x80T\x90\x91\x90a\x0C\xFEWa\x0C\xFEa\x1A\xDEV[_\x91\x82R` \x90\x91 \x01T`\x01`\xC0\x1B\x90\x04c\xFF\xFF\xFF\xFF\x16\x86\x10[\x15a\r7W`@Qc?L\xB7\x0F`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[\x80[\x80\x15a\r\xD8W`\xFF\x83\x16_\x90\x81R`\x04` R`@\x90 \x87\x90a\r]`\x01\x84a\x1C\xB8V[\x81T\x81\x10a\rmWa\rma\x1A\xDEV[_\x91\x82R` \x90\x91 \x01T`\x01`\xC0\x1B\x90\x04c\xFF\xFF\xFF\xFF\x16\x11a\r\xC6Wa\r\x95`\x01\x82a\x1C\xB8V[\x85\x85\x81Q\x81\x10a\r\xA7Wa\r\xA7a\x1A\xDEV[` \x02` \x01\x01\x90c\xFF\xFF\xFF\xFF\x16\x90\x81c\xFF\xFF\xFF\xFF\x16\x81RPPa\r\xD8V[\x80a\r\xD0\x81a\x1C\xCBV[\x91PPa\r9V[PPP`\x01\x01a\x0C\x9EV[P\x94\x93PPPPV[a\r\xF4a\x0ESV[_a\r\xFE\x83a\x08\"V[P\x90Pa\x0E\x13\x82a\x0E\x0E\x83a\x15rV[a\x0E\x9EV[\x7F\xF8C\xEC\xD5:V6u\xE6!\x07\xBE\x14\x94\xFD\xDEJ=I\xAE\xED\xAF\x8D\x88\xC6\x16\xD8SF\xE3P\x0E\x83a\x05\xB4\x85`\x01`\x01`\xA0\x1B\x03\x16_\x90\x81R`\x01` R`@\x90 T\x90V[3`\x01`\x01`\xA0\x1B\x03\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x16\x14a\x0E\x9CW`@Qcpp\xF3\xB1`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[V[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01R_[\x83Q\x81\x10\x15a\x10\x7FW_\x84\x82\x81Q\x81\x10a\x0E\xCFWa\x0E\xCFa\x1A\xDEV[\x01` \x90\x81\x01Q`\xF8\x1C_\x81\x81R`\x04\x90\x92R`@\x82 T\x90\x92P\x90\x81\x90\x03a\x0F\x0BW`@Qcs\x10\xCF\xF5`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\xFF\x82\x16_\x90\x81R`\x05` \x90\x81R`@\x91\x82\x90 \x82Q\x80\x84\x01\x90\x93R\x80T\x83R`\x01\x01T\x90\x82\x01Ra\x0F>\x90\x86a\x10\xF5V[`\xFF\x83\x16_\x81\x81R`\x05` \x90\x81R`@\x80\x83 \x85Q\x80\x82U\x86\x84\x01\x80Q`\x01\x93\x84\x01U\x90\x85RQ\x83R\x81\x84 \x94\x84R`\x04\x90\x92R\x82 \x93\x97P\x91\x92\x90\x91a\x0F\x86\x90\x85a\x1C\xB8V[\x81T\x81\x10a\x0F\x96Wa\x0F\x96a\x1A\xDEV[_\x91\x82R` \x90\x91 \x01\x80T\x90\x91Pc\xFF\xFF\xFF\xFFC\x81\x16`\x01`\xC0\x1B\x90\x92\x04\x16\x03a\x0F\xD4W\x80T`\x01`\x01`\xC0\x1B\x03\x19\x16`@\x83\x90\x1C\x17\x81Ua\x10oV[\x80Tc\xFF\xFF\xFF\xFFC\x81\x16`\x01`\xE0\x1B\x81\x81\x02`\x01`\x01`\xE0\x1B\x03\x94\x85\x16\x17\x85U`\xFF\x88\x16_\x90\x81R`\x04` \x90\x81R`@\x80\x83 \x81Q``\x81\x01\x83Rg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x8B\x16\x81R\x80\x84\x01\x96\x87R\x80\x83\x01\x85\x81R\x82T`\x01\x81\x01\x84U\x92\x86R\x93\x90\x94 \x93Q\x93\x01\x80T\x95Q\x92Q\x87\x16\x90\x94\x02\x91\x90\x95\x16`\x01`\xC0\x1B\x02`\x01`\x01`\xE0\x1B\x03\x19\x94\x90\x94\x16\x91\x90\x94\x1C\x17\x91\x90\x91\x17\x90\x92\x16\x17\x90U[PP`\x01\x90\x92\x01\x91Pa\x0E\xB3\x90PV[PPPPV[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01Ra\x10\xA0a\x16SV[\x83Q\x81R` \x80\x85\x01Q\x90\x82\x01R`@\x80\x82\x01\x84\x90R_\x90\x83``\x84`\x07a\x07\xD0Z\x03\xFA\x90P\x80\x80a\x10\xCEW\xFE[P\x80a\x10\xEDW`@Qc#\x19\xDF\x19`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[PP\x92\x91PPV[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01Ra\x11\x10a\x16qV[\x83Q\x81R` \x80\x85\x01Q\x81\x83\x01R\x83Q`@\x80\x84\x01\x91\x90\x91R\x90\x84\x01Q``\x83\x01R_\x90\x83`\x80\x84`\x06a\x07\xD0Z\x03\xFA\x90P\x80\x80a\x11JW\xFE[P\x80a\x10\xEDW`@Qc\xD4\xB6\x8F\xD7`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[a\x11qa\x16.V[P`@\x80Q`\x80\x81\x01\x82R\x7F\x19\x8E\x93\x93\x92\rH:r`\xBF\xB71\xFB]%\xF1\xAAI35\xA9\xE7\x12\x97\xE4\x85\xB7\xAE\xF3\x12\xC2\x81\x83\x01\x90\x81R\x7F\x18\0\xDE\xEF\x12\x1F\x1EvBj\0f^\\DygC\"\xD4\xF7^\xDA\xDDF\xDE\xBD\\\xD9\x92\xF6\xED``\x83\x01R\x81R\x81Q\x80\x83\x01\x90\x92R\x7F']\xC4\xA2\x88\xD1\xAF\xB3\xCB\xB1\xAC\t\x18u$\xC7\xDB69]\xF7\xBE;\x99\xE6s\xB1:\x07Ze\xEC\x82R\x7F\x1D\x9B\xEF\xCD\x05\xA52>m\xA4\xD45\xF3\xB6\x17\xCD\xB3\xAF\x83(\\-\xF7\x11\xEF9\xC0\x15q\x82\x7F\x9D` \x83\x81\x01\x91\x90\x91R\x81\x01\x91\x90\x91R\x90V[`@\x80Q\x80\x82\x01\x82R\x85\x81R` \x80\x82\x01\x85\x90R\x82Q\x80\x84\x01\x90\x93R\x85\x83R\x82\x01\x83\x90R_\x91a\x12Wa\x16\x8FV[_[`\x02\x81\x10\x15a\x14\x0EW_a\x12n\x82`\x06a\x1C\x19V[\x90P\x84\x82`\x02\x81\x10a\x12\x82Wa\x12\x82a\x1A\xDEV[` \x02\x01QQ\x83a\x12\x93\x83_a\x1C\xE0V[`\x0C\x81\x10a\x12\xA3Wa\x12\xA3a\x1A\xDEV[` \x02\x01R\x84\x82`\x02\x81\x10a\x12\xBAWa\x12\xBAa\x1A\xDEV[` \x02\x01Q` \x01Q\x83\x82`\x01a\x12\xD1\x91\x90a\x1C\xE0V[`\x0C\x81\x10a\x12\xE1Wa\x12\xE1a\x1A\xDEV[` \x02\x01R\x83\x82`\x02\x81\x10a\x12\xF8Wa\x12\xF8a\x1A\xDEV[` \x02\x01QQQ\x83a\x13\x0B\x83`\x02a\x1C\xE0V[`\x0C\x81\x10a\x13\x1BWa\x13\x1Ba\x1A\xDEV[` \x02\x01R\x83\x82`\x02\x81\x10a\x132Wa\x132a\x1A\xDEV[` \x02\x01QQ`\x01` \x02\x01Q\x83a\x13K\x83`\x03a\x1C\xE0V[`\x0C\x81\x10a\x13[Wa\x13[a\x1A\xDEV[` \x02\x01R\x83\x82`\x02\x81\x10a\x13rWa\x13ra\x1A\xDEV[` \x02\x01Q` \x01Q_`\x02\x81\x10a\x13\x8CWa\x13\x8Ca\x1A\xDEV[` \x02\x01Q\x83a\x13\x9D\x83`\x04a\x1C\xE0V[`\x0C\x81\x10a\x13\xADWa\x13\xADa\x1A\xDEV[` \x02\x01R\x83\x82`\x02\x81\x10a\x13\xC4Wa\x13\xC4a\x1A\xDEV[` \x02\x01Q` \x01Q`\x01`\x02\x81\x10a\x13\xDFWa\x13\xDFa\x1A\xDEV[` \x02\x01Q\x83a\x13\xF0\x83`\x05a\x1C\xE0V[`\x0C\x81\x10a\x14\0Wa\x14\0a\x1A\xDEV[` \x02\x01RP`\x01\x01a\x12YV[Pa\x14\x17a\x16\xAEV[_` \x82a\x01\x80\x85`\x08a\x07\xD0Z\x03\xFA\x90P\x80\x80a\x141W\xFE[P\x80a\x14PW`@Qc$\xCC\xC7\x93`\xE2\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[PQ\x15\x15\x98\x97PPPPPPPPV[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\x01`\x01`\xA0\x1B\x03\x16c\x8D\xA5\xCB[`@Q\x81c\xFF\xFF\xFF\xFF\x16`\xE0\x1B\x81R`\x04\x01` `@Q\x80\x83\x03\x81\x86Z\xFA\x15\x80\x15a\x14\xBCW=__>=_\xFD[PPPP`@Q=`\x1F\x19`\x1F\x82\x01\x16\x82\x01\x80`@RP\x81\x01\x90a\x14\xE0\x91\x90a\x1C\xF3V[`\x01`\x01`\xA0\x1B\x03\x163`\x01`\x01`\xA0\x1B\x03\x16\x14a\x0E\x9CW`@Qcpp\xF3\xB1`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[_a\x15\x1B\x82a\x06bV[\x80QQ\x90\x91P\x15\x80\x15a\x151WP\x80Q` \x01Q\x15[\x80\x15a\x15@WP` \x81\x01QQ\x15[\x80\x15a\x15QWP` \x81\x81\x01Q\x01Q\x15[a\x15nW`@Qc\x08I\xE5\xCF`\xE4\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[PPV[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01R\x81Q\x15\x80\x15a\x15\x96WP` \x82\x01Q\x15[\x15a\x15\xB3WPP`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01R\x90V[`@Q\x80`@\x01`@R\x80\x83_\x01Q\x81R` \x01\x7F0dNr\xE11\xA0)\xB8PE\xB6\x81\x81X]\x97\x81j\x91hq\xCA\x8D< \x8C\x16\xD8|\xFDG\x84` \x01Qa\x15\xF7\x91\x90a\x1BeV[a\x16!\x90\x7F0dNr\xE11\xA0)\xB8PE\xB6\x81\x81X]\x97\x81j\x91hq\xCA\x8D< \x8C\x16\xD8|\xFDGa\x1C\xB8V[\x90R\x92\x91PPV[\x91\x90PV[`@Q\x80`@\x01`@R\x80a\x16Aa\x16\xCCV[\x81R` \x01a\x16Na\x16\xCCV[\x90R\x90V[`@Q\x80``\x01`@R\x80`\x03\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`@Q\x80`\x80\x01`@R\x80`\x04\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`@Q\x80a\x01\x80\x01`@R\x80`\x0C\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`@Q\x80` \x01`@R\x80`\x01\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`@Q\x80`@\x01`@R\x80`\x02\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`\x01`\x01`\xA0\x1B\x03\x81\x16\x81\x14a\x16\xFEW__\xFD[PV[_` \x82\x84\x03\x12\x15a\x17\x11W__\xFD[\x815a\x07\xD4\x81a\x16\xEAV[\x805`\xFF\x81\x16\x81\x14a\x16)W__\xFD[_` \x82\x84\x03\x12\x15a\x17<W__\xFD[a\x07\xD4\x82a\x17\x1CV[cNH{q`\xE0\x1B_R`A`\x04R`$_\xFD[`@\x80Q\x90\x81\x01g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x82\x82\x10\x17\x15a\x17|Wa\x17|a\x17EV[`@R\x90V[`@Q`\x1F\x82\x01`\x1F\x19\x16\x81\x01g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x82\x82\x10\x17\x15a\x17\xABWa\x17\xABa\x17EV[`@R\x91\x90PV[__`@\x83\x85\x03\x12\x15a\x17\xC4W__\xFD[\x825a\x17\xCF\x81a\x16\xEAV[\x91P` \x83\x015g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x17\xEAW__\xFD[\x83\x01`\x1F\x81\x01\x85\x13a\x17\xFAW__\xFD[\x805g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x18\x14Wa\x18\x14a\x17EV[a\x18'`\x1F\x82\x01`\x1F\x19\x16` \x01a\x17\x82V[\x81\x81R\x86` \x83\x85\x01\x01\x11\x15a\x18;W__\xFD[\x81` \x84\x01` \x83\x017_` \x83\x83\x01\x01R\x80\x93PPPP\x92P\x92\x90PV[_` \x82\x84\x03\x12\x15a\x18jW__\xFD[P5\x91\x90PV[\x81Q\x81R` \x80\x83\x01Q\x90\x82\x01R`@\x81\x01a\x06\\V[__`@\x83\x85\x03\x12\x15a\x18\x99W__\xFD[a\x18\xA2\x83a\x17\x1CV[\x94` \x93\x90\x93\x015\x93PPPV[\x80_[`\x02\x81\x10\x15a\x10\x7FW\x81Q\x84R` \x93\x84\x01\x93\x90\x91\x01\x90`\x01\x01a\x18\xB3V[_`\x80\x82\x01\x90Pa\x18\xE4\x82\x84Qa\x18\xB0V[` \x83\x01Qa\x18\xF6`@\x84\x01\x82a\x18\xB0V[P\x92\x91PPV[___``\x84\x86\x03\x12\x15a\x19\x0FW__\xFD[a\x19\x18\x84a\x17\x1CV[\x92P` \x84\x015c\xFF\xFF\xFF\xFF\x81\x16\x81\x14a\x190W__\xFD[\x92\x95\x92\x94PPP`@\x91\x90\x91\x015\x90V[___\x83\x85\x03a\x01`\x81\x12\x15a\x19UW__\xFD[\x845a\x19`\x81a\x16\xEAV[\x93Pa\x01\0`\x1F\x19\x82\x01\x12\x15a\x19tW__\xFD[` \x85\x01\x92P`@a\x01\x1F\x19\x82\x01\x12\x15a\x19\x8CW__\xFD[Pa\x01 \x84\x01\x90P\x92P\x92P\x92V[__\x82\x84\x03`\xA0\x81\x12\x15a\x19\xADW__\xFD[\x835a\x19\xB8\x81a\x16\xEAV[\x92P`\x80`\x1F\x19\x82\x01\x12\x15a\x19\xCBW__\xFD[P` \x83\x01\x90P\x92P\x92\x90PV[___`@\x84\x86\x03\x12\x15a\x19\xEBW__\xFD[\x835g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x1A\x01W__\xFD[\x84\x01`\x1F\x81\x01\x86\x13a\x1A\x11W__\xFD[\x805g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x1A'W__\xFD[\x86` \x82\x84\x01\x01\x11\x15a\x1A8W__\xFD[` \x91\x82\x01\x97\x90\x96P\x94\x015\x93\x92PPPV[` \x80\x82R\x82Q\x82\x82\x01\x81\x90R_\x91\x84\x01\x90`@\x84\x01\x90\x83[\x81\x81\x10\x15a\x1A\x88W\x83Qc\xFF\xFF\xFF\xFF\x16\x83R` \x93\x84\x01\x93\x90\x92\x01\x91`\x01\x01a\x1AdV[P\x90\x95\x94PPPPPV[`\x01\x80`\xA0\x1B\x03\x84\x16\x81R\x82` \x82\x01R```@\x82\x01R_\x82Q\x80``\x84\x01R\x80` \x85\x01`\x80\x85\x01^_`\x80\x82\x85\x01\x01R`\x80`\x1F\x19`\x1F\x83\x01\x16\x84\x01\x01\x91PP\x94\x93PPPPV[cNH{q`\xE0\x1B_R`2`\x04R`$_\xFD[_`@\x82\x84\x03\x12\x80\x15a\x1B\x03W__\xFD[Pa\x1B\x0Ca\x17YV[\x825\x81R` \x92\x83\x015\x92\x81\x01\x92\x90\x92RP\x91\x90PV[\x88\x81R\x87` \x82\x01R\x86`@\x82\x01R\x85``\x82\x01R`@\x85`\x80\x83\x017`@\x84`\xC0\x83\x017a\x01\0\x81\x01\x92\x90\x92Ra\x01 \x82\x01Ra\x01@\x01\x96\x95PPPPPPV[_\x82a\x1B\x7FWcNH{q`\xE0\x1B_R`\x12`\x04R`$_\xFD[P\x06\x90V[_\x82`\x1F\x83\x01\x12a\x1B\x93W__\xFD[a\x1B\x9Ba\x17YV[\x80`@\x84\x01\x85\x81\x11\x15a\x1B\xACW__\xFD[\x84[\x81\x81\x10\x15a\x1A\x88W\x805\x84R` \x93\x84\x01\x93\x01a\x1B\xAEV[_`\x80\x82\x84\x03\x12\x80\x15a\x1B\xD7W__\xFD[Pa\x1B\xE0a\x17YV[a\x1B\xEA\x84\x84a\x1B\x84V[\x81Ra\x1B\xF9\x84`@\x85\x01a\x1B\x84V[` \x82\x01R\x93\x92PPPV[cNH{q`\xE0\x1B_R`\x11`\x04R`$_\xFD[\x80\x82\x02\x81\x15\x82\x82\x04\x84\x14\x17a\x06\\Wa\x06\\a\x1C\x05V[\x81_[`\x02\x81\x10\x15a\x1CPW\x815\x83\x82\x01U` \x90\x91\x01\x90`\x01\x01a\x1C3V[PP`@\x82\x01_[`\x02\x81\x10\x15a\x10\x7FW\x815\x83\x82\x01`\x02\x01U` \x90\x91\x01\x90`\x01\x01a\x1CXV[`@\x81\x837`@\x80\x82\x01`@\x84\x017PPV[\x825\x81R` \x80\x84\x015\x90\x82\x01R`\xC0\x81\x01a\x07\xD4`@\x83\x01\x84a\x1CxV[`\x80\x81\x01a\x06\\\x82\x84a\x1CxV[\x81\x81\x03\x81\x81\x11\x15a\x06\\Wa\x06\\a\x1C\x05V[_\x81a\x1C\xD9Wa\x1C\xD9a\x1C\x05V[P_\x19\x01\x90V[\x80\x82\x01\x80\x82\x11\x15a\x06\\Wa\x06\\a\x1C\x05V[_` \x82\x84\x03\x12\x15a\x1D\x03W__\xFD[\x81Qa\x07\xD4\x81a\x16\xEAV\xFE\xA2dipfsX\"\x12 >~\xE8\x96\xD3\x1E\xB9#9H\xC1\x002\x9BM\x9A\x89\x9A\x0B\x96\x10\xF4\x83\t\x99\xCA>w\xEEy\xE2\xFAdsolcC\0\x08\x1B\x003",
-    );
-    /// The runtime bytecode of the contract, as deployed on the network.
-    ///
-    /// ```text
-    ///0x608060405234801561000f575f5ffd5b5060043610610126575f3560e01c80636d14a987116100a9578063d1a646501161006e578063d1a6465014610408578063d5254a8c1461041b578063de29fac01461043b578063e8bb9ae61461045a578063f4e24fe514610482575f5ffd5b80636d14a987146103345780637916cea61461035b5780637ff81a871461039c578063a3db80e2146103cf578063bf79ce58146103f5575f5ffd5b806347b314e8116100ef57806347b314e8146101ff5780635f61a8841461023f578063605747d51461029957806367169911146102e757806368bccaac14610307575f5ffd5b8062a1f4cb1461012a57806313542a4e1461016a57806326d941f2146101a0578063377ed99d146101b55780633fb27952146101ec575b5f5ffd5b610150610138366004611701565b60036020525f90815260409020805460019091015482565b604080519283526020830191909152015b60405180910390f35b610192610178366004611701565b6001600160a01b03165f9081526001602052604090205490565b604051908152602001610161565b6101b36101ae36600461172c565b610495565b005b6101d76101c336600461172c565b60ff165f9081526004602052604090205490565b60405163ffffffff9091168152602001610161565b6101b36101fa3660046117b3565b610555565b61022761020d36600461185a565b5f908152600260205260409020546001600160a01b031690565b6040516001600160a01b039091168152602001610161565b61028c61024d36600461172c565b604080518082019091525f80825260208201525060ff165f90815260056020908152604091829020825180840190935280548352600101549082015290565b6040516101619190611871565b6102ac6102a7366004611888565b6105d1565b60408051825167ffffffffffffffff1916815260208084015163ffffffff908116918301919091529282015190921690820152606001610161565b6102fa6102f5366004611701565b610662565b60405161016191906118d2565b61031a6103153660046118fd565b6106f6565b60405167ffffffffffffffff199091168152602001610161565b6102277f000000000000000000000000000000000000000000000000000000000000000081565b61036e610369366004611888565b6107db565b6040805167ffffffffffffffff19909416845263ffffffff9283166020850152911690820152606001610161565b6103af6103aa366004611701565b610822565b604080518351815260209384015193810193909352820152606001610161565b6101506103dd36600461172c565b60056020525f90815260409020805460019091015482565b610192610403366004611941565b610898565b6101b361041636600461199b565b610b6d565b61042e6104293660046119d9565b610c54565b6040516101619190611a4b565b610192610449366004611701565b60016020525f908152604090205481565b61022761046836600461185a565b60026020525f90815260409020546001600160a01b031681565b6101b36104903660046117b3565b610dec565b61049d610e53565b60ff81165f90815260046020526040902054156104cd576040516310cda51760e21b815260040160405180910390fd5b60ff165f908152600460209081526040808320815160608101835284815263ffffffff4381168286019081528285018781528454600181018655948852959096209151919092018054955194518316600160e01b026001600160e01b0395909316600160c01b026001600160e01b03199096169190931c179390931791909116919091179055565b61055d610e53565b5f61056783610822565b5090506105748282610e9e565b7f73a2b7fb844724b971802ae9b15db094d4b7192df9d7350e14eb466b9b22eb4e836105b4856001600160a01b03165f9081526001602052604090205490565b846040516105c493929190611a93565b60405180910390a1505050565b604080516060810182525f808252602080830182905282840182905260ff86168252600490529190912080548390811061060d5761060d611ade565b5f91825260209182902060408051606081018252919092015467ffffffffffffffff1981841b16825263ffffffff600160c01b8204811694830194909452600160e01b90049092169082015290505b92915050565b61066a61162e565b6001600160a01b0382165f9081526006602052604090819020815160808101835291829081018260028282826020028201915b81548152602001906001019080831161069d57505050918352505060408051808201918290526020909201919060028481019182845b8154815260200190600101908083116106d3575050505050815250509050919050565b60ff83165f90815260046020526040812080548291908490811061071c5761071c611ade565b5f91825260209182902060408051606081018252919092015467ffffffffffffffff1981841b16825263ffffffff600160c01b82048116948301859052600160e01b90910481169282019290925292508516101561078d57604051633d22884160e01b815260040160405180910390fd5b604081015163ffffffff1615806107b35750806040015163ffffffff168463ffffffff16105b6107d057604051636fe02d4b60e01b815260040160405180910390fd5b5190505b9392505050565b6004602052815f5260405f2081815481106107f4575f80fd5b5f91825260209091200154604081901b925063ffffffff600160c01b820481169250600160e01b9091041683565b604080518082019091525f80825260208201526001600160a01b0382165f8181526003602090815260408083208151808301835281548152600191820154818501529484529091528120549091908061088e576040516325ec6c1f60e01b815260040160405180910390fd5b9094909350915050565b5f6108a1610e53565b5f6108cd6108b736869003860160408701611af2565b80515f9081526020918201519091526040902090565b90507fad3228b676f7d3cd4284a5443f17f1962b36e491b30a40b2405849e597ba5fb5810361090f57604051630cc7509160e01b815260040160405180910390fd5b6001600160a01b0385165f9081526001602052604081205414610945576040516342ee68b560e01b815260040160405180910390fd5b5f818152600260205260409020546001600160a01b03161561097a57604051634c334c9760e11b815260040160405180910390fd5b604080515f917f30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001916109d2918835916020808b0135928b01359160608c01359160808d019160c08e01918d35918e8201359101611b23565b604051602081830303815290604052805190602001205f1c6109f49190611b65565b9050610a8d610a2d610a1883610a12368a90038a0160408b01611af2565b90611085565b610a2736899003890189611af2565b906110f5565b610a35611169565b610a76610a6785610a126040805180820182525f80825260209182015281518083019092526001825260029082015290565b610a27368a90038a018a611af2565b610a88368a90038a0160808b01611bc6565b611229565b610aaa5760405163a72d026360e01b815260040160405180910390fd5b6001600160a01b0386165f9081526003602090815260408083208882013581556060890135600190910155600690915290206080860190610aeb8282611c30565b50506001600160a01b0386165f81815260016020908152604080832086905585835260029091529081902080546001600160a01b0319168317905580517fe3fb6613af2e8930cf85d47fcf6db10192224a64c6cbe8023e0eee1ba382804191610b5c919089019060808a0190611c8b565b60405180910390a250949350505050565b610b75611460565b5f610b7f83610822565b509050610b8b83611511565b610bcb81610b97611169565b6040805180820182525f808252602091820152815180830190925260018252600290820152610a8836879003870187611bc6565b610be85760405163a72d026360e01b815260040160405180910390fd5b6001600160a01b0383165f9081526006602052604090208290610c0b8282611c30565b905050826001600160a01b03167f5c4f9f28153dbf3f00e69607a59e82ad806fffb78d09f179f62432f7e9d2511a83604051610c479190611caa565b60405180910390a2505050565b60605f8367ffffffffffffffff811115610c7057610c70611745565b604051908082528060200260200182016040528015610c99578160200160208202803683370190505b5090505f5b84811015610de3575f868683818110610cb957610cb9611ade565b919091013560f81c5f818152600460205260409020549092509050801580610d19575060ff82165f9081526004602052604081208054909190610cfe57610cfe611ade565b5f91825260209091200154600160c01b900463ffffffff1686105b15610d3757604051633f4cb70f60e01b815260040160405180910390fd5b805b8015610dd85760ff83165f9081526004602052604090208790610d5d600184611cb8565b81548110610d6d57610d6d611ade565b5f91825260209091200154600160c01b900463ffffffff1611610dc657610d95600182611cb8565b858581518110610da757610da7611ade565b602002602001019063ffffffff16908163ffffffff1681525050610dd8565b80610dd081611ccb565b915050610d39565b505050600101610c9e565b50949350505050565b610df4610e53565b5f610dfe83610822565b509050610e1382610e0e83611572565b610e9e565b7ff843ecd53a563675e62107be1494fdde4a3d49aeedaf8d88c616d85346e3500e836105b4856001600160a01b03165f9081526001602052604090205490565b336001600160a01b037f00000000000000000000000000000000000000000000000000000000000000001614610e9c57604051637070f3b160e11b815260040160405180910390fd5b565b604080518082019091525f80825260208201525f5b835181101561107f575f848281518110610ecf57610ecf611ade565b0160209081015160f81c5f8181526004909252604082205490925090819003610f0b57604051637310cff560e11b815260040160405180910390fd5b60ff82165f908152600560209081526040918290208251808401909352805483526001015490820152610f3e90866110f5565b60ff83165f818152600560209081526040808320855180825586840180516001938401559085525183528184209484526004909252822093975091929091610f869085611cb8565b81548110610f9657610f96611ade565b5f918252602090912001805490915063ffffffff438116600160c01b9092041603610fd45780546001600160c01b031916604083901c17815561106f565b805463ffffffff438116600160e01b8181026001600160e01b0394851617855560ff88165f908152600460209081526040808320815160608101835267ffffffffffffffff198b16815280840196875280830185815282546001810184559286529390942093519301805495519251871690940291909516600160c01b026001600160e01b0319949094169190941c17919091179092161790555b505060019092019150610eb39050565b50505050565b604080518082019091525f80825260208201526110a0611653565b835181526020808501519082015260408082018490525f908360608460076107d05a03fa905080806110ce57fe5b50806110ed57604051632319df1960e11b815260040160405180910390fd5b505092915050565b604080518082019091525f8082526020820152611110611671565b835181526020808501518183015283516040808401919091529084015160608301525f908360808460066107d05a03fa9050808061114a57fe5b50806110ed5760405163d4b68fd760e01b815260040160405180910390fd5b61117161162e565b50604080516080810182527f198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c28183019081527f1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed6060830152815281518083019092527f275dc4a288d1afb3cbb1ac09187524c7db36395df7be3b99e673b13a075a65ec82527f1d9befcd05a5323e6da4d435f3b617cdb3af83285c2df711ef39c01571827f9d60208381019190915281019190915290565b6040805180820182528581526020808201859052825180840190935285835282018390525f9161125761168f565b5f5b600281101561140e575f61126e826006611c19565b905084826002811061128257611282611ade565b60200201515183611293835f611ce0565b600c81106112a3576112a3611ade565b60200201528482600281106112ba576112ba611ade565b602002015160200151838260016112d19190611ce0565b600c81106112e1576112e1611ade565b60200201528382600281106112f8576112f8611ade565b602002015151518361130b836002611ce0565b600c811061131b5761131b611ade565b602002015283826002811061133257611332611ade565b602002015151600160200201518361134b836003611ce0565b600c811061135b5761135b611ade565b602002015283826002811061137257611372611ade565b6020020151602001515f6002811061138c5761138c611ade565b60200201518361139d836004611ce0565b600c81106113ad576113ad611ade565b60200201528382600281106113c4576113c4611ade565b6020020151602001516001600281106113df576113df611ade565b6020020151836113f0836005611ce0565b600c811061140057611400611ade565b602002015250600101611259565b506114176116ae565b5f6020826101808560086107d05a03fa9050808061143157fe5b5080611450576040516324ccc79360e21b815260040160405180910390fd5b5051151598975050505050505050565b7f00000000000000000000000000000000000000000000000000000000000000006001600160a01b0316638da5cb5b6040518163ffffffff1660e01b8152600401602060405180830381865afa1580156114bc573d5f5f3e3d5ffd5b505050506040513d601f19601f820116820180604052508101906114e09190611cf3565b6001600160a01b0316336001600160a01b031614610e9c57604051637070f3b160e11b815260040160405180910390fd5b5f61151b82610662565b8051519091501580156115315750805160200151155b80156115405750602081015151155b801561155157506020818101510151155b61156e57604051630849e5cf60e41b815260040160405180910390fd5b5050565b604080518082019091525f8082526020820152815115801561159657506020820151155b156115b3575050604080518082019091525f808252602082015290565b6040518060400160405280835f015181526020017f30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd4784602001516115f79190611b65565b611621907f30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47611cb8565b905292915050565b919050565b60405180604001604052806116416116cc565b815260200161164e6116cc565b905290565b60405180606001604052806003906020820280368337509192915050565b60405180608001604052806004906020820280368337509192915050565b604051806101800160405280600c906020820280368337509192915050565b60405180602001604052806001906020820280368337509192915050565b60405180604001604052806002906020820280368337509192915050565b6001600160a01b03811681146116fe575f5ffd5b50565b5f60208284031215611711575f5ffd5b81356107d4816116ea565b803560ff81168114611629575f5ffd5b5f6020828403121561173c575f5ffd5b6107d48261171c565b634e487b7160e01b5f52604160045260245ffd5b6040805190810167ffffffffffffffff8111828210171561177c5761177c611745565b60405290565b604051601f8201601f1916810167ffffffffffffffff811182821017156117ab576117ab611745565b604052919050565b5f5f604083850312156117c4575f5ffd5b82356117cf816116ea565b9150602083013567ffffffffffffffff8111156117ea575f5ffd5b8301601f810185136117fa575f5ffd5b803567ffffffffffffffff81111561181457611814611745565b611827601f8201601f1916602001611782565b81815286602083850101111561183b575f5ffd5b816020840160208301375f602083830101528093505050509250929050565b5f6020828403121561186a575f5ffd5b5035919050565b81518152602080830151908201526040810161065c565b5f5f60408385031215611899575f5ffd5b6118a28361171c565b946020939093013593505050565b805f5b600281101561107f5781518452602093840193909101906001016118b3565b5f6080820190506118e48284516118b0565b60208301516118f660408401826118b0565b5092915050565b5f5f5f6060848603121561190f575f5ffd5b6119188461171c565b9250602084013563ffffffff81168114611930575f5ffd5b929592945050506040919091013590565b5f5f5f838503610160811215611955575f5ffd5b8435611960816116ea565b9350610100601f1982011215611974575f5ffd5b602085019250604061011f198201121561198c575f5ffd5b50610120840190509250925092565b5f5f82840360a08112156119ad575f5ffd5b83356119b8816116ea565b92506080601f19820112156119cb575f5ffd5b506020830190509250929050565b5f5f5f604084860312156119eb575f5ffd5b833567ffffffffffffffff811115611a01575f5ffd5b8401601f81018613611a11575f5ffd5b803567ffffffffffffffff811115611a27575f5ffd5b866020828401011115611a38575f5ffd5b6020918201979096509401359392505050565b602080825282518282018190525f918401906040840190835b81811015611a8857835163ffffffff16835260209384019390920191600101611a64565b509095945050505050565b60018060a01b0384168152826020820152606060408201525f82518060608401528060208501608085015e5f608082850101526080601f19601f830116840101915050949350505050565b634e487b7160e01b5f52603260045260245ffd5b5f6040828403128015611b03575f5ffd5b50611b0c611759565b823581526020928301359281019290925250919050565b888152876020820152866040820152856060820152604085608083013760408460c0830137610100810192909252610120820152610140019695505050505050565b5f82611b7f57634e487b7160e01b5f52601260045260245ffd5b500690565b5f82601f830112611b93575f5ffd5b611b9b611759565b806040840185811115611bac575f5ffd5b845b81811015611a88578035845260209384019301611bae565b5f6080828403128015611bd7575f5ffd5b50611be0611759565b611bea8484611b84565b8152611bf98460408501611b84565b60208201529392505050565b634e487b7160e01b5f52601160045260245ffd5b808202811582820484141761065c5761065c611c05565b815f5b6002811015611c5057813583820155602090910190600101611c33565b5050604082015f5b600281101561107f57813583820160020155602090910190600101611c58565b6040818337604080820160408401375050565b823581526020808401359082015260c081016107d46040830184611c78565b6080810161065c8284611c78565b8181038181111561065c5761065c611c05565b5f81611cd957611cd9611c05565b505f190190565b8082018082111561065c5761065c611c05565b5f60208284031215611d03575f5ffd5b81516107d4816116ea56fea26469706673582212203e7ee896d31eb9233948c100329b4d9a899a0b9610f4830999ca3e77ee79e2fa64736f6c634300081b0033
-    /// ```
-    #[rustfmt::skip]
-    #[allow(clippy::all)]
-    pub static DEPLOYED_BYTECODE: alloy_sol_types::private::Bytes = alloy_sol_types::private::Bytes::from_static(
-        b"`\x80`@R4\x80\x15a\0\x0FW__\xFD[P`\x046\x10a\x01&W_5`\xE0\x1C\x80cm\x14\xA9\x87\x11a\0\xA9W\x80c\xD1\xA6FP\x11a\0nW\x80c\xD1\xA6FP\x14a\x04\x08W\x80c\xD5%J\x8C\x14a\x04\x1BW\x80c\xDE)\xFA\xC0\x14a\x04;W\x80c\xE8\xBB\x9A\xE6\x14a\x04ZW\x80c\xF4\xE2O\xE5\x14a\x04\x82W__\xFD[\x80cm\x14\xA9\x87\x14a\x034W\x80cy\x16\xCE\xA6\x14a\x03[W\x80c\x7F\xF8\x1A\x87\x14a\x03\x9CW\x80c\xA3\xDB\x80\xE2\x14a\x03\xCFW\x80c\xBFy\xCEX\x14a\x03\xF5W__\xFD[\x80cG\xB3\x14\xE8\x11a\0\xEFW\x80cG\xB3\x14\xE8\x14a\x01\xFFW\x80c_a\xA8\x84\x14a\x02?W\x80c`WG\xD5\x14a\x02\x99W\x80cg\x16\x99\x11\x14a\x02\xE7W\x80ch\xBC\xCA\xAC\x14a\x03\x07W__\xFD[\x80b\xA1\xF4\xCB\x14a\x01*W\x80c\x13T*N\x14a\x01jW\x80c&\xD9A\xF2\x14a\x01\xA0W\x80c7~\xD9\x9D\x14a\x01\xB5W\x80c?\xB2yR\x14a\x01\xECW[__\xFD[a\x01Pa\x0186`\x04a\x17\x01V[`\x03` R_\x90\x81R`@\x90 \x80T`\x01\x90\x91\x01T\x82V[`@\x80Q\x92\x83R` \x83\x01\x91\x90\x91R\x01[`@Q\x80\x91\x03\x90\xF3[a\x01\x92a\x01x6`\x04a\x17\x01V[`\x01`\x01`\xA0\x1B\x03\x16_\x90\x81R`\x01` R`@\x90 T\x90V[`@Q\x90\x81R` \x01a\x01aV[a\x01\xB3a\x01\xAE6`\x04a\x17,V[a\x04\x95V[\0[a\x01\xD7a\x01\xC36`\x04a\x17,V[`\xFF\x16_\x90\x81R`\x04` R`@\x90 T\x90V[`@Qc\xFF\xFF\xFF\xFF\x90\x91\x16\x81R` \x01a\x01aV[a\x01\xB3a\x01\xFA6`\x04a\x17\xB3V[a\x05UV[a\x02'a\x02\r6`\x04a\x18ZV[_\x90\x81R`\x02` R`@\x90 T`\x01`\x01`\xA0\x1B\x03\x16\x90V[`@Q`\x01`\x01`\xA0\x1B\x03\x90\x91\x16\x81R` \x01a\x01aV[a\x02\x8Ca\x02M6`\x04a\x17,V[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01RP`\xFF\x16_\x90\x81R`\x05` \x90\x81R`@\x91\x82\x90 \x82Q\x80\x84\x01\x90\x93R\x80T\x83R`\x01\x01T\x90\x82\x01R\x90V[`@Qa\x01a\x91\x90a\x18qV[a\x02\xACa\x02\xA76`\x04a\x18\x88V[a\x05\xD1V[`@\x80Q\x82Qg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x16\x81R` \x80\x84\x01Qc\xFF\xFF\xFF\xFF\x90\x81\x16\x91\x83\x01\x91\x90\x91R\x92\x82\x01Q\x90\x92\x16\x90\x82\x01R``\x01a\x01aV[a\x02\xFAa\x02\xF56`\x04a\x17\x01V[a\x06bV[`@Qa\x01a\x91\x90a\x18\xD2V[a\x03\x1Aa\x03\x156`\x04a\x18\xFDV[a\x06\xF6V[`@Qg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x90\x91\x16\x81R` \x01a\x01aV[a\x02'\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x81V[a\x03na\x03i6`\x04a\x18\x88V[a\x07\xDBV[`@\x80Qg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x90\x94\x16\x84Rc\xFF\xFF\xFF\xFF\x92\x83\x16` \x85\x01R\x91\x16\x90\x82\x01R``\x01a\x01aV[a\x03\xAFa\x03\xAA6`\x04a\x17\x01V[a\x08\"V[`@\x80Q\x83Q\x81R` \x93\x84\x01Q\x93\x81\x01\x93\x90\x93R\x82\x01R``\x01a\x01aV[a\x01Pa\x03\xDD6`\x04a\x17,V[`\x05` R_\x90\x81R`@\x90 \x80T`\x01\x90\x91\x01T\x82V[a\x01\x92a\x04\x036`\x04a\x19AV[a\x08\x98V[a\x01\xB3a\x04\x166`\x04a\x19\x9BV[a\x0BmV[a\x04.a\x04)6`\x04a\x19\xD9V[a\x0CTV[`@Qa\x01a\x91\x90a\x1AKV[a\x01\x92a\x04I6`\x04a\x17\x01V[`\x01` R_\x90\x81R`@\x90 T\x81V[a\x02'a\x04h6`\x04a\x18ZV[`\x02` R_\x90\x81R`@\x90 T`\x01`\x01`\xA0\x1B\x03\x16\x81V[a\x01\xB3a\x04\x906`\x04a\x17\xB3V[a\r\xECV[a\x04\x9Da\x0ESV[`\xFF\x81\x16_\x90\x81R`\x04` R`@\x90 T\x15a\x04\xCDW`@Qc\x10\xCD\xA5\x17`\xE2\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\xFF\x16_\x90\x81R`\x04` \x90\x81R`@\x80\x83 \x81Q``\x81\x01\x83R\x84\x81Rc\xFF\xFF\xFF\xFFC\x81\x16\x82\x86\x01\x90\x81R\x82\x85\x01\x87\x81R\x84T`\x01\x81\x01\x86U\x94\x88R\x95\x90\x96 \x91Q\x91\x90\x92\x01\x80T\x95Q\x94Q\x83\x16`\x01`\xE0\x1B\x02`\x01`\x01`\xE0\x1B\x03\x95\x90\x93\x16`\x01`\xC0\x1B\x02`\x01`\x01`\xE0\x1B\x03\x19\x90\x96\x16\x91\x90\x93\x1C\x17\x93\x90\x93\x17\x91\x90\x91\x16\x91\x90\x91\x17\x90UV[a\x05]a\x0ESV[_a\x05g\x83a\x08\"V[P\x90Pa\x05t\x82\x82a\x0E\x9EV[\x7Fs\xA2\xB7\xFB\x84G$\xB9q\x80*\xE9\xB1]\xB0\x94\xD4\xB7\x19-\xF9\xD75\x0E\x14\xEBFk\x9B\"\xEBN\x83a\x05\xB4\x85`\x01`\x01`\xA0\x1B\x03\x16_\x90\x81R`\x01` R`@\x90 T\x90V[\x84`@Qa\x05\xC4\x93\x92\x91\x90a\x1A\x93V[`@Q\x80\x91\x03\x90\xA1PPPV[`@\x80Q``\x81\x01\x82R_\x80\x82R` \x80\x83\x01\x82\x90R\x82\x84\x01\x82\x90R`\xFF\x86\x16\x82R`\x04\x90R\x91\x90\x91 \x80T\x83\x90\x81\x10a\x06\rWa\x06\ra\x1A\xDEV[_\x91\x82R` \x91\x82\x90 `@\x80Q``\x81\x01\x82R\x91\x90\x92\x01Tg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x81\x84\x1B\x16\x82Rc\xFF\xFF\xFF\xFF`\x01`\xC0\x1B\x82\x04\x81\x16\x94\x83\x01\x94\x90\x94R`\x01`\xE0\x1B\x90\x04\x90\x92\x16\x90\x82\x01R\x90P[\x92\x91PPV[a\x06ja\x16.V[`\x01`\x01`\xA0\x1B\x03\x82\x16_\x90\x81R`\x06` R`@\x90\x81\x90 \x81Q`\x80\x81\x01\x83R\x91\x82\x90\x81\x01\x82`\x02\x82\x82\x82` \x02\x82\x01\x91[\x81T\x81R` \x01\x90`\x01\x01\x90\x80\x83\x11a\x06\x9DWPPP\x91\x83RPP`@\x80Q\x80\x82\x01\x91\x82\x90R` \x90\x92\x01\x91\x90`\x02\x84\x81\x01\x91\x82\x84[\x81T\x81R` \x01\x90`\x01\x01\x90\x80\x83\x11a\x06\xD3WPPPPP\x81RPP\x90P\x91\x90PV[`\xFF\x83\x16_\x90\x81R`\x04` R`@\x81 \x80T\x82\x91\x90\x84\x90\x81\x10a\x07\x1CWa\x07\x1Ca\x1A\xDEV[_\x91\x82R` \x91\x82\x90 `@\x80Q``\x81\x01\x82R\x91\x90\x92\x01Tg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x81\x84\x1B\x16\x82Rc\xFF\xFF\xFF\xFF`\x01`\xC0\x1B\x82\x04\x81\x16\x94\x83\x01\x85\x90R`\x01`\xE0\x1B\x90\x91\x04\x81\x16\x92\x82\x01\x92\x90\x92R\x92P\x85\x16\x10\x15a\x07\x8DW`@Qc=\"\x88A`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`@\x81\x01Qc\xFF\xFF\xFF\xFF\x16\x15\x80a\x07\xB3WP\x80`@\x01Qc\xFF\xFF\xFF\xFF\x16\x84c\xFF\xFF\xFF\xFF\x16\x10[a\x07\xD0W`@Qco\xE0-K`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[Q\x90P[\x93\x92PPPV[`\x04` R\x81_R`@_ \x81\x81T\x81\x10a\x07\xF4W_\x80\xFD[_\x91\x82R` \x90\x91 \x01T`@\x81\x90\x1B\x92Pc\xFF\xFF\xFF\xFF`\x01`\xC0\x1B\x82\x04\x81\x16\x92P`\x01`\xE0\x1B\x90\x91\x04\x16\x83V[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01R`\x01`\x01`\xA0\x1B\x03\x82\x16_\x81\x81R`\x03` \x90\x81R`@\x80\x83 \x81Q\x80\x83\x01\x83R\x81T\x81R`\x01\x91\x82\x01T\x81\x85\x01R\x94\x84R\x90\x91R\x81 T\x90\x91\x90\x80a\x08\x8EW`@Qc%\xECl\x1F`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[\x90\x94\x90\x93P\x91PPV[_a\x08\xA1a\x0ESV[_a\x08\xCDa\x08\xB76\x86\x90\x03\x86\x01`@\x87\x01a\x1A\xF2V[\x80Q_\x90\x81R` \x91\x82\x01Q\x90\x91R`@\x90 \x90V[\x90P\x7F\xAD2(\xB6v\xF7\xD3\xCDB\x84\xA5D?\x17\xF1\x96+6\xE4\x91\xB3\n@\xB2@XI\xE5\x97\xBA_\xB5\x81\x03a\t\x0FW`@Qc\x0C\xC7P\x91`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\x01`\x01`\xA0\x1B\x03\x85\x16_\x90\x81R`\x01` R`@\x81 T\x14a\tEW`@QcB\xEEh\xB5`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[_\x81\x81R`\x02` R`@\x90 T`\x01`\x01`\xA0\x1B\x03\x16\x15a\tzW`@QcL3L\x97`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`@\x80Q_\x91\x7F0dNr\xE11\xA0)\xB8PE\xB6\x81\x81X](3\xE8Hy\xB9p\x91C\xE1\xF5\x93\xF0\0\0\x01\x91a\t\xD2\x91\x885\x91` \x80\x8B\x015\x92\x8B\x015\x91``\x8C\x015\x91`\x80\x8D\x01\x91`\xC0\x8E\x01\x91\x8D5\x91\x8E\x82\x015\x91\x01a\x1B#V[`@Q` \x81\x83\x03\x03\x81R\x90`@R\x80Q\x90` \x01 _\x1Ca\t\xF4\x91\x90a\x1BeV[\x90Pa\n\x8Da\n-a\n\x18\x83a\n\x126\x8A\x90\x03\x8A\x01`@\x8B\x01a\x1A\xF2V[\x90a\x10\x85V[a\n'6\x89\x90\x03\x89\x01\x89a\x1A\xF2V[\x90a\x10\xF5V[a\n5a\x11iV[a\nva\ng\x85a\n\x12`@\x80Q\x80\x82\x01\x82R_\x80\x82R` \x91\x82\x01R\x81Q\x80\x83\x01\x90\x92R`\x01\x82R`\x02\x90\x82\x01R\x90V[a\n'6\x8A\x90\x03\x8A\x01\x8Aa\x1A\xF2V[a\n\x886\x8A\x90\x03\x8A\x01`\x80\x8B\x01a\x1B\xC6V[a\x12)V[a\n\xAAW`@Qc\xA7-\x02c`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\x01`\x01`\xA0\x1B\x03\x86\x16_\x90\x81R`\x03` \x90\x81R`@\x80\x83 \x88\x82\x015\x81U``\x89\x015`\x01\x90\x91\x01U`\x06\x90\x91R\x90 `\x80\x86\x01\x90a\n\xEB\x82\x82a\x1C0V[PP`\x01`\x01`\xA0\x1B\x03\x86\x16_\x81\x81R`\x01` \x90\x81R`@\x80\x83 \x86\x90U\x85\x83R`\x02\x90\x91R\x90\x81\x90 \x80T`\x01`\x01`\xA0\x1B\x03\x19\x16\x83\x17\x90U\x80Q\x7F\xE3\xFBf\x13\xAF.\x890\xCF\x85\xD4\x7F\xCFm\xB1\x01\x92\"Jd\xC6\xCB\xE8\x02>\x0E\xEE\x1B\xA3\x82\x80A\x91a\x0B\\\x91\x90\x89\x01\x90`\x80\x8A\x01\x90a\x1C\x8BV[`@Q\x80\x91\x03\x90\xA2P\x94\x93PPPPV[a\x0Bua\x14`V[_a\x0B\x7F\x83a\x08\"V[P\x90Pa\x0B\x8B\x83a\x15\x11V[a\x0B\xCB\x81a\x0B\x97a\x11iV[`@\x80Q\x80\x82\x01\x82R_\x80\x82R` \x91\x82\x01R\x81Q\x80\x83\x01\x90\x92R`\x01\x82R`\x02\x90\x82\x01Ra\n\x886\x87\x90\x03\x87\x01\x87a\x1B\xC6V[a\x0B\xE8W`@Qc\xA7-\x02c`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\x01`\x01`\xA0\x1B\x03\x83\x16_\x90\x81R`\x06` R`@\x90 \x82\x90a\x0C\x0B\x82\x82a\x1C0V[\x90PP\x82`\x01`\x01`\xA0\x1B\x03\x16\x7F\\O\x9F(\x15=\xBF?\0\xE6\x96\x07\xA5\x9E\x82\xAD\x80o\xFF\xB7\x8D\t\xF1y\xF6$2\xF7\xE9\xD2Q\x1A\x83`@Qa\x0CG\x91\x90a\x1C\xAAV[`@Q\x80\x91\x03\x90\xA2PPPV[``_\x83g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x0CpWa\x0Cpa\x17EV[`@Q\x90\x80\x82R\x80` \x02` \x01\x82\x01`@R\x80\x15a\x0C\x99W\x81` \x01` \x82\x02\x806\x837\x01\x90P[P\x90P_[\x84\x81\x10\x15a\r\xE3W_\x86\x86\x83\x81\x81\x10a\x0C\xB9Wa\x0C\xB9a\x1A\xDEV[\x91\x90\x91\x015`\xF8\x1C_\x81\x81R`\x04` R`@\x90 T\x90\x92P\x90P\x80\x15\x80a\r\x19WP`\xFF\x82\x16_\x90\x81R`\x04` R`@\x81 \x80T\x90\x91\x90a\x0C\xFEWa\x0C\xFEa\x1A\xDEV[_\x91\x82R` \x90\x91 \x01T`\x01`\xC0\x1B\x90\x04c\xFF\xFF\xFF\xFF\x16\x86\x10[\x15a\r7W`@Qc?L\xB7\x0F`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[\x80[\x80\x15a\r\xD8W`\xFF\x83\x16_\x90\x81R`\x04` R`@\x90 \x87\x90a\r]`\x01\x84a\x1C\xB8V[\x81T\x81\x10a\rmWa\rma\x1A\xDEV[_\x91\x82R` \x90\x91 \x01T`\x01`\xC0\x1B\x90\x04c\xFF\xFF\xFF\xFF\x16\x11a\r\xC6Wa\r\x95`\x01\x82a\x1C\xB8V[\x85\x85\x81Q\x81\x10a\r\xA7Wa\r\xA7a\x1A\xDEV[` \x02` \x01\x01\x90c\xFF\xFF\xFF\xFF\x16\x90\x81c\xFF\xFF\xFF\xFF\x16\x81RPPa\r\xD8V[\x80a\r\xD0\x81a\x1C\xCBV[\x91PPa\r9V[PPP`\x01\x01a\x0C\x9EV[P\x94\x93PPPPV[a\r\xF4a\x0ESV[_a\r\xFE\x83a\x08\"V[P\x90Pa\x0E\x13\x82a\x0E\x0E\x83a\x15rV[a\x0E\x9EV[\x7F\xF8C\xEC\xD5:V6u\xE6!\x07\xBE\x14\x94\xFD\xDEJ=I\xAE\xED\xAF\x8D\x88\xC6\x16\xD8SF\xE3P\x0E\x83a\x05\xB4\x85`\x01`\x01`\xA0\x1B\x03\x16_\x90\x81R`\x01` R`@\x90 T\x90V[3`\x01`\x01`\xA0\x1B\x03\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x16\x14a\x0E\x9CW`@Qcpp\xF3\xB1`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[V[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01R_[\x83Q\x81\x10\x15a\x10\x7FW_\x84\x82\x81Q\x81\x10a\x0E\xCFWa\x0E\xCFa\x1A\xDEV[\x01` \x90\x81\x01Q`\xF8\x1C_\x81\x81R`\x04\x90\x92R`@\x82 T\x90\x92P\x90\x81\x90\x03a\x0F\x0BW`@Qcs\x10\xCF\xF5`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[`\xFF\x82\x16_\x90\x81R`\x05` \x90\x81R`@\x91\x82\x90 \x82Q\x80\x84\x01\x90\x93R\x80T\x83R`\x01\x01T\x90\x82\x01Ra\x0F>\x90\x86a\x10\xF5V[`\xFF\x83\x16_\x81\x81R`\x05` \x90\x81R`@\x80\x83 \x85Q\x80\x82U\x86\x84\x01\x80Q`\x01\x93\x84\x01U\x90\x85RQ\x83R\x81\x84 \x94\x84R`\x04\x90\x92R\x82 \x93\x97P\x91\x92\x90\x91a\x0F\x86\x90\x85a\x1C\xB8V[\x81T\x81\x10a\x0F\x96Wa\x0F\x96a\x1A\xDEV[_\x91\x82R` \x90\x91 \x01\x80T\x90\x91Pc\xFF\xFF\xFF\xFFC\x81\x16`\x01`\xC0\x1B\x90\x92\x04\x16\x03a\x0F\xD4W\x80T`\x01`\x01`\xC0\x1B\x03\x19\x16`@\x83\x90\x1C\x17\x81Ua\x10oV[\x80Tc\xFF\xFF\xFF\xFFC\x81\x16`\x01`\xE0\x1B\x81\x81\x02`\x01`\x01`\xE0\x1B\x03\x94\x85\x16\x17\x85U`\xFF\x88\x16_\x90\x81R`\x04` \x90\x81R`@\x80\x83 \x81Q``\x81\x01\x83Rg\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x8B\x16\x81R\x80\x84\x01\x96\x87R\x80\x83\x01\x85\x81R\x82T`\x01\x81\x01\x84U\x92\x86R\x93\x90\x94 \x93Q\x93\x01\x80T\x95Q\x92Q\x87\x16\x90\x94\x02\x91\x90\x95\x16`\x01`\xC0\x1B\x02`\x01`\x01`\xE0\x1B\x03\x19\x94\x90\x94\x16\x91\x90\x94\x1C\x17\x91\x90\x91\x17\x90\x92\x16\x17\x90U[PP`\x01\x90\x92\x01\x91Pa\x0E\xB3\x90PV[PPPPV[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01Ra\x10\xA0a\x16SV[\x83Q\x81R` \x80\x85\x01Q\x90\x82\x01R`@\x80\x82\x01\x84\x90R_\x90\x83``\x84`\x07a\x07\xD0Z\x03\xFA\x90P\x80\x80a\x10\xCEW\xFE[P\x80a\x10\xEDW`@Qc#\x19\xDF\x19`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[PP\x92\x91PPV[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01Ra\x11\x10a\x16qV[\x83Q\x81R` \x80\x85\x01Q\x81\x83\x01R\x83Q`@\x80\x84\x01\x91\x90\x91R\x90\x84\x01Q``\x83\x01R_\x90\x83`\x80\x84`\x06a\x07\xD0Z\x03\xFA\x90P\x80\x80a\x11JW\xFE[P\x80a\x10\xEDW`@Qc\xD4\xB6\x8F\xD7`\xE0\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[a\x11qa\x16.V[P`@\x80Q`\x80\x81\x01\x82R\x7F\x19\x8E\x93\x93\x92\rH:r`\xBF\xB71\xFB]%\xF1\xAAI35\xA9\xE7\x12\x97\xE4\x85\xB7\xAE\xF3\x12\xC2\x81\x83\x01\x90\x81R\x7F\x18\0\xDE\xEF\x12\x1F\x1EvBj\0f^\\DygC\"\xD4\xF7^\xDA\xDDF\xDE\xBD\\\xD9\x92\xF6\xED``\x83\x01R\x81R\x81Q\x80\x83\x01\x90\x92R\x7F']\xC4\xA2\x88\xD1\xAF\xB3\xCB\xB1\xAC\t\x18u$\xC7\xDB69]\xF7\xBE;\x99\xE6s\xB1:\x07Ze\xEC\x82R\x7F\x1D\x9B\xEF\xCD\x05\xA52>m\xA4\xD45\xF3\xB6\x17\xCD\xB3\xAF\x83(\\-\xF7\x11\xEF9\xC0\x15q\x82\x7F\x9D` \x83\x81\x01\x91\x90\x91R\x81\x01\x91\x90\x91R\x90V[`@\x80Q\x80\x82\x01\x82R\x85\x81R` \x80\x82\x01\x85\x90R\x82Q\x80\x84\x01\x90\x93R\x85\x83R\x82\x01\x83\x90R_\x91a\x12Wa\x16\x8FV[_[`\x02\x81\x10\x15a\x14\x0EW_a\x12n\x82`\x06a\x1C\x19V[\x90P\x84\x82`\x02\x81\x10a\x12\x82Wa\x12\x82a\x1A\xDEV[` \x02\x01QQ\x83a\x12\x93\x83_a\x1C\xE0V[`\x0C\x81\x10a\x12\xA3Wa\x12\xA3a\x1A\xDEV[` \x02\x01R\x84\x82`\x02\x81\x10a\x12\xBAWa\x12\xBAa\x1A\xDEV[` \x02\x01Q` \x01Q\x83\x82`\x01a\x12\xD1\x91\x90a\x1C\xE0V[`\x0C\x81\x10a\x12\xE1Wa\x12\xE1a\x1A\xDEV[` \x02\x01R\x83\x82`\x02\x81\x10a\x12\xF8Wa\x12\xF8a\x1A\xDEV[` \x02\x01QQQ\x83a\x13\x0B\x83`\x02a\x1C\xE0V[`\x0C\x81\x10a\x13\x1BWa\x13\x1Ba\x1A\xDEV[` \x02\x01R\x83\x82`\x02\x81\x10a\x132Wa\x132a\x1A\xDEV[` \x02\x01QQ`\x01` \x02\x01Q\x83a\x13K\x83`\x03a\x1C\xE0V[`\x0C\x81\x10a\x13[Wa\x13[a\x1A\xDEV[` \x02\x01R\x83\x82`\x02\x81\x10a\x13rWa\x13ra\x1A\xDEV[` \x02\x01Q` \x01Q_`\x02\x81\x10a\x13\x8CWa\x13\x8Ca\x1A\xDEV[` \x02\x01Q\x83a\x13\x9D\x83`\x04a\x1C\xE0V[`\x0C\x81\x10a\x13\xADWa\x13\xADa\x1A\xDEV[` \x02\x01R\x83\x82`\x02\x81\x10a\x13\xC4Wa\x13\xC4a\x1A\xDEV[` \x02\x01Q` \x01Q`\x01`\x02\x81\x10a\x13\xDFWa\x13\xDFa\x1A\xDEV[` \x02\x01Q\x83a\x13\xF0\x83`\x05a\x1C\xE0V[`\x0C\x81\x10a\x14\0Wa\x14\0a\x1A\xDEV[` \x02\x01RP`\x01\x01a\x12YV[Pa\x14\x17a\x16\xAEV[_` \x82a\x01\x80\x85`\x08a\x07\xD0Z\x03\xFA\x90P\x80\x80a\x141W\xFE[P\x80a\x14PW`@Qc$\xCC\xC7\x93`\xE2\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[PQ\x15\x15\x98\x97PPPPPPPPV[\x7F\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0`\x01`\x01`\xA0\x1B\x03\x16c\x8D\xA5\xCB[`@Q\x81c\xFF\xFF\xFF\xFF\x16`\xE0\x1B\x81R`\x04\x01` `@Q\x80\x83\x03\x81\x86Z\xFA\x15\x80\x15a\x14\xBCW=__>=_\xFD[PPPP`@Q=`\x1F\x19`\x1F\x82\x01\x16\x82\x01\x80`@RP\x81\x01\x90a\x14\xE0\x91\x90a\x1C\xF3V[`\x01`\x01`\xA0\x1B\x03\x163`\x01`\x01`\xA0\x1B\x03\x16\x14a\x0E\x9CW`@Qcpp\xF3\xB1`\xE1\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[_a\x15\x1B\x82a\x06bV[\x80QQ\x90\x91P\x15\x80\x15a\x151WP\x80Q` \x01Q\x15[\x80\x15a\x15@WP` \x81\x01QQ\x15[\x80\x15a\x15QWP` \x81\x81\x01Q\x01Q\x15[a\x15nW`@Qc\x08I\xE5\xCF`\xE4\x1B\x81R`\x04\x01`@Q\x80\x91\x03\x90\xFD[PPV[`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01R\x81Q\x15\x80\x15a\x15\x96WP` \x82\x01Q\x15[\x15a\x15\xB3WPP`@\x80Q\x80\x82\x01\x90\x91R_\x80\x82R` \x82\x01R\x90V[`@Q\x80`@\x01`@R\x80\x83_\x01Q\x81R` \x01\x7F0dNr\xE11\xA0)\xB8PE\xB6\x81\x81X]\x97\x81j\x91hq\xCA\x8D< \x8C\x16\xD8|\xFDG\x84` \x01Qa\x15\xF7\x91\x90a\x1BeV[a\x16!\x90\x7F0dNr\xE11\xA0)\xB8PE\xB6\x81\x81X]\x97\x81j\x91hq\xCA\x8D< \x8C\x16\xD8|\xFDGa\x1C\xB8V[\x90R\x92\x91PPV[\x91\x90PV[`@Q\x80`@\x01`@R\x80a\x16Aa\x16\xCCV[\x81R` \x01a\x16Na\x16\xCCV[\x90R\x90V[`@Q\x80``\x01`@R\x80`\x03\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`@Q\x80`\x80\x01`@R\x80`\x04\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`@Q\x80a\x01\x80\x01`@R\x80`\x0C\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`@Q\x80` \x01`@R\x80`\x01\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`@Q\x80`@\x01`@R\x80`\x02\x90` \x82\x02\x806\x837P\x91\x92\x91PPV[`\x01`\x01`\xA0\x1B\x03\x81\x16\x81\x14a\x16\xFEW__\xFD[PV[_` \x82\x84\x03\x12\x15a\x17\x11W__\xFD[\x815a\x07\xD4\x81a\x16\xEAV[\x805`\xFF\x81\x16\x81\x14a\x16)W__\xFD[_` \x82\x84\x03\x12\x15a\x17<W__\xFD[a\x07\xD4\x82a\x17\x1CV[cNH{q`\xE0\x1B_R`A`\x04R`$_\xFD[`@\x80Q\x90\x81\x01g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x82\x82\x10\x17\x15a\x17|Wa\x17|a\x17EV[`@R\x90V[`@Q`\x1F\x82\x01`\x1F\x19\x16\x81\x01g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x82\x82\x10\x17\x15a\x17\xABWa\x17\xABa\x17EV[`@R\x91\x90PV[__`@\x83\x85\x03\x12\x15a\x17\xC4W__\xFD[\x825a\x17\xCF\x81a\x16\xEAV[\x91P` \x83\x015g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x17\xEAW__\xFD[\x83\x01`\x1F\x81\x01\x85\x13a\x17\xFAW__\xFD[\x805g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x18\x14Wa\x18\x14a\x17EV[a\x18'`\x1F\x82\x01`\x1F\x19\x16` \x01a\x17\x82V[\x81\x81R\x86` \x83\x85\x01\x01\x11\x15a\x18;W__\xFD[\x81` \x84\x01` \x83\x017_` \x83\x83\x01\x01R\x80\x93PPPP\x92P\x92\x90PV[_` \x82\x84\x03\x12\x15a\x18jW__\xFD[P5\x91\x90PV[\x81Q\x81R` \x80\x83\x01Q\x90\x82\x01R`@\x81\x01a\x06\\V[__`@\x83\x85\x03\x12\x15a\x18\x99W__\xFD[a\x18\xA2\x83a\x17\x1CV[\x94` \x93\x90\x93\x015\x93PPPV[\x80_[`\x02\x81\x10\x15a\x10\x7FW\x81Q\x84R` \x93\x84\x01\x93\x90\x91\x01\x90`\x01\x01a\x18\xB3V[_`\x80\x82\x01\x90Pa\x18\xE4\x82\x84Qa\x18\xB0V[` \x83\x01Qa\x18\xF6`@\x84\x01\x82a\x18\xB0V[P\x92\x91PPV[___``\x84\x86\x03\x12\x15a\x19\x0FW__\xFD[a\x19\x18\x84a\x17\x1CV[\x92P` \x84\x015c\xFF\xFF\xFF\xFF\x81\x16\x81\x14a\x190W__\xFD[\x92\x95\x92\x94PPP`@\x91\x90\x91\x015\x90V[___\x83\x85\x03a\x01`\x81\x12\x15a\x19UW__\xFD[\x845a\x19`\x81a\x16\xEAV[\x93Pa\x01\0`\x1F\x19\x82\x01\x12\x15a\x19tW__\xFD[` \x85\x01\x92P`@a\x01\x1F\x19\x82\x01\x12\x15a\x19\x8CW__\xFD[Pa\x01 \x84\x01\x90P\x92P\x92P\x92V[__\x82\x84\x03`\xA0\x81\x12\x15a\x19\xADW__\xFD[\x835a\x19\xB8\x81a\x16\xEAV[\x92P`\x80`\x1F\x19\x82\x01\x12\x15a\x19\xCBW__\xFD[P` \x83\x01\x90P\x92P\x92\x90PV[___`@\x84\x86\x03\x12\x15a\x19\xEBW__\xFD[\x835g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x1A\x01W__\xFD[\x84\x01`\x1F\x81\x01\x86\x13a\x1A\x11W__\xFD[\x805g\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x81\x11\x15a\x1A'W__\xFD[\x86` \x82\x84\x01\x01\x11\x15a\x1A8W__\xFD[` \x91\x82\x01\x97\x90\x96P\x94\x015\x93\x92PPPV[` \x80\x82R\x82Q\x82\x82\x01\x81\x90R_\x91\x84\x01\x90`@\x84\x01\x90\x83[\x81\x81\x10\x15a\x1A\x88W\x83Qc\xFF\xFF\xFF\xFF\x16\x83R` \x93\x84\x01\x93\x90\x92\x01\x91`\x01\x01a\x1AdV[P\x90\x95\x94PPPPPV[`\x01\x80`\xA0\x1B\x03\x84\x16\x81R\x82` \x82\x01R```@\x82\x01R_\x82Q\x80``\x84\x01R\x80` \x85\x01`\x80\x85\x01^_`\x80\x82\x85\x01\x01R`\x80`\x1F\x19`\x1F\x83\x01\x16\x84\x01\x01\x91PP\x94\x93PPPPV[cNH{q`\xE0\x1B_R`2`\x04R`$_\xFD[_`@\x82\x84\x03\x12\x80\x15a\x1B\x03W__\xFD[Pa\x1B\x0Ca\x17YV[\x825\x81R` \x92\x83\x015\x92\x81\x01\x92\x90\x92RP\x91\x90PV[\x88\x81R\x87` \x82\x01R\x86`@\x82\x01R\x85``\x82\x01R`@\x85`\x80\x83\x017`@\x84`\xC0\x83\x017a\x01\0\x81\x01\x92\x90\x92Ra\x01 \x82\x01Ra\x01@\x01\x96\x95PPPPPPV[_\x82a\x1B\x7FWcNH{q`\xE0\x1B_R`\x12`\x04R`$_\xFD[P\x06\x90V[_\x82`\x1F\x83\x01\x12a\x1B\x93W__\xFD[a\x1B\x9Ba\x17YV[\x80`@\x84\x01\x85\x81\x11\x15a\x1B\xACW__\xFD[\x84[\x81\x81\x10\x15a\x1A\x88W\x805\x84R` \x93\x84\x01\x93\x01a\x1B\xAEV[_`\x80\x82\x84\x03\x12\x80\x15a\x1B\xD7W__\xFD[Pa\x1B\xE0a\x17YV[a\x1B\xEA\x84\x84a\x1B\x84V[\x81Ra\x1B\xF9\x84`@\x85\x01a\x1B\x84V[` \x82\x01R\x93\x92PPPV[cNH{q`\xE0\x1B_R`\x11`\x04R`$_\xFD[\x80\x82\x02\x81\x15\x82\x82\x04\x84\x14\x17a\x06\\Wa\x06\\a\x1C\x05V[\x81_[`\x02\x81\x10\x15a\x1CPW\x815\x83\x82\x01U` \x90\x91\x01\x90`\x01\x01a\x1C3V[PP`@\x82\x01_[`\x02\x81\x10\x15a\x10\x7FW\x815\x83\x82\x01`\x02\x01U` \x90\x91\x01\x90`\x01\x01a\x1CXV[`@\x81\x837`@\x80\x82\x01`@\x84\x017PPV[\x825\x81R` \x80\x84\x015\x90\x82\x01R`\xC0\x81\x01a\x07\xD4`@\x83\x01\x84a\x1CxV[`\x80\x81\x01a\x06\\\x82\x84a\x1CxV[\x81\x81\x03\x81\x81\x11\x15a\x06\\Wa\x06\\a\x1C\x05V[_\x81a\x1C\xD9Wa\x1C\xD9a\x1C\x05V[P_\x19\x01\x90V[\x80\x82\x01\x80\x82\x11\x15a\x06\\Wa\x06\\a\x1C\x05V[_` \x82\x84\x03\x12\x15a\x1D\x03W__\xFD[\x81Qa\x07\xD4\x81a\x16\xEAV\xFE\xA2dipfsX\"\x12 >~\xE8\x96\xD3\x1E\xB9#9H\xC1\x002\x9BM\x9A\x89\x9A\x0B\x96\x10\xF4\x83\t\x99\xCA>w\xEEy\xE2\xFAdsolcC\0\x08\x1B\x003",
-    );
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `BLSPubkeyAlreadyRegistered()` and selector `0x9866992e`.
-    ```solidity
-    error BLSPubkeyAlreadyRegistered();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct BLSPubkeyAlreadyRegistered {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<BLSPubkeyAlreadyRegistered> for UnderlyingRustTuple<'_> {
-            fn from(value: BLSPubkeyAlreadyRegistered) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for BLSPubkeyAlreadyRegistered {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for BLSPubkeyAlreadyRegistered {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "BLSPubkeyAlreadyRegistered()";
-            const SELECTOR: [u8; 4] = [152u8, 102u8, 153u8, 46u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `BlockNumberBeforeFirstUpdate()` and selector `0x3f4cb70f`.
-    ```solidity
-    error BlockNumberBeforeFirstUpdate();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct BlockNumberBeforeFirstUpdate {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<BlockNumberBeforeFirstUpdate> for UnderlyingRustTuple<'_> {
-            fn from(value: BlockNumberBeforeFirstUpdate) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for BlockNumberBeforeFirstUpdate {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for BlockNumberBeforeFirstUpdate {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "BlockNumberBeforeFirstUpdate()";
-            const SELECTOR: [u8; 4] = [63u8, 76u8, 183u8, 15u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `BlockNumberNotLatest()` and selector `0x6fe02d4b`.
-    ```solidity
-    error BlockNumberNotLatest();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct BlockNumberNotLatest {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<BlockNumberNotLatest> for UnderlyingRustTuple<'_> {
-            fn from(value: BlockNumberNotLatest) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for BlockNumberNotLatest {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for BlockNumberNotLatest {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "BlockNumberNotLatest()";
-            const SELECTOR: [u8; 4] = [111u8, 224u8, 45u8, 75u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `BlockNumberTooRecent()` and selector `0x3d228841`.
-    ```solidity
-    error BlockNumberTooRecent();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct BlockNumberTooRecent {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<BlockNumberTooRecent> for UnderlyingRustTuple<'_> {
-            fn from(value: BlockNumberTooRecent) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for BlockNumberTooRecent {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for BlockNumberTooRecent {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "BlockNumberTooRecent()";
-            const SELECTOR: [u8; 4] = [61u8, 34u8, 136u8, 65u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `ECAddFailed()` and selector `0xd4b68fd7`.
-    ```solidity
-    error ECAddFailed();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct ECAddFailed {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<ECAddFailed> for UnderlyingRustTuple<'_> {
-            fn from(value: ECAddFailed) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for ECAddFailed {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for ECAddFailed {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "ECAddFailed()";
-            const SELECTOR: [u8; 4] = [212u8, 182u8, 143u8, 215u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `ECMulFailed()` and selector `0x4633be32`.
-    ```solidity
-    error ECMulFailed();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct ECMulFailed {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<ECMulFailed> for UnderlyingRustTuple<'_> {
-            fn from(value: ECMulFailed) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for ECMulFailed {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for ECMulFailed {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "ECMulFailed()";
-            const SELECTOR: [u8; 4] = [70u8, 51u8, 190u8, 50u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `ECPairingFailed()` and selector `0x93331e4c`.
-    ```solidity
-    error ECPairingFailed();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct ECPairingFailed {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<ECPairingFailed> for UnderlyingRustTuple<'_> {
-            fn from(value: ECPairingFailed) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for ECPairingFailed {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for ECPairingFailed {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "ECPairingFailed()";
-            const SELECTOR: [u8; 4] = [147u8, 51u8, 30u8, 76u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `G2PubkeyAlreadySet()` and selector `0x849e5cf0`.
-    ```solidity
-    error G2PubkeyAlreadySet();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct G2PubkeyAlreadySet {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<G2PubkeyAlreadySet> for UnderlyingRustTuple<'_> {
-            fn from(value: G2PubkeyAlreadySet) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for G2PubkeyAlreadySet {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for G2PubkeyAlreadySet {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "G2PubkeyAlreadySet()";
-            const SELECTOR: [u8; 4] = [132u8, 158u8, 92u8, 240u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `InvalidBLSSignatureOrPrivateKey()` and selector `0xa72d0263`.
-    ```solidity
-    error InvalidBLSSignatureOrPrivateKey();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct InvalidBLSSignatureOrPrivateKey {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<InvalidBLSSignatureOrPrivateKey> for UnderlyingRustTuple<'_> {
-            fn from(value: InvalidBLSSignatureOrPrivateKey) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for InvalidBLSSignatureOrPrivateKey {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for InvalidBLSSignatureOrPrivateKey {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "InvalidBLSSignatureOrPrivateKey()";
-            const SELECTOR: [u8; 4] = [167u8, 45u8, 2u8, 99u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `OnlyRegistryCoordinatorOwner()` and selector `0xe0e1e762`.
-    ```solidity
-    error OnlyRegistryCoordinatorOwner();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct OnlyRegistryCoordinatorOwner {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<OnlyRegistryCoordinatorOwner> for UnderlyingRustTuple<'_> {
-            fn from(value: OnlyRegistryCoordinatorOwner) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for OnlyRegistryCoordinatorOwner {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for OnlyRegistryCoordinatorOwner {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "OnlyRegistryCoordinatorOwner()";
-            const SELECTOR: [u8; 4] = [224u8, 225u8, 231u8, 98u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `OperatorAlreadyRegistered()` and selector `0x42ee68b5`.
-    ```solidity
-    error OperatorAlreadyRegistered();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct OperatorAlreadyRegistered {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<OperatorAlreadyRegistered> for UnderlyingRustTuple<'_> {
-            fn from(value: OperatorAlreadyRegistered) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for OperatorAlreadyRegistered {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for OperatorAlreadyRegistered {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "OperatorAlreadyRegistered()";
-            const SELECTOR: [u8; 4] = [66u8, 238u8, 104u8, 181u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `OperatorNotRegistered()` and selector `0x25ec6c1f`.
-    ```solidity
-    error OperatorNotRegistered();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct OperatorNotRegistered {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<OperatorNotRegistered> for UnderlyingRustTuple<'_> {
-            fn from(value: OperatorNotRegistered) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for OperatorNotRegistered {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for OperatorNotRegistered {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "OperatorNotRegistered()";
-            const SELECTOR: [u8; 4] = [37u8, 236u8, 108u8, 31u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `QuorumAlreadyExists()` and selector `0x4336945c`.
-    ```solidity
-    error QuorumAlreadyExists();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct QuorumAlreadyExists {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<QuorumAlreadyExists> for UnderlyingRustTuple<'_> {
-            fn from(value: QuorumAlreadyExists) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for QuorumAlreadyExists {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for QuorumAlreadyExists {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "QuorumAlreadyExists()";
-            const SELECTOR: [u8; 4] = [67u8, 54u8, 148u8, 92u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `QuorumDoesNotExist()` and selector `0xe6219fea`.
-    ```solidity
-    error QuorumDoesNotExist();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct QuorumDoesNotExist {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<QuorumDoesNotExist> for UnderlyingRustTuple<'_> {
-            fn from(value: QuorumDoesNotExist) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for QuorumDoesNotExist {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for QuorumDoesNotExist {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "QuorumDoesNotExist()";
-            const SELECTOR: [u8; 4] = [230u8, 33u8, 159u8, 234u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Custom error with signature `ZeroPubKey()` and selector `0x0cc75091`.
-    ```solidity
-    error ZeroPubKey();
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct ZeroPubKey {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[doc(hidden)]
-        type UnderlyingSolTuple<'a> = ();
-        #[doc(hidden)]
-        type UnderlyingRustTuple<'a> = ();
-        #[cfg(test)]
-        #[allow(dead_code, unreachable_patterns)]
-        fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-            match _t {
-                alloy_sol_types::private::AssertTypeEq::<
-                    <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                >(_) => {}
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<ZeroPubKey> for UnderlyingRustTuple<'_> {
-            fn from(value: ZeroPubKey) -> Self {
-                ()
-            }
-        }
-        #[automatically_derived]
-        #[doc(hidden)]
-        impl ::core::convert::From<UnderlyingRustTuple<'_>> for ZeroPubKey {
-            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                Self {}
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolError for ZeroPubKey {
-            type Parameters<'a> = UnderlyingSolTuple<'a>;
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "ZeroPubKey()";
-            const SELECTOR: [u8; 4] = [12u8, 199u8, 80u8, 145u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Event with signature `Initialized(uint8)` and selector `0x7f26b83ff96e1f2b6a682f133852f6798a09c465da95921460cefb3847402498`.
-    ```solidity
-    event Initialized(uint8 version);
-    ```*/
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    #[derive(Clone)]
-    pub struct Initialized {
-        #[allow(missing_docs)]
-        pub version: u8,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[automatically_derived]
-        impl alloy_sol_types::SolEvent for Initialized {
-            type DataTuple<'a> = (alloy::sol_types::sol_data::Uint<8>,);
-            type DataToken<'a> = <Self::DataTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type TopicList = (alloy_sol_types::sol_data::FixedBytes<32>,);
-            const SIGNATURE: &'static str = "Initialized(uint8)";
-            const SIGNATURE_HASH: alloy_sol_types::private::B256 =
-                alloy_sol_types::private::B256::new([
-                    127u8, 38u8, 184u8, 63u8, 249u8, 110u8, 31u8, 43u8, 106u8, 104u8, 47u8, 19u8,
-                    56u8, 82u8, 246u8, 121u8, 138u8, 9u8, 196u8, 101u8, 218u8, 149u8, 146u8, 20u8,
-                    96u8, 206u8, 251u8, 56u8, 71u8, 64u8, 36u8, 152u8,
-                ]);
-            const ANONYMOUS: bool = false;
-            #[allow(unused_variables)]
-            #[inline]
-            fn new(
-                topics: <Self::TopicList as alloy_sol_types::SolType>::RustType,
-                data: <Self::DataTuple<'_> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                Self { version: data.0 }
-            }
-            #[inline]
-            fn check_signature(
-                topics: &<Self::TopicList as alloy_sol_types::SolType>::RustType,
-            ) -> alloy_sol_types::Result<()> {
-                if topics.0 != Self::SIGNATURE_HASH {
-                    return Err(alloy_sol_types::Error::invalid_event_signature_hash(
-                        Self::SIGNATURE,
-                        topics.0,
-                        Self::SIGNATURE_HASH,
-                    ));
-                }
-                Ok(())
-            }
-            #[inline]
-            fn tokenize_body(&self) -> Self::DataToken<'_> {
-                (
-                    <alloy::sol_types::sol_data::Uint<8> as alloy_sol_types::SolType>::tokenize(
-                        &self.version,
-                    ),
-                )
-            }
-            #[inline]
-            fn topics(&self) -> <Self::TopicList as alloy_sol_types::SolType>::RustType {
-                (Self::SIGNATURE_HASH,)
-            }
-            #[inline]
-            fn encode_topics_raw(
-                &self,
-                out: &mut [alloy_sol_types::abi::token::WordToken],
-            ) -> alloy_sol_types::Result<()> {
-                if out.len() < <Self::TopicList as alloy_sol_types::TopicList>::COUNT {
-                    return Err(alloy_sol_types::Error::Overrun);
-                }
-                out[0usize] = alloy_sol_types::abi::token::WordToken(Self::SIGNATURE_HASH);
-                Ok(())
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::private::IntoLogData for Initialized {
-            fn to_log_data(&self) -> alloy_sol_types::private::LogData {
-                From::from(self)
-            }
-            fn into_log_data(self) -> alloy_sol_types::private::LogData {
-                From::from(&self)
-            }
-        }
-        #[automatically_derived]
-        impl From<&Initialized> for alloy_sol_types::private::LogData {
-            #[inline]
-            fn from(this: &Initialized) -> alloy_sol_types::private::LogData {
-                alloy_sol_types::SolEvent::encode_log_data(this)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Event with signature `NewG2PubkeyRegistration(address,(uint256[2],uint256[2]))` and selector `0x5c4f9f28153dbf3f00e69607a59e82ad806fffb78d09f179f62432f7e9d2511a`.
-    ```solidity
-    event NewG2PubkeyRegistration(address indexed operator, BN254.G2Point pubkeyG2);
-    ```*/
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    #[derive(Clone)]
-    pub struct NewG2PubkeyRegistration {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-        #[allow(missing_docs)]
-        pub pubkeyG2: <BN254::G2Point as alloy::sol_types::SolType>::RustType,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[automatically_derived]
-        impl alloy_sol_types::SolEvent for NewG2PubkeyRegistration {
-            type DataTuple<'a> = (BN254::G2Point,);
-            type DataToken<'a> = <Self::DataTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type TopicList = (
-                alloy_sol_types::sol_data::FixedBytes<32>,
-                alloy::sol_types::sol_data::Address,
-            );
-            const SIGNATURE: &'static str =
-                "NewG2PubkeyRegistration(address,(uint256[2],uint256[2]))";
-            const SIGNATURE_HASH: alloy_sol_types::private::B256 =
-                alloy_sol_types::private::B256::new([
-                    92u8, 79u8, 159u8, 40u8, 21u8, 61u8, 191u8, 63u8, 0u8, 230u8, 150u8, 7u8,
-                    165u8, 158u8, 130u8, 173u8, 128u8, 111u8, 255u8, 183u8, 141u8, 9u8, 241u8,
-                    121u8, 246u8, 36u8, 50u8, 247u8, 233u8, 210u8, 81u8, 26u8,
-                ]);
-            const ANONYMOUS: bool = false;
-            #[allow(unused_variables)]
-            #[inline]
-            fn new(
-                topics: <Self::TopicList as alloy_sol_types::SolType>::RustType,
-                data: <Self::DataTuple<'_> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                Self {
-                    operator: topics.1,
-                    pubkeyG2: data.0,
-                }
-            }
-            #[inline]
-            fn check_signature(
-                topics: &<Self::TopicList as alloy_sol_types::SolType>::RustType,
-            ) -> alloy_sol_types::Result<()> {
-                if topics.0 != Self::SIGNATURE_HASH {
-                    return Err(alloy_sol_types::Error::invalid_event_signature_hash(
-                        Self::SIGNATURE,
-                        topics.0,
-                        Self::SIGNATURE_HASH,
-                    ));
-                }
-                Ok(())
-            }
-            #[inline]
-            fn tokenize_body(&self) -> Self::DataToken<'_> {
-                (<BN254::G2Point as alloy_sol_types::SolType>::tokenize(
-                    &self.pubkeyG2,
-                ),)
-            }
-            #[inline]
-            fn topics(&self) -> <Self::TopicList as alloy_sol_types::SolType>::RustType {
-                (Self::SIGNATURE_HASH, self.operator)
-            }
-            #[inline]
-            fn encode_topics_raw(
-                &self,
-                out: &mut [alloy_sol_types::abi::token::WordToken],
-            ) -> alloy_sol_types::Result<()> {
-                if out.len() < <Self::TopicList as alloy_sol_types::TopicList>::COUNT {
-                    return Err(alloy_sol_types::Error::Overrun);
-                }
-                out[0usize] = alloy_sol_types::abi::token::WordToken(Self::SIGNATURE_HASH);
-                out[1usize] = <alloy::sol_types::sol_data::Address as alloy_sol_types::EventTopic>::encode_topic(
-                    &self.operator,
-                );
-                Ok(())
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::private::IntoLogData for NewG2PubkeyRegistration {
-            fn to_log_data(&self) -> alloy_sol_types::private::LogData {
-                From::from(self)
-            }
-            fn into_log_data(self) -> alloy_sol_types::private::LogData {
-                From::from(&self)
-            }
-        }
-        #[automatically_derived]
-        impl From<&NewG2PubkeyRegistration> for alloy_sol_types::private::LogData {
-            #[inline]
-            fn from(this: &NewG2PubkeyRegistration) -> alloy_sol_types::private::LogData {
-                alloy_sol_types::SolEvent::encode_log_data(this)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Event with signature `NewPubkeyRegistration(address,(uint256,uint256),(uint256[2],uint256[2]))` and selector `0xe3fb6613af2e8930cf85d47fcf6db10192224a64c6cbe8023e0eee1ba3828041`.
-    ```solidity
-    event NewPubkeyRegistration(address indexed operator, BN254.G1Point pubkeyG1, BN254.G2Point pubkeyG2);
-    ```*/
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    #[derive(Clone)]
-    pub struct NewPubkeyRegistration {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-        #[allow(missing_docs)]
-        pub pubkeyG1: <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-        #[allow(missing_docs)]
-        pub pubkeyG2: <BN254::G2Point as alloy::sol_types::SolType>::RustType,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[automatically_derived]
-        impl alloy_sol_types::SolEvent for NewPubkeyRegistration {
-            type DataTuple<'a> = (BN254::G1Point, BN254::G2Point);
-            type DataToken<'a> = <Self::DataTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type TopicList = (
-                alloy_sol_types::sol_data::FixedBytes<32>,
-                alloy::sol_types::sol_data::Address,
-            );
-            const SIGNATURE: &'static str =
-                "NewPubkeyRegistration(address,(uint256,uint256),(uint256[2],uint256[2]))";
-            const SIGNATURE_HASH: alloy_sol_types::private::B256 =
-                alloy_sol_types::private::B256::new([
-                    227u8, 251u8, 102u8, 19u8, 175u8, 46u8, 137u8, 48u8, 207u8, 133u8, 212u8,
-                    127u8, 207u8, 109u8, 177u8, 1u8, 146u8, 34u8, 74u8, 100u8, 198u8, 203u8, 232u8,
-                    2u8, 62u8, 14u8, 238u8, 27u8, 163u8, 130u8, 128u8, 65u8,
-                ]);
-            const ANONYMOUS: bool = false;
-            #[allow(unused_variables)]
-            #[inline]
-            fn new(
-                topics: <Self::TopicList as alloy_sol_types::SolType>::RustType,
-                data: <Self::DataTuple<'_> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                Self {
-                    operator: topics.1,
-                    pubkeyG1: data.0,
-                    pubkeyG2: data.1,
-                }
-            }
-            #[inline]
-            fn check_signature(
-                topics: &<Self::TopicList as alloy_sol_types::SolType>::RustType,
-            ) -> alloy_sol_types::Result<()> {
-                if topics.0 != Self::SIGNATURE_HASH {
-                    return Err(alloy_sol_types::Error::invalid_event_signature_hash(
-                        Self::SIGNATURE,
-                        topics.0,
-                        Self::SIGNATURE_HASH,
-                    ));
-                }
-                Ok(())
-            }
-            #[inline]
-            fn tokenize_body(&self) -> Self::DataToken<'_> {
-                (
-                    <BN254::G1Point as alloy_sol_types::SolType>::tokenize(&self.pubkeyG1),
-                    <BN254::G2Point as alloy_sol_types::SolType>::tokenize(&self.pubkeyG2),
-                )
-            }
-            #[inline]
-            fn topics(&self) -> <Self::TopicList as alloy_sol_types::SolType>::RustType {
-                (Self::SIGNATURE_HASH, self.operator)
-            }
-            #[inline]
-            fn encode_topics_raw(
-                &self,
-                out: &mut [alloy_sol_types::abi::token::WordToken],
-            ) -> alloy_sol_types::Result<()> {
-                if out.len() < <Self::TopicList as alloy_sol_types::TopicList>::COUNT {
-                    return Err(alloy_sol_types::Error::Overrun);
-                }
-                out[0usize] = alloy_sol_types::abi::token::WordToken(Self::SIGNATURE_HASH);
-                out[1usize] = <alloy::sol_types::sol_data::Address as alloy_sol_types::EventTopic>::encode_topic(
-                    &self.operator,
-                );
-                Ok(())
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::private::IntoLogData for NewPubkeyRegistration {
-            fn to_log_data(&self) -> alloy_sol_types::private::LogData {
-                From::from(self)
-            }
-            fn into_log_data(self) -> alloy_sol_types::private::LogData {
-                From::from(&self)
-            }
-        }
-        #[automatically_derived]
-        impl From<&NewPubkeyRegistration> for alloy_sol_types::private::LogData {
-            #[inline]
-            fn from(this: &NewPubkeyRegistration) -> alloy_sol_types::private::LogData {
-                alloy_sol_types::SolEvent::encode_log_data(this)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Event with signature `OperatorAddedToQuorums(address,bytes32,bytes)` and selector `0x73a2b7fb844724b971802ae9b15db094d4b7192df9d7350e14eb466b9b22eb4e`.
-    ```solidity
-    event OperatorAddedToQuorums(address operator, bytes32 operatorId, bytes quorumNumbers);
-    ```*/
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    #[derive(Clone)]
-    pub struct OperatorAddedToQuorums {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-        #[allow(missing_docs)]
-        pub operatorId: alloy::sol_types::private::FixedBytes<32>,
-        #[allow(missing_docs)]
-        pub quorumNumbers: alloy::sol_types::private::Bytes,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[automatically_derived]
-        impl alloy_sol_types::SolEvent for OperatorAddedToQuorums {
-            type DataTuple<'a> = (
-                alloy::sol_types::sol_data::Address,
-                alloy::sol_types::sol_data::FixedBytes<32>,
-                alloy::sol_types::sol_data::Bytes,
-            );
-            type DataToken<'a> = <Self::DataTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type TopicList = (alloy_sol_types::sol_data::FixedBytes<32>,);
-            const SIGNATURE: &'static str = "OperatorAddedToQuorums(address,bytes32,bytes)";
-            const SIGNATURE_HASH: alloy_sol_types::private::B256 =
-                alloy_sol_types::private::B256::new([
-                    115u8, 162u8, 183u8, 251u8, 132u8, 71u8, 36u8, 185u8, 113u8, 128u8, 42u8,
-                    233u8, 177u8, 93u8, 176u8, 148u8, 212u8, 183u8, 25u8, 45u8, 249u8, 215u8, 53u8,
-                    14u8, 20u8, 235u8, 70u8, 107u8, 155u8, 34u8, 235u8, 78u8,
-                ]);
-            const ANONYMOUS: bool = false;
-            #[allow(unused_variables)]
-            #[inline]
-            fn new(
-                topics: <Self::TopicList as alloy_sol_types::SolType>::RustType,
-                data: <Self::DataTuple<'_> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                Self {
-                    operator: data.0,
-                    operatorId: data.1,
-                    quorumNumbers: data.2,
-                }
-            }
-            #[inline]
-            fn check_signature(
-                topics: &<Self::TopicList as alloy_sol_types::SolType>::RustType,
-            ) -> alloy_sol_types::Result<()> {
-                if topics.0 != Self::SIGNATURE_HASH {
-                    return Err(alloy_sol_types::Error::invalid_event_signature_hash(
-                        Self::SIGNATURE,
-                        topics.0,
-                        Self::SIGNATURE_HASH,
-                    ));
-                }
-                Ok(())
-            }
-            #[inline]
-            fn tokenize_body(&self) -> Self::DataToken<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                    <alloy::sol_types::sol_data::FixedBytes<
-                        32,
-                    > as alloy_sol_types::SolType>::tokenize(&self.operatorId),
-                    <alloy::sol_types::sol_data::Bytes as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumbers,
-                    ),
-                )
-            }
-            #[inline]
-            fn topics(&self) -> <Self::TopicList as alloy_sol_types::SolType>::RustType {
-                (Self::SIGNATURE_HASH,)
-            }
-            #[inline]
-            fn encode_topics_raw(
-                &self,
-                out: &mut [alloy_sol_types::abi::token::WordToken],
-            ) -> alloy_sol_types::Result<()> {
-                if out.len() < <Self::TopicList as alloy_sol_types::TopicList>::COUNT {
-                    return Err(alloy_sol_types::Error::Overrun);
-                }
-                out[0usize] = alloy_sol_types::abi::token::WordToken(Self::SIGNATURE_HASH);
-                Ok(())
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::private::IntoLogData for OperatorAddedToQuorums {
-            fn to_log_data(&self) -> alloy_sol_types::private::LogData {
-                From::from(self)
-            }
-            fn into_log_data(self) -> alloy_sol_types::private::LogData {
-                From::from(&self)
-            }
-        }
-        #[automatically_derived]
-        impl From<&OperatorAddedToQuorums> for alloy_sol_types::private::LogData {
-            #[inline]
-            fn from(this: &OperatorAddedToQuorums) -> alloy_sol_types::private::LogData {
-                alloy_sol_types::SolEvent::encode_log_data(this)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Event with signature `OperatorRemovedFromQuorums(address,bytes32,bytes)` and selector `0xf843ecd53a563675e62107be1494fdde4a3d49aeedaf8d88c616d85346e3500e`.
-    ```solidity
-    event OperatorRemovedFromQuorums(address operator, bytes32 operatorId, bytes quorumNumbers);
-    ```*/
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    #[derive(Clone)]
-    pub struct OperatorRemovedFromQuorums {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-        #[allow(missing_docs)]
-        pub operatorId: alloy::sol_types::private::FixedBytes<32>,
-        #[allow(missing_docs)]
-        pub quorumNumbers: alloy::sol_types::private::Bytes,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        #[automatically_derived]
-        impl alloy_sol_types::SolEvent for OperatorRemovedFromQuorums {
-            type DataTuple<'a> = (
-                alloy::sol_types::sol_data::Address,
-                alloy::sol_types::sol_data::FixedBytes<32>,
-                alloy::sol_types::sol_data::Bytes,
-            );
-            type DataToken<'a> = <Self::DataTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type TopicList = (alloy_sol_types::sol_data::FixedBytes<32>,);
-            const SIGNATURE: &'static str = "OperatorRemovedFromQuorums(address,bytes32,bytes)";
-            const SIGNATURE_HASH: alloy_sol_types::private::B256 =
-                alloy_sol_types::private::B256::new([
-                    248u8, 67u8, 236u8, 213u8, 58u8, 86u8, 54u8, 117u8, 230u8, 33u8, 7u8, 190u8,
-                    20u8, 148u8, 253u8, 222u8, 74u8, 61u8, 73u8, 174u8, 237u8, 175u8, 141u8, 136u8,
-                    198u8, 22u8, 216u8, 83u8, 70u8, 227u8, 80u8, 14u8,
-                ]);
-            const ANONYMOUS: bool = false;
-            #[allow(unused_variables)]
-            #[inline]
-            fn new(
-                topics: <Self::TopicList as alloy_sol_types::SolType>::RustType,
-                data: <Self::DataTuple<'_> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                Self {
-                    operator: data.0,
-                    operatorId: data.1,
-                    quorumNumbers: data.2,
-                }
-            }
-            #[inline]
-            fn check_signature(
-                topics: &<Self::TopicList as alloy_sol_types::SolType>::RustType,
-            ) -> alloy_sol_types::Result<()> {
-                if topics.0 != Self::SIGNATURE_HASH {
-                    return Err(alloy_sol_types::Error::invalid_event_signature_hash(
-                        Self::SIGNATURE,
-                        topics.0,
-                        Self::SIGNATURE_HASH,
-                    ));
-                }
-                Ok(())
-            }
-            #[inline]
-            fn tokenize_body(&self) -> Self::DataToken<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                    <alloy::sol_types::sol_data::FixedBytes<
-                        32,
-                    > as alloy_sol_types::SolType>::tokenize(&self.operatorId),
-                    <alloy::sol_types::sol_data::Bytes as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumbers,
-                    ),
-                )
-            }
-            #[inline]
-            fn topics(&self) -> <Self::TopicList as alloy_sol_types::SolType>::RustType {
-                (Self::SIGNATURE_HASH,)
-            }
-            #[inline]
-            fn encode_topics_raw(
-                &self,
-                out: &mut [alloy_sol_types::abi::token::WordToken],
-            ) -> alloy_sol_types::Result<()> {
-                if out.len() < <Self::TopicList as alloy_sol_types::TopicList>::COUNT {
-                    return Err(alloy_sol_types::Error::Overrun);
-                }
-                out[0usize] = alloy_sol_types::abi::token::WordToken(Self::SIGNATURE_HASH);
-                Ok(())
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::private::IntoLogData for OperatorRemovedFromQuorums {
-            fn to_log_data(&self) -> alloy_sol_types::private::LogData {
-                From::from(self)
-            }
-            fn into_log_data(self) -> alloy_sol_types::private::LogData {
-                From::from(&self)
-            }
-        }
-        #[automatically_derived]
-        impl From<&OperatorRemovedFromQuorums> for alloy_sol_types::private::LogData {
-            #[inline]
-            fn from(this: &OperatorRemovedFromQuorums) -> alloy_sol_types::private::LogData {
-                alloy_sol_types::SolEvent::encode_log_data(this)
-            }
-        }
-    };
-    /**Constructor`.
-    ```solidity
-    constructor(address _slashingRegistryCoordinator);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct constructorCall {
-        #[allow(missing_docs)]
-        pub _slashingRegistryCoordinator: alloy::sol_types::private::Address,
-    }
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<constructorCall> for UnderlyingRustTuple<'_> {
-                fn from(value: constructorCall) -> Self {
-                    (value._slashingRegistryCoordinator,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for constructorCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        _slashingRegistryCoordinator: tuple.0,
-                    }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolConstructor for constructorCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Address,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self._slashingRegistryCoordinator,
-                    ),
-                )
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `apkHistory(uint8,uint256)` and selector `0x7916cea6`.
-    ```solidity
-    function apkHistory(uint8 quorumNumber, uint256) external view returns (bytes24 apkHash, uint32 updateBlockNumber, uint32 nextUpdateBlockNumber);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct apkHistoryCall {
-        #[allow(missing_docs)]
-        pub quorumNumber: u8,
-        #[allow(missing_docs)]
-        pub _1: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`apkHistory(uint8,uint256)`](apkHistoryCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct apkHistoryReturn {
-        #[allow(missing_docs)]
-        pub apkHash: alloy::sol_types::private::FixedBytes<24>,
-        #[allow(missing_docs)]
-        pub updateBlockNumber: u32,
-        #[allow(missing_docs)]
-        pub nextUpdateBlockNumber: u32,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Uint<8>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> =
-                (u8, alloy::sol_types::private::primitives::aliases::U256);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<apkHistoryCall> for UnderlyingRustTuple<'_> {
-                fn from(value: apkHistoryCall) -> Self {
-                    (value.quorumNumber, value._1)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for apkHistoryCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        quorumNumber: tuple.0,
-                        _1: tuple.1,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::FixedBytes<24>,
-                alloy::sol_types::sol_data::Uint<32>,
-                alloy::sol_types::sol_data::Uint<32>,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::FixedBytes<24>, u32, u32);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<apkHistoryReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: apkHistoryReturn) -> Self {
-                    (
-                        value.apkHash,
-                        value.updateBlockNumber,
-                        value.nextUpdateBlockNumber,
-                    )
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for apkHistoryReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        apkHash: tuple.0,
-                        updateBlockNumber: tuple.1,
-                        nextUpdateBlockNumber: tuple.2,
-                    }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for apkHistoryCall {
-            type Parameters<'a> = (
-                alloy::sol_types::sol_data::Uint<8>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = apkHistoryReturn;
-            type ReturnTuple<'a> = (
-                alloy::sol_types::sol_data::FixedBytes<24>,
-                alloy::sol_types::sol_data::Uint<32>,
-                alloy::sol_types::sol_data::Uint<32>,
-            );
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "apkHistory(uint8,uint256)";
-            const SELECTOR: [u8; 4] = [121u8, 22u8, 206u8, 166u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Uint<8> as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumber,
-                    ),
-                    <alloy::sol_types::sol_data::Uint<256> as alloy_sol_types::SolType>::tokenize(
-                        &self._1,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `currentApk(uint8)` and selector `0xa3db80e2`.
-    ```solidity
-    function currentApk(uint8 quorumNumber) external view returns (uint256 X, uint256 Y);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct currentApkCall {
-        #[allow(missing_docs)]
-        pub quorumNumber: u8,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`currentApk(uint8)`](currentApkCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct currentApkReturn {
-        #[allow(missing_docs)]
-        pub X: alloy::sol_types::private::primitives::aliases::U256,
-        #[allow(missing_docs)]
-        pub Y: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<8>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (u8,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<currentApkCall> for UnderlyingRustTuple<'_> {
-                fn from(value: currentApkCall) -> Self {
-                    (value.quorumNumber,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for currentApkCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        quorumNumber: tuple.0,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Uint<256>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                alloy::sol_types::private::primitives::aliases::U256,
-                alloy::sol_types::private::primitives::aliases::U256,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<currentApkReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: currentApkReturn) -> Self {
-                    (value.X, value.Y)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for currentApkReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        X: tuple.0,
-                        Y: tuple.1,
-                    }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for currentApkCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Uint<8>,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = currentApkReturn;
-            type ReturnTuple<'a> = (
-                alloy::sol_types::sol_data::Uint<256>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "currentApk(uint8)";
-            const SELECTOR: [u8; 4] = [163u8, 219u8, 128u8, 226u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Uint<8> as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumber,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `deregisterOperator(address,bytes)` and selector `0xf4e24fe5`.
-    ```solidity
-    function deregisterOperator(address operator, bytes memory quorumNumbers) external;
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct deregisterOperatorCall {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-        #[allow(missing_docs)]
-        pub quorumNumbers: alloy::sol_types::private::Bytes,
-    }
-    ///Container type for the return parameters of the [`deregisterOperator(address,bytes)`](deregisterOperatorCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct deregisterOperatorReturn {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Address,
-                alloy::sol_types::sol_data::Bytes,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                alloy::sol_types::private::Address,
-                alloy::sol_types::private::Bytes,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<deregisterOperatorCall> for UnderlyingRustTuple<'_> {
-                fn from(value: deregisterOperatorCall) -> Self {
-                    (value.operator, value.quorumNumbers)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for deregisterOperatorCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        operator: tuple.0,
-                        quorumNumbers: tuple.1,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = ();
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = ();
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<deregisterOperatorReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: deregisterOperatorReturn) -> Self {
-                    ()
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for deregisterOperatorReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {}
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for deregisterOperatorCall {
-            type Parameters<'a> = (
-                alloy::sol_types::sol_data::Address,
-                alloy::sol_types::sol_data::Bytes,
-            );
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = deregisterOperatorReturn;
-            type ReturnTuple<'a> = ();
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "deregisterOperator(address,bytes)";
-            const SELECTOR: [u8; 4] = [244u8, 226u8, 79u8, 229u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                    <alloy::sol_types::sol_data::Bytes as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumbers,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `getApk(uint8)` and selector `0x5f61a884`.
-    ```solidity
-    function getApk(uint8 quorumNumber) external view returns (BN254.G1Point memory);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkCall {
-        #[allow(missing_docs)]
-        pub quorumNumber: u8,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`getApk(uint8)`](getApkCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkReturn {
-        #[allow(missing_docs)]
-        pub _0: <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<8>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (u8,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkCall> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkCall) -> Self {
-                    (value.quorumNumber,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        quorumNumber: tuple.0,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (BN254::G1Point,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> =
-                (<BN254::G1Point as alloy::sol_types::SolType>::RustType,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for getApkCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Uint<8>,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = getApkReturn;
-            type ReturnTuple<'a> = (BN254::G1Point,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "getApk(uint8)";
-            const SELECTOR: [u8; 4] = [95u8, 97u8, 168u8, 132u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Uint<8> as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumber,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `getApkHashAtBlockNumberAndIndex(uint8,uint32,uint256)` and selector `0x68bccaac`.
-    ```solidity
-    function getApkHashAtBlockNumberAndIndex(uint8 quorumNumber, uint32 blockNumber, uint256 index) external view returns (bytes24);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkHashAtBlockNumberAndIndexCall {
-        #[allow(missing_docs)]
-        pub quorumNumber: u8,
-        #[allow(missing_docs)]
-        pub blockNumber: u32,
-        #[allow(missing_docs)]
-        pub index: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`getApkHashAtBlockNumberAndIndex(uint8,uint32,uint256)`](getApkHashAtBlockNumberAndIndexCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkHashAtBlockNumberAndIndexReturn {
-        #[allow(missing_docs)]
-        pub _0: alloy::sol_types::private::FixedBytes<24>,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Uint<8>,
-                alloy::sol_types::sol_data::Uint<32>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                u8,
-                u32,
-                alloy::sol_types::private::primitives::aliases::U256,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkHashAtBlockNumberAndIndexCall> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkHashAtBlockNumberAndIndexCall) -> Self {
-                    (value.quorumNumber, value.blockNumber, value.index)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkHashAtBlockNumberAndIndexCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        quorumNumber: tuple.0,
-                        blockNumber: tuple.1,
-                        index: tuple.2,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<24>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::FixedBytes<24>,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkHashAtBlockNumberAndIndexReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkHashAtBlockNumberAndIndexReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkHashAtBlockNumberAndIndexReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for getApkHashAtBlockNumberAndIndexCall {
-            type Parameters<'a> = (
-                alloy::sol_types::sol_data::Uint<8>,
-                alloy::sol_types::sol_data::Uint<32>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = getApkHashAtBlockNumberAndIndexReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<24>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "getApkHashAtBlockNumberAndIndex(uint8,uint32,uint256)";
-            const SELECTOR: [u8; 4] = [104u8, 188u8, 202u8, 172u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Uint<8> as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumber,
-                    ),
-                    <alloy::sol_types::sol_data::Uint<32> as alloy_sol_types::SolType>::tokenize(
-                        &self.blockNumber,
-                    ),
-                    <alloy::sol_types::sol_data::Uint<256> as alloy_sol_types::SolType>::tokenize(
-                        &self.index,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `getApkHistoryLength(uint8)` and selector `0x377ed99d`.
-    ```solidity
-    function getApkHistoryLength(uint8 quorumNumber) external view returns (uint32);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkHistoryLengthCall {
-        #[allow(missing_docs)]
-        pub quorumNumber: u8,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`getApkHistoryLength(uint8)`](getApkHistoryLengthCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkHistoryLengthReturn {
-        #[allow(missing_docs)]
-        pub _0: u32,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<8>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (u8,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkHistoryLengthCall> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkHistoryLengthCall) -> Self {
-                    (value.quorumNumber,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkHistoryLengthCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        quorumNumber: tuple.0,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<32>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (u32,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkHistoryLengthReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkHistoryLengthReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkHistoryLengthReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for getApkHistoryLengthCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Uint<8>,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = getApkHistoryLengthReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::Uint<32>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "getApkHistoryLength(uint8)";
-            const SELECTOR: [u8; 4] = [55u8, 126u8, 217u8, 157u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Uint<8> as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumber,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `getApkIndicesAtBlockNumber(bytes,uint256)` and selector `0xd5254a8c`.
-    ```solidity
-    function getApkIndicesAtBlockNumber(bytes memory quorumNumbers, uint256 blockNumber) external view returns (uint32[] memory);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkIndicesAtBlockNumberCall {
-        #[allow(missing_docs)]
-        pub quorumNumbers: alloy::sol_types::private::Bytes,
-        #[allow(missing_docs)]
-        pub blockNumber: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`getApkIndicesAtBlockNumber(bytes,uint256)`](getApkIndicesAtBlockNumberCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkIndicesAtBlockNumberReturn {
-        #[allow(missing_docs)]
-        pub _0: alloy::sol_types::private::Vec<u32>,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Bytes,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                alloy::sol_types::private::Bytes,
-                alloy::sol_types::private::primitives::aliases::U256,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkIndicesAtBlockNumberCall> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkIndicesAtBlockNumberCall) -> Self {
-                    (value.quorumNumbers, value.blockNumber)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkIndicesAtBlockNumberCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        quorumNumbers: tuple.0,
-                        blockNumber: tuple.1,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> =
-                (alloy::sol_types::sol_data::Array<alloy::sol_types::sol_data::Uint<32>>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Vec<u32>,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkIndicesAtBlockNumberReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkIndicesAtBlockNumberReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkIndicesAtBlockNumberReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for getApkIndicesAtBlockNumberCall {
-            type Parameters<'a> = (
-                alloy::sol_types::sol_data::Bytes,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = getApkIndicesAtBlockNumberReturn;
-            type ReturnTuple<'a> =
-                (alloy::sol_types::sol_data::Array<alloy::sol_types::sol_data::Uint<32>>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "getApkIndicesAtBlockNumber(bytes,uint256)";
-            const SELECTOR: [u8; 4] = [213u8, 37u8, 74u8, 140u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Bytes as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumbers,
-                    ),
-                    <alloy::sol_types::sol_data::Uint<256> as alloy_sol_types::SolType>::tokenize(
-                        &self.blockNumber,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `getApkUpdateAtIndex(uint8,uint256)` and selector `0x605747d5`.
-    ```solidity
-    function getApkUpdateAtIndex(uint8 quorumNumber, uint256 index) external view returns (IBLSApkRegistryTypes.ApkUpdate memory);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkUpdateAtIndexCall {
-        #[allow(missing_docs)]
-        pub quorumNumber: u8,
-        #[allow(missing_docs)]
-        pub index: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`getApkUpdateAtIndex(uint8,uint256)`](getApkUpdateAtIndexCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getApkUpdateAtIndexReturn {
-        #[allow(missing_docs)]
-        pub _0: <IBLSApkRegistryTypes::ApkUpdate as alloy::sol_types::SolType>::RustType,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Uint<8>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> =
-                (u8, alloy::sol_types::private::primitives::aliases::U256);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkUpdateAtIndexCall> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkUpdateAtIndexCall) -> Self {
-                    (value.quorumNumber, value.index)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkUpdateAtIndexCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        quorumNumber: tuple.0,
-                        index: tuple.1,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (IBLSApkRegistryTypes::ApkUpdate,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> =
-                (<IBLSApkRegistryTypes::ApkUpdate as alloy::sol_types::SolType>::RustType,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getApkUpdateAtIndexReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: getApkUpdateAtIndexReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getApkUpdateAtIndexReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for getApkUpdateAtIndexCall {
-            type Parameters<'a> = (
-                alloy::sol_types::sol_data::Uint<8>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = getApkUpdateAtIndexReturn;
-            type ReturnTuple<'a> = (IBLSApkRegistryTypes::ApkUpdate,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "getApkUpdateAtIndex(uint8,uint256)";
-            const SELECTOR: [u8; 4] = [96u8, 87u8, 71u8, 213u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Uint<8> as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumber,
-                    ),
-                    <alloy::sol_types::sol_data::Uint<256> as alloy_sol_types::SolType>::tokenize(
-                        &self.index,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `getOperatorFromPubkeyHash(bytes32)` and selector `0x47b314e8`.
-    ```solidity
-    function getOperatorFromPubkeyHash(bytes32 pubkeyHash) external view returns (address);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getOperatorFromPubkeyHashCall {
-        #[allow(missing_docs)]
-        pub pubkeyHash: alloy::sol_types::private::FixedBytes<32>,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`getOperatorFromPubkeyHash(bytes32)`](getOperatorFromPubkeyHashCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getOperatorFromPubkeyHashReturn {
-        #[allow(missing_docs)]
-        pub _0: alloy::sol_types::private::Address,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::FixedBytes<32>,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getOperatorFromPubkeyHashCall> for UnderlyingRustTuple<'_> {
-                fn from(value: getOperatorFromPubkeyHashCall) -> Self {
-                    (value.pubkeyHash,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getOperatorFromPubkeyHashCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        pubkeyHash: tuple.0,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getOperatorFromPubkeyHashReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: getOperatorFromPubkeyHashReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getOperatorFromPubkeyHashReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for getOperatorFromPubkeyHashCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = getOperatorFromPubkeyHashReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "getOperatorFromPubkeyHash(bytes32)";
-            const SELECTOR: [u8; 4] = [71u8, 179u8, 20u8, 232u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::FixedBytes<
-                        32,
-                    > as alloy_sol_types::SolType>::tokenize(&self.pubkeyHash),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `getOperatorId(address)` and selector `0x13542a4e`.
-    ```solidity
-    function getOperatorId(address operator) external view returns (bytes32);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getOperatorIdCall {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`getOperatorId(address)`](getOperatorIdCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getOperatorIdReturn {
-        #[allow(missing_docs)]
-        pub _0: alloy::sol_types::private::FixedBytes<32>,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getOperatorIdCall> for UnderlyingRustTuple<'_> {
-                fn from(value: getOperatorIdCall) -> Self {
-                    (value.operator,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getOperatorIdCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { operator: tuple.0 }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::FixedBytes<32>,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getOperatorIdReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: getOperatorIdReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getOperatorIdReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for getOperatorIdCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Address,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = getOperatorIdReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "getOperatorId(address)";
-            const SELECTOR: [u8; 4] = [19u8, 84u8, 42u8, 78u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `getOperatorPubkeyG2(address)` and selector `0x67169911`.
-    ```solidity
-    function getOperatorPubkeyG2(address operator) external view returns (BN254.G2Point memory);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getOperatorPubkeyG2Call {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`getOperatorPubkeyG2(address)`](getOperatorPubkeyG2Call) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getOperatorPubkeyG2Return {
-        #[allow(missing_docs)]
-        pub _0: <BN254::G2Point as alloy::sol_types::SolType>::RustType,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getOperatorPubkeyG2Call> for UnderlyingRustTuple<'_> {
-                fn from(value: getOperatorPubkeyG2Call) -> Self {
-                    (value.operator,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getOperatorPubkeyG2Call {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { operator: tuple.0 }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (BN254::G2Point,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> =
-                (<BN254::G2Point as alloy::sol_types::SolType>::RustType,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getOperatorPubkeyG2Return> for UnderlyingRustTuple<'_> {
-                fn from(value: getOperatorPubkeyG2Return) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getOperatorPubkeyG2Return {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for getOperatorPubkeyG2Call {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Address,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = getOperatorPubkeyG2Return;
-            type ReturnTuple<'a> = (BN254::G2Point,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "getOperatorPubkeyG2(address)";
-            const SELECTOR: [u8; 4] = [103u8, 22u8, 153u8, 17u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `getRegisteredPubkey(address)` and selector `0x7ff81a87`.
-    ```solidity
-    function getRegisteredPubkey(address operator) external view returns (BN254.G1Point memory, bytes32);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getRegisteredPubkeyCall {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`getRegisteredPubkey(address)`](getRegisteredPubkeyCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct getRegisteredPubkeyReturn {
-        #[allow(missing_docs)]
-        pub _0: <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-        #[allow(missing_docs)]
-        pub _1: alloy::sol_types::private::FixedBytes<32>,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getRegisteredPubkeyCall> for UnderlyingRustTuple<'_> {
-                fn from(value: getRegisteredPubkeyCall) -> Self {
-                    (value.operator,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getRegisteredPubkeyCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { operator: tuple.0 }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> =
-                (BN254::G1Point, alloy::sol_types::sol_data::FixedBytes<32>);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-                alloy::sol_types::private::FixedBytes<32>,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<getRegisteredPubkeyReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: getRegisteredPubkeyReturn) -> Self {
-                    (value._0, value._1)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for getRegisteredPubkeyReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        _0: tuple.0,
-                        _1: tuple.1,
-                    }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for getRegisteredPubkeyCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Address,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = getRegisteredPubkeyReturn;
-            type ReturnTuple<'a> = (BN254::G1Point, alloy::sol_types::sol_data::FixedBytes<32>);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "getRegisteredPubkey(address)";
-            const SELECTOR: [u8; 4] = [127u8, 248u8, 26u8, 135u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `initializeQuorum(uint8)` and selector `0x26d941f2`.
-    ```solidity
-    function initializeQuorum(uint8 quorumNumber) external;
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct initializeQuorumCall {
-        #[allow(missing_docs)]
-        pub quorumNumber: u8,
-    }
-    ///Container type for the return parameters of the [`initializeQuorum(uint8)`](initializeQuorumCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct initializeQuorumReturn {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<8>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (u8,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<initializeQuorumCall> for UnderlyingRustTuple<'_> {
-                fn from(value: initializeQuorumCall) -> Self {
-                    (value.quorumNumber,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for initializeQuorumCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        quorumNumber: tuple.0,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = ();
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = ();
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<initializeQuorumReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: initializeQuorumReturn) -> Self {
-                    ()
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for initializeQuorumReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {}
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for initializeQuorumCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Uint<8>,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = initializeQuorumReturn;
-            type ReturnTuple<'a> = ();
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "initializeQuorum(uint8)";
-            const SELECTOR: [u8; 4] = [38u8, 217u8, 65u8, 242u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Uint<8> as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumber,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `operatorToPubkey(address)` and selector `0x00a1f4cb`.
-    ```solidity
-    function operatorToPubkey(address operator) external view returns (uint256 X, uint256 Y);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct operatorToPubkeyCall {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`operatorToPubkey(address)`](operatorToPubkeyCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct operatorToPubkeyReturn {
-        #[allow(missing_docs)]
-        pub X: alloy::sol_types::private::primitives::aliases::U256,
-        #[allow(missing_docs)]
-        pub Y: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<operatorToPubkeyCall> for UnderlyingRustTuple<'_> {
-                fn from(value: operatorToPubkeyCall) -> Self {
-                    (value.operator,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for operatorToPubkeyCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { operator: tuple.0 }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Uint<256>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                alloy::sol_types::private::primitives::aliases::U256,
-                alloy::sol_types::private::primitives::aliases::U256,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<operatorToPubkeyReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: operatorToPubkeyReturn) -> Self {
-                    (value.X, value.Y)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for operatorToPubkeyReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        X: tuple.0,
-                        Y: tuple.1,
-                    }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for operatorToPubkeyCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Address,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = operatorToPubkeyReturn;
-            type ReturnTuple<'a> = (
-                alloy::sol_types::sol_data::Uint<256>,
-                alloy::sol_types::sol_data::Uint<256>,
-            );
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "operatorToPubkey(address)";
-            const SELECTOR: [u8; 4] = [0u8, 161u8, 244u8, 203u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `operatorToPubkeyHash(address)` and selector `0xde29fac0`.
-    ```solidity
-    function operatorToPubkeyHash(address operator) external view returns (bytes32 operatorId);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct operatorToPubkeyHashCall {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`operatorToPubkeyHash(address)`](operatorToPubkeyHashCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct operatorToPubkeyHashReturn {
-        #[allow(missing_docs)]
-        pub operatorId: alloy::sol_types::private::FixedBytes<32>,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<operatorToPubkeyHashCall> for UnderlyingRustTuple<'_> {
-                fn from(value: operatorToPubkeyHashCall) -> Self {
-                    (value.operator,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for operatorToPubkeyHashCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { operator: tuple.0 }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::FixedBytes<32>,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<operatorToPubkeyHashReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: operatorToPubkeyHashReturn) -> Self {
-                    (value.operatorId,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for operatorToPubkeyHashReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        operatorId: tuple.0,
-                    }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for operatorToPubkeyHashCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Address,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = operatorToPubkeyHashReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "operatorToPubkeyHash(address)";
-            const SELECTOR: [u8; 4] = [222u8, 41u8, 250u8, 192u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `pubkeyHashToOperator(bytes32)` and selector `0xe8bb9ae6`.
-    ```solidity
-    function pubkeyHashToOperator(bytes32 pubkeyHash) external view returns (address operator);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct pubkeyHashToOperatorCall {
-        #[allow(missing_docs)]
-        pub pubkeyHash: alloy::sol_types::private::FixedBytes<32>,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`pubkeyHashToOperator(bytes32)`](pubkeyHashToOperatorCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct pubkeyHashToOperatorReturn {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::FixedBytes<32>,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<pubkeyHashToOperatorCall> for UnderlyingRustTuple<'_> {
-                fn from(value: pubkeyHashToOperatorCall) -> Self {
-                    (value.pubkeyHash,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for pubkeyHashToOperatorCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        pubkeyHash: tuple.0,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<pubkeyHashToOperatorReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: pubkeyHashToOperatorReturn) -> Self {
-                    (value.operator,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for pubkeyHashToOperatorReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { operator: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for pubkeyHashToOperatorCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = pubkeyHashToOperatorReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "pubkeyHashToOperator(bytes32)";
-            const SELECTOR: [u8; 4] = [232u8, 187u8, 154u8, 230u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::FixedBytes<
-                        32,
-                    > as alloy_sol_types::SolType>::tokenize(&self.pubkeyHash),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `registerBLSPublicKey(address,((uint256,uint256),(uint256,uint256),(uint256[2],uint256[2])),(uint256,uint256))` and selector `0xbf79ce58`.
-    ```solidity
-    function registerBLSPublicKey(address operator, IBLSApkRegistryTypes.PubkeyRegistrationParams memory params, BN254.G1Point memory pubkeyRegistrationMessageHash) external returns (bytes32 operatorId);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct registerBLSPublicKeyCall {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-        #[allow(missing_docs)]
-        pub params:
-            <IBLSApkRegistryTypes::PubkeyRegistrationParams as alloy::sol_types::SolType>::RustType,
-        #[allow(missing_docs)]
-        pub pubkeyRegistrationMessageHash: <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-    }
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`registerBLSPublicKey(address,((uint256,uint256),(uint256,uint256),(uint256[2],uint256[2])),(uint256,uint256))`](registerBLSPublicKeyCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct registerBLSPublicKeyReturn {
-        #[allow(missing_docs)]
-        pub operatorId: alloy::sol_types::private::FixedBytes<32>,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Address,
-                IBLSApkRegistryTypes::PubkeyRegistrationParams,
-                BN254::G1Point,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                alloy::sol_types::private::Address,
-                <IBLSApkRegistryTypes::PubkeyRegistrationParams as alloy::sol_types::SolType>::RustType,
-                <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<registerBLSPublicKeyCall> for UnderlyingRustTuple<'_> {
-                fn from(value: registerBLSPublicKeyCall) -> Self {
-                    (
-                        value.operator,
-                        value.params,
-                        value.pubkeyRegistrationMessageHash,
-                    )
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for registerBLSPublicKeyCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        operator: tuple.0,
-                        params: tuple.1,
-                        pubkeyRegistrationMessageHash: tuple.2,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::FixedBytes<32>,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<registerBLSPublicKeyReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: registerBLSPublicKeyReturn) -> Self {
-                    (value.operatorId,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for registerBLSPublicKeyReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        operatorId: tuple.0,
-                    }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for registerBLSPublicKeyCall {
-            type Parameters<'a> = (
-                alloy::sol_types::sol_data::Address,
-                IBLSApkRegistryTypes::PubkeyRegistrationParams,
-                BN254::G1Point,
-            );
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = registerBLSPublicKeyReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::FixedBytes<32>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "registerBLSPublicKey(address,((uint256,uint256),(uint256,uint256),(uint256[2],uint256[2])),(uint256,uint256))";
-            const SELECTOR: [u8; 4] = [191u8, 121u8, 206u8, 88u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                    <IBLSApkRegistryTypes::PubkeyRegistrationParams as alloy_sol_types::SolType>::tokenize(
-                        &self.params,
-                    ),
-                    <BN254::G1Point as alloy_sol_types::SolType>::tokenize(
-                        &self.pubkeyRegistrationMessageHash,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `registerOperator(address,bytes)` and selector `0x3fb27952`.
-    ```solidity
-    function registerOperator(address operator, bytes memory quorumNumbers) external;
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct registerOperatorCall {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-        #[allow(missing_docs)]
-        pub quorumNumbers: alloy::sol_types::private::Bytes,
-    }
-    ///Container type for the return parameters of the [`registerOperator(address,bytes)`](registerOperatorCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct registerOperatorReturn {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Address,
-                alloy::sol_types::sol_data::Bytes,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                alloy::sol_types::private::Address,
-                alloy::sol_types::private::Bytes,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<registerOperatorCall> for UnderlyingRustTuple<'_> {
-                fn from(value: registerOperatorCall) -> Self {
-                    (value.operator, value.quorumNumbers)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for registerOperatorCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        operator: tuple.0,
-                        quorumNumbers: tuple.1,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = ();
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = ();
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<registerOperatorReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: registerOperatorReturn) -> Self {
-                    ()
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for registerOperatorReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {}
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for registerOperatorCall {
-            type Parameters<'a> = (
-                alloy::sol_types::sol_data::Address,
-                alloy::sol_types::sol_data::Bytes,
-            );
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = registerOperatorReturn;
-            type ReturnTuple<'a> = ();
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "registerOperator(address,bytes)";
-            const SELECTOR: [u8; 4] = [63u8, 178u8, 121u8, 82u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                    <alloy::sol_types::sol_data::Bytes as alloy_sol_types::SolType>::tokenize(
-                        &self.quorumNumbers,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `registryCoordinator()` and selector `0x6d14a987`.
-    ```solidity
-    function registryCoordinator() external view returns (address);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct registryCoordinatorCall {}
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    ///Container type for the return parameters of the [`registryCoordinator()`](registryCoordinatorCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct registryCoordinatorReturn {
-        #[allow(missing_docs)]
-        pub _0: alloy::sol_types::private::Address,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = ();
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = ();
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<registryCoordinatorCall> for UnderlyingRustTuple<'_> {
-                fn from(value: registryCoordinatorCall) -> Self {
-                    ()
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for registryCoordinatorCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {}
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<registryCoordinatorReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: registryCoordinatorReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for registryCoordinatorReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for registryCoordinatorCall {
-            type Parameters<'a> = ();
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = registryCoordinatorReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "registryCoordinator()";
-            const SELECTOR: [u8; 4] = [109u8, 20u8, 169u8, 135u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Eq, Hash)]
-    /**Function with signature `verifyAndRegisterG2PubkeyForOperator(address,(uint256[2],uint256[2]))` and selector `0xd1a64650`.
-    ```solidity
-    function verifyAndRegisterG2PubkeyForOperator(address operator, BN254.G2Point memory pubkeyG2) external;
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct verifyAndRegisterG2PubkeyForOperatorCall {
-        #[allow(missing_docs)]
-        pub operator: alloy::sol_types::private::Address,
-        #[allow(missing_docs)]
-        pub pubkeyG2: <BN254::G2Point as alloy::sol_types::SolType>::RustType,
-    }
-    ///Container type for the return parameters of the [`verifyAndRegisterG2PubkeyForOperator(address,(uint256[2],uint256[2]))`](verifyAndRegisterG2PubkeyForOperatorCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct verifyAndRegisterG2PubkeyForOperatorReturn {}
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address, BN254::G2Point);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                alloy::sol_types::private::Address,
-                <BN254::G2Point as alloy::sol_types::SolType>::RustType,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<verifyAndRegisterG2PubkeyForOperatorCall> for UnderlyingRustTuple<'_> {
-                fn from(value: verifyAndRegisterG2PubkeyForOperatorCall) -> Self {
-                    (value.operator, value.pubkeyG2)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for verifyAndRegisterG2PubkeyForOperatorCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        operator: tuple.0,
-                        pubkeyG2: tuple.1,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = ();
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = ();
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<verifyAndRegisterG2PubkeyForOperatorReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: verifyAndRegisterG2PubkeyForOperatorReturn) -> Self {
-                    ()
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for verifyAndRegisterG2PubkeyForOperatorReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {}
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for verifyAndRegisterG2PubkeyForOperatorCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Address, BN254::G2Point);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = verifyAndRegisterG2PubkeyForOperatorReturn;
-            type ReturnTuple<'a> = ();
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str =
-                "verifyAndRegisterG2PubkeyForOperator(address,(uint256[2],uint256[2]))";
-            const SELECTOR: [u8; 4] = [209u8, 166u8, 70u8, 80u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.operator,
-                    ),
-                    <BN254::G2Point as alloy_sol_types::SolType>::tokenize(&self.pubkeyG2),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    ///Container for all the [`BLSApkRegistry`](self) function calls.
-    #[derive(serde::Serialize, serde::Deserialize)]
-    pub enum BLSApkRegistryCalls {
-        #[allow(missing_docs)]
-        apkHistory(apkHistoryCall),
-        #[allow(missing_docs)]
-        currentApk(currentApkCall),
-        #[allow(missing_docs)]
-        deregisterOperator(deregisterOperatorCall),
-        #[allow(missing_docs)]
-        getApk(getApkCall),
-        #[allow(missing_docs)]
-        getApkHashAtBlockNumberAndIndex(getApkHashAtBlockNumberAndIndexCall),
-        #[allow(missing_docs)]
-        getApkHistoryLength(getApkHistoryLengthCall),
-        #[allow(missing_docs)]
-        getApkIndicesAtBlockNumber(getApkIndicesAtBlockNumberCall),
-        #[allow(missing_docs)]
-        getApkUpdateAtIndex(getApkUpdateAtIndexCall),
-        #[allow(missing_docs)]
-        getOperatorFromPubkeyHash(getOperatorFromPubkeyHashCall),
-        #[allow(missing_docs)]
-        getOperatorId(getOperatorIdCall),
-        #[allow(missing_docs)]
-        getOperatorPubkeyG2(getOperatorPubkeyG2Call),
-        #[allow(missing_docs)]
-        getRegisteredPubkey(getRegisteredPubkeyCall),
-        #[allow(missing_docs)]
-        initializeQuorum(initializeQuorumCall),
-        #[allow(missing_docs)]
-        operatorToPubkey(operatorToPubkeyCall),
-        #[allow(missing_docs)]
-        operatorToPubkeyHash(operatorToPubkeyHashCall),
-        #[allow(missing_docs)]
-        pubkeyHashToOperator(pubkeyHashToOperatorCall),
-        #[allow(missing_docs)]
-        registerBLSPublicKey(registerBLSPublicKeyCall),
-        #[allow(missing_docs)]
-        registerOperator(registerOperatorCall),
-        #[allow(missing_docs)]
-        registryCoordinator(registryCoordinatorCall),
-        #[allow(missing_docs)]
-        verifyAndRegisterG2PubkeyForOperator(verifyAndRegisterG2PubkeyForOperatorCall),
-    }
-    #[automatically_derived]
-    impl BLSApkRegistryCalls {
-        /// All the selectors of this enum.
-        ///
-        /// Note that the selectors might not be in the same order as the variants.
-        /// No guarantees are made about the order of the selectors.
-        ///
-        /// Prefer using `SolInterface` methods instead.
-        pub const SELECTORS: &'static [[u8; 4usize]] = &[
-            [0u8, 161u8, 244u8, 203u8],
-            [19u8, 84u8, 42u8, 78u8],
-            [38u8, 217u8, 65u8, 242u8],
-            [55u8, 126u8, 217u8, 157u8],
-            [63u8, 178u8, 121u8, 82u8],
-            [71u8, 179u8, 20u8, 232u8],
-            [95u8, 97u8, 168u8, 132u8],
-            [96u8, 87u8, 71u8, 213u8],
-            [103u8, 22u8, 153u8, 17u8],
-            [104u8, 188u8, 202u8, 172u8],
-            [109u8, 20u8, 169u8, 135u8],
-            [121u8, 22u8, 206u8, 166u8],
-            [127u8, 248u8, 26u8, 135u8],
-            [163u8, 219u8, 128u8, 226u8],
-            [191u8, 121u8, 206u8, 88u8],
-            [209u8, 166u8, 70u8, 80u8],
-            [213u8, 37u8, 74u8, 140u8],
-            [222u8, 41u8, 250u8, 192u8],
-            [232u8, 187u8, 154u8, 230u8],
-            [244u8, 226u8, 79u8, 229u8],
-        ];
-    }
-    #[automatically_derived]
-    impl alloy_sol_types::SolInterface for BLSApkRegistryCalls {
-        const NAME: &'static str = "BLSApkRegistryCalls";
-        const MIN_DATA_LENGTH: usize = 0usize;
-        const COUNT: usize = 20usize;
-        #[inline]
-        fn selector(&self) -> [u8; 4] {
-            match self {
-                Self::apkHistory(_) => <apkHistoryCall as alloy_sol_types::SolCall>::SELECTOR,
-                Self::currentApk(_) => <currentApkCall as alloy_sol_types::SolCall>::SELECTOR,
-                Self::deregisterOperator(_) => {
-                    <deregisterOperatorCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::getApk(_) => <getApkCall as alloy_sol_types::SolCall>::SELECTOR,
-                Self::getApkHashAtBlockNumberAndIndex(_) => {
-                    <getApkHashAtBlockNumberAndIndexCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::getApkHistoryLength(_) => {
-                    <getApkHistoryLengthCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::getApkIndicesAtBlockNumber(_) => {
-                    <getApkIndicesAtBlockNumberCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::getApkUpdateAtIndex(_) => {
-                    <getApkUpdateAtIndexCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::getOperatorFromPubkeyHash(_) => {
-                    <getOperatorFromPubkeyHashCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::getOperatorId(_) => <getOperatorIdCall as alloy_sol_types::SolCall>::SELECTOR,
-                Self::getOperatorPubkeyG2(_) => {
-                    <getOperatorPubkeyG2Call as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::getRegisteredPubkey(_) => {
-                    <getRegisteredPubkeyCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::initializeQuorum(_) => {
-                    <initializeQuorumCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::operatorToPubkey(_) => {
-                    <operatorToPubkeyCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::operatorToPubkeyHash(_) => {
-                    <operatorToPubkeyHashCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::pubkeyHashToOperator(_) => {
-                    <pubkeyHashToOperatorCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::registerBLSPublicKey(_) => {
-                    <registerBLSPublicKeyCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::registerOperator(_) => {
-                    <registerOperatorCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::registryCoordinator(_) => {
-                    <registryCoordinatorCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::verifyAndRegisterG2PubkeyForOperator(_) => {
-                    <verifyAndRegisterG2PubkeyForOperatorCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-            }
-        }
-        #[inline]
-        fn selector_at(i: usize) -> ::core::option::Option<[u8; 4]> {
-            Self::SELECTORS.get(i).copied()
-        }
-        #[inline]
-        fn valid_selector(selector: [u8; 4]) -> bool {
-            Self::SELECTORS.binary_search(&selector).is_ok()
-        }
-        #[inline]
-        #[allow(non_snake_case)]
-        fn abi_decode_raw(
-            selector: [u8; 4],
-            data: &[u8],
-            validate: bool,
-        ) -> alloy_sol_types::Result<Self> {
-            static DECODE_SHIMS: &[fn(
-                &[u8],
-                bool,
-            )
-                -> alloy_sol_types::Result<BLSApkRegistryCalls>] = &[
-                {
-                    fn operatorToPubkey(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <operatorToPubkeyCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::operatorToPubkey)
-                    }
-                    operatorToPubkey
-                },
-                {
-                    fn getOperatorId(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <getOperatorIdCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::getOperatorId)
-                    }
-                    getOperatorId
-                },
-                {
-                    fn initializeQuorum(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <initializeQuorumCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::initializeQuorum)
-                    }
-                    initializeQuorum
-                },
-                {
-                    fn getApkHistoryLength(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <getApkHistoryLengthCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::getApkHistoryLength)
-                    }
-                    getApkHistoryLength
-                },
-                {
-                    fn registerOperator(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <registerOperatorCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::registerOperator)
-                    }
-                    registerOperator
-                },
-                {
-                    fn getOperatorFromPubkeyHash(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <getOperatorFromPubkeyHashCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::getOperatorFromPubkeyHash)
-                    }
-                    getOperatorFromPubkeyHash
-                },
-                {
-                    fn getApk(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <getApkCall as alloy_sol_types::SolCall>::abi_decode_raw(data, validate)
-                            .map(BLSApkRegistryCalls::getApk)
-                    }
-                    getApk
-                },
-                {
-                    fn getApkUpdateAtIndex(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <getApkUpdateAtIndexCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::getApkUpdateAtIndex)
-                    }
-                    getApkUpdateAtIndex
-                },
-                {
-                    fn getOperatorPubkeyG2(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <getOperatorPubkeyG2Call as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::getOperatorPubkeyG2)
-                    }
-                    getOperatorPubkeyG2
-                },
-                {
-                    fn getApkHashAtBlockNumberAndIndex(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <getApkHashAtBlockNumberAndIndexCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                                data,
-                                validate,
-                            )
-                            .map(BLSApkRegistryCalls::getApkHashAtBlockNumberAndIndex)
-                    }
-                    getApkHashAtBlockNumberAndIndex
-                },
-                {
-                    fn registryCoordinator(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <registryCoordinatorCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::registryCoordinator)
-                    }
-                    registryCoordinator
-                },
-                {
-                    fn apkHistory(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <apkHistoryCall as alloy_sol_types::SolCall>::abi_decode_raw(data, validate)
-                            .map(BLSApkRegistryCalls::apkHistory)
-                    }
-                    apkHistory
-                },
-                {
-                    fn getRegisteredPubkey(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <getRegisteredPubkeyCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::getRegisteredPubkey)
-                    }
-                    getRegisteredPubkey
-                },
-                {
-                    fn currentApk(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <currentApkCall as alloy_sol_types::SolCall>::abi_decode_raw(data, validate)
-                            .map(BLSApkRegistryCalls::currentApk)
-                    }
-                    currentApk
-                },
-                {
-                    fn registerBLSPublicKey(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <registerBLSPublicKeyCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::registerBLSPublicKey)
-                    }
-                    registerBLSPublicKey
-                },
-                {
-                    fn verifyAndRegisterG2PubkeyForOperator(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <verifyAndRegisterG2PubkeyForOperatorCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                                data,
-                                validate,
-                            )
-                            .map(
-                                BLSApkRegistryCalls::verifyAndRegisterG2PubkeyForOperator,
-                            )
-                    }
-                    verifyAndRegisterG2PubkeyForOperator
-                },
-                {
-                    fn getApkIndicesAtBlockNumber(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <getApkIndicesAtBlockNumberCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                                data,
-                                validate,
-                            )
-                            .map(BLSApkRegistryCalls::getApkIndicesAtBlockNumber)
-                    }
-                    getApkIndicesAtBlockNumber
-                },
-                {
-                    fn operatorToPubkeyHash(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <operatorToPubkeyHashCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::operatorToPubkeyHash)
-                    }
-                    operatorToPubkeyHash
-                },
-                {
-                    fn pubkeyHashToOperator(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <pubkeyHashToOperatorCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::pubkeyHashToOperator)
-                    }
-                    pubkeyHashToOperator
-                },
-                {
-                    fn deregisterOperator(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryCalls> {
-                        <deregisterOperatorCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryCalls::deregisterOperator)
-                    }
-                    deregisterOperator
-                },
-            ];
-            let Ok(idx) = Self::SELECTORS.binary_search(&selector) else {
-                return Err(alloy_sol_types::Error::unknown_selector(
-                    <Self as alloy_sol_types::SolInterface>::NAME,
-                    selector,
-                ));
-            };
-            DECODE_SHIMS[idx](data, validate)
-        }
-        #[inline]
-        fn abi_encoded_size(&self) -> usize {
-            match self {
-                Self::apkHistory(inner) => {
-                    <apkHistoryCall as alloy_sol_types::SolCall>::abi_encoded_size(inner)
-                }
-                Self::currentApk(inner) => {
-                    <currentApkCall as alloy_sol_types::SolCall>::abi_encoded_size(inner)
-                }
-                Self::deregisterOperator(inner) => {
-                    <deregisterOperatorCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::getApk(inner) => {
-                    <getApkCall as alloy_sol_types::SolCall>::abi_encoded_size(inner)
-                }
-                Self::getApkHashAtBlockNumberAndIndex(inner) => {
-                    <getApkHashAtBlockNumberAndIndexCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::getApkHistoryLength(inner) => {
-                    <getApkHistoryLengthCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::getApkIndicesAtBlockNumber(inner) => {
-                    <getApkIndicesAtBlockNumberCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::getApkUpdateAtIndex(inner) => {
-                    <getApkUpdateAtIndexCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::getOperatorFromPubkeyHash(inner) => {
-                    <getOperatorFromPubkeyHashCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::getOperatorId(inner) => {
-                    <getOperatorIdCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::getOperatorPubkeyG2(inner) => {
-                    <getOperatorPubkeyG2Call as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::getRegisteredPubkey(inner) => {
-                    <getRegisteredPubkeyCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::initializeQuorum(inner) => {
-                    <initializeQuorumCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::operatorToPubkey(inner) => {
-                    <operatorToPubkeyCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::operatorToPubkeyHash(inner) => {
-                    <operatorToPubkeyHashCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::pubkeyHashToOperator(inner) => {
-                    <pubkeyHashToOperatorCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::registerBLSPublicKey(inner) => {
-                    <registerBLSPublicKeyCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::registerOperator(inner) => {
-                    <registerOperatorCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::registryCoordinator(inner) => {
-                    <registryCoordinatorCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::verifyAndRegisterG2PubkeyForOperator(inner) => {
-                    <verifyAndRegisterG2PubkeyForOperatorCall as alloy_sol_types::SolCall>::abi_encoded_size(
-                        inner,
-                    )
-                }
-            }
-        }
-        #[inline]
-        fn abi_encode_raw(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-            match self {
-                Self::apkHistory(inner) => {
-                    <apkHistoryCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::currentApk(inner) => {
-                    <currentApkCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::deregisterOperator(inner) => {
-                    <deregisterOperatorCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::getApk(inner) => {
-                    <getApkCall as alloy_sol_types::SolCall>::abi_encode_raw(inner, out)
-                }
-                Self::getApkHashAtBlockNumberAndIndex(inner) => {
-                    <getApkHashAtBlockNumberAndIndexCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::getApkHistoryLength(inner) => {
-                    <getApkHistoryLengthCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::getApkIndicesAtBlockNumber(inner) => {
-                    <getApkIndicesAtBlockNumberCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::getApkUpdateAtIndex(inner) => {
-                    <getApkUpdateAtIndexCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::getOperatorFromPubkeyHash(inner) => {
-                    <getOperatorFromPubkeyHashCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::getOperatorId(inner) => {
-                    <getOperatorIdCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::getOperatorPubkeyG2(inner) => {
-                    <getOperatorPubkeyG2Call as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::getRegisteredPubkey(inner) => {
-                    <getRegisteredPubkeyCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::initializeQuorum(inner) => {
-                    <initializeQuorumCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::operatorToPubkey(inner) => {
-                    <operatorToPubkeyCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::operatorToPubkeyHash(inner) => {
-                    <operatorToPubkeyHashCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::pubkeyHashToOperator(inner) => {
-                    <pubkeyHashToOperatorCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::registerBLSPublicKey(inner) => {
-                    <registerBLSPublicKeyCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::registerOperator(inner) => {
-                    <registerOperatorCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::registryCoordinator(inner) => {
-                    <registryCoordinatorCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-                Self::verifyAndRegisterG2PubkeyForOperator(inner) => {
-                    <verifyAndRegisterG2PubkeyForOperatorCall as alloy_sol_types::SolCall>::abi_encode_raw(
-                        inner,
-                        out,
-                    )
-                }
-            }
-        }
-    }
-    ///Container for all the [`BLSApkRegistry`](self) custom errors.
-    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Hash)]
-    pub enum BLSApkRegistryErrors {
-        #[allow(missing_docs)]
-        BLSPubkeyAlreadyRegistered(BLSPubkeyAlreadyRegistered),
-        #[allow(missing_docs)]
-        BlockNumberBeforeFirstUpdate(BlockNumberBeforeFirstUpdate),
-        #[allow(missing_docs)]
-        BlockNumberNotLatest(BlockNumberNotLatest),
-        #[allow(missing_docs)]
-        BlockNumberTooRecent(BlockNumberTooRecent),
-        #[allow(missing_docs)]
-        ECAddFailed(ECAddFailed),
-        #[allow(missing_docs)]
-        ECMulFailed(ECMulFailed),
-        #[allow(missing_docs)]
-        ECPairingFailed(ECPairingFailed),
-        #[allow(missing_docs)]
-        G2PubkeyAlreadySet(G2PubkeyAlreadySet),
-        #[allow(missing_docs)]
-        InvalidBLSSignatureOrPrivateKey(InvalidBLSSignatureOrPrivateKey),
-        #[allow(missing_docs)]
-        OnlyRegistryCoordinatorOwner(OnlyRegistryCoordinatorOwner),
-        #[allow(missing_docs)]
-        OperatorAlreadyRegistered(OperatorAlreadyRegistered),
-        #[allow(missing_docs)]
-        OperatorNotRegistered(OperatorNotRegistered),
-        #[allow(missing_docs)]
-        QuorumAlreadyExists(QuorumAlreadyExists),
-        #[allow(missing_docs)]
-        QuorumDoesNotExist(QuorumDoesNotExist),
-        #[allow(missing_docs)]
-        ZeroPubKey(ZeroPubKey),
-    }
-    #[automatically_derived]
-    impl BLSApkRegistryErrors {
-        /// All the selectors of this enum.
-        ///
-        /// Note that the selectors might not be in the same order as the variants.
-        /// No guarantees are made about the order of the selectors.
-        ///
-        /// Prefer using `SolInterface` methods instead.
-        pub const SELECTORS: &'static [[u8; 4usize]] = &[
-            [12u8, 199u8, 80u8, 145u8],
-            [37u8, 236u8, 108u8, 31u8],
-            [61u8, 34u8, 136u8, 65u8],
-            [63u8, 76u8, 183u8, 15u8],
-            [66u8, 238u8, 104u8, 181u8],
-            [67u8, 54u8, 148u8, 92u8],
-            [70u8, 51u8, 190u8, 50u8],
-            [111u8, 224u8, 45u8, 75u8],
-            [132u8, 158u8, 92u8, 240u8],
-            [147u8, 51u8, 30u8, 76u8],
-            [152u8, 102u8, 153u8, 46u8],
-            [167u8, 45u8, 2u8, 99u8],
-            [212u8, 182u8, 143u8, 215u8],
-            [224u8, 225u8, 231u8, 98u8],
-            [230u8, 33u8, 159u8, 234u8],
-        ];
-    }
-    #[automatically_derived]
-    impl alloy_sol_types::SolInterface for BLSApkRegistryErrors {
-        const NAME: &'static str = "BLSApkRegistryErrors";
-        const MIN_DATA_LENGTH: usize = 0usize;
-        const COUNT: usize = 15usize;
-        #[inline]
-        fn selector(&self) -> [u8; 4] {
-            match self {
-                Self::BLSPubkeyAlreadyRegistered(_) => {
-                    <BLSPubkeyAlreadyRegistered as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::BlockNumberBeforeFirstUpdate(_) => {
-                    <BlockNumberBeforeFirstUpdate as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::BlockNumberNotLatest(_) => {
-                    <BlockNumberNotLatest as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::BlockNumberTooRecent(_) => {
-                    <BlockNumberTooRecent as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::ECAddFailed(_) => <ECAddFailed as alloy_sol_types::SolError>::SELECTOR,
-                Self::ECMulFailed(_) => <ECMulFailed as alloy_sol_types::SolError>::SELECTOR,
-                Self::ECPairingFailed(_) => {
-                    <ECPairingFailed as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::G2PubkeyAlreadySet(_) => {
-                    <G2PubkeyAlreadySet as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::InvalidBLSSignatureOrPrivateKey(_) => {
-                    <InvalidBLSSignatureOrPrivateKey as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::OnlyRegistryCoordinatorOwner(_) => {
-                    <OnlyRegistryCoordinatorOwner as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::OperatorAlreadyRegistered(_) => {
-                    <OperatorAlreadyRegistered as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::OperatorNotRegistered(_) => {
-                    <OperatorNotRegistered as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::QuorumAlreadyExists(_) => {
-                    <QuorumAlreadyExists as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::QuorumDoesNotExist(_) => {
-                    <QuorumDoesNotExist as alloy_sol_types::SolError>::SELECTOR
-                }
-                Self::ZeroPubKey(_) => <ZeroPubKey as alloy_sol_types::SolError>::SELECTOR,
-            }
-        }
-        #[inline]
-        fn selector_at(i: usize) -> ::core::option::Option<[u8; 4]> {
-            Self::SELECTORS.get(i).copied()
-        }
-        #[inline]
-        fn valid_selector(selector: [u8; 4]) -> bool {
-            Self::SELECTORS.binary_search(&selector).is_ok()
-        }
-        #[inline]
-        #[allow(non_snake_case)]
-        fn abi_decode_raw(
-            selector: [u8; 4],
-            data: &[u8],
-            validate: bool,
-        ) -> alloy_sol_types::Result<Self> {
-            static DECODE_SHIMS: &[fn(
-                &[u8],
-                bool,
-            )
-                -> alloy_sol_types::Result<BLSApkRegistryErrors>] = &[
-                {
-                    fn ZeroPubKey(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <ZeroPubKey as alloy_sol_types::SolError>::abi_decode_raw(data, validate)
-                            .map(BLSApkRegistryErrors::ZeroPubKey)
-                    }
-                    ZeroPubKey
-                },
-                {
-                    fn OperatorNotRegistered(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <OperatorNotRegistered as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::OperatorNotRegistered)
-                    }
-                    OperatorNotRegistered
-                },
-                {
-                    fn BlockNumberTooRecent(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <BlockNumberTooRecent as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::BlockNumberTooRecent)
-                    }
-                    BlockNumberTooRecent
-                },
-                {
-                    fn BlockNumberBeforeFirstUpdate(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <BlockNumberBeforeFirstUpdate as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::BlockNumberBeforeFirstUpdate)
-                    }
-                    BlockNumberBeforeFirstUpdate
-                },
-                {
-                    fn OperatorAlreadyRegistered(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <OperatorAlreadyRegistered as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::OperatorAlreadyRegistered)
-                    }
-                    OperatorAlreadyRegistered
-                },
-                {
-                    fn QuorumAlreadyExists(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <QuorumAlreadyExists as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::QuorumAlreadyExists)
-                    }
-                    QuorumAlreadyExists
-                },
-                {
-                    fn ECMulFailed(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <ECMulFailed as alloy_sol_types::SolError>::abi_decode_raw(data, validate)
-                            .map(BLSApkRegistryErrors::ECMulFailed)
-                    }
-                    ECMulFailed
-                },
-                {
-                    fn BlockNumberNotLatest(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <BlockNumberNotLatest as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::BlockNumberNotLatest)
-                    }
-                    BlockNumberNotLatest
-                },
-                {
-                    fn G2PubkeyAlreadySet(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <G2PubkeyAlreadySet as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::G2PubkeyAlreadySet)
-                    }
-                    G2PubkeyAlreadySet
-                },
-                {
-                    fn ECPairingFailed(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <ECPairingFailed as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::ECPairingFailed)
-                    }
-                    ECPairingFailed
-                },
-                {
-                    fn BLSPubkeyAlreadyRegistered(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <BLSPubkeyAlreadyRegistered as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::BLSPubkeyAlreadyRegistered)
-                    }
-                    BLSPubkeyAlreadyRegistered
-                },
-                {
-                    fn InvalidBLSSignatureOrPrivateKey(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <InvalidBLSSignatureOrPrivateKey as alloy_sol_types::SolError>::abi_decode_raw(
-                                data,
-                                validate,
-                            )
-                            .map(BLSApkRegistryErrors::InvalidBLSSignatureOrPrivateKey)
-                    }
-                    InvalidBLSSignatureOrPrivateKey
-                },
-                {
-                    fn ECAddFailed(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <ECAddFailed as alloy_sol_types::SolError>::abi_decode_raw(data, validate)
-                            .map(BLSApkRegistryErrors::ECAddFailed)
-                    }
-                    ECAddFailed
-                },
-                {
-                    fn OnlyRegistryCoordinatorOwner(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <OnlyRegistryCoordinatorOwner as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::OnlyRegistryCoordinatorOwner)
-                    }
-                    OnlyRegistryCoordinatorOwner
-                },
-                {
-                    fn QuorumDoesNotExist(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<BLSApkRegistryErrors> {
-                        <QuorumDoesNotExist as alloy_sol_types::SolError>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(BLSApkRegistryErrors::QuorumDoesNotExist)
-                    }
-                    QuorumDoesNotExist
-                },
-            ];
-            let Ok(idx) = Self::SELECTORS.binary_search(&selector) else {
-                return Err(alloy_sol_types::Error::unknown_selector(
-                    <Self as alloy_sol_types::SolInterface>::NAME,
-                    selector,
-                ));
-            };
-            DECODE_SHIMS[idx](data, validate)
-        }
-        #[inline]
-        fn abi_encoded_size(&self) -> usize {
-            match self {
-                Self::BLSPubkeyAlreadyRegistered(inner) => {
-                    <BLSPubkeyAlreadyRegistered as alloy_sol_types::SolError>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::BlockNumberBeforeFirstUpdate(inner) => {
-                    <BlockNumberBeforeFirstUpdate as alloy_sol_types::SolError>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::BlockNumberNotLatest(inner) => {
-                    <BlockNumberNotLatest as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-                Self::BlockNumberTooRecent(inner) => {
-                    <BlockNumberTooRecent as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-                Self::ECAddFailed(inner) => {
-                    <ECAddFailed as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-                Self::ECMulFailed(inner) => {
-                    <ECMulFailed as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-                Self::ECPairingFailed(inner) => {
-                    <ECPairingFailed as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-                Self::G2PubkeyAlreadySet(inner) => {
-                    <G2PubkeyAlreadySet as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-                Self::InvalidBLSSignatureOrPrivateKey(inner) => {
-                    <InvalidBLSSignatureOrPrivateKey as alloy_sol_types::SolError>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::OnlyRegistryCoordinatorOwner(inner) => {
-                    <OnlyRegistryCoordinatorOwner as alloy_sol_types::SolError>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::OperatorAlreadyRegistered(inner) => {
-                    <OperatorAlreadyRegistered as alloy_sol_types::SolError>::abi_encoded_size(
-                        inner,
-                    )
-                }
-                Self::OperatorNotRegistered(inner) => {
-                    <OperatorNotRegistered as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-                Self::QuorumAlreadyExists(inner) => {
-                    <QuorumAlreadyExists as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-                Self::QuorumDoesNotExist(inner) => {
-                    <QuorumDoesNotExist as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-                Self::ZeroPubKey(inner) => {
-                    <ZeroPubKey as alloy_sol_types::SolError>::abi_encoded_size(inner)
-                }
-            }
-        }
-        #[inline]
-        fn abi_encode_raw(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-            match self {
-                Self::BLSPubkeyAlreadyRegistered(inner) => {
-                    <BLSPubkeyAlreadyRegistered as alloy_sol_types::SolError>::abi_encode_raw(
-                        inner, out,
-                    )
-                }
-                Self::BlockNumberBeforeFirstUpdate(inner) => {
-                    <BlockNumberBeforeFirstUpdate as alloy_sol_types::SolError>::abi_encode_raw(
-                        inner, out,
-                    )
-                }
-                Self::BlockNumberNotLatest(inner) => {
-                    <BlockNumberNotLatest as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-                Self::BlockNumberTooRecent(inner) => {
-                    <BlockNumberTooRecent as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-                Self::ECAddFailed(inner) => {
-                    <ECAddFailed as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-                Self::ECMulFailed(inner) => {
-                    <ECMulFailed as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-                Self::ECPairingFailed(inner) => {
-                    <ECPairingFailed as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-                Self::G2PubkeyAlreadySet(inner) => {
-                    <G2PubkeyAlreadySet as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-                Self::InvalidBLSSignatureOrPrivateKey(inner) => {
-                    <InvalidBLSSignatureOrPrivateKey as alloy_sol_types::SolError>::abi_encode_raw(
-                        inner, out,
-                    )
-                }
-                Self::OnlyRegistryCoordinatorOwner(inner) => {
-                    <OnlyRegistryCoordinatorOwner as alloy_sol_types::SolError>::abi_encode_raw(
-                        inner, out,
-                    )
-                }
-                Self::OperatorAlreadyRegistered(inner) => {
-                    <OperatorAlreadyRegistered as alloy_sol_types::SolError>::abi_encode_raw(
-                        inner, out,
-                    )
-                }
-                Self::OperatorNotRegistered(inner) => {
-                    <OperatorNotRegistered as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-                Self::QuorumAlreadyExists(inner) => {
-                    <QuorumAlreadyExists as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-                Self::QuorumDoesNotExist(inner) => {
-                    <QuorumDoesNotExist as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-                Self::ZeroPubKey(inner) => {
-                    <ZeroPubKey as alloy_sol_types::SolError>::abi_encode_raw(inner, out)
-                }
-            }
-        }
-    }
-    ///Container for all the [`BLSApkRegistry`](self) events.
-    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Hash)]
-    pub enum BLSApkRegistryEvents {
-        #[allow(missing_docs)]
-        Initialized(Initialized),
-        #[allow(missing_docs)]
-        NewG2PubkeyRegistration(NewG2PubkeyRegistration),
-        #[allow(missing_docs)]
-        NewPubkeyRegistration(NewPubkeyRegistration),
-        #[allow(missing_docs)]
-        OperatorAddedToQuorums(OperatorAddedToQuorums),
-        #[allow(missing_docs)]
-        OperatorRemovedFromQuorums(OperatorRemovedFromQuorums),
-    }
-    #[automatically_derived]
-    impl BLSApkRegistryEvents {
-        /// All the selectors of this enum.
-        ///
-        /// Note that the selectors might not be in the same order as the variants.
-        /// No guarantees are made about the order of the selectors.
-        ///
-        /// Prefer using `SolInterface` methods instead.
-        pub const SELECTORS: &'static [[u8; 32usize]] = &[
-            [
-                92u8, 79u8, 159u8, 40u8, 21u8, 61u8, 191u8, 63u8, 0u8, 230u8, 150u8, 7u8, 165u8,
-                158u8, 130u8, 173u8, 128u8, 111u8, 255u8, 183u8, 141u8, 9u8, 241u8, 121u8, 246u8,
-                36u8, 50u8, 247u8, 233u8, 210u8, 81u8, 26u8,
-            ],
-            [
-                115u8, 162u8, 183u8, 251u8, 132u8, 71u8, 36u8, 185u8, 113u8, 128u8, 42u8, 233u8,
-                177u8, 93u8, 176u8, 148u8, 212u8, 183u8, 25u8, 45u8, 249u8, 215u8, 53u8, 14u8,
-                20u8, 235u8, 70u8, 107u8, 155u8, 34u8, 235u8, 78u8,
-            ],
-            [
-                127u8, 38u8, 184u8, 63u8, 249u8, 110u8, 31u8, 43u8, 106u8, 104u8, 47u8, 19u8, 56u8,
-                82u8, 246u8, 121u8, 138u8, 9u8, 196u8, 101u8, 218u8, 149u8, 146u8, 20u8, 96u8,
-                206u8, 251u8, 56u8, 71u8, 64u8, 36u8, 152u8,
-            ],
-            [
-                227u8, 251u8, 102u8, 19u8, 175u8, 46u8, 137u8, 48u8, 207u8, 133u8, 212u8, 127u8,
-                207u8, 109u8, 177u8, 1u8, 146u8, 34u8, 74u8, 100u8, 198u8, 203u8, 232u8, 2u8, 62u8,
-                14u8, 238u8, 27u8, 163u8, 130u8, 128u8, 65u8,
-            ],
-            [
-                248u8, 67u8, 236u8, 213u8, 58u8, 86u8, 54u8, 117u8, 230u8, 33u8, 7u8, 190u8, 20u8,
-                148u8, 253u8, 222u8, 74u8, 61u8, 73u8, 174u8, 237u8, 175u8, 141u8, 136u8, 198u8,
-                22u8, 216u8, 83u8, 70u8, 227u8, 80u8, 14u8,
-            ],
-        ];
-    }
-    #[automatically_derived]
-    impl alloy_sol_types::SolEventInterface for BLSApkRegistryEvents {
-        const NAME: &'static str = "BLSApkRegistryEvents";
-        const COUNT: usize = 5usize;
-        fn decode_raw_log(
-            topics: &[alloy_sol_types::Word],
-            data: &[u8],
-            validate: bool,
-        ) -> alloy_sol_types::Result<Self> {
-            match topics.first().copied() {
-                Some(<Initialized as alloy_sol_types::SolEvent>::SIGNATURE_HASH) => {
-                    <Initialized as alloy_sol_types::SolEvent>::decode_raw_log(
-                        topics, data, validate,
-                    )
-                    .map(Self::Initialized)
-                }
-                Some(<NewG2PubkeyRegistration as alloy_sol_types::SolEvent>::SIGNATURE_HASH) => {
-                    <NewG2PubkeyRegistration as alloy_sol_types::SolEvent>::decode_raw_log(
-                        topics, data, validate,
-                    )
-                    .map(Self::NewG2PubkeyRegistration)
-                }
-                Some(<NewPubkeyRegistration as alloy_sol_types::SolEvent>::SIGNATURE_HASH) => {
-                    <NewPubkeyRegistration as alloy_sol_types::SolEvent>::decode_raw_log(
-                        topics, data, validate,
-                    )
-                    .map(Self::NewPubkeyRegistration)
-                }
-                Some(<OperatorAddedToQuorums as alloy_sol_types::SolEvent>::SIGNATURE_HASH) => {
-                    <OperatorAddedToQuorums as alloy_sol_types::SolEvent>::decode_raw_log(
-                        topics, data, validate,
-                    )
-                    .map(Self::OperatorAddedToQuorums)
-                }
-                Some(<OperatorRemovedFromQuorums as alloy_sol_types::SolEvent>::SIGNATURE_HASH) => {
-                    <OperatorRemovedFromQuorums as alloy_sol_types::SolEvent>::decode_raw_log(
-                        topics, data, validate,
-                    )
-                    .map(Self::OperatorRemovedFromQuorums)
-                }
-                _ => alloy_sol_types::private::Err(alloy_sol_types::Error::InvalidLog {
-                    name: <Self as alloy_sol_types::SolEventInterface>::NAME,
-                    log: alloy_sol_types::private::Box::new(
-                        alloy_sol_types::private::LogData::new_unchecked(
-                            topics.to_vec(),
-                            data.to_vec().into(),
-                        ),
-                    ),
-                }),
-            }
-        }
-    }
-    #[automatically_derived]
-    impl alloy_sol_types::private::IntoLogData for BLSApkRegistryEvents {
-        fn to_log_data(&self) -> alloy_sol_types::private::LogData {
-            match self {
-                Self::Initialized(inner) => {
-                    alloy_sol_types::private::IntoLogData::to_log_data(inner)
-                }
-                Self::NewG2PubkeyRegistration(inner) => {
-                    alloy_sol_types::private::IntoLogData::to_log_data(inner)
-                }
-                Self::NewPubkeyRegistration(inner) => {
-                    alloy_sol_types::private::IntoLogData::to_log_data(inner)
-                }
-                Self::OperatorAddedToQuorums(inner) => {
-                    alloy_sol_types::private::IntoLogData::to_log_data(inner)
-                }
-                Self::OperatorRemovedFromQuorums(inner) => {
-                    alloy_sol_types::private::IntoLogData::to_log_data(inner)
-                }
-            }
-        }
-        fn into_log_data(self) -> alloy_sol_types::private::LogData {
-            match self {
-                Self::Initialized(inner) => {
-                    alloy_sol_types::private::IntoLogData::into_log_data(inner)
-                }
-                Self::NewG2PubkeyRegistration(inner) => {
-                    alloy_sol_types::private::IntoLogData::into_log_data(inner)
-                }
-                Self::NewPubkeyRegistration(inner) => {
-                    alloy_sol_types::private::IntoLogData::into_log_data(inner)
-                }
-                Self::OperatorAddedToQuorums(inner) => {
-                    alloy_sol_types::private::IntoLogData::into_log_data(inner)
-                }
-                Self::OperatorRemovedFromQuorums(inner) => {
-                    alloy_sol_types::private::IntoLogData::into_log_data(inner)
-                }
-            }
-        }
-    }
-    use alloy::contract as alloy_contract;
-    /**Creates a new wrapper around an on-chain [`BLSApkRegistry`](self) contract instance.
-
-    See the [wrapper's documentation](`BLSApkRegistryInstance`) for more details.*/
-    #[inline]
-    pub const fn new<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    >(
-        address: alloy_sol_types::private::Address,
-        provider: P,
-    ) -> BLSApkRegistryInstance<T, P, N> {
-        BLSApkRegistryInstance::<T, P, N>::new(address, provider)
-    }
-    /**Deploys this contract using the given `provider` and constructor arguments, if any.
-
-    Returns a new instance of the contract, if the deployment was successful.
-
-    For more fine-grained control over the deployment process, use [`deploy_builder`] instead.*/
-    #[inline]
-    pub fn deploy<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    >(
-        provider: P,
-        _slashingRegistryCoordinator: alloy::sol_types::private::Address,
-    ) -> impl ::core::future::Future<Output = alloy_contract::Result<BLSApkRegistryInstance<T, P, N>>>
-    {
-        BLSApkRegistryInstance::<T, P, N>::deploy(provider, _slashingRegistryCoordinator)
-    }
-    /**Creates a `RawCallBuilder` for deploying this contract using the given `provider`
-    and constructor arguments, if any.
-
-    This is a simple wrapper around creating a `RawCallBuilder` with the data set to
-    the bytecode concatenated with the constructor's ABI-encoded arguments.*/
-    #[inline]
-    pub fn deploy_builder<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    >(
-        provider: P,
-        _slashingRegistryCoordinator: alloy::sol_types::private::Address,
-    ) -> alloy_contract::RawCallBuilder<T, P, N> {
-        BLSApkRegistryInstance::<T, P, N>::deploy_builder(provider, _slashingRegistryCoordinator)
-    }
-    /**A [`BLSApkRegistry`](self) instance.
-
-    Contains type-safe methods for interacting with an on-chain instance of the
-    [`BLSApkRegistry`](self) contract located at a given `address`, using a given
-    provider `P`.
-
-    If the contract bytecode is available (see the [`sol!`](alloy_sol_types::sol!)
-    documentation on how to provide it), the `deploy` and `deploy_builder` methods can
-    be used to deploy a new instance of the contract.
-
-    See the [module-level documentation](self) for all the available methods.*/
-    #[derive(Clone)]
-    pub struct BLSApkRegistryInstance<T, P, N = alloy_contract::private::Ethereum> {
-        address: alloy_sol_types::private::Address,
-        provider: P,
-        _network_transport: ::core::marker::PhantomData<(N, T)>,
-    }
-    #[automatically_derived]
-    impl<T, P, N> ::core::fmt::Debug for BLSApkRegistryInstance<T, P, N> {
-        #[inline]
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_tuple("BLSApkRegistryInstance")
-                .field(&self.address)
-                .finish()
-        }
-    }
-    /// Instantiation and getters/setters.
-    #[automatically_derived]
-    impl<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    > BLSApkRegistryInstance<T, P, N>
-    {
-        /**Creates a new wrapper around an on-chain [`BLSApkRegistry`](self) contract instance.
-
-        See the [wrapper's documentation](`BLSApkRegistryInstance`) for more details.*/
-        #[inline]
-        pub const fn new(address: alloy_sol_types::private::Address, provider: P) -> Self {
-            Self {
-                address,
-                provider,
-                _network_transport: ::core::marker::PhantomData,
-            }
-        }
-        /**Deploys this contract using the given `provider` and constructor arguments, if any.
-
-        Returns a new instance of the contract, if the deployment was successful.
-
-        For more fine-grained control over the deployment process, use [`deploy_builder`] instead.*/
-        #[inline]
-        pub async fn deploy(
-            provider: P,
-            _slashingRegistryCoordinator: alloy::sol_types::private::Address,
-        ) -> alloy_contract::Result<BLSApkRegistryInstance<T, P, N>> {
-            let call_builder = Self::deploy_builder(provider, _slashingRegistryCoordinator);
-            let contract_address = call_builder.deploy().await?;
-            Ok(Self::new(contract_address, call_builder.provider))
-        }
-        /**Creates a `RawCallBuilder` for deploying this contract using the given `provider`
-        and constructor arguments, if any.
-
-        This is a simple wrapper around creating a `RawCallBuilder` with the data set to
-        the bytecode concatenated with the constructor's ABI-encoded arguments.*/
-        #[inline]
-        pub fn deploy_builder(
-            provider: P,
-            _slashingRegistryCoordinator: alloy::sol_types::private::Address,
-        ) -> alloy_contract::RawCallBuilder<T, P, N> {
-            alloy_contract::RawCallBuilder::new_raw_deploy(
-                provider,
-                [
-                    &BYTECODE[..],
-                    &alloy_sol_types::SolConstructor::abi_encode(&constructorCall {
-                        _slashingRegistryCoordinator,
-                    })[..],
-                ]
-                .concat()
-                .into(),
-            )
-        }
-        /// Returns a reference to the address.
-        #[inline]
-        pub const fn address(&self) -> &alloy_sol_types::private::Address {
-            &self.address
-        }
-        /// Sets the address.
-        #[inline]
-        pub fn set_address(&mut self, address: alloy_sol_types::private::Address) {
-            self.address = address;
-        }
-        /// Sets the address and returns `self`.
-        pub fn at(mut self, address: alloy_sol_types::private::Address) -> Self {
-            self.set_address(address);
-            self
-        }
-        /// Returns a reference to the provider.
-        #[inline]
-        pub const fn provider(&self) -> &P {
-            &self.provider
-        }
-    }
-    impl<T, P: ::core::clone::Clone, N> BLSApkRegistryInstance<T, &P, N> {
-        /// Clones the provider and returns a new instance with the cloned provider.
-        #[inline]
-        pub fn with_cloned_provider(self) -> BLSApkRegistryInstance<T, P, N> {
-            BLSApkRegistryInstance {
-                address: self.address,
-                provider: ::core::clone::Clone::clone(&self.provider),
-                _network_transport: ::core::marker::PhantomData,
-            }
-        }
-    }
-    /// Function calls.
-    #[automatically_derived]
-    impl<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    > BLSApkRegistryInstance<T, P, N>
-    {
-        /// Creates a new call builder using this contract instance's provider and address.
-        ///
-        /// Note that the call can be any function call, not just those defined in this
-        /// contract. Prefer using the other methods for building type-safe contract calls.
-        pub fn call_builder<C: alloy_sol_types::SolCall>(
-            &self,
-            call: &C,
-        ) -> alloy_contract::SolCallBuilder<T, &P, C, N> {
-            alloy_contract::SolCallBuilder::new_sol(&self.provider, &self.address, call)
-        }
-        ///Creates a new call builder for the [`apkHistory`] function.
-        pub fn apkHistory(
-            &self,
-            quorumNumber: u8,
-            _1: alloy::sol_types::private::primitives::aliases::U256,
-        ) -> alloy_contract::SolCallBuilder<T, &P, apkHistoryCall, N> {
-            self.call_builder(&apkHistoryCall { quorumNumber, _1 })
-        }
-        ///Creates a new call builder for the [`currentApk`] function.
-        pub fn currentApk(
-            &self,
-            quorumNumber: u8,
-        ) -> alloy_contract::SolCallBuilder<T, &P, currentApkCall, N> {
-            self.call_builder(&currentApkCall { quorumNumber })
-        }
-        ///Creates a new call builder for the [`deregisterOperator`] function.
-        pub fn deregisterOperator(
-            &self,
-            operator: alloy::sol_types::private::Address,
-            quorumNumbers: alloy::sol_types::private::Bytes,
-        ) -> alloy_contract::SolCallBuilder<T, &P, deregisterOperatorCall, N> {
-            self.call_builder(&deregisterOperatorCall {
-                operator,
-                quorumNumbers,
-            })
-        }
-        ///Creates a new call builder for the [`getApk`] function.
-        pub fn getApk(
-            &self,
-            quorumNumber: u8,
-        ) -> alloy_contract::SolCallBuilder<T, &P, getApkCall, N> {
-            self.call_builder(&getApkCall { quorumNumber })
-        }
-        ///Creates a new call builder for the [`getApkHashAtBlockNumberAndIndex`] function.
-        pub fn getApkHashAtBlockNumberAndIndex(
-            &self,
-            quorumNumber: u8,
-            blockNumber: u32,
-            index: alloy::sol_types::private::primitives::aliases::U256,
-        ) -> alloy_contract::SolCallBuilder<T, &P, getApkHashAtBlockNumberAndIndexCall, N> {
-            self.call_builder(&getApkHashAtBlockNumberAndIndexCall {
-                quorumNumber,
-                blockNumber,
-                index,
-            })
-        }
-        ///Creates a new call builder for the [`getApkHistoryLength`] function.
-        pub fn getApkHistoryLength(
-            &self,
-            quorumNumber: u8,
-        ) -> alloy_contract::SolCallBuilder<T, &P, getApkHistoryLengthCall, N> {
-            self.call_builder(&getApkHistoryLengthCall { quorumNumber })
-        }
-        ///Creates a new call builder for the [`getApkIndicesAtBlockNumber`] function.
-        pub fn getApkIndicesAtBlockNumber(
-            &self,
-            quorumNumbers: alloy::sol_types::private::Bytes,
-            blockNumber: alloy::sol_types::private::primitives::aliases::U256,
-        ) -> alloy_contract::SolCallBuilder<T, &P, getApkIndicesAtBlockNumberCall, N> {
-            self.call_builder(&getApkIndicesAtBlockNumberCall {
-                quorumNumbers,
-                blockNumber,
-            })
-        }
-        ///Creates a new call builder for the [`getApkUpdateAtIndex`] function.
-        pub fn getApkUpdateAtIndex(
-            &self,
-            quorumNumber: u8,
-            index: alloy::sol_types::private::primitives::aliases::U256,
-        ) -> alloy_contract::SolCallBuilder<T, &P, getApkUpdateAtIndexCall, N> {
-            self.call_builder(&getApkUpdateAtIndexCall {
-                quorumNumber,
-                index,
-            })
-        }
-        ///Creates a new call builder for the [`getOperatorFromPubkeyHash`] function.
-        pub fn getOperatorFromPubkeyHash(
-            &self,
-            pubkeyHash: alloy::sol_types::private::FixedBytes<32>,
-        ) -> alloy_contract::SolCallBuilder<T, &P, getOperatorFromPubkeyHashCall, N> {
-            self.call_builder(&getOperatorFromPubkeyHashCall { pubkeyHash })
-        }
-        ///Creates a new call builder for the [`getOperatorId`] function.
-        pub fn getOperatorId(
-            &self,
-            operator: alloy::sol_types::private::Address,
-        ) -> alloy_contract::SolCallBuilder<T, &P, getOperatorIdCall, N> {
-            self.call_builder(&getOperatorIdCall { operator })
-        }
-        ///Creates a new call builder for the [`getOperatorPubkeyG2`] function.
-        pub fn getOperatorPubkeyG2(
-            &self,
-            operator: alloy::sol_types::private::Address,
-        ) -> alloy_contract::SolCallBuilder<T, &P, getOperatorPubkeyG2Call, N> {
-            self.call_builder(&getOperatorPubkeyG2Call { operator })
-        }
-        ///Creates a new call builder for the [`getRegisteredPubkey`] function.
-        pub fn getRegisteredPubkey(
-            &self,
-            operator: alloy::sol_types::private::Address,
-        ) -> alloy_contract::SolCallBuilder<T, &P, getRegisteredPubkeyCall, N> {
-            self.call_builder(&getRegisteredPubkeyCall { operator })
-        }
-        ///Creates a new call builder for the [`initializeQuorum`] function.
-        pub fn initializeQuorum(
-            &self,
-            quorumNumber: u8,
-        ) -> alloy_contract::SolCallBuilder<T, &P, initializeQuorumCall, N> {
-            self.call_builder(&initializeQuorumCall { quorumNumber })
-        }
-        ///Creates a new call builder for the [`operatorToPubkey`] function.
-        pub fn operatorToPubkey(
-            &self,
-            operator: alloy::sol_types::private::Address,
-        ) -> alloy_contract::SolCallBuilder<T, &P, operatorToPubkeyCall, N> {
-            self.call_builder(&operatorToPubkeyCall { operator })
-        }
-        ///Creates a new call builder for the [`operatorToPubkeyHash`] function.
-        pub fn operatorToPubkeyHash(
-            &self,
-            operator: alloy::sol_types::private::Address,
-        ) -> alloy_contract::SolCallBuilder<T, &P, operatorToPubkeyHashCall, N> {
-            self.call_builder(&operatorToPubkeyHashCall { operator })
-        }
-        ///Creates a new call builder for the [`pubkeyHashToOperator`] function.
-        pub fn pubkeyHashToOperator(
-            &self,
-            pubkeyHash: alloy::sol_types::private::FixedBytes<32>,
-        ) -> alloy_contract::SolCallBuilder<T, &P, pubkeyHashToOperatorCall, N> {
-            self.call_builder(&pubkeyHashToOperatorCall { pubkeyHash })
-        }
-        ///Creates a new call builder for the [`registerBLSPublicKey`] function.
-        pub fn registerBLSPublicKey(
-            &self,
-            operator: alloy::sol_types::private::Address,
-            params: <IBLSApkRegistryTypes::PubkeyRegistrationParams as alloy::sol_types::SolType>::RustType,
-            pubkeyRegistrationMessageHash: <BN254::G1Point as alloy::sol_types::SolType>::RustType,
-        ) -> alloy_contract::SolCallBuilder<T, &P, registerBLSPublicKeyCall, N> {
-            self.call_builder(&registerBLSPublicKeyCall {
-                operator,
-                params,
-                pubkeyRegistrationMessageHash,
-            })
-        }
-        ///Creates a new call builder for the [`registerOperator`] function.
-        pub fn registerOperator(
-            &self,
-            operator: alloy::sol_types::private::Address,
-            quorumNumbers: alloy::sol_types::private::Bytes,
-        ) -> alloy_contract::SolCallBuilder<T, &P, registerOperatorCall, N> {
-            self.call_builder(&registerOperatorCall {
-                operator,
-                quorumNumbers,
-            })
-        }
-        ///Creates a new call builder for the [`registryCoordinator`] function.
-        pub fn registryCoordinator(
-            &self,
-        ) -> alloy_contract::SolCallBuilder<T, &P, registryCoordinatorCall, N> {
-            self.call_builder(&registryCoordinatorCall {})
-        }
-        ///Creates a new call builder for the [`verifyAndRegisterG2PubkeyForOperator`] function.
-        pub fn verifyAndRegisterG2PubkeyForOperator(
-            &self,
-            operator: alloy::sol_types::private::Address,
-            pubkeyG2: <BN254::G2Point as alloy::sol_types::SolType>::RustType,
-        ) -> alloy_contract::SolCallBuilder<T, &P, verifyAndRegisterG2PubkeyForOperatorCall, N>
-        {
-            self.call_builder(&verifyAndRegisterG2PubkeyForOperatorCall { operator, pubkeyG2 })
-        }
-    }
-    /// Event filters.
-    #[automatically_derived]
-    impl<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    > BLSApkRegistryInstance<T, P, N>
-    {
-        /// Creates a new event filter using this contract instance's provider and address.
-        ///
-        /// Note that the type can be any event, not just those defined in this contract.
-        /// Prefer using the other methods for building type-safe event filters.
-        pub fn event_filter<E: alloy_sol_types::SolEvent>(
-            &self,
-        ) -> alloy_contract::Event<T, &P, E, N> {
-            alloy_contract::Event::new_sol(&self.provider, &self.address)
-        }
-        ///Creates a new event filter for the [`Initialized`] event.
-        pub fn Initialized_filter(&self) -> alloy_contract::Event<T, &P, Initialized, N> {
-            self.event_filter::<Initialized>()
-        }
-        ///Creates a new event filter for the [`NewG2PubkeyRegistration`] event.
-        pub fn NewG2PubkeyRegistration_filter(
-            &self,
-        ) -> alloy_contract::Event<T, &P, NewG2PubkeyRegistration, N> {
-            self.event_filter::<NewG2PubkeyRegistration>()
-        }
-        ///Creates a new event filter for the [`NewPubkeyRegistration`] event.
-        pub fn NewPubkeyRegistration_filter(
-            &self,
-        ) -> alloy_contract::Event<T, &P, NewPubkeyRegistration, N> {
-            self.event_filter::<NewPubkeyRegistration>()
-        }
-        ///Creates a new event filter for the [`OperatorAddedToQuorums`] event.
-        pub fn OperatorAddedToQuorums_filter(
-            &self,
-        ) -> alloy_contract::Event<T, &P, OperatorAddedToQuorums, N> {
-            self.event_filter::<OperatorAddedToQuorums>()
-        }
-        ///Creates a new event filter for the [`OperatorRemovedFromQuorums`] event.
-        pub fn OperatorRemovedFromQuorums_filter(
-            &self,
-        ) -> alloy_contract::Event<T, &P, OperatorRemovedFromQuorums, N> {
-            self.event_filter::<OperatorRemovedFromQuorums>()
-        }
-    }
-}
@@ -1,64 +1,3 @@
-#[allow(
-    non_camel_case_types,
-    non_snake_case,
-    clippy::pub_underscore_fields,
-    clippy::style,
-    clippy::empty_structs_with_brackets,
-    clippy::too_many_arguments,
-    clippy::type_complexity,
-    missing_docs,
-    dead_code
-)]
-pub mod votingcontract;
+//! Contract bindings, generated by `build.rs` from the ABIs in `contracts/abi`.
 
-#[allow(
-    non_camel_case_types,
-    non_snake_case,
-    clippy::pub_underscore_fields,
-    clippy::style,
-    clippy::empty_structs_with_brackets,
-    clippy::too_many_arguments,
-    clippy::type_complexity,
-    missing_docs,
-    dead_code
-)]
-pub mod blssigcheckoperatorstateretriever;
-
-#[allow(
-    non_camel_case_types,
-    non_snake_case,
-    clippy::pub_underscore_fields,
-    clippy::style,
-    clippy::empty_structs_with_brackets,
-    clippy::too_many_arguments,
-    clippy::type_complexity,
-    missing_docs,
-    dead_code
-)]
-pub mod blssignaturechecker;
-
-#[allow(
-    non_camel_case_types,
-    non_snake_case,
-    clippy::pub_underscore_fields,
-    clippy::style,
-    clippy::empty_structs_with_brackets,
-    clippy::too_many_arguments,
-    clippy::type_complexity,
-    missing_docs,
-    dead_code
-)]
-pub mod blsapkregistry;
-
-#[allow(
-    non_camel_case_types,
-    non_snake_case,
-    clippy::pub_underscore_fields,
-    clippy::style,
-    clippy::empty_structs_with_brackets,
-    clippy::too_many_arguments,
-    clippy::type_complexity,
-    missing_docs,
-    dead_code
-)]
-pub mod counter;
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
@@ -1,7 +0,0 @@
-use alloy::sol;
-
-sol!(
-    #[sol(rpc)]
-    VotingContract,
-    "src/bindings/abi/VotingContract.abi"
-);
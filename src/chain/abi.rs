@@ -0,0 +1,142 @@
+//! Calldata checking a [QuorumCertificate] with `BLSSignatureChecker.checkSignatures`.
+//!
+//! The certificate provides the message hash and the aggregate signature `sigma`.
+//! The rest of `NonSignerStakesAndSignature` (non-signer keys, quorum APKs and the
+//! registry indices they were read at) comes from the registries at the reference
+//! block, as returned by `OperatorStateRetriever.getCheckSignaturesIndices`, and is
+//! passed in as [CheckSignaturesParams].
+
+use crate::chain::task_responder::{G1Coordinates, G2Coordinates};
+use crate::contributor::QuorumCertificate;
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy_json_abi::{Function, JsonAbi};
+use alloy_primitives::{B256, Bytes, U256};
+use anyhow::{Result, anyhow};
+use ark_bn254::G1Affine;
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalDeserialize;
+use bn254::Signature;
+use std::sync::LazyLock;
+
+/// ABI of the checked-in `BLSSignatureChecker`, the one the bindings are built from
+const SIGNATURE_CHECKER_ABI: &str = include_str!("../../contracts/abi/BLSSignatureChecker.abi");
+
+/// `checkSignatures` of the checked-in ABI, `None` if the ABI does not declare it
+static CHECK_SIGNATURES: LazyLock<Option<Function>> = LazyLock::new(|| {
+    let abi: JsonAbi = serde_json::from_str(SIGNATURE_CHECKER_ABI).ok()?;
+    abi.function("checkSignatures")
+        .and_then(|overloads| overloads.first())
+        .cloned()
+});
+
+/// Registry state `checkSignatures` checks a certificate against
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CheckSignaturesParams {
+    /// Block the registry state is read at
+    pub reference_block: u32,
+    /// Index of each non-signer's quorum bitmap at the reference block
+    pub non_signer_quorum_bitmap_indices: Vec<u32>,
+    /// G1 keys of the non-signers, sorted by operator id
+    pub non_signer_pubkeys: Vec<G1Coordinates>,
+    /// APK of each quorum, in the order of the quorum numbers
+    pub quorum_apks: Vec<G1Coordinates>,
+    /// Aggregate G2 key of the signers
+    pub apk_g2: G2Coordinates,
+    /// Index of each quorum APK at the reference block
+    pub quorum_apk_indices: Vec<u32>,
+    /// Index of each quorum's total stake at the reference block
+    pub total_stake_indices: Vec<u32>,
+    /// Index of each non-signer's stake, per quorum
+    pub non_signer_stake_indices: Vec<Vec<u32>>,
+}
+
+/// Calldata of `checkSignatures` for `qc` over `quorum_numbers`
+pub fn encode_for_signature_checker(
+    qc: &QuorumCertificate,
+    quorum_numbers: &[u8],
+    params: &CheckSignaturesParams,
+) -> Result<Bytes> {
+    let per_quorum = [
+        params.quorum_apks.len(),
+        params.quorum_apk_indices.len(),
+        params.total_stake_indices.len(),
+        params.non_signer_stake_indices.len(),
+    ];
+    if per_quorum.iter().any(|len| *len != quorum_numbers.len()) {
+        return Err(anyhow!(
+            "expected one apk and index per quorum for {} quorums, got {per_quorum:?}",
+            quorum_numbers.len()
+        ));
+    }
+    if params.non_signer_pubkeys.len() != params.non_signer_quorum_bitmap_indices.len() {
+        return Err(anyhow!(
+            "{} non-signer keys for {} bitmap indices",
+            params.non_signer_pubkeys.len(),
+            params.non_signer_quorum_bitmap_indices.len()
+        ));
+    }
+    let sigma = signature_coordinates(&qc.signature).ok_or_else(|| {
+        anyhow!(
+            "aggregate signature of round {} is not a G1 point",
+            qc.round
+        )
+    })?;
+
+    let values = [
+        DynSolValue::FixedBytes(B256::from(qc.payload), 32),
+        DynSolValue::Bytes(quorum_numbers.to_vec()),
+        uint32(params.reference_block),
+        DynSolValue::Tuple(vec![
+            uint32_array(&params.non_signer_quorum_bitmap_indices),
+            DynSolValue::Array(params.non_signer_pubkeys.iter().map(g1).collect()),
+            DynSolValue::Array(params.quorum_apks.iter().map(g1).collect()),
+            g2(&params.apk_g2),
+            g1(&sigma),
+            uint32_array(&params.quorum_apk_indices),
+            uint32_array(&params.total_stake_indices),
+            DynSolValue::Array(
+                params
+                    .non_signer_stake_indices
+                    .iter()
+                    .map(|indices| uint32_array(indices))
+                    .collect(),
+            ),
+        ]),
+    ];
+    let function = CHECK_SIGNATURES
+        .as_ref()
+        .ok_or_else(|| anyhow!("BLSSignatureChecker abi has no checkSignatures"))?;
+    Ok(function.abi_encode_input(&values)?.into())
+}
+
+/// Coordinates of a signature, `None` if its bytes are not a G1 point
+pub fn signature_coordinates(signature: &Signature) -> Option<G1Coordinates> {
+    let point = G1Affine::deserialize_compressed(&signature[..]).ok()?;
+    Some(G1Coordinates {
+        x: U256::from_limbs(point.x.into_bigint().0),
+        y: U256::from_limbs(point.y.into_bigint().0),
+    })
+}
+
+fn uint(value: U256) -> DynSolValue {
+    DynSolValue::Uint(value, 256)
+}
+
+fn uint32(value: u32) -> DynSolValue {
+    DynSolValue::Uint(U256::from(value), 32)
+}
+
+fn uint32_array(values: &[u32]) -> DynSolValue {
+    DynSolValue::Array(values.iter().copied().map(uint32).collect())
+}
+
+fn g1(point: &G1Coordinates) -> DynSolValue {
+    DynSolValue::Tuple(vec![uint(point.x), uint(point.y)])
+}
+
+fn g2(point: &G2Coordinates) -> DynSolValue {
+    DynSolValue::Tuple(vec![
+        DynSolValue::FixedArray(point.x.map(uint).to_vec()),
+        DynSolValue::FixedArray(point.y.map(uint).to_vec()),
+    ])
+}
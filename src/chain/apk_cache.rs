@@ -0,0 +1,113 @@
+//! Cache of quorum aggregate public keys (APKs) read from the registry.
+//!
+//! The APK of a quorum only depends on the block it is read at, so entries are
+//! keyed by `(quorum_number, reference_block)`: aggregations at the same
+//! reference block reuse the cached APK, and a new reference block is always
+//! read from the registry. The least recently used entry is evicted once the
+//! cache is full.
+
+use crate::chain::task_responder::G1Coordinates;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::debug;
+
+/// Number of `(quorum, block)` entries kept by default
+pub const DEFAULT_APK_CACHE_CAPACITY: NonZeroUsize = NonZeroUsize::MIN.saturating_add(63);
+
+/// Source of quorum APKs
+pub trait ApkRegistry: Send + Sync + 'static {
+    /// APK of `quorum_number` at `reference_block`
+    fn quorum_apk(
+        &self,
+        quorum_number: u8,
+        reference_block: u64,
+    ) -> impl Future<Output = Result<G1Coordinates>> + Send;
+}
+
+#[derive(Default)]
+struct Entries {
+    apks: HashMap<(u8, u64), (G1Coordinates, u64)>,
+    /// Incremented on every access, the entry with the lowest value is evicted first
+    clock: u64,
+}
+
+/// LRU cache of quorum APKs keyed by `(quorum_number, reference_block)`
+pub struct ApkCache<R: ApkRegistry> {
+    registry: Arc<R>,
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+impl<R: ApkRegistry> ApkCache<R> {
+    /// Cache of [DEFAULT_APK_CACHE_CAPACITY] APKs read from `registry`
+    pub fn new(registry: Arc<R>) -> Self {
+        Self::with_capacity(registry, DEFAULT_APK_CACHE_CAPACITY)
+    }
+
+    /// Cache of `capacity` APKs read from `registry`
+    pub fn with_capacity(registry: Arc<R>, capacity: NonZeroUsize) -> Self {
+        Self {
+            registry,
+            capacity: capacity.get(),
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// APK of `quorum_number` at `reference_block`, read from the registry on a miss
+    ///
+    /// Lookups are serialized so concurrent aggregations at the same block fetch once.
+    pub async fn get(&self, quorum_number: u8, reference_block: u64) -> Result<G1Coordinates> {
+        let mut entries = self.entries.lock().await;
+        entries.clock += 1;
+        let clock = entries.clock;
+        let key = (quorum_number, reference_block);
+        if let Some((apk, used)) = entries.apks.get_mut(&key) {
+            *used = clock;
+            return Ok(*apk);
+        }
+
+        let apk = self
+            .registry
+            .quorum_apk(quorum_number, reference_block)
+            .await?;
+        if entries.apks.len() >= self.capacity
+            && let Some(oldest) = entries
+                .apks
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| *key)
+        {
+            debug!(
+                quorum_number = oldest.0,
+                reference_block = oldest.1,
+                "evicted apk"
+            );
+            entries.apks.remove(&oldest);
+        }
+        entries.apks.insert(key, (apk, clock));
+        Ok(apk)
+    }
+
+    /// Drop every cached APK of `quorum_number`, e.g. after its membership changed
+    pub async fn invalidate(&self, quorum_number: u8) {
+        self.entries
+            .lock()
+            .await
+            .apks
+            .retain(|(quorum, _), _| *quorum != quorum_number);
+    }
+
+    /// Number of cached APKs
+    pub async fn len(&self) -> usize {
+        self.entries.lock().await.apks.len()
+    }
+
+    /// Whether no APK is cached
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
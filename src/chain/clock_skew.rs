@@ -0,0 +1,344 @@
+//! Skew of the local clock against the chain and other sources of time.
+//!
+//! Deadline checks, liveness windows and audit timestamps assume a roughly correct wall
+//! clock: a node with a skewed clock refuses valid rounds or signs expired ones. A
+//! [ClockSkewMonitor] compares the local time to the timestamp of the latest block, and
+//! optionally to the `Date` header of an HTTP server, at startup and periodically. A
+//! skew above [ClockSkewConfig::soft_threshold] is warned about, one above
+//! [ClockSkewConfig::hard_threshold] keeps the node from being ready.
+//!
+//! The latest block was produced up to a block time ago, so against the chain a correct
+//! clock reads as ahead by as much. The thresholds stay well above the block time.
+
+use crate::clock::{Clock, SystemClock};
+use anyhow::{Result, anyhow, bail};
+use futures::future::BoxFuture;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
+
+/// Skew above which a warning is logged, unless configured otherwise
+pub const DEFAULT_SOFT_SKEW_THRESHOLD: Duration = Duration::from_secs(15);
+
+/// Skew above which the node is not ready, unless configured otherwise
+pub const DEFAULT_HARD_SKEW_THRESHOLD: Duration = Duration::from_secs(60);
+
+/// Time between two checks of the skew, unless configured otherwise
+pub const DEFAULT_SKEW_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Source of a reference time the local clock is compared to
+pub trait TimeSource: Send + Sync {
+    /// Name of the source in logs
+    fn name(&self) -> &str;
+
+    /// Current time according to the source
+    fn now(&self) -> BoxFuture<'_, Result<SystemTime>>;
+}
+
+/// Offset of the local clock from a reference time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockSkew {
+    /// Local time minus the reference time in milliseconds, positive when the local
+    /// clock is ahead
+    pub offset_ms: i64,
+}
+
+impl ClockSkew {
+    /// Skew of `local` from `reference`
+    pub fn between(local: SystemTime, reference: SystemTime) -> Self {
+        let millis = |duration: Duration| i64::try_from(duration.as_millis()).unwrap_or(i64::MAX);
+        let offset_ms = match local.duration_since(reference) {
+            Ok(ahead) => millis(ahead),
+            Err(behind) => -millis(behind.duration()),
+        };
+        Self { offset_ms }
+    }
+
+    /// Size of the skew, whichever way the clock is off
+    pub fn magnitude(&self) -> Duration {
+        Duration::from_millis(self.offset_ms.unsigned_abs())
+    }
+}
+
+impl fmt::Display for ClockSkew {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = if self.offset_ms < 0 {
+            "behind"
+        } else {
+            "ahead"
+        };
+        write!(f, "{}ms {direction}", self.offset_ms.unsigned_abs())
+    }
+}
+
+/// How a skew compares to the thresholds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkewSeverity {
+    /// Within the soft threshold
+    Ok,
+    /// Above the soft threshold, the node keeps running
+    Warning,
+    /// Above the hard threshold, the node is not ready
+    Critical,
+}
+
+/// Thresholds and period of the skew checks
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClockSkewConfig {
+    /// Skew above which a warning is logged
+    pub soft_threshold: Duration,
+    /// Skew above which the node is not ready
+    pub hard_threshold: Duration,
+    /// Time between two checks
+    pub interval: Duration,
+}
+
+impl Default for ClockSkewConfig {
+    fn default() -> Self {
+        Self {
+            soft_threshold: DEFAULT_SOFT_SKEW_THRESHOLD,
+            hard_threshold: DEFAULT_HARD_SKEW_THRESHOLD,
+            interval: DEFAULT_SKEW_CHECK_INTERVAL,
+        }
+    }
+}
+
+impl ClockSkewConfig {
+    /// Severity of `skew`
+    pub fn severity(&self, skew: &ClockSkew) -> SkewSeverity {
+        let magnitude = skew.magnitude();
+        if magnitude > self.hard_threshold {
+            SkewSeverity::Critical
+        } else if magnitude > self.soft_threshold {
+            SkewSeverity::Warning
+        } else {
+            SkewSeverity::Ok
+        }
+    }
+}
+
+/// Measures the skew of the local clock against its [TimeSource]s
+///
+/// The estimate is the median of the skews measured against the sources that answered,
+/// published to [ClockSkewMonitor::subscribe] after every check.
+pub struct ClockSkewMonitor {
+    sources: Vec<Arc<dyn TimeSource>>,
+    config: ClockSkewConfig,
+    clock: Arc<dyn Clock>,
+    estimate: watch::Sender<Option<ClockSkew>>,
+}
+
+impl ClockSkewMonitor {
+    /// Monitor with no source yet
+    pub fn new(config: ClockSkewConfig) -> Self {
+        Self {
+            sources: Vec::new(),
+            config,
+            clock: Arc::new(SystemClock),
+            estimate: watch::Sender::new(None),
+        }
+    }
+
+    /// Compare the local clock to `source`
+    pub fn with_source(mut self, source: Arc<dyn TimeSource>) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Read the local time from `clock`
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Thresholds and period of the checks
+    pub fn config(&self) -> &ClockSkewConfig {
+        &self.config
+    }
+
+    /// Latest estimate, `None` until a check succeeded
+    pub fn subscribe(&self) -> watch::Receiver<Option<ClockSkew>> {
+        self.estimate.subscribe()
+    }
+
+    /// Measure the skew against every source and publish the estimate
+    ///
+    /// Fails if no source answered, keeping the previous estimate.
+    pub async fn check(&self) -> Result<ClockSkew> {
+        let mut offsets = Vec::new();
+        for source in &self.sources {
+            let sent = self.clock.monotonic_now();
+            match source.now().await {
+                Ok(reference) => {
+                    // The reference was read about halfway through the request
+                    let now = self.clock.now();
+                    let local = now.checked_sub(self.clock.elapsed(sent) / 2).unwrap_or(now);
+                    let skew = ClockSkew::between(local, reference);
+                    debug!(source = source.name(), %skew, "measured clock skew");
+                    offsets.push(skew.offset_ms);
+                }
+                Err(err) => debug!(source = source.name(), ?err, "time source unavailable"),
+            }
+        }
+        offsets.sort_unstable();
+        let Some(&offset_ms) = offsets.get(offsets.len() / 2) else {
+            bail!("no time source answered");
+        };
+        let skew = ClockSkew { offset_ms };
+        match self.config.severity(&skew) {
+            SkewSeverity::Ok => {}
+            SkewSeverity::Warning => warn!(
+                %skew,
+                threshold = ?self.config.soft_threshold,
+                "local clock skewed"
+            ),
+            SkewSeverity::Critical => error!(
+                %skew,
+                threshold = ?self.config.hard_threshold,
+                "local clock skewed beyond tolerance, node not ready"
+            ),
+        }
+        self.estimate.send_replace(Some(skew));
+        Ok(skew)
+    }
+
+    /// Check the skew, failing above the hard threshold
+    pub async fn ensure_within_tolerance(&self) -> Result<ClockSkew> {
+        let skew = self.check().await?;
+        if self.config.severity(&skew) == SkewSeverity::Critical {
+            bail!(
+                "local clock {skew}, beyond the tolerated {:?}",
+                self.config.hard_threshold
+            );
+        }
+        info!(%skew, "local clock within tolerance");
+        Ok(skew)
+    }
+
+    /// Check the skew every [ClockSkewConfig::interval], until dropped
+    pub async fn run(&self) {
+        let mut ticker = tokio::time::interval(self.config.interval);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = self.check().await {
+                warn!(?err, "failed to measure clock skew");
+            }
+        }
+    }
+}
+
+/// Parse an HTTP `Date` header in the IMF-fixdate format every server sends, such as
+/// `Sun, 06 Nov 1994 08:49:37 GMT`
+pub fn parse_http_date(value: &str) -> Result<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let invalid = || anyhow!("{value} is not an HTTP date");
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    let [_, day, month, year, time, "GMT"] = parts[..] else {
+        return Err(invalid());
+    };
+    let month = MONTHS
+        .iter()
+        .position(|name| *name == month)
+        .ok_or_else(invalid)?
+        + 1;
+    let date = crate::contributor::archive::parse_date(&format!("{year}-{month:02}-{day}"))
+        .map_err(|_| invalid())?;
+    let fields: Vec<&str> = time.split(':').collect();
+    let [hours, minutes, seconds] = fields[..] else {
+        return Err(invalid());
+    };
+    let field = |value: &str, max: u64| {
+        value
+            .parse::<u64>()
+            .ok()
+            .filter(|value| *value <= max)
+            .ok_or_else(invalid)
+    };
+    let seconds = field(hours, 23)? * 3600 + field(minutes, 59)? * 60 + field(seconds, 60)?;
+    Ok(date + Duration::from_secs(seconds))
+}
+
+/// Time of the latest block of the chain
+#[cfg(feature = "chain")]
+pub struct BlockTimeSource {
+    provider: alloy_provider::DynProvider,
+}
+
+#[cfg(feature = "chain")]
+impl BlockTimeSource {
+    /// Source reading the latest block through `provider`
+    pub fn new(provider: alloy_provider::DynProvider) -> Self {
+        Self { provider }
+    }
+
+    /// Source reading the latest block from the RPC endpoint at `http_rpc`
+    pub fn from_url(http_rpc: &str) -> Result<Self> {
+        use alloy_provider::{Provider, ProviderBuilder};
+
+        Ok(Self::new(
+            ProviderBuilder::new().on_http(http_rpc.parse()?).erased(),
+        ))
+    }
+}
+
+#[cfg(feature = "chain")]
+impl TimeSource for BlockTimeSource {
+    fn name(&self) -> &str {
+        "latest_block"
+    }
+
+    fn now(&self) -> BoxFuture<'_, Result<SystemTime>> {
+        use alloy::rpc::types::BlockNumberOrTag;
+        use alloy_provider::Provider;
+
+        Box::pin(async move {
+            let block = self
+                .provider
+                .get_block_by_number(BlockNumberOrTag::Latest)
+                .await?
+                .ok_or_else(|| anyhow!("no latest block"))?;
+            Ok(SystemTime::UNIX_EPOCH + Duration::from_secs(block.header.timestamp))
+        })
+    }
+}
+
+/// Time in the `Date` header of an HTTP server's responses
+#[cfg(feature = "chain")]
+pub struct HttpDateSource {
+    client: reqwest::Client,
+    url: String,
+}
+
+#[cfg(feature = "chain")]
+impl HttpDateSource {
+    /// Source sending `HEAD` requests to `url` with `client`
+    pub fn new(client: reqwest::Client, url: impl Into<String>) -> Self {
+        Self {
+            client,
+            url: url.into(),
+        }
+    }
+}
+
+#[cfg(feature = "chain")]
+impl TimeSource for HttpDateSource {
+    fn name(&self) -> &str {
+        &self.url
+    }
+
+    fn now(&self) -> BoxFuture<'_, Result<SystemTime>> {
+        Box::pin(async move {
+            let response = self.client.head(&self.url).send().await?;
+            let date = response
+                .headers()
+                .get(reqwest::header::DATE)
+                .ok_or_else(|| anyhow!("response has no Date header"))?
+                .to_str()?;
+            parse_http_date(date)
+        })
+    }
+}
@@ -0,0 +1,88 @@
+//! Coalescing of refreshes triggered by bursts of on-chain events.
+//!
+//! A large onboarding can emit dozens of registration or churn events in one block,
+//! and refreshing once per event hammers the RPC for the same result. A
+//! [RefreshCoalescer] collects the triggers arriving within a debounce window opened
+//! by the first of them and makes a single refresh due at its end. Refreshes are also
+//! kept a minimum interval apart, however steadily events keep arriving.
+
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Time triggers are collected for before refreshing, unless configured otherwise
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// Time between two refreshes, unless configured otherwise
+///
+/// One block, as refreshing more often reads the same state.
+pub const DEFAULT_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(12);
+
+/// Debounce window and minimum interval between refreshes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoalescerConfig {
+    /// Quiet time after a trigger before refreshing
+    pub debounce: Duration,
+    /// Time between two refreshes
+    pub min_interval: Duration,
+}
+
+impl Default for CoalescerConfig {
+    fn default() -> Self {
+        Self {
+            debounce: DEFAULT_DEBOUNCE,
+            min_interval: DEFAULT_MIN_REFRESH_INTERVAL,
+        }
+    }
+}
+
+/// Triggers waiting for a refresh, and when the last refresh happened
+#[derive(Clone, Debug)]
+pub struct RefreshCoalescer {
+    config: CoalescerConfig,
+    /// First trigger not covered by a refresh yet
+    window_start: Option<Instant>,
+    triggers: usize,
+    last_refresh: Option<Instant>,
+}
+
+impl RefreshCoalescer {
+    /// Coalescer that has not refreshed yet
+    pub fn new(config: CoalescerConfig) -> Self {
+        Self {
+            config,
+            window_start: None,
+            triggers: 0,
+            last_refresh: None,
+        }
+    }
+
+    /// Record a trigger at `now`, opening a window if none is
+    pub fn trigger(&mut self, now: Instant) {
+        self.window_start.get_or_insert(now);
+        self.triggers += 1;
+    }
+
+    /// When the pending refresh is due, `None` without triggers
+    pub fn deadline(&self) -> Option<Instant> {
+        let window_end = self.window_start? + self.config.debounce;
+        Some(match self.last_refresh {
+            Some(last) => window_end.max(last + self.config.min_interval),
+            None => window_end,
+        })
+    }
+
+    /// Take the refresh due at `now`, returning the number of triggers it covers
+    pub fn poll(&mut self, now: Instant) -> Option<usize> {
+        if self.deadline()? > now {
+            return None;
+        }
+        self.window_start = None;
+        self.last_refresh = Some(now);
+        Some(std::mem::take(&mut self.triggers))
+    }
+
+    /// Triggers not covered by a refresh yet
+    pub fn pending(&self) -> usize {
+        self.triggers
+    }
+}
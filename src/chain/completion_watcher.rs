@@ -0,0 +1,165 @@
+//! Retire rounds whose task response landed on-chain.
+//!
+//! Once a response is submitted for a task, by this node or by any other
+//! submitter, contributors should stop spending effort on its round. The watcher
+//! polls the task-response events of the contract and broadcasts a [RetireRound]
+//! for each of them. Events identify their round by task index, which is the
+//! round number, or by the payload hash the response was signed over.
+
+use alloy_primitives::TxHash;
+use anyhow::Result;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::{Instrument, Span, debug, warn};
+
+/// How often new task responses are polled
+pub const DEFAULT_COMPLETION_POLL_INTERVAL: Duration = Duration::from_secs(12);
+
+const RETIRE_CHANNEL_CAPACITY: usize = 64;
+
+/// Task response event emitted by the contract
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TaskResponded {
+    /// Task index of the response, if the event carries one
+    pub task_index: Option<u64>,
+    /// Payload hash of the response, if the event carries one
+    pub payload_hash: Option<[u8; 32]>,
+    /// Block the response was included in
+    pub block_number: u64,
+    /// Transaction carrying the response
+    pub transaction_hash: TxHash,
+}
+
+/// Round a task response refers to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundRef {
+    /// Round with this number
+    Round(u64),
+    /// Round signed over this payload hash, resolved by the contributor
+    PayloadHash([u8; 32]),
+}
+
+/// Command retiring a round completed on-chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetireRound {
+    /// Round to retire
+    pub round: RoundRef,
+    /// Transaction that completed the round
+    pub transaction_hash: TxHash,
+}
+
+impl RetireRound {
+    /// Retirement for `event`, `None` if it carries neither a task index nor a payload hash
+    pub fn from_event(event: &TaskResponded) -> Option<Self> {
+        let round = match (event.task_index, event.payload_hash) {
+            (Some(task_index), _) => RoundRef::Round(task_index),
+            (None, Some(payload_hash)) => RoundRef::PayloadHash(payload_hash),
+            (None, None) => return None,
+        };
+        Some(Self {
+            round,
+            transaction_hash: event.transaction_hash,
+        })
+    }
+}
+
+/// Emitted by a contributor once it retired a round completed on-chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoundCompletedOnChain {
+    /// Round retired
+    pub round: u64,
+    /// Transaction that completed the round
+    pub transaction_hash: TxHash,
+    /// Signatures collected for the round that were dropped
+    pub dropped_signatures: usize,
+}
+
+/// Source of task response events
+pub trait TaskEventSource: Send + Sync + 'static {
+    /// Latest block number
+    fn block_number(&self) -> impl Future<Output = Result<u64>> + Send;
+
+    /// Task responses emitted in `from_block..=to_block`
+    fn task_responses(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> impl Future<Output = Result<Vec<TaskResponded>>> + Send;
+}
+
+/// Polls a [TaskEventSource] and broadcasts a [RetireRound] per task response
+pub struct CompletionWatcher<S: TaskEventSource> {
+    source: Arc<S>,
+    next_block: u64,
+    poll_interval: Duration,
+    sender: broadcast::Sender<RetireRound>,
+}
+
+impl<S: TaskEventSource> CompletionWatcher<S> {
+    /// Create a watcher reporting task responses from `from_block` on
+    pub fn new(source: Arc<S>, from_block: u64) -> Self {
+        let (sender, _) = broadcast::channel(RETIRE_CHANNEL_CAPACITY);
+        Self {
+            source,
+            next_block: from_block,
+            poll_interval: DEFAULT_COMPLETION_POLL_INTERVAL,
+            sender,
+        }
+    }
+
+    /// Poll for task responses every `poll_interval`
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Subscribe to retirements
+    pub fn subscribe(&self) -> broadcast::Receiver<RetireRound> {
+        self.sender.subscribe()
+    }
+
+    /// Read the task responses emitted since the last poll and broadcast their retirements
+    pub async fn poll_once(&mut self) -> Result<Vec<RetireRound>> {
+        let block_number = self.source.block_number().await?;
+        if block_number < self.next_block {
+            return Ok(Vec::new());
+        }
+        let events = self
+            .source
+            .task_responses(self.next_block, block_number)
+            .await?;
+        self.next_block = block_number + 1;
+
+        let mut retired = Vec::with_capacity(events.len());
+        for event in events {
+            let Some(retire) = RetireRound::from_event(&event) else {
+                warn!(?event, "task response without task index or payload hash");
+                continue;
+            };
+            debug!(round = ?retire.round, "task completed on-chain");
+            // No subscribers is not an error, the retirement is simply dropped
+            let _ = self.sender.send(retire.clone());
+            retired.push(retire);
+        }
+        Ok(retired)
+    }
+
+    /// Poll the source in a background task, within the current span
+    pub fn spawn(mut self) -> JoinHandle<()> {
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(self.poll_interval);
+                loop {
+                    interval.tick().await;
+                    if let Err(err) = self.poll_once().await {
+                        warn!(?err, "failed to read task responses");
+                    }
+                }
+            }
+            .instrument(Span::current()),
+        )
+    }
+}
@@ -0,0 +1,168 @@
+//! Forwarding of quorum certificates to AVS contracts on other EVM chains.
+//!
+//! Deployments responding on several chains, e.g. L2s, register one destination per
+//! chain. A certificate is submitted to every destination concurrently, each through
+//! its own submitter, so a failing chain never blocks or delays the others.
+
+use crate::chain::submitter::ChainSubmitter;
+use crate::contributor::QuorumCertificate;
+use alloy::rpc::types::TransactionRequest;
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy_json_abi::Function;
+use alloy_primitives::{Address, TxHash, U256};
+use anyhow::{Result, bail};
+use futures::future::join_all;
+use std::future::Future;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Entrypoint receiving a quorum certificate on a destination chain
+pub const SUBMIT_CERTIFICATE_FUNCTION: &str =
+    "function submitCertificate(uint256 round, bytes32 payload, bytes signature, uint256 signers)";
+
+/// Transaction submitting `qc` to `contract`
+pub fn certificate_transaction(
+    contract: Address,
+    qc: &QuorumCertificate,
+) -> Result<TransactionRequest> {
+    let function = Function::parse(SUBMIT_CERTIFICATE_FUNCTION)?;
+    let calldata = function.abi_encode_input(&[
+        DynSolValue::Uint(U256::from(qc.round), 256),
+        DynSolValue::FixedBytes(qc.payload.into(), 32),
+        DynSolValue::Bytes(qc.signature.to_vec()),
+        DynSolValue::Uint(qc.signers.to_u256(), 256),
+    ])?;
+    Ok(TransactionRequest::default()
+        .to(contract)
+        .input(calldata.into()))
+}
+
+/// AVS contract on a destination chain
+struct Destination {
+    chain_id: u64,
+    contract: Address,
+    submitter: Arc<dyn ChainSubmitter>,
+}
+
+/// Submits quorum certificates to the AVS contracts of several chains
+#[derive(Default)]
+pub struct CrossChainAggregationRouter {
+    destinations: Vec<Destination>,
+    #[cfg(feature = "chain")]
+    signer: Option<alloy_signer_local::PrivateKeySigner>,
+    #[cfg(feature = "chain")]
+    tls: Option<crate::config::TlsConfig>,
+}
+
+impl CrossChainAggregationRouter {
+    /// Router without destinations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sign the transactions of destinations added by RPC URL with `signer`
+    #[cfg(feature = "chain")]
+    pub fn with_signer(mut self, signer: alloy_signer_local::PrivateKeySigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Reach the nodes of destinations added by RPC URL over TLS set up with `tls`
+    #[cfg(feature = "chain")]
+    pub fn with_tls(mut self, tls: crate::config::TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Submit certificates to `contract_addr` on `chain_id` through the node at `rpc_url`
+    #[cfg(feature = "chain")]
+    pub fn add_destination(
+        &mut self,
+        chain_id: u64,
+        contract_addr: Address,
+        rpc_url: String,
+    ) -> Result<()> {
+        let Some(signer) = self.signer.clone() else {
+            bail!("no signer for destination chain {chain_id}");
+        };
+        let submitter = match &self.tls {
+            Some(tls) => crate::chain::HttpSubmitter::with_tls(
+                vec![rpc_url],
+                signer,
+                Default::default(),
+                Default::default(),
+                tls,
+            )?,
+            None => crate::chain::HttpSubmitter::new(rpc_url, signer)?,
+        };
+        self.add_destination_with_submitter(chain_id, contract_addr, Arc::new(submitter))
+    }
+
+    /// Submit certificates to `contract_addr` on `chain_id` through `submitter`
+    pub fn add_destination_with_submitter(
+        &mut self,
+        chain_id: u64,
+        contract_addr: Address,
+        submitter: Arc<dyn ChainSubmitter>,
+    ) -> Result<()> {
+        if self
+            .destinations
+            .iter()
+            .any(|destination| destination.chain_id == chain_id)
+        {
+            bail!("destination chain {chain_id} already registered");
+        }
+        self.destinations.push(Destination {
+            chain_id,
+            contract: contract_addr,
+            submitter,
+        });
+        Ok(())
+    }
+
+    /// Chain ids of the destinations, in the order they were added
+    pub fn chain_ids(&self) -> Vec<u64> {
+        self.destinations
+            .iter()
+            .map(|destination| destination.chain_id)
+            .collect()
+    }
+
+    /// Submission of `qc` to each destination, in the order they were added
+    ///
+    /// The submissions are independent, a failed one does not affect the others.
+    pub fn route<'a>(
+        &'a self,
+        qc: &QuorumCertificate,
+    ) -> Vec<impl Future<Output = Result<TxHash>> + use<'a>> {
+        self.destinations
+            .iter()
+            .map(|destination| {
+                let transaction =
+                    certificate_transaction(destination.contract, qc).map(|mut transaction| {
+                        transaction.chain_id = Some(destination.chain_id);
+                        transaction
+                    });
+                async move { destination.submitter.submit(transaction?).await }
+            })
+            .collect()
+    }
+
+    /// Submit `qc` to every destination concurrently, returning the result of each chain
+    pub async fn submit(&self, qc: &QuorumCertificate) -> Vec<(u64, Result<TxHash>)> {
+        let results = join_all(self.route(qc)).await;
+        self.chain_ids()
+            .into_iter()
+            .zip(results)
+            .inspect(|(chain_id, result)| match result {
+                Ok(hash) => info!(chain_id, round = qc.round, %hash, "submitted certificate"),
+                Err(err) => warn!(
+                    chain_id,
+                    round = qc.round,
+                    ?err,
+                    "failed to submit certificate"
+                ),
+            })
+            .collect()
+    }
+}
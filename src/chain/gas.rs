@@ -0,0 +1,100 @@
+//! EIP-1559 fees of the transactions the node sends.
+//!
+//! A transaction paying less than the base fee is dropped, and the base fee can
+//! rise by 12.5% per block. The [GasOracle] reads the fee history of the last
+//! [FEE_HISTORY_BLOCKS] blocks, tips the median of each block's 10th percentile
+//! priority fee, and caps the fee at twice the next base fee plus that tip, which
+//! stays valid through several consecutive full blocks. Estimates are reused for
+//! [FEE_CACHE_TTL], about one block.
+
+use alloy::rpc::types::FeeHistory;
+use anyhow::{Result, anyhow};
+use futures::future::BoxFuture;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::debug;
+
+/// Blocks of fee history an estimate is based on
+pub const FEE_HISTORY_BLOCKS: u64 = 10;
+
+/// Percentiles of the priority fees paid in each block, the first one is tipped
+pub const REWARD_PERCENTILES: [f64; 3] = [10.0, 50.0, 90.0];
+
+/// Time an estimate is reused for
+pub const FEE_CACHE_TTL: Duration = Duration::from_secs(12);
+
+/// Reads the fee history of the chain
+pub trait FeeHistorySource: Send + Sync {
+    /// `eth_feeHistory` over the last `block_count` blocks up to the latest one
+    fn fee_history<'a>(
+        &'a self,
+        block_count: u64,
+        reward_percentiles: &'a [f64],
+    ) -> BoxFuture<'a, Result<FeeHistory>>;
+}
+
+/// Fees of a transaction, in wei per gas
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fees {
+    /// Tip paid to the block builder
+    pub max_priority_fee_per_gas: u128,
+    /// Cap on the base fee and tip together
+    pub max_fee_per_gas: u128,
+}
+
+impl Fees {
+    /// Fees for the next block, from a history queried with [REWARD_PERCENTILES]
+    pub fn from_history(history: &FeeHistory) -> Result<Self> {
+        let base_fee = history
+            .base_fee_per_gas
+            .last()
+            .copied()
+            .ok_or_else(|| anyhow!("fee history has no base fee"))?;
+        let mut tips: Vec<u128> = history
+            .reward
+            .iter()
+            .flatten()
+            .filter_map(|rewards| rewards.first().copied())
+            .collect();
+        tips.sort_unstable();
+        let Some(&tip) = tips.get(tips.len() / 2) else {
+            return Err(anyhow!("fee history has no priority fees"));
+        };
+        Ok(Self {
+            max_priority_fee_per_gas: tip,
+            max_fee_per_gas: base_fee.saturating_mul(2).saturating_add(tip),
+        })
+    }
+}
+
+/// Estimates EIP-1559 fees from the recent fee history, caching them for a block
+#[derive(Debug, Default)]
+pub struct GasOracle {
+    cached: Mutex<Option<(Instant, Fees)>>,
+}
+
+impl GasOracle {
+    /// Oracle without cached fees
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `(max_priority_fee_per_gas, max_fee_per_gas)` for a transaction sent now
+    pub async fn estimate_fees(&self, source: &impl FeeHistorySource) -> Result<(u128, u128)> {
+        let mut cached = self.cached.lock().await;
+        let fees = match *cached {
+            Some((read, fees)) if read.elapsed() < FEE_CACHE_TTL => fees,
+            _ => {
+                let history = source
+                    .fee_history(FEE_HISTORY_BLOCKS, &REWARD_PERCENTILES)
+                    .await?;
+                let fees = Fees::from_history(&history)?;
+                debug!(?fees, "estimated fees");
+                *cached = Some((Instant::now(), fees));
+                fees
+            }
+        };
+        Ok((fees.max_priority_fee_per_gas, fees.max_fee_per_gas))
+    }
+}
@@ -0,0 +1,204 @@
+//! Submission of transactions through HTTP endpoints, signed with a local key.
+
+use crate::chain::gas::{FeeHistorySource, GasOracle};
+use crate::chain::multi_rpc::{MultiRpcConfig, MultiRpcProvider, endpoint_name};
+use crate::chain::nonce::{NonceManager, NonceSource};
+use crate::chain::pool::{PoolConfig, RpcConnectionPool};
+use crate::chain::submitter::ChainSubmitter;
+use crate::config::{TlsConfig, require_https};
+#[cfg(feature = "observability")]
+use crate::metrics::RpcMetrics;
+use alloy::network::EthereumWallet;
+use alloy::rpc::types::{BlockNumberOrTag, FeeHistory, TransactionRequest};
+use alloy_primitives::{Address, TxHash};
+use alloy_provider::{DynProvider, Provider, ProviderBuilder};
+use alloy_rpc_client::RpcClient;
+use alloy_signer_local::PrivateKeySigner;
+use alloy_transport_http::Http;
+use anyhow::Result;
+use futures::future::BoxFuture;
+
+/// Submitter sending transactions through HTTP endpoints, signed with a local key
+///
+/// Providers are pooled per endpoint, so consecutive submissions reuse their
+/// connections. With several endpoints, submissions go round-robin and fall back to
+/// the next endpoint when one fails. Nonces are assigned by a [NonceManager], so
+/// concurrent submissions never share one. Transactions without fees pay the
+/// EIP-1559 fees estimated by a [GasOracle].
+pub struct HttpSubmitter {
+    endpoints: MultiRpcProvider<RpcConnectionPool<DynProvider>>,
+    address: Address,
+    nonces: NonceManager,
+    gas: GasOracle,
+}
+
+impl HttpSubmitter {
+    /// Submitter signing with `signer` through a single endpoint
+    pub fn new(http_rpc: String, signer: PrivateKeySigner) -> Result<Self> {
+        Self::with_pool_config(http_rpc, signer, PoolConfig::default())
+    }
+
+    /// Submitter through a single endpoint, pooling connections with `config`
+    pub fn with_pool_config(
+        http_rpc: String,
+        signer: PrivateKeySigner,
+        config: PoolConfig,
+    ) -> Result<Self> {
+        Self::with_endpoints(vec![http_rpc], signer, config, MultiRpcConfig::default())
+    }
+
+    /// Submitter spreading submissions over `http_rpcs`
+    ///
+    /// Fails if `http_rpcs` is empty.
+    pub fn with_endpoints(
+        http_rpcs: Vec<String>,
+        signer: PrivateKeySigner,
+        config: PoolConfig,
+        multi_rpc: MultiRpcConfig,
+    ) -> Result<Self> {
+        Self::with_client(http_rpcs, signer, config, multi_rpc, reqwest::Client::new())
+    }
+
+    /// Submitter spreading submissions over `http_rpcs` through TLS connections set up
+    /// with `tls`
+    ///
+    /// Fails if an endpoint is not an `https://` URL or `tls` cannot be loaded.
+    pub fn with_tls(
+        http_rpcs: Vec<String>,
+        signer: PrivateKeySigner,
+        config: PoolConfig,
+        multi_rpc: MultiRpcConfig,
+        tls: &TlsConfig,
+    ) -> Result<Self> {
+        for http_rpc in &http_rpcs {
+            require_https(http_rpc)?;
+        }
+        let client = tls.client()?;
+        Self::with_client(http_rpcs, signer, config, multi_rpc, client)
+    }
+
+    /// Submitter whose pooled providers all send their requests through `client`
+    fn with_client(
+        http_rpcs: Vec<String>,
+        signer: PrivateKeySigner,
+        config: PoolConfig,
+        multi_rpc: MultiRpcConfig,
+        client: reqwest::Client,
+    ) -> Result<Self> {
+        let address = signer.address();
+        let wallet = EthereumWallet::from(signer);
+        let endpoints = http_rpcs
+            .into_iter()
+            .map(|http_rpc| {
+                let name = endpoint_name(&http_rpc);
+                let wallet = wallet.clone();
+                let client = client.clone();
+                let pool = RpcConnectionPool::new(config.clone(), move || {
+                    let transport = Http::with_client(client.clone(), http_rpc.parse()?);
+                    let is_local = transport.guess_local();
+                    let provider = ProviderBuilder::new()
+                        .wallet(wallet.clone())
+                        .on_client(RpcClient::new(transport, is_local));
+                    Ok(provider.erased())
+                });
+                (name, pool)
+            })
+            .collect();
+        Ok(Self {
+            endpoints: MultiRpcProvider::new(endpoints, multi_rpc)?,
+            address,
+            nonces: NonceManager::new(),
+            gas: GasOracle::new(),
+        })
+    }
+
+    /// Report requests to each endpoint to `metrics`
+    #[cfg(feature = "observability")]
+    pub fn with_metrics(mut self, metrics: RpcMetrics) -> Self {
+        self.endpoints = self.endpoints.with_metrics(metrics);
+        self
+    }
+
+    /// Send `transaction` through the first endpoint accepting it
+    async fn send(&self, transaction: TransactionRequest) -> Result<TxHash> {
+        self.endpoints
+            .call(|pool| {
+                let transaction = transaction.clone();
+                Box::pin(async move {
+                    let provider = pool.acquire().await?;
+                    let result = provider.send_transaction(transaction).await;
+                    match result {
+                        Ok(pending) => Ok(*pending.tx_hash()),
+                        Err(err) => {
+                            // Start over with a fresh connection after a failure
+                            provider.discard();
+                            Err(err.into())
+                        }
+                    }
+                })
+            })
+            .await
+    }
+}
+
+impl NonceSource for HttpSubmitter {
+    fn latest_nonce(&self) -> BoxFuture<'_, Result<u64>> {
+        let address = self.address;
+        Box::pin(async move {
+            self.endpoints
+                .call(|pool| {
+                    Box::pin(async move {
+                        let provider = pool.acquire().await?;
+                        // Counted at the latest block by default
+                        Ok(provider.get_transaction_count(address).await?)
+                    })
+                })
+                .await
+        })
+    }
+}
+
+impl FeeHistorySource for HttpSubmitter {
+    fn fee_history<'a>(
+        &'a self,
+        block_count: u64,
+        reward_percentiles: &'a [f64],
+    ) -> BoxFuture<'a, Result<FeeHistory>> {
+        Box::pin(async move {
+            self.endpoints
+                .call(|pool| {
+                    let reward_percentiles = reward_percentiles.to_vec();
+                    Box::pin(async move {
+                        let provider = pool.acquire().await?;
+                        Ok(provider
+                            .get_fee_history(
+                                block_count,
+                                BlockNumberOrTag::Latest,
+                                &reward_percentiles,
+                            )
+                            .await?)
+                    })
+                })
+                .await
+        })
+    }
+}
+
+impl ChainSubmitter for HttpSubmitter {
+    fn submit(&self, mut transaction: TransactionRequest) -> BoxFuture<'_, Result<TxHash>> {
+        Box::pin(async move {
+            if transaction.gas_price.is_none() && transaction.max_fee_per_gas.is_none() {
+                let (max_priority_fee, max_fee) = self.gas.estimate_fees(self).await?;
+                transaction.max_priority_fee_per_gas = Some(max_priority_fee);
+                transaction.max_fee_per_gas = Some(max_fee);
+            }
+            self.nonces
+                .submit(self, |nonce| {
+                    let mut transaction = transaction.clone();
+                    transaction.nonce = Some(nonce);
+                    Box::pin(self.send(transaction))
+                })
+                .await
+        })
+    }
+}
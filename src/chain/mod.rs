@@ -0,0 +1,61 @@
+//! On-chain state the node tracks while running.
+
+#[cfg(test)]
+mod tests;
+
+pub mod abi;
+pub mod apk_cache;
+pub mod clock_skew;
+pub mod coalescer;
+pub mod completion_watcher;
+pub mod cross_chain;
+pub mod gas;
+#[cfg(feature = "chain")]
+pub mod http_submitter;
+pub mod multi_rpc;
+pub mod nonce;
+pub mod operator_state;
+pub mod pool;
+pub mod quorum_updater;
+pub mod registration;
+#[cfg(feature = "chain")]
+pub mod service_manager;
+pub mod stake_cache;
+pub mod submitter;
+pub mod task_responder;
+
+pub use abi::{CheckSignaturesParams, encode_for_signature_checker};
+pub use apk_cache::{ApkCache, ApkRegistry};
+#[cfg(feature = "chain")]
+pub use clock_skew::{BlockTimeSource, HttpDateSource};
+pub use clock_skew::{ClockSkew, ClockSkewConfig, ClockSkewMonitor, SkewSeverity, TimeSource};
+pub use coalescer::{CoalescerConfig, RefreshCoalescer};
+pub use completion_watcher::{
+    CompletionWatcher, RetireRound, RoundCompletedOnChain, RoundRef, TaskEventSource, TaskResponded,
+};
+pub use cross_chain::CrossChainAggregationRouter;
+pub use gas::{FeeHistorySource, GasOracle};
+#[cfg(feature = "chain")]
+pub use http_submitter::HttpSubmitter;
+pub use multi_rpc::{MultiRpcConfig, MultiRpcProvider};
+pub use nonce::{NonceManager, NonceSource};
+#[cfg(feature = "chain")]
+pub use operator_state::BlsApkRegistryKeys;
+pub use operator_state::{OperatorInfo, OperatorKeySource, OperatorStateRetrieverClient};
+pub use pool::{PoolConfig, PooledConnection, RpcConnectionPool};
+pub use quorum_updater::{
+    DynamicQuorumUpdater, QuorumMember, QuorumRegistry, QuorumUpdated, RefreshTrigger,
+};
+#[cfg(feature = "chain")]
+pub use registration::EigenLayerRegistrations;
+pub use registration::{
+    AvsRegistrationStatus, OperatorRegistry, RegistrationChecker, RegistrationConfig,
+    RegistrationSource,
+};
+#[cfg(feature = "chain")]
+pub use service_manager::{AvsMetadata, ServiceManagerClient};
+#[cfg(feature = "chain")]
+pub use stake_cache::OperatorStateStakes;
+pub use stake_cache::{OperatorStake, StakeRetriever, StakeSnapshot, StakeSnapshotCache};
+pub use submitter::ChainSubmitter;
+pub use task_responder::{TaskResponder, TaskResponderConfig, TaskResponse};
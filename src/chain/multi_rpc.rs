@@ -0,0 +1,225 @@
+//! Requests spread over several RPC endpoints, falling back when one fails.
+//!
+//! Calls start at the next endpoint in round-robin order and move on to the
+//! following one if it fails. A failed endpoint is left out for a cooldown that
+//! doubles with each consecutive failure, up to [MultiRpcConfig::max_cooldown_secs],
+//! and is used again as soon as it answers. If every endpoint is cooling down, the
+//! one whose cooldown ends first is tried rather than failing outright.
+
+#[cfg(feature = "observability")]
+use crate::metrics::RpcMetrics;
+use anyhow::{Result, anyhow, bail};
+use futures::future::BoxFuture;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tokio::time::Instant;
+use url::Url;
+
+/// Cooldown after a first failure by default
+pub const DEFAULT_COOLDOWN_SECS: u64 = 5;
+
+/// Longest cooldown by default
+pub const DEFAULT_MAX_COOLDOWN_SECS: u64 = 300;
+
+/// Configuration of a [MultiRpcProvider]
+#[derive(Clone, Debug)]
+pub struct MultiRpcConfig {
+    /// Time an endpoint is left out after it fails once
+    pub cooldown_secs: u64,
+    /// Upper bound of the cooldown as consecutive failures double it
+    pub max_cooldown_secs: u64,
+}
+
+impl Default for MultiRpcConfig {
+    fn default() -> Self {
+        Self {
+            cooldown_secs: DEFAULT_COOLDOWN_SECS,
+            max_cooldown_secs: DEFAULT_MAX_COOLDOWN_SECS,
+        }
+    }
+}
+
+impl MultiRpcConfig {
+    /// Cooldown after `failures` consecutive failures
+    pub fn cooldown(&self, failures: u32) -> Duration {
+        let factor = 1u64 << failures.saturating_sub(1).min(32);
+        Duration::from_secs(
+            self.cooldown_secs
+                .saturating_mul(factor)
+                .min(self.max_cooldown_secs),
+        )
+    }
+}
+
+/// Health of one endpoint
+#[derive(Clone, Copy, Debug, Default)]
+struct Health {
+    failures: u32,
+    unavailable_until: Option<Instant>,
+}
+
+impl Health {
+    fn is_available(&self, now: Instant) -> bool {
+        self.unavailable_until.is_none_or(|until| until <= now)
+    }
+}
+
+/// Endpoint provider with the name it is reported under
+struct Endpoint<P> {
+    name: String,
+    provider: P,
+}
+
+/// Providers of several endpoints used in round-robin order with fallback
+pub struct MultiRpcProvider<P> {
+    endpoints: Vec<Endpoint<P>>,
+    health: Mutex<Vec<Health>>,
+    next: AtomicUsize,
+    config: MultiRpcConfig,
+    #[cfg(feature = "observability")]
+    metrics: RpcMetrics,
+}
+
+impl<P> MultiRpcProvider<P> {
+    /// Create a provider over `endpoints`, each a name and the provider reaching it
+    ///
+    /// Fails if `endpoints` is empty.
+    pub fn new(endpoints: Vec<(String, P)>, config: MultiRpcConfig) -> Result<Self> {
+        if endpoints.is_empty() {
+            bail!("at least one rpc endpoint is required");
+        }
+        Ok(Self {
+            health: Mutex::new(vec![Health::default(); endpoints.len()]),
+            endpoints: endpoints
+                .into_iter()
+                .map(|(name, provider)| Endpoint { name, provider })
+                .collect(),
+            next: AtomicUsize::new(0),
+            config,
+            #[cfg(feature = "observability")]
+            metrics: RpcMetrics::new(),
+        })
+    }
+
+    /// Report requests to `metrics`
+    #[cfg(feature = "observability")]
+    pub fn with_metrics(mut self, metrics: RpcMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Names of the endpoints, in round-robin order
+    pub fn endpoints(&self) -> impl Iterator<Item = &str> {
+        self.endpoints.iter().map(|endpoint| endpoint.name.as_str())
+    }
+
+    /// Whether the endpoint at `index` is outside its cooldown
+    pub fn is_available(&self, index: usize) -> bool {
+        self.health
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(index)
+            .is_some_and(|health| health.is_available(Instant::now()))
+    }
+
+    /// Run `request` on the first endpoint that answers it
+    ///
+    /// Fails with the last error once every endpoint tried has failed.
+    pub async fn call<T, F>(&self, request: F) -> Result<T>
+    where
+        F: for<'p> Fn(&'p P) -> BoxFuture<'p, Result<T>>,
+    {
+        let mut last_error = None;
+        for index in self.order() {
+            let Some(endpoint) = self.endpoints.get(index) else {
+                continue;
+            };
+            match request(&endpoint.provider).await {
+                Ok(value) => {
+                    self.succeeded(index);
+                    return Ok(value);
+                }
+                Err(err) => {
+                    self.failed(index);
+                    tracing::warn!(endpoint = %endpoint.name, ?err, "rpc request failed");
+                    last_error = Some(err);
+                }
+            }
+        }
+        match last_error {
+            Some(err) => Err(anyhow!("all rpc endpoints failed, last error: {err}")),
+            None => Err(anyhow!("no rpc endpoint to try")),
+        }
+    }
+
+    /// Endpoints to try for the next call
+    ///
+    /// Available endpoints in round-robin order, or the endpoint whose cooldown ends
+    /// first if none is.
+    fn order(&self) -> Vec<usize> {
+        let count = self.endpoints.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % count;
+        let now = Instant::now();
+        let health = self.health.lock().unwrap_or_else(PoisonError::into_inner);
+        let available: Vec<usize> = (0..count)
+            .map(|offset| (start + offset) % count)
+            .filter(|&index| {
+                health
+                    .get(index)
+                    .is_some_and(|health| health.is_available(now))
+            })
+            .collect();
+        if !available.is_empty() {
+            return available;
+        }
+        health
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, health)| health.unavailable_until)
+            .map(|(index, _)| index)
+            .into_iter()
+            .collect()
+    }
+
+    fn succeeded(&self, index: usize) {
+        if let Some(health) = self
+            .health
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(index)
+        {
+            *health = Health::default();
+        }
+        #[cfg(feature = "observability")]
+        if let Some(endpoint) = self.endpoints.get(index) {
+            self.metrics.request_succeeded(&endpoint.name);
+        }
+    }
+
+    fn failed(&self, index: usize) {
+        let mut health = self.health.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(health) = health.get_mut(index) {
+            health.failures = health.failures.saturating_add(1);
+            health.unavailable_until = Some(Instant::now() + self.config.cooldown(health.failures));
+        }
+        #[cfg(feature = "observability")]
+        if let Some(endpoint) = self.endpoints.get(index) {
+            self.metrics.request_failed(&endpoint.name);
+        }
+    }
+}
+
+/// Name an endpoint is reported under
+///
+/// Only the host and port are kept, as paths and queries often carry API keys.
+pub fn endpoint_name(endpoint: &str) -> String {
+    match Url::parse(endpoint) {
+        Ok(url) => match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            _ => url.scheme().to_string(),
+        },
+        Err(_) => "invalid".to_string(),
+    }
+}
@@ -0,0 +1,95 @@
+//! Nonces of the transactions the node sends from its account.
+//!
+//! Two rounds finalizing at the same time would otherwise both have their nonce
+//! filled from the same transaction count, and one of the transactions would be
+//! rejected. The [NonceManager] tracks the next nonce locally, starting from the
+//! account's transaction count at the latest block, and serializes submissions so
+//! a nonce is only consumed once the node accepted the transaction carrying it.
+
+use anyhow::Result;
+use futures::future::BoxFuture;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Times a submission is retried after being rejected for a stale nonce
+pub const MAX_NONCE_RETRIES: usize = 3;
+
+/// Reads the transaction count of the submitting account
+pub trait NonceSource: Send + Sync {
+    /// Number of transactions of the account at the latest block
+    fn latest_nonce(&self) -> BoxFuture<'_, Result<u64>>;
+}
+
+/// Whether `err` is a node rejecting a transaction whose nonce was already used
+pub fn is_nonce_too_low(err: &anyhow::Error) -> bool {
+    format!("{err:#}").to_lowercase().contains("nonce too low")
+}
+
+/// Hands out consecutive nonces and serializes the submissions using them
+///
+/// The nonce is read from a [NonceSource] on first use and after stale nonces.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    /// Next nonce to use, `None` until read from the source
+    next: Mutex<Option<u64>>,
+}
+
+impl NonceManager {
+    /// Manager reading the nonce from its source on first use
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reserve the next nonce for a transaction sent outside [NonceManager::submit]
+    pub async fn next_nonce(&self, source: &impl NonceSource) -> Result<u64> {
+        let mut next = self.next.lock().await;
+        let nonce = match *next {
+            Some(nonce) => nonce,
+            None => source.latest_nonce().await?,
+        };
+        *next = Some(nonce + 1);
+        Ok(nonce)
+    }
+
+    /// Read the next nonce from the chain again, returning it
+    pub async fn resync(&self, source: &impl NonceSource) -> Result<u64> {
+        let mut next = self.next.lock().await;
+        let nonce = source.latest_nonce().await?;
+        *next = Some(nonce);
+        Ok(nonce)
+    }
+
+    /// Send a transaction with the next nonce through `send`
+    ///
+    /// Submissions run one at a time. The nonce is consumed only if `send` succeeds,
+    /// so a failed submission leaves no gap. If the node reports the nonce as too
+    /// low, the nonce is read from the chain again and the submission retried.
+    pub async fn submit<'a, T, F>(&self, source: &impl NonceSource, send: F) -> Result<T>
+    where
+        F: Fn(u64) -> BoxFuture<'a, Result<T>>,
+    {
+        let mut next = self.next.lock().await;
+        let mut retries = 0;
+        loop {
+            let nonce = match *next {
+                Some(nonce) => nonce,
+                None => source.latest_nonce().await?,
+            };
+            *next = Some(nonce);
+            match send(nonce).await {
+                Ok(value) => {
+                    debug!(nonce, "submitted transaction");
+                    *next = Some(nonce + 1);
+                    return Ok(value);
+                }
+                Err(err) if is_nonce_too_low(&err) && retries < MAX_NONCE_RETRIES => {
+                    retries += 1;
+                    let latest = source.latest_nonce().await?;
+                    warn!(nonce, latest, retries, "nonce too low, resynced from chain");
+                    *next = Some(latest);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
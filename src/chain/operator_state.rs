@@ -0,0 +1,187 @@
+//! Operator sets read in one call to the operator state retriever.
+//!
+//! The retriever returns every operator of a quorum with its stake at a block, so a
+//! new operator set costs one call instead of one per operator. BLS keys never
+//! change once registered, so they are read once per operator and kept. Operator
+//! sets are cached by `(quorum_id, block_number)`, evicting the least recently
+//! used one beyond [MAX_CACHED_OPERATOR_SETS].
+
+use crate::chain::stake_cache::StakeRetriever;
+use alloy_primitives::Address;
+use anyhow::{Result, anyhow};
+use bn254::{G1PublicKey, PublicKey as PubKey};
+use futures::future::try_join_all;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::debug;
+
+/// Number of `(quorum, block)` operator sets kept
+pub const MAX_CACHED_OPERATOR_SETS: usize = 32;
+
+/// Registered operator of a quorum with its keys and stake
+#[derive(Clone)]
+pub struct OperatorInfo {
+    /// G2 key the operator signs with
+    pub pubkey: PubKey,
+    /// G1 key of the operator, used to aggregate its signatures
+    pub g1_pubkey: G1PublicKey,
+    /// Stake of the operator in the quorum
+    pub stake: u128,
+}
+
+/// Source of the BLS keys an operator registered
+pub trait OperatorKeySource: Send + Sync + 'static {
+    /// G2 and G1 keys of `operator`
+    fn operator_keys(
+        &self,
+        operator: Address,
+    ) -> impl Future<Output = Result<(PubKey, G1PublicKey)>> + Send;
+}
+
+#[derive(Default)]
+struct Entries {
+    sets: HashMap<(u8, u64), (Vec<OperatorInfo>, u64)>,
+    keys: HashMap<Address, (PubKey, G1PublicKey)>,
+    /// Incremented on every access, the set with the lowest value is evicted first
+    clock: u64,
+}
+
+/// Client reading operator sets through the operator state retriever
+pub struct OperatorStateRetrieverClient<R: StakeRetriever, K: OperatorKeySource> {
+    retriever: Arc<R>,
+    keys: Arc<K>,
+    entries: Mutex<Entries>,
+}
+
+impl<R: StakeRetriever, K: OperatorKeySource> OperatorStateRetrieverClient<R, K> {
+    /// Client reading stakes from `retriever` and keys from `keys`
+    pub fn new(retriever: Arc<R>, keys: Arc<K>) -> Self {
+        Self {
+            retriever,
+            keys,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Operators of `quorum_id` at `block_number`, in the order the retriever lists them
+    ///
+    /// Lookups are serialized so concurrent rounds at the same block read once.
+    pub async fn get_operators_for_quorum(
+        &self,
+        quorum_id: u8,
+        block_number: u64,
+    ) -> Result<Vec<OperatorInfo>> {
+        let mut entries = self.entries.lock().await;
+        entries.clock += 1;
+        let clock = entries.clock;
+        let key = (quorum_id, block_number);
+        if let Some((operators, used)) = entries.sets.get_mut(&key) {
+            *used = clock;
+            return Ok(operators.clone());
+        }
+
+        let stakes = self
+            .retriever
+            .operator_stakes(quorum_id, block_number)
+            .await?;
+        let unknown: Vec<Address> = stakes
+            .iter()
+            .map(|operator| operator.operator)
+            .filter(|operator| !entries.keys.contains_key(operator))
+            .collect();
+        let fetched = try_join_all(
+            unknown
+                .iter()
+                .map(|operator| self.keys.operator_keys(*operator)),
+        )
+        .await?;
+        entries.keys.extend(unknown.into_iter().zip(fetched));
+
+        let operators = stakes
+            .into_iter()
+            .map(|operator| {
+                let (pubkey, g1_pubkey) = entries
+                    .keys
+                    .get(&operator.operator)
+                    .cloned()
+                    .ok_or_else(|| anyhow!("no keys of operator {}", operator.operator))?;
+                Ok(OperatorInfo {
+                    pubkey,
+                    g1_pubkey,
+                    stake: u128::try_from(operator.stake)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if entries.sets.len() >= MAX_CACHED_OPERATOR_SETS
+            && let Some(oldest) = entries
+                .sets
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| *key)
+        {
+            debug!(
+                quorum_id = oldest.0,
+                block_number = oldest.1,
+                "evicted operator set"
+            );
+            entries.sets.remove(&oldest);
+        }
+        entries.sets.insert(key, (operators.clone(), clock));
+        Ok(operators)
+    }
+
+    /// Number of cached operator sets
+    pub async fn len(&self) -> usize {
+        self.entries.lock().await.sets.len()
+    }
+
+    /// Whether no operator set is cached
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
+
+/// BLS keys read from the `BLSApkRegistry`
+#[cfg(feature = "chain")]
+pub struct BlsApkRegistryKeys {
+    provider: alloy_provider::DynProvider,
+    apk_registry: Address,
+}
+
+#[cfg(feature = "chain")]
+impl BlsApkRegistryKeys {
+    /// Keys of the `BLSApkRegistry` at `apk_registry`
+    pub fn new(provider: alloy_provider::DynProvider, apk_registry: Address) -> Self {
+        Self {
+            provider,
+            apk_registry,
+        }
+    }
+}
+
+#[cfg(feature = "chain")]
+impl OperatorKeySource for BlsApkRegistryKeys {
+    async fn operator_keys(&self, operator: Address) -> Result<(PubKey, G1PublicKey)> {
+        use crate::bindings::blsapkregistry::BLSApkRegistry;
+        use crate::crypto::identity::G2Point;
+        use anyhow::anyhow;
+
+        let contract = BLSApkRegistry::new(self.apk_registry, self.provider.clone());
+        let g1 = contract.getRegisteredPubkey(operator).call().await?._0;
+        let g2 = contract.getOperatorPubkeyG2(operator).call().await?._0;
+        let pubkey = G2Point {
+            x: g2.X.map(|coordinate| coordinate.to_string()),
+            y: g2.Y.map(|coordinate| coordinate.to_string()),
+        }
+        .to_public_key()
+        .ok_or_else(|| anyhow!("invalid g2 key registered for {operator}"))?;
+        let g1_pubkey =
+            G1PublicKey::create_from_g1_coordinates(&g1.X.to_string(), &g1.Y.to_string())
+                .ok()
+                .ok_or_else(|| anyhow!("invalid g1 key registered for {operator}"))?;
+        Ok((pubkey, g1_pubkey))
+    }
+}
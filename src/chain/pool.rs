@@ -0,0 +1,143 @@
+//! Pool of JSON-RPC providers shared by the calls a node makes to the chain.
+//!
+//! Each HTTP provider owns its own client and connections, so building one per
+//! call pays a TCP (and TLS) handshake every time. The pool keeps up to
+//! [PoolConfig::max_connections] providers, lends one per call and takes it back
+//! once the call is done, so consecutive calls reuse warm connections. Providers are
+//! cloned back into the pool, so they should be cheap to clone as alloy providers are.
+
+use anyhow::{Result, anyhow};
+use std::num::NonZeroUsize;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Providers kept by default
+pub const DEFAULT_MAX_CONNECTIONS: NonZeroUsize = NonZeroUsize::MIN.saturating_add(9);
+
+/// Time waited for a free provider by default
+pub const DEFAULT_CONNECTION_TIMEOUT_MS: u64 = 5_000;
+
+/// Configuration of a [RpcConnectionPool]
+#[derive(Clone, Debug)]
+pub struct PoolConfig {
+    /// Largest number of providers in use or idle at once
+    pub max_connections: NonZeroUsize,
+    /// Time waited for a provider to be released once all are in use
+    pub connection_timeout_ms: u64,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            connection_timeout_ms: DEFAULT_CONNECTION_TIMEOUT_MS,
+        }
+    }
+}
+
+type Connect<P> = Box<dyn Fn() -> Result<P> + Send + Sync>;
+
+/// Pool lending providers built by a connect function
+pub struct RpcConnectionPool<P> {
+    connect: Connect<P>,
+    idle: Arc<Mutex<Vec<P>>>,
+    permits: Arc<Semaphore>,
+    connection_timeout: Duration,
+}
+
+impl<P: Clone + Send + 'static> RpcConnectionPool<P> {
+    /// Create a pool building providers with `connect` as they are first needed
+    pub fn new<F>(config: PoolConfig, connect: F) -> Self
+    where
+        F: Fn() -> Result<P> + Send + Sync + 'static,
+    {
+        let max_connections = config.max_connections.get();
+        Self {
+            connect: Box::new(connect),
+            idle: Arc::new(Mutex::new(Vec::with_capacity(max_connections))),
+            permits: Arc::new(Semaphore::new(max_connections)),
+            connection_timeout: Duration::from_millis(config.connection_timeout_ms),
+        }
+    }
+
+    /// Borrow a provider until the returned guard is dropped
+    ///
+    /// Reuses an idle provider if any, builds one otherwise. Fails if all providers
+    /// stay in use for the connection timeout.
+    pub async fn acquire(&self) -> Result<PooledConnection<P>> {
+        let permit = tokio::time::timeout(
+            self.connection_timeout,
+            self.permits.clone().acquire_owned(),
+        )
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "no rpc connection available after {:?}",
+                self.connection_timeout
+            )
+        })??;
+        let idle = self
+            .idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop();
+        let provider = match idle {
+            Some(provider) => provider,
+            None => (self.connect)()?,
+        };
+        Ok(PooledConnection {
+            provider,
+            idle: Some(self.idle.clone()),
+            _permit: permit,
+        })
+    }
+
+    /// Providers currently idle in the pool
+    pub fn idle(&self) -> usize {
+        self.idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Providers that can be acquired without waiting
+    pub fn available(&self) -> usize {
+        self.permits.available_permits()
+    }
+}
+
+/// Provider borrowed from a [RpcConnectionPool], returned to it on drop
+pub struct PooledConnection<P: Clone> {
+    provider: P,
+    /// Idle list the provider returns to, none once discarded
+    idle: Option<Arc<Mutex<Vec<P>>>>,
+    // Released after the provider is back in the idle list
+    _permit: OwnedSemaphorePermit,
+}
+
+impl<P: Clone> PooledConnection<P> {
+    /// Drop the provider instead of returning it, e.g. after a transport error
+    pub fn discard(mut self) {
+        self.idle = None;
+    }
+}
+
+impl<P: Clone> Deref for PooledConnection<P> {
+    type Target = P;
+
+    fn deref(&self) -> &P {
+        &self.provider
+    }
+}
+
+impl<P: Clone> Drop for PooledConnection<P> {
+    fn drop(&mut self) {
+        if let Some(idle) = self.idle.take() {
+            idle.lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(self.provider.clone());
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! Reload quorum membership from the chain at every epoch boundary.
+//!
+//! Operators may register or deregister between epochs. The updater polls the
+//! registry for the operator set of a quorum once per epoch and broadcasts the
+//! difference with the previous set as a [QuorumUpdated] event.
+//!
+//! Registry events, such as a key registration, can also trigger a refresh at the
+//! latest block through a [RefreshTrigger]. Bursts of them are coalesced into a single
+//! read and a single update, see [RefreshCoalescer].
+
+use crate::chain::coalescer::{CoalescerConfig, RefreshCoalescer};
+use crate::contributor::committee::canonicalize_key;
+use anyhow::Result;
+use bn254::{G1PublicKey, PublicKey as PubKey};
+#[cfg(feature = "chain")]
+use commonware_eigenlayer::network_configuration::EigenStakingClient;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::num::NonZeroU64;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::{Instrument, Span, debug, info, warn};
+
+/// Number of blocks in an epoch
+pub const DEFAULT_EPOCH_DURATION_BLOCKS: u64 = 32;
+
+/// How often the current block number is polled
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(12);
+
+const UPDATE_CHANNEL_CAPACITY: usize = 16;
+
+/// Registered operator of a quorum
+#[derive(Clone)]
+pub struct QuorumMember {
+    /// G2 key the operator signs with
+    pub g2: PubKey,
+    /// G1 key of the operator
+    pub g1: G1PublicKey,
+}
+
+/// Change in the operator set of a quorum between two epochs
+#[derive(Clone)]
+pub struct QuorumUpdated {
+    /// Quorum whose operator set changed
+    pub quorum_id: u8,
+    /// Operators that joined the quorum
+    pub added: Vec<PubKey>,
+    /// Operators that left the quorum
+    pub removed: Vec<PubKey>,
+    /// G1 keys of the added operators, needed to aggregate their signatures
+    pub g1_keys: HashMap<PubKey, G1PublicKey>,
+}
+
+/// Source of quorum membership
+pub trait QuorumRegistry: Send + Sync + 'static {
+    /// Latest block number
+    fn block_number(&self) -> impl Future<Output = Result<u64>> + Send;
+
+    /// Operators registered for `quorum_id` at `block_number`
+    fn operator_set(
+        &self,
+        quorum_id: u8,
+        block_number: u64,
+    ) -> impl Future<Output = Result<Vec<QuorumMember>>> + Send;
+}
+
+/// Handle asking a [DynamicQuorumUpdater] to refresh its operator set
+#[derive(Clone, Debug)]
+pub struct RefreshTrigger(mpsc::UnboundedSender<()>);
+
+impl RefreshTrigger {
+    /// Report a registry event, the refresh is coalesced with the ones around it
+    pub fn notify(&self) {
+        // The updater may have stopped, there is nothing to refresh then
+        let _ = self.0.send(());
+    }
+}
+
+/// Registry backed by the EigenLayer staking client
+///
+/// The staking client only reads operator states at the latest block, so
+/// [QuorumRegistry::operator_set] ignores the block number it is given: an epoch's set
+/// is the one registered when the node polls, not at the epoch boundary. Nodes polling
+/// on either side of a registration may briefly disagree on the membership of an
+/// epoch, until their next poll.
+#[cfg(feature = "chain")]
+pub struct EigenQuorumRegistry {
+    client: EigenStakingClient,
+    http_rpc: String,
+}
+
+#[cfg(feature = "chain")]
+impl EigenQuorumRegistry {
+    /// Registry of the AVS deployed at `avs_deployment_path`
+    pub async fn new(
+        http_rpc: String,
+        ws_rpc: String,
+        avs_deployment_path: String,
+    ) -> Result<Self> {
+        let client = EigenStakingClient::new(http_rpc.clone(), ws_rpc, avs_deployment_path)
+            .await
+            .map_err(|err| anyhow::anyhow!("failed to create staking client: {err}"))?;
+        Ok(Self { client, http_rpc })
+    }
+}
+
+#[cfg(feature = "chain")]
+impl QuorumRegistry for EigenQuorumRegistry {
+    async fn block_number(&self) -> Result<u64> {
+        use alloy_provider::{Provider, ProviderBuilder};
+
+        let provider = ProviderBuilder::new().on_http(self.http_rpc.parse()?);
+        Ok(provider.get_block_number().await?)
+    }
+
+    async fn operator_set(&self, quorum_id: u8, _block_number: u64) -> Result<Vec<QuorumMember>> {
+        // The staking client reads operator states at the latest block, see above
+        let quorum_infos = self
+            .client
+            .get_operator_states()
+            .await
+            .map_err(|err| anyhow::anyhow!("failed to get operator states: {err}"))?;
+        let Some(quorum) = quorum_infos.get(quorum_id as usize) else {
+            return Err(anyhow::anyhow!("quorum not found: {quorum_id}"));
+        };
+        Ok(quorum
+            .operators
+            .iter()
+            .filter_map(|operator| operator.pub_keys.as_ref())
+            .map(|keys| QuorumMember {
+                g2: keys.g2_pub_key.clone(),
+                g1: keys.g1_pub_key.clone(),
+            })
+            .collect())
+    }
+}
+
+/// Polls a [QuorumRegistry] every epoch and broadcasts membership changes
+pub struct DynamicQuorumUpdater<R: QuorumRegistry> {
+    registry: Arc<R>,
+    quorum_id: u8,
+    epoch_duration_blocks: u64,
+    poll_interval: Duration,
+    members: HashSet<PubKey>,
+    last_epoch: Option<u64>,
+    sender: broadcast::Sender<QuorumUpdated>,
+    coalescing: CoalescerConfig,
+    trigger: mpsc::UnboundedSender<()>,
+    triggers: mpsc::UnboundedReceiver<()>,
+}
+
+impl<R: QuorumRegistry> DynamicQuorumUpdater<R> {
+    /// Create an updater for `quorum_id` starting from the currently known members
+    pub fn new(registry: Arc<R>, quorum_id: u8, members: Vec<PubKey>) -> Self {
+        let (sender, _) = broadcast::channel(UPDATE_CHANNEL_CAPACITY);
+        let (trigger, triggers) = mpsc::unbounded_channel();
+        Self {
+            registry,
+            quorum_id,
+            epoch_duration_blocks: DEFAULT_EPOCH_DURATION_BLOCKS,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            members: members.iter().map(canonicalize_key).collect(),
+            last_epoch: None,
+            sender,
+            coalescing: CoalescerConfig::default(),
+            trigger,
+            triggers,
+        }
+    }
+
+    /// Read the operator sets once every `epoch_duration_blocks` blocks
+    pub fn with_epoch_duration(mut self, epoch_duration_blocks: NonZeroU64) -> Self {
+        self.epoch_duration_blocks = epoch_duration_blocks.get();
+        self
+    }
+
+    /// Poll for new blocks every `poll_interval`
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Coalesce triggered refreshes with `config`
+    pub fn with_coalescing(mut self, config: CoalescerConfig) -> Self {
+        self.coalescing = config;
+        self
+    }
+
+    /// Handle for a registry event watcher to trigger refreshes with
+    pub fn refresh_trigger(&self) -> RefreshTrigger {
+        RefreshTrigger(self.trigger.clone())
+    }
+
+    /// Subscribe to membership changes
+    pub fn subscribe(&self) -> broadcast::Receiver<QuorumUpdated> {
+        self.sender.subscribe()
+    }
+
+    /// Reload the operator set if a new epoch started, broadcasting any change
+    pub async fn poll_once(&mut self) -> Result<Option<QuorumUpdated>> {
+        let block_number = self.registry.block_number().await?;
+        let epoch = block_number / self.epoch_duration_blocks;
+        if self.last_epoch == Some(epoch) {
+            return Ok(None);
+        }
+
+        // Read the set at the epoch boundary so every node sees the same membership, for
+        // registries able to read past blocks
+        let boundary = epoch * self.epoch_duration_blocks;
+        let operators = self.registry.operator_set(self.quorum_id, boundary).await?;
+        self.last_epoch = Some(epoch);
+        Ok(self.apply_operator_set(operators, boundary))
+    }
+
+    /// Reload the operator set at the latest block, broadcasting any change
+    pub async fn refresh(&mut self) -> Result<Option<QuorumUpdated>> {
+        let block_number = self.registry.block_number().await?;
+        let operators = self
+            .registry
+            .operator_set(self.quorum_id, block_number)
+            .await?;
+        Ok(self.apply_operator_set(operators, block_number))
+    }
+
+    /// Replace the members with `operators` read at `block_number`, broadcasting the
+    /// difference
+    fn apply_operator_set(
+        &mut self,
+        operators: Vec<QuorumMember>,
+        block_number: u64,
+    ) -> Option<QuorumUpdated> {
+        // Keys are compared in the encoding contributors index them by
+        let operators: Vec<QuorumMember> = operators
+            .into_iter()
+            .map(|member| QuorumMember {
+                g2: canonicalize_key(&member.g2),
+                g1: member.g1,
+            })
+            .collect();
+        let current: HashSet<PubKey> = operators.iter().map(|member| member.g2.clone()).collect();
+        let mut added: Vec<PubKey> = current.difference(&self.members).cloned().collect();
+        let mut removed: Vec<PubKey> = self.members.difference(&current).cloned().collect();
+        if added.is_empty() && removed.is_empty() {
+            return None;
+        }
+        added.sort();
+        removed.sort();
+        let g1_keys = operators
+            .into_iter()
+            .filter(|member| added.contains(&member.g2))
+            .map(|member| (member.g2, member.g1))
+            .collect();
+        self.members = current;
+
+        let update = QuorumUpdated {
+            quorum_id: self.quorum_id,
+            added,
+            removed,
+            g1_keys,
+        };
+        info!(
+            quorum_id = self.quorum_id,
+            block_number,
+            added = update.added.len(),
+            removed = update.removed.len(),
+            "quorum membership changed"
+        );
+        // No subscribers is not an error, the update is simply dropped
+        let _ = self.sender.send(update.clone());
+        Some(update)
+    }
+
+    /// Poll the registry in a background task within the current span, refreshing on
+    /// coalesced triggers
+    pub fn spawn(mut self) -> JoinHandle<()> {
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(self.poll_interval);
+                let mut coalescer = RefreshCoalescer::new(self.coalescing);
+                loop {
+                    let deadline = coalescer.deadline();
+                    tokio::select! {
+                        _ = interval.tick() => {
+                            if let Err(err) = self.poll_once().await {
+                                warn!(quorum_id = self.quorum_id, ?err, "failed to reload quorum");
+                            }
+                        }
+                        Some(()) = self.triggers.recv() => coalescer.trigger(Instant::now()),
+                        () = sleep_until(deadline) => {
+                            let Some(triggers) = coalescer.poll(Instant::now()) else {
+                                continue;
+                            };
+                            debug!(quorum_id = self.quorum_id, triggers, "refreshing quorum");
+                            if let Err(err) = self.refresh().await {
+                                warn!(quorum_id = self.quorum_id, ?err, "failed to refresh quorum");
+                            }
+                        }
+                    }
+                }
+            }
+            .instrument(Span::current()),
+        )
+    }
+}
+
+/// Sleep until `deadline`, pending forever without one
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
@@ -0,0 +1,170 @@
+//! EigenLayer registration of the operators whose signatures are counted.
+//!
+//! An operator is registered if the `DelegationManager` knows it as an operator and
+//! the `AVSDirectory` lists it as registered with our AVS. A deregistered operator may
+//! keep signing, so its shares must not be counted. Positive results are cached for
+//! [RegistrationConfig::registration_cache_ttl_secs]; negative ones are always read
+//! again so an operator registering again is accepted on its next share.
+
+use alloy_primitives::Address;
+use anyhow::Result;
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::debug;
+
+/// Time a positive registration is trusted by default
+pub const DEFAULT_REGISTRATION_CACHE_TTL_SECS: u64 = 300;
+
+/// Configuration of a [RegistrationChecker]
+#[derive(Clone, Debug)]
+pub struct RegistrationConfig {
+    /// Time a registered operator is not checked again
+    pub registration_cache_ttl_secs: u64,
+}
+
+impl Default for RegistrationConfig {
+    fn default() -> Self {
+        Self {
+            registration_cache_ttl_secs: DEFAULT_REGISTRATION_CACHE_TTL_SECS,
+        }
+    }
+}
+
+/// Registration of an operator with an AVS, as in `IAVSDirectoryTypes`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AvsRegistrationStatus {
+    /// The operator is not registered with the AVS
+    Unregistered,
+    /// The operator is registered with the AVS
+    Registered,
+}
+
+impl From<u8> for AvsRegistrationStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            1 => AvsRegistrationStatus::Registered,
+            _ => AvsRegistrationStatus::Unregistered,
+        }
+    }
+}
+
+/// Source of EigenLayer registrations
+pub trait RegistrationSource: Send + Sync + 'static {
+    /// Whether `operator` is registered with the `DelegationManager`
+    fn is_operator(&self, operator: Address) -> impl Future<Output = Result<bool>> + Send;
+
+    /// Registration of `operator` with `avs` in the `AVSDirectory`
+    fn avs_operator_status(
+        &self,
+        avs: Address,
+        operator: Address,
+    ) -> impl Future<Output = Result<AvsRegistrationStatus>> + Send;
+}
+
+/// Registration check of the operators whose shares are collected
+pub trait OperatorRegistry: Send + Sync {
+    /// Whether `operator` is currently registered with the AVS
+    fn is_registered(&self, operator: Address) -> BoxFuture<'_, Result<bool>>;
+}
+
+/// Checks operators are registered with EigenLayer and an AVS, caching registrations
+pub struct RegistrationChecker<S: RegistrationSource> {
+    source: Arc<S>,
+    avs: Address,
+    ttl: Duration,
+    registered: Mutex<HashMap<Address, Instant>>,
+}
+
+impl<S: RegistrationSource> RegistrationChecker<S> {
+    /// Checker of the registrations with `avs` read from `source`
+    pub fn new(source: Arc<S>, avs: Address, config: RegistrationConfig) -> Self {
+        Self {
+            source,
+            avs,
+            ttl: Duration::from_secs(config.registration_cache_ttl_secs),
+            registered: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether `operator` is an EigenLayer operator registered with the AVS
+    pub async fn is_registered(&self, operator: Address) -> Result<bool> {
+        if let Some(checked) = self.registered.lock().await.get(&operator)
+            && checked.elapsed() < self.ttl
+        {
+            return Ok(true);
+        }
+
+        let registered = self.source.is_operator(operator).await?
+            && self.source.avs_operator_status(self.avs, operator).await?
+                == AvsRegistrationStatus::Registered;
+        let mut cache = self.registered.lock().await;
+        if registered {
+            cache.insert(operator, Instant::now());
+        } else if cache.remove(&operator).is_some() {
+            debug!(%operator, "operator no longer registered");
+        }
+        Ok(registered)
+    }
+
+    /// Forget the registration of `operator`, e.g. after it deregistered
+    pub async fn invalidate(&self, operator: Address) {
+        self.registered.lock().await.remove(&operator);
+    }
+}
+
+impl<S: RegistrationSource> OperatorRegistry for RegistrationChecker<S> {
+    fn is_registered(&self, operator: Address) -> BoxFuture<'_, Result<bool>> {
+        Box::pin(RegistrationChecker::is_registered(self, operator))
+    }
+}
+
+/// Registrations read from the EigenLayer core contracts
+#[cfg(feature = "chain")]
+pub struct EigenLayerRegistrations {
+    provider: alloy_provider::DynProvider,
+    delegation_manager: Address,
+    avs_directory: Address,
+}
+
+#[cfg(feature = "chain")]
+impl EigenLayerRegistrations {
+    /// Registrations read from the `DelegationManager` and `AVSDirectory` at the given addresses
+    pub fn new(
+        provider: alloy_provider::DynProvider,
+        delegation_manager: Address,
+        avs_directory: Address,
+    ) -> Self {
+        Self {
+            provider,
+            delegation_manager,
+            avs_directory,
+        }
+    }
+}
+
+#[cfg(feature = "chain")]
+impl RegistrationSource for EigenLayerRegistrations {
+    async fn is_operator(&self, operator: Address) -> Result<bool> {
+        use crate::bindings::delegationmanager::DelegationManager;
+
+        let contract = DelegationManager::new(self.delegation_manager, self.provider.clone());
+        Ok(contract.isOperator(operator).call().await?._0)
+    }
+
+    async fn avs_operator_status(
+        &self,
+        avs: Address,
+        operator: Address,
+    ) -> Result<AvsRegistrationStatus> {
+        use crate::bindings::avsdirectory::AVSDirectory;
+
+        let contract = AVSDirectory::new(self.avs_directory, self.provider.clone());
+        let status = contract.avsOperatorStatus(avs, operator).call().await?._0;
+        Ok(status.into())
+    }
+}
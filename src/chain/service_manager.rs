@@ -0,0 +1,67 @@
+//! Reads of the AVS `ServiceManager`, which records where the rest of the AVS lives.
+
+use crate::bindings::servicemanager::ServiceManager;
+use alloy_primitives::Address;
+use alloy_provider::{DynProvider, Provider, ProviderBuilder};
+use anyhow::Result;
+
+/// Name and version an AVS publishes in its `ServiceManager`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AvsMetadata {
+    /// Name of the AVS
+    pub name: String,
+    /// Version of the AVS
+    pub version: String,
+}
+
+/// Client of the `ServiceManager` contract at a fixed address
+#[derive(Clone)]
+pub struct ServiceManagerClient {
+    provider: DynProvider,
+    address: Address,
+}
+
+impl ServiceManagerClient {
+    /// Client of the `ServiceManager` at `address`, read through `http_rpc`
+    pub fn new(http_rpc: &str, address: Address) -> Result<Self> {
+        let provider = ProviderBuilder::new().on_http(http_rpc.parse()?).erased();
+        Ok(Self::with_provider(provider, address))
+    }
+
+    /// Client of the `ServiceManager` at `address`, read through `provider`
+    pub fn with_provider(provider: DynProvider, address: Address) -> Self {
+        Self { provider, address }
+    }
+
+    /// Address of the `ServiceManager`
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Address of the task manager contract tasks are created on
+    pub async fn get_task_manager_address(&self) -> Result<Address> {
+        Ok(self.contract().taskManager().call().await?._0)
+    }
+
+    /// Name and version the AVS publishes
+    pub async fn get_avs_metadata(&self) -> Result<AvsMetadata> {
+        let contract = self.contract();
+        let name = contract.avsName().call().await?._0;
+        let version = contract.avsVersion().call().await?._0;
+        Ok(AvsMetadata { name, version })
+    }
+
+    /// Whether `operator` is registered with the AVS
+    pub async fn is_operator_registered(&self, operator: Address) -> Result<bool> {
+        Ok(self
+            .contract()
+            .isOperatorRegistered(operator)
+            .call()
+            .await?
+            ._0)
+    }
+
+    fn contract(&self) -> ServiceManager::ServiceManagerInstance<DynProvider> {
+        ServiceManager::new(self.address, self.provider.clone())
+    }
+}
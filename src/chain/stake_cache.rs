@@ -0,0 +1,272 @@
+//! Cache of operator stakes read from the operator state retriever.
+//!
+//! Stake-weighted thresholds and the non-signer part of the calldata both need the
+//! stake of every operator of a quorum at the round's reference block, and many
+//! rounds share a reference block. Snapshots are keyed by `(quorum_number,
+//! reference_block)` like the [ApkCache](crate::chain::ApkCache), and the least
+//! recently used one is evicted once the cache is full. Concurrent rounds asking
+//! for the same missing snapshot wait on a single read.
+
+use alloy_primitives::{Address, B256, U256};
+use anyhow::Result;
+use std::collections::HashMap;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::OnceCell;
+use tracing::debug;
+
+/// Number of `(quorum, block)` snapshots kept by default
+pub const DEFAULT_STAKE_CACHE_CAPACITY: NonZeroUsize = NonZeroUsize::MIN.saturating_add(63);
+
+/// Stake of a registered operator in a quorum
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OperatorStake {
+    /// Address of the operator
+    pub operator: Address,
+    /// Id of the operator in the registry coordinator
+    pub operator_id: B256,
+    /// Stake of the operator in the quorum
+    pub stake: U256,
+}
+
+/// Stakes of the operators of a quorum at a block
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StakeSnapshot {
+    /// Quorum the stakes are in
+    pub quorum_number: u8,
+    /// Block the stakes were read at
+    pub reference_block: u64,
+    /// Operators registered in the quorum at the block
+    pub operators: Vec<OperatorStake>,
+}
+
+impl StakeSnapshot {
+    /// Stake of all the operators together
+    pub fn total_stake(&self) -> U256 {
+        self.operators.iter().map(|operator| operator.stake).sum()
+    }
+
+    /// Stake of `operator_id`, `None` if it is not in the quorum
+    pub fn stake_of(&self, operator_id: &B256) -> Option<U256> {
+        self.operators
+            .iter()
+            .find(|operator| &operator.operator_id == operator_id)
+            .map(|operator| operator.stake)
+    }
+
+    /// Stake of the `signers` in the quorum, for stake-weighted thresholds
+    pub fn signed_stake(&self, signers: &[B256]) -> U256 {
+        self.operators
+            .iter()
+            .filter(|operator| signers.contains(&operator.operator_id))
+            .map(|operator| operator.stake)
+            .sum()
+    }
+
+    /// Operators of the quorum missing from `signers`, as listed in the calldata
+    pub fn non_signers(&self, signers: &[B256]) -> Vec<&OperatorStake> {
+        self.operators
+            .iter()
+            .filter(|operator| !signers.contains(&operator.operator_id))
+            .collect()
+    }
+}
+
+/// Source of operator stakes
+pub trait StakeRetriever: Send + Sync + 'static {
+    /// Operators of `quorum_number` with their stake at `reference_block`
+    fn operator_stakes(
+        &self,
+        quorum_number: u8,
+        reference_block: u64,
+    ) -> impl Future<Output = Result<Vec<OperatorStake>>> + Send;
+}
+
+type Slot = Arc<OnceCell<Arc<StakeSnapshot>>>;
+
+#[derive(Default)]
+struct Entries {
+    snapshots: HashMap<(u8, u64), (Slot, u64)>,
+    /// Incremented on every access, the entry with the lowest value is evicted first
+    clock: u64,
+}
+
+/// LRU cache of stake snapshots keyed by `(quorum_number, reference_block)`
+pub struct StakeSnapshotCache<R: StakeRetriever> {
+    retriever: Arc<R>,
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+impl<R: StakeRetriever> StakeSnapshotCache<R> {
+    /// Cache of [DEFAULT_STAKE_CACHE_CAPACITY] snapshots read from `retriever`
+    pub fn new(retriever: Arc<R>) -> Self {
+        Self::with_capacity(retriever, DEFAULT_STAKE_CACHE_CAPACITY)
+    }
+
+    /// Cache of `capacity` snapshots read from `retriever`
+    pub fn with_capacity(retriever: Arc<R>, capacity: NonZeroUsize) -> Self {
+        Self {
+            retriever,
+            capacity: capacity.get(),
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Snapshot of `quorum_number` at `reference_block`, read from the retriever on a miss
+    ///
+    /// Only lookups of the same key wait on each other. A failed read is not cached:
+    /// waiting lookups and the next ones read again.
+    pub async fn get(&self, quorum_number: u8, reference_block: u64) -> Result<Arc<StakeSnapshot>> {
+        let slot = self.slot(quorum_number, reference_block);
+        let result = slot
+            .get_or_try_init(|| async {
+                let operators = self
+                    .retriever
+                    .operator_stakes(quorum_number, reference_block)
+                    .await?;
+                debug!(
+                    quorum_number,
+                    reference_block,
+                    operators = operators.len(),
+                    "read stake snapshot"
+                );
+                Ok::<_, anyhow::Error>(Arc::new(StakeSnapshot {
+                    quorum_number,
+                    reference_block,
+                    operators,
+                }))
+            })
+            .await;
+        match result {
+            Ok(snapshot) => Ok(snapshot.clone()),
+            Err(err) => {
+                self.forget(quorum_number, reference_block, &slot);
+                Err(err)
+            }
+        }
+    }
+
+    /// Drop the slot of a failed read, unless it was replaced or filled meanwhile
+    fn forget(&self, quorum_number: u8, reference_block: u64, slot: &Slot) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let key = (quorum_number, reference_block);
+        if entries
+            .snapshots
+            .get(&key)
+            .is_some_and(|(cached, _)| Arc::ptr_eq(cached, slot) && !cached.initialized())
+        {
+            entries.snapshots.remove(&key);
+        }
+    }
+
+    /// Slot of a key, inserted and evicting the least recently used one if missing
+    fn slot(&self, quorum_number: u8, reference_block: u64) -> Slot {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.clock += 1;
+        let clock = entries.clock;
+        let key = (quorum_number, reference_block);
+        if let Some((slot, used)) = entries.snapshots.get_mut(&key) {
+            *used = clock;
+            return slot.clone();
+        }
+
+        if entries.snapshots.len() >= self.capacity
+            && let Some(oldest) = entries
+                .snapshots
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| *key)
+        {
+            debug!(
+                quorum_number = oldest.0,
+                reference_block = oldest.1,
+                "evicted stake snapshot"
+            );
+            entries.snapshots.remove(&oldest);
+        }
+        let slot = Slot::default();
+        entries.snapshots.insert(key, (slot.clone(), clock));
+        slot
+    }
+
+    /// Drop every cached snapshot of `quorum_number`, e.g. after its membership changed
+    pub fn invalidate(&self, quorum_number: u8) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .snapshots
+            .retain(|(quorum, _), _| *quorum != quorum_number);
+    }
+
+    /// Number of cached snapshots, including those being read
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .snapshots
+            .len()
+    }
+
+    /// Whether no snapshot is cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Stakes read from the `OperatorStateRetriever` of a registry coordinator
+#[cfg(feature = "chain")]
+pub struct OperatorStateStakes {
+    provider: alloy_provider::DynProvider,
+    retriever: Address,
+    registry_coordinator: Address,
+}
+
+#[cfg(feature = "chain")]
+impl OperatorStateStakes {
+    /// Stakes read from the `OperatorStateRetriever` for the registry coordinator
+    pub fn new(
+        provider: alloy_provider::DynProvider,
+        retriever: Address,
+        registry_coordinator: Address,
+    ) -> Self {
+        Self {
+            provider,
+            retriever,
+            registry_coordinator,
+        }
+    }
+}
+
+#[cfg(feature = "chain")]
+impl StakeRetriever for OperatorStateStakes {
+    async fn operator_stakes(
+        &self,
+        quorum_number: u8,
+        reference_block: u64,
+    ) -> Result<Vec<OperatorStake>> {
+        use crate::bindings::blssigcheckoperatorstateretriever::BLSSigCheckOperatorStateRetriever;
+
+        let contract =
+            BLSSigCheckOperatorStateRetriever::new(self.retriever, self.provider.clone());
+        let quorums = contract
+            .getOperatorState_0(
+                self.registry_coordinator,
+                vec![quorum_number].into(),
+                u32::try_from(reference_block)?,
+            )
+            .call()
+            .await?
+            ._0;
+        Ok(quorums
+            .into_iter()
+            .flatten()
+            .map(|operator| OperatorStake {
+                operator: operator.operator,
+                operator_id: operator.operatorId,
+                stake: U256::from(operator.stake),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,12 @@
+//! Submission of transactions built by the node.
+
+use alloy::rpc::types::TransactionRequest;
+use alloy_primitives::TxHash;
+use anyhow::Result;
+use futures::future::BoxFuture;
+
+/// Signs and sends transactions to the chain
+pub trait ChainSubmitter: Send + Sync {
+    /// Send `transaction`, returning its hash once accepted by the node
+    fn submit(&self, transaction: TransactionRequest) -> BoxFuture<'_, Result<TxHash>>;
+}
@@ -0,0 +1,183 @@
+//! Submission of aggregated responses to a custom task responder contract.
+//!
+//! Instead of generated bindings, the target entrypoint is described by an ABI
+//! fragment in the node configuration. Each parameter of the function is bound by
+//! name to a field of the aggregated [TaskResponse].
+
+use alloy::rpc::types::TransactionRequest;
+use alloy_dyn_abi::{DynSolType, DynSolValue, JsonAbiExt, Specifier};
+use alloy_json_abi::Function;
+use alloy_primitives::{Address, Bytes, Selector, U256};
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Field of an aggregated response that can be bound to a function parameter
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseField {
+    /// Round (task index) as an unsigned integer
+    Round,
+    /// Reference block as an unsigned integer
+    ReferenceBlock,
+    /// Quorum numbers as `bytes`
+    QuorumNumbers,
+    /// Task response as `bytes`
+    TaskResponse,
+    /// Aggregate signature as a G1 point `(uint256 X, uint256 Y)`
+    Signature,
+    /// Aggregate public key as a G2 point `(uint256[2] X, uint256[2] Y)`
+    Apk,
+}
+
+/// Configuration of a task responder contract
+#[derive(Clone, Debug, Deserialize)]
+pub struct TaskResponderConfig {
+    /// Address of the contract
+    pub contract: Address,
+    /// JSON ABI fragment or human-readable signature of the entrypoint
+    pub function: String,
+    /// Expected selector of the entrypoint, checked against the fragment
+    pub selector: Selector,
+    /// Field bound to each parameter, by parameter name
+    pub params: HashMap<String, ResponseField>,
+}
+
+/// G1 point coordinates
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct G1Coordinates {
+    /// X coordinate
+    pub x: U256,
+    /// Y coordinate
+    pub y: U256,
+}
+
+/// G2 point coordinates, each as `[c1, c0]` like the on-chain `BN254.G2Point`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct G2Coordinates {
+    /// X coordinate, as `[c1, c0]`
+    pub x: [U256; 2],
+    /// Y coordinate, as `[c1, c0]`
+    pub y: [U256; 2],
+}
+
+/// Aggregated response submitted for a round
+#[derive(Clone, Debug, Default)]
+pub struct TaskResponse {
+    /// Round responded to
+    pub round: u64,
+    /// Block the signers' stakes are checked at
+    pub reference_block: u64,
+    /// Quorums that signed
+    pub quorum_numbers: Vec<u8>,
+    /// Response the signers agreed on
+    pub response: Vec<u8>,
+    /// Aggregate signature of the signers
+    pub signature: G1Coordinates,
+    /// Aggregate G2 key of the signers
+    pub apk: G2Coordinates,
+}
+
+/// Encodes calls to a task responder contract described by a [TaskResponderConfig]
+#[derive(Clone, Debug)]
+pub struct TaskResponder {
+    contract: Address,
+    function: Function,
+    bindings: Vec<(DynSolType, ResponseField)>,
+}
+
+impl TaskResponder {
+    /// Parse the configured fragment and check every parameter is bound
+    pub fn new(config: &TaskResponderConfig) -> Result<Self> {
+        let fragment = config.function.trim();
+        let function: Function = if fragment.starts_with('{') {
+            serde_json::from_str(fragment)?
+        } else {
+            Function::parse(fragment)?
+        };
+        if function.selector() != config.selector {
+            return Err(anyhow!(
+                "selector mismatch for {}: configured {}, computed {}",
+                function.signature(),
+                config.selector,
+                function.selector()
+            ));
+        }
+        let mut bindings = Vec::with_capacity(function.inputs.len());
+        for param in &function.inputs {
+            let Some(field) = config.params.get(&param.name) else {
+                return Err(anyhow!("parameter not bound: {}", param.name));
+            };
+            bindings.push((param.resolve()?, *field));
+        }
+        Ok(Self {
+            contract: config.contract,
+            function,
+            bindings,
+        })
+    }
+
+    /// Selector of the entrypoint
+    pub fn selector(&self) -> Selector {
+        self.function.selector()
+    }
+
+    /// Calldata for submitting `response`
+    pub fn encode_call(&self, response: &TaskResponse) -> Result<Bytes> {
+        let values = self
+            .bindings
+            .iter()
+            .map(|(ty, field)| field_value(ty, *field, response))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.function.abi_encode_input(&values)?.into())
+    }
+
+    /// Transaction submitting `response`, to be filled and signed by a provider
+    pub fn transaction(&self, response: &TaskResponse) -> Result<TransactionRequest> {
+        let calldata = self.encode_call(response)?;
+        Ok(TransactionRequest::default()
+            .to(self.contract)
+            .input(calldata.into()))
+    }
+}
+
+fn uint(value: U256) -> DynSolValue {
+    DynSolValue::Uint(value, 256)
+}
+
+fn field_value(
+    ty: &DynSolType,
+    field: ResponseField,
+    response: &TaskResponse,
+) -> Result<DynSolValue> {
+    let value = match field {
+        ResponseField::Round | ResponseField::ReferenceBlock => {
+            let DynSolType::Uint(bits) = ty else {
+                return Err(anyhow!(
+                    "{field:?} must be bound to an unsigned integer, got {ty}"
+                ));
+            };
+            let value = match field {
+                ResponseField::Round => response.round,
+                _ => response.reference_block,
+            };
+            if *bits < 64 && value >> bits != 0 {
+                return Err(anyhow!("{field:?} does not fit in uint{bits}: {value}"));
+            }
+            DynSolValue::Uint(U256::from(value), *bits)
+        }
+        ResponseField::QuorumNumbers => DynSolValue::Bytes(response.quorum_numbers.clone()),
+        ResponseField::TaskResponse => DynSolValue::Bytes(response.response.clone()),
+        ResponseField::Signature => {
+            DynSolValue::Tuple(vec![uint(response.signature.x), uint(response.signature.y)])
+        }
+        ResponseField::Apk => DynSolValue::Tuple(vec![
+            DynSolValue::FixedArray(response.apk.x.map(uint).to_vec()),
+            DynSolValue::FixedArray(response.apk.y.map(uint).to_vec()),
+        ]),
+    };
+    if !ty.matches(&value) {
+        return Err(anyhow!("{field:?} cannot be encoded as {ty}"));
+    }
+    Ok(value)
+}
@@ -0,0 +1,238 @@
+use crate::chain::abi::signature_coordinates;
+use crate::chain::task_responder::{G1Coordinates, G2Coordinates};
+use crate::chain::{CheckSignaturesParams, encode_for_signature_checker};
+use crate::contributor::QuorumCertificate;
+use crate::contributor::tests::mock::MockContributor;
+use crate::contributor::types::ParticipationBitmap;
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy_json_abi::JsonAbi;
+use alloy_primitives::{U256, hex};
+use ark_bn254::{Fq, G1Affine};
+use ark_ff::{BigInt, PrimeField};
+use ark_serialize::CanonicalSerialize;
+use bn254::Signature;
+use commonware_cryptography::Signer;
+
+/// `checkSignatures` calldata captured by `scripts/capture-check-signatures.sh`
+const CHECK_SIGNATURES_VECTOR: &str =
+    include_str!("../../../tests/fixtures/check_signatures_vector.hex");
+
+/// ABI the vector is decoded with
+const SIGNATURE_CHECKER_ABI: &str = include_str!("../../../contracts/abi/BLSSignatureChecker.abi");
+
+/// Selector of `checkSignatures` on EigenLayer's `BLSSignatureChecker`
+const CHECK_SIGNATURES_SELECTOR: [u8; 4] = [0x6e, 0xfb, 0x46, 0x36];
+
+fn point(x: u64, y: u64) -> G1Coordinates {
+    G1Coordinates {
+        x: U256::from(x),
+        y: U256::from(y),
+    }
+}
+
+/// Certificate of round 3 over `[0x11; 32]` whose signature is the G1 generator
+fn known_certificate() -> QuorumCertificate {
+    let mut generator = vec![0; 32];
+    generator[0] = 1;
+    QuorumCertificate {
+        round: 3,
+        payload: [0x11; 32],
+        signature: Signature::try_from(generator).unwrap(),
+        signers: [0, 2].into_iter().collect(),
+    }
+}
+
+/// Arguments of a `checkSignatures` call
+struct CheckSignaturesCall {
+    certificate: QuorumCertificate,
+    quorum_numbers: Vec<u8>,
+    params: CheckSignaturesParams,
+}
+
+fn as_u32(value: &DynSolValue) -> u32 {
+    value.as_uint().unwrap().0.to::<u32>()
+}
+
+fn as_u32s(value: &DynSolValue) -> Vec<u32> {
+    value.as_array().unwrap().iter().map(as_u32).collect()
+}
+
+fn as_g1(value: &DynSolValue) -> G1Coordinates {
+    let [x, y] = value.as_tuple().unwrap() else {
+        panic!("not a G1 point: {value:?}");
+    };
+    G1Coordinates {
+        x: x.as_uint().unwrap().0,
+        y: y.as_uint().unwrap().0,
+    }
+}
+
+fn as_g1s(value: &DynSolValue) -> Vec<G1Coordinates> {
+    value.as_array().unwrap().iter().map(as_g1).collect()
+}
+
+fn as_g2(value: &DynSolValue) -> G2Coordinates {
+    let [x, y] = value.as_tuple().unwrap() else {
+        panic!("not a G2 point: {value:?}");
+    };
+    let pair = |value: &DynSolValue| -> [U256; 2] {
+        let [a, b] = value.as_fixed_array().unwrap() else {
+            panic!("not a coordinate pair: {value:?}");
+        };
+        [a.as_uint().unwrap().0, b.as_uint().unwrap().0]
+    };
+    G2Coordinates {
+        x: pair(x),
+        y: pair(y),
+    }
+}
+
+/// Compressed signature at `point`
+fn signature_at(point: &G1Coordinates) -> Signature {
+    let coordinate = |value: U256| Fq::from_bigint(BigInt(value.into_limbs())).unwrap();
+    let point = G1Affine::new(coordinate(point.x), coordinate(point.y));
+    let mut bytes = Vec::new();
+    point.serialize_compressed(&mut bytes).unwrap();
+    Signature::try_from(bytes).unwrap()
+}
+
+/// Decode `checkSignatures` calldata, the certificate carrying only what it encodes
+fn decode_call(calldata: &[u8]) -> CheckSignaturesCall {
+    let abi: JsonAbi = serde_json::from_str(SIGNATURE_CHECKER_ABI).unwrap();
+    let function = abi.function("checkSignatures").unwrap().first().unwrap();
+    assert_eq!(calldata[..4], function.selector()[..]);
+    let values = function.abi_decode_input(&calldata[4..], true).unwrap();
+    let [msg_hash, quorum_numbers, reference_block, params] = &values[..] else {
+        panic!("unexpected arguments: {values:?}");
+    };
+    let [
+        bitmap_indices,
+        non_signer_pubkeys,
+        quorum_apks,
+        apk_g2,
+        sigma,
+        quorum_apk_indices,
+        total_stake_indices,
+        non_signer_stake_indices,
+    ] = params.as_tuple().unwrap()
+    else {
+        panic!("unexpected params: {params:?}");
+    };
+    CheckSignaturesCall {
+        certificate: QuorumCertificate {
+            round: 0,
+            payload: msg_hash.as_fixed_bytes().unwrap().0.try_into().unwrap(),
+            signature: signature_at(&as_g1(sigma)),
+            signers: ParticipationBitmap::default(),
+        },
+        quorum_numbers: quorum_numbers.as_bytes().unwrap().to_vec(),
+        params: CheckSignaturesParams {
+            reference_block: as_u32(reference_block),
+            non_signer_quorum_bitmap_indices: as_u32s(bitmap_indices),
+            non_signer_pubkeys: as_g1s(non_signer_pubkeys),
+            quorum_apks: as_g1s(quorum_apks),
+            apk_g2: as_g2(apk_g2),
+            quorum_apk_indices: as_u32s(quorum_apk_indices),
+            total_stake_indices: as_u32s(total_stake_indices),
+            non_signer_stake_indices: non_signer_stake_indices
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(as_u32s)
+                .collect(),
+        },
+    }
+}
+
+fn known_params() -> CheckSignaturesParams {
+    CheckSignaturesParams {
+        reference_block: 1234,
+        non_signer_quorum_bitmap_indices: vec![7, 1],
+        non_signer_pubkeys: vec![point(3, 4), point(21, 22)],
+        quorum_apks: vec![point(5, 6), point(15, 16)],
+        apk_g2: G2Coordinates {
+            x: [U256::from(10), U256::from(11)],
+            y: [U256::from(12), U256::from(13)],
+        },
+        quorum_apk_indices: vec![2, 8],
+        total_stake_indices: vec![3, 9],
+        non_signer_stake_indices: vec![vec![4], vec![5]],
+    }
+}
+
+#[cfg(test)]
+mod abi_tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_calldata() {
+        // Encoding the arguments of the captured call gives back its calldata
+        let vector = hex::decode(CHECK_SIGNATURES_VECTOR.trim()).unwrap();
+        let call = decode_call(&vector);
+        let calldata =
+            encode_for_signature_checker(&call.certificate, &call.quorum_numbers, &call.params)
+                .unwrap();
+        assert_eq!(hex::encode(&calldata), CHECK_SIGNATURES_VECTOR.trim());
+        assert_eq!(calldata[..4], CHECK_SIGNATURES_SELECTOR);
+    }
+
+    #[test]
+    fn test_known_params_round_trip() {
+        let calldata =
+            encode_for_signature_checker(&known_certificate(), &[0, 1], &known_params()).unwrap();
+        let call = decode_call(&calldata);
+        assert_eq!(call.certificate.payload, known_certificate().payload);
+        assert_eq!(
+            signature_coordinates(&call.certificate.signature),
+            Some(point(1, 2))
+        );
+        assert_eq!(call.quorum_numbers, [0, 1]);
+        assert_eq!(call.params, known_params());
+    }
+
+    #[test]
+    fn test_signature_coordinates() {
+        assert_eq!(
+            signature_coordinates(&known_certificate().signature),
+            Some(point(1, 2))
+        );
+
+        let signer = MockContributor::create_test_bn254(5);
+        let signature = signer.sign(None, &[0x11; 32]);
+        let sigma = signature_coordinates(&signature).unwrap();
+        assert_ne!(sigma, G1Coordinates::default());
+    }
+
+    #[test]
+    fn test_rejects_mismatched_quorums() {
+        let qc = known_certificate();
+
+        // One quorum number for APKs and indices of two quorums
+        assert!(encode_for_signature_checker(&qc, &[0], &known_params()).is_err());
+
+        let mut params = known_params();
+        params.total_stake_indices.pop();
+        assert!(encode_for_signature_checker(&qc, &[0, 1], &params).is_err());
+
+        let mut params = known_params();
+        params.non_signer_pubkeys.pop();
+        assert!(encode_for_signature_checker(&qc, &[0, 1], &params).is_err());
+    }
+
+    #[test]
+    fn test_all_signed() {
+        // Without non-signers the non-signer arrays are empty
+        let params = CheckSignaturesParams {
+            non_signer_quorum_bitmap_indices: Vec::new(),
+            non_signer_pubkeys: Vec::new(),
+            non_signer_stake_indices: vec![Vec::new(), Vec::new()],
+            ..known_params()
+        };
+        let calldata =
+            encode_for_signature_checker(&known_certificate(), &[0, 1], &params).unwrap();
+        assert_eq!(calldata[..4], CHECK_SIGNATURES_SELECTOR);
+        let with_non_signers =
+            encode_for_signature_checker(&known_certificate(), &[0, 1], &known_params()).unwrap();
+        assert!(calldata.len() < with_non_signers.len());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::chain::task_responder::G1Coordinates;
+use crate::chain::{ApkCache, ApkRegistry};
+use alloy_primitives::U256;
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+/// Registry counting reads, with an APK derived from the quorum and block
+#[derive(Default)]
+struct MockApkRegistry {
+    fetches: Mutex<HashMap<(u8, u64), usize>>,
+    failing: Mutex<bool>,
+}
+
+impl MockApkRegistry {
+    fn fetches(&self, quorum_number: u8, reference_block: u64) -> usize {
+        let fetches = self.fetches.lock().unwrap();
+        fetches
+            .get(&(quorum_number, reference_block))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn total_fetches(&self) -> usize {
+        self.fetches.lock().unwrap().values().sum()
+    }
+}
+
+fn apk(quorum_number: u8, reference_block: u64) -> G1Coordinates {
+    G1Coordinates {
+        x: U256::from(quorum_number),
+        y: U256::from(reference_block),
+    }
+}
+
+impl ApkRegistry for MockApkRegistry {
+    async fn quorum_apk(&self, quorum_number: u8, reference_block: u64) -> Result<G1Coordinates> {
+        *self
+            .fetches
+            .lock()
+            .unwrap()
+            .entry((quorum_number, reference_block))
+            .or_default() += 1;
+        if *self.failing.lock().unwrap() {
+            return Err(anyhow!("registry unavailable"));
+        }
+        Ok(apk(quorum_number, reference_block))
+    }
+}
+
+#[cfg(test)]
+mod apk_cache_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_once_per_quorum_and_block() {
+        let registry = Arc::new(MockApkRegistry::default());
+        let cache = ApkCache::new(registry.clone());
+
+        // Several aggregations at the same reference blocks
+        for _ in 0..5 {
+            assert_eq!(cache.get(0, 100).await.unwrap(), apk(0, 100));
+            assert_eq!(cache.get(1, 100).await.unwrap(), apk(1, 100));
+            assert_eq!(cache.get(0, 101).await.unwrap(), apk(0, 101));
+        }
+        assert_eq!(registry.fetches(0, 100), 1);
+        assert_eq!(registry.fetches(1, 100), 1);
+        assert_eq!(registry.fetches(0, 101), 1);
+        assert_eq!(registry.total_fetches(), 3);
+        assert_eq!(cache.len().await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_new_block_is_fetched() {
+        let registry = Arc::new(MockApkRegistry::default());
+        let cache = ApkCache::new(registry.clone());
+
+        assert_eq!(cache.get(0, 100).await.unwrap(), apk(0, 100));
+        assert_eq!(cache.get(0, 132).await.unwrap(), apk(0, 132));
+        assert_eq!(registry.fetches(0, 132), 1);
+    }
+
+    #[tokio::test]
+    async fn test_least_recently_used_evicted() {
+        let registry = Arc::new(MockApkRegistry::default());
+        let cache = ApkCache::with_capacity(registry.clone(), NonZeroUsize::new(2).unwrap());
+
+        cache.get(0, 1).await.unwrap();
+        cache.get(0, 2).await.unwrap();
+        // Block 1 was used more recently than block 2
+        cache.get(0, 1).await.unwrap();
+        cache.get(0, 3).await.unwrap();
+        assert_eq!(cache.len().await, 2);
+
+        cache.get(0, 1).await.unwrap();
+        assert_eq!(registry.fetches(0, 1), 1);
+        cache.get(0, 2).await.unwrap();
+        assert_eq!(registry.fetches(0, 2), 2);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_quorum() {
+        let registry = Arc::new(MockApkRegistry::default());
+        let cache = ApkCache::new(registry.clone());
+
+        cache.get(0, 100).await.unwrap();
+        cache.get(1, 100).await.unwrap();
+        cache.invalidate(0).await;
+        assert_eq!(cache.len().await, 1);
+
+        cache.get(0, 100).await.unwrap();
+        cache.get(1, 100).await.unwrap();
+        assert_eq!(registry.fetches(0, 100), 2);
+        assert_eq!(registry.fetches(1, 100), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_fetch_not_cached() {
+        let registry = Arc::new(MockApkRegistry::default());
+        let cache = ApkCache::new(registry.clone());
+
+        *registry.failing.lock().unwrap() = true;
+        assert!(cache.get(0, 100).await.is_err());
+        assert!(cache.is_empty().await);
+
+        *registry.failing.lock().unwrap() = false;
+        assert_eq!(cache.get(0, 100).await.unwrap(), apk(0, 100));
+        assert_eq!(registry.fetches(0, 100), 2);
+    }
+}
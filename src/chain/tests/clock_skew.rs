@@ -0,0 +1,152 @@
+use crate::chain::clock_skew::parse_http_date;
+use crate::chain::{ClockSkew, ClockSkewConfig, ClockSkewMonitor, SkewSeverity, TimeSource};
+use crate::clock::{Clock, MockClock};
+use anyhow::{Result, anyhow};
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const NOW: u64 = 1_700_000_000;
+
+/// Source reading `clock` off by `offset_secs`, positive when the source is ahead
+struct SkewedSource {
+    clock: Arc<MockClock>,
+    offset_secs: i64,
+}
+
+impl TimeSource for SkewedSource {
+    fn name(&self) -> &str {
+        "skewed"
+    }
+
+    fn now(&self) -> BoxFuture<'_, Result<SystemTime>> {
+        let now = self.clock.now();
+        let offset = Duration::from_secs(self.offset_secs.unsigned_abs());
+        Box::pin(async move {
+            Ok(if self.offset_secs < 0 {
+                now - offset
+            } else {
+                now + offset
+            })
+        })
+    }
+}
+
+/// Source that never answers
+struct UnreachableSource;
+
+impl TimeSource for UnreachableSource {
+    fn name(&self) -> &str {
+        "unreachable"
+    }
+
+    fn now(&self) -> BoxFuture<'_, Result<SystemTime>> {
+        Box::pin(async { Err(anyhow!("connection refused")) })
+    }
+}
+
+/// Monitor with the default thresholds, against sources off from the local clock by
+/// `offsets` seconds
+fn monitor(offsets: &[i64]) -> ClockSkewMonitor {
+    let clock = Arc::new(MockClock::new(UNIX_EPOCH + Duration::from_secs(NOW)));
+    offsets.iter().fold(
+        ClockSkewMonitor::new(ClockSkewConfig::default()).with_clock(clock.clone()),
+        |monitor, &offset_secs| {
+            monitor.with_source(Arc::new(SkewedSource {
+                clock: clock.clone(),
+                offset_secs,
+            }))
+        },
+    )
+}
+
+fn skew_secs(secs: i64) -> ClockSkew {
+    ClockSkew {
+        offset_ms: secs * 1000,
+    }
+}
+
+#[cfg(test)]
+mod clock_skew_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_small_skew_within_tolerance() {
+        let monitor = monitor(&[-3]);
+        let skew = monitor.ensure_within_tolerance().await.unwrap();
+        assert_eq!(skew, skew_secs(3));
+        assert_eq!(monitor.config().severity(&skew), SkewSeverity::Ok);
+        assert_eq!(*monitor.subscribe().borrow(), Some(skew));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_skew_above_soft_threshold_warns() {
+        let monitor = monitor(&[30]);
+        let skew = monitor.ensure_within_tolerance().await.unwrap();
+        assert_eq!(skew, skew_secs(-30));
+        assert_eq!(skew.to_string(), "30000ms behind");
+        assert_eq!(monitor.config().severity(&skew), SkewSeverity::Warning);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_large_skew_refused_either_way() {
+        for (offset, expected) in [(-120, "120000ms ahead"), (120, "120000ms behind")] {
+            let monitor = monitor(&[offset]);
+            let err = monitor.ensure_within_tolerance().await.unwrap_err();
+            assert!(err.to_string().contains(expected), "{err}");
+
+            // The estimate is still published, for readiness to fail on
+            let skew = monitor.subscribe().borrow().unwrap();
+            assert_eq!(monitor.config().severity(&skew), SkewSeverity::Critical);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_median_of_sources() {
+        let monitor = monitor(&[-2, 300, -4]).with_source(Arc::new(UnreachableSource));
+        assert_eq!(monitor.check().await.unwrap(), skew_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_no_source_answered() {
+        let monitor = monitor(&[]).with_source(Arc::new(UnreachableSource));
+        assert!(monitor.check().await.is_err());
+        assert_eq!(*monitor.subscribe().borrow(), None);
+    }
+
+    #[cfg(feature = "http-api")]
+    #[tokio::test(start_paused = true)]
+    async fn test_not_ready_above_hard_threshold() {
+        use crate::server::HealthCheckServer;
+        use tokio::sync::watch;
+        use tokio::time::Instant;
+
+        let monitor = monitor(&[-90]);
+        let (_heartbeat, receiver) = watch::channel(Instant::now());
+        let server = HealthCheckServer::new(receiver)
+            .with_clock_skew(monitor.subscribe(), monitor.config().hard_threshold);
+
+        // Not measured yet
+        assert_eq!(server.ready(), Ok(()));
+
+        monitor.check().await.unwrap();
+        let reason = server.ready().unwrap_err().reason;
+        assert_eq!(reason, "clock skew of 90000ms ahead exceeds 60s");
+    }
+
+    #[test]
+    fn test_parse_http_date() {
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT").unwrap(),
+            UNIX_EPOCH + Duration::from_secs(784_111_777)
+        );
+        for invalid in [
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+            "Sun, 06 Foo 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 24:00:00 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+        ] {
+            assert!(parse_http_date(invalid).is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::chain::{CoalescerConfig, RefreshCoalescer};
+use std::time::Duration;
+use tokio::time::Instant;
+
+const CONFIG: CoalescerConfig = CoalescerConfig {
+    debounce: Duration::from_secs(2),
+    min_interval: Duration::from_secs(5),
+};
+
+fn secs(secs: u64) -> Duration {
+    Duration::from_secs(secs)
+}
+
+#[cfg(test)]
+mod coalescer_tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_coalesced_into_one_refresh() {
+        let start = Instant::now();
+        let mut coalescer = RefreshCoalescer::new(CONFIG);
+        assert_eq!(coalescer.deadline(), None);
+
+        for millis in 0..50 {
+            coalescer.trigger(start + Duration::from_millis(millis));
+        }
+        assert_eq!(coalescer.deadline(), Some(start + CONFIG.debounce));
+        assert_eq!(coalescer.poll(start + secs(1)), None);
+
+        assert_eq!(coalescer.poll(start + CONFIG.debounce), Some(50));
+        assert_eq!(coalescer.deadline(), None);
+        assert_eq!(coalescer.pending(), 0);
+    }
+
+    #[test]
+    fn test_trickle_refreshed_at_min_interval() {
+        let start = Instant::now();
+        let mut coalescer = RefreshCoalescer::new(CONFIG);
+        let mut refreshes = Vec::new();
+        let mut covered = 0;
+
+        // A trigger every second, each window waits for the minimum interval
+        for second in 0..20 {
+            let now = start + secs(second);
+            coalescer.trigger(now);
+            if let Some(triggers) = coalescer.poll(now) {
+                refreshes.push(second);
+                covered += triggers;
+            }
+        }
+        assert_eq!(refreshes, vec![2, 7, 12, 17]);
+        assert_eq!(covered + coalescer.pending(), 20);
+        assert_eq!(coalescer.deadline(), Some(start + secs(22)));
+    }
+
+    #[test]
+    fn test_quiet_period_refreshes_after_debounce() {
+        let start = Instant::now();
+        let mut coalescer = RefreshCoalescer::new(CONFIG);
+        coalescer.trigger(start);
+        assert_eq!(coalescer.poll(start + CONFIG.debounce), Some(1));
+
+        // Nothing is due while quiet
+        assert_eq!(coalescer.poll(start + secs(60)), None);
+
+        // Long after the last refresh, only the debounce window is waited for
+        coalescer.trigger(start + secs(100));
+        assert_eq!(coalescer.deadline(), Some(start + secs(102)));
+
+        // Shortly after it, the minimum interval is
+        assert_eq!(coalescer.poll(start + secs(102)), Some(1));
+        coalescer.trigger(start + secs(103));
+        assert_eq!(coalescer.deadline(), Some(start + secs(107)));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::chain::cross_chain::{SUBMIT_CERTIFICATE_FUNCTION, certificate_transaction};
+use crate::chain::{ChainSubmitter, CrossChainAggregationRouter};
+use crate::contributor::QuorumCertificate;
+use crate::contributor::tests::mock::MockContributor;
+use alloy::rpc::types::TransactionRequest;
+use alloy_json_abi::Function;
+use alloy_primitives::{Address, TxHash, TxKind};
+use anyhow::{Result, anyhow};
+use commonware_cryptography::Signer;
+use futures::future::BoxFuture;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Submitter of one chain, answering after `delay` with a hash of its chain id
+struct MockSubmitter {
+    chain_id: u8,
+    delay: Duration,
+    failing: bool,
+    submitted: Mutex<Vec<TransactionRequest>>,
+}
+
+impl MockSubmitter {
+    fn new(chain_id: u8) -> Arc<Self> {
+        Arc::new(Self {
+            chain_id,
+            delay: Duration::from_secs(10),
+            failing: false,
+            submitted: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn failing(chain_id: u8) -> Arc<Self> {
+        Arc::new(Self {
+            chain_id,
+            delay: Duration::from_secs(10),
+            failing: true,
+            submitted: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn submitted(&self) -> Vec<TransactionRequest> {
+        self.submitted.lock().unwrap().clone()
+    }
+}
+
+impl ChainSubmitter for MockSubmitter {
+    fn submit(&self, transaction: TransactionRequest) -> BoxFuture<'_, Result<TxHash>> {
+        self.submitted.lock().unwrap().push(transaction);
+        Box::pin(async move {
+            tokio::time::sleep(self.delay).await;
+            if self.failing {
+                return Err(anyhow!("chain {} unavailable", self.chain_id));
+            }
+            Ok(TxHash::repeat_byte(self.chain_id))
+        })
+    }
+}
+
+fn certificate() -> QuorumCertificate {
+    let signer = MockContributor::create_test_bn254(1);
+    QuorumCertificate {
+        round: 7,
+        payload: [3; 32],
+        signature: signer.sign(None, &[3; 32]),
+        signers: [0, 2].into_iter().collect(),
+    }
+}
+
+#[cfg(test)]
+mod cross_chain_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_routes_to_every_destination_concurrently() {
+        let (first, second) = (MockSubmitter::new(1), MockSubmitter::new(2));
+        let mut router = CrossChainAggregationRouter::new();
+        router
+            .add_destination_with_submitter(1, Address::repeat_byte(0xa), first.clone())
+            .unwrap();
+        router
+            .add_destination_with_submitter(2, Address::repeat_byte(0xb), second.clone())
+            .unwrap();
+
+        let start = tokio::time::Instant::now();
+        let results = router.submit(&certificate()).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(10));
+
+        let hashes: Vec<(u64, TxHash)> = results
+            .into_iter()
+            .map(|(chain_id, result)| (chain_id, result.unwrap()))
+            .collect();
+        assert_eq!(
+            hashes,
+            vec![(1, TxHash::repeat_byte(1)), (2, TxHash::repeat_byte(2))]
+        );
+
+        // Each chain receives the certificate for its own contract and chain id
+        for (submitter, chain_id, contract) in [
+            (&first, 1, Address::repeat_byte(0xa)),
+            (&second, 2, Address::repeat_byte(0xb)),
+        ] {
+            let submitted = submitter.submitted();
+            assert_eq!(submitted.len(), 1);
+            assert_eq!(submitted[0].chain_id, Some(chain_id));
+            assert_eq!(submitted[0].to, Some(TxKind::Call(contract)));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_failed_chain_does_not_block_others() {
+        let (first, second) = (MockSubmitter::new(1), MockSubmitter::failing(2));
+        let mut router = CrossChainAggregationRouter::new();
+        router
+            .add_destination_with_submitter(1, Address::repeat_byte(0xa), first.clone())
+            .unwrap();
+        router
+            .add_destination_with_submitter(2, Address::repeat_byte(0xb), second)
+            .unwrap();
+
+        let results = router.submit(&certificate()).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, 1);
+        assert_eq!(*results[0].1.as_ref().unwrap(), TxHash::repeat_byte(1));
+        assert_eq!(results[1].0, 2);
+        assert!(results[1].1.is_err());
+        assert_eq!(first.submitted().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_route_returns_one_submission_per_destination() {
+        let mut router = CrossChainAggregationRouter::new();
+        assert!(router.route(&certificate()).is_empty());
+
+        for chain_id in 1..=3 {
+            router
+                .add_destination_with_submitter(
+                    chain_id,
+                    Address::repeat_byte(chain_id as u8),
+                    MockSubmitter::new(chain_id as u8),
+                )
+                .unwrap();
+        }
+        let submissions = router.route(&certificate());
+        assert_eq!(submissions.len(), 3);
+        let hashes = futures::future::join_all(submissions).await;
+        assert!(hashes.iter().all(|hash| hash.is_ok()));
+    }
+
+    #[test]
+    fn test_duplicate_destination_rejected() {
+        let mut router = CrossChainAggregationRouter::new();
+        router
+            .add_destination_with_submitter(1, Address::repeat_byte(0xa), MockSubmitter::new(1))
+            .unwrap();
+        assert!(
+            router
+                .add_destination_with_submitter(1, Address::repeat_byte(0xb), MockSubmitter::new(1))
+                .is_err()
+        );
+        assert_eq!(router.chain_ids(), vec![1]);
+    }
+
+    #[test]
+    fn test_certificate_transaction_calls_entrypoint() {
+        let transaction =
+            certificate_transaction(Address::repeat_byte(0xa), &certificate()).unwrap();
+        let selector = Function::parse(SUBMIT_CERTIFICATE_FUNCTION)
+            .unwrap()
+            .selector();
+        let input = transaction.input.input().unwrap();
+        assert_eq!(&input[..4], selector.as_slice());
+        // Four head words, then the length and padded bytes of the signature
+        assert_eq!(input.len(), 4 + 32 * 6);
+    }
+}
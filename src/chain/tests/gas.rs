@@ -0,0 +1,113 @@
+use crate::chain::gas::{FEE_HISTORY_BLOCKS, Fees, REWARD_PERCENTILES};
+use crate::chain::{FeeHistorySource, GasOracle};
+use alloy::rpc::types::FeeHistory;
+use anyhow::Result;
+use futures::future::BoxFuture;
+use std::sync::Mutex;
+use std::time::Duration;
+
+const GWEI: u128 = 1_000_000_000;
+
+/// Node answering `eth_feeHistory` with a configurable history
+#[derive(Default)]
+struct MockHistory {
+    history: Mutex<FeeHistory>,
+    /// Arguments of every call
+    calls: Mutex<Vec<(u64, Vec<f64>)>>,
+}
+
+impl MockHistory {
+    fn with_history(history: FeeHistory) -> Self {
+        Self {
+            history: Mutex::new(history),
+            ..Default::default()
+        }
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.lock().unwrap().len()
+    }
+}
+
+impl FeeHistorySource for MockHistory {
+    fn fee_history<'a>(
+        &'a self,
+        block_count: u64,
+        reward_percentiles: &'a [f64],
+    ) -> BoxFuture<'a, Result<FeeHistory>> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push((block_count, reward_percentiles.to_vec()));
+        let history = self.history.lock().unwrap().clone();
+        Box::pin(async move { Ok(history) })
+    }
+}
+
+/// History whose next base fee is `base_fee` with one reward row per block
+fn history(base_fee: u128, rewards: &[[u128; 3]]) -> FeeHistory {
+    FeeHistory {
+        base_fee_per_gas: vec![base_fee / 2; rewards.len()]
+            .into_iter()
+            .chain([base_fee])
+            .collect(),
+        gas_used_ratio: vec![0.5; rewards.len()],
+        reward: Some(rewards.iter().map(|row| row.to_vec()).collect()),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod gas_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fees_follow_formula() {
+        let rewards = [
+            [3 * GWEI, 5 * GWEI, 9 * GWEI],
+            [GWEI, 4 * GWEI, 8 * GWEI],
+            [2 * GWEI, 6 * GWEI, 7 * GWEI],
+        ];
+        let source = MockHistory::with_history(history(30 * GWEI, &rewards));
+        let oracle = GasOracle::new();
+        let (priority, max_fee) = oracle.estimate_fees(&source).await.unwrap();
+
+        // Median of the 10th percentiles, on top of twice the next base fee
+        assert_eq!(priority, 2 * GWEI);
+        assert_eq!(max_fee, 2 * 30 * GWEI + 2 * GWEI);
+
+        let calls = source.calls.lock().unwrap().clone();
+        assert_eq!(
+            calls,
+            vec![(FEE_HISTORY_BLOCKS, REWARD_PERCENTILES.to_vec())]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_estimate_is_cached_for_a_block() {
+        let source = MockHistory::with_history(history(10 * GWEI, &[[GWEI, GWEI, GWEI]]));
+        let oracle = GasOracle::new();
+        let first = oracle.estimate_fees(&source).await.unwrap();
+
+        *source.history.lock().unwrap() = history(20 * GWEI, &[[GWEI, GWEI, GWEI]]);
+        tokio::time::sleep(Duration::from_secs(11)).await;
+        assert_eq!(oracle.estimate_fees(&source).await.unwrap(), first);
+        assert_eq!(source.calls(), 1);
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        let refreshed = oracle.estimate_fees(&source).await.unwrap();
+        assert_eq!(refreshed, (GWEI, 41 * GWEI));
+        assert_eq!(source.calls(), 2);
+    }
+
+    #[test]
+    fn test_history_without_data_is_rejected() {
+        assert!(Fees::from_history(&FeeHistory::default()).is_err());
+
+        let without_rewards = FeeHistory {
+            base_fee_per_gas: vec![GWEI],
+            ..Default::default()
+        };
+        assert!(Fees::from_history(&without_rewards).is_err());
+    }
+}
@@ -0,0 +1,15 @@
+mod abi;
+mod apk_cache;
+mod clock_skew;
+mod coalescer;
+mod cross_chain;
+mod gas;
+mod multi_rpc;
+mod nonce;
+mod operator_state;
+mod pool;
+mod quorum_updater;
+mod stake_cache;
+mod task_responder;
+#[cfg(feature = "chain")]
+mod tls;
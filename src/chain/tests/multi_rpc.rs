@@ -0,0 +1,215 @@
+use crate::chain::multi_rpc::endpoint_name;
+use crate::chain::{MultiRpcConfig, MultiRpcProvider};
+#[cfg(feature = "observability")]
+use crate::metrics::RpcMetrics;
+use anyhow::{Result, anyhow};
+use futures::future::BoxFuture;
+#[cfg(feature = "observability")]
+use prometheus_client::{encoding::text::encode, registry::Registry};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Endpoint answering with its name unless told to fail
+#[derive(Default)]
+struct StubEndpoint {
+    failing: AtomicBool,
+    calls: AtomicUsize,
+}
+
+impl StubEndpoint {
+    fn failing() -> Arc<Self> {
+        let endpoint = Self::default();
+        endpoint.failing.store(true, Ordering::SeqCst);
+        Arc::new(endpoint)
+    }
+
+    fn healthy() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    fn set_failing(&self, failing: bool) {
+        self.failing.store(failing, Ordering::SeqCst);
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+fn request(endpoint: &Arc<StubEndpoint>) -> BoxFuture<'_, Result<usize>> {
+    Box::pin(async move {
+        let call = endpoint.calls.fetch_add(1, Ordering::SeqCst);
+        if endpoint.failing.load(Ordering::SeqCst) {
+            return Err(anyhow!("endpoint unavailable"));
+        }
+        Ok(call)
+    })
+}
+
+fn provider(endpoints: &[Arc<StubEndpoint>]) -> MultiRpcProvider<Arc<StubEndpoint>> {
+    let endpoints = endpoints
+        .iter()
+        .enumerate()
+        .map(|(index, endpoint)| (format!("rpc-{index}"), endpoint.clone()))
+        .collect();
+    MultiRpcProvider::new(
+        endpoints,
+        MultiRpcConfig {
+            cooldown_secs: 5,
+            max_cooldown_secs: 30,
+        },
+    )
+    .unwrap()
+}
+
+/// `provider` reporting its requests to the returned metrics
+#[cfg(feature = "observability")]
+fn metered(
+    provider: MultiRpcProvider<Arc<StubEndpoint>>,
+) -> (MultiRpcProvider<Arc<StubEndpoint>>, RpcMetrics) {
+    let metrics = RpcMetrics::new();
+    (provider.with_metrics(metrics.clone()), metrics)
+}
+
+// Encode the registry in the Prometheus text format
+#[cfg(feature = "observability")]
+fn encoded(metrics: &RpcMetrics) -> String {
+    let mut registry = Registry::default();
+    metrics.register(&mut registry);
+    let mut buf = String::new();
+    encode(&mut buf, &registry).unwrap();
+    buf
+}
+
+#[cfg(test)]
+mod multi_rpc_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_falls_back_to_second_endpoint() {
+        let endpoints = [StubEndpoint::failing(), StubEndpoint::healthy()];
+        let provider = provider(&endpoints);
+        #[cfg(feature = "observability")]
+        let (provider, metrics) = metered(provider);
+        for _ in 0..4 {
+            assert!(provider.call(request).await.is_ok());
+        }
+        assert!(!provider.is_available(0));
+        assert!(provider.is_available(1));
+
+        // The failing endpoint is only tried once, then left out for its cooldown
+        assert_eq!(endpoints[0].calls(), 1);
+        assert_eq!(endpoints[1].calls(), 4);
+        #[cfg(feature = "observability")]
+        {
+            let buf = encoded(&metrics);
+            assert!(buf.contains(r#"rpc_request_failure_total{endpoint="rpc-0"} 1"#));
+            assert!(buf.contains(r#"rpc_request_success_total{endpoint="rpc-1"} 4"#));
+            assert!(!buf.contains(r#"rpc_request_success_total{endpoint="rpc-0"}"#));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_round_robin() {
+        let endpoints = [StubEndpoint::healthy(), StubEndpoint::healthy()];
+        let provider = provider(&endpoints);
+        for _ in 0..6 {
+            provider.call(request).await.unwrap();
+        }
+        assert_eq!(endpoints[0].calls(), 3);
+        assert_eq!(endpoints[1].calls(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cooldown_backs_off_exponentially() {
+        let endpoints = [StubEndpoint::failing(), StubEndpoint::healthy()];
+        let provider = provider(&endpoints);
+
+        // Each consecutive failure doubles the cooldown, up to the maximum
+        for cooldown in [5, 10, 20, 30, 30] {
+            let calls = endpoints[0].calls();
+            while endpoints[0].calls() == calls {
+                provider.call(request).await.unwrap();
+            }
+            let failed_at = tokio::time::Instant::now();
+            while !provider.is_available(0) {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            assert_eq!(failed_at.elapsed(), Duration::from_secs(cooldown));
+        }
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let config = MultiRpcConfig {
+            cooldown_secs: 5,
+            max_cooldown_secs: 300,
+        };
+        assert_eq!(config.cooldown(1), Duration::from_secs(5));
+        assert_eq!(config.cooldown(2), Duration::from_secs(10));
+        assert_eq!(config.cooldown(7), Duration::from_secs(300));
+        assert_eq!(config.cooldown(u32::MAX), Duration::from_secs(300));
+    }
+
+    #[test]
+    fn test_requires_an_endpoint() {
+        let provider =
+            MultiRpcProvider::<Arc<StubEndpoint>>::new(Vec::new(), MultiRpcConfig::default());
+        assert!(provider.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_recovered_endpoint_resets_backoff() {
+        let endpoints = [StubEndpoint::failing(), StubEndpoint::healthy()];
+        let provider = provider(&endpoints);
+        provider.call(request).await.unwrap();
+        assert!(!provider.is_available(0));
+
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        endpoints[0].set_failing(false);
+        provider.call(request).await.unwrap();
+        provider.call(request).await.unwrap();
+        assert_eq!(endpoints[0].calls(), 2);
+
+        // The next failure starts over from the base cooldown
+        endpoints[0].set_failing(true);
+        while provider.is_available(0) {
+            provider.call(request).await.unwrap();
+        }
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert!(provider.is_available(0));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_all_endpoints_failing() {
+        let endpoints = [StubEndpoint::failing(), StubEndpoint::failing()];
+        let provider = provider(&endpoints);
+        #[cfg(feature = "observability")]
+        let (provider, metrics) = metered(provider);
+        assert!(provider.call(request).await.is_err());
+        assert_eq!(endpoints[0].calls(), 1);
+        assert_eq!(endpoints[1].calls(), 1);
+
+        // With every endpoint cooling down, the one recovering first is still tried
+        assert!(provider.call(request).await.is_err());
+        assert_eq!(endpoints[0].calls(), 2);
+        assert_eq!(endpoints[1].calls(), 1);
+        #[cfg(feature = "observability")]
+        assert!(encoded(&metrics).contains(r#"rpc_request_failure_total{endpoint="rpc-0"} 2"#));
+
+        endpoints[1].set_failing(false);
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert!(provider.call(request).await.is_ok());
+    }
+
+    #[test]
+    fn test_endpoint_name_hides_path() {
+        assert_eq!(
+            endpoint_name("https://eth-mainnet.example.com/v2/secret-key"),
+            "eth-mainnet.example.com"
+        );
+        assert_eq!(endpoint_name("http://localhost:8545"), "localhost:8545");
+        assert_eq!(endpoint_name("not a url"), "invalid");
+    }
+}
@@ -0,0 +1,141 @@
+use crate::chain::nonce::{MAX_NONCE_RETRIES, is_nonce_too_low};
+use crate::chain::{NonceManager, NonceSource};
+use anyhow::{Result, anyhow};
+use futures::future::BoxFuture;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Account on a chain accepting only transactions with the next nonce
+#[derive(Default)]
+struct MockChain {
+    /// Nonces of the transactions that landed, in order
+    landed: Mutex<Vec<u64>>,
+    reads: AtomicUsize,
+}
+
+impl MockChain {
+    fn with_count(count: u64) -> Self {
+        let chain = Self::default();
+        chain.landed.lock().unwrap().extend(0..count);
+        chain
+    }
+
+    fn count(&self) -> u64 {
+        self.landed.lock().unwrap().len() as u64
+    }
+
+    /// Send a transaction with `nonce`, taking a moment like a node would
+    async fn send(&self, nonce: u64) -> Result<u64> {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let mut landed = self.landed.lock().unwrap();
+        let count = landed.len() as u64;
+        if nonce < count {
+            return Err(anyhow!("server returned an error response: nonce too low"));
+        }
+        if nonce > count {
+            return Err(anyhow!("nonce gap: expected {count}, got {nonce}"));
+        }
+        landed.push(nonce);
+        Ok(nonce)
+    }
+
+    fn landed(&self) -> Vec<u64> {
+        self.landed.lock().unwrap().clone()
+    }
+}
+
+impl NonceSource for MockChain {
+    fn latest_nonce(&self) -> BoxFuture<'_, Result<u64>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async move { Ok(self.count()) })
+    }
+}
+
+async fn submit(nonces: &NonceManager, chain: &MockChain) -> Result<u64> {
+    nonces
+        .submit(chain, |nonce| Box::pin(chain.send(nonce)))
+        .await
+}
+
+#[cfg(test)]
+mod nonce_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrent_submissions_get_consecutive_nonces() {
+        let chain = MockChain::with_count(5);
+        let nonces = NonceManager::new();
+        let (first, second) = tokio::join!(submit(&nonces, &chain), submit(&nonces, &chain));
+        let mut sent = vec![first.unwrap(), second.unwrap()];
+        sent.sort();
+        assert_eq!(sent, vec![5, 6]);
+        assert_eq!(chain.landed()[5..], [5, 6]);
+
+        // The count is only read once, at the first submission
+        assert_eq!(chain.reads.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_nonce_too_low_resyncs_and_retries() {
+        let chain = MockChain::default();
+        let nonces = NonceManager::new();
+        assert_eq!(submit(&nonces, &chain).await.unwrap(), 0);
+
+        // Another process sends from the same account
+        chain.landed.lock().unwrap().extend([1, 2]);
+        assert_eq!(submit(&nonces, &chain).await.unwrap(), 3);
+        assert_eq!(chain.landed(), vec![0, 1, 2, 3]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_failed_submission_keeps_nonce() {
+        let chain = MockChain::default();
+        let nonces = NonceManager::new();
+        let failed = nonces
+            .submit(&chain, |_| {
+                Box::pin(async { Err::<u64, _>(anyhow!("insufficient funds")) })
+            })
+            .await;
+        assert!(failed.is_err());
+        assert_eq!(submit(&nonces, &chain).await.unwrap(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retries_are_bounded() {
+        let chain = MockChain::default();
+        let nonces = NonceManager::new();
+        let attempts = AtomicUsize::new(0);
+        let result = nonces
+            .submit(&chain, |_| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async { Err::<u64, _>(anyhow!("nonce too low")) })
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), MAX_NONCE_RETRIES + 1);
+    }
+
+    #[tokio::test]
+    async fn test_next_nonce_and_resync() {
+        let chain = MockChain::with_count(2);
+        let nonces = NonceManager::new();
+        assert_eq!(nonces.next_nonce(&chain).await.unwrap(), 2);
+        assert_eq!(nonces.next_nonce(&chain).await.unwrap(), 3);
+        assert_eq!(nonces.resync(&chain).await.unwrap(), 2);
+        assert_eq!(nonces.next_nonce(&chain).await.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_nonce_too_low_detection() {
+        assert!(is_nonce_too_low(&anyhow!(
+            "Nonce too low: next nonce 4, tx nonce 3"
+        )));
+        let wrapped = anyhow!("server returned an error response: nonce too low")
+            .context("failed to send transaction");
+        assert!(is_nonce_too_low(&wrapped));
+        assert!(!is_nonce_too_low(&anyhow!(
+            "replacement transaction underpriced"
+        )));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::chain::operator_state::MAX_CACHED_OPERATOR_SETS;
+use crate::chain::{
+    OperatorKeySource, OperatorStake, OperatorStateRetrieverClient, StakeRetriever,
+};
+use crate::contributor::tests::harness::Harness;
+use crate::contributor::tests::mock::MockContributor;
+use alloy_primitives::{Address, B256, U256};
+use anyhow::{Result, anyhow};
+use bn254::{G1PublicKey, PublicKey};
+use commonware_cryptography::Signer;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Operator state retriever listing the operators at `addresses`, counting calls
+struct MockRetriever {
+    addresses: Vec<Address>,
+    calls: Mutex<HashMap<(u8, u64), usize>>,
+}
+
+impl MockRetriever {
+    fn new(operators: usize) -> Self {
+        Self {
+            addresses: (1..=operators as u8).map(Address::repeat_byte).collect(),
+            calls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn calls(&self, quorum_id: u8, block_number: u64) -> usize {
+        let calls = self.calls.lock().unwrap();
+        calls
+            .get(&(quorum_id, block_number))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn total_calls(&self) -> usize {
+        self.calls.lock().unwrap().values().sum()
+    }
+}
+
+impl StakeRetriever for MockRetriever {
+    async fn operator_stakes(
+        &self,
+        quorum_id: u8,
+        block_number: u64,
+    ) -> Result<Vec<OperatorStake>> {
+        *self
+            .calls
+            .lock()
+            .unwrap()
+            .entry((quorum_id, block_number))
+            .or_default() += 1;
+        Ok(self
+            .addresses
+            .iter()
+            .enumerate()
+            .map(|(index, operator)| OperatorStake {
+                operator: *operator,
+                operator_id: B256::left_padding_from(operator.as_slice()),
+                stake: U256::from(1000 * (index as u64 + 1)),
+            })
+            .collect())
+    }
+}
+
+/// APK registry holding the key of a harness signer for each operator, counting reads
+struct MockKeys {
+    keys: HashMap<Address, PublicKey>,
+    reads: Mutex<HashMap<Address, usize>>,
+}
+
+impl MockKeys {
+    fn new(harness: &Harness) -> Self {
+        Self {
+            keys: harness
+                .signers
+                .iter()
+                .enumerate()
+                .map(|(index, signer)| (Address::repeat_byte(index as u8 + 1), signer.public_key()))
+                .collect(),
+            reads: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn reads(&self) -> usize {
+        self.reads.lock().unwrap().values().sum()
+    }
+}
+
+impl OperatorKeySource for MockKeys {
+    async fn operator_keys(&self, operator: Address) -> Result<(PublicKey, G1PublicKey)> {
+        *self.reads.lock().unwrap().entry(operator).or_default() += 1;
+        let key = self
+            .keys
+            .get(&operator)
+            .ok_or_else(|| anyhow!("operator not registered: {operator}"))?;
+        // G1 keys only feed the APK, a placeholder is enough
+        Ok((key.clone(), MockContributor::placeholder_g1_key()?))
+    }
+}
+
+#[cfg(test)]
+mod operator_state_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_operator_set_read_in_one_call() {
+        let harness = Harness::new(3);
+        let retriever = Arc::new(MockRetriever::new(3));
+        let keys = Arc::new(MockKeys::new(&harness));
+        let client = OperatorStateRetrieverClient::new(retriever.clone(), keys.clone());
+
+        let operators = client.get_operators_for_quorum(0, 100).await.unwrap();
+        let pubkeys: Vec<PublicKey> = operators.iter().map(|op| op.pubkey.clone()).collect();
+        let expected: Vec<PublicKey> = harness.signers.iter().map(|s| s.public_key()).collect();
+        assert_eq!(pubkeys, expected);
+        let stakes: Vec<u128> = operators.iter().map(|op| op.stake).collect();
+        assert_eq!(stakes, vec![1000, 2000, 3000]);
+        assert_eq!(retriever.calls(0, 100), 1);
+        assert_eq!(keys.reads(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_cached_by_quorum_and_block() {
+        let harness = Harness::new(3);
+        let retriever = Arc::new(MockRetriever::new(3));
+        let keys = Arc::new(MockKeys::new(&harness));
+        let client = OperatorStateRetrieverClient::new(retriever.clone(), keys.clone());
+
+        for _ in 0..5 {
+            client.get_operators_for_quorum(0, 100).await.unwrap();
+            client.get_operators_for_quorum(1, 100).await.unwrap();
+        }
+        assert_eq!(retriever.calls(0, 100), 1);
+        assert_eq!(retriever.calls(1, 100), 1);
+        assert_eq!(client.len().await, 2);
+
+        // A new block reads the set again, but not the keys of known operators
+        client.get_operators_for_quorum(0, 101).await.unwrap();
+        assert_eq!(retriever.calls(0, 101), 1);
+        assert_eq!(keys.reads(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_least_recently_used_set_evicted() {
+        let harness = Harness::new(2);
+        let retriever = Arc::new(MockRetriever::new(2));
+        let client =
+            OperatorStateRetrieverClient::new(retriever.clone(), Arc::new(MockKeys::new(&harness)));
+
+        for block in 0..MAX_CACHED_OPERATOR_SETS as u64 {
+            client.get_operators_for_quorum(0, block).await.unwrap();
+        }
+        // Block 0 was used more recently than block 1
+        client.get_operators_for_quorum(0, 0).await.unwrap();
+        client
+            .get_operators_for_quorum(0, MAX_CACHED_OPERATOR_SETS as u64)
+            .await
+            .unwrap();
+        assert_eq!(client.len().await, MAX_CACHED_OPERATOR_SETS);
+
+        client.get_operators_for_quorum(0, 0).await.unwrap();
+        assert_eq!(retriever.calls(0, 0), 1);
+        client.get_operators_for_quorum(0, 1).await.unwrap();
+        assert_eq!(retriever.calls(0, 1), 2);
+        assert_eq!(retriever.total_calls(), MAX_CACHED_OPERATOR_SETS + 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_read_not_cached() {
+        // The third operator has no registered key
+        let harness = Harness::new(2);
+        let retriever = Arc::new(MockRetriever::new(3));
+        let client =
+            OperatorStateRetrieverClient::new(retriever.clone(), Arc::new(MockKeys::new(&harness)));
+
+        assert!(client.get_operators_for_quorum(0, 100).await.is_err());
+        assert!(client.is_empty().await);
+        assert!(client.get_operators_for_quorum(0, 100).await.is_err());
+        assert_eq!(retriever.calls(0, 100), 2);
+    }
+}
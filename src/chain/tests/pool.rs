@@ -0,0 +1,79 @@
+use crate::chain::{PoolConfig, RpcConnectionPool};
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Pool of numbered stub providers, counting how many were built
+fn pool(max_connections: usize) -> (RpcConnectionPool<usize>, Arc<AtomicUsize>) {
+    let built = Arc::new(AtomicUsize::new(0));
+    let config = PoolConfig {
+        max_connections: NonZeroUsize::new(max_connections).unwrap(),
+        connection_timeout_ms: 1_000,
+    };
+    let pool = RpcConnectionPool::new(config, {
+        let built = built.clone();
+        move || Ok(built.fetch_add(1, Ordering::SeqCst))
+    });
+    (pool, built)
+}
+
+#[cfg(test)]
+mod pool_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reuses_released_provider() {
+        let (pool, built) = pool(2);
+        for _ in 0..100 {
+            let provider = pool.acquire().await.unwrap();
+            assert_eq!(*provider, 0);
+        }
+        assert_eq!(built.load(Ordering::SeqCst), 1);
+        assert_eq!(pool.idle(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_builds_up_to_max_connections() {
+        let (pool, built) = pool(2);
+        let first = pool.acquire().await.unwrap();
+        let second = pool.acquire().await.unwrap();
+        assert_ne!(*first, *second);
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_times_out_when_exhausted() {
+        let (pool, _) = pool(1);
+        let _held = pool.acquire().await.unwrap();
+        let start = tokio::time::Instant::now();
+        assert!(pool.acquire().await.is_err());
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_waits_for_release() {
+        let (pool, built) = pool(1);
+        let pool = Arc::new(pool);
+        let held = pool.acquire().await.unwrap();
+        let waiter = tokio::spawn({
+            let pool = pool.clone();
+            async move { *pool.acquire().await.unwrap() }
+        });
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        drop(held);
+        assert_eq!(waiter.await.unwrap(), 0);
+        assert_eq!(built.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_discarded_provider_replaced() {
+        let (pool, built) = pool(1);
+        pool.acquire().await.unwrap().discard();
+        assert_eq!(pool.idle(), 0);
+        assert_eq!(pool.available(), 1);
+        assert_eq!(*pool.acquire().await.unwrap(), 1);
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,465 @@
+use crate::chain::{
+    CoalescerConfig, DynamicQuorumUpdater, QuorumMember, QuorumRegistry, QuorumUpdated,
+};
+use crate::contributor::tests::harness::{EventLog, Harness, LogBuffer, MockValidator};
+use crate::contributor::tests::mock::MockContributor;
+use crate::contributor::types::DroppedShare;
+use crate::contributor::unknown_peers::UnknownPeerConfig;
+use crate::contributor::{AggregationInput, Contribute, ContributorBase};
+use crate::handlers::Contributor;
+use crate::handlers::RunConfig;
+use crate::types::ContributorIndex;
+use anyhow::Result;
+use bn254::{Bn254, PublicKey};
+use commonware_cryptography::Signer;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+fn member(signer: &Bn254) -> QuorumMember {
+    QuorumMember {
+        g2: signer.public_key(),
+        g1: MockContributor::placeholder_g1_key().expect("placeholder g1 key"),
+    }
+}
+
+/// Registry returning the latest operator set registered at or before a block
+#[derive(Default)]
+struct MockRegistry {
+    block: Mutex<u64>,
+    sets: Mutex<BTreeMap<u64, Vec<QuorumMember>>>,
+    /// Operator sets read so far
+    reads: AtomicUsize,
+}
+
+impl MockRegistry {
+    fn set_block(&self, block: u64) {
+        *self.block.lock().unwrap() = block;
+    }
+
+    fn set_operators(&self, from_block: u64, members: Vec<QuorumMember>) {
+        self.sets.lock().unwrap().insert(from_block, members);
+    }
+
+    fn reads(&self) -> usize {
+        self.reads.load(Ordering::Relaxed)
+    }
+}
+
+impl QuorumRegistry for MockRegistry {
+    async fn block_number(&self) -> Result<u64> {
+        Ok(*self.block.lock().unwrap())
+    }
+
+    async fn operator_set(&self, _quorum_id: u8, block_number: u64) -> Result<Vec<QuorumMember>> {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        let sets = self.sets.lock().unwrap();
+        Ok(sets
+            .range(..=block_number)
+            .next_back()
+            .map(|(_, members)| members.clone())
+            .unwrap_or_default())
+    }
+}
+
+/// Aggregator knowing the first two of three contributors and needing all three signatures
+fn aggregator_knowing_two(harness: &Harness) -> Contributor {
+    let initial = vec![
+        harness.signers[0].public_key(),
+        harness.signers[1].public_key(),
+    ];
+    let g1_map = initial
+        .iter()
+        .map(|key| (key.clone(), member(&harness.signers[0]).g1))
+        .collect();
+    Contributor::new(
+        harness.orchestrator.public_key(),
+        harness.signers[0].clone(),
+        initial,
+        Some(AggregationInput::new(3, g1_map)),
+    )
+    .unwrap()
+    .with_validator_factory(Arc::new(MockValidator))
+}
+
+/// Run `aggregator` alongside the other two contributors, all three signing
+fn spawn_with_unknown_third(
+    harness: &Harness,
+    aggregator: Contributor,
+) -> Vec<JoinHandle<Result<()>>> {
+    let mut handles = vec![harness.spawn(aggregator, 0)];
+    for i in 1..3 {
+        handles.push(harness.spawn(harness.contributor(i, None), i));
+    }
+    handles
+}
+
+/// Start `round` and wait for its signatures
+async fn sign_round(harness: &mut Harness, round: u64) {
+    harness.start(round).await;
+    harness.signed_rounds(Duration::from_millis(200)).await;
+}
+
+/// Run an aggregator knowing the first two of three contributors and needing all three
+/// signatures, then add the third once a round was signed after `delay`
+///
+/// Returns whether the round aggregated and how many shares were dropped from unknown
+/// senders.
+async fn add_third_after(grace: Duration, delay: Duration) -> (usize, usize) {
+    let mut harness = Harness::new(3);
+    let log = EventLog::default();
+    let (updates, receiver) = broadcast::channel(1);
+    let aggregator = aggregator_knowing_two(&harness)
+        .with_event_sink(Arc::new(log.clone()))
+        .with_quorum_updates(receiver)
+        .with_run_config(RunConfig {
+            unknown_sender_grace: grace,
+            ..RunConfig::default()
+        });
+    let handles = spawn_with_unknown_third(&harness, aggregator);
+
+    // The third signature arrives before the update adding its sender
+    sign_round(&mut harness, 1).await;
+    tokio::time::sleep(delay).await;
+    let added = member(&harness.signers[2]);
+    updates
+        .send(QuorumUpdated {
+            quorum_id: 0,
+            added: vec![added.g2.clone()],
+            removed: Vec::new(),
+            g1_keys: HashMap::from([(added.g2, added.g1)]),
+        })
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    for handle in handles {
+        handle.abort();
+    }
+
+    let reached = log.count("threshold_reached");
+    let dropped = log.count_reason("share_dropped", DroppedShare::UnknownSender.kind());
+    (reached, dropped)
+}
+
+#[cfg(test)]
+mod quorum_updater_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_no_update_within_epoch() {
+        let harness = Harness::new(2);
+        let registry = Arc::new(MockRegistry::default());
+        registry.set_operators(0, harness.signers.iter().map(member).collect());
+        let mut updater = DynamicQuorumUpdater::new(registry.clone(), 0, harness.contributors());
+
+        registry.set_block(5);
+        assert!(updater.poll_once().await.unwrap().is_none());
+
+        // A change registered mid-epoch is only picked up at the next boundary
+        registry.set_operators(10, vec![member(&harness.signers[0])]);
+        registry.set_block(20);
+        assert!(updater.poll_once().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_epoch_boundary_reports_changes() {
+        let harness = Harness::new(3);
+        let registry = Arc::new(MockRegistry::default());
+        registry.set_operators(
+            0,
+            vec![member(&harness.signers[0]), member(&harness.signers[1])],
+        );
+        registry.set_operators(
+            32,
+            vec![member(&harness.signers[0]), member(&harness.signers[2])],
+        );
+        let initial = vec![
+            harness.signers[0].public_key(),
+            harness.signers[1].public_key(),
+        ];
+        let mut updater = DynamicQuorumUpdater::new(registry.clone(), 0, initial);
+        let mut subscriber = updater.subscribe();
+
+        registry.set_block(31);
+        assert!(updater.poll_once().await.unwrap().is_none());
+
+        registry.set_block(33);
+        let update = updater
+            .poll_once()
+            .await
+            .unwrap()
+            .expect("membership changed");
+        assert_eq!(update.quorum_id, 0);
+        assert_eq!(update.added, vec![harness.signers[2].public_key()]);
+        assert_eq!(update.removed, vec![harness.signers[1].public_key()]);
+        assert!(
+            update
+                .g1_keys
+                .contains_key(&harness.signers[2].public_key())
+        );
+
+        let broadcast = subscriber.try_recv().unwrap();
+        assert_eq!(broadcast.added, update.added);
+        assert_eq!(broadcast.removed, update.removed);
+    }
+
+    #[tokio::test]
+    async fn test_update_contributor_set_reindexes() {
+        let harness = Harness::new(3);
+        let registry = Arc::new(MockRegistry::default());
+        registry.set_operators(0, harness.signers.iter().map(member).collect());
+        let initial = vec![
+            harness.signers[0].public_key(),
+            harness.signers[1].public_key(),
+        ];
+        let mut updater = DynamicQuorumUpdater::new(registry.clone(), 0, initial.clone());
+        let update = updater.poll_once().await.unwrap().unwrap();
+
+        let g1_map = initial
+            .iter()
+            .map(|key| (key.clone(), member(&harness.signers[0]).g1))
+            .collect();
+        let mut contributor = Contributor::new(
+            harness.orchestrator.public_key(),
+            harness.signers[0].clone(),
+            initial,
+            Some(AggregationInput::new(2, g1_map)),
+        )
+        .unwrap();
+        let added = harness.signers[2].public_key();
+        assert!(contributor.get_contributor_index(&added).is_none());
+
+        contributor.update_contributor_set(&update);
+        let mut expected = harness.contributors();
+        expected.sort();
+        assert_eq!(contributor.contributors_for_round(1), expected);
+        let index = ContributorIndex::from(expected.iter().position(|key| key == &added).unwrap());
+        assert_eq!(contributor.get_contributor_index(&added), Some(&index));
+    }
+
+    #[tokio::test]
+    async fn test_update_applied_before_next_round() {
+        let mut harness = Harness::new(3);
+        let registry = Arc::new(MockRegistry::default());
+        let initial = vec![
+            harness.signers[0].public_key(),
+            harness.signers[1].public_key(),
+        ];
+        registry.set_operators(
+            0,
+            vec![member(&harness.signers[0]), member(&harness.signers[1])],
+        );
+        registry.set_operators(32, harness.signers.iter().map(member).collect());
+        let mut updater = DynamicQuorumUpdater::new(registry.clone(), 0, initial.clone());
+
+        // Aggregator only knows two contributors but needs all three signatures
+        let log = EventLog::default();
+        let g1_map = initial
+            .iter()
+            .map(|key| (key.clone(), member(&harness.signers[0]).g1))
+            .collect();
+        let aggregator = Contributor::new(
+            harness.orchestrator.public_key(),
+            harness.signers[0].clone(),
+            initial,
+            Some(AggregationInput::new(3, g1_map)),
+        )
+        .unwrap()
+        .with_validator_factory(Arc::new(MockValidator))
+        .with_event_sink(Arc::new(log.clone()))
+        .with_quorum_updates(updater.subscribe());
+        let mut handles = vec![harness.spawn(aggregator, 0)];
+        for i in 1..3 {
+            handles.push(harness.spawn(harness.contributor(i, None), i));
+        }
+
+        // Cross the epoch boundary, then start a round
+        registry.set_block(32);
+        assert!(updater.poll_once().await.unwrap().is_some());
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+
+        assert_eq!(log.count("threshold_reached"), 1);
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_registry_event_burst_refreshed_once() {
+        let harness = Harness::new(3);
+        let registry = Arc::new(MockRegistry::default());
+        let initial = vec![
+            harness.signers[0].public_key(),
+            harness.signers[1].public_key(),
+        ];
+        registry.set_operators(
+            0,
+            vec![member(&harness.signers[0]), member(&harness.signers[1])],
+        );
+        let config = CoalescerConfig {
+            debounce: Duration::from_secs(2),
+            min_interval: Duration::from_secs(12),
+        };
+        let updater = DynamicQuorumUpdater::new(registry.clone(), 0, initial)
+            .with_poll_interval(Duration::from_secs(3600))
+            .with_coalescing(config);
+        let trigger = updater.refresh_trigger();
+        let mut subscriber = updater.subscribe();
+        let handle = updater.spawn();
+
+        // The first epoch poll reads the unchanged set
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(registry.reads(), 1);
+
+        // An onboarding emits a burst of registration events mid-epoch
+        registry.set_operators(5, harness.signers.iter().map(member).collect());
+        registry.set_block(5);
+        for _ in 0..30 {
+            trigger.notify();
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(registry.reads(), 1);
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        assert_eq!(registry.reads(), 2);
+        let update = subscriber.try_recv().unwrap();
+        assert_eq!(update.added, vec![harness.signers[2].public_key()]);
+        assert!(update.removed.is_empty());
+        assert!(subscriber.try_recv().is_err());
+
+        // Events right after the refresh wait for the minimum interval
+        trigger.notify();
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(registry.reads(), 2);
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        assert_eq!(registry.reads(), 3);
+        assert!(subscriber.try_recv().is_err());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_refuses_to_sign_with_stale_index() {
+        let mut harness = Harness::new(2);
+        let (updates, receiver) = broadcast::channel(1);
+        let removed = harness.contributor(0, None).with_quorum_updates(receiver);
+        let handles = [
+            harness.spawn(removed, 0),
+            harness.spawn(harness.contributor(1, None), 1),
+        ];
+
+        // Removing our own key leaves `me` pointing at another contributor
+        updates
+            .send(QuorumUpdated {
+                quorum_id: 0,
+                added: Vec::new(),
+                removed: vec![harness.signers[0].public_key()],
+                g1_keys: HashMap::new(),
+            })
+            .unwrap();
+        harness.start(1).await;
+        let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+        assert_eq!(signed.len(), 1);
+        assert!(signed.contains_key(&harness.signers[1].public_key()));
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_sender_counted_after_update_within_grace() {
+        let (reached, dropped) = add_third_after(Duration::from_secs(1), Duration::ZERO).await;
+        assert_eq!(reached, 1);
+        assert_eq!(dropped, 0);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_sender_dropped_after_grace() {
+        let (reached, dropped) =
+            add_third_after(Duration::from_millis(50), Duration::from_millis(100)).await;
+        assert_eq!(reached, 0);
+        assert_eq!(dropped, 1);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_sender_dropped_without_grace() {
+        let (reached, dropped) = add_third_after(Duration::ZERO, Duration::ZERO).await;
+        assert_eq!(reached, 0);
+        assert_eq!(dropped, 1);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_sender_warned_once() {
+        let logs = LogBuffer::default();
+        let _guard = logs.install();
+        let mut harness = Harness::new(3);
+        let aggregator = aggregator_knowing_two(&harness).with_run_config(RunConfig {
+            unknown_sender_grace: Duration::ZERO,
+            ..RunConfig::default()
+        });
+        let handles = spawn_with_unknown_third(&harness, aggregator);
+        for round in 1..=3 {
+            sign_round(&mut harness, round).await;
+        }
+        for handle in handles {
+            handle.abort();
+        }
+
+        let contents = logs.contents();
+        let sightings: Vec<&str> = contents
+            .lines()
+            .filter(|line| line.contains("message from peer not in contributor set"))
+            .collect();
+        assert!(sightings.len() >= 3, "{contents}");
+        assert!(sightings[0].contains("WARN"));
+        assert!(sightings[1..].iter().all(|line| line.contains("DEBUG")));
+        assert!(!contents.contains("contributor not found"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_sender_refreshes_committee_once_per_interval() {
+        let mut harness = Harness::new(3);
+        let registry = Arc::new(MockRegistry::default());
+        registry.set_operators(
+            0,
+            vec![member(&harness.signers[0]), member(&harness.signers[1])],
+        );
+        let known = harness.contributors()[..2].to_vec();
+        let updater = DynamicQuorumUpdater::new(registry.clone(), 0, known)
+            .with_poll_interval(Duration::from_secs(3600))
+            .with_coalescing(CoalescerConfig {
+                debounce: Duration::ZERO,
+                min_interval: Duration::ZERO,
+            });
+        let aggregator = aggregator_knowing_two(&harness)
+            .with_clock(harness.clock.clone())
+            .with_committee_refresh(updater.refresh_trigger())
+            .with_run_config(RunConfig {
+                unknown_sender_grace: Duration::ZERO,
+                ..RunConfig::default()
+            });
+        let handles = spawn_with_unknown_third(&harness, aggregator);
+        let updater = updater.spawn();
+
+        // The first epoch poll, then a single refresh however often the sender shares
+        sign_round(&mut harness, 1).await;
+        assert_eq!(registry.reads(), 2);
+        sign_round(&mut harness, 2).await;
+        sign_round(&mut harness, 3).await;
+        assert_eq!(registry.reads(), 2);
+
+        // The sender is checked again once the interval passed
+        harness.advance(UnknownPeerConfig::default().refresh_interval);
+        sign_round(&mut harness, 4).await;
+        assert_eq!(registry.reads(), 3);
+
+        updater.abort();
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::chain::{OperatorStake, StakeRetriever, StakeSnapshotCache};
+use alloy_primitives::{Address, B256, U256};
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Retriever counting reads, each taking a block to answer
+#[derive(Default)]
+struct MockRetriever {
+    reads: Mutex<HashMap<(u8, u64), usize>>,
+    failing: Mutex<bool>,
+}
+
+impl MockRetriever {
+    fn reads(&self, quorum_number: u8, reference_block: u64) -> usize {
+        let reads = self.reads.lock().unwrap();
+        reads
+            .get(&(quorum_number, reference_block))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Operator `index` with a stake growing with the reference block
+fn operator(index: u8, reference_block: u64) -> OperatorStake {
+    OperatorStake {
+        operator: Address::repeat_byte(index),
+        operator_id: B256::repeat_byte(index),
+        stake: U256::from(u64::from(index) * 100 + reference_block),
+    }
+}
+
+impl StakeRetriever for MockRetriever {
+    async fn operator_stakes(
+        &self,
+        quorum_number: u8,
+        reference_block: u64,
+    ) -> Result<Vec<OperatorStake>> {
+        *self
+            .reads
+            .lock()
+            .unwrap()
+            .entry((quorum_number, reference_block))
+            .or_default() += 1;
+        tokio::time::sleep(Duration::from_secs(12)).await;
+        if *self.failing.lock().unwrap() {
+            return Err(anyhow!("retriever unavailable"));
+        }
+        Ok((1..=3)
+            .map(|index| operator(index, reference_block))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod stake_cache_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrent_rounds_share_one_read() {
+        let retriever = Arc::new(MockRetriever::default());
+        let cache = StakeSnapshotCache::new(retriever.clone());
+
+        let (first, second, third) =
+            tokio::join!(cache.get(0, 100), cache.get(0, 100), cache.get(0, 100));
+        let (first, second, third) = (first.unwrap(), second.unwrap(), third.unwrap());
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(Arc::ptr_eq(&first, &third));
+        assert_eq!(retriever.reads(0, 100), 1);
+
+        // Other keys are read on their own
+        let (other_quorum, other_block) = tokio::join!(cache.get(1, 100), cache.get(0, 101));
+        assert_eq!(other_quorum.unwrap().quorum_number, 1);
+        assert_eq!(other_block.unwrap().reference_block, 101);
+        assert_eq!(retriever.reads(1, 100), 1);
+        assert_eq!(retriever.reads(0, 101), 1);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_least_recently_used_evicted() {
+        let retriever = Arc::new(MockRetriever::default());
+        let cache =
+            StakeSnapshotCache::with_capacity(retriever.clone(), NonZeroUsize::new(2).unwrap());
+
+        cache.get(0, 1).await.unwrap();
+        cache.get(0, 2).await.unwrap();
+        // Block 1 was used more recently than block 2
+        cache.get(0, 1).await.unwrap();
+        cache.get(0, 3).await.unwrap();
+        assert_eq!(cache.len(), 2);
+
+        cache.get(0, 1).await.unwrap();
+        assert_eq!(retriever.reads(0, 1), 1);
+        cache.get(0, 2).await.unwrap();
+        assert_eq!(retriever.reads(0, 2), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_failed_read_not_cached() {
+        let retriever = Arc::new(MockRetriever::default());
+        let cache = StakeSnapshotCache::new(retriever.clone());
+
+        *retriever.failing.lock().unwrap() = true;
+        assert!(cache.get(0, 100).await.is_err());
+        assert!(cache.is_empty());
+
+        *retriever.failing.lock().unwrap() = false;
+        assert!(cache.get(0, 100).await.is_ok());
+        assert_eq!(retriever.reads(0, 100), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_invalidate_quorum() {
+        let retriever = Arc::new(MockRetriever::default());
+        let cache = StakeSnapshotCache::new(retriever.clone());
+
+        cache.get(0, 100).await.unwrap();
+        cache.get(1, 100).await.unwrap();
+        cache.invalidate(0);
+        assert_eq!(cache.len(), 1);
+
+        cache.get(0, 100).await.unwrap();
+        cache.get(1, 100).await.unwrap();
+        assert_eq!(retriever.reads(0, 100), 2);
+        assert_eq!(retriever.reads(1, 100), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_snapshot_serves_threshold_and_non_signers() {
+        let cache = StakeSnapshotCache::new(Arc::new(MockRetriever::default()));
+        let snapshot = cache.get(0, 10).await.unwrap();
+
+        // Stakes of 110, 210 and 310 at block 10
+        assert_eq!(snapshot.total_stake(), U256::from(630));
+        let signers = [B256::repeat_byte(1), B256::repeat_byte(3)];
+        assert_eq!(snapshot.signed_stake(&signers), U256::from(420));
+        assert_eq!(snapshot.non_signers(&signers), vec![&operator(2, 10)]);
+        assert_eq!(
+            snapshot.stake_of(&B256::repeat_byte(2)),
+            Some(U256::from(210))
+        );
+        assert_eq!(snapshot.stake_of(&B256::repeat_byte(4)), None);
+    }
+}
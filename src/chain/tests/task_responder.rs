@@ -0,0 +1,142 @@
+use crate::chain::task_responder::{
+    G1Coordinates, G2Coordinates, ResponseField, TaskResponder, TaskResponderConfig, TaskResponse,
+};
+use alloy::sol_types::SolCall;
+use alloy_primitives::{Address, U256, keccak256};
+
+alloy::sol! {
+    struct Sigma {
+        uint256 X;
+        uint256 Y;
+    }
+
+    struct ApkG2 {
+        uint256[2] X;
+        uint256[2] Y;
+    }
+
+    function respondToTask(uint32 taskIndex, bytes response, Sigma sigma, ApkG2 apkG2);
+}
+
+const FRAGMENT: &str = r#"{
+    "type": "function",
+    "name": "respondToTask",
+    "stateMutability": "nonpayable",
+    "inputs": [
+        { "name": "taskIndex", "type": "uint32" },
+        { "name": "response", "type": "bytes" },
+        {
+            "name": "sigma",
+            "type": "tuple",
+            "components": [
+                { "name": "X", "type": "uint256" },
+                { "name": "Y", "type": "uint256" }
+            ]
+        },
+        {
+            "name": "apkG2",
+            "type": "tuple",
+            "components": [
+                { "name": "X", "type": "uint256[2]" },
+                { "name": "Y", "type": "uint256[2]" }
+            ]
+        }
+    ],
+    "outputs": []
+}"#;
+
+fn config(function: &str) -> TaskResponderConfig {
+    let signature = "respondToTask(uint32,bytes,(uint256,uint256),(uint256[2],uint256[2]))";
+    TaskResponderConfig {
+        contract: Address::repeat_byte(0x11),
+        function: function.to_string(),
+        selector: keccak256(signature)[..4].try_into().unwrap(),
+        params: [
+            ("taskIndex", ResponseField::Round),
+            ("response", ResponseField::TaskResponse),
+            ("sigma", ResponseField::Signature),
+            ("apkG2", ResponseField::Apk),
+        ]
+        .into_iter()
+        .map(|(name, field)| (name.to_string(), field))
+        .collect(),
+    }
+}
+
+fn response() -> TaskResponse {
+    TaskResponse {
+        round: 7,
+        response: vec![0xab, 0xcd],
+        signature: G1Coordinates {
+            x: U256::from(1),
+            y: U256::from(2),
+        },
+        apk: G2Coordinates {
+            x: [U256::from(3), U256::from(4)],
+            y: [U256::from(5), U256::from(6)],
+        },
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod task_responder_tests {
+    use super::*;
+
+    #[test]
+    fn test_calldata_matches_sample_abi() {
+        let responder = TaskResponder::new(&config(FRAGMENT)).unwrap();
+        let calldata = responder.encode_call(&response()).unwrap();
+
+        let expected = respondToTaskCall {
+            taskIndex: 7,
+            response: vec![0xab, 0xcd].into(),
+            sigma: Sigma {
+                X: U256::from(1),
+                Y: U256::from(2),
+            },
+            apkG2: ApkG2 {
+                X: [U256::from(3), U256::from(4)],
+                Y: [U256::from(5), U256::from(6)],
+            },
+        }
+        .abi_encode();
+        assert_eq!(calldata.to_vec(), expected);
+        assert_eq!(responder.selector(), respondToTaskCall::SELECTOR);
+    }
+
+    #[test]
+    fn test_human_readable_fragment() {
+        let fragment = "function respondToTask(uint32 taskIndex, bytes response, (uint256 X, uint256 Y) sigma, (uint256[2] X, uint256[2] Y) apkG2)";
+        let json = TaskResponder::new(&config(FRAGMENT)).unwrap();
+        let readable = TaskResponder::new(&config(fragment)).unwrap();
+        assert_eq!(
+            json.encode_call(&response()).unwrap(),
+            readable.encode_call(&response()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_selector_mismatch_is_rejected() {
+        let mut config = config(FRAGMENT);
+        config.selector = [0, 0, 0, 0].into();
+        assert!(TaskResponder::new(&config).is_err());
+    }
+
+    #[test]
+    fn test_unbound_parameter_is_rejected() {
+        let mut config = config(FRAGMENT);
+        config.params.remove("apkG2");
+        assert!(TaskResponder::new(&config).is_err());
+    }
+
+    #[test]
+    fn test_round_must_fit_parameter() {
+        let responder = TaskResponder::new(&config(FRAGMENT)).unwrap();
+        let response = TaskResponse {
+            round: u64::from(u32::MAX) + 1,
+            ..response()
+        };
+        assert!(responder.encode_call(&response).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::chain::{HttpSubmitter, NonceSource};
+use crate::config::{NodeConfig, TlsConfig};
+use alloy_signer_local::PrivateKeySigner;
+use rcgen::CertifiedKey;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::rustls::crypto::ring;
+use tokio_rustls::rustls::pki_types::PrivatePkcs8KeyDer;
+
+/// Nonce every endpoint answers with
+const NONCE: u64 = 5;
+
+/// Self-signed certificate for localhost
+fn certificate() -> CertifiedKey {
+    rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap()
+}
+
+/// Client settings trusting `ca`, written under a temp dir unique to `name`
+fn tls_config(name: &str, ca: &CertifiedKey, verify_peer: bool) -> TlsConfig {
+    let dir = std::env::temp_dir().join(format!("rpc-tls-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let write = |file: &str, pem: String| -> PathBuf {
+        let path = dir.join(file);
+        std::fs::write(&path, pem).unwrap();
+        path
+    };
+    let client = certificate();
+    TlsConfig {
+        cert_path: write("client.pem", client.cert.pem()),
+        key_path: write("client.key", client.key_pair.serialize_pem()),
+        ca_cert_path: write("ca.pem", ca.cert.pem()),
+        verify_peer,
+    }
+}
+
+/// Acceptor presenting `server`
+fn acceptor(server: &CertifiedKey) -> TlsAcceptor {
+    let key = PrivatePkcs8KeyDer::from(server.key_pair.serialize_der());
+    let config = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(vec![server.cert.der().clone()], key.into())
+        .unwrap();
+    TlsAcceptor::from(Arc::new(config))
+}
+
+/// Answer the JSON-RPC request on `stream` with [NONCE]
+async fn answer(mut stream: impl AsyncRead + AsyncWrite + Unpin) -> std::io::Result<()> {
+    let mut request = Vec::new();
+    let mut chunk = [0; 4096];
+    let body_start = loop {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(());
+        }
+        request.extend_from_slice(&chunk[..read]);
+        if let Some(end) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end + 4;
+        }
+    };
+    let headers = String::from_utf8_lossy(&request[..body_start]).to_lowercase();
+    let length: usize = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:"))
+        .and_then(|length| length.trim().parse().ok())
+        .unwrap_or(0);
+    while request.len() < body_start + length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(());
+        }
+        request.extend_from_slice(&chunk[..read]);
+    }
+    let call: serde_json::Value = serde_json::from_slice(&request[body_start..body_start + length])
+        .map_err(std::io::Error::other)?;
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": call["id"],
+        "result": format!("{NONCE:#x}"),
+    })
+    .to_string();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\
+         connection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Answer whatever arrives on `stream` with a plain HTTP error
+async fn refuse(mut stream: impl AsyncWrite + Unpin) -> std::io::Result<()> {
+    stream
+        .write_all(b"HTTP/1.1 400 Bad Request\r\ncontent-length: 0\r\n\r\n")
+        .await?;
+    stream.shutdown().await
+}
+
+/// Serve JSON-RPC on a local port over TLS, or plain HTTP errors without an acceptor
+async fn serve(acceptor: Option<TlsAcceptor>) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let acceptor = acceptor.clone();
+            tokio::spawn(async move {
+                let _ = match acceptor {
+                    Some(acceptor) => match acceptor.accept(stream).await {
+                        Ok(stream) => answer(stream).await,
+                        Err(err) => Err(err),
+                    },
+                    None => refuse(stream).await,
+                };
+            });
+        }
+    });
+    port
+}
+
+/// Nonce read through a submitter reaching `url` with `tls`
+async fn nonce(url: String, tls: &TlsConfig) -> anyhow::Result<u64> {
+    let submitter = HttpSubmitter::with_tls(
+        vec![url],
+        PrivateKeySigner::random(),
+        Default::default(),
+        Default::default(),
+        tls,
+    )?;
+    submitter.latest_nonce().await
+}
+
+#[cfg(test)]
+mod tls_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_https_endpoint_reached() {
+        let server = certificate();
+        let port = serve(Some(acceptor(&server))).await;
+        let tls = tls_config("trusted", &server, true);
+        let nonce = nonce(format!("https://localhost:{port}"), &tls).await;
+        assert_eq!(nonce.unwrap(), NONCE);
+    }
+
+    #[tokio::test]
+    async fn test_plain_endpoint_fails() {
+        let server = certificate();
+        let port = serve(None).await;
+        let tls = tls_config("plain", &server, true);
+        assert!(
+            nonce(format!("https://localhost:{port}"), &tls)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_untrusted_certificate() {
+        let server = certificate();
+        let port = serve(Some(acceptor(&server))).await;
+        let url = format!("https://localhost:{port}");
+
+        let other_ca = certificate();
+        let verified = tls_config("untrusted", &other_ca, true);
+        assert!(nonce(url.clone(), &verified).await.is_err());
+
+        let unverified = tls_config("unverified", &other_ca, false);
+        assert_eq!(nonce(url, &unverified).await.unwrap(), NONCE);
+    }
+
+    #[test]
+    fn test_http_url_rejected() {
+        let tls = tls_config("http", &certificate(), true);
+        let submitter = HttpSubmitter::with_tls(
+            vec!["http://localhost:8545".to_string()],
+            PrivateKeySigner::random(),
+            Default::default(),
+            Default::default(),
+            &tls,
+        );
+        assert!(submitter.is_err());
+
+        let required = NodeConfig {
+            tls_required: true,
+            ..NodeConfig::default()
+        };
+        assert!(required.check_rpc_url("http://localhost:8545").is_err());
+        assert!(required.check_rpc_url("https://rpc.example.com").is_ok());
+        assert!(
+            NodeConfig::default()
+                .check_rpc_url("http://localhost:8545")
+                .is_ok()
+        );
+    }
+}
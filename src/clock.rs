@@ -0,0 +1,123 @@
+//! Source of time, replaceable to make time-dependent behavior reproducible.
+//!
+//! Contributors read wall-clock time to check task deadlines, and monotonic time for
+//! round deadlines, timeouts and quarantines. Both are read through a [Clock] so tests
+//! can move time forward without sleeping.
+
+use futures::future::BoxFuture;
+use std::future::Future;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
+use tokio::sync::watch;
+use tokio::time::Instant;
+
+/// Source of the current time
+pub trait Clock: Send + Sync {
+    /// Wall-clock time
+    fn now(&self) -> SystemTime;
+
+    /// Monotonic time deadlines and timeouts are measured in
+    fn monotonic_now(&self) -> Instant;
+
+    /// Complete once the monotonic time reaches `deadline`
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()>;
+
+    /// Monotonic time passed since `earlier`
+    fn elapsed(&self, earlier: Instant) -> Duration {
+        self.monotonic_now().saturating_duration_since(earlier)
+    }
+}
+
+/// Output of `future`, or `None` if it did not complete within `duration` of `clock`
+pub async fn timeout<F: Future>(
+    clock: &dyn Clock,
+    duration: Duration,
+    future: F,
+) -> Option<F::Output> {
+    let deadline = clock.monotonic_now() + duration;
+    tokio::select! {
+        output = future => Some(output),
+        () = clock.sleep_until(deadline) => None,
+    }
+}
+
+/// Clock reading the system time and the tokio clock
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn monotonic_now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep_until(deadline))
+    }
+}
+
+/// Clock moved forward by hand
+///
+/// Its wall-clock time only moves when set or advanced. Its monotonic time follows the
+/// tokio clock, ahead by every [MockClock::advance], so deadlines can be passed without
+/// sleeping.
+#[derive(Debug)]
+pub struct MockClock {
+    wall: Mutex<SystemTime>,
+    advanced: watch::Sender<Duration>,
+}
+
+impl MockClock {
+    /// Clock stopped at `now` until moved
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            wall: Mutex::new(now),
+            advanced: watch::Sender::new(Duration::ZERO),
+        }
+    }
+
+    /// Set the wall-clock time, the monotonic time is unchanged
+    pub fn set(&self, now: SystemTime) {
+        *self.wall.lock().unwrap_or_else(PoisonError::into_inner) = now;
+    }
+
+    /// Move both the wall-clock and monotonic time forward, waking expired sleeps
+    pub fn advance(&self, by: Duration) {
+        *self.wall.lock().unwrap_or_else(PoisonError::into_inner) += by;
+        self.advanced.send_modify(|advanced| *advanced += by);
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        *self.wall.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn monotonic_now(&self) -> Instant {
+        Instant::now() + *self.advanced.borrow()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()> {
+        let mut advanced = self.advanced.subscribe();
+        Box::pin(async move {
+            loop {
+                // Deadline on the tokio clock, earlier by what we advanced
+                let Some(deadline) = deadline.checked_sub(*advanced.borrow_and_update()) else {
+                    return;
+                };
+                tokio::select! {
+                    () = tokio::time::sleep_until(deadline) => return,
+                    changed = advanced.changed() => {
+                        if changed.is_err() {
+                            tokio::time::sleep_until(deadline).await;
+                            return;
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,8 @@
+//! Collections shared by the contributors.
+
+#[cfg(test)]
+mod tests;
+
+pub mod task_queue;
+
+pub use task_queue::TaskPriorityQueue;
@@ -0,0 +1,165 @@
+//! Queue serving the most urgent task first.
+//!
+//! Tasks carry a `priority` where lower values are more urgent; tasks of equal
+//! priority are served in arrival order. A task passed over for
+//! [TaskPriorityQueue::max_wait_rounds] dequeues is promoted ahead of every task
+//! not promoted yet, so a steady stream of urgent tasks cannot starve the others.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// Dequeues a task may wait before it is promoted, by default
+pub const DEFAULT_MAX_WAIT_ROUNDS: u64 = 8;
+
+/// Priority of tasks that do not set one
+pub const DEFAULT_PRIORITY: u8 = u8::MAX / 2;
+
+struct Entry<T> {
+    priority: u8,
+    /// Arrival order, breaking ties between equal priorities
+    seq: u64,
+    /// Dequeues served when the task was queued
+    queued_at: u64,
+    promoted: bool,
+    task: T,
+}
+
+impl<T> Entry<T> {
+    fn key(&self) -> (bool, Reverse<u8>, Reverse<u64>) {
+        (self.promoted, Reverse(self.priority), Reverse(self.seq))
+    }
+}
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<T> Eq for Entry<T> {}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The most urgent entry is the greatest
+impl<T> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// Priority queue of tasks, lower priority values first
+pub struct TaskPriorityQueue<T> {
+    heap: BinaryHeap<Entry<T>>,
+    max_wait_rounds: u64,
+    next_seq: u64,
+    /// Tasks dequeued so far
+    served: u64,
+}
+
+impl<T> Default for TaskPriorityQueue<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_WAIT_ROUNDS)
+    }
+}
+
+impl<T> TaskPriorityQueue<T> {
+    /// Queue promoting tasks passed over for `max_wait_rounds` dequeues
+    pub fn new(max_wait_rounds: u64) -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            max_wait_rounds,
+            next_seq: 0,
+            served: 0,
+        }
+    }
+
+    /// Dequeues a task may be passed over for before it is promoted
+    pub fn max_wait_rounds(&self) -> u64 {
+        self.max_wait_rounds
+    }
+
+    /// Queue `task` with `priority`, lower values are served first
+    pub fn push(&mut self, task: T, priority: u8) {
+        self.heap.push(Entry {
+            priority,
+            seq: self.next_seq,
+            queued_at: self.served,
+            promoted: false,
+            task,
+        });
+        self.next_seq += 1;
+    }
+
+    /// Most urgent task, after promoting the tasks that waited too long
+    pub fn pop(&mut self) -> Option<T> {
+        self.promote_overdue();
+        let entry = self.heap.pop()?;
+        self.served += 1;
+        Some(entry.task)
+    }
+
+    /// Remove the first task, in service order, matching `predicate`
+    ///
+    /// Counts as a dequeue, e.g. when a task must be served out of order.
+    pub fn take(&mut self, mut predicate: impl FnMut(&T) -> bool) -> Option<T> {
+        if !self.heap.iter().any(|entry| predicate(&entry.task)) {
+            return None;
+        }
+        let mut entries = std::mem::take(&mut self.heap).into_sorted_vec();
+        // Sorted from least to most urgent
+        let Some(index) = entries.iter().rposition(|entry| predicate(&entry.task)) else {
+            self.heap = entries.into();
+            return None;
+        };
+        let entry = entries.remove(index);
+        self.heap = entries.into();
+        self.served += 1;
+        Some(entry.task)
+    }
+
+    /// Tasks in service order, without promoting any
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut entries: Vec<&Entry<T>> = self.heap.iter().collect();
+        entries.sort_by(|a, b| b.cmp(a));
+        entries.into_iter().map(|entry| &entry.task)
+    }
+
+    /// Number of queued tasks
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Whether no task is queued
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Drop every queued task
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+
+    /// Promote the tasks passed over for `max_wait_rounds` dequeues
+    ///
+    /// The heap is rebuilt when a task is promoted, linear in the queued tasks.
+    fn promote_overdue(&mut self) {
+        let served = self.served;
+        let max_wait_rounds = self.max_wait_rounds;
+        let overdue =
+            |entry: &Entry<T>| !entry.promoted && served - entry.queued_at >= max_wait_rounds;
+        if !self.heap.iter().any(overdue) {
+            return;
+        }
+        let mut entries = std::mem::take(&mut self.heap).into_vec();
+        for entry in &mut entries {
+            if overdue(&*entry) {
+                entry.promoted = true;
+            }
+        }
+        self.heap = entries.into();
+    }
+}
@@ -0,0 +1 @@
+mod task_queue;
@@ -0,0 +1,115 @@
+use crate::collections::TaskPriorityQueue;
+use crate::contributor::OutboundRouter;
+use crate::contributor::tests::harness::Harness;
+use crate::handlers::{PriorityReader, RunConfig};
+use commonware_avs_router::wire::aggregation::Payload;
+use commonware_cryptography::Signer;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Pop every queued task in service order
+fn drain(queue: &mut TaskPriorityQueue<&'static str>) -> Vec<&'static str> {
+    std::iter::from_fn(|| queue.pop()).collect()
+}
+
+/// Rounds signed by contributors, in the order the orchestrator received them
+async fn signed_in_order(harness: &mut Harness, timeout: Duration) -> Vec<u64> {
+    let mut rounds = Vec::new();
+    while let Some((_, message)) = harness.orchestrator_receiver.next_message(timeout).await {
+        if let Some(Payload::Signature(_)) = message.payload {
+            rounds.push(message.round);
+        }
+    }
+    rounds
+}
+
+#[cfg(test)]
+mod task_queue_tests {
+    use super::*;
+
+    #[test]
+    fn test_lower_priority_value_served_first() {
+        let mut queue = TaskPriorityQueue::default();
+        queue.push("low", 200);
+        queue.push("urgent", 0);
+        queue.push("normal", 100);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            ["urgent", "normal", "low"]
+        );
+        assert_eq!(drain(&mut queue), ["urgent", "normal", "low"]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_equal_priorities_served_in_arrival_order() {
+        let mut queue = TaskPriorityQueue::default();
+        for task in ["first", "second", "third"] {
+            queue.push(task, 5);
+        }
+        assert_eq!(drain(&mut queue), ["first", "second", "third"]);
+    }
+
+    #[test]
+    fn test_waiting_task_promoted_after_max_wait_rounds() {
+        let mut queue = TaskPriorityQueue::new(2);
+        queue.push("background", 200);
+        queue.push("urgent-1", 0);
+        assert_eq!(queue.pop(), Some("urgent-1"));
+        queue.push("urgent-2", 0);
+        assert_eq!(queue.pop(), Some("urgent-2"));
+
+        // Passed over twice, the background task goes ahead of newer urgent ones
+        queue.push("urgent-3", 0);
+        assert_eq!(drain(&mut queue), ["background", "urgent-3"]);
+    }
+
+    #[test]
+    fn test_steady_urgent_tasks_do_not_starve() {
+        let mut queue = TaskPriorityQueue::new(3);
+        queue.push("background", u8::MAX);
+        let mut served = Vec::new();
+        for _ in 0..5 {
+            queue.push("urgent", 0);
+            served.push(queue.pop().unwrap());
+        }
+        assert_eq!(served[3], "background");
+    }
+
+    #[test]
+    fn test_take_serves_out_of_order() {
+        let mut queue = TaskPriorityQueue::default();
+        queue.push("a", 1);
+        queue.push("b", 2);
+        queue.push("c", 3);
+        assert_eq!(queue.take(|task| *task == "b"), Some("b"));
+        assert_eq!(queue.take(|task| *task == "b"), None);
+        assert_eq!(drain(&mut queue), ["a", "c"]);
+
+        queue.push("d", 1);
+        queue.clear();
+        assert!(queue.pop().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_urgent_start_signed_first() {
+        let mut harness = Harness::new(1);
+        let urgent: PriorityReader = Arc::new(|message| if message.round == 3 { 0 } else { 100 });
+        let contributor = harness.contributor(0, None).with_run_config(RunConfig {
+            task_priority: Some((urgent, 8)),
+            ..RunConfig::default()
+        });
+
+        // Queue every Start before the contributor runs
+        let (sender, receiver) = harness.network.register(harness.signers[0].public_key());
+        for round in 1..=3 {
+            harness.start(round).await;
+        }
+        let handle = tokio::spawn(contributor.run(OutboundRouter::single(sender), receiver));
+
+        let rounds = signed_in_order(&mut harness, Duration::from_millis(200)).await;
+        assert_eq!(rounds, [3, 1, 2]);
+        handle.abort();
+    }
+}
@@ -0,0 +1,137 @@
+//! Settings of a node read from the environment.
+
+use crate::chain::ClockSkewConfig;
+use anyhow::{Context, Result, bail};
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Settings of a node beyond its keys and peers
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NodeConfig {
+    /// Port of the health probes, not served when unset
+    pub health_port: Option<u16>,
+    /// Refuse RPC endpoints not served over TLS
+    pub tls_required: bool,
+    /// Client certificate and trusted CA of the RPC endpoints, if any
+    pub tls: Option<TlsConfig>,
+    /// Thresholds of the clock skew checks
+    pub clock_skew: ClockSkewConfig,
+    /// HTTP server whose `Date` header the clock is also compared to, if any
+    pub time_url: Option<String>,
+}
+
+impl NodeConfig {
+    /// Read the settings from `HEALTH_PORT`, `RPC_TLS_REQUIRED`, the `RPC_TLS_*` paths of
+    /// [TlsConfig::from_env], `CLOCK_SKEW_WARN_SECS`, `CLOCK_SKEW_MAX_SECS` and
+    /// `CLOCK_TIME_URL`
+    pub fn from_env() -> Result<Self> {
+        let health_port = env::var("HEALTH_PORT")
+            .ok()
+            .map(|port| port.parse().context("HEALTH_PORT is not a port"))
+            .transpose()?;
+        let tls_required = env::var("RPC_TLS_REQUIRED")
+            .ok()
+            .map(|required| required.parse().context("RPC_TLS_REQUIRED is not a bool"))
+            .transpose()?
+            .unwrap_or(false);
+        let secs = |name: &str| -> Result<Option<Duration>> {
+            env::var(name)
+                .ok()
+                .map(|secs| secs.parse().map(Duration::from_secs))
+                .transpose()
+                .with_context(|| format!("{name} is not a number of seconds"))
+        };
+        let defaults = ClockSkewConfig::default();
+        let clock_skew = ClockSkewConfig {
+            soft_threshold: secs("CLOCK_SKEW_WARN_SECS")?.unwrap_or(defaults.soft_threshold),
+            hard_threshold: secs("CLOCK_SKEW_MAX_SECS")?.unwrap_or(defaults.hard_threshold),
+            ..defaults
+        };
+        if clock_skew.soft_threshold > clock_skew.hard_threshold {
+            bail!("CLOCK_SKEW_WARN_SECS is above CLOCK_SKEW_MAX_SECS");
+        }
+        Ok(Self {
+            health_port,
+            tls_required,
+            tls: TlsConfig::from_env()?,
+            clock_skew,
+            time_url: env::var("CLOCK_TIME_URL").ok(),
+        })
+    }
+
+    /// Fail if TLS is required and `url` is not an `https://` URL
+    pub fn check_rpc_url(&self, url: &str) -> Result<()> {
+        if self.tls_required {
+            require_https(url)?;
+        }
+        Ok(())
+    }
+}
+
+/// TLS settings of the connections to the RPC endpoints
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// PEM certificate presented to the endpoints
+    pub cert_path: PathBuf,
+    /// PEM private key of the certificate
+    pub key_path: PathBuf,
+    /// PEM certificate of the CA the endpoints' certificates are checked against
+    pub ca_cert_path: PathBuf,
+    /// Check the endpoints' certificates, only disabled against test endpoints
+    pub verify_peer: bool,
+}
+
+impl TlsConfig {
+    /// Read the settings from `RPC_TLS_CERT`, `RPC_TLS_KEY`, `RPC_TLS_CA` and
+    /// `RPC_TLS_VERIFY_PEER`, `None` if no path is set
+    pub fn from_env() -> Result<Option<Self>> {
+        let paths = ["RPC_TLS_CERT", "RPC_TLS_KEY", "RPC_TLS_CA"].map(|name| env::var(name).ok());
+        let [Some(cert), Some(key), Some(ca)] = paths.clone() else {
+            if paths.iter().any(Option::is_some) {
+                bail!("RPC_TLS_CERT, RPC_TLS_KEY and RPC_TLS_CA must be set together");
+            }
+            return Ok(None);
+        };
+        let verify_peer = env::var("RPC_TLS_VERIFY_PEER")
+            .ok()
+            .map(|verify| verify.parse().context("RPC_TLS_VERIFY_PEER is not a bool"))
+            .transpose()?
+            .unwrap_or(true);
+        Ok(Some(Self {
+            cert_path: cert.into(),
+            key_path: key.into(),
+            ca_cert_path: ca.into(),
+            verify_peer,
+        }))
+    }
+
+    /// HTTPS-only client presenting the certificate and trusting only the CA
+    #[cfg(feature = "chain")]
+    pub fn client(&self) -> Result<reqwest::Client> {
+        let read = |path: &PathBuf| {
+            std::fs::read(path).with_context(|| format!("failed to read {}", path.display()))
+        };
+        let ca = reqwest::Certificate::from_pem(&read(&self.ca_cert_path)?)
+            .context("invalid CA certificate")?;
+        let mut identity = read(&self.cert_path)?;
+        identity.extend(read(&self.key_path)?);
+        let identity = reqwest::Identity::from_pem(&identity).context("invalid certificate")?;
+        Ok(reqwest::Client::builder()
+            .use_rustls_tls()
+            .https_only(true)
+            .tls_built_in_root_certs(false)
+            .add_root_certificate(ca)
+            .identity(identity)
+            .danger_accept_invalid_certs(!self.verify_peer)
+            .build()?)
+    }
+}
+
+/// Fail unless `url` is an `https://` URL
+pub fn require_https(url: &str) -> Result<()> {
+    if !url.to_ascii_lowercase().starts_with("https://") {
+        bail!("RPC endpoint is not an https:// URL");
+    }
+    Ok(())
+}
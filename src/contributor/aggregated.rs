@@ -0,0 +1,77 @@
+//! Aggregates returned to the orchestrator that started their round.
+//!
+//! In a star topology the orchestrator does not aggregate itself: the aggregating
+//! contributor sends it an [Aggregated] frame once a round reaches its threshold, for
+//! the orchestrator to submit. The frame carries the same fields as a
+//! [FinalAggregate](crate::contributor::final_aggregate::FinalAggregate) under its own
+//! prefix, so an aggregate reported by a contributor is never mistaken for one the
+//! orchestrator settled on.
+
+use crate::contributor::final_aggregate::MAX_SIGNATURE_LEN;
+use crate::contributor::types::ParticipationBitmap;
+use bytes::{Buf, BufMut};
+use commonware_codec::{EncodeSize, Error, Read, ReadExt, Write};
+
+/// Prefix distinguishing reported aggregates from aggregation frames
+pub const AGGREGATED_MAGIC: [u8; 4] = *b"AGGD";
+
+/// Aggregate signature of a round with the contributors whose shares it combines
+#[derive(Clone, Debug, PartialEq)]
+pub struct Aggregated {
+    /// Round the aggregate is for
+    pub round: u64,
+    /// Encoded aggregate signature
+    pub signature: Vec<u8>,
+    /// Contributors whose shares were aggregated
+    pub participants: ParticipationBitmap,
+}
+
+impl Aggregated {
+    /// Whether a raw frame is a reported aggregate
+    pub fn is_aggregated(frame: &[u8]) -> bool {
+        frame.starts_with(&AGGREGATED_MAGIC)
+    }
+}
+
+impl Write for Aggregated {
+    fn write(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&AGGREGATED_MAGIC);
+        self.round.write(buf);
+        buf.put_slice(&self.participants.to_bytes());
+        (self.signature.len() as u16).write(buf);
+        buf.put_slice(&self.signature);
+    }
+}
+
+impl EncodeSize for Aggregated {
+    fn encode_size(&self) -> usize {
+        AGGREGATED_MAGIC.len() + 8 + 32 + 2 + self.signature.len()
+    }
+}
+
+impl Read for Aggregated {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _: &()) -> Result<Self, Error> {
+        let magic = <[u8; 4]>::read(buf)?;
+        if magic != AGGREGATED_MAGIC {
+            return Err(Error::Invalid("Aggregated", "missing aggregated prefix"));
+        }
+        let round = u64::read(buf)?;
+        let participants = ParticipationBitmap::from_bytes(<[u8; 32]>::read(buf)?);
+        let len = u16::read(buf)? as usize;
+        if len > MAX_SIGNATURE_LEN {
+            return Err(Error::InvalidLength(len));
+        }
+        if buf.remaining() < len {
+            return Err(Error::EndOfBuffer);
+        }
+        let mut signature = vec![0; len];
+        buf.copy_to_slice(&mut signature);
+        Ok(Self {
+            round,
+            signature,
+            participants,
+        })
+    }
+}
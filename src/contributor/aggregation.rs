@@ -0,0 +1,118 @@
+//! Aggregation of the signatures collected for a round.
+//!
+//! The aggregate of a round is canonical: it is fully determined by the set of verified
+//! shares it combines, whatever order they arrived in. Shares are kept by contributor
+//! index and combined in index order, participants are listed in ascending order, and
+//! [canonical_result] recomputes the result a set of shares must produce. Which shares
+//! a node has collected when it emits still depends on arrival, the result for them
+//! does not.
+
+use crate::contributor::sink::AggregationResult;
+use bn254::{Signature as Sig, aggregate_signatures};
+use std::collections::BTreeMap;
+
+/// Outcome of attempting to aggregate the signatures collected for a round
+#[derive(Debug, PartialEq)]
+pub enum AggregationOutcome {
+    /// Signatures were combined; `participants` lists contributor indices in ascending order
+    Aggregated {
+        /// Aggregate of the collected signatures
+        signature: Sig,
+        /// Contributors whose signatures were aggregated
+        participants: Vec<usize>,
+    },
+    /// No signatures were collected yet, nothing to aggregate
+    Empty,
+    /// Aggregation failed and the listed contributors' signatures were removed from the round
+    Evicted(Vec<usize>),
+}
+
+/// Aggregate the signatures of a round, evicting malformed signatures on failure.
+pub fn aggregate_round(signatures: &mut BTreeMap<usize, Sig>) -> AggregationOutcome {
+    aggregate_round_with(signatures, |sigs| aggregate_signatures(sigs))
+}
+
+/// Same as [aggregate_round] but with an injectable aggregation function.
+///
+/// When `aggregate` returns `None` for the full set, the set is bisected until the
+/// signatures that cannot be combined are isolated. Those entries are removed from
+/// `signatures` so the next attempt does not retry the same bad set. If no single
+/// signature can be isolated, every signature of the round is evicted.
+pub fn aggregate_round_with<F>(
+    signatures: &mut BTreeMap<usize, Sig>,
+    aggregate: F,
+) -> AggregationOutcome
+where
+    F: Fn(&[Sig]) -> Option<Sig>,
+{
+    if signatures.is_empty() {
+        return AggregationOutcome::Empty;
+    }
+
+    // Ordered by contributor index so the aggregate is independent of arrival order
+    let shares: Vec<(usize, Sig)> = signatures
+        .iter()
+        .map(|(index, signature)| (*index, signature.clone()))
+        .collect();
+
+    let sigs: Vec<Sig> = shares.iter().map(|(_, sig)| sig.clone()).collect();
+    if let Some(signature) = aggregate(&sigs) {
+        return AggregationOutcome::Aggregated {
+            signature,
+            participants: shares.iter().map(|(index, _)| *index).collect(),
+        };
+    }
+
+    // Find the offending signatures
+    let mut malformed = Vec::new();
+    bisect(&shares, &aggregate, &mut malformed);
+    if malformed.is_empty() {
+        malformed = shares.iter().map(|(index, _)| *index).collect();
+    }
+    for index in &malformed {
+        signatures.remove(index);
+    }
+    AggregationOutcome::Evicted(malformed)
+}
+
+fn bisect<F>(shares: &[(usize, Sig)], aggregate: &F, malformed: &mut Vec<usize>)
+where
+    F: Fn(&[Sig]) -> Option<Sig>,
+{
+    if let [(index, _)] = shares {
+        malformed.push(*index);
+        return;
+    }
+    let (left, right) = shares.split_at(shares.len() / 2);
+    for half in [left, right] {
+        let sigs: Vec<Sig> = half.iter().map(|(_, sig)| sig.clone()).collect();
+        if aggregate(&sigs).is_none() {
+            bisect(half, aggregate, malformed);
+        }
+    }
+}
+
+/// Result the `signatures` of `round` over `payload` aggregate to, computed from the
+/// shares alone
+///
+/// Returns `None` if there are no signatures or they cannot be combined. `signatures`
+/// is left untouched, nothing is evicted.
+pub fn canonical_result(
+    round: u64,
+    payload: [u8; 32],
+    signatures: &BTreeMap<usize, Sig>,
+) -> Option<AggregationResult> {
+    let mut signatures = signatures.clone();
+    match aggregate_round(&mut signatures) {
+        AggregationOutcome::Aggregated {
+            signature,
+            participants,
+        } => Some(AggregationResult {
+            round,
+            payload,
+            signature,
+            signers: participants.into_iter().collect(),
+        }),
+        AggregationOutcome::Empty | AggregationOutcome::Evicted(_) => None,
+    }
+}
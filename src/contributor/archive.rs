@@ -0,0 +1,569 @@
+//! Archival of the aggregates a [FileSink] wrote into compressed bundles.
+//!
+//! Audits can reach months back, but the `round-{n}.json` files of a [FileSink]
+//! are not meant to pile up forever. A [RoundArchive] moves the files older than a
+//! cutoff into gzipped JSONL bundles of [ArchiveConfig::rounds_per_bundle] rounds
+//! under `{dir}/archive/`, listed in `archive/manifest.json` with the keccak256 of
+//! their bytes. A bundle is read back and compared to the files it holds before
+//! they are deleted, and checked against its checksum on every later read, so a
+//! corrupted bundle fails the read instead of silently losing rounds.
+//!
+//! [FileSink]: crate::contributor::sink::FileSink
+
+use crate::clock::{Clock, SystemClock};
+use crate::contributor::sink::AggregationResult;
+use alloy_primitives::{hex, keccak256};
+use anyhow::{Context, Result, anyhow, bail};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+/// Directory of the bundles, under the directory of the round files
+pub const ARCHIVE_DIR: &str = "archive";
+
+/// Manifest listing the bundles, under [ARCHIVE_DIR]
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Configuration of [RoundArchive]
+#[derive(Clone, Debug)]
+pub struct ArchiveConfig {
+    /// Age of the round files [RoundArchive::archive_expired] moves into bundles
+    pub retention: Duration,
+    /// Rounds per bundle, the last bundle of a run may hold fewer
+    pub rounds_per_bundle: usize,
+}
+
+impl Default for ArchiveConfig {
+    fn default() -> Self {
+        Self {
+            retention: Duration::from_secs(30 * SECONDS_PER_DAY),
+            rounds_per_bundle: 1000,
+        }
+    }
+}
+
+/// Bundle listed in the manifest
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleEntry {
+    /// File name under the archive directory
+    pub file: String,
+    /// Lowest round in the bundle
+    pub first_round: u64,
+    /// Highest round in the bundle
+    pub last_round: u64,
+    /// Number of rounds in the bundle
+    pub rounds: usize,
+    /// Hex keccak256 of the compressed bundle
+    pub checksum: String,
+    /// Unix time in seconds when the bundle was written
+    pub archived_at: u64,
+}
+
+/// Bundles of an archive, oldest first
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    /// Bundles in the order they were written
+    pub bundles: Vec<BundleEntry>,
+}
+
+/// Archive of the round files written to `dir`
+#[derive(Clone)]
+pub struct RoundArchive {
+    dir: PathBuf,
+    config: ArchiveConfig,
+    clock: Arc<dyn Clock>,
+}
+
+impl RoundArchive {
+    /// Archive of the round files in `dir`
+    pub fn new(dir: impl Into<PathBuf>, config: ArchiveConfig) -> Self {
+        Self {
+            dir: dir.into(),
+            config,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Read the retention cutoff, bundle times and run interval from `clock`
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Directory the bundles and the manifest are written to
+    pub fn archive_dir(&self) -> PathBuf {
+        self.dir.join(ARCHIVE_DIR)
+    }
+
+    /// Bundles archived so far, none if nothing was archived yet
+    pub fn manifest(&self) -> Result<ArchiveManifest> {
+        let path = self.archive_dir().join(MANIFEST_FILE);
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .with_context(|| format!("invalid manifest {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                Ok(ArchiveManifest::default())
+            }
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    /// Move the round files last written before `before` into bundles
+    ///
+    /// Each bundle is listed in the manifest before the files it holds are deleted,
+    /// so an interrupted run leaves rounds duplicated, never lost.
+    pub fn archive(&self, before: SystemTime) -> Result<Vec<BundleEntry>> {
+        let expired: Vec<(u64, PathBuf)> = self
+            .round_files()?
+            .into_iter()
+            .filter(|(_, (_, modified))| *modified < before)
+            .map(|(round, (path, _))| (round, path))
+            .collect();
+        if expired.is_empty() {
+            return Ok(Vec::new());
+        }
+        let archive_dir = self.archive_dir();
+        std::fs::create_dir_all(&archive_dir)
+            .with_context(|| format!("failed to create {}", archive_dir.display()))?;
+
+        let mut manifest = self.manifest()?;
+        let mut archived = Vec::new();
+        for chunk in expired.chunks(self.config.rounds_per_bundle.max(1)) {
+            let results = chunk
+                .iter()
+                .map(|(_, path)| read_round(path))
+                .collect::<Result<Vec<_>>>()?;
+            let entry = self.write_bundle(&results)?;
+            let read_back = self.read_bundle(&entry).and_then(|read_back| {
+                (read_back == results)
+                    .then_some(())
+                    .ok_or_else(|| anyhow!("rounds differ"))
+            });
+            if let Err(err) = read_back {
+                let _ = std::fs::remove_file(archive_dir.join(&entry.file));
+                return Err(err.context(format!("bundle {} does not round-trip", entry.file)));
+            }
+            manifest.bundles.push(entry.clone());
+            self.write_manifest(&manifest)?;
+            for (_, path) in chunk {
+                std::fs::remove_file(path)
+                    .with_context(|| format!("failed to remove {}", path.display()))?;
+            }
+            info!(
+                file = %entry.file,
+                first_round = entry.first_round,
+                last_round = entry.last_round,
+                "archived rounds"
+            );
+            archived.push(entry);
+        }
+        Ok(archived)
+    }
+
+    /// Move the round files older than the retention into bundles
+    pub fn archive_expired(&self) -> Result<Vec<BundleEntry>> {
+        let before = self
+            .clock
+            .now()
+            .checked_sub(self.config.retention)
+            .unwrap_or(UNIX_EPOCH);
+        self.archive(before)
+    }
+
+    /// Aggregates of a bundle, failing if it does not match its checksum
+    #[allow(
+        clippy::disallowed_methods,
+        reason = "hex encoded for storage, not logged"
+    )]
+    pub fn read_bundle(&self, entry: &BundleEntry) -> Result<Vec<AggregationResult>> {
+        let path = self.archive_dir().join(&entry.file);
+        let bytes =
+            std::fs::read(&path).with_context(|| format!("failed to read {}", path.display()))?;
+        if hex::encode(keccak256(&bytes)) != entry.checksum {
+            bail!("bundle {} does not match its checksum", entry.file);
+        }
+        let mut contents = String::new();
+        GzDecoder::new(&bytes[..])
+            .read_to_string(&mut contents)
+            .with_context(|| format!("failed to decompress {}", entry.file))?;
+        let results = contents
+            .lines()
+            .map(|line| AggregationResult::from_record(serde_json::from_str(line)?))
+            .collect::<Result<Vec<_>>>()
+            .with_context(|| format!("invalid aggregate in {}", entry.file))?;
+        if results.len() != entry.rounds {
+            bail!(
+                "bundle {} holds {} rounds instead of {}",
+                entry.file,
+                results.len(),
+                entry.rounds
+            );
+        }
+        Ok(results)
+    }
+
+    /// Aggregates by round, from the round files and the bundles if `include_archived`
+    ///
+    /// A round archived more than once is read from the latest bundle, and a round
+    /// file takes precedence over the bundles.
+    pub fn rounds(&self, include_archived: bool) -> Result<Vec<AggregationResult>> {
+        let mut rounds = BTreeMap::new();
+        if include_archived {
+            for entry in self.manifest()?.bundles {
+                for result in self.read_bundle(&entry)? {
+                    rounds.insert(result.round, result);
+                }
+            }
+        }
+        for (round, (path, _)) in self.round_files()? {
+            rounds.insert(round, read_round(&path)?);
+        }
+        Ok(rounds.into_values().collect())
+    }
+
+    /// Archive the expired round files every `interval`, until dropped
+    pub async fn run(self, interval: Duration) {
+        let mut next = self.clock.monotonic_now();
+        loop {
+            self.clock.sleep_until(next).await;
+            if let Err(err) = self.archive_expired() {
+                warn!(?err, "failed to archive rounds");
+            }
+            next += interval;
+        }
+    }
+
+    /// Round files by round, with the time they were last written
+    fn round_files(&self) -> Result<BTreeMap<u64, (PathBuf, SystemTime)>> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(BTreeMap::new());
+            }
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to list {}", self.dir.display()));
+            }
+        };
+        let mut files = BTreeMap::new();
+        for entry in entries {
+            let entry = entry.with_context(|| format!("failed to list {}", self.dir.display()))?;
+            let name = entry.file_name();
+            let Some(round) = name
+                .to_str()
+                .and_then(|name| name.strip_prefix("round-"))
+                .and_then(|name| name.strip_suffix(".json"))
+                .and_then(|round| round.parse().ok())
+            else {
+                continue;
+            };
+            let modified = entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .with_context(|| format!("failed to stat {}", entry.path().display()))?;
+            files.insert(round, (entry.path(), modified));
+        }
+        Ok(files)
+    }
+
+    /// Compress `results` into a new bundle, not yet listed in the manifest
+    #[allow(
+        clippy::disallowed_methods,
+        reason = "hex encoded for storage, not logged"
+    )]
+    fn write_bundle(&self, results: &[AggregationResult]) -> Result<BundleEntry> {
+        let (Some(first), Some(last)) = (results.first(), results.last()) else {
+            bail!("no rounds to bundle");
+        };
+        let archived_at = unix_seconds(self.clock.now());
+        let file = format!(
+            "rounds-{}-{}-{archived_at}.jsonl.gz",
+            first.round, last.round
+        );
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        for result in results {
+            serde_json::to_writer(&mut encoder, &result.to_record())?;
+            encoder.write_all(b"\n")?;
+        }
+        let bytes = encoder.finish()?;
+        write_atomically(&self.archive_dir().join(&file), &bytes)?;
+        Ok(BundleEntry {
+            file,
+            first_round: first.round,
+            last_round: last.round,
+            rounds: results.len(),
+            checksum: hex::encode(keccak256(&bytes)),
+            archived_at,
+        })
+    }
+
+    fn write_manifest(&self, manifest: &ArchiveManifest) -> Result<()> {
+        let mut contents = serde_json::to_string_pretty(manifest)?;
+        contents.push('\n');
+        write_atomically(&self.archive_dir().join(MANIFEST_FILE), contents.as_bytes())
+    }
+}
+
+fn read_round(path: &Path) -> Result<AggregationResult> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    AggregationResult::from_json(&contents)
+        .with_context(|| format!("invalid aggregate {}", path.display()))
+}
+
+/// Write `contents` to a temporary file renamed to `path`, so readers never see a
+/// partial file
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+    let mut file = std::fs::File::create(&temp)
+        .with_context(|| format!("failed to create {}", temp.display()))?;
+    file.write_all(contents)
+        .and_then(|()| file.sync_all())
+        .with_context(|| format!("failed to write {}", temp.display()))?;
+    std::fs::rename(&temp, path)
+        .with_context(|| format!("failed to move {} into place", path.display()))
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Midnight UTC of a `YYYY-MM-DD` date
+pub fn parse_date(date: &str) -> Result<SystemTime> {
+    let invalid = || anyhow!("{date} is not a YYYY-MM-DD date");
+    let parts: Vec<&str> = date.split('-').collect();
+    let [year, month, day] = parts[..] else {
+        return Err(invalid());
+    };
+    let year: i64 = year.parse().map_err(|_| invalid())?;
+    let month: u32 = month.parse().map_err(|_| invalid())?;
+    let day: u32 = day.parse().map_err(|_| invalid())?;
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return Err(invalid()),
+    };
+    if day == 0 || day > days_in_month {
+        return Err(invalid());
+    }
+
+    // Days since the epoch of the proleptic Gregorian date, in eras of 400 years
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    let days = u64::try_from(days).map_err(|_| anyhow!("{date} is before 1970"))?;
+    Ok(UNIX_EPOCH + Duration::from_secs(days * SECONDS_PER_DAY))
+}
+
+#[cfg(test)]
+#[allow(clippy::disallowed_methods)]
+mod tests {
+    use super::{ArchiveConfig, RoundArchive, parse_date};
+    use crate::clock::{Clock, MockClock};
+    use crate::contributor::sink::{AggregationResult, AggregationSink, FileSink};
+    use crate::contributor::tests::harness::{Harness, digest_of, start_message};
+    use bn254::{Signature as Bn254Signature, aggregate_signatures};
+    use commonware_cryptography::Signer;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    /// Fresh directory under the system temp dir, unique to `name` and this process
+    fn archive_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("archive-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Aggregate of every harness signer for the Start of `round`
+    fn result(harness: &Harness, round: u64) -> AggregationResult {
+        let payload = digest_of(&start_message(round));
+        let signatures: Vec<Bn254Signature> = harness
+            .signers
+            .iter()
+            .map(|signer| signer.sign(None, &payload))
+            .collect();
+        AggregationResult {
+            round,
+            payload,
+            signature: aggregate_signatures(&signatures).unwrap(),
+            signers: (0..harness.signers.len()).collect(),
+        }
+    }
+
+    /// Write the aggregates of `rounds` to `dir`, returning them in order
+    async fn write_rounds(
+        dir: &Path,
+        rounds: impl IntoIterator<Item = u64>,
+    ) -> Vec<AggregationResult> {
+        let harness = Harness::new(2);
+        let sink = FileSink::new(dir).unwrap();
+        let mut results = Vec::new();
+        for round in rounds {
+            let result = result(&harness, round);
+            sink.emit(&result).await.unwrap();
+            results.push(result);
+        }
+        results
+    }
+
+    fn archive(dir: &Path, rounds_per_bundle: usize) -> RoundArchive {
+        RoundArchive::new(
+            dir,
+            ArchiveConfig {
+                rounds_per_bundle,
+                ..ArchiveConfig::default()
+            },
+        )
+    }
+
+    /// Pretend the round file of `round` was last written at `time`
+    fn set_written(dir: &Path, round: u64, time: SystemTime) {
+        std::fs::File::options()
+            .write(true)
+            .open(dir.join(format!("round-{round}.json")))
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    fn later() -> SystemTime {
+        SystemTime::now() + Duration::from_secs(60)
+    }
+
+    #[tokio::test]
+    async fn test_rounds_moved_into_bundles() {
+        let dir = archive_dir("bundles");
+        let results = write_rounds(&dir, 1..=5).await;
+        let archive = archive(&dir, 2);
+
+        let bundles = archive.archive(later()).unwrap();
+        let spans: Vec<_> = bundles
+            .iter()
+            .map(|bundle| (bundle.first_round, bundle.last_round, bundle.rounds))
+            .collect();
+        assert_eq!(spans, [(1, 2, 2), (3, 4, 2), (5, 5, 1)]);
+        assert_eq!(archive.manifest().unwrap().bundles, bundles);
+
+        // The hot copies are gone, the bundles still answer for them
+        assert!(!dir.join("round-1.json").exists());
+        assert!(archive.rounds(false).unwrap().is_empty());
+        assert_eq!(archive.rounds(true).unwrap(), results);
+
+        // Nothing is left to archive
+        assert!(archive.archive(later()).unwrap().is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_only_rounds_before_cutoff_archived() {
+        let dir = archive_dir("cutoff");
+        let results = write_rounds(&dir, 1..=4).await;
+        let old = SystemTime::now() - Duration::from_secs(90 * 24 * 60 * 60);
+        set_written(&dir, 1, old);
+        set_written(&dir, 2, old);
+        let archive = RoundArchive::new(&dir, ArchiveConfig::default());
+
+        let bundles = archive.archive_expired().unwrap();
+        assert_eq!(bundles.len(), 1);
+        assert_eq!((bundles[0].first_round, bundles[0].last_round), (1, 2));
+        assert_eq!(archive.rounds(false).unwrap(), results[2..]);
+        assert_eq!(archive.rounds(true).unwrap(), results);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_retention_measured_with_clock() {
+        let dir = archive_dir("clock");
+        let results = write_rounds(&dir, 1..=2).await;
+        let clock = Arc::new(MockClock::new(SystemTime::now()));
+        let archive = RoundArchive::new(&dir, ArchiveConfig::default()).with_clock(clock.clone());
+        assert!(archive.archive_expired().unwrap().is_empty());
+
+        clock.advance(Duration::from_secs(31 * 24 * 60 * 60));
+        let bundles = archive.archive_expired().unwrap();
+        assert_eq!(bundles.len(), 1);
+        let archived_at = clock.now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        assert_eq!(bundles[0].archived_at, archived_at);
+        assert_eq!(archive.rounds(true).unwrap(), results);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_round_file_preferred_over_bundle() {
+        let dir = archive_dir("preferred");
+        let results = write_rounds(&dir, [1]).await;
+        let archive = archive(&dir, 10);
+        archive.archive(later()).unwrap();
+
+        // The round aggregated again after being archived
+        let again = AggregationResult {
+            signers: [0].into_iter().collect(),
+            ..results[0].clone()
+        };
+        FileSink::new(&dir).unwrap().emit(&again).await.unwrap();
+        assert_eq!(archive.rounds(true).unwrap(), [again]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_corrupted_bundle_detected_on_read() {
+        let dir = archive_dir("corrupted");
+        write_rounds(&dir, 1..=4).await;
+        let archive = archive(&dir, 2);
+        let bundles = archive.archive(later()).unwrap();
+
+        let path = archive.archive_dir().join(&bundles[1].file);
+        let mut bytes = std::fs::read(&path).unwrap();
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 0xff;
+        std::fs::write(&path, bytes).unwrap();
+
+        assert!(archive.read_bundle(&bundles[0]).is_ok());
+        let err = archive.read_bundle(&bundles[1]).unwrap_err();
+        assert!(err.to_string().contains("checksum"), "{err}");
+        assert!(archive.rounds(true).is_err());
+
+        // Hot rounds are still readable without the bundles
+        assert!(archive.rounds(false).unwrap().is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let day = Duration::from_secs(24 * 60 * 60);
+        assert_eq!(parse_date("1970-01-01").unwrap(), UNIX_EPOCH);
+        assert_eq!(parse_date("1970-01-02").unwrap(), UNIX_EPOCH + day);
+        assert_eq!(parse_date("2024-02-29").unwrap(), UNIX_EPOCH + day * 19_782);
+        assert_eq!(parse_date("2000-03-01").unwrap(), UNIX_EPOCH + day * 11_017);
+
+        for invalid in [
+            "2023-02-29",
+            "2024-13-01",
+            "2024-04-31",
+            "2024-1",
+            "1969-12-31",
+        ] {
+            assert!(parse_date(invalid).is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Canonical form of the contributor set every node indexes into.
+//!
+//! Contributor indices are positions in the sorted set, so all nodes must sort the
+//! same bytes. Keys are normalized to the compressed encoding of their G2 point
+//! before sorting, so a key read as compressed bytes by one node and as uncompressed
+//! bytes by another still lands at the same index. A key listed twice, under any
+//! encoding, is either rejected or kept once depending on the [DuplicatePolicy].
+
+use crate::logging::short;
+use ark_bn254::G2Affine;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use bn254::{G1PublicKey, PublicKey as PubKey};
+use std::collections::HashMap;
+use tracing::warn;
+
+/// How a contributor set listing the same key more than once is handled
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Refuse the set
+    #[default]
+    Reject,
+    /// Keep the key once, logging each dropped copy
+    Deduplicate,
+}
+
+/// Key of the G2 point encoded in `bytes`, compressed or uncompressed
+///
+/// Returns `None` if `bytes` are not a valid point in either encoding.
+pub fn canonical_key(bytes: &[u8]) -> Option<PubKey> {
+    let point = G2Affine::deserialize_compressed(bytes)
+        .or_else(|_| G2Affine::deserialize_uncompressed(bytes))
+        .ok()?;
+    let mut compressed = Vec::with_capacity(point.compressed_size());
+    point.serialize_compressed(&mut compressed).ok()?;
+    PubKey::try_from(compressed).ok()
+}
+
+/// `key` in its canonical encoding, or unchanged if it cannot be decoded as a point
+pub fn canonicalize_key(key: &PubKey) -> PubKey {
+    canonical_key(&key[..]).unwrap_or_else(|| key.clone())
+}
+
+/// Sorted, canonical contributor set, failing with a key listed twice
+///
+/// Used for every set nodes index into, whether configured or read from the
+/// registry, so the index of a key is the same on every node.
+pub fn canonicalize_contributors(
+    contributors: impl IntoIterator<Item = PubKey>,
+    policy: DuplicatePolicy,
+) -> Result<Vec<PubKey>, PubKey> {
+    let mut contributors: Vec<PubKey> = contributors
+        .into_iter()
+        .map(|key| canonicalize_key(&key))
+        .collect();
+    contributors.sort();
+    let mut unique: Vec<PubKey> = Vec::with_capacity(contributors.len());
+    for key in contributors {
+        if unique.last() == Some(&key) {
+            match policy {
+                DuplicatePolicy::Reject => return Err(key),
+                DuplicatePolicy::Deduplicate => {
+                    warn!(key = %short(&key), "contributor listed more than once, keeping one");
+                    continue;
+                }
+            }
+        }
+        unique.push(key);
+    }
+    Ok(unique)
+}
+
+/// Sorted, canonical contributor set keeping a key listed twice once
+///
+/// Same as [canonicalize_contributors] with [DuplicatePolicy::Deduplicate], which never
+/// fails.
+pub fn deduplicate_contributors(contributors: impl IntoIterator<Item = PubKey>) -> Vec<PubKey> {
+    let mut contributors: Vec<PubKey> = contributors
+        .into_iter()
+        .map(|key| canonicalize_key(&key))
+        .collect();
+    contributors.sort();
+    contributors.dedup_by(|key, kept| {
+        let duplicate = key == kept;
+        if duplicate {
+            warn!(key = %short(&key), "contributor listed more than once, keeping one");
+        }
+        duplicate
+    });
+    contributors
+}
+
+/// G1 keys of contributors, keyed by their canonical G2 key
+pub fn canonical_g1_map(g1_map: &HashMap<PubKey, G1PublicKey>) -> HashMap<PubKey, G1PublicKey> {
+    g1_map
+        .iter()
+        .map(|(key, g1)| (canonicalize_key(key), g1.clone()))
+        .collect()
+}
@@ -0,0 +1,42 @@
+//! Deadline of a round, derived from the response window of its task.
+//!
+//! The contract accepts the response to a task for a fixed number of blocks after
+//! its reference block. With a [BlockWindow], a round collects contributions for as
+//! long as the blocks left in that window take to be produced, instead of the fixed
+//! [SyncConfig::round_deadline](crate::contributor::sync::SyncConfig::round_deadline),
+//! so the node stops collecting when the contract stops accepting.
+
+use std::time::Duration;
+
+/// Response window of tasks on-chain, with the expected time between blocks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockWindow {
+    /// Blocks after the reference block during which a response is accepted
+    pub response_window_blocks: u64,
+    /// Expected time between blocks
+    pub block_time: Duration,
+}
+
+impl BlockWindow {
+    /// Window of `response_window_blocks` blocks produced every `block_time`
+    pub fn new(response_window_blocks: u64, block_time: Duration) -> Self {
+        Self {
+            response_window_blocks,
+            block_time,
+        }
+    }
+
+    /// Blocks left to respond to a task at `reference_block` with the chain at `head`
+    pub fn blocks_remaining(&self, reference_block: u64, head: u64) -> u64 {
+        reference_block
+            .saturating_add(self.response_window_blocks)
+            .saturating_sub(head)
+    }
+
+    /// Time left to respond to a task at `reference_block` with the chain at `head`
+    pub fn deadline(&self, reference_block: u64, head: u64) -> Duration {
+        let blocks = self.blocks_remaining(reference_block, head);
+        self.block_time
+            .saturating_mul(u32::try_from(blocks).unwrap_or(u32::MAX))
+    }
+}
@@ -0,0 +1,172 @@
+//! Context for frames that fail to decode.
+
+use crate::logging::{Hex, LogPolicy};
+use bn254::Signature;
+use commonware_avs_router::wire::{self, aggregation::Payload};
+use commonware_codec::{Error, FixedSize};
+use std::fmt;
+use tracing::warn;
+
+/// Number of leading bytes of a dropped frame included in logs
+pub const LOGGED_PREFIX_LEN: usize = 16;
+
+/// Why a frame could not be decoded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeFailure {
+    /// The frame ended before the message was complete
+    Truncated,
+    /// A field held a value that is not valid for its type
+    Malformed,
+    /// The frame is larger than any message accepted, it was not parsed
+    Oversized,
+}
+
+impl DecodeFailure {
+    /// Classify a decoding error
+    pub fn classify(err: &Error) -> Self {
+        match err {
+            Error::EndOfBuffer => DecodeFailure::Truncated,
+            _ => DecodeFailure::Malformed,
+        }
+    }
+}
+
+impl fmt::Display for DecodeFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeFailure::Truncated => write!(f, "truncated"),
+            DecodeFailure::Malformed => write!(f, "malformed"),
+            DecodeFailure::Oversized => write!(f, "oversized"),
+        }
+    }
+}
+
+/// Log a dropped frame with its sender, the failure and the first bytes of the frame
+/// under `policy`
+pub fn log_decode_error(
+    policy: LogPolicy,
+    sender: &impl AsRef<[u8]>,
+    kind: &str,
+    frame: &[u8],
+    err: &Error,
+) {
+    let prefix = frame.get(..LOGGED_PREFIX_LEN).unwrap_or(frame);
+    warn!(
+        sender = %policy.short(sender),
+        failure = %DecodeFailure::classify(err),
+        len = frame.len(),
+        prefix = %Hex(prefix),
+        %err,
+        "dropping {kind} frame"
+    );
+}
+
+/// Why a raw signature was rejected before or during conversion to a curve point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MalformedSignature {
+    /// The blob is not exactly one encoded signature long
+    Length {
+        /// Length of an encoded signature
+        expected: usize,
+        /// Length of the blob
+        actual: usize,
+    },
+    /// The blob encodes the point at infinity, which never verifies
+    Identity,
+    /// Both encoding flags are set in the last byte, which no point encodes to
+    Flags,
+    /// The blob passed the checks but is not a point on the curve
+    NotOnCurve,
+}
+
+impl MalformedSignature {
+    /// Short classification used to label metrics
+    pub fn kind(&self) -> &'static str {
+        match self {
+            MalformedSignature::Length { .. } => "signature_length",
+            MalformedSignature::Identity => "signature_identity",
+            MalformedSignature::Flags => "signature_flags",
+            MalformedSignature::NotOnCurve => "signature_not_on_curve",
+        }
+    }
+}
+
+impl fmt::Display for MalformedSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MalformedSignature::Length { expected, actual } => {
+                write!(f, "signature is {actual} bytes, expected {expected}")
+            }
+            MalformedSignature::Identity => write!(f, "signature is the identity"),
+            MalformedSignature::Flags => write!(f, "signature has invalid encoding flags"),
+            MalformedSignature::NotOnCurve => write!(f, "signature is not a curve point"),
+        }
+    }
+}
+
+impl std::error::Error for MalformedSignature {}
+
+/// Flags of the arkworks point encoding, held by the two top bits of the last byte
+const ENCODING_FLAGS: u8 = 0b1100_0000;
+
+/// Cheap structural checks on a raw signature, run before any conversion
+pub fn precheck_signature(bytes: &[u8]) -> Result<(), MalformedSignature> {
+    if bytes.len() != Signature::SIZE {
+        return Err(MalformedSignature::Length {
+            expected: Signature::SIZE,
+            actual: bytes.len(),
+        });
+    }
+    if bytes.iter().all(|byte| *byte == 0) {
+        return Err(MalformedSignature::Identity);
+    }
+    if bytes
+        .last()
+        .is_some_and(|last| last & ENCODING_FLAGS == ENCODING_FLAGS)
+    {
+        return Err(MalformedSignature::Flags);
+    }
+    Ok(())
+}
+
+/// Convert a raw signature with `convert` once it passed [precheck_signature]
+///
+/// Blobs failing the precheck never reach `convert`, so malformed signatures cost
+/// neither an allocation nor a point decompression.
+pub fn decode_signature<F>(bytes: &[u8], convert: F) -> Result<Signature, MalformedSignature>
+where
+    F: FnOnce(&[u8]) -> Option<Signature>,
+{
+    precheck_signature(bytes)?;
+    convert(bytes).ok_or(MalformedSignature::NotOnCurve)
+}
+
+/// Convert a raw signature from a slice, without copying it
+pub fn signature_from_slice(bytes: &[u8]) -> Result<Signature, MalformedSignature> {
+    decode_signature(bytes, |bytes| Signature::try_from(bytes).ok())
+}
+
+/// What an aggregation message carries, with its signature already decoded
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessageKind {
+    /// The orchestrator starting a round
+    Start,
+    /// A contributor's signature over the round's payload
+    Signature(Signature),
+    /// No payload, a malformed signature, or a payload this node does not handle
+    Unknown,
+}
+
+/// Kind of `message`, failing with the reason its signature is malformed
+pub fn try_classify<T>(message: &wire::Aggregation<T>) -> Result<MessageKind, MalformedSignature> {
+    match &message.payload {
+        Some(Payload::Start) => Ok(MessageKind::Start),
+        Some(Payload::Signature(raw)) => signature_from_slice(raw).map(MessageKind::Signature),
+        _ => Ok(MessageKind::Unknown),
+    }
+}
+
+/// Kind of `message`, with malformed signatures mapped to [MessageKind::Unknown]
+pub fn classify<T>(message: &wire::Aggregation<T>) -> MessageKind {
+    try_classify(message).unwrap_or(MessageKind::Unknown)
+}
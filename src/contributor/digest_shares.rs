@@ -0,0 +1,30 @@
+//! Shares of executed rounds, tagged with the digest they sign.
+//!
+//! In a round computed by an [Executor](crate::execution::Executor) each contributor
+//! signs the digest of its own response, which its peers cannot derive from the Start.
+//! Such shares are tagged with their digest, so peers verify them against it and
+//! aggregate only the shares over the same digest.
+
+use bytes::{BufMut, Bytes};
+
+/// Prefix distinguishing digest-tagged shares from untagged frames
+pub const DIGEST_TAG_MAGIC: [u8; 4] = *b"DTAG";
+
+/// Tag a share `frame` with the `digest` it signs
+pub fn tag_digest(digest: &[u8; 32], frame: &[u8]) -> Bytes {
+    let mut buf = Vec::with_capacity(DIGEST_TAG_MAGIC.len() + digest.len() + frame.len());
+    buf.put_slice(&DIGEST_TAG_MAGIC);
+    buf.put_slice(digest);
+    buf.put_slice(frame);
+    Bytes::from(buf)
+}
+
+/// Digest and share of a tagged frame, `None` for untagged frames
+pub fn untag_digest(frame: &Bytes) -> Option<([u8; 32], Bytes)> {
+    let share = DIGEST_TAG_MAGIC.len() + 32;
+    if !frame.starts_with(&DIGEST_TAG_MAGIC) || frame.len() <= share {
+        return None;
+    }
+    let digest = frame.get(DIGEST_TAG_MAGIC.len()..share)?.try_into().ok()?;
+    Some((digest, frame.slice(share..)))
+}
@@ -0,0 +1,245 @@
+//! Round events a contributor reports while running.
+//!
+//! The run loop reports through an [EventSink] rather than a concrete metrics
+//! backend, so the contributor builds without one. Every event defaults to a
+//! no-op; the Prometheus [Metrics](crate::metrics::Metrics) sink is available
+//! with the `observability` feature. [ContributorMetrics] counts the main events
+//! with plain atomics, for embedders exposing them their own way.
+
+use crate::contributor::types::DroppedShare;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Receives the round events of a contributor, labelled by quorum
+pub trait EventSink: Send + Sync {
+    /// A frame was received from a peer or the orchestrator
+    fn message_received(&self, _quorum_id: u8) {}
+
+    /// A round was signed and started collecting signatures
+    fn round_started(&self, _quorum_id: u8) {}
+
+    /// We signed the payload of a round
+    fn round_signed(&self, _quorum_id: u8) {}
+
+    /// A peer signature was verified
+    fn signature_received(&self, _quorum_id: u8) {}
+
+    /// A peer signature failed verification against the round payload
+    fn invalid_signature(&self, _quorum_id: u8) {}
+
+    /// A peer sent a second signature for a round
+    fn duplicate_share(&self, _quorum_id: u8) {}
+
+    /// A round collected enough signatures to aggregate
+    fn threshold_reached(&self, _quorum_id: u8) {}
+
+    /// Signatures of a round failed to aggregate
+    fn aggregation_failed(&self, _quorum_id: u8) {}
+
+    /// Time from signing a round to aggregating it
+    fn observe_latency(&self, _quorum_id: u8, _latency: Duration) {}
+
+    /// Time spent validating a round payload
+    fn observe_validation(&self, _quorum_id: u8, _duration: Duration) {}
+
+    /// A round payload failed validation
+    fn validation_failed(&self, _quorum_id: u8) {}
+
+    /// A round passed its deadline before aggregating
+    fn round_timed_out(&self, _quorum_id: u8) {}
+
+    /// A Start was rejected for its metadata
+    fn metadata_rejected(&self, _quorum_id: u8, _reason: &str) {}
+
+    /// A peer signature was dropped before verification
+    fn share_dropped(&self, _quorum_id: u8, _reason: &str) {}
+
+    /// A round was retired after its response was submitted on-chain
+    fn round_completed_on_chain(&self, _quorum_id: u8) {}
+
+    /// A peer frame or signature failed to decode
+    fn decode_failed(&self, _quorum_id: u8, _reason: &str) {}
+
+    /// A peer was quarantined for repeated decode failures
+    fn peer_quarantined(&self, _quorum_id: u8) {}
+
+    /// A peer was ignored for scoring too low
+    fn peer_ignored(&self, _quorum_id: u8) {}
+
+    /// The orchestrator's final aggregate of a round did not match our payload or
+    /// aggregate
+    fn aggregate_mismatch(&self, _quorum_id: u8) {}
+
+    /// A contributor signed another digest than ours in an executed round
+    fn digest_disagreement(&self, _quorum_id: u8) {}
+
+    /// A task with an executor failed or timed out, its round was not signed
+    fn execution_failed(&self, _quorum_id: u8) {}
+
+    /// A round in flight was preempted to admit a newer one
+    fn round_preempted(&self, _quorum_id: u8) {}
+
+    /// No Start was received from the orchestrators for longer than the stale threshold
+    fn orchestrator_stale(&self, _quorum_id: u8) {}
+
+    /// A Start was rejected, the maximum number of active rounds being reached
+    fn round_rejected_capacity(&self, _quorum_id: u8) {}
+}
+
+/// Sink dropping every event
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopEventSink;
+
+impl EventSink for NoopEventSink {}
+
+/// Sink counting the rounds that reached the aggregation threshold
+///
+/// Cloning is cheap and clones share the count.
+#[derive(Clone, Debug, Default)]
+pub struct ThresholdCounter(Arc<AtomicU64>);
+
+impl ThresholdCounter {
+    /// Counter starting at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rounds that reached the threshold so far
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl EventSink for ThresholdCounter {
+    fn threshold_reached(&self, _quorum_id: u8) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Counts of [ContributorMetrics] at one point in time
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ContributorMetricsSnapshot {
+    /// Frames received from peers
+    pub messages_received: u64,
+    /// Frames that failed to decode
+    pub decode_failures: u64,
+    /// Shares dropped because their sender is not in the contributor set
+    pub unknown_contributors: u64,
+    /// Shares from a contributor that already had one in the round
+    pub duplicate_shares: u64,
+    /// Shares that failed verification
+    pub invalid_signatures: u64,
+    /// Starts whose payload failed validation
+    pub validation_failures: u64,
+    /// Rounds started
+    pub rounds_started: u64,
+    /// Rounds that reached the aggregation threshold
+    pub rounds_aggregated: u64,
+    /// Rounds that expired before aggregating
+    pub rounds_timed_out: u64,
+    /// Starts rejected at the maximum number of active rounds
+    pub rounds_rejected_capacity: u64,
+    /// Signatures we produced
+    pub signing_operations: u64,
+}
+
+#[derive(Debug, Default)]
+struct ContributorCounters {
+    messages_received: AtomicU64,
+    decode_failures: AtomicU64,
+    unknown_contributors: AtomicU64,
+    duplicate_shares: AtomicU64,
+    invalid_signatures: AtomicU64,
+    validation_failures: AtomicU64,
+    rounds_started: AtomicU64,
+    rounds_aggregated: AtomicU64,
+    rounds_timed_out: AtomicU64,
+    rounds_rejected_capacity: AtomicU64,
+    signing_operations: AtomicU64,
+}
+
+/// Sink counting received messages, rejections and round progress, over all quorums
+///
+/// No endpoint is served, the embedder reads [ContributorMetrics::snapshot] and exposes
+/// the counts however it likes. Cloning is cheap and clones share the counts.
+#[derive(Clone, Debug, Default)]
+pub struct ContributorMetrics(Arc<ContributorCounters>);
+
+impl ContributorMetrics {
+    /// Metrics with every count at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts at this point in time
+    pub fn snapshot(&self) -> ContributorMetricsSnapshot {
+        let counters = &self.0;
+        let get = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+        ContributorMetricsSnapshot {
+            messages_received: get(&counters.messages_received),
+            decode_failures: get(&counters.decode_failures),
+            unknown_contributors: get(&counters.unknown_contributors),
+            duplicate_shares: get(&counters.duplicate_shares),
+            invalid_signatures: get(&counters.invalid_signatures),
+            validation_failures: get(&counters.validation_failures),
+            rounds_started: get(&counters.rounds_started),
+            rounds_aggregated: get(&counters.rounds_aggregated),
+            rounds_timed_out: get(&counters.rounds_timed_out),
+            rounds_rejected_capacity: get(&counters.rounds_rejected_capacity),
+            signing_operations: get(&counters.signing_operations),
+        }
+    }
+}
+
+fn increment(counter: &AtomicU64) {
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+impl EventSink for ContributorMetrics {
+    fn message_received(&self, _quorum_id: u8) {
+        increment(&self.0.messages_received);
+    }
+
+    fn round_started(&self, _quorum_id: u8) {
+        increment(&self.0.rounds_started);
+    }
+
+    fn round_signed(&self, _quorum_id: u8) {
+        increment(&self.0.signing_operations);
+    }
+
+    fn invalid_signature(&self, _quorum_id: u8) {
+        increment(&self.0.invalid_signatures);
+    }
+
+    fn duplicate_share(&self, _quorum_id: u8) {
+        increment(&self.0.duplicate_shares);
+    }
+
+    fn threshold_reached(&self, _quorum_id: u8) {
+        increment(&self.0.rounds_aggregated);
+    }
+
+    fn validation_failed(&self, _quorum_id: u8) {
+        increment(&self.0.validation_failures);
+    }
+
+    fn round_timed_out(&self, _quorum_id: u8) {
+        increment(&self.0.rounds_timed_out);
+    }
+
+    fn round_rejected_capacity(&self, _quorum_id: u8) {
+        increment(&self.0.rounds_rejected_capacity);
+    }
+
+    fn share_dropped(&self, _quorum_id: u8, reason: &str) {
+        if reason == DroppedShare::UnknownSender.kind() {
+            increment(&self.0.unknown_contributors);
+        }
+    }
+
+    fn decode_failed(&self, _quorum_id: u8, _reason: &str) {
+        increment(&self.0.decode_failures);
+    }
+}
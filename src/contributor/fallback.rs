@@ -0,0 +1,135 @@
+//! Taking over from an orchestrator that went silent.
+//!
+//! Every contributor tracks when it last heard from the primary orchestrators. Once
+//! they are silent for the promotion timeout, all contributors deterministically
+//! promote the contributor with the highest index in the sorted contributor set and
+//! accept its Starts. The promoted contributor starts the rounds the primary would
+//! have started next, one per round interval, until a primary is heard again.
+
+use bn254::PublicKey as PubKey;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Silence of the primary orchestrators before a contributor is promoted, by default
+pub const DEFAULT_PROMOTION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Time between the rounds a promoted contributor starts, by default
+pub const DEFAULT_FALLBACK_ROUND_INTERVAL: Duration = Duration::from_secs(30);
+
+/// When a contributor takes over and how often it starts rounds
+#[derive(Clone, Debug)]
+pub struct FallbackConfig {
+    /// Silence from the orchestrator after which a contributor is promoted
+    pub promotion_timeout: Duration,
+    /// Time between the rounds the promoted contributor starts
+    pub round_interval: Duration,
+}
+
+impl Default for FallbackConfig {
+    fn default() -> Self {
+        Self {
+            promotion_timeout: DEFAULT_PROMOTION_TIMEOUT,
+            round_interval: DEFAULT_FALLBACK_ROUND_INTERVAL,
+        }
+    }
+}
+
+/// Contributor promoted to orchestrator
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Promotion {
+    /// Key of the promoted contributor
+    pub key: PubKey,
+    /// Whether the promoted contributor is us
+    pub is_self: bool,
+}
+
+/// Liveness of the primary orchestrators and the fallback promoted in their place
+#[derive(Clone, Debug)]
+pub struct FallbackOrchestrator {
+    config: FallbackConfig,
+    last_heard: Instant,
+    promoted: Option<Promotion>,
+    /// Highest round started by anyone, promoted rounds continue after it
+    highest_round: u64,
+    /// When the promoted contributor starts its next round, if it is us
+    next_start: Option<Instant>,
+}
+
+impl FallbackOrchestrator {
+    /// Track liveness from `now`, as if the primary was just heard
+    pub fn new(config: FallbackConfig, now: Instant) -> Self {
+        Self {
+            config,
+            last_heard: now,
+            promoted: None,
+            highest_round: 0,
+            next_start: None,
+        }
+    }
+
+    /// Contributor taking over among the sorted `contributors`: the highest indexed
+    pub fn fallback_key(contributors: &[PubKey]) -> Option<&PubKey> {
+        contributors.last()
+    }
+
+    /// A primary orchestrator was heard, returning the promotion it ends
+    pub fn heard_primary(&mut self, now: Instant) -> Option<Promotion> {
+        self.last_heard = now;
+        self.next_start = None;
+        self.promoted.take()
+    }
+
+    /// A round was started, by a primary or the promoted contributor
+    pub fn observe_round(&mut self, round: u64) {
+        self.highest_round = self.highest_round.max(round);
+    }
+
+    /// Promote the fallback among `contributors` if the primary was silent until `now`
+    ///
+    /// Returns the promotion once, when it happens.
+    pub fn check_silence(
+        &mut self,
+        contributors: &[PubKey],
+        me: &PubKey,
+        now: Instant,
+    ) -> Option<Promotion> {
+        if self.promoted.is_some() || now < self.promotion_deadline() {
+            return None;
+        }
+        let key = Self::fallback_key(contributors)?.clone();
+        let promotion = Promotion {
+            is_self: &key == me,
+            key,
+        };
+        if promotion.is_self {
+            self.next_start = Some(now);
+        }
+        self.promoted = Some(promotion.clone());
+        Some(promotion)
+    }
+
+    /// Round to start at `now` if we are promoted and it is due
+    pub fn next_round(&mut self, now: Instant) -> Option<u64> {
+        self.next_start.filter(|at| *at <= now)?;
+        self.next_start = Some(now + self.config.round_interval);
+        self.highest_round += 1;
+        Some(self.highest_round)
+    }
+
+    /// Next time the fallback has something to do: promote, or start a round
+    pub fn deadline(&self) -> Option<Instant> {
+        match &self.promoted {
+            None => Some(self.promotion_deadline()),
+            Some(_) => self.next_start,
+        }
+    }
+
+    /// Contributor promoted so far, if any
+    pub fn promoted(&self) -> Option<&Promotion> {
+        self.promoted.as_ref()
+    }
+
+    fn promotion_deadline(&self) -> Instant {
+        self.last_heard + self.config.promotion_timeout
+    }
+}
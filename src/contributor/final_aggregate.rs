@@ -0,0 +1,177 @@
+//! Final aggregates broadcast by the orchestrator.
+//!
+//! Once a round is aggregated, the orchestrator may send the aggregate it settled on
+//! back to the contributors in a [FinalAggregate]. Contributors verify it against
+//! the payload they signed and the claimed signers, and compare it with the
+//! aggregate they computed themselves. An aggregate that does not verify over our
+//! payload means someone aggregated over a different one.
+
+use crate::contributor::decode::signature_from_slice;
+use crate::contributor::types::ParticipationBitmap;
+use bn254::{G1PublicKey, PublicKey, Signature, aggregate_verify};
+use bytes::{Buf, BufMut};
+use commonware_codec::{EncodeSize, Error, Read, ReadExt, Write};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Prefix distinguishing final aggregates from aggregation frames
+pub const FINAL_AGGREGATE_MAGIC: [u8; 4] = *b"FAGG";
+
+/// Upper bound on the encoded signature carried by a [FinalAggregate]
+pub const MAX_SIGNATURE_LEN: usize = 256;
+
+/// Aggregate signature of a round with the contributors it claims signed
+#[derive(Clone, Debug, PartialEq)]
+pub struct FinalAggregate {
+    /// Round the aggregate is for
+    pub round: u64,
+    /// Encoded aggregate signature
+    pub signature: Vec<u8>,
+    /// Contributors the aggregate claims signed
+    pub signers: ParticipationBitmap,
+}
+
+impl FinalAggregate {
+    /// Whether a raw frame is a final aggregate
+    pub fn is_final_aggregate(frame: &[u8]) -> bool {
+        frame.starts_with(&FINAL_AGGREGATE_MAGIC)
+    }
+}
+
+impl Write for FinalAggregate {
+    fn write(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&FINAL_AGGREGATE_MAGIC);
+        self.round.write(buf);
+        buf.put_slice(&self.signers.to_bytes());
+        (self.signature.len() as u16).write(buf);
+        buf.put_slice(&self.signature);
+    }
+}
+
+impl EncodeSize for FinalAggregate {
+    fn encode_size(&self) -> usize {
+        FINAL_AGGREGATE_MAGIC.len() + 8 + 32 + 2 + self.signature.len()
+    }
+}
+
+impl Read for FinalAggregate {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _: &()) -> Result<Self, Error> {
+        let magic = <[u8; 4]>::read(buf)?;
+        if magic != FINAL_AGGREGATE_MAGIC {
+            return Err(Error::Invalid(
+                "FinalAggregate",
+                "missing final aggregate prefix",
+            ));
+        }
+        let round = u64::read(buf)?;
+        let signers = ParticipationBitmap::from_bytes(<[u8; 32]>::read(buf)?);
+        let len = u16::read(buf)? as usize;
+        if len > MAX_SIGNATURE_LEN {
+            return Err(Error::InvalidLength(len));
+        }
+        if buf.remaining() < len {
+            return Err(Error::EndOfBuffer);
+        }
+        let mut signature = vec![0; len];
+        buf.copy_to_slice(&mut signature);
+        Ok(Self {
+            round,
+            signature,
+            signers,
+        })
+    }
+}
+
+/// How a verified final aggregate compares with ours
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AggregateCheck {
+    /// Verifies over our payload, and equals our aggregate when the signers are ours
+    Match,
+    /// Does not verify over our payload, or differs from our aggregate of the same
+    /// signers
+    Mismatch,
+    /// Verifies over our payload, we did not aggregate the round
+    NotAggregatedLocally,
+}
+
+impl AggregateCheck {
+    /// Short classification used in logs
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AggregateCheck::Match => "match",
+            AggregateCheck::Mismatch => "mismatch",
+            AggregateCheck::NotAggregatedLocally => "not_aggregated_locally",
+        }
+    }
+}
+
+/// Why a final aggregate cannot be checked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AggregateRejection {
+    /// We never signed the round, so there is no payload to check against
+    UnknownRound,
+    /// The bitmap claims no signer
+    NoSigners,
+    /// The bitmap claims a signer that is not a contributor or has no G1 key
+    UnknownParticipant(usize),
+    /// The frame could not be decoded
+    Malformed,
+}
+
+impl fmt::Display for AggregateRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AggregateRejection::UnknownRound => write!(f, "round was not signed"),
+            AggregateRejection::NoSigners => write!(f, "aggregate claims no signer"),
+            AggregateRejection::UnknownParticipant(index) => {
+                write!(f, "aggregate claims unknown participant {index}")
+            }
+            AggregateRejection::Malformed => write!(f, "aggregate signature is malformed"),
+        }
+    }
+}
+
+impl std::error::Error for AggregateRejection {}
+
+/// Check `aggregate` against the payload we signed and the aggregate we computed
+///
+/// Signers are indexed into the sorted `contributors`. With a `g1_map`, each must
+/// also have a G1 key so the aggregate can be checked on-chain. `local` is our
+/// aggregate of the round with its signers, if we aggregated it.
+pub fn verify_final_aggregate(
+    aggregate: &FinalAggregate,
+    expected_hash: &[u8; 32],
+    contributors: &[PublicKey],
+    g1_map: Option<&HashMap<PublicKey, G1PublicKey>>,
+    local: Option<&(Signature, ParticipationBitmap)>,
+) -> Result<AggregateCheck, AggregateRejection> {
+    if aggregate.signers.is_empty() {
+        return Err(AggregateRejection::NoSigners);
+    }
+    let signers = aggregate
+        .signers
+        .iter()
+        .map(|index| {
+            contributors
+                .get(index)
+                .filter(|key| g1_map.is_none_or(|g1_map| g1_map.contains_key(*key)))
+                .cloned()
+                .ok_or(AggregateRejection::UnknownParticipant(index))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let signature =
+        signature_from_slice(&aggregate.signature).map_err(|_| AggregateRejection::Malformed)?;
+
+    if !aggregate_verify(&signers, None, expected_hash, &signature) {
+        return Ok(AggregateCheck::Mismatch);
+    }
+    Ok(match local {
+        None => AggregateCheck::NotAggregatedLocally,
+        Some((ours, our_signers)) if *our_signers == aggregate.signers && *ours != signature => {
+            AggregateCheck::Mismatch
+        }
+        Some(_) => AggregateCheck::Match,
+    })
+}
@@ -0,0 +1,66 @@
+//! Detection of an orchestrator that stopped issuing Starts.
+//!
+//! Without Starts a contributor idles with nothing to log. A [StaleDetector] tracks
+//! the time since the last Start and reports an [OrchestratorStale] once it exceeds
+//! the threshold, so monitoring can alert on a stalled orchestrator.
+
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Orchestrators issued no Start for longer than the threshold
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrchestratorStale {
+    /// Time since the last Start, or since the contributor started without one
+    pub silent_for: Duration,
+    /// Round of the last Start, if any was received
+    pub last_round: Option<u64>,
+}
+
+/// Time since the last Start, reporting once per silence longer than the threshold
+#[derive(Clone, Debug)]
+pub struct StaleDetector {
+    threshold: Duration,
+    last_start: Instant,
+    last_round: Option<u64>,
+    reported: bool,
+}
+
+impl StaleDetector {
+    /// Track Starts from `now`, as if one was just received
+    pub fn new(threshold: Duration, now: Instant) -> Self {
+        Self {
+            threshold,
+            last_start: now,
+            last_round: None,
+            reported: false,
+        }
+    }
+
+    /// A Start for `round` was received at `now`, ending any silence
+    pub fn start_seen(&mut self, round: u64, now: Instant) {
+        self.last_start = now;
+        self.last_round = Some(round);
+        self.reported = false;
+    }
+
+    /// Report the silence at `now` if it exceeds the threshold and was not reported yet
+    pub fn check(&mut self, now: Instant) -> Option<OrchestratorStale> {
+        if self.reported || now < self.stale_at() {
+            return None;
+        }
+        self.reported = true;
+        Some(OrchestratorStale {
+            silent_for: now.duration_since(self.last_start),
+            last_round: self.last_round,
+        })
+    }
+
+    /// When the silence becomes reportable, `None` once reported
+    pub fn deadline(&self) -> Option<Instant> {
+        (!self.reported).then(|| self.stale_at())
+    }
+
+    fn stale_at(&self) -> Instant {
+        self.last_start + self.threshold
+    }
+}
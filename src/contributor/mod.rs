@@ -1,8 +1,51 @@
+//! Building blocks of a contributor: round state, signing, aggregation and the
+//! messages exchanged with peers.
+#![deny(clippy::disallowed_methods)]
+
 #[cfg(test)]
+#[allow(missing_docs, clippy::disallowed_methods)]
 pub mod tests;
 
+pub mod aggregated;
+pub mod aggregation;
+pub mod archive;
+pub mod committee;
+pub mod deadline;
+pub mod decode;
+pub mod digest_shares;
+pub mod events;
+pub mod fallback;
+pub mod final_aggregate;
+pub mod liveness;
+pub mod quarantine;
+pub mod quorum_channels;
+pub mod relay;
+pub mod replay;
+pub mod rounds;
+pub mod router;
+pub mod scores;
+pub mod signing;
+pub mod sink;
+pub mod start;
+pub mod sync;
 pub mod traits;
+pub mod transcript;
 pub mod types;
+pub mod unknown_peers;
 
-pub use traits::{Contribute, ContributorBase};
-pub use types::AggregationInput;
+pub use aggregated::Aggregated;
+pub use archive::{ArchiveConfig, RoundArchive};
+pub use committee::{DuplicatePolicy, canonicalize_contributors};
+pub use events::{ContributorMetrics, ContributorMetricsSnapshot, EventSink, NoopEventSink};
+pub use fallback::{FallbackConfig, FallbackOrchestrator};
+pub use liveness::{OrchestratorStale, StaleDetector};
+pub use quorum_channels::{QuorumChannels, QuorumDispatcher, QuorumReceiver};
+pub use relay::{ForwardedShare, RelayConfig, ShareRelay};
+pub use rounds::{InvalidTransition, RoundState, RoundStatus, RoundTable};
+pub use router::{Destination, MessageClass, OutboundRouter};
+pub use signing::{AsyncSigner, SharedSigner};
+pub use sink::{AggregationResult, AggregationSink, FileSink, IdempotentSink};
+pub use traits::{Contribute, ContributeError, ContributorBase};
+pub use types::{
+    AggregationInput, Assignment, ParticipationBitmap, QuorumCertificate, WeightError,
+};
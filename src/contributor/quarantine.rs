@@ -0,0 +1,101 @@
+//! Quarantine of peers repeatedly sending frames that fail to decode.
+//!
+//! Every frame or signature a peer sends that fails to decode counts against it.
+//! Once a peer reaches [QuarantineConfig::max_failures] within
+//! [QuarantineConfig::window], its frames are dropped unread for
+//! [QuarantineConfig::duration], so a misbehaving peer stops costing decoding work.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Configuration of a [PeerQuarantine]
+#[derive(Clone, Debug)]
+pub struct QuarantineConfig {
+    /// Decode failures within the window that quarantine a peer
+    pub max_failures: u32,
+    /// Time over which decode failures are counted
+    pub window: Duration,
+    /// Time a quarantined peer's frames are dropped
+    pub duration: Duration,
+}
+
+impl Default for QuarantineConfig {
+    fn default() -> Self {
+        Self {
+            max_failures: 16,
+            window: Duration::from_secs(60),
+            duration: Duration::from_secs(300),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PeerRecord {
+    failures: u32,
+    window_start: Instant,
+    quarantined_until: Option<Instant>,
+}
+
+/// Per-peer decode failure counts and quarantines
+#[derive(Debug)]
+pub struct PeerQuarantine<K> {
+    config: QuarantineConfig,
+    peers: HashMap<K, PeerRecord>,
+}
+
+impl<K> Default for PeerQuarantine<K> {
+    fn default() -> Self {
+        Self {
+            config: QuarantineConfig::default(),
+            peers: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> PeerQuarantine<K> {
+    /// Quarantine with no peer penalized
+    pub fn new(config: QuarantineConfig) -> Self {
+        Self {
+            config,
+            peers: HashMap::new(),
+        }
+    }
+
+    /// Count a decode failure from `peer`, returning whether it was just quarantined
+    pub fn record_failure(&mut self, peer: &K, now: Instant) -> bool {
+        let record = self.peers.entry(peer.clone()).or_insert(PeerRecord {
+            failures: 0,
+            window_start: now,
+            quarantined_until: None,
+        });
+        if now.duration_since(record.window_start) > self.config.window {
+            record.failures = 0;
+            record.window_start = now;
+        }
+        record.failures += 1;
+        if record.failures < self.config.max_failures
+            || record.quarantined_until.is_some_and(|until| until > now)
+        {
+            return false;
+        }
+        record.failures = 0;
+        record.window_start = now;
+        record.quarantined_until = Some(now + self.config.duration);
+        true
+    }
+
+    /// Whether frames from `peer` are currently dropped
+    pub fn is_quarantined(&self, peer: &K, now: Instant) -> bool {
+        self.peers
+            .get(peer)
+            .and_then(|record| record.quarantined_until)
+            .is_some_and(|until| until > now)
+    }
+
+    /// Decode failures counted for `peer` in the current window
+    pub fn failures(&self, peer: &K) -> u32 {
+        self.peers.get(peer).map_or(0, |record| record.failures)
+    }
+}
@@ -0,0 +1,257 @@
+//! Per-quorum p2p channels.
+//!
+//! Multi-quorum deployments can give each quorum a channel of its own, so the shares
+//! of one quorum are rate limited and prioritized apart from the others. Quorums
+//! without a channel share a fallback channel. Shares are tagged with their quorum and
+//! a [QuorumDispatcher] hands each quorum's contributor the frames of its quorum,
+//! dropping shares tagged for a quorum the channel they arrived on does not carry.
+
+use crate::contributor::events::{EventSink, NoopEventSink};
+use crate::contributor::types::DroppedShare;
+use crate::logging::short;
+use anyhow::{Result, bail};
+use bn254::PublicKey;
+use bytes::{BufMut, Bytes};
+use commonware_p2p::{Channel, Receiver};
+use futures::StreamExt;
+use futures::stream::{self, BoxStream};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Prefix distinguishing quorum-tagged shares from untagged frames
+pub const QUORUM_TAG_MAGIC: [u8; 4] = *b"QTAG";
+
+/// Frames buffered per quorum before the dispatcher waits on its contributor
+pub const DEFAULT_QUORUM_BACKLOG: usize = 256;
+
+/// Tag a share `frame` with the quorum it is signed for
+pub fn tag_share(quorum_id: u8, frame: &[u8]) -> Bytes {
+    let mut buf = Vec::with_capacity(QUORUM_TAG_MAGIC.len() + 1 + frame.len());
+    buf.put_slice(&QUORUM_TAG_MAGIC);
+    buf.put_u8(quorum_id);
+    buf.put_slice(frame);
+    Bytes::from(buf)
+}
+
+/// Quorum and share of a tagged frame, `None` for untagged frames
+pub fn untag_share(frame: &Bytes) -> Option<(u8, Bytes)> {
+    if !frame.starts_with(&QUORUM_TAG_MAGIC) || frame.len() <= QUORUM_TAG_MAGIC.len() {
+        return None;
+    }
+    let quorum_id = *frame.get(QUORUM_TAG_MAGIC.len())?;
+    Some((quorum_id, frame.slice(QUORUM_TAG_MAGIC.len() + 1..)))
+}
+
+/// Channel of each quorum, falling back to a shared channel
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuorumChannels {
+    shared: Channel,
+    dedicated: BTreeMap<u8, Channel>,
+}
+
+impl QuorumChannels {
+    /// Every quorum on the `shared` channel
+    pub fn new(shared: Channel) -> Self {
+        Self {
+            shared,
+            dedicated: BTreeMap::new(),
+        }
+    }
+
+    /// Move `quorum_id` to a channel of its own
+    ///
+    /// Fails if `channel` is the shared channel or carries another quorum.
+    pub fn with_quorum_channel(mut self, quorum_id: u8, channel: Channel) -> Result<Self> {
+        if channel == self.shared {
+            bail!("quorum channel {channel} is the shared channel");
+        }
+        if self
+            .dedicated
+            .iter()
+            .any(|(quorum, used)| *quorum != quorum_id && *used == channel)
+        {
+            bail!("channel {channel} already assigned");
+        }
+        self.dedicated.insert(quorum_id, channel);
+        Ok(self)
+    }
+
+    /// Channel the shares of `quorum_id` are sent and received on
+    pub fn channel(&self, quorum_id: u8) -> Channel {
+        self.dedicated
+            .get(&quorum_id)
+            .copied()
+            .unwrap_or(self.shared)
+    }
+
+    /// Channel of the quorums without a dedicated one
+    pub fn shared(&self) -> Channel {
+        self.shared
+    }
+
+    /// Shared channel followed by the dedicated ones, in quorum order
+    pub fn channels(&self) -> Vec<Channel> {
+        std::iter::once(self.shared)
+            .chain(self.dedicated.values().copied())
+            .collect()
+    }
+
+    /// Whether the shares of `quorum_id` belong on `channel`
+    pub fn carries(&self, channel: Channel, quorum_id: u8) -> bool {
+        self.channel(quorum_id) == channel
+    }
+}
+
+/// Error returned by a [QuorumReceiver] once the dispatcher stopped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DispatcherClosed;
+
+impl fmt::Display for DispatcherClosed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "quorum dispatcher closed")
+    }
+}
+
+impl std::error::Error for DispatcherClosed {}
+
+/// Frames of a single quorum, handed out by a [QuorumDispatcher]
+#[derive(Debug)]
+pub struct QuorumReceiver {
+    quorum_id: u8,
+    inbox: mpsc::Receiver<(PublicKey, Bytes)>,
+}
+
+impl QuorumReceiver {
+    /// Quorum the receiver carries frames for
+    pub fn quorum_id(&self) -> u8 {
+        self.quorum_id
+    }
+}
+
+impl Receiver for QuorumReceiver {
+    type Error = DispatcherClosed;
+    type PublicKey = PublicKey;
+
+    async fn recv(&mut self) -> Result<(Self::PublicKey, Bytes), Self::Error> {
+        self.inbox.recv().await.ok_or(DispatcherClosed)
+    }
+}
+
+/// Routes frames received on the quorum channels to the contributor of each quorum
+///
+/// Tagged shares go to their quorum, untagged frames such as Starts and sync messages
+/// go to every quorum of the channel. Shares tagged for a quorum the channel does not
+/// carry are dropped and reported as [DroppedShare::MisTagged].
+pub struct QuorumDispatcher<R: Receiver<PublicKey = PublicKey>> {
+    channels: QuorumChannels,
+    receivers: Vec<(Channel, R)>,
+    routes: HashMap<u8, mpsc::Sender<(PublicKey, Bytes)>>,
+    backlog: usize,
+    events: Arc<dyn EventSink>,
+}
+
+impl<R: Receiver<PublicKey = PublicKey>> QuorumDispatcher<R> {
+    /// Dispatcher routing frames to `channels`
+    pub fn new(channels: QuorumChannels) -> Self {
+        Self {
+            channels,
+            receivers: Vec::new(),
+            routes: HashMap::new(),
+            backlog: DEFAULT_QUORUM_BACKLOG,
+            events: Arc::new(NoopEventSink),
+        }
+    }
+
+    /// Receive the frames of `channel` through `receiver`
+    pub fn with_receiver(mut self, channel: Channel, receiver: R) -> Self {
+        self.receivers.push((channel, receiver));
+        self
+    }
+
+    /// Frames buffered per quorum before waiting on its contributor
+    pub fn with_backlog(mut self, backlog: usize) -> Self {
+        self.backlog = backlog;
+        self
+    }
+
+    /// Report mis-tagged shares to `events`
+    pub fn with_event_sink(mut self, events: Arc<dyn EventSink>) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Receiver of the frames of `quorum_id`, to run its contributor on
+    pub fn quorum_receiver(&mut self, quorum_id: u8) -> QuorumReceiver {
+        let (sender, inbox) = mpsc::channel(self.backlog);
+        self.routes.insert(quorum_id, sender);
+        QuorumReceiver { quorum_id, inbox }
+    }
+
+    /// Dispatch until every channel closed or every quorum receiver was dropped
+    pub async fn run(mut self) -> Result<()> {
+        let receivers: Vec<BoxStream<'static, (Channel, PublicKey, Bytes)>> =
+            std::mem::take(&mut self.receivers)
+                .into_iter()
+                .map(|(channel, receiver)| {
+                    stream::unfold(receiver, move |mut receiver| async move {
+                        match receiver.recv().await {
+                            Ok((sender, frame)) => Some(((channel, sender, frame), receiver)),
+                            Err(err) => {
+                                warn!(channel, ?err, "quorum channel closed");
+                                None
+                            }
+                        }
+                    })
+                    .boxed()
+                })
+                .collect();
+        let mut frames = stream::select_all(receivers);
+        while !self.routes.is_empty() {
+            let Some((channel, sender, frame)) = frames.next().await else {
+                break;
+            };
+            self.dispatch(channel, sender, frame).await;
+        }
+        Ok(())
+    }
+
+    /// Hand a frame received on `channel` to the quorums it is for
+    async fn dispatch(&mut self, channel: Channel, sender: PublicKey, frame: Bytes) {
+        let (quorums, frame): (Vec<u8>, Bytes) = match untag_share(&frame) {
+            Some((quorum_id, share)) => {
+                if !self.channels.carries(channel, quorum_id) {
+                    debug!(
+                        channel,
+                        quorum_id,
+                        sender = %short(&sender),
+                        "dropping mis-tagged share"
+                    );
+                    self.events
+                        .share_dropped(quorum_id, DroppedShare::MisTagged.kind());
+                    return;
+                }
+                (vec![quorum_id], share)
+            }
+            None => (
+                self.routes
+                    .keys()
+                    .copied()
+                    .filter(|quorum_id| self.channels.carries(channel, *quorum_id))
+                    .collect(),
+                frame,
+            ),
+        };
+        for quorum_id in quorums {
+            let Some(route) = self.routes.get(&quorum_id) else {
+                continue;
+            };
+            if route.send((sender.clone(), frame.clone())).await.is_err() {
+                debug!(quorum_id, "quorum receiver dropped");
+                self.routes.remove(&quorum_id);
+            }
+        }
+    }
+}
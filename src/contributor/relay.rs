@@ -0,0 +1,196 @@
+//! Forwarding of signature shares between contributors that cannot reach each other.
+//!
+//! Contributors may reach the orchestrator but not every peer, so a node aggregating
+//! locally never sees the shares of the peers it is cut off from. With relaying
+//! enabled, a node that verified a share forwards the frame it received to the
+//! contributors it has no share from for that round, wrapped in a [ForwardedShare]
+//! naming the signer and the forwarder. The signed frame is carried unmodified, so
+//! receivers verify it against the signer, never the forwarder.
+//!
+//! A [ShareRelay] forwards each signer's share at most once per round, and at most
+//! [RelayConfig::budget_per_round] frames per round, so relayed shares cannot loop
+//! between relaying peers.
+
+use bn254::PublicKey;
+use bytes::{Buf, BufMut, Bytes};
+use commonware_codec::{EncodeSize, Error, Read, ReadExt, Write};
+use std::collections::{BTreeMap, HashSet};
+use std::hash::Hash;
+
+/// Prefix distinguishing forwarded shares from aggregation frames
+pub const FORWARDED_SHARE_MAGIC: [u8; 4] = *b"FWDS";
+
+/// Upper bound on the share frame carried by a [ForwardedShare]
+pub const MAX_SHARE_LEN: usize = 64 * 1024;
+
+/// Upper bound on an encoded public key
+const MAX_KEY_LEN: usize = 256;
+
+/// Share frame of `origin` forwarded by `forwarder`
+///
+/// Only `share` is signed, the keys around it are the forwarded-by annotation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForwardedShare {
+    /// Contributor that signed the share
+    pub origin: PublicKey,
+    /// Contributor relaying the share
+    pub forwarder: PublicKey,
+    /// Signature frame as the signer sent it
+    pub share: Bytes,
+}
+
+impl ForwardedShare {
+    /// Whether a raw frame is a forwarded share
+    pub fn is_forwarded_share(frame: &[u8]) -> bool {
+        frame.starts_with(&FORWARDED_SHARE_MAGIC)
+    }
+}
+
+fn write_key(key: &PublicKey, buf: &mut impl BufMut) {
+    let key = &key[..];
+    (key.len() as u16).write(buf);
+    buf.put_slice(key);
+}
+
+fn read_key(buf: &mut impl Buf) -> Result<PublicKey, Error> {
+    let len = u16::read(buf)? as usize;
+    if len > MAX_KEY_LEN {
+        return Err(Error::InvalidLength(len));
+    }
+    if buf.remaining() < len {
+        return Err(Error::EndOfBuffer);
+    }
+    let mut key = vec![0; len];
+    buf.copy_to_slice(&mut key);
+    PublicKey::try_from(key).map_err(|_| Error::Invalid("ForwardedShare", "invalid public key"))
+}
+
+impl Write for ForwardedShare {
+    fn write(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&FORWARDED_SHARE_MAGIC);
+        write_key(&self.origin, buf);
+        write_key(&self.forwarder, buf);
+        (self.share.len() as u32).write(buf);
+        buf.put_slice(&self.share);
+    }
+}
+
+impl EncodeSize for ForwardedShare {
+    fn encode_size(&self) -> usize {
+        FORWARDED_SHARE_MAGIC.len()
+            + 2
+            + self.origin[..].len()
+            + 2
+            + self.forwarder[..].len()
+            + 4
+            + self.share.len()
+    }
+}
+
+impl Read for ForwardedShare {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _: &()) -> Result<Self, Error> {
+        let magic = <[u8; 4]>::read(buf)?;
+        if magic != FORWARDED_SHARE_MAGIC {
+            return Err(Error::Invalid(
+                "ForwardedShare",
+                "missing forwarded share prefix",
+            ));
+        }
+        let origin = read_key(buf)?;
+        let forwarder = read_key(buf)?;
+        let len = u32::read(buf)? as usize;
+        if len > MAX_SHARE_LEN {
+            return Err(Error::InvalidLength(len));
+        }
+        if buf.remaining() < len {
+            return Err(Error::EndOfBuffer);
+        }
+        let share = buf.copy_to_bytes(len);
+        Ok(Self {
+            origin,
+            forwarder,
+            share,
+        })
+    }
+}
+
+/// Configuration of [ShareRelay]
+#[derive(Clone, Debug)]
+pub struct RelayConfig {
+    /// Frames forwarded per round, across all signers
+    pub budget_per_round: usize,
+    /// Rounds remembered, the lowest are forgotten beyond that
+    pub max_rounds: usize,
+}
+
+impl Default for RelayConfig {
+    fn default() -> Self {
+        Self {
+            budget_per_round: 64,
+            max_rounds: 256,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RoundRelay<K> {
+    /// Signers whose share was already forwarded
+    origins: HashSet<K>,
+    /// Frames forwarded so far
+    sent: usize,
+}
+
+/// Shares forwarded per round, bounding what a node relays
+#[derive(Debug)]
+pub struct ShareRelay<K> {
+    config: RelayConfig,
+    rounds: BTreeMap<u64, RoundRelay<K>>,
+}
+
+impl<K: Clone + Eq + Hash> ShareRelay<K> {
+    /// Relay that has forwarded nothing yet
+    pub fn new(config: RelayConfig) -> Self {
+        Self {
+            config,
+            rounds: BTreeMap::new(),
+        }
+    }
+
+    /// Peers among `silent` to forward the share of `origin` for `round` to
+    ///
+    /// Empty once the share of `origin` was forwarded for the round, and cut to the
+    /// budget left for the round otherwise.
+    pub fn forward(
+        &mut self,
+        round: u64,
+        origin: &K,
+        silent: impl IntoIterator<Item = K>,
+    ) -> Vec<K> {
+        let relay = self.rounds.entry(round).or_insert_with(|| RoundRelay {
+            origins: HashSet::new(),
+            sent: 0,
+        });
+        if !relay.origins.insert(origin.clone()) {
+            return Vec::new();
+        }
+        let left = self.config.budget_per_round.saturating_sub(relay.sent);
+        let peers: Vec<K> = silent.into_iter().take(left).collect();
+        relay.sent += peers.len();
+        while self.rounds.len() > self.config.max_rounds.max(1) {
+            self.rounds.pop_first();
+        }
+        peers
+    }
+
+    /// Frames forwarded for `round`
+    pub fn forwarded(&self, round: u64) -> usize {
+        self.rounds.get(&round).map_or(0, |relay| relay.sent)
+    }
+
+    /// Forget every round
+    pub fn clear(&mut self) {
+        self.rounds.clear();
+    }
+}
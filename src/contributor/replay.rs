@@ -0,0 +1,403 @@
+//! Capture of the traffic seen by a contributor and deterministic replay of it.
+//!
+//! A [Capture] wraps the receiver, sender and validator factory of a running
+//! contributor and records every frame received, every frame sent and the hash
+//! validated for each round. [replay] feeds the received frames through a fresh
+//! [Contributor] that returns the recorded hashes instead of reading the chain and
+//! whose clock is frozen at the start of the capture, reporting what it sent.
+//!
+//! Durations (validation time, signing timeouts) follow the tokio clock, so a
+//! replay run on a paused runtime is fully deterministic.
+
+use crate::clock::MockClock;
+use crate::contributor::events::ThresholdCounter;
+use crate::contributor::{AggregationInput, Contribute, OutboundRouter};
+use crate::handlers::{Contributor, RunConfig};
+use crate::validation::metadata::{MetadataPolicy, MetadataReader};
+use crate::validation::{PayloadValidator, ValidatorFactory};
+use anyhow::{Result, anyhow, ensure};
+use bn254::{Bn254, PublicKey as PubKey};
+use bytes::Bytes;
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::wire::{self, aggregation::Payload};
+use commonware_codec::ReadExt;
+use commonware_p2p::{Receiver, Recipients, Sender};
+use futures::future::BoxFuture;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::SystemTime;
+
+/// Frame received by a contributor, with its sender
+pub type Frame = (PubKey, Bytes);
+
+/// Recipients of a sent frame
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SentTo {
+    /// Every peer
+    All,
+    /// The listed peers
+    Some(Vec<PubKey>),
+    /// A single peer
+    One(PubKey),
+}
+
+/// Record the recipients of a frame, passing them on unchanged
+fn split(recipients: Recipients<PubKey>) -> (SentTo, Recipients<PubKey>) {
+    match recipients {
+        Recipients::All => (SentTo::All, Recipients::All),
+        Recipients::Some(keys) => (SentTo::Some(keys.clone()), Recipients::Some(keys)),
+        Recipients::One(key) => (SentTo::One(key.clone()), Recipients::One(key)),
+    }
+}
+
+/// Round of an encoded aggregation message
+fn round_of(message: &[u8]) -> Option<u64> {
+    wire::Aggregation::<CounterTaskData>::read(&mut std::io::Cursor::new(message))
+        .ok()
+        .map(|message| message.round)
+}
+
+/// Rounds a contributor sent a signature for
+pub fn signed_rounds(sent: &[(SentTo, Bytes)]) -> BTreeSet<u64> {
+    sent.iter()
+        .filter_map(|(_, frame)| {
+            wire::Aggregation::<CounterTaskData>::read(&mut std::io::Cursor::new(frame.clone()))
+                .ok()
+        })
+        .filter(|message| matches!(message.payload, Some(Payload::Signature(_))))
+        .map(|message| message.round)
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct Recorded {
+    received: Vec<Frame>,
+    sent: Vec<(SentTo, Bytes)>,
+    hashes: BTreeMap<u64, [u8; 32]>,
+}
+
+/// Records the traffic and validations of a contributor
+///
+/// Cloning is cheap and clones record into the same capture.
+#[derive(Clone, Debug, Default)]
+pub struct Capture(Arc<Mutex<Recorded>>);
+
+impl Capture {
+    /// Empty capture
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record every frame received through `receiver`
+    pub fn receiver<R>(&self, receiver: R) -> CapturingReceiver<R> {
+        CapturingReceiver {
+            inner: receiver,
+            capture: self.clone(),
+        }
+    }
+
+    /// Record every frame sent through `sender`
+    pub fn sender<S>(&self, sender: S) -> CapturingSender<S> {
+        CapturingSender {
+            inner: sender,
+            capture: self.clone(),
+        }
+    }
+
+    /// Record the hash of every successful validation by validators of `factory`
+    pub fn validator_factory(
+        &self,
+        factory: Arc<dyn ValidatorFactory>,
+    ) -> Arc<dyn ValidatorFactory> {
+        Arc::new(RecordingValidatorFactory {
+            inner: factory,
+            capture: self.clone(),
+        })
+    }
+
+    /// Frames received so far, in order
+    pub fn frames(&self) -> Vec<Frame> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .received
+            .clone()
+    }
+
+    /// Frames sent so far, in order
+    pub fn sent(&self) -> Vec<(SentTo, Bytes)> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .sent
+            .clone()
+    }
+
+    /// Hash validated for each round
+    pub fn hashes(&self) -> BTreeMap<u64, [u8; 32]> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .hashes
+            .clone()
+    }
+}
+
+/// Receiver recording the frames it yields into a [Capture]
+#[derive(Debug)]
+pub struct CapturingReceiver<R> {
+    inner: R,
+    capture: Capture,
+}
+
+impl<R: Receiver<PublicKey = PubKey>> Receiver for CapturingReceiver<R> {
+    type Error = R::Error;
+    type PublicKey = PubKey;
+
+    async fn recv(&mut self) -> Result<Frame, Self::Error> {
+        let frame = self.inner.recv().await?;
+        self.capture
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .received
+            .push(frame.clone());
+        Ok(frame)
+    }
+}
+
+/// Sender recording the frames it sends into a [Capture]
+#[derive(Clone, Debug)]
+pub struct CapturingSender<S> {
+    inner: S,
+    capture: Capture,
+}
+
+impl<S: Sender<PublicKey = PubKey>> Sender for CapturingSender<S> {
+    type Error = S::Error;
+    type PublicKey = PubKey;
+
+    async fn send(
+        &mut self,
+        recipients: Recipients<Self::PublicKey>,
+        message: Bytes,
+        priority: bool,
+    ) -> Result<Vec<Self::PublicKey>, Self::Error> {
+        let (sent_to, recipients) = split(recipients);
+        self.capture
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .sent
+            .push((sent_to, message.clone()));
+        self.inner.send(recipients, message, priority).await
+    }
+}
+
+struct RecordingValidatorFactory {
+    inner: Arc<dyn ValidatorFactory>,
+    capture: Capture,
+}
+
+impl ValidatorFactory for RecordingValidatorFactory {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        Box::pin(async move {
+            let validator: Arc<dyn PayloadValidator> = Arc::new(RecordingValidator {
+                inner: self.inner.build().await?,
+                capture: self.capture.clone(),
+            });
+            Ok(validator)
+        })
+    }
+}
+
+struct RecordingValidator {
+    inner: Arc<dyn PayloadValidator>,
+    capture: Capture,
+}
+
+impl PayloadValidator for RecordingValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move {
+            let hash = self.inner.validate(message).await?;
+            if let Some(round) = round_of(message) {
+                self.capture
+                    .0
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .hashes
+                    .insert(round, hash);
+            }
+            Ok(hash)
+        })
+    }
+}
+
+/// Validator returning the hashes recorded by a [Capture]
+#[derive(Clone, Debug, Default)]
+pub struct RecordedValidator {
+    hashes: BTreeMap<u64, [u8; 32]>,
+    /// Rounds validated without a recorded hash, shared by clones
+    missing: Arc<Mutex<BTreeSet<u64>>>,
+}
+
+impl RecordedValidator {
+    /// Validator returning the recorded hash of each round
+    pub fn new(hashes: BTreeMap<u64, [u8; 32]>) -> Self {
+        Self {
+            hashes,
+            missing: Arc::default(),
+        }
+    }
+
+    /// Rounds validated so far that had no recorded hash
+    pub fn missing(&self) -> BTreeSet<u64> {
+        self.missing
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl PayloadValidator for RecordedValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move {
+            let round = round_of(message).ok_or_else(|| anyhow!("undecodable message"))?;
+            let hash = self.hashes.get(&round).copied();
+            if hash.is_none() {
+                self.missing
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(round);
+            }
+            hash.ok_or_else(|| anyhow!("no recorded hash for round {round}"))
+        })
+    }
+}
+
+impl ValidatorFactory for RecordedValidator {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        let validator: Arc<dyn PayloadValidator> = Arc::new(self.clone());
+        Box::pin(async move { Ok(validator) })
+    }
+}
+
+/// Error of the replay network once every captured frame was delivered
+#[derive(Debug)]
+pub struct ReplayFinished;
+
+impl fmt::Display for ReplayFinished {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "replay finished")
+    }
+}
+
+impl std::error::Error for ReplayFinished {}
+
+/// Receiver yielding captured frames, then closing
+#[derive(Debug)]
+struct ReplayReceiver {
+    frames: VecDeque<Frame>,
+}
+
+impl Receiver for ReplayReceiver {
+    type Error = ReplayFinished;
+    type PublicKey = PubKey;
+
+    async fn recv(&mut self) -> Result<Frame, Self::Error> {
+        // Let signatures ready by now go out before the next frame, as they would live
+        tokio::task::yield_now().await;
+        self.frames.pop_front().ok_or(ReplayFinished)
+    }
+}
+
+/// Sender dropping every frame
+#[derive(Clone, Debug)]
+struct NullSender;
+
+impl Sender for NullSender {
+    type Error = ReplayFinished;
+    type PublicKey = PubKey;
+
+    async fn send(
+        &mut self,
+        _recipients: Recipients<Self::PublicKey>,
+        _message: Bytes,
+        _priority: bool,
+    ) -> Result<Vec<Self::PublicKey>, Self::Error> {
+        Ok(Vec::new())
+    }
+}
+
+/// Contributor setup a capture is replayed against
+pub struct ReplayConfig {
+    /// Orchestrator of the captured contributor
+    pub orchestrator: PubKey,
+    /// Key of the captured contributor, which must be one of `contributors`
+    pub signer: Bn254,
+    /// Contributor set of the capture
+    pub contributors: Vec<PubKey>,
+    /// Aggregation data, if the captured contributor aggregated
+    pub aggregation: Option<AggregationInput>,
+    /// Quorum the capture was taken on
+    pub quorum_id: u8,
+    /// Metadata policy the captured contributor enforced, if any
+    pub metadata_policy: Option<(MetadataPolicy, MetadataReader)>,
+    /// Hash validated for each round, see [Capture::hashes]
+    pub hashes: BTreeMap<u64, [u8; 32]>,
+    /// Wall-clock time when the capture started
+    pub start_time: SystemTime,
+}
+
+/// What a contributor did when replaying a capture
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayOutcome {
+    /// Frames sent, in order
+    pub sent: Vec<(SentTo, Bytes)>,
+    /// Rounds we signed
+    pub signed_rounds: BTreeSet<u64>,
+    /// Rounds that reached the aggregation threshold
+    pub aggregated: u64,
+}
+
+/// Feed captured `frames` through a fresh contributor and report what it did
+pub async fn replay(frames: Vec<Frame>, config: ReplayConfig) -> Result<ReplayOutcome> {
+    let capture = Capture::new();
+    let aggregated = ThresholdCounter::new();
+    let validator = RecordedValidator::new(config.hashes);
+    let contributor = Contributor::new(
+        config.orchestrator,
+        config.signer,
+        config.contributors,
+        config.aggregation,
+    )?
+    .with_quorum(config.quorum_id)
+    .with_event_sink(Arc::new(aggregated.clone()))
+    .with_validator_factory(Arc::new(validator.clone()))
+    .with_clock(Arc::new(MockClock::new(config.start_time)))
+    .with_run_config(RunConfig {
+        metadata_policy: config.metadata_policy,
+        ..RunConfig::default()
+    });
+
+    let receiver = ReplayReceiver {
+        frames: frames.into(),
+    };
+    contributor
+        .run(OutboundRouter::single(capture.sender(NullSender)), receiver)
+        .await?;
+    // A Start failing validation is skipped by the contributor, not an error
+    let missing = validator.missing();
+    ensure!(
+        missing.is_empty(),
+        "no recorded hash for rounds {missing:?}"
+    );
+
+    let sent = capture.sent();
+    Ok(ReplayOutcome {
+        signed_rounds: signed_rounds(&sent),
+        sent,
+        aggregated: aggregated.get(),
+    })
+}
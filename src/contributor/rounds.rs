@@ -0,0 +1,476 @@
+//! State of the rounds a contributor takes part in.
+//!
+//! A round is started once its Start is validated, signed once our signature is
+//! produced, and aggregated once its shares reach the threshold. It stops accepting
+//! shares once aggregated, when its deadline passes or its response is submitted
+//! on-chain. The
+//! [RoundTable] applies these transitions, so both handlers agree on when a share
+//! is accepted and a round is never signed twice.
+//!
+//! ```text
+//! Signing    -> Signed | Aborted | Aggregated | Expired | Retired
+//! Signed     -> Aggregated | Expired | Retired
+//! Aborted    -> Aggregated | Expired | Retired
+//! Aggregated -> Expired | Retired
+//! Expired    -> Retired
+//! ```
+//!
+//! An aggregated round is aggregated once: shares arriving past the threshold are
+//! dropped. Any other move, such as signing an expired round or aggregating a round
+//! again, is refused with an [InvalidTransition].
+
+use crate::clock::{Clock, SystemClock};
+use crate::contributor::final_aggregate::AggregateCheck;
+use crate::contributor::types::{DroppedShare, ParticipationBitmap};
+use bn254::Signature as Sig;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Stage of a round
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundStatus {
+    /// Started, our signature is being produced
+    Signing,
+    /// Our signature was produced
+    Signed,
+    /// Our signature could not be produced, shares of peers are still collected
+    Aborted,
+    /// Shares reached the threshold and were aggregated, later shares are dropped
+    Aggregated,
+    /// The deadline passed, shares are dropped
+    Expired,
+    /// The response was submitted on-chain, the round is never signed again
+    Retired,
+}
+
+impl RoundStatus {
+    /// Whether a round may move from this status to `next`
+    pub fn can_become(self, next: RoundStatus) -> bool {
+        match next {
+            RoundStatus::Signing => false,
+            RoundStatus::Signed | RoundStatus::Aborted => self == RoundStatus::Signing,
+            RoundStatus::Aggregated => !matches!(
+                self,
+                RoundStatus::Aggregated | RoundStatus::Expired | RoundStatus::Retired
+            ),
+            RoundStatus::Expired => !matches!(self, RoundStatus::Expired | RoundStatus::Retired),
+            RoundStatus::Retired => self != RoundStatus::Retired,
+        }
+    }
+}
+
+impl fmt::Display for RoundStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            RoundStatus::Signing => "signing",
+            RoundStatus::Signed => "signed",
+            RoundStatus::Aborted => "aborted",
+            RoundStatus::Aggregated => "aggregated",
+            RoundStatus::Expired => "expired",
+            RoundStatus::Retired => "retired",
+        };
+        f.write_str(status)
+    }
+}
+
+/// A round was asked to move to a status it cannot reach from its current one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidTransition {
+    /// Status the round was in
+    pub from: RoundStatus,
+    /// Status the round was moved to
+    pub to: RoundStatus,
+}
+
+impl fmt::Display for InvalidTransition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "round cannot move from {} to {}", self.from, self.to)
+    }
+}
+
+impl Error for InvalidTransition {}
+
+/// State of a round
+#[derive(Clone, Debug)]
+pub struct RoundState {
+    /// Payload hash of the Start, `None` for rounds retired before being started
+    pub expected_hash: Option<[u8; 32]>,
+    /// End of the time the round accepts shares
+    pub deadline: Option<Instant>,
+    /// Quorum epoch the round belongs to, if tracked
+    pub epoch: Option<u64>,
+    /// Reference block of the round's task, if known
+    pub start_block: Option<u64>,
+    /// Our own share, once produced
+    pub our_signature: Option<Sig>,
+    /// Verified shares by contributor index, ours included
+    pub shares: BTreeMap<usize, Sig>,
+    /// Whether the expected hash is the digest of our own executed response, shares
+    /// then being tagged with the digest they sign
+    pub executed: bool,
+    /// Digest each share signs by contributor index, in executed rounds
+    pub share_digests: BTreeMap<usize, [u8; 32]>,
+    /// Latest aggregate of the shares, with its signers
+    pub aggregate: Option<(Sig, ParticipationBitmap)>,
+    /// How the final aggregate broadcast by the orchestrator compared with ours
+    pub final_aggregate: Option<AggregateCheck>,
+    /// Where the round is in its lifecycle
+    pub status: RoundStatus,
+    /// When the round was started, until its aggregation latency is taken
+    started: Option<Instant>,
+}
+
+impl RoundState {
+    fn new(status: RoundStatus) -> Self {
+        Self {
+            expected_hash: None,
+            deadline: None,
+            epoch: None,
+            start_block: None,
+            our_signature: None,
+            shares: BTreeMap::new(),
+            executed: false,
+            share_digests: BTreeMap::new(),
+            aggregate: None,
+            final_aggregate: None,
+            status,
+            started: None,
+        }
+    }
+
+    /// Move the round to `next`, refusing moves the state machine does not allow
+    pub fn transition(&mut self, next: RoundStatus) -> Result<(), InvalidTransition> {
+        if !self.status.can_become(next) {
+            return Err(InvalidTransition {
+                from: self.status,
+                to: next,
+            });
+        }
+        self.status = next;
+        Ok(())
+    }
+
+    /// Whether the round is neither expired nor retired
+    pub fn is_open(&self) -> bool {
+        !matches!(self.status, RoundStatus::Expired | RoundStatus::Retired)
+    }
+
+    /// Whether the round is still being signed or collecting shares
+    pub fn is_active(&self) -> bool {
+        matches!(self.status, RoundStatus::Signing | RoundStatus::Signed)
+    }
+
+    /// Shares signing `digest`, which is every share outside executed rounds
+    pub fn shares_over(&self, digest: &[u8; 32]) -> BTreeMap<usize, Sig> {
+        self.shares
+            .iter()
+            .filter(|(index, _)| !self.executed || self.share_digests.get(index) == Some(digest))
+            .map(|(index, share)| (*index, share.clone()))
+            .collect()
+    }
+}
+
+/// Reason a share is not recorded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShareRejection {
+    /// The share was dropped before verification
+    Dropped(DroppedShare),
+    /// The contributor already has a share in the round
+    Duplicate,
+}
+
+/// Rounds of a contributor by round number
+pub struct RoundTable {
+    rounds: HashMap<u64, RoundState>,
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for RoundTable {
+    fn default() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+}
+
+impl RoundTable {
+    /// Table with no round
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Table measuring aggregation latency with `clock`
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            rounds: HashMap::new(),
+            clock,
+        }
+    }
+
+    /// Start `round` for the validated `expected_hash`
+    ///
+    /// Returns `false` if the round was already started or retired, it must not be
+    /// signed again.
+    pub fn start_round(
+        &mut self,
+        round: u64,
+        expected_hash: [u8; 32],
+        deadline: Option<Instant>,
+    ) -> bool {
+        if self.rounds.contains_key(&round) {
+            return false;
+        }
+        let mut state = RoundState::new(RoundStatus::Signing);
+        state.expected_hash = Some(expected_hash);
+        state.deadline = deadline;
+        state.started = Some(self.clock.monotonic_now());
+        self.rounds.insert(round, state);
+        true
+    }
+
+    /// Whether `round` was signed, is being signed, or was retired
+    pub fn is_signed(&self, round: u64) -> bool {
+        self.rounds.contains_key(&round)
+    }
+
+    /// Whether `round` was retired
+    pub fn is_retired(&self, round: u64) -> bool {
+        self.status(round) == Some(RoundStatus::Retired)
+    }
+
+    /// Status of `round`, if tracked
+    pub fn status(&self, round: u64) -> Option<RoundStatus> {
+        self.rounds.get(&round).map(|state| state.status)
+    }
+
+    /// State of `round`, if tracked
+    pub fn get(&self, round: u64) -> Option<&RoundState> {
+        self.rounds.get(&round)
+    }
+
+    /// Mutable state of `round`, if tracked
+    pub fn get_mut(&mut self, round: u64) -> Option<&mut RoundState> {
+        self.rounds.get_mut(&round)
+    }
+
+    /// Whether the deadline of `round` passed at `now`
+    pub fn deadline_passed(&self, round: u64, now: Instant) -> bool {
+        self.rounds
+            .get(&round)
+            .and_then(|state| state.deadline)
+            .is_some_and(|deadline| deadline <= now)
+    }
+
+    /// Record our signature of `round` as the share of contributor `me`
+    ///
+    /// Returns `false` if the round is unknown, no longer open or was aborted.
+    pub fn record_own_signature(&mut self, round: u64, me: usize, signature: Sig) -> bool {
+        let Some(state) = self.rounds.get_mut(&round).filter(|state| state.is_open()) else {
+            return false;
+        };
+        // Peers' shares may have aggregated the round before our signature was produced
+        match state.transition(RoundStatus::Signed) {
+            Ok(()) => {}
+            Err(_) if state.status == RoundStatus::Aggregated => {}
+            Err(_) => return false,
+        }
+        state.shares.insert(me, signature.clone());
+        state.our_signature = Some(signature);
+        if state.executed
+            && let Some(hash) = state.expected_hash
+        {
+            state.share_digests.insert(me, hash);
+        }
+        true
+    }
+
+    /// Mark `round` as signing the digest of our executed response, see
+    /// [RoundState::executed]
+    ///
+    /// Returns `false` if the round is unknown.
+    pub fn mark_executed(&mut self, round: u64) -> bool {
+        let Some(state) = self.rounds.get_mut(&round) else {
+            return false;
+        };
+        state.executed = true;
+        true
+    }
+
+    /// Abort `round` once our signature could not be produced
+    ///
+    /// Returns `false` if the round is unknown or no longer being signed.
+    pub fn abort(&mut self, round: u64) -> bool {
+        let Some(state) = self.rounds.get_mut(&round) else {
+            return false;
+        };
+        state.transition(RoundStatus::Aborted).is_ok()
+    }
+
+    /// Whether a share of contributor `index` for `round` would be recorded at `now`
+    ///
+    /// A round whose deadline passed expires, dropping its shares. An aggregated round
+    /// drops every later share.
+    pub fn check_share(
+        &mut self,
+        round: u64,
+        index: usize,
+        now: Instant,
+    ) -> Result<(), ShareRejection> {
+        if self.deadline_passed(round, now) {
+            self.expire(round);
+        }
+        let Some(state) = self.rounds.get(&round) else {
+            return Err(ShareRejection::Dropped(DroppedShare::UnknownRound));
+        };
+        match state.status {
+            RoundStatus::Expired => Err(ShareRejection::Dropped(DroppedShare::DeadlinePassed)),
+            RoundStatus::Retired => Err(ShareRejection::Dropped(DroppedShare::CompletedOnChain)),
+            RoundStatus::Aggregated => Err(ShareRejection::Dropped(DroppedShare::Aggregated)),
+            _ if state.shares.contains_key(&index) => Err(ShareRejection::Duplicate),
+            _ => Ok(()),
+        }
+    }
+
+    /// Record a verified share of contributor `index` for `round`
+    pub fn record_share(
+        &mut self,
+        round: u64,
+        index: usize,
+        signature: Sig,
+        now: Instant,
+    ) -> Result<&mut RoundState, ShareRejection> {
+        self.check_share(round, index, now)?;
+        let state = self
+            .rounds
+            .get_mut(&round)
+            .ok_or(ShareRejection::Dropped(DroppedShare::UnknownRound))?;
+        state.shares.insert(index, signature);
+        Ok(state)
+    }
+
+    /// Mark `round` aggregated, returning the time since it started the first time
+    pub fn mark_aggregated(&mut self, round: u64) -> Option<Duration> {
+        let state = self.rounds.get_mut(&round)?;
+        // Closed rounds keep their status, the latency is still taken once
+        let _ = state.transition(RoundStatus::Aggregated);
+        let started = state.started.take()?;
+        Some(self.clock.elapsed(started))
+    }
+
+    /// Keep the aggregate of `round` signed by `signers`, replacing the previous one
+    pub fn record_aggregate(&mut self, round: u64, signature: Sig, signers: ParticipationBitmap) {
+        if let Some(state) = self.rounds.get_mut(&round) {
+            state.aggregate = Some((signature, signers));
+        }
+    }
+
+    /// Record how the final aggregate of `round` compared with ours
+    ///
+    /// Returns `false` if the round is unknown.
+    pub fn record_final_aggregate(&mut self, round: u64, check: AggregateCheck) -> bool {
+        let Some(state) = self.rounds.get_mut(&round) else {
+            return false;
+        };
+        state.final_aggregate = Some(check);
+        true
+    }
+
+    /// Stop `round` from accepting shares after its deadline, dropping them
+    pub fn expire(&mut self, round: u64) {
+        if let Some(state) = self.rounds.get_mut(&round)
+            && state.transition(RoundStatus::Expired).is_ok()
+        {
+            state.shares.clear();
+            state.share_digests.clear();
+            state.started = None;
+        }
+    }
+
+    /// Retire `round` once its response was submitted on-chain
+    ///
+    /// Rounds never started are retired too, so they are never signed. Returns the
+    /// number of shares dropped, or `None` if the round was already retired.
+    pub fn retire(&mut self, round: u64) -> Option<usize> {
+        let state = self
+            .rounds
+            .entry(round)
+            .or_insert_with(|| RoundState::new(RoundStatus::Signing));
+        state.transition(RoundStatus::Retired).ok()?;
+        state.started = None;
+        state.deadline = None;
+        let dropped = state.shares.len();
+        state.shares.clear();
+        state.share_digests.clear();
+        Some(dropped)
+    }
+
+    /// Move every share to the index `reindex` maps it to, dropping unmapped ones
+    pub fn reindex(&mut self, reindex: impl Fn(usize) -> Option<usize>) {
+        for state in self.rounds.values_mut() {
+            state.shares = std::mem::take(&mut state.shares)
+                .into_iter()
+                .filter_map(|(index, signature)| Some((reindex(index)?, signature)))
+                .collect();
+            state.share_digests = std::mem::take(&mut state.share_digests)
+                .into_iter()
+                .filter_map(|(index, digest)| Some((reindex(index)?, digest)))
+                .collect();
+        }
+    }
+
+    /// Forget every round
+    pub fn clear(&mut self) {
+        self.rounds.clear();
+    }
+
+    /// Number of rounds signed, being signed, or retired
+    pub fn len(&self) -> usize {
+        self.rounds.len()
+    }
+
+    /// Whether no round is tracked
+    pub fn is_empty(&self) -> bool {
+        self.rounds.is_empty()
+    }
+
+    /// Number of rounds started and neither aggregated, expired nor retired
+    pub fn active(&self) -> usize {
+        self.rounds
+            .values()
+            .filter(|state| state.is_active())
+            .count()
+    }
+
+    /// Active rounds whose deadline passed at `now`
+    pub fn overdue(&self, now: Instant) -> Vec<u64> {
+        self.rounds
+            .iter()
+            .filter(|(_, state)| state.is_active())
+            .filter(|(_, state)| state.deadline.is_some_and(|deadline| deadline <= now))
+            .map(|(round, _)| *round)
+            .collect()
+    }
+
+    /// Number of rounds neither expired nor retired
+    pub fn open(&self) -> usize {
+        self.rounds.values().filter(|state| state.is_open()).count()
+    }
+
+    /// Number of started rounds not aggregated yet
+    pub fn awaiting_aggregation(&self) -> usize {
+        self.rounds
+            .values()
+            .filter(|state| state.started.is_some())
+            .count()
+    }
+
+    /// Number of retired rounds still tracked
+    pub fn retired(&self) -> usize {
+        self.rounds
+            .values()
+            .filter(|state| state.status == RoundStatus::Retired)
+            .count()
+    }
+}
@@ -0,0 +1,139 @@
+//! Routing of outbound frames to the peers that should receive them.
+
+use anyhow::Result;
+use bytes::Bytes;
+use commonware_p2p::{Recipients, Sender};
+use std::collections::HashMap;
+
+/// Class of an outbound message, used to pick the sender and destination
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MessageClass {
+    /// Signature returned to the orchestrator that issued the Start
+    Reply,
+    /// Signature share for peers performing aggregation
+    Share,
+    /// Peer's signature share relayed to a contributor silent for the round
+    Forward,
+    /// Request for a summary of rounds missed while partitioned
+    SyncRequest,
+    /// Answer to a peer's sync request
+    SyncResponse,
+    /// Start broadcast by a contributor promoted to orchestrator
+    Start,
+    /// Aggregate returned to the orchestrator that started the round
+    Aggregate,
+}
+
+/// Destination of an outbound message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Destination {
+    /// Only the orchestrator of the round
+    Orchestrator,
+    /// Only the peer the message answers or is forwarded to
+    Peer,
+    /// All connected peers (including the orchestrator)
+    All,
+}
+
+/// Sender index and destination for a message class
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Route {
+    /// Index of the contributor sending
+    pub sender: usize,
+    /// Where the frame goes
+    pub destination: Destination,
+}
+
+/// Maps outbound message classes to a concrete sender and destination
+pub struct OutboundRouter<S: Sender> {
+    senders: Vec<S>,
+    routes: HashMap<MessageClass, Route>,
+}
+
+impl<S: Sender> OutboundRouter<S> {
+    /// Create a router over the given senders without any routes
+    pub fn new(senders: Vec<S>) -> Self {
+        Self {
+            senders,
+            routes: HashMap::new(),
+        }
+    }
+
+    /// Create a router that broadcasts shares to all peers over a single sender.
+    ///
+    /// Replies have no route of their own since the orchestrator is reached by the
+    /// share broadcast.
+    pub fn single(sender: S) -> Self {
+        let mut router = Self::new(vec![sender]);
+        for (class, destination) in [
+            (MessageClass::Share, Destination::All),
+            (MessageClass::Forward, Destination::Peer),
+            (MessageClass::SyncRequest, Destination::All),
+            (MessageClass::SyncResponse, Destination::Peer),
+            (MessageClass::Start, Destination::All),
+            (MessageClass::Aggregate, Destination::Orchestrator),
+        ] {
+            router.routes.insert(
+                class,
+                Route {
+                    sender: 0,
+                    destination,
+                },
+            );
+        }
+        router
+    }
+
+    /// Route a message class to the sender at `sender` and the given destination
+    ///
+    /// Fails if there is no sender at `sender`.
+    pub fn with_route(
+        mut self,
+        class: MessageClass,
+        sender: usize,
+        destination: Destination,
+    ) -> Result<Self> {
+        if sender >= self.senders.len() {
+            anyhow::bail!("sender index {sender} out of bounds for {class:?}");
+        }
+        self.routes.insert(
+            class,
+            Route {
+                sender,
+                destination,
+            },
+        );
+        Ok(self)
+    }
+
+    /// Get the route configured for a message class
+    pub fn route(&self, class: MessageClass) -> Option<Route> {
+        self.routes.get(&class).copied()
+    }
+
+    /// Send a message of the given class, messages without a route are skipped.
+    ///
+    /// `to` is the orchestrator of the round or the peer being answered, used by
+    /// directed destinations.
+    pub async fn send(
+        &mut self,
+        class: MessageClass,
+        to: &S::PublicKey,
+        message: Bytes,
+    ) -> Result<Vec<S::PublicKey>> {
+        let Some(route) = self.route(class) else {
+            return Ok(Vec::new());
+        };
+        let recipients = match route.destination {
+            Destination::Orchestrator | Destination::Peer => Recipients::One(to.clone()),
+            Destination::All => Recipients::All,
+        };
+        let Some(sender) = self.senders.get_mut(route.sender) else {
+            anyhow::bail!("No sender at index {} for {:?}", route.sender, class);
+        };
+        sender
+            .send(recipients, message, true)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to send {:?}: {}", class, e))
+    }
+}
@@ -0,0 +1,138 @@
+//! Scores of peers by the shares and frames they send.
+//!
+//! Every valid share raises the score of the peer it came from, every invalid signature
+//! or malformed frame lowers it. A peer whose score falls below
+//! [ScoreConfig::ignore_below] is ignored for [ScoreConfig::ignore_for]: its frames are
+//! dropped before verification, so a persistently bad peer stops costing verification
+//! work. Once the time is up it starts over from a neutral score and can recover.
+//!
+//! Unlike a [PeerQuarantine](crate::contributor::quarantine::PeerQuarantine), which only
+//! counts frames failing to decode, scores weigh well-formed shares that do not verify
+//! against the valid ones a peer sent.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// What a peer sent that lowers its score
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Offense {
+    /// A share whose signature does not verify
+    InvalidSignature,
+    /// A frame or signature that failed to decode
+    Malformed,
+}
+
+/// Configuration of [PeerScores]
+#[derive(Clone, Debug)]
+pub struct ScoreConfig {
+    /// Score gained for a valid share
+    pub reward: i32,
+    /// Score lost for a share whose signature does not verify
+    pub invalid_signature_penalty: i32,
+    /// Score lost for a frame or signature that failed to decode
+    pub malformed_penalty: i32,
+    /// Highest score, so a long-standing peer turning bad is still ignored quickly
+    pub max_score: i32,
+    /// Score below which a peer is ignored
+    pub ignore_below: i32,
+    /// Time an ignored peer's frames are dropped
+    pub ignore_for: Duration,
+}
+
+impl Default for ScoreConfig {
+    fn default() -> Self {
+        Self {
+            reward: 1,
+            invalid_signature_penalty: 10,
+            malformed_penalty: 5,
+            max_score: 50,
+            ignore_below: -50,
+            ignore_for: Duration::from_secs(120),
+        }
+    }
+}
+
+impl ScoreConfig {
+    fn penalty(&self, offense: Offense) -> i32 {
+        match offense {
+            Offense::InvalidSignature => self.invalid_signature_penalty,
+            Offense::Malformed => self.malformed_penalty,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct PeerScore {
+    score: i32,
+    ignored_until: Option<Instant>,
+}
+
+/// Per-peer scores and the peers ignored for a low score
+#[derive(Debug)]
+pub struct PeerScores<K> {
+    config: ScoreConfig,
+    peers: HashMap<K, PeerScore>,
+}
+
+impl<K> Default for PeerScores<K> {
+    fn default() -> Self {
+        Self {
+            config: ScoreConfig::default(),
+            peers: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> PeerScores<K> {
+    /// Scores with every peer neutral
+    pub fn new(config: ScoreConfig) -> Self {
+        Self {
+            config,
+            peers: HashMap::new(),
+        }
+    }
+
+    /// Score of `peer`, starting over once the time it was ignored for is up
+    fn entry(&mut self, peer: &K, now: Instant) -> &mut PeerScore {
+        let record = self.peers.entry(peer.clone()).or_default();
+        if record.ignored_until.is_some_and(|until| until <= now) {
+            *record = PeerScore::default();
+        }
+        record
+    }
+
+    /// Raise the score of `peer` for a valid share
+    pub fn reward(&mut self, peer: &K, now: Instant) {
+        let (reward, max_score) = (self.config.reward, self.config.max_score);
+        let record = self.entry(peer, now);
+        record.score = record.score.saturating_add(reward).min(max_score);
+    }
+
+    /// Lower the score of `peer` for `offense`, returning whether it was just ignored
+    pub fn penalize(&mut self, peer: &K, offense: Offense, now: Instant) -> bool {
+        let penalty = self.config.penalty(offense);
+        let (ignore_below, ignore_for) = (self.config.ignore_below, self.config.ignore_for);
+        let record = self.entry(peer, now);
+        record.score = record.score.saturating_sub(penalty);
+        if record.score >= ignore_below || record.ignored_until.is_some() {
+            return false;
+        }
+        record.ignored_until = Some(now + ignore_for);
+        true
+    }
+
+    /// Whether frames from `peer` are currently dropped
+    pub fn is_ignored(&self, peer: &K, now: Instant) -> bool {
+        self.peers
+            .get(peer)
+            .and_then(|record| record.ignored_until)
+            .is_some_and(|until| until > now)
+    }
+
+    /// Score of `peer` as last updated, zero for a peer not scored yet
+    pub fn score(&self, peer: &K) -> i32 {
+        self.peers.get(peer).map_or(0, |record| record.score)
+    }
+}
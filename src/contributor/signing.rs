@@ -0,0 +1,47 @@
+//! Signing abstraction allowing signatures to be produced off the receive loop.
+
+use anyhow::Result;
+use bn254::{Bn254, PublicKey, Signature};
+use commonware_cryptography::Signer;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+/// Signer that may take time or fail, such as a remote signer or an HSM
+pub trait AsyncSigner: Send + Sync {
+    /// Key signatures verify under
+    type PublicKey;
+    /// Signature produced
+    type Signature;
+
+    /// Key of the signer
+    fn public_key(&self) -> Self::PublicKey;
+
+    /// Sign `payload` under `namespace`
+    fn sign<'a>(
+        &'a self,
+        namespace: Option<&'a [u8]>,
+        payload: &'a [u8],
+    ) -> BoxFuture<'a, Result<Self::Signature>>;
+}
+
+/// Signer shared between the receive loop and in-flight signing tasks
+pub type SharedSigner = Arc<dyn AsyncSigner<PublicKey = PublicKey, Signature = Signature>>;
+
+/// The local key signs immediately and never fails
+impl AsyncSigner for Bn254 {
+    type PublicKey = PublicKey;
+    type Signature = Signature;
+
+    fn public_key(&self) -> PublicKey {
+        Signer::public_key(self)
+    }
+
+    fn sign<'a>(
+        &'a self,
+        namespace: Option<&'a [u8]>,
+        payload: &'a [u8],
+    ) -> BoxFuture<'a, Result<Signature>> {
+        let signature = Signer::sign(self, namespace, payload);
+        Box::pin(async move { Ok(signature) })
+    }
+}
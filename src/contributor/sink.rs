@@ -0,0 +1,241 @@
+//! Destinations of the aggregates a contributor produces.
+//!
+//! An aggregating contributor hands every aggregate to its [AggregationSink]. The
+//! voting contributor submits them on-chain, while a [FileSink] writes them to a
+//! directory for air-gapped workflows, where another machine submits the files. An
+//! [IdempotentSink] in front of either hands each payload hash over once.
+
+use crate::api_types::{AggregateRecord, SCHEMA_VERSION, check_schema_version};
+use crate::contributor::decode::signature_from_slice;
+use crate::contributor::types::ParticipationBitmap;
+use crate::logging::short;
+use alloy_primitives::hex;
+use anyhow::{Context, Result, anyhow};
+use bn254::Signature as Sig;
+use futures::future::BoxFuture;
+use std::collections::HashSet;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use tracing::info;
+
+/// Aggregate signature of a round with the contributors it covers
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregationResult {
+    /// Round the aggregate is for
+    pub round: u64,
+    /// Payload hash the contributors signed
+    pub payload: [u8; 32],
+    /// Aggregate signature over `payload`
+    pub signature: Sig,
+    /// Contributors the aggregate covers
+    pub signers: ParticipationBitmap,
+}
+
+impl AggregationResult {
+    /// The result as an [AggregateRecord] of the current schema
+    #[allow(
+        clippy::disallowed_methods,
+        reason = "hex encoded for storage, not logged"
+    )]
+    pub fn to_record(&self) -> AggregateRecord {
+        AggregateRecord {
+            schema_version: SCHEMA_VERSION,
+            round: self.round,
+            payload: hex::encode(self.payload),
+            signature: hex::encode(self.signature.to_vec()),
+            signers: self.signers.iter().collect(),
+        }
+    }
+
+    /// Result of a record of any supported schema
+    pub fn from_record(record: AggregateRecord) -> Result<Self> {
+        check_schema_version(record.schema_version)?;
+        let payload = hex::decode(&record.payload)?
+            .try_into()
+            .map_err(|_| anyhow!("payload is not 32 bytes"))?;
+        let signature = signature_from_slice(&hex::decode(&record.signature)?)
+            .map_err(|err| anyhow!("invalid signature: {err}"))?;
+        Ok(Self {
+            round: record.round,
+            payload,
+            signature,
+            signers: record.signers.into_iter().collect(),
+        })
+    }
+
+    /// The result as pretty JSON of the current schema
+    pub fn to_json(&self) -> Result<String> {
+        let mut contents = serde_json::to_string_pretty(&self.to_record())?;
+        contents.push('\n');
+        Ok(contents)
+    }
+
+    /// Result of a JSON record of any supported schema
+    pub fn from_json(contents: &str) -> Result<Self> {
+        Self::from_record(serde_json::from_str(contents)?)
+    }
+}
+
+/// Receives the aggregates of an aggregating contributor
+pub trait AggregationSink: Send + Sync {
+    /// Hand over the aggregate of a round, a round aggregated again is emitted again
+    fn emit<'a>(&'a self, result: &'a AggregationResult) -> BoxFuture<'a, Result<()>>;
+}
+
+/// Sink writing each aggregate to `{dir}/round-{n}.json`
+///
+/// Files are written to a temporary file first and renamed into place, so a reader
+/// never sees a partial aggregate. A round aggregated again replaces its file.
+#[derive(Clone, Debug)]
+pub struct FileSink {
+    dir: PathBuf,
+}
+
+impl FileSink {
+    /// Sink writing into `dir`, created if missing
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+        Ok(Self { dir })
+    }
+
+    /// Directory the round files are written to
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// File the aggregate of `round` is written to
+    pub fn path(&self, round: u64) -> PathBuf {
+        self.dir.join(format!("round-{round}.json"))
+    }
+
+    fn write(&self, result: &AggregationResult) -> Result<()> {
+        let path = self.path(result.round);
+        let temp = self.dir.join(format!(".round-{}.json.tmp", result.round));
+        let contents = result.to_json()?;
+        let mut file = std::fs::File::create(&temp)
+            .with_context(|| format!("failed to create {}", temp.display()))?;
+        file.write_all(contents.as_bytes())
+            .and_then(|()| file.sync_all())
+            .with_context(|| format!("failed to write {}", temp.display()))?;
+        std::fs::rename(&temp, &path)
+            .with_context(|| format!("failed to move {} into place", path.display()))
+    }
+}
+
+impl AggregationSink for FileSink {
+    fn emit<'a>(&'a self, result: &'a AggregationResult) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move { self.write(result) })
+    }
+}
+
+/// Sink handing each payload hash to `inner` once, skipping the aggregates already
+/// submitted
+///
+/// Guards against a retried submission or a second orchestrator submitting the same
+/// result, which wastes gas or reverts. A hash is claimed before `inner` is called, so
+/// racing aggregates of one payload are submitted once, and released if `inner` fails
+/// so it can be retried. With a log, submitted hashes are appended to it one per line
+/// in hex and still skipped after a restart.
+pub struct IdempotentSink {
+    inner: Arc<dyn AggregationSink>,
+    submitted: Mutex<HashSet<[u8; 32]>>,
+    log: Option<PathBuf>,
+}
+
+impl IdempotentSink {
+    /// Guard `inner`, remembering submitted hashes in memory only
+    pub fn new(inner: Arc<dyn AggregationSink>) -> Self {
+        Self {
+            inner,
+            submitted: Mutex::new(HashSet::new()),
+            log: None,
+        }
+    }
+
+    /// Guard `inner`, persisting submitted hashes to `log`, read back if it exists
+    pub fn with_log(inner: Arc<dyn AggregationSink>, log: impl Into<PathBuf>) -> Result<Self> {
+        let log = log.into();
+        let submitted = match std::fs::read_to_string(&log) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(parse_payload)
+                .collect::<Result<_>>()
+                .with_context(|| format!("invalid submission log {}", log.display()))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => HashSet::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", log.display()));
+            }
+        };
+        Ok(Self {
+            inner,
+            submitted: Mutex::new(submitted),
+            log: Some(log),
+        })
+    }
+
+    /// Whether the aggregate of `payload` was submitted
+    pub fn is_submitted(&self, payload: &[u8; 32]) -> bool {
+        self.submitted
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(payload)
+    }
+
+    /// Append `payload` to the log, if any
+    #[allow(
+        clippy::disallowed_methods,
+        reason = "hex encoded for storage, not logged"
+    )]
+    fn persist(&self, payload: &[u8; 32]) -> Result<()> {
+        let Some(log) = &self.log else {
+            return Ok(());
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log)
+            .with_context(|| format!("failed to open {}", log.display()))?;
+        writeln!(file, "{}", hex::encode(payload))
+            .and_then(|()| file.sync_all())
+            .with_context(|| format!("failed to write {}", log.display()))
+    }
+}
+
+fn parse_payload(line: &str) -> Result<[u8; 32]> {
+    hex::decode(line.trim())?
+        .try_into()
+        .map_err(|_| anyhow!("payload is not 32 bytes"))
+}
+
+impl AggregationSink for IdempotentSink {
+    fn emit<'a>(&'a self, result: &'a AggregationResult) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            if !self
+                .submitted
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(result.payload)
+            {
+                info!(
+                    round = result.round,
+                    payload = %short(&result.payload),
+                    "skipping aggregate already submitted"
+                );
+                return Ok(());
+            }
+            if let Err(err) = self.inner.emit(result).await {
+                self.submitted
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .remove(&result.payload);
+                return Err(err);
+            }
+            self.persist(&result.payload)
+        })
+    }
+}
@@ -0,0 +1,102 @@
+//! Starts signed by the orchestrator.
+//!
+//! A Start is otherwise trusted because the transport reports the orchestrator as
+//! its sender. When contributors require signed Starts, the orchestrator wraps the
+//! encoded Start in a [SignedStart] carrying its signature over the frame, and
+//! contributors verify it against the orchestrator key before signing the round.
+//! Peers relay the envelope unchanged during catch-up synchronization, so synced
+//! Starts are verified the same way.
+
+use bn254::{Bn254, PublicKey, Signature, aggregate_verify};
+use bytes::{Buf, BufMut, Bytes};
+use commonware_codec::{EncodeSize, Error, Read, ReadExt, Write};
+use commonware_cryptography::Signer;
+
+/// Prefix distinguishing signed Starts from aggregation frames
+pub const SIGNED_START_MAGIC: [u8; 4] = *b"SSTA";
+
+/// Namespace of the orchestrator's signature over a Start
+pub const START_NAMESPACE: &[u8] = b"_COMMONWARE_AVS_START";
+
+/// Upper bound on the encoded Start carried by a [SignedStart]
+pub const MAX_START_LEN: usize = 64 * 1024;
+
+/// Encoded Start with the orchestrator's signature over it
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignedStart {
+    /// Encoded Start frame
+    pub start: Bytes,
+    /// Orchestrator signature over the frame
+    pub signature: Vec<u8>,
+}
+
+impl SignedStart {
+    /// Sign the encoded Start `start` as the orchestrator
+    pub fn sign(orchestrator: &Bn254, start: Bytes) -> Self {
+        let signature = Signer::sign(orchestrator, Some(START_NAMESPACE), &start);
+        Self {
+            start,
+            signature: signature.to_vec(),
+        }
+    }
+
+    /// Whether a raw frame is a signed Start
+    pub fn is_signed_start(frame: &[u8]) -> bool {
+        frame.starts_with(&SIGNED_START_MAGIC)
+    }
+
+    /// Whether the Start was signed by `orchestrator`
+    pub fn verify(&self, orchestrator: &PublicKey) -> bool {
+        let Ok(signature) = Signature::try_from(self.signature.clone()) else {
+            return false;
+        };
+        aggregate_verify(
+            std::slice::from_ref(orchestrator),
+            Some(START_NAMESPACE),
+            &self.start,
+            &signature,
+        )
+    }
+}
+
+impl Write for SignedStart {
+    fn write(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&SIGNED_START_MAGIC);
+        (self.start.len() as u32).write(buf);
+        buf.put_slice(&self.start);
+        (self.signature.len() as u16).write(buf);
+        buf.put_slice(&self.signature);
+    }
+}
+
+impl EncodeSize for SignedStart {
+    fn encode_size(&self) -> usize {
+        SIGNED_START_MAGIC.len() + 4 + self.start.len() + 2 + self.signature.len()
+    }
+}
+
+impl Read for SignedStart {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _: &()) -> Result<Self, Error> {
+        let magic = <[u8; 4]>::read(buf)?;
+        if magic != SIGNED_START_MAGIC {
+            return Err(Error::Invalid("SignedStart", "missing signed start prefix"));
+        }
+        let len = u32::read(buf)? as usize;
+        if len > MAX_START_LEN {
+            return Err(Error::InvalidLength(len));
+        }
+        if buf.remaining() < len {
+            return Err(Error::EndOfBuffer);
+        }
+        let start = buf.copy_to_bytes(len);
+        let len = u16::read(buf)? as usize;
+        if buf.remaining() < len {
+            return Err(Error::EndOfBuffer);
+        }
+        let mut signature = vec![0; len];
+        buf.copy_to_slice(&mut signature);
+        Ok(Self { start, signature })
+    }
+}
@@ -0,0 +1,370 @@
+//! Catch-up synchronization of rounds missed while a node was partitioned.
+//!
+//! A node that sees a Start whose round jumps ahead of the last round it saw by
+//! more than [SyncConfig::max_gap] asks its peers for a summary of the rounds in
+//! between. Peers answer with the rounds they know of and, for rounds that are
+//! still open, the original Start frame. The requester processes those frames
+//! exactly like a Start received from the orchestrator: the payload is validated
+//! again and the peer-reported hash is never trusted.
+
+use crate::clock::{Clock, SystemClock};
+use bytes::{Buf, BufMut, Bytes};
+use commonware_codec::{EncodeSize, Error, Read, ReadExt, Write};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Prefix distinguishing sync frames from aggregation frames
+pub const SYNC_MAGIC: [u8; 4] = *b"SYNC";
+
+/// Upper bound on the rounds carried by a single sync response
+pub const MAX_SYNC_ROUNDS: usize = 64;
+
+const REQUEST_TAG: u8 = 0;
+const RESPONSE_TAG: u8 = 1;
+
+/// Configuration of catch-up synchronization
+#[derive(Clone, Debug)]
+pub struct SyncConfig {
+    /// Largest jump between consecutive Start rounds that does not trigger a sync
+    pub max_gap: u64,
+    /// Maximum number of rounds requested (and served) per sync exchange
+    pub max_rounds: usize,
+    /// Time after a Start during which the round accepts late contributions, unless
+    /// derived from the task's [BlockWindow](crate::contributor::deadline::BlockWindow)
+    pub round_deadline: Duration,
+    /// Number of rounds kept to answer sync requests
+    pub retained_rounds: usize,
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            max_gap: 1,
+            max_rounds: 16,
+            round_deadline: Duration::from_secs(30),
+            retained_rounds: 256,
+        }
+    }
+}
+
+/// Request for the rounds in `from_round..=to_round`
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncRequest {
+    /// First round requested
+    pub from_round: u64,
+    /// Last round requested
+    pub to_round: u64,
+}
+
+/// What a peer knows about a round
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundSummary {
+    /// Round summarized
+    pub round: u64,
+    /// Payload hash of the round's Start
+    pub payload_hash: [u8; 32],
+    /// Whether the round reached the aggregation threshold
+    pub aggregated: bool,
+    /// Original Start frame, only included while the round is still open
+    pub start: Option<Bytes>,
+}
+
+/// Answer to a [SyncRequest]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncResponse {
+    /// Rounds known in the requested range
+    pub rounds: Vec<RoundSummary>,
+}
+
+/// Frame exchanged during catch-up synchronization
+#[derive(Clone, Debug, PartialEq)]
+pub enum SyncMessage {
+    /// Ask a peer for rounds
+    Request(SyncRequest),
+    /// Answer a peer's request
+    Response(SyncResponse),
+}
+
+impl SyncMessage {
+    /// Whether a raw frame is a sync frame
+    pub fn is_sync(frame: &[u8]) -> bool {
+        frame.starts_with(&SYNC_MAGIC)
+    }
+}
+
+impl Write for RoundSummary {
+    fn write(&self, buf: &mut impl BufMut) {
+        self.round.write(buf);
+        self.payload_hash.write(buf);
+        self.aggregated.write(buf);
+        match &self.start {
+            Some(start) => {
+                true.write(buf);
+                (start.len() as u32).write(buf);
+                buf.put_slice(start);
+            }
+            None => false.write(buf),
+        }
+    }
+}
+
+impl EncodeSize for RoundSummary {
+    fn encode_size(&self) -> usize {
+        let start = match &self.start {
+            Some(start) => 4 + start.len(),
+            None => 0,
+        };
+        8 + 32 + 1 + 1 + start
+    }
+}
+
+impl Read for RoundSummary {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _: &()) -> Result<Self, Error> {
+        let round = u64::read(buf)?;
+        let payload_hash = <[u8; 32]>::read(buf)?;
+        let aggregated = bool::read(buf)?;
+        let start = if bool::read(buf)? {
+            let len = u32::read(buf)? as usize;
+            if buf.remaining() < len {
+                return Err(Error::EndOfBuffer);
+            }
+            Some(buf.copy_to_bytes(len))
+        } else {
+            None
+        };
+        Ok(Self {
+            round,
+            payload_hash,
+            aggregated,
+            start,
+        })
+    }
+}
+
+impl Write for SyncMessage {
+    fn write(&self, buf: &mut impl BufMut) {
+        buf.put_slice(&SYNC_MAGIC);
+        match self {
+            SyncMessage::Request(request) => {
+                REQUEST_TAG.write(buf);
+                request.from_round.write(buf);
+                request.to_round.write(buf);
+            }
+            SyncMessage::Response(response) => {
+                RESPONSE_TAG.write(buf);
+                (response.rounds.len() as u16).write(buf);
+                for round in &response.rounds {
+                    round.write(buf);
+                }
+            }
+        }
+    }
+}
+
+impl EncodeSize for SyncMessage {
+    fn encode_size(&self) -> usize {
+        SYNC_MAGIC.len()
+            + 1
+            + match self {
+                SyncMessage::Request(_) => 16,
+                SyncMessage::Response(response) => {
+                    2 + response
+                        .rounds
+                        .iter()
+                        .map(EncodeSize::encode_size)
+                        .sum::<usize>()
+                }
+            }
+    }
+}
+
+impl Read for SyncMessage {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _: &()) -> Result<Self, Error> {
+        let magic = <[u8; 4]>::read(buf)?;
+        if magic != SYNC_MAGIC {
+            return Err(Error::Invalid("SyncMessage", "missing sync prefix"));
+        }
+        match u8::read(buf)? {
+            REQUEST_TAG => Ok(SyncMessage::Request(SyncRequest {
+                from_round: u64::read(buf)?,
+                to_round: u64::read(buf)?,
+            })),
+            RESPONSE_TAG => {
+                let count = u16::read(buf)? as usize;
+                if count > MAX_SYNC_ROUNDS {
+                    return Err(Error::InvalidLength(count));
+                }
+                let mut rounds = Vec::with_capacity(count);
+                for _ in 0..count {
+                    rounds.push(RoundSummary::read(buf)?);
+                }
+                Ok(SyncMessage::Response(SyncResponse { rounds }))
+            }
+            tag => Err(Error::InvalidEnum(tag)),
+        }
+    }
+}
+
+struct LoggedRound {
+    start: Bytes,
+    payload_hash: [u8; 32],
+    /// End of the time the round accepts late contributions
+    deadline: Instant,
+    aggregated: bool,
+    completed_on_chain: bool,
+}
+
+/// Rounds known to this node, used to detect gaps and to answer peers
+pub struct SyncLog {
+    config: SyncConfig,
+    last_round: Option<u64>,
+    rounds: BTreeMap<u64, LoggedRound>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SyncLog {
+    /// Empty log
+    pub fn new(config: SyncConfig) -> Self {
+        Self {
+            config,
+            last_round: None,
+            rounds: BTreeMap::new(),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Measure round deadlines with `clock`
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Configuration of the log
+    pub fn config(&self) -> &SyncConfig {
+        &self.config
+    }
+
+    /// Note a Start for `round`, returning a request for missed rounds if the round jumped ahead
+    pub fn observe_round(&mut self, round: u64) -> Option<SyncRequest> {
+        let request = match self.last_round {
+            Some(last) if round > last.saturating_add(self.config.max_gap) => {
+                // Prefer the most recent missed rounds, they are the most likely to be open
+                let from_round =
+                    (last + 1).max(round.saturating_sub(self.config.max_rounds as u64));
+                Some(SyncRequest {
+                    from_round,
+                    to_round: round - 1,
+                })
+            }
+            _ => None,
+        };
+        if self.last_round.is_none_or(|last| round > last) {
+            self.last_round = Some(round);
+        }
+        request
+    }
+
+    /// Record a Start this node validated and signed
+    pub fn record_start(&mut self, round: u64, start: Bytes, payload_hash: [u8; 32]) {
+        let deadline = self.clock.monotonic_now() + self.config.round_deadline;
+        self.record_start_until(round, start, payload_hash, deadline);
+    }
+
+    /// Record a Start accepting late contributions until `deadline`
+    pub fn record_start_until(
+        &mut self,
+        round: u64,
+        start: Bytes,
+        payload_hash: [u8; 32],
+        deadline: Instant,
+    ) {
+        self.rounds.insert(
+            round,
+            LoggedRound {
+                start,
+                payload_hash,
+                deadline,
+                aggregated: false,
+                completed_on_chain: false,
+            },
+        );
+        while self.rounds.len() > self.config.retained_rounds {
+            self.rounds.pop_first();
+        }
+    }
+
+    /// Record that a round was aggregated and no longer accepts contributions
+    pub fn mark_aggregated(&mut self, round: u64) {
+        if let Some(logged) = self.rounds.get_mut(&round) {
+            logged.aggregated = true;
+        }
+    }
+
+    /// Record that a response for a round was submitted on-chain, closing the round
+    pub fn mark_completed_on_chain(&mut self, round: u64) {
+        if let Some(logged) = self.rounds.get_mut(&round) {
+            logged.completed_on_chain = true;
+        }
+    }
+
+    /// Whether a response for a round was submitted on-chain
+    pub fn is_completed_on_chain(&self, round: u64) -> bool {
+        self.rounds
+            .get(&round)
+            .is_some_and(|logged| logged.completed_on_chain)
+    }
+
+    /// Round this node signed over `payload_hash`
+    pub fn round_of_payload(&self, payload_hash: &[u8; 32]) -> Option<u64> {
+        self.rounds
+            .iter()
+            .find(|(_, logged)| &logged.payload_hash == payload_hash)
+            .map(|(round, _)| *round)
+    }
+
+    /// Number of rounds logged
+    pub fn len(&self) -> usize {
+        self.rounds.len()
+    }
+
+    /// Whether no round is logged
+    pub fn is_empty(&self) -> bool {
+        self.rounds.is_empty()
+    }
+
+    /// Forget every logged round, as if no Start was seen yet
+    pub fn clear(&mut self) {
+        self.last_round = None;
+        self.rounds.clear();
+    }
+
+    /// Summarize the requested rounds, attaching the Start frame of rounds still open
+    pub fn respond(&self, request: &SyncRequest) -> SyncResponse {
+        if request.from_round > request.to_round {
+            return SyncResponse { rounds: Vec::new() };
+        }
+        let limit = self.config.max_rounds.min(MAX_SYNC_ROUNDS);
+        let rounds = self
+            .rounds
+            .range(request.from_round..=request.to_round)
+            .take(limit)
+            .map(|(round, logged)| {
+                let closed = logged.aggregated || logged.completed_on_chain;
+                let open = !closed && self.clock.monotonic_now() < logged.deadline;
+                RoundSummary {
+                    round: *round,
+                    payload_hash: logged.payload_hash,
+                    aggregated: closed,
+                    start: open.then(|| logged.start.clone()),
+                }
+            })
+            .collect();
+        SyncResponse { rounds }
+    }
+}
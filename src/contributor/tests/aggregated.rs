@@ -0,0 +1,123 @@
+use super::harness::{Harness, digest_of, encode, start_message};
+use super::mock::MockContributor;
+use crate::contributor::aggregated::Aggregated;
+use crate::contributor::decode::signature_from_slice;
+use bn254::{PublicKey, aggregate_verify};
+use commonware_codec::{EncodeSize, ReadExt, Write};
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_millis(500);
+
+fn envelope(aggregated: &Aggregated) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(aggregated.encode_size());
+    aggregated.write(&mut buf);
+    buf
+}
+
+/// Check `aggregated` verifies over the Start of its round for its participants
+fn assert_verifies(harness: &Harness, aggregated: &Aggregated) {
+    let mut contributors: Vec<PublicKey> = harness.contributors();
+    contributors.sort();
+    let participants: Vec<PublicKey> = aggregated
+        .participants
+        .iter()
+        .map(|index| contributors[index].clone())
+        .collect();
+    let signature = signature_from_slice(&aggregated.signature).unwrap();
+    let payload = digest_of(&start_message(aggregated.round));
+    assert!(aggregate_verify(&participants, None, &payload, &signature));
+}
+
+#[cfg(test)]
+mod aggregated_tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_round_trip() {
+        let aggregated = Aggregated {
+            round: 7,
+            signature: vec![1; 64],
+            participants: [0, 2].into_iter().collect(),
+        };
+        let frame = envelope(&aggregated);
+        assert_eq!(frame.len(), aggregated.encode_size());
+        assert!(Aggregated::is_aggregated(&frame));
+        assert_eq!(Aggregated::read(&mut &frame[..]).unwrap(), aggregated);
+
+        assert!(!Aggregated::is_aggregated(&frame[1..]));
+        assert!(Aggregated::read(&mut &frame[1..]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_returned_to_orchestrator() {
+        let mut harness = Harness::new(3);
+        let mut handles = vec![harness.spawn(harness.contributor(0, Some(2)), 0)];
+        for i in 1..3 {
+            handles.push(harness.spawn(harness.contributor(i, None), i));
+        }
+
+        harness.start(1).await;
+        let (sender, aggregated) = harness
+            .orchestrator_receiver
+            .next_aggregated(TIMEOUT)
+            .await
+            .expect("aggregate returned");
+        assert_eq!(sender, harness.signers[0].public_key());
+        assert_eq!(aggregated.round, 1);
+        assert!(aggregated.participants.count() >= 2);
+        assert_verifies(&harness, &aggregated);
+
+        // Shares past the threshold are dropped, a single aggregate is returned
+        assert!(
+            harness
+                .orchestrator_receiver
+                .next_aggregated(TIMEOUT)
+                .await
+                .is_none()
+        );
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_returned_to_originating_orchestrator_only() {
+        let mut harness = Harness::new(2);
+        let second = MockContributor::create_test_bn254(1001);
+        let (mut second_sender, mut second_receiver) =
+            harness.network.register(second.public_key());
+        let handles: Vec<_> = [Some(2), None]
+            .into_iter()
+            .enumerate()
+            .map(|(i, threshold)| {
+                let contributor = harness
+                    .contributor(i, threshold)
+                    .with_orchestrator(second.public_key());
+                harness.spawn(contributor, i)
+            })
+            .collect();
+
+        let start = encode(&start_message(1));
+        commonware_p2p::Sender::send(&mut second_sender, Recipients::All, start, true)
+            .await
+            .unwrap();
+        let (sender, aggregated) = second_receiver
+            .next_aggregated(TIMEOUT)
+            .await
+            .expect("aggregate returned");
+        assert_eq!(sender, harness.signers[0].public_key());
+        assert_verifies(&harness, &aggregated);
+        assert!(
+            harness
+                .orchestrator_receiver
+                .next_aggregated(TIMEOUT)
+                .await
+                .is_none()
+        );
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
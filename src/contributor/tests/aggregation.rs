@@ -0,0 +1,281 @@
+use super::harness::{Harness, MockValidator, digest_of, encode, signature_message, start_message};
+use super::mock::MockContributor;
+use crate::contributor::AggregationInput;
+use crate::contributor::aggregation::{AggregationOutcome, aggregate_round_with, canonical_result};
+use crate::contributor::events::EventSink;
+use crate::contributor::sink::{AggregationResult, AggregationSink};
+use crate::handlers::Contributor;
+use anyhow::Result;
+use bn254::{PublicKey, Signature as Bn254Signature, aggregate_signatures};
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use futures::future::BoxFuture;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+const PAYLOAD: &[u8] = b"payload";
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+// Sign the test payload with deterministic keys, one per contributor index
+fn signatures(count: u64) -> BTreeMap<usize, Bn254Signature> {
+    (0..count)
+        .map(|i| {
+            let signer = MockContributor::create_test_bn254(100 + i);
+            (i as usize, signer.sign(None, PAYLOAD))
+        })
+        .collect()
+}
+
+/// Sink keeping every aggregate it is handed
+#[derive(Clone, Default)]
+struct Aggregates(Arc<Mutex<Vec<AggregationResult>>>);
+
+impl AggregationSink for Aggregates {
+    fn emit<'a>(&'a self, result: &'a AggregationResult) -> BoxFuture<'a, Result<()>> {
+        self.0.lock().unwrap().push(result.clone());
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Sink counting failed aggregations
+#[derive(Clone, Default)]
+struct AggregationFailures(Arc<AtomicU64>);
+
+impl EventSink for AggregationFailures {
+    fn aggregation_failed(&self, _quorum_id: u8) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Share of each harness signer for `round`, by index in the sorted contributors
+fn harness_shares(harness: &Harness, round: u64) -> BTreeMap<usize, Bn254Signature> {
+    let mut contributors: Vec<PublicKey> = harness.contributors();
+    contributors.sort();
+    let payload = digest_of(&start_message(round));
+    harness
+        .signers
+        .iter()
+        .map(|signer| {
+            let index = contributors
+                .iter()
+                .position(|key| *key == signer.public_key())
+                .unwrap();
+            (index, signer.sign(None, &payload))
+        })
+        .collect()
+}
+
+/// Results the first of four contributors emits for round 1, aggregating at
+/// `threshold`, once the shares of the others reach it in the given `order`
+async fn emitted_results(threshold: usize, order: &[usize]) -> Vec<AggregationResult> {
+    let mut harness = Harness::new(4);
+    let aggregates = Aggregates::default();
+    let aggregator = harness
+        .contributor(0, Some(threshold))
+        .with_aggregation_sink(Arc::new(aggregates.clone()));
+    let handle = harness.spawn(aggregator, 0);
+    let mut peers: Vec<_> = (1..4)
+        .map(|i| harness.network.register(harness.signers[i].public_key()).0)
+        .collect();
+
+    // Our own share is recorded before the peers' arrive
+    harness.start(1).await;
+    harness
+        .orchestrator_receiver
+        .next_message(TIMEOUT)
+        .await
+        .expect("aggregator signed");
+    let payload = digest_of(&start_message(1));
+    let aggregator_key = harness.signers[0].public_key();
+    for &peer in order {
+        let signature = harness.signers[peer].sign(None, &payload);
+        let frame = encode(&signature_message(1, signature.to_vec()));
+        let recipients = Recipients::One(aggregator_key.clone());
+        commonware_p2p::Sender::send(&mut peers[peer - 1], recipients, frame, true)
+            .await
+            .unwrap();
+    }
+
+    let expected = order.len() + 2 - threshold;
+    let deadline = Instant::now() + TIMEOUT;
+    while aggregates.0.lock().unwrap().len() < expected && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    handle.abort();
+    let results = aggregates.0.lock().unwrap().clone();
+    assert_eq!(results.len(), expected, "{order:?}");
+    results
+}
+
+#[cfg(test)]
+mod aggregation_tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_round_is_not_evicted() {
+        let mut signatures = BTreeMap::new();
+        let outcome = aggregate_round_with(&mut signatures, |_| unreachable!());
+        assert_eq!(outcome, AggregationOutcome::Empty);
+    }
+
+    #[test]
+    fn test_aggregates_in_index_order() {
+        let mut signatures = signatures(3);
+        let first = signatures[&0].clone();
+        let outcome = aggregate_round_with(&mut signatures, |sigs| Some(sigs[0].clone()));
+        assert_eq!(
+            outcome,
+            AggregationOutcome::Aggregated {
+                signature: first,
+                participants: vec![0, 1, 2],
+            }
+        );
+        assert_eq!(signatures.len(), 3);
+    }
+
+    #[test]
+    fn test_malformed_signature_is_evicted() {
+        let mut signatures = signatures(4);
+        let malformed = signatures[&2].clone();
+
+        // Aggregation fails whenever the malformed signature is part of the set
+        let outcome = aggregate_round_with(&mut signatures, |sigs| {
+            if sigs.contains(&malformed) {
+                None
+            } else {
+                Some(sigs[0].clone())
+            }
+        });
+
+        assert_eq!(outcome, AggregationOutcome::Evicted(vec![2]));
+        assert_eq!(signatures.len(), 3);
+        assert!(!signatures.contains_key(&2));
+    }
+
+    #[test]
+    fn test_unisolated_failure_evicts_round() {
+        let mut signatures = signatures(2);
+
+        // Each signature aggregates alone but the pair does not
+        let outcome = aggregate_round_with(&mut signatures, |sigs| {
+            (sigs.len() == 1).then(|| sigs[0].clone())
+        });
+
+        assert_eq!(outcome, AggregationOutcome::Evicted(vec![0, 1]));
+        assert!(signatures.is_empty());
+    }
+
+    #[test]
+    fn test_canonical_result_independent_of_insertion_order() {
+        let all = signatures(5);
+        let expected = canonical_result(1, [7; 32], &all).unwrap();
+        let in_order: Vec<Bn254Signature> = all.values().cloned().collect();
+        assert_eq!(expected.signature, aggregate_signatures(&in_order).unwrap());
+        assert_eq!(expected.signers.iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+
+        let mut rng = StdRng::seed_from_u64(914);
+        for _ in 0..20 {
+            let mut arrivals: Vec<(usize, Bn254Signature)> = all.clone().into_iter().collect();
+            arrivals.shuffle(&mut rng);
+            let shuffled: BTreeMap<usize, Bn254Signature> = arrivals.into_iter().collect();
+            let result = canonical_result(1, [7; 32], &shuffled).unwrap();
+            assert_eq!(result, expected);
+            assert_eq!(result.signature.to_vec(), expected.signature.to_vec());
+        }
+        assert!(canonical_result(1, [7; 32], &BTreeMap::new()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_emitted_results_independent_of_arrival_order() {
+        let shares = harness_shares(&Harness::new(4), 1);
+        let permutations = [
+            [1, 2, 3],
+            [1, 3, 2],
+            [2, 1, 3],
+            [2, 3, 1],
+            [3, 1, 2],
+            [3, 2, 1],
+        ];
+        let mut finals = Vec::new();
+        for order in permutations {
+            let results = emitted_results(2, &order).await;
+
+            // Each result is the canonical aggregate of the shares it covers
+            for result in &results {
+                let covered = result
+                    .signers
+                    .iter()
+                    .map(|index| (index, shares[&index].clone()))
+                    .collect();
+                let canonical = canonical_result(1, result.payload, &covered).unwrap();
+                assert_eq!(*result, canonical, "{order:?}");
+            }
+            finals.push(results.last().unwrap().to_record());
+        }
+
+        // Once every share arrived, every order emits byte-identical results
+        let first = serde_json::to_vec(&finals[0]).unwrap();
+        for record in &finals {
+            assert_eq!(serde_json::to_vec(record).unwrap(), first);
+            assert_eq!(record.signers, [0, 1, 2, 3]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_missing_g1_key_skips_aggregation() {
+        let mut harness = Harness::new(2);
+        let mut g1_map = harness.aggregation_input(2).g1_map().clone();
+        g1_map.remove(&harness.signers[1].public_key());
+        let aggregates = Aggregates::default();
+        let failures = AggregationFailures::default();
+        let aggregator = Contributor::new(
+            harness.orchestrator.public_key(),
+            harness.signers[0].clone(),
+            harness.contributors(),
+            Some(AggregationInput::new(2, g1_map)),
+        )
+        .unwrap()
+        .with_validator_factory(Arc::new(MockValidator))
+        .with_clock(harness.clock.clone())
+        .with_aggregation_sink(Arc::new(aggregates.clone()))
+        .with_event_sink(Arc::new(failures.clone()));
+        let handles = [
+            harness.spawn(aggregator, 0),
+            harness.spawn(harness.contributor(1, None), 1),
+        ];
+
+        harness.start(1).await;
+        let signed = harness.signed_rounds(Duration::from_millis(300)).await;
+        assert_eq!(signed.len(), 2);
+        assert_eq!(failures.0.load(Ordering::Relaxed), 1);
+        assert!(aggregates.0.lock().unwrap().is_empty());
+        assert!(!handles[0].is_finished());
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_full_threshold_emits_one_identical_result() {
+        let mut rng = StdRng::seed_from_u64(9143);
+        let mut order = [1, 2, 3];
+        let mut emitted = Vec::new();
+        for _ in 0..4 {
+            order.shuffle(&mut rng);
+            emitted.extend(emitted_results(4, &order).await);
+        }
+        let shares = harness_shares(&Harness::new(4), 1);
+        let payload = digest_of(&start_message(1));
+        let expected = canonical_result(1, payload, &shares).unwrap();
+        for result in emitted {
+            assert_eq!(result, expected);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::api_types::{
+    AggregateRecord, CalldataRecord, EventRecord, SCHEMA_VERSION, StatusRecord,
+};
+use crate::chain::{ClockSkew, RoundCompletedOnChain};
+use crate::contributor::liveness::OrchestratorStale;
+use crate::contributor::sink::AggregationResult;
+use crate::handlers::RoundStateSummary;
+use alloy_primitives::{Address, TxHash};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+use std::time::Duration;
+
+const AGGREGATE_RECORD: &str = include_str!("../../../tests/fixtures/api/aggregate_record.json");
+const ROUND_COMPLETED: &str =
+    include_str!("../../../tests/fixtures/api/event_round_completed_on_chain.json");
+const ORCHESTRATOR_STALE: &str =
+    include_str!("../../../tests/fixtures/api/event_orchestrator_stale.json");
+const STATUS_RECORD: &str = include_str!("../../../tests/fixtures/api/status_record.json");
+const CALLDATA_RECORD: &str = include_str!("../../../tests/fixtures/api/calldata_record.json");
+
+/// Check `value` serializes to the checked-in `snapshot` and reads back unchanged
+fn assert_snapshot<T>(value: &T, snapshot: &str)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let mut json = serde_json::to_string_pretty(value).unwrap();
+    json.push('\n');
+    assert_eq!(json, snapshot);
+    assert_eq!(&serde_json::from_str::<T>(snapshot).unwrap(), value);
+}
+
+fn aggregate_record() -> AggregateRecord {
+    AggregateRecord {
+        schema_version: SCHEMA_VERSION,
+        round: 7,
+        payload: "11".repeat(32),
+        signature: "22".repeat(64),
+        signers: vec![0, 2],
+    }
+}
+
+#[cfg(test)]
+mod api_schema_tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_record_snapshot() {
+        assert_snapshot(&aggregate_record(), AGGREGATE_RECORD);
+    }
+
+    #[test]
+    fn test_event_snapshots() {
+        let completed = RoundCompletedOnChain {
+            round: 7,
+            transaction_hash: TxHash::repeat_byte(0x33),
+            dropped_signatures: 2,
+        };
+        assert_snapshot(&EventRecord::from(&completed), ROUND_COMPLETED);
+
+        let stale = OrchestratorStale {
+            silent_for: Duration::from_secs(90),
+            last_round: Some(7),
+        };
+        assert_snapshot(&EventRecord::from(&stale), ORCHESTRATOR_STALE);
+    }
+
+    #[test]
+    fn test_status_record_snapshot() {
+        let summary = RoundStateSummary {
+            signed: 5,
+            signatures: 4,
+            started: 3,
+            retired: 1,
+            held_shares: 2,
+            pending_signatures: 1,
+            sync_rounds: 5,
+            active_rounds: 3,
+            max_active_rounds: 64,
+        };
+        let record =
+            StatusRecord::from(&summary).with_clock_skew(Some(ClockSkew { offset_ms: -1500 }));
+        assert_snapshot(&record, STATUS_RECORD);
+    }
+
+    #[test]
+    fn test_calldata_record_snapshot() {
+        let record = CalldataRecord::new(7, Address::repeat_byte(0x42), &[0xde, 0xad, 0xbe, 0xef]);
+        assert_snapshot(&record, CALLDATA_RECORD);
+    }
+
+    #[test]
+    fn test_unknown_input_fields_rejected() {
+        let mut value: serde_json::Value = serde_json::from_str(AGGREGATE_RECORD).unwrap();
+        value["extra"] = serde_json::Value::Bool(true);
+        assert!(serde_json::from_value::<AggregateRecord>(value).is_err());
+    }
+
+    #[test]
+    fn test_other_schema_version_rejected() {
+        let record = AggregateRecord {
+            schema_version: SCHEMA_VERSION + 1,
+            ..aggregate_record()
+        };
+        let err = AggregationResult::from_record(record).unwrap_err();
+        assert!(
+            err.to_string().contains("unsupported schema version"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use super::mock::MockContributor;
+use crate::contributor::ParticipationBitmap;
+use crate::contributor::types::MAX_BITMAP_CONTRIBUTORS;
+use alloy_primitives::U256;
+use bn254::PublicKey;
+use commonware_cryptography::Signer;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// Random bitmap with each index set with probability one half
+fn random_bitmap(rng: &mut StdRng) -> ParticipationBitmap {
+    (0..MAX_BITMAP_CONTRIBUTORS)
+        .filter(|_| rng.random_bool(0.5))
+        .collect()
+}
+
+// Sorted keys of a known operator set
+fn sorted_operators(count: u64) -> Vec<PublicKey> {
+    let mut keys: Vec<PublicKey> = (0..count)
+        .map(|seed| MockContributor::create_test_bn254(100 + seed).public_key())
+        .collect();
+    keys.sort();
+    keys
+}
+
+#[cfg(test)]
+mod bitmap_tests {
+    use super::*;
+
+    #[test]
+    fn test_set_get_count() {
+        let mut bitmap = ParticipationBitmap::new();
+        assert!(bitmap.is_empty());
+        bitmap.set(0);
+        bitmap.set(5);
+        bitmap.set(255);
+        bitmap.set(5);
+        assert!(bitmap.get(0) && bitmap.get(5) && bitmap.get(255));
+        assert!(!bitmap.get(1) && !bitmap.get(256));
+        assert_eq!(bitmap.count(), 3);
+        assert_eq!(bitmap.iter().collect::<Vec<_>>(), vec![0, 5, 255]);
+    }
+
+    #[test]
+    fn test_set_past_width_refused() {
+        let mut bitmap = ParticipationBitmap::new();
+        assert!(bitmap.set(MAX_BITMAP_CONTRIBUTORS - 1));
+        assert!(!bitmap.set(MAX_BITMAP_CONTRIBUTORS));
+        assert_eq!(bitmap.count(), 1);
+
+        let bitmap = ParticipationBitmap::from_indices([0, 7]).unwrap();
+        assert_eq!(bitmap.iter().collect::<Vec<_>>(), vec![0, 7]);
+        assert_eq!(
+            ParticipationBitmap::from_indices([0, MAX_BITMAP_CONTRIBUTORS]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut left: ParticipationBitmap = [0, 2].into_iter().collect();
+        let right: ParticipationBitmap = [2, 3].into_iter().collect();
+        left.merge(&right);
+        assert_eq!(left.iter().collect::<Vec<_>>(), vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_bit_ordering_matches_uint256() {
+        // Index i is the i-th least significant bit, as in BitmapUtils
+        let bitmap: ParticipationBitmap = [0, 1, 8].into_iter().collect();
+        assert_eq!(bitmap.to_u256(), U256::from(0b1_0000_0011u64));
+        let bytes = bitmap.to_bytes();
+        assert_eq!(bytes[31], 0b0000_0011);
+        assert_eq!(bytes[30], 0b0000_0001);
+        assert!(bytes[..30].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn test_random_bitmaps_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..256 {
+            let bitmap = random_bitmap(&mut rng);
+            assert_eq!(ParticipationBitmap::from_u256(bitmap.to_u256()), bitmap);
+            assert_eq!(ParticipationBitmap::from_bytes(bitmap.to_bytes()), bitmap);
+            let rebuilt: ParticipationBitmap = bitmap.iter().collect();
+            assert_eq!(rebuilt, bitmap);
+            assert_eq!(bitmap.iter().count(), bitmap.count());
+        }
+    }
+
+    #[test]
+    fn test_random_merges_are_unions() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..64 {
+            let left = random_bitmap(&mut rng);
+            let right = random_bitmap(&mut rng);
+            let mut merged = left;
+            merged.merge(&right);
+            for index in 0..MAX_BITMAP_CONTRIBUTORS {
+                assert_eq!(merged.get(index), left.get(index) || right.get(index));
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_operator_set_fixture() {
+        let operators = sorted_operators(5);
+        let signers = vec![
+            operators[4].clone(),
+            operators[0].clone(),
+            operators[3].clone(),
+        ];
+        let bitmap = ParticipationBitmap::from_participants(&operators, &signers).unwrap();
+
+        // Computed independently: 1 << 0 | 1 << 3 | 1 << 4
+        assert_eq!(bitmap.to_u256(), U256::from(25u64));
+        let mut expected = [0u8; 32];
+        expected[31] = 25;
+        assert_eq!(bitmap.to_bytes(), expected);
+    }
+
+    #[test]
+    fn test_unknown_participant_is_rejected() {
+        let operators = sorted_operators(3);
+        let outsider = MockContributor::create_test_bn254(999).public_key();
+        assert!(ParticipationBitmap::from_participants(&operators, &[outsider]).is_none());
+    }
+
+    #[test]
+    fn test_packed_layout() {
+        assert!(ParticipationBitmap::new().to_packed().is_empty());
+        let bitmap: ParticipationBitmap = [0, 9].into_iter().collect();
+        assert_eq!(bitmap.to_packed(), [0x80, 0x40]);
+        let bitmap: ParticipationBitmap = [255].into_iter().collect();
+        let packed = bitmap.to_packed();
+        assert_eq!(packed.len(), 32);
+        assert_eq!(packed[31], 0x01);
+    }
+
+    #[test]
+    fn test_random_participant_subsets_roundtrip_packed() {
+        let operators = sorted_operators(64);
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..256 {
+            let count = rng.random_range(1..=operators.len());
+            let participants: Vec<PublicKey> = operators[..count]
+                .iter()
+                .filter(|_| rng.random_bool(0.5))
+                .cloned()
+                .collect();
+            let bitmap =
+                ParticipationBitmap::from_participants(&operators[..count], &participants).unwrap();
+            let packed = bitmap.to_packed();
+            assert!(packed.len() <= count.div_ceil(8));
+            let decoded = ParticipationBitmap::from_packed(&packed).unwrap();
+            assert_eq!(decoded, bitmap);
+            for (index, operator) in operators[..count].iter().enumerate() {
+                assert_eq!(decoded.get(index), participants.contains(operator));
+            }
+        }
+
+        // Any bitmap round-trips, up to the widest
+        for _ in 0..256 {
+            let bitmap = random_bitmap(&mut rng);
+            assert_eq!(
+                ParticipationBitmap::from_packed(&bitmap.to_packed()),
+                Some(bitmap)
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_canonical_packed_rejected() {
+        assert_eq!(ParticipationBitmap::from_packed(&[0x80, 0x00]), None);
+        assert_eq!(ParticipationBitmap::from_packed(&[0x01; 33]), None);
+        assert_eq!(
+            ParticipationBitmap::from_packed(&[]),
+            Some(ParticipationBitmap::new())
+        );
+    }
+}
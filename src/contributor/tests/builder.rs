@@ -0,0 +1,174 @@
+use super::harness::{Harness, MockValidator};
+use crate::contributor::{AggregationInput, Contribute, ContributeError, ContributorBase};
+use crate::handlers::{BuildError, Contributor, ContributorBuilder};
+use crate::types::ContributorIndex;
+use commonware_cryptography::Signer;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Builder for the first signer of `harness` with every field set
+fn complete(harness: &Harness, threshold: usize) -> ContributorBuilder {
+    Contributor::builder()
+        .orchestrator(harness.orchestrator.public_key())
+        .signer(harness.signers[0].clone())
+        .contributors(harness.contributors())
+        .aggregation(harness.aggregation_input(threshold))
+        .validator(Arc::new(MockValidator))
+}
+
+fn build_error(builder: ContributorBuilder) -> BuildError {
+    match builder.build() {
+        Ok(_) => panic!("build succeeded"),
+        Err(err) => err,
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_complete_build_aggregates() {
+        let mut harness = Harness::new(2);
+        let aggregator = complete(&harness, 2).build().unwrap();
+        let mut expected = harness.contributors();
+        expected.sort();
+        assert_eq!(aggregator.contributors_for_round(1), expected);
+        let index = expected
+            .iter()
+            .position(|key| key == &harness.signers[1].public_key())
+            .map(ContributorIndex::from)
+            .unwrap();
+        assert_eq!(
+            aggregator.get_contributor_index(&harness.signers[1].public_key()),
+            Some(&index)
+        );
+
+        // Rounds are signed with the configured mock validator, without a chain
+        let handles = [
+            harness.spawn(aggregator, 0),
+            harness.spawn(harness.contributor(1, None), 1),
+        ];
+        harness.start(1).await;
+        let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+        assert_eq!(signed.len(), 2);
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[test]
+    fn test_aggregation_is_optional() {
+        let harness = Harness::new(2);
+        let contributor = Contributor::builder()
+            .orchestrator(harness.orchestrator.public_key())
+            .signer(harness.signers[0].clone())
+            .contributors(harness.contributors())
+            .build()
+            .unwrap();
+        assert!(
+            contributor
+                .get_contributor_index(&harness.signers[1].public_key())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let harness = Harness::new(2);
+        let orchestrator = harness.orchestrator.public_key();
+        let signer = harness.signers[0].clone();
+
+        assert_eq!(
+            build_error(ContributorBuilder::new()),
+            BuildError::MissingOrchestrator
+        );
+        assert_eq!(
+            build_error(Contributor::builder().orchestrator(orchestrator.clone())),
+            BuildError::MissingSigner
+        );
+        assert_eq!(
+            build_error(
+                Contributor::builder()
+                    .orchestrator(orchestrator.clone())
+                    .signer(signer.clone())
+            ),
+            BuildError::MissingContributors
+        );
+        assert_eq!(
+            build_error(
+                Contributor::builder()
+                    .orchestrator(orchestrator)
+                    .signer(signer)
+                    .contributors(Vec::new())
+            ),
+            BuildError::MissingContributors
+        );
+    }
+
+    #[test]
+    fn test_signer_not_contributor() {
+        let harness = Harness::new(3);
+        let builder = complete(&harness, 2).contributors(vec![
+            harness.signers[1].public_key(),
+            harness.signers[2].public_key(),
+        ]);
+        assert_eq!(build_error(builder), BuildError::SignerNotContributor);
+    }
+
+    #[test]
+    fn test_new_fails_for_signer_outside_contributors() {
+        let harness = Harness::new(3);
+        let contributor = Contributor::new(
+            harness.orchestrator.public_key(),
+            harness.signers[0].clone(),
+            vec![
+                harness.signers[1].public_key(),
+                harness.signers[2].public_key(),
+            ],
+            None,
+        );
+        assert_eq!(
+            contributor.err(),
+            Some(ContributeError::SignerNotInContributors)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_contributor() {
+        let harness = Harness::new(2);
+        let duplicate = harness.signers[1].public_key();
+        let mut contributors = harness.contributors();
+        contributors.push(duplicate.clone());
+        let builder = complete(&harness, 2).contributors(contributors);
+        assert_eq!(
+            build_error(builder),
+            BuildError::DuplicateContributor(duplicate)
+        );
+    }
+
+    #[test]
+    fn test_invalid_threshold() {
+        let harness = Harness::new(2);
+        for threshold in [0, 3] {
+            assert_eq!(
+                build_error(complete(&harness, threshold)),
+                BuildError::InvalidThreshold {
+                    threshold,
+                    contributors: 2
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_g1_key() {
+        let harness = Harness::new(2);
+        let missing = harness.signers[1].public_key();
+        let mut g1_map: HashMap<_, _> = harness.aggregation_input(2).g1_map().clone();
+        g1_map.remove(&missing);
+        let builder = complete(&harness, 2).aggregation(AggregationInput::new(2, g1_map));
+        assert_eq!(build_error(builder), BuildError::MissingG1Key(missing));
+    }
+}
@@ -0,0 +1,182 @@
+use super::mock::MockContributor;
+use crate::contributor::types::{Assignment, assigned_contributors};
+use crate::contributor::{ParticipationBitmap, QuorumCertificate};
+use alloy_primitives::hex;
+use bn254::{Bn254, PublicKey, aggregate_signatures};
+use commonware_codec::{EncodeSize, ReadExt, Write};
+use commonware_cryptography::Signer;
+use std::sync::Arc;
+
+const PAYLOAD: [u8; 32] = [7; 32];
+
+// Signers sorted by public key, as contributors are
+fn sorted_signers(count: u64) -> Vec<Bn254> {
+    let mut signers: Vec<Bn254> = (0..count)
+        .map(|seed| MockContributor::create_test_bn254(300 + seed))
+        .collect();
+    signers.sort_by_key(|signer| signer.public_key());
+    signers
+}
+
+// Certificate over the test payload signed by the signers at `indices`
+fn certificate(signers: &[Bn254], indices: &[usize]) -> QuorumCertificate {
+    let signatures: Vec<_> = indices
+        .iter()
+        .map(|index| signers[*index].sign(None, &PAYLOAD))
+        .collect();
+    QuorumCertificate {
+        round: 1,
+        payload: PAYLOAD,
+        signature: aggregate_signatures(&signatures).unwrap(),
+        signers: indices.iter().copied().collect(),
+    }
+}
+
+fn keys(signers: &[Bn254]) -> Vec<PublicKey> {
+    signers.iter().map(|signer| signer.public_key()).collect()
+}
+
+fn encode_certificate(certificate: &QuorumCertificate) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(certificate.encode_size());
+    certificate.write(&mut buf);
+    buf
+}
+
+fn decode_certificate(bytes: &[u8]) -> Result<QuorumCertificate, commonware_codec::Error> {
+    QuorumCertificate::read(&mut std::io::Cursor::new(bytes))
+}
+
+#[cfg(test)]
+mod certificate_tests {
+    use super::*;
+
+    #[test]
+    fn test_certificate_verifies() {
+        let signers = sorted_signers(4);
+        let contributors = keys(&signers);
+        let certificate = certificate(&signers, &[0, 2]);
+        assert_eq!(
+            certificate.signer_keys(&contributors),
+            Some(vec![contributors[0].clone(), contributors[2].clone()])
+        );
+        assert!(certificate.verify(&contributors));
+    }
+
+    #[test]
+    fn test_wrong_signers_rejected() {
+        let signers = sorted_signers(4);
+        let contributors = keys(&signers);
+        let mut certificate = certificate(&signers, &[0, 2]);
+        certificate.signers = [0, 1].into_iter().collect();
+        assert!(!certificate.verify(&contributors));
+    }
+
+    #[test]
+    fn test_wrong_payload_rejected() {
+        let signers = sorted_signers(4);
+        let contributors = keys(&signers);
+        let mut certificate = certificate(&signers, &[1, 3]);
+        certificate.payload = [8; 32];
+        assert!(!certificate.verify(&contributors));
+    }
+
+    #[test]
+    fn test_empty_signers_rejected() {
+        let signers = sorted_signers(4);
+        let contributors = keys(&signers);
+        let mut certificate = certificate(&signers, &[0]);
+        certificate.signers = ParticipationBitmap::new();
+        assert_eq!(certificate.signer_keys(&contributors), Some(Vec::new()));
+        assert!(!certificate.verify(&contributors));
+    }
+
+    #[test]
+    fn test_unknown_signer_rejected() {
+        let signers = sorted_signers(4);
+        let contributors = keys(&signers);
+        let mut certificate = certificate(&signers, &[0, 2]);
+        certificate.signers.set(4);
+        assert_eq!(certificate.signer_keys(&contributors), None);
+        assert!(!certificate.verify(&contributors));
+    }
+
+    #[test]
+    fn test_assigned_contributors() {
+        let contributors = keys(&sorted_signers(4));
+        assert_eq!(assigned_contributors(None, 3, &contributors), contributors);
+
+        let assignment: Assignment = Arc::new(|round, contributors| {
+            vec![contributors[round as usize % contributors.len()].clone()]
+        });
+        assert_eq!(
+            assigned_contributors(Some(&assignment), 5, &contributors),
+            vec![contributors[1].clone()]
+        );
+    }
+
+    #[test]
+    fn test_certificate_encoding_roundtrips() {
+        let signers = sorted_signers(12);
+        let contributors = keys(&signers);
+        let certificate = certificate(&signers, &[0, 3, 11]);
+        let encoded = encode_certificate(&certificate);
+        assert_eq!(encoded.len(), certificate.encode_size());
+
+        // Twelve contributors pack into two bytes
+        let signature_len = certificate.signature.to_vec().len();
+        assert_eq!(encoded.len(), 8 + 32 + 2 + signature_len + 2 + 2);
+        let decoded = decode_certificate(&encoded).unwrap();
+        assert_eq!(decoded, certificate);
+        assert!(decoded.verify(&contributors));
+    }
+
+    #[test]
+    fn test_malformed_certificate_rejected() {
+        let signers = sorted_signers(4);
+        let encoded = encode_certificate(&certificate(&signers, &[1]));
+        assert!(decode_certificate(&encoded[..encoded.len() - 1]).is_err());
+
+        // A trailing zero byte in the bitmap is not canonical
+        let mut padded = encoded[..encoded.len() - 3].to_vec();
+        padded.extend_from_slice(&[0, 2, 0x40, 0x00]);
+        assert!(decode_certificate(&padded).is_err());
+    }
+
+    #[test]
+    fn test_certificate_json_uses_prefixed_hex() {
+        let signers = sorted_signers(3);
+        let certificate = certificate(&signers, &[0, 2]);
+        let json = serde_json::to_value(&certificate).unwrap();
+        assert_eq!(json["round"], 1);
+        assert_eq!(json["payload"], format!("0x{}", "07".repeat(32)));
+        let signature = json["signature"].as_str().unwrap();
+        assert_eq!(
+            signature,
+            format!("0x{}", hex::encode(certificate.signature.to_vec()))
+        );
+        assert_eq!(json["signers"], serde_json::json!([0, 2]));
+
+        let decoded: QuorumCertificate = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, certificate);
+        assert!(decoded.verify(&keys(&signers)));
+    }
+
+    #[test]
+    fn test_certificate_json_rejects_malformed_hex() {
+        let signers = sorted_signers(2);
+        let json = serde_json::to_value(certificate(&signers, &[0, 1])).unwrap();
+        let with = |field: &str, value: serde_json::Value| {
+            let mut json = json.clone();
+            json[field] = value;
+            serde_json::from_value::<QuorumCertificate>(json)
+        };
+
+        // Hex without its prefix, of the wrong length, or not hex at all
+        assert!(with("payload", "07".repeat(32).into()).is_err());
+        assert!(with("payload", format!("0x{}", "07".repeat(31)).into()).is_err());
+        assert!(with("signature", "0xzz".into()).is_err());
+        assert!(with("signature", format!("0x{}", "00".repeat(64)).into()).is_err());
+        assert!(with("signers", serde_json::json!([256])).is_err());
+        assert!(with("signers", serde_json::json!([1])).is_ok());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::clock::{self, Clock, MockClock, SystemClock};
+use crate::contributor::rounds::RoundTable;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, UNIX_EPOCH};
+
+fn mock_clock() -> MockClock {
+    MockClock::new(UNIX_EPOCH + Duration::from_secs(1_000_000))
+}
+
+#[cfg(test)]
+mod clock_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_advance_moves_both_clocks() {
+        let clock = mock_clock();
+        let wall = clock.now();
+        let monotonic = clock.monotonic_now();
+
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(clock.now(), wall + Duration::from_secs(60));
+        assert!(clock.monotonic_now() >= monotonic + Duration::from_secs(60));
+        assert!(clock.elapsed(monotonic) >= Duration::from_secs(60));
+
+        // Setting the wall-clock time never moves the monotonic time back
+        let monotonic = clock.monotonic_now();
+        clock.set(wall);
+        assert_eq!(clock.now(), wall);
+        assert!(clock.monotonic_now() >= monotonic);
+    }
+
+    #[tokio::test]
+    async fn test_advance_wakes_sleep() {
+        let clock = Arc::new(mock_clock());
+        let woke = Arc::new(AtomicBool::new(false));
+        let deadline = clock.monotonic_now() + Duration::from_secs(3600);
+        let sleep = clock.sleep_until(deadline);
+        let handle = tokio::spawn({
+            let woke = woke.clone();
+            async move {
+                sleep.await;
+                woke.store(true, Ordering::SeqCst);
+            }
+        });
+
+        clock.advance(Duration::from_secs(1800));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!woke.load(Ordering::SeqCst));
+
+        clock.advance(Duration::from_secs(1800));
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .expect("sleep woken by advance")
+            .unwrap();
+        assert!(woke.load(Ordering::SeqCst));
+
+        // A deadline already passed completes at once
+        clock.sleep_until(deadline).await;
+    }
+
+    #[tokio::test]
+    async fn test_timeout_on_advance() {
+        let clock = Arc::new(mock_clock());
+        let pending = clock::timeout(
+            clock.as_ref(),
+            Duration::from_secs(3600),
+            std::future::pending::<()>(),
+        );
+        let advance = async {
+            tokio::task::yield_now().await;
+            clock.advance(Duration::from_secs(3600));
+        };
+        let (output, ()) = tokio::time::timeout(Duration::from_secs(1), async {
+            tokio::join!(pending, advance)
+        })
+        .await
+        .expect("timed out on advance");
+        assert_eq!(output, None);
+
+        let ready = clock::timeout(clock.as_ref(), Duration::from_secs(1), async { 7 }).await;
+        assert_eq!(ready, Some(7));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_system_clock_follows_tokio() {
+        let start = SystemClock.monotonic_now();
+        let wall = SystemClock.now();
+        SystemClock
+            .sleep_until(start + Duration::from_secs(10))
+            .await;
+        assert_eq!(SystemClock.elapsed(start), Duration::from_secs(10));
+        assert!(SystemClock.now() >= wall);
+    }
+
+    #[test]
+    fn test_round_latency_measured_with_clock() {
+        let clock = Arc::new(mock_clock());
+        let mut rounds = RoundTable::with_clock(clock.clone());
+        rounds.start_round(1, [1; 32], None);
+        clock.advance(Duration::from_secs(5));
+        let latency = rounds.mark_aggregated(1).unwrap();
+        assert!(latency >= Duration::from_secs(5));
+        assert!(latency < Duration::from_secs(6));
+    }
+}
@@ -0,0 +1,248 @@
+use super::harness::Harness;
+use super::mock::MockContributor;
+use crate::chain::QuorumUpdated;
+use crate::contributor::committee::{
+    canonical_g1_map, canonical_key, canonicalize_key, deduplicate_contributors,
+};
+use crate::contributor::types::{AggregationData, MAX_BITMAP_CONTRIBUTORS};
+use crate::contributor::{ContributorBase, DuplicatePolicy, canonicalize_contributors};
+use crate::handlers::{BuildError, Contributor, ContributorBuilder};
+use crate::types::ContributorIndex;
+use ark_bn254::G2Affine;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use bn254::PublicKey;
+use commonware_cryptography::Signer;
+use std::collections::HashMap;
+
+/// The key's point serialized compressed and uncompressed
+fn encodings(key: &PublicKey) -> [Vec<u8>; 2] {
+    let point = G2Affine::deserialize_compressed(&key[..]).unwrap();
+    let mut compressed = Vec::new();
+    point.serialize_compressed(&mut compressed).unwrap();
+    let mut uncompressed = Vec::new();
+    point.serialize_uncompressed(&mut uncompressed).unwrap();
+    [compressed, uncompressed]
+}
+
+/// Contributors of `harness` read from mixed encodings, with the first signer's key
+/// listed again in its uncompressed encoding
+fn mixed_contributors(harness: &Harness) -> Vec<PublicKey> {
+    let mut contributors: Vec<PublicKey> = harness
+        .contributors()
+        .iter()
+        .enumerate()
+        .map(|(index, key)| canonical_key(&encodings(key)[index % 2]).unwrap())
+        .collect();
+    let [_, uncompressed] = encodings(&harness.signers[0].public_key());
+    contributors.push(canonical_key(&uncompressed).unwrap());
+    contributors
+}
+
+/// `count` keys of signers outside `harness`
+fn outside_keys(count: usize) -> Vec<PublicKey> {
+    (0..count as u64)
+        .map(|seed| MockContributor::create_test_bn254(10_000 + seed).public_key())
+        .collect()
+}
+
+fn builder(harness: &Harness, contributors: Vec<PublicKey>) -> ContributorBuilder {
+    Contributor::builder()
+        .orchestrator(harness.orchestrator.public_key())
+        .signer(harness.signers[0].clone())
+        .contributors(contributors)
+        .aggregation(harness.aggregation_input(1))
+}
+
+#[cfg(test)]
+mod committee_tests {
+    use super::*;
+
+    #[test]
+    fn test_encodings_share_canonical_key() {
+        let harness = Harness::new(1);
+        let key = harness.signers[0].public_key();
+        let [compressed, uncompressed] = encodings(&key);
+        assert_ne!(compressed.len(), uncompressed.len());
+        assert_eq!(canonical_key(&compressed), Some(key.clone()));
+        assert_eq!(canonical_key(&uncompressed), Some(key.clone()));
+        assert_eq!(canonicalize_key(&key), key);
+    }
+
+    #[test]
+    fn test_invalid_encoding() {
+        assert_eq!(canonical_key(&[]), None);
+        assert_eq!(canonical_key(&[0xff; 64]), None);
+        assert_eq!(canonical_key(&[0xff; 128]), None);
+    }
+
+    #[test]
+    fn test_canonicalize_sorts() {
+        let harness = Harness::new(4);
+        let mut expected = harness.contributors();
+        expected.sort();
+        let mut reversed = expected.clone();
+        reversed.reverse();
+        assert_eq!(
+            canonicalize_contributors(reversed, DuplicatePolicy::Reject),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_policy() {
+        let harness = Harness::new(3);
+        let own = harness.signers[0].public_key();
+        assert_eq!(
+            canonicalize_contributors(mixed_contributors(&harness), DuplicatePolicy::Reject),
+            Err(own)
+        );
+
+        let mut expected = harness.contributors();
+        expected.sort();
+        assert_eq!(
+            canonicalize_contributors(mixed_contributors(&harness), DuplicatePolicy::Deduplicate),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_builder_rejects_own_key_twice() {
+        let harness = Harness::new(3);
+        let result = builder(&harness, mixed_contributors(&harness)).build();
+        assert!(matches!(
+            result,
+            Err(BuildError::DuplicateContributor(key)) if key == harness.signers[0].public_key()
+        ));
+    }
+
+    #[test]
+    fn test_single_canonical_index() {
+        let harness = Harness::new(3);
+        let clean = builder(&harness, harness.contributors()).build().unwrap();
+        let mixed = builder(&harness, mixed_contributors(&harness))
+            .duplicates(DuplicatePolicy::Deduplicate)
+            .build()
+            .unwrap();
+
+        // Every node agrees on every index, whichever encodings it was configured with
+        assert_eq!(
+            mixed.contributors_for_round(0),
+            clean.contributors_for_round(0)
+        );
+        for key in harness.contributors() {
+            assert!(clean.get_contributor_index(&key).is_some());
+            assert_eq!(
+                mixed.get_contributor_index(&key),
+                clean.get_contributor_index(&key)
+            );
+        }
+    }
+
+    #[test]
+    fn test_aggregation_data_from_input() {
+        let harness = Harness::new(4);
+        let contributors = deduplicate_contributors(mixed_contributors(&harness));
+        let input = harness.aggregation_input(3);
+        let g1_map = canonical_g1_map(input.g1_map());
+
+        // The index each contributor was given before the conversion existed
+        let mut expected = HashMap::new();
+        for (idx, contributor) in contributors.iter().enumerate() {
+            expected.insert(contributor.clone(), ContributorIndex::from(idx));
+        }
+
+        let data = AggregationData::from((input, contributors.clone()));
+        assert_eq!(data.ordered_contributors, expected);
+        assert_eq!(data.contributors, contributors);
+        assert_eq!(data.threshold, 3);
+        assert_eq!(data.g1_map, g1_map);
+
+        // Contributors index through the same mapping
+        let contributor = builder(&harness, mixed_contributors(&harness))
+            .duplicates(DuplicatePolicy::Deduplicate)
+            .build()
+            .unwrap();
+        for (key, idx) in &expected {
+            assert_eq!(contributor.get_contributor_index(key), Some(idx));
+        }
+    }
+
+    #[test]
+    fn test_g1_operators_sorted_by_key() {
+        let harness = Harness::new(4);
+        let mut reversed = harness.contributors();
+        reversed.sort();
+        reversed.reverse();
+        let contributor = builder(&harness, reversed).build().unwrap();
+
+        let configured = harness.aggregation_input(1);
+        let mut expected: Vec<PublicKey> = configured.g1_map().keys().cloned().collect();
+        expected.sort();
+        let operators: Vec<PublicKey> = contributor
+            .g1_operators()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        assert_eq!(operators, expected);
+
+        // Only aggregators hold G1 keys
+        assert!(harness.contributor(0, None).g1_operators().is_empty());
+    }
+
+    #[test]
+    fn test_update_adding_known_key_keeps_index() {
+        let harness = Harness::new(3);
+        let mut contributor = builder(&harness, harness.contributors()).build().unwrap();
+        let own = harness.signers[0].public_key();
+        let before = contributor.get_contributor_index(&own).copied();
+
+        let [_, uncompressed] = encodings(&own);
+        contributor.update_contributor_set(&QuorumUpdated {
+            quorum_id: 0,
+            added: vec![canonical_key(&uncompressed).unwrap()],
+            removed: Vec::new(),
+            g1_keys: HashMap::new(),
+        });
+        assert_eq!(contributor.contributors_for_round(0).len(), 3);
+        assert_eq!(contributor.get_contributor_index(&own).copied(), before);
+    }
+
+    #[test]
+    fn test_contributors_past_bitmap_rejected() {
+        let harness = Harness::new(1);
+        let mut contributors = harness.contributors();
+        contributors.extend(outside_keys(MAX_BITMAP_CONTRIBUTORS));
+        let err = builder(&harness, contributors).build().err().unwrap();
+        assert_eq!(
+            err,
+            BuildError::TooManyContributors(MAX_BITMAP_CONTRIBUTORS + 1)
+        );
+    }
+
+    #[test]
+    fn test_update_past_bitmap_refused() {
+        let harness = Harness::new(3);
+        let mut contributor = builder(&harness, harness.contributors()).build().unwrap();
+        let before = contributor.contributors_for_round(0);
+
+        let update = QuorumUpdated {
+            quorum_id: 0,
+            added: outside_keys(MAX_BITMAP_CONTRIBUTORS - 2),
+            removed: Vec::new(),
+            g1_keys: HashMap::new(),
+        };
+        assert!(!contributor.update_contributor_set(&update));
+        assert_eq!(contributor.contributors_for_round(0), before);
+
+        // Up to the width of the bitmap is fine
+        let update = QuorumUpdated {
+            added: update.added[1..].to_vec(),
+            ..update
+        };
+        assert!(contributor.update_contributor_set(&update));
+        assert_eq!(
+            contributor.contributors_for_round(0).len(),
+            MAX_BITMAP_CONTRIBUTORS
+        );
+    }
+}
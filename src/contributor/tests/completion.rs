@@ -0,0 +1,184 @@
+use super::harness::{EventLog, Harness, digest_of, start_message};
+use crate::chain::{
+    CompletionWatcher, RetireRound, RoundCompletedOnChain, RoundRef, TaskEventSource, TaskResponded,
+};
+use crate::contributor::types::DroppedShare;
+#[cfg(feature = "observability")]
+use crate::metrics::QuorumLabel;
+use alloy_primitives::TxHash;
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Event source replaying task responses registered per block
+#[derive(Default)]
+struct MockSource {
+    block: Mutex<u64>,
+    events: Mutex<BTreeMap<u64, Vec<TaskResponded>>>,
+}
+
+impl MockSource {
+    /// Emit `event` at the next block and make it the latest
+    fn emit(&self, task_index: Option<u64>, payload_hash: Option<[u8; 32]>) {
+        let mut block = self.block.lock().unwrap();
+        *block += 1;
+        self.events
+            .lock()
+            .unwrap()
+            .entry(*block)
+            .or_default()
+            .push(TaskResponded {
+                task_index,
+                payload_hash,
+                block_number: *block,
+                transaction_hash: TxHash::repeat_byte(*block as u8),
+            });
+    }
+}
+
+impl TaskEventSource for MockSource {
+    async fn block_number(&self) -> Result<u64> {
+        Ok(*self.block.lock().unwrap())
+    }
+
+    async fn task_responses(&self, from_block: u64, to_block: u64) -> Result<Vec<TaskResponded>> {
+        Ok(self
+            .events
+            .lock()
+            .unwrap()
+            .range(from_block..=to_block)
+            .flat_map(|(_, events)| events.clone())
+            .collect())
+    }
+}
+
+fn shares_dropped(events: &EventLog, dropped: DroppedShare) -> usize {
+    events.count_reason("share_dropped", dropped.kind())
+}
+
+/// Start round 1 with two of three contributors, retire it with `emit`, then let the
+/// third contributor sign late
+async fn retire_open_round(
+    emit: impl FnOnce(&MockSource),
+) -> (RoundCompletedOnChain, EventLog, usize) {
+    let mut harness = Harness::new(3);
+    let source = Arc::new(MockSource::default());
+    let mut watcher = CompletionWatcher::new(source.clone(), 1);
+    let (events, mut completed) = broadcast::channel(4);
+    let log = EventLog::default();
+    let aggregator = harness
+        .contributor(0, Some(3))
+        .with_event_sink(Arc::new(log.clone()))
+        .with_retirements(watcher.subscribe())
+        .with_completion_events(events);
+    let mut handles = vec![
+        harness.spawn(aggregator, 0),
+        harness.spawn(harness.contributor(1, None), 1),
+    ];
+
+    harness.start(1).await;
+    let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+    assert_eq!(signed.len(), 2);
+
+    emit(&source);
+    assert_eq!(watcher.poll_once().await.unwrap().len(), 1);
+    let event = tokio::time::timeout(Duration::from_secs(1), completed.recv())
+        .await
+        .unwrap()
+        .unwrap();
+
+    // A late contributor signs the retired round, its share must be dropped
+    handles.push(harness.spawn(harness.contributor(2, None), 2));
+    harness.start(1).await;
+    let late = harness
+        .signed_rounds(Duration::from_millis(200))
+        .await
+        .len();
+    for handle in handles {
+        handle.abort();
+    }
+    (event, log, late)
+}
+
+#[cfg(test)]
+mod completion_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_watcher_maps_events_to_rounds() {
+        let source = Arc::new(MockSource::default());
+        let mut watcher = CompletionWatcher::new(source.clone(), 1);
+        let mut retirements = watcher.subscribe();
+        assert!(watcher.poll_once().await.unwrap().is_empty());
+
+        source.emit(Some(4), Some([1; 32]));
+        source.emit(None, Some([2; 32]));
+        source.emit(None, None);
+        let retired = watcher.poll_once().await.unwrap();
+        let expected = vec![
+            RetireRound {
+                round: RoundRef::Round(4),
+                transaction_hash: TxHash::repeat_byte(1),
+            },
+            RetireRound {
+                round: RoundRef::PayloadHash([2; 32]),
+                transaction_hash: TxHash::repeat_byte(2),
+            },
+        ];
+        assert_eq!(retired, expected);
+        assert_eq!(retirements.recv().await.unwrap(), expected[0]);
+        assert_eq!(retirements.recv().await.unwrap(), expected[1]);
+
+        // Events already reported are not reported again
+        assert!(watcher.poll_once().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_retired_round_drops_late_shares() {
+        let (event, log, late) = retire_open_round(|source| source.emit(Some(1), None)).await;
+        assert_eq!(
+            event,
+            RoundCompletedOnChain {
+                round: 1,
+                transaction_hash: TxHash::repeat_byte(1),
+                dropped_signatures: 2,
+            }
+        );
+        // Only the late contributor signs, the aggregator does not sign the round again
+        assert_eq!(late, 1);
+        assert_eq!(shares_dropped(&log, DroppedShare::CompletedOnChain), 1);
+        assert_eq!(shares_dropped(&log, DroppedShare::UnknownRound), 0);
+        assert_eq!(log.count("round_completed_on_chain"), 1);
+        assert_eq!(log.count("threshold_reached"), 0);
+        #[cfg(feature = "observability")]
+        {
+            let label = QuorumLabel { quorum_id: 0 };
+            let metrics = log.metrics();
+            assert_eq!(
+                metrics
+                    .rounds_completed_on_chain
+                    .get_or_create(&label)
+                    .get(),
+                1
+            );
+            assert_eq!(
+                metrics
+                    .aggregation_threshold_reached
+                    .get_or_create(&label)
+                    .get(),
+                0
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_retired_by_payload_hash() {
+        let payload_hash = digest_of(&start_message(1));
+        let (event, log, _) =
+            retire_open_round(|source| source.emit(None, Some(payload_hash))).await;
+        assert_eq!(event.round, 1);
+        assert_eq!(shares_dropped(&log, DroppedShare::CompletedOnChain), 1);
+    }
+}
@@ -0,0 +1,161 @@
+use super::harness::{
+    Harness, NetworkReceiver, NetworkSender, digest_of, encode, signature_message, start_message,
+};
+use super::mock::MockContributor;
+use crate::contributor::events::{ContributorMetrics, ContributorMetricsSnapshot};
+use crate::handlers::RunConfig;
+use anyhow::Result;
+use bn254::Bn254;
+use bytes::Bytes;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// Aggregator at index 0 waiting for both harness signers, counting into `metrics`
+fn spawn_aggregator(harness: &Harness, metrics: &ContributorMetrics) -> JoinHandle<Result<()>> {
+    let contributor = harness
+        .contributor(0, Some(2))
+        .with_event_sink(Arc::new(metrics.clone()))
+        .with_run_config(RunConfig {
+            unknown_sender_grace: Duration::ZERO,
+            ..RunConfig::default()
+        });
+    harness.spawn(contributor, 0)
+}
+
+/// Scripted peer sending frames to the aggregator
+struct Peer {
+    signer: Bn254,
+    sender: NetworkSender,
+    /// Kept so the aggregator can still reach the peer
+    _receiver: NetworkReceiver,
+}
+
+impl Peer {
+    fn new(harness: &Harness, signer: Bn254) -> Self {
+        let (sender, receiver) = harness.network.register(signer.public_key());
+        Self {
+            signer,
+            sender,
+            _receiver: receiver,
+        }
+    }
+
+    async fn send(&mut self, frame: Bytes) {
+        commonware_p2p::Sender::send(&mut self.sender, Recipients::All, frame, true)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    /// Send a share of `round` signed over `payload`
+    async fn share(&mut self, round: u64, payload: &[u8]) {
+        let signature = self.signer.sign(None, payload);
+        self.send(encode(&signature_message(round, signature.to_vec())))
+            .await;
+    }
+
+    /// Send a valid share of `round`
+    async fn valid_share(&mut self, round: u64) {
+        self.share(round, &digest_of(&start_message(round))).await;
+    }
+}
+
+async fn start(harness: &mut Harness, round: u64) {
+    harness.start(round).await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+#[cfg(test)]
+mod contributor_metrics_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_aggregated_round_counted() {
+        let mut harness = Harness::new(2);
+        let metrics = ContributorMetrics::new();
+        let handle = spawn_aggregator(&harness, &metrics);
+        let mut peer = Peer::new(&harness, harness.signers[1].clone());
+
+        start(&mut harness, 1).await;
+        peer.valid_share(1).await;
+
+        assert_eq!(
+            metrics.snapshot(),
+            ContributorMetricsSnapshot {
+                messages_received: 2,
+                rounds_started: 1,
+                signing_operations: 1,
+                rounds_aggregated: 1,
+                ..ContributorMetricsSnapshot::default()
+            }
+        );
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_garbage_signature_counted_once() {
+        let mut harness = Harness::new(2);
+        let metrics = ContributorMetrics::new();
+        let handle = spawn_aggregator(&harness, &metrics);
+        let mut peer = Peer::new(&harness, harness.signers[1].clone());
+
+        start(&mut harness, 1).await;
+        peer.share(1, b"garbage").await;
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.invalid_signatures, 1);
+        assert_eq!(snapshot.rounds_aggregated, 0);
+        assert_eq!(snapshot.decode_failures, 0);
+
+        // The peer can still contribute a valid share afterwards, but only once
+        peer.valid_share(1).await;
+        peer.valid_share(1).await;
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.invalid_signatures, 1);
+        assert_eq!(snapshot.rounds_aggregated, 1);
+        assert_eq!(snapshot.duplicate_shares, 1);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_rejected_frames_counted_by_reason() {
+        let mut harness = Harness::new(2);
+        let metrics = ContributorMetrics::new();
+        let handle = spawn_aggregator(&harness, &metrics);
+        let mut peer = Peer::new(&harness, harness.signers[1].clone());
+        let mut stranger = Peer::new(&harness, MockContributor::create_test_bn254(3000));
+
+        start(&mut harness, 1).await;
+        let frame = encode(&start_message(1));
+        peer.send(frame.slice(..frame.len() - 1)).await;
+        stranger.valid_share(1).await;
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.messages_received, 3);
+        assert_eq!(snapshot.decode_failures, 1);
+        assert_eq!(snapshot.unknown_contributors, 1);
+        assert_eq!(snapshot.invalid_signatures, 0);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_round_past_deadline_timed_out() {
+        let mut harness = Harness::new(2);
+        let metrics = ContributorMetrics::new();
+        let handle = spawn_aggregator(&harness, &metrics);
+        let mut peer = Peer::new(&harness, harness.signers[1].clone());
+
+        start(&mut harness, 1).await;
+        harness.advance(Duration::from_secs(60));
+        peer.valid_share(1).await;
+        peer.valid_share(1).await;
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.rounds_timed_out, 1);
+        assert_eq!(snapshot.rounds_aggregated, 0);
+        handle.abort();
+    }
+}
@@ -0,0 +1,170 @@
+use super::harness::{MockValidator, encode, start_message};
+use crate::validation::PayloadValidator;
+use crate::validation::counter::{
+    CachedCounterValidator, CounterCacheConfig, CounterCacheStatus, CounterSource, ValidationError,
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Counter source returning a settable value and counting reads
+#[derive(Default)]
+struct MockCounter {
+    confirmed: AtomicU64,
+    reads: AtomicUsize,
+}
+
+impl MockCounter {
+    fn new(confirmed: u64) -> Arc<Self> {
+        let counter = Self::default();
+        counter.confirmed.store(confirmed, Ordering::SeqCst);
+        Arc::new(counter)
+    }
+
+    fn reads(&self) -> usize {
+        self.reads.load(Ordering::SeqCst)
+    }
+}
+
+impl CounterSource for MockCounter {
+    async fn confirmed_counter(&self) -> Result<u64> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.confirmed.load(Ordering::SeqCst))
+    }
+}
+
+/// Inner validator counting the messages that reach it
+#[derive(Default)]
+struct CountingValidator(AtomicUsize);
+
+impl PayloadValidator for CountingValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async move { MockValidator.validate(message).await })
+    }
+}
+
+fn validator(
+    counter: &Arc<MockCounter>,
+    inner: &Arc<CountingValidator>,
+) -> CachedCounterValidator<MockCounter> {
+    CachedCounterValidator::new(
+        counter.clone(),
+        inner.clone(),
+        CounterCacheConfig {
+            ttl: Duration::from_secs(12),
+            tolerance: 1,
+        },
+    )
+}
+
+async fn validate(validator: &CachedCounterValidator<MockCounter>, round: u64) -> Result<[u8; 32]> {
+    validator.validate(&encode(&start_message(round))).await
+}
+
+fn rejection(result: Result<[u8; 32]>) -> ValidationError {
+    result
+        .unwrap_err()
+        .downcast::<ValidationError>()
+        .expect("validation error")
+}
+
+#[cfg(test)]
+mod counter_cache_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cache_hit() {
+        let counter = MockCounter::new(5);
+        let inner = Arc::new(CountingValidator::default());
+        let validator = validator(&counter, &inner);
+
+        validate(&validator, 6).await.unwrap();
+        tokio::time::advance(Duration::from_secs(5)).await;
+        validate(&validator, 6).await.unwrap();
+        assert_eq!(counter.reads(), 1);
+        assert_eq!(inner.0.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            validator.status().await,
+            Some(CounterCacheStatus {
+                confirmed: 5,
+                age: Duration::from_secs(5)
+            })
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_expired_cache_is_read_again() {
+        let counter = MockCounter::new(5);
+        let inner = Arc::new(CountingValidator::default());
+        let validator = validator(&counter, &inner);
+
+        validate(&validator, 6).await.unwrap();
+        counter.confirmed.store(6, Ordering::SeqCst);
+        tokio::time::advance(Duration::from_secs(12)).await;
+        validate(&validator, 7).await.unwrap();
+        assert_eq!(counter.reads(), 2);
+        assert_eq!(validator.status().await.unwrap().confirmed, 6);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_lagging_cache_within_tolerance() {
+        let counter = MockCounter::new(5);
+        let inner = Arc::new(CountingValidator::default());
+        let validator = validator(&counter, &inner);
+
+        // One increment not yet seen by the cache
+        validate(&validator, 7).await.unwrap();
+        assert_eq!(counter.reads(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stale_cache_falls_back_to_source() {
+        let counter = MockCounter::new(5);
+        let inner = Arc::new(CountingValidator::default());
+        let validator = validator(&counter, &inner);
+        validator.refresh().await.unwrap();
+
+        // The chain moved on by more than the tolerance
+        counter.confirmed.store(8, Ordering::SeqCst);
+        validate(&validator, 9).await.unwrap();
+        assert_eq!(counter.reads(), 2);
+        assert_eq!(validator.status().await.unwrap().confirmed, 8);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_skip_ahead_rejected_before_inner() {
+        let counter = MockCounter::new(5);
+        let inner = Arc::new(CountingValidator::default());
+        let validator = validator(&counter, &inner);
+        validator.refresh().await.unwrap();
+
+        assert_eq!(
+            rejection(validate(&validator, 8).await),
+            ValidationError::SkipsAhead {
+                confirmed: 5,
+                counter: 8
+            }
+        );
+        assert_eq!(counter.reads(), 2);
+        assert_eq!(inner.0.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stale_proposal_rejected_before_inner() {
+        let counter = MockCounter::new(5);
+        let inner = Arc::new(CountingValidator::default());
+        let validator = validator(&counter, &inner);
+
+        assert_eq!(
+            rejection(validate(&validator, 5).await),
+            ValidationError::NotIncreasing {
+                last: 5,
+                counter: 5
+            }
+        );
+        assert_eq!(inner.0.load(Ordering::SeqCst), 0);
+    }
+}
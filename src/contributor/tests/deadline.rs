@@ -0,0 +1,108 @@
+use super::harness::{EventLog, Harness, digest_of, encode, signature_message, start_message};
+use crate::contributor::deadline::BlockWindow;
+use crate::contributor::types::DroppedShare;
+use crate::handlers::RunConfig;
+#[cfg(feature = "observability")]
+use crate::metrics::{QuorumLabel, RejectionLabel};
+use crate::validation::metadata::{MetadataPolicy, MetadataReader, RoundMetadata};
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Reader reporting tasks at block 100 for every round
+fn reader() -> MetadataReader {
+    let deadline = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 60;
+    Arc::new(move |_: &CounterTaskData| RoundMetadata {
+        task_id: Some(b"task".to_vec()),
+        reference_block: Some(100),
+        deadline: Some(deadline),
+    })
+}
+
+#[cfg(test)]
+mod deadline_tests {
+    use super::*;
+
+    #[test]
+    fn test_deadline_from_blocks_remaining() {
+        // Task at block 100 with a 10 block window, chain at block 105
+        let window = BlockWindow::new(10, Duration::from_secs(12));
+        assert_eq!(window.blocks_remaining(100, 105), 5);
+        assert_eq!(window.deadline(100, 105), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_closed_window_has_no_time_left() {
+        let window = BlockWindow::new(10, Duration::from_secs(12));
+        assert_eq!(window.deadline(100, 110), Duration::ZERO);
+        assert_eq!(window.deadline(100, 200), Duration::ZERO);
+
+        // A head behind the reference block leaves the whole window
+        assert_eq!(window.blocks_remaining(100, 90), 20);
+    }
+
+    #[tokio::test]
+    async fn test_aggregator_stops_collecting_at_derived_deadline() {
+        let mut harness = Harness::new(2);
+        let log = EventLog::default();
+
+        // 5 blocks remaining at 100ms per block
+        let aggregator = harness
+            .contributor(0, Some(2))
+            .with_event_sink(Arc::new(log.clone()))
+            .with_chain_head(Arc::new(AtomicU64::new(105)))
+            .with_run_config(RunConfig {
+                metadata_policy: Some((MetadataPolicy::default(), reader())),
+                block_window: Some(BlockWindow::new(10, Duration::from_millis(100))),
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(aggregator, 0);
+        let (mut peer, _peer_receiver) = harness.network.register(harness.signers[1].public_key());
+        let aggregator_key = harness.signers[0].public_key();
+
+        // A share within the window is aggregated, one after it is dropped
+        for (round, delay) in [(1, Duration::ZERO), (2, Duration::from_millis(600))] {
+            harness.start(round).await;
+            harness.signed_rounds(Duration::from_millis(200)).await;
+            harness.advance(delay);
+            let signature = harness.signers[1].sign(None, &digest_of(&start_message(round)));
+            let share = encode(&signature_message(round, signature.to_vec()));
+            commonware_p2p::Sender::send(
+                &mut peer,
+                Recipients::One(aggregator_key.clone()),
+                share,
+                true,
+            )
+            .await
+            .unwrap();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        let deadline_passed = DroppedShare::DeadlinePassed.kind();
+        assert_eq!(log.count("threshold_reached"), 1);
+        assert_eq!(log.count_reason("share_dropped", deadline_passed), 1);
+        #[cfg(feature = "observability")]
+        {
+            let metrics = log.metrics();
+            let aggregated = metrics
+                .aggregation_threshold_reached
+                .get_or_create(&QuorumLabel { quorum_id: 0 })
+                .get();
+            assert_eq!(aggregated, 1);
+            let label = RejectionLabel {
+                quorum_id: 0,
+                reason: deadline_passed.to_string(),
+            };
+            assert_eq!(metrics.shares_dropped.get_or_create(&label).get(), 1);
+        }
+
+        handle.abort();
+    }
+}
@@ -0,0 +1,141 @@
+use super::harness::{Harness, LogBuffer, digest_of, encode, signature_message, start_message};
+use super::mock::MockContributor;
+use crate::contributor::decode::{DecodeFailure, MessageKind, classify, try_classify};
+use crate::contributor::events::ContributorMetrics;
+use crate::handlers::RunConfig;
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::wire;
+use commonware_codec::ReadExt;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Decode error for a frame
+fn decode_error(frame: &[u8]) -> commonware_codec::Error {
+    wire::Aggregation::<CounterTaskData>::read(&mut std::io::Cursor::new(frame))
+        .err()
+        .expect("frame should not decode")
+}
+
+#[cfg(test)]
+mod decode_tests {
+    use super::*;
+
+    #[test]
+    fn test_truncated_frame_is_classified_as_truncated() {
+        let frame = encode(&start_message(7));
+        let err = decode_error(&frame[..frame.len() - 1]);
+        assert_eq!(DecodeFailure::classify(&err), DecodeFailure::Truncated);
+    }
+
+    #[test]
+    fn test_bad_field_is_classified_as_malformed() {
+        let err = commonware_codec::Error::InvalidEnum(9);
+        assert_eq!(DecodeFailure::classify(&err), DecodeFailure::Malformed);
+    }
+
+    #[tokio::test]
+    async fn test_contributor_logs_truncated_frame() {
+        let logs = LogBuffer::default();
+        let _guard = logs.install();
+
+        let mut harness = Harness::new(1);
+        let handle = harness.spawn(harness.contributor(0, None), 0);
+        let frame = encode(&start_message(7));
+        let truncated = frame.slice(..frame.len() - 1);
+        commonware_p2p::Sender::send(
+            &mut harness.orchestrator_sender,
+            Recipients::All,
+            truncated,
+            true,
+        )
+        .await
+        .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let contents = logs.contents();
+        assert!(
+            contents.contains("dropping aggregation frame"),
+            "{contents}"
+        );
+        assert!(contents.contains("failure=truncated"), "{contents}");
+        assert!(!contents.contains("failure=malformed"), "{contents}");
+
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_oversized_frame_dropped_unparsed() {
+        let logs = LogBuffer::default();
+        let _guard = logs.install();
+
+        // Starts fit, shares carrying a signature do not
+        let mut harness = Harness::new(2);
+        let metrics = ContributorMetrics::new();
+        let max = encode(&start_message(1)).len();
+        let aggregator = harness
+            .contributor(0, Some(2))
+            .with_event_sink(Arc::new(metrics.clone()))
+            .with_run_config(RunConfig {
+                max_message_size: max,
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(aggregator, 0);
+        let (mut peer, _peer_receiver) = harness.network.register(harness.signers[1].public_key());
+
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+        let signature = harness.signers[1].sign(None, &digest_of(&start_message(1)));
+        let share = encode(&signature_message(1, signature.to_vec()));
+        assert!(share.len() > max);
+        commonware_p2p::Sender::send(&mut peer, Recipients::All, share, true)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.rounds_started, 1);
+        assert_eq!(snapshot.decode_failures, 1);
+        assert_eq!(snapshot.invalid_signatures, 0);
+        assert_eq!(snapshot.rounds_aggregated, 0);
+        let contents = logs.contents();
+        assert!(contents.contains("dropping oversized frame"), "{contents}");
+        assert!(
+            !contents.contains("dropping aggregation frame"),
+            "{contents}"
+        );
+        handle.abort();
+    }
+
+    #[test]
+    fn test_classify_start() {
+        assert_eq!(classify(&start_message(3)), MessageKind::Start);
+    }
+
+    #[test]
+    fn test_classify_signature() {
+        let signature = MockContributor::create_test_bn254(1).sign(None, b"payload");
+        let message = signature_message(3, signature.to_vec());
+        assert_eq!(classify(&message), MessageKind::Signature(signature));
+    }
+
+    #[test]
+    fn test_classify_malformed_signature_is_unknown() {
+        let message = signature_message(3, vec![0xab; 7]);
+        assert_eq!(classify(&message), MessageKind::Unknown);
+        assert_eq!(
+            try_classify(&message).unwrap_err().kind(),
+            "signature_length"
+        );
+    }
+
+    #[test]
+    fn test_classify_missing_payload_is_unknown() {
+        let message = wire::Aggregation::<CounterTaskData> {
+            payload: None,
+            ..start_message(3)
+        };
+        assert_eq!(classify(&message), MessageKind::Unknown);
+    }
+}
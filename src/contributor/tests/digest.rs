@@ -0,0 +1,305 @@
+use super::harness::{
+    EventLog, Harness, MockValidator, digest_of, encode, signature_message, start_message,
+};
+use crate::digest::{HashAlgorithm, SigningDomain, compute_signing_digest};
+use crate::validation::PayloadValidator;
+use anyhow::Result;
+use bn254::aggregate_verify;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use futures::future::BoxFuture;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Vectors shared with the router, see [crate::digest]
+const VECTORS: &str = include_str!("../../../tests/fixtures/signing_digest.json");
+
+#[derive(Deserialize)]
+struct Fixture {
+    vectors: Vec<Vector>,
+}
+
+#[derive(Deserialize)]
+struct Vector {
+    name: String,
+    hash: String,
+    domain: Option<String>,
+    digest: String,
+}
+
+fn bytes32(hex: &str) -> [u8; 32] {
+    alloy_primitives::hex::decode(hex)
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+/// Validator returning the same hash for every message
+struct FixedValidator([u8; 32]);
+
+impl PayloadValidator for FixedValidator {
+    fn validate<'a>(&'a self, _message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move { Ok(self.0) })
+    }
+}
+
+/// Digest of the Start of `round` under `domain`, for a validator hash shared by
+/// every round
+async fn digest_in_round(domain: &SigningDomain, round: u64) -> [u8; 32] {
+    compute_signing_digest(
+        &start_message(round),
+        &FixedValidator([7; 32]),
+        domain,
+        HashAlgorithm::Keccak256,
+    )
+    .await
+    .unwrap()
+}
+
+fn rotating(rounds_per_epoch: u64) -> SigningDomain {
+    SigningDomain::Rotating {
+        tag: b"COMMONWARE_AVS_V1".to_vec(),
+        rounds_per_epoch,
+    }
+}
+
+/// Whether an aggregator and a contributor with the given domains reach a threshold of two
+async fn aggregates(aggregator: SigningDomain, contributor: SigningDomain) -> bool {
+    let mut harness = Harness::new(2);
+    let log = EventLog::default();
+    let handles = [
+        harness.spawn(
+            harness
+                .contributor(0, Some(2))
+                .with_event_sink(Arc::new(log.clone()))
+                .with_signing_domain(aggregator),
+            0,
+        ),
+        harness.spawn(
+            harness
+                .contributor(1, None)
+                .with_signing_domain(contributor),
+            1,
+        ),
+    ];
+
+    harness.start(1).await;
+    harness.signed_rounds(Duration::from_millis(200)).await;
+    for handle in handles {
+        handle.abort();
+    }
+    log.count("threshold_reached") == 1
+}
+
+/// Whether an aggregator hashing with `node` accepts a share whose tagged digest was
+/// hashed with `share`, reaching a threshold of two
+async fn accepts_share(node: HashAlgorithm, share: HashAlgorithm) -> bool {
+    let domain = SigningDomain::Tagged(b"COMMONWARE_AVS_V1".to_vec());
+    let mut harness = Harness::new(2);
+    let log = EventLog::default();
+    let aggregator = harness
+        .contributor(0, Some(2))
+        .with_event_sink(Arc::new(log.clone()))
+        .with_signing_domain(domain.clone())
+        .with_payload_hash(node);
+    let handle = harness.spawn(aggregator, 0);
+    let (mut peer, _inbox) = harness.network.register(harness.signers[1].public_key());
+
+    harness.start(1).await;
+    harness
+        .orchestrator_receiver
+        .next_message(Duration::from_millis(500))
+        .await
+        .expect("aggregator signed");
+    let digest = domain.apply_in_round_with(share, 1, digest_of(&start_message(1)));
+    let signature = harness.signers[1].sign(None, &digest);
+    let frame = encode(&signature_message(1, signature.to_vec()));
+    let recipients = Recipients::One(harness.signers[0].public_key());
+    commonware_p2p::Sender::send(&mut peer, recipients, frame, true)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    handle.abort();
+
+    let reached = log.count("threshold_reached") == 1;
+    let invalid = log.count("invalid_signature") == 1;
+    assert_ne!(reached, invalid);
+    reached
+}
+
+#[cfg(test)]
+mod digest_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_shared_vectors() {
+        let fixture: Fixture = serde_json::from_str(VECTORS).unwrap();
+        assert!(!fixture.vectors.is_empty());
+        for vector in fixture.vectors {
+            let domain = match vector.domain {
+                Some(tag) => SigningDomain::Tagged(alloy_primitives::hex::decode(tag).unwrap()),
+                None => SigningDomain::None,
+            };
+            let validator = FixedValidator(bytes32(&vector.hash));
+            let digest = compute_signing_digest(
+                &start_message(1),
+                &validator,
+                &domain,
+                HashAlgorithm::Keccak256,
+            )
+            .await
+            .unwrap();
+            assert_eq!(digest, bytes32(&vector.digest), "{}", vector.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_and_signature_share_digest() {
+        let domain = SigningDomain::Tagged(b"COMMONWARE_AVS_V1".to_vec());
+        for round in [0, 1, u64::MAX] {
+            let start = compute_signing_digest(
+                &start_message(round),
+                &MockValidator,
+                &domain,
+                HashAlgorithm::Keccak256,
+            )
+            .await
+            .unwrap();
+            let signature = compute_signing_digest(
+                &signature_message(round, vec![1; 64]),
+                &MockValidator,
+                &domain,
+                HashAlgorithm::Keccak256,
+            )
+            .await
+            .unwrap();
+            assert_eq!(start, signature);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_domain_changes_digest() {
+        let untagged = compute_signing_digest(
+            &start_message(1),
+            &MockValidator,
+            &SigningDomain::None,
+            HashAlgorithm::Keccak256,
+        )
+        .await
+        .unwrap();
+        let tagged = compute_signing_digest(
+            &start_message(1),
+            &MockValidator,
+            &SigningDomain::Tagged(b"COMMONWARE_AVS_V1".to_vec()),
+            HashAlgorithm::Keccak256,
+        )
+        .await
+        .unwrap();
+        assert_ne!(untagged, tagged);
+    }
+
+    #[tokio::test]
+    async fn test_signatures_verified_under_same_domain() {
+        let domain = SigningDomain::Tagged(b"COMMONWARE_AVS_V1".to_vec());
+        assert!(aggregates(domain.clone(), domain.clone()).await);
+        assert!(!aggregates(domain, SigningDomain::None).await);
+    }
+
+    #[test]
+    fn test_namespace_of_epoch() {
+        let domain = rotating(10);
+        assert_eq!(domain.epoch(9), Some(0));
+        assert_eq!(domain.epoch(10), Some(1));
+        assert_eq!(
+            domain.namespace(19).unwrap(),
+            [&b"COMMONWARE_AVS_V1"[..], &1u64.to_be_bytes()].concat()
+        );
+        assert_eq!(SigningDomain::Tagged(b"tag".to_vec()).epoch(19), None);
+    }
+
+    #[tokio::test]
+    async fn test_signature_rejected_in_next_epoch() {
+        let domain = rotating(10);
+        let harness = Harness::new(1);
+        let key = harness.signers[0].public_key();
+        let signed = digest_in_round(&domain, 15).await;
+        let signature = harness.signers[0].sign(None, &signed);
+
+        // Any round of the epoch verifies it
+        let same_epoch = digest_in_round(&domain, 19).await;
+        assert!(aggregate_verify(
+            std::slice::from_ref(&key),
+            None,
+            &same_epoch,
+            &signature
+        ));
+
+        let next_epoch = digest_in_round(&domain, 20).await;
+        assert!(!aggregate_verify(
+            std::slice::from_ref(&key),
+            None,
+            &next_epoch,
+            &signature
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rotating_domain_shared_by_signer_and_verifier() {
+        assert!(aggregates(rotating(10), rotating(10)).await);
+        // The epoch is part of the namespace, so a static tag does not verify
+        let tagged = SigningDomain::Tagged(b"COMMONWARE_AVS_V1".to_vec());
+        assert!(!aggregates(rotating(10), tagged).await);
+    }
+
+    #[test]
+    fn test_hash_algorithms_match_references() {
+        assert_eq!(
+            HashAlgorithm::Keccak256.hash(b"abc"),
+            bytes32("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")
+        );
+        assert_eq!(
+            HashAlgorithm::Sha256.hash(b"abc"),
+            bytes32("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(HashAlgorithm::default(), HashAlgorithm::Keccak256);
+        for algorithm in [HashAlgorithm::Keccak256, HashAlgorithm::Sha256] {
+            assert_eq!(
+                algorithm.to_string().parse::<HashAlgorithm>().unwrap(),
+                algorithm
+            );
+        }
+        assert!("md5".parse::<HashAlgorithm>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_algorithm_applied_to_domain() {
+        let domain = SigningDomain::Tagged(b"COMMONWARE_AVS_V1".to_vec());
+        let hash = digest_of(&start_message(1));
+        let preimage = [&b"COMMONWARE_AVS_V1"[..], &hash].concat();
+        for algorithm in [HashAlgorithm::Keccak256, HashAlgorithm::Sha256] {
+            let digest =
+                compute_signing_digest(&start_message(1), &MockValidator, &domain, algorithm)
+                    .await
+                    .unwrap();
+            assert_eq!(digest, algorithm.hash(&preimage));
+        }
+        let keccak = compute_signing_digest(
+            &start_message(1),
+            &MockValidator,
+            &domain,
+            HashAlgorithm::Keccak256,
+        )
+        .await
+        .unwrap();
+        assert_eq!(keccak, alloy_primitives::keccak256(&preimage).0);
+        assert_eq!(keccak, domain.apply_in_round(1, hash));
+    }
+
+    #[tokio::test]
+    async fn test_keccak_node_rejects_sha256_digest() {
+        assert!(accepts_share(HashAlgorithm::Keccak256, HashAlgorithm::Keccak256).await);
+        assert!(!accepts_share(HashAlgorithm::Keccak256, HashAlgorithm::Sha256).await);
+        assert!(accepts_share(HashAlgorithm::Sha256, HashAlgorithm::Sha256).await);
+    }
+}
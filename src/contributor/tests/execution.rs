@@ -0,0 +1,245 @@
+use super::harness::Harness;
+use super::mock::MockContributor;
+use crate::contributor::digest_shares::{tag_digest, untag_digest};
+use crate::contributor::events::EventSink;
+use crate::contributor::rounds::RoundTable;
+use crate::contributor::sink::{AggregationResult, AggregationSink};
+use crate::digest::{HashAlgorithm, SigningDomain, compute_execution_digest};
+use crate::execution::{Executor, ExecutorRegistry};
+use crate::handlers::RunConfig;
+use anyhow::Result;
+use bytes::Bytes;
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_cryptography::Signer;
+use futures::future::BoxFuture;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+const AGREED: &[u8] = b"agreed response";
+
+const DIVERGENT: &[u8] = b"divergent response";
+
+/// Executor answering every task with a fixed response
+struct FixedExecutor(&'static [u8]);
+
+impl Executor for FixedExecutor {
+    fn execute<'a>(
+        &'a self,
+        _round: u64,
+        _task: &'a CounterTaskData,
+    ) -> BoxFuture<'a, Result<Vec<u8>>> {
+        Box::pin(async move { Ok(self.0.to_vec()) })
+    }
+}
+
+/// Executor failing round 1, never answering round 2 and answering later rounds
+struct UnreliableExecutor;
+
+impl Executor for UnreliableExecutor {
+    fn execute<'a>(
+        &'a self,
+        round: u64,
+        _task: &'a CounterTaskData,
+    ) -> BoxFuture<'a, Result<Vec<u8>>> {
+        Box::pin(async move {
+            match round {
+                1 => Err(anyhow::anyhow!("task source unavailable")),
+                2 => std::future::pending().await,
+                _ => Ok(AGREED.to_vec()),
+            }
+        })
+    }
+}
+
+/// Sink counting failed executions
+#[derive(Clone, Default)]
+struct ExecutionFailures(Arc<AtomicU64>);
+
+impl EventSink for ExecutionFailures {
+    fn execution_failed(&self, _quorum_id: u8) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Registry computing every task with an executor answering `response`
+fn executors(response: &'static [u8]) -> ExecutorRegistry {
+    ExecutorRegistry::new(Arc::new(|_: &CounterTaskData| Some("fetch".to_string())))
+        .with_executor("fetch", Arc::new(FixedExecutor(response)))
+}
+
+fn digest(round: u64, response: &[u8]) -> [u8; 32] {
+    compute_execution_digest(
+        round,
+        response,
+        &SigningDomain::default(),
+        HashAlgorithm::Keccak256,
+    )
+}
+
+/// Sink counting digest disagreements
+#[derive(Clone, Default)]
+struct Disagreements(Arc<AtomicU64>);
+
+impl EventSink for Disagreements {
+    fn digest_disagreement(&self, _quorum_id: u8) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Sink keeping every aggregate it is handed
+#[derive(Clone, Default)]
+struct Aggregates(Arc<Mutex<Vec<AggregationResult>>>);
+
+impl AggregationSink for Aggregates {
+    fn emit<'a>(&'a self, result: &'a AggregationResult) -> BoxFuture<'a, Result<()>> {
+        self.0.lock().unwrap().push(result.clone());
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Run four contributors computing every task, the last one reaching a divergent
+/// response, with the first aggregating at `threshold`
+async fn run_divergent(threshold: usize) -> (Harness, Aggregates, Disagreements) {
+    let mut harness = Harness::new(4);
+    let aggregates = Aggregates::default();
+    let disagreements = Disagreements::default();
+    let mut handles = Vec::new();
+    for i in 0..4 {
+        let contributor = if i == 0 {
+            harness
+                .contributor(i, Some(threshold))
+                .with_aggregation_sink(Arc::new(aggregates.clone()))
+                .with_event_sink(Arc::new(disagreements.clone()))
+        } else {
+            harness.contributor(i, None)
+        };
+        let response = if i == 3 { DIVERGENT } else { AGREED };
+        handles.push(harness.spawn(contributor.with_executors(executors(response)), i));
+    }
+
+    harness.start(1).await;
+    let deadline = Instant::now() + TIMEOUT;
+    while disagreements.0.load(Ordering::Relaxed) == 0 && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    for handle in handles {
+        handle.abort();
+    }
+    (harness, aggregates, disagreements)
+}
+
+#[cfg(test)]
+mod execution_tests {
+    use super::*;
+
+    #[test]
+    fn test_digest_tag_round_trip() {
+        let frame = Bytes::from_static(b"share");
+        let tagged = tag_digest(&[9; 32], &frame);
+        assert_eq!(untag_digest(&tagged), Some(([9; 32], frame.clone())));
+        assert_eq!(untag_digest(&frame), None);
+        // A tag without a share is not a tagged share
+        assert_eq!(untag_digest(&tagged.slice(..36)), None);
+    }
+
+    #[test]
+    fn test_execution_digest_binds_round_and_response() {
+        assert_ne!(digest(1, AGREED), digest(2, AGREED));
+        assert_ne!(digest(1, AGREED), digest(1, DIVERGENT));
+    }
+
+    #[test]
+    fn test_shares_grouped_by_digest() {
+        let signer = MockContributor::create_test_bn254(100);
+        let share = signer.sign(None, b"payload");
+        let mut rounds = RoundTable::new();
+        rounds.start_round(1, [1; 32], None);
+        for index in 0..3 {
+            let state = rounds
+                .record_share(1, index, share.clone(), Instant::now())
+                .unwrap();
+            state.share_digests.insert(index, [index.min(1) as u8; 32]);
+        }
+
+        // Rounds only validated aggregate every share
+        let state = rounds.get(1).unwrap();
+        assert_eq!(state.shares_over(&[0; 32]).len(), 3);
+
+        assert!(rounds.mark_executed(1));
+        assert!(!rounds.mark_executed(2));
+        let state = rounds.get(1).unwrap();
+        assert_eq!(state.shares_over(&[0; 32]).keys().collect::<Vec<_>>(), [&0]);
+        assert_eq!(
+            state.shares_over(&[1; 32]).keys().collect::<Vec<_>>(),
+            [&1, &2]
+        );
+        assert!(state.shares_over(&[2; 32]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_aggregates_only_matching_digests() {
+        let (harness, aggregates, disagreements) = run_divergent(3).await;
+        assert_eq!(disagreements.0.load(Ordering::Relaxed), 1);
+
+        let results = aggregates.0.lock().unwrap().clone();
+        assert!(!results.is_empty());
+        let mut contributors = harness.contributors();
+        contributors.sort();
+        let divergent = contributors
+            .iter()
+            .position(|key| *key == harness.signers[3].public_key())
+            .unwrap();
+        for result in results {
+            assert_eq!(result.round, 1);
+            assert_eq!(result.payload, digest(1, AGREED));
+            assert_eq!(result.signers.count(), 3);
+            assert!(!result.signers.get(divergent));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_failed_execution_skips_round() {
+        let mut harness = Harness::new(1);
+        let failures = ExecutionFailures::default();
+        let registry =
+            ExecutorRegistry::new(Arc::new(|_: &CounterTaskData| Some("fetch".to_string())))
+                .with_executor("fetch", Arc::new(UnreliableExecutor));
+        let contributor = harness
+            .contributor(0, None)
+            .with_executors(registry)
+            .with_event_sink(Arc::new(failures.clone()))
+            .with_run_config(RunConfig {
+                signing_timeout: Duration::from_secs(1),
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(contributor, 0);
+
+        // Neither the failed nor the hanging execution is signed
+        harness.start(1).await;
+        harness.start(2).await;
+        let signed = harness.signed_rounds(Duration::from_secs(5)).await;
+        assert!(signed.is_empty());
+        assert_eq!(failures.0.load(Ordering::Relaxed), 2);
+
+        // The contributor keeps serving later rounds
+        harness.start(3).await;
+        let signed = harness.signed_rounds(Duration::from_secs(1)).await;
+        let rounds = &signed[&harness.signers[0].public_key()];
+        assert_eq!(rounds.iter().copied().collect::<Vec<_>>(), vec![3]);
+        assert!(!handle.is_finished());
+
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_divergent_share_does_not_complete_threshold() {
+        let (_harness, aggregates, disagreements) = run_divergent(4).await;
+        assert_eq!(disagreements.0.load(Ordering::Relaxed), 1);
+        assert!(aggregates.0.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+use super::harness::{Harness, digest_of, start_message};
+use bn254::{PublicKey, aggregate_verify};
+use commonware_cryptography::Signer;
+use std::time::Duration;
+
+/// Keys of the signers at `indices`, sorted as contributors are indexed
+fn sorted_keys(harness: &Harness, indices: &[usize]) -> Vec<PublicKey> {
+    let mut keys: Vec<PublicKey> = indices
+        .iter()
+        .map(|index| harness.signers[*index].public_key())
+        .collect();
+    keys.sort();
+    keys
+}
+
+#[cfg(test)]
+mod export_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_exports_signatures_of_both_contributors() {
+        let mut harness = Harness::new(2);
+        let mut contributor = harness.contributor(0, Some(2));
+        let export = contributor.signature_export();
+        let handles = [
+            harness.spawn(contributor, 0),
+            harness.spawn(harness.contributor(1, None), 1),
+        ];
+
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+
+        let signatures = export.collected_signatures(1).await.unwrap();
+        let keys: Vec<PublicKey> = signatures.iter().map(|(key, _)| key.clone()).collect();
+        assert_eq!(keys, sorted_keys(&harness, &[0, 1]));
+
+        // Each signature verifies under the key it is exported with
+        let payload = digest_of(&start_message(1));
+        for (key, signature) in &signatures {
+            assert!(aggregate_verify(
+                std::slice::from_ref(key),
+                None,
+                &payload,
+                signature
+            ));
+        }
+
+        // Nothing was collected for a round never started
+        assert!(export.collected_signatures(2).await.unwrap().is_empty());
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_fails_once_stopped() {
+        let harness = Harness::new(1);
+        let mut contributor = harness.contributor(0, None);
+        let export = contributor.signature_export();
+        drop(contributor);
+        assert!(export.collected_signatures(1).await.is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use super::harness::Harness;
+use super::mock::MockContributor;
+use crate::contributor::fallback::{FallbackConfig, FallbackOrchestrator, Promotion};
+use crate::contributor::sink::{AggregationResult, AggregationSink};
+use crate::contributor::{DuplicatePolicy, QuorumCertificate, canonicalize_contributors};
+use crate::handlers::RunConfig;
+use anyhow::Result;
+use bn254::PublicKey;
+use commonware_cryptography::Signer;
+use futures::future::BoxFuture;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+const PROMOTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+const ROUND_INTERVAL: Duration = Duration::from_secs(60);
+
+fn config() -> FallbackConfig {
+    FallbackConfig {
+        promotion_timeout: PROMOTION_TIMEOUT,
+        round_interval: ROUND_INTERVAL,
+    }
+}
+
+fn keys(count: u64) -> Vec<PublicKey> {
+    let mut keys: Vec<PublicKey> = (0..count)
+        .map(|seed| MockContributor::create_test_bn254(400 + seed).public_key())
+        .collect();
+    keys.sort();
+    keys
+}
+
+/// Sink keeping every aggregate it is handed
+#[derive(Clone, Default)]
+struct Aggregates(Arc<Mutex<Vec<AggregationResult>>>);
+
+impl Aggregates {
+    fn rounds(&self) -> Vec<u64> {
+        let mut rounds: Vec<u64> = self.0.lock().unwrap().iter().map(|r| r.round).collect();
+        rounds.sort();
+        rounds.dedup();
+        rounds
+    }
+
+    fn get(&self, round: u64) -> Option<AggregationResult> {
+        let results = self.0.lock().unwrap();
+        results.iter().find(|result| result.round == round).cloned()
+    }
+}
+
+impl AggregationSink for Aggregates {
+    fn emit<'a>(&'a self, result: &'a AggregationResult) -> BoxFuture<'a, Result<()>> {
+        self.0.lock().unwrap().push(result.clone());
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[cfg(test)]
+mod fallback_tests {
+    use super::*;
+
+    #[test]
+    fn test_highest_indexed_contributor_promoted_once() {
+        let contributors = keys(3);
+        let now = Instant::now();
+        let mut fallback = FallbackOrchestrator::new(config(), now);
+        assert_eq!(fallback.deadline(), Some(now + PROMOTION_TIMEOUT));
+
+        // Not yet silent for long enough
+        let early = now + PROMOTION_TIMEOUT / 2;
+        assert_eq!(
+            fallback.check_silence(&contributors, &contributors[2], early),
+            None
+        );
+
+        let silent = now + PROMOTION_TIMEOUT;
+        let promotion = fallback.check_silence(&contributors, &contributors[0], silent);
+        assert_eq!(
+            promotion,
+            Some(Promotion {
+                key: contributors[2].clone(),
+                is_self: false,
+            })
+        );
+        assert_eq!(
+            fallback.check_silence(&contributors, &contributors[0], silent),
+            None
+        );
+
+        // Only the promoted contributor starts rounds
+        assert_eq!(fallback.next_round(silent), None);
+        assert_eq!(fallback.deadline(), None);
+    }
+
+    #[test]
+    fn test_promoted_rounds_follow_the_primary() {
+        let contributors = keys(3);
+        let now = Instant::now();
+        let mut fallback = FallbackOrchestrator::new(config(), now);
+        fallback.observe_round(7);
+
+        let silent = now + PROMOTION_TIMEOUT;
+        let promotion = fallback
+            .check_silence(&contributors, &contributors[2], silent)
+            .unwrap();
+        assert!(promotion.is_self);
+        assert_eq!(fallback.next_round(silent), Some(8));
+        assert_eq!(fallback.next_round(silent), None);
+        assert_eq!(fallback.deadline(), Some(silent + ROUND_INTERVAL));
+        assert_eq!(fallback.next_round(silent + ROUND_INTERVAL), Some(9));
+
+        // The primary coming back ends the promotion and delays the next one
+        let back = silent + ROUND_INTERVAL * 2;
+        assert_eq!(fallback.heard_primary(back), Some(promotion));
+        assert_eq!(fallback.next_round(back), None);
+        assert_eq!(fallback.deadline(), Some(back + PROMOTION_TIMEOUT));
+    }
+
+    #[tokio::test]
+    async fn test_fallback_promoted_when_primary_silent() {
+        let mut harness = Harness::new(3);
+        let aggregates = Aggregates::default();
+        let handles: Vec<_> = (0..3)
+            .map(|i| {
+                let contributor = harness
+                    .contributor(i, Some(3))
+                    .with_aggregation_sink(Arc::new(aggregates.clone()))
+                    .with_run_config(RunConfig {
+                        fallback: Some(config()),
+                        ..RunConfig::default()
+                    });
+                harness.spawn(contributor, i)
+            })
+            .collect();
+
+        // The primary starts a round, then goes silent
+        harness.start(1).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(aggregates.rounds(), [1]);
+        harness.advance(PROMOTION_TIMEOUT);
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // The promoted contributor started the next round and certified it
+        assert_eq!(aggregates.rounds(), [1, 2]);
+        let result = aggregates.get(2).unwrap();
+        let contributors =
+            canonicalize_contributors(harness.contributors(), DuplicatePolicy::Reject).unwrap();
+        let certificate = QuorumCertificate {
+            round: result.round,
+            payload: result.payload,
+            signature: result.signature,
+            signers: result.signers,
+        };
+        assert_eq!(certificate.signers.count(), 3);
+        assert!(certificate.verify(&contributors));
+
+        // No further round before the interval
+        harness.advance(ROUND_INTERVAL / 2);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(aggregates.rounds(), [1, 2]);
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_promotion_while_primary_active() {
+        let mut harness = Harness::new(3);
+        let aggregates = Aggregates::default();
+        let handles: Vec<_> = (0..3)
+            .map(|i| {
+                let contributor = harness
+                    .contributor(i, Some(3))
+                    .with_aggregation_sink(Arc::new(aggregates.clone()))
+                    .with_run_config(RunConfig {
+                        fallback: Some(config()),
+                        ..RunConfig::default()
+                    });
+                harness.spawn(contributor, i)
+            })
+            .collect();
+
+        // The primary keeps starting rounds within the timeout
+        for round in 1..=3 {
+            harness.advance(PROMOTION_TIMEOUT / 2);
+            harness.start(round).await;
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(aggregates.rounds(), [1, 2, 3]);
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
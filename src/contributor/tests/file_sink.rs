@@ -0,0 +1,100 @@
+use super::harness::{Harness, digest_of, start_message};
+use crate::contributor::sink::{AggregationResult, AggregationSink, FileSink};
+use bn254::{Signature as Bn254Signature, aggregate_signatures};
+use commonware_cryptography::Signer;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Fresh directory under the system temp dir, unique to `name` and this process
+fn sink_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("file-sink-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Aggregate the harness signers produce for the Start of `round`
+fn expected_result(harness: &Harness, round: u64) -> AggregationResult {
+    let payload = digest_of(&start_message(round));
+    let signatures: Vec<Bn254Signature> = harness
+        .signers
+        .iter()
+        .map(|signer| signer.sign(None, &payload))
+        .collect();
+    AggregationResult {
+        round,
+        payload,
+        signature: aggregate_signatures(&signatures).unwrap(),
+        signers: (0..harness.signers.len()).collect(),
+    }
+}
+
+#[cfg(test)]
+mod file_sink_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_aggregate_written_per_round() {
+        let mut harness = Harness::new(2);
+        let dir = sink_dir("round");
+        let sink = FileSink::new(&dir).unwrap();
+        let handles = [
+            harness.spawn(
+                harness
+                    .contributor(0, Some(2))
+                    .with_aggregation_sink(Arc::new(sink.clone())),
+                0,
+            ),
+            harness.spawn(harness.contributor(1, None), 1),
+        ];
+
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+
+        let expected = expected_result(&harness, 1);
+        let contents = std::fs::read_to_string(sink.path(1)).unwrap();
+        assert_eq!(contents, expected.to_json().unwrap());
+        assert_eq!(AggregationResult::from_json(&contents).unwrap(), expected);
+
+        // Only the renamed file is left behind
+        let files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(files, ["round-1.json"]);
+
+        for handle in handles {
+            handle.abort();
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_replaced_when_emitted_again() {
+        let harness = Harness::new(2);
+        let dir = sink_dir("replace");
+        let sink = FileSink::new(&dir).unwrap();
+        let first = expected_result(&harness, 1);
+        let second = AggregationResult {
+            signers: [0].into_iter().collect(),
+            ..first.clone()
+        };
+
+        sink.emit(&first).await.unwrap();
+        sink.emit(&second).await.unwrap();
+        let contents = std::fs::read_to_string(sink.path(1)).unwrap();
+        assert_eq!(AggregationResult::from_json(&contents).unwrap(), second);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_file_rejected() {
+        let harness = Harness::new(2);
+        let json = expected_result(&harness, 1).to_json().unwrap();
+        assert!(
+            AggregationResult::from_json(&json.replace("\"payload\": \"", "\"payload\": \"00"))
+                .is_err()
+        );
+        assert!(AggregationResult::from_json("{}").is_err());
+    }
+}
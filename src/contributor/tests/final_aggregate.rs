@@ -0,0 +1,281 @@
+use super::harness::{Harness, digest_of, start_message};
+use super::mock::MockContributor;
+use crate::contributor::events::EventSink;
+use crate::contributor::final_aggregate::{
+    AggregateCheck, AggregateRejection, FinalAggregate, verify_final_aggregate,
+};
+use crate::contributor::rounds::RoundTable;
+use crate::contributor::types::ParticipationBitmap;
+use bn254::{Bn254, G1PublicKey, PublicKey, Signature as Bn254Signature, aggregate_signatures};
+use bytes::Bytes;
+use commonware_codec::{EncodeSize, Error, ReadExt, Write};
+use commonware_cryptography::Signer;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+const PAYLOAD: [u8; 32] = [9; 32];
+
+/// Signers sorted by public key, as contributors are indexed
+fn sorted_signers(count: u64) -> Vec<Bn254> {
+    let mut signers: Vec<Bn254> = (0..count)
+        .map(|i| MockContributor::create_test_bn254(300 + i))
+        .collect();
+    signers.sort_by_key(|signer| signer.public_key());
+    signers
+}
+
+fn keys(signers: &[Bn254]) -> Vec<PublicKey> {
+    signers.iter().map(|signer| signer.public_key()).collect()
+}
+
+fn g1_map(contributors: &[PublicKey]) -> HashMap<PublicKey, G1PublicKey> {
+    contributors
+        .iter()
+        .map(|key| (key.clone(), MockContributor::placeholder_g1_key().unwrap()))
+        .collect()
+}
+
+/// Aggregate of the signers at `indices` over `payload`
+fn aggregate(signers: &[Bn254], indices: &[usize], payload: &[u8]) -> Bn254Signature {
+    let signatures: Vec<Bn254Signature> = indices
+        .iter()
+        .map(|index| signers[*index].sign(None, payload))
+        .collect();
+    aggregate_signatures(&signatures).unwrap()
+}
+
+fn final_aggregate(round: u64, signature: &Bn254Signature, indices: &[usize]) -> FinalAggregate {
+    FinalAggregate {
+        round,
+        signature: signature.to_vec(),
+        signers: indices.iter().copied().collect(),
+    }
+}
+
+fn envelope(aggregate: &FinalAggregate) -> Bytes {
+    let mut buf = Vec::with_capacity(aggregate.encode_size());
+    aggregate.write(&mut buf);
+    Bytes::from(buf)
+}
+
+/// Sink counting final aggregates that did not match
+#[derive(Clone, Default)]
+struct MismatchCounter(Arc<AtomicU64>);
+
+impl EventSink for MismatchCounter {
+    fn aggregate_mismatch(&self, _quorum_id: u8) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod final_aggregate_tests {
+    use super::*;
+
+    #[test]
+    fn test_encoding_round_trips() {
+        let signers = sorted_signers(3);
+        let aggregate = final_aggregate(7, &aggregate(&signers, &[0, 2], &PAYLOAD), &[0, 2]);
+        let frame = envelope(&aggregate);
+        assert!(FinalAggregate::is_final_aggregate(&frame));
+        let decoded = FinalAggregate::read(&mut &frame[..]).unwrap();
+        assert_eq!(decoded, aggregate);
+
+        // Frames without the prefix are not final aggregates
+        assert!(!FinalAggregate::is_final_aggregate(&frame[1..]));
+        assert!(FinalAggregate::read(&mut &frame[1..]).is_err());
+    }
+
+    #[test]
+    fn test_oversized_signature_rejected() {
+        let aggregate = FinalAggregate {
+            round: 1,
+            signature: vec![1; 1024],
+            signers: [0].into_iter().collect(),
+        };
+        let frame = envelope(&aggregate);
+        assert!(matches!(
+            FinalAggregate::read(&mut &frame[..]),
+            Err(Error::InvalidLength(1024))
+        ));
+    }
+
+    #[test]
+    fn test_matching_aggregate() {
+        let signers = sorted_signers(3);
+        let contributors = keys(&signers);
+        let g1_map = g1_map(&contributors);
+        let signature = aggregate(&signers, &[0, 1, 2], &PAYLOAD);
+        let aggregate = final_aggregate(1, &signature, &[0, 1, 2]);
+
+        let ours = (signature, aggregate.signers);
+        let check = verify_final_aggregate(
+            &aggregate,
+            &PAYLOAD,
+            &contributors,
+            Some(&g1_map),
+            Some(&ours),
+        );
+        assert_eq!(check, Ok(AggregateCheck::Match));
+
+        // Another valid signer set over the same payload matches too
+        let ours = (
+            super::aggregate(&signers, &[0, 1], &PAYLOAD),
+            [0, 1].into_iter().collect(),
+        );
+        let check = verify_final_aggregate(
+            &aggregate,
+            &PAYLOAD,
+            &contributors,
+            Some(&g1_map),
+            Some(&ours),
+        );
+        assert_eq!(check, Ok(AggregateCheck::Match));
+    }
+
+    #[test]
+    fn test_aggregate_without_local_aggregate() {
+        let signers = sorted_signers(3);
+        let contributors = keys(&signers);
+        let aggregate = final_aggregate(1, &aggregate(&signers, &[1, 2], &PAYLOAD), &[1, 2]);
+        let check = verify_final_aggregate(&aggregate, &PAYLOAD, &contributors, None, None);
+        assert_eq!(check, Ok(AggregateCheck::NotAggregatedLocally));
+    }
+
+    #[test]
+    fn test_aggregate_over_other_payload_mismatches() {
+        let signers = sorted_signers(3);
+        let contributors = keys(&signers);
+        let g1_map = g1_map(&contributors);
+        let ours = (
+            aggregate(&signers, &[0, 1, 2], &PAYLOAD),
+            [0, 1, 2].into_iter().collect(),
+        );
+        let aggregate = final_aggregate(1, &aggregate(&signers, &[0, 1, 2], &[8; 32]), &[0, 1, 2]);
+
+        let check = verify_final_aggregate(
+            &aggregate,
+            &PAYLOAD,
+            &contributors,
+            Some(&g1_map),
+            Some(&ours),
+        );
+        assert_eq!(check, Ok(AggregateCheck::Mismatch));
+        let check = verify_final_aggregate(&aggregate, &PAYLOAD, &contributors, None, None);
+        assert_eq!(check, Ok(AggregateCheck::Mismatch));
+    }
+
+    #[test]
+    fn test_aggregate_with_wrong_signers_mismatches() {
+        let signers = sorted_signers(3);
+        let contributors = keys(&signers);
+        // Signed by 0 and 1, claimed by 0 and 2
+        let aggregate = final_aggregate(1, &aggregate(&signers, &[0, 1], &PAYLOAD), &[0, 2]);
+        let check = verify_final_aggregate(&aggregate, &PAYLOAD, &contributors, None, None);
+        assert_eq!(check, Ok(AggregateCheck::Mismatch));
+    }
+
+    #[test]
+    fn test_bitmap_with_stranger_fails() {
+        let signers = sorted_signers(4);
+        let contributors = keys(&signers[..3]);
+        let g1_map = g1_map(&contributors);
+        // The fourth signer is not a contributor
+        let aggregate = final_aggregate(1, &aggregate(&signers, &[0, 3], &PAYLOAD), &[0, 3]);
+        let check =
+            verify_final_aggregate(&aggregate, &PAYLOAD, &contributors, Some(&g1_map), None);
+        assert_eq!(check, Err(AggregateRejection::UnknownParticipant(3)));
+
+        // A contributor without a G1 key cannot be checked on-chain
+        let mut partial = g1_map.clone();
+        partial.remove(&contributors[1]);
+        let aggregate = final_aggregate(1, &super::aggregate(&signers, &[0, 1], &PAYLOAD), &[0, 1]);
+        let check =
+            verify_final_aggregate(&aggregate, &PAYLOAD, &contributors, Some(&partial), None);
+        assert_eq!(check, Err(AggregateRejection::UnknownParticipant(1)));
+    }
+
+    #[test]
+    fn test_empty_or_malformed_aggregate_fails() {
+        let signers = sorted_signers(3);
+        let contributors = keys(&signers);
+        let signature = aggregate(&signers, &[0], &PAYLOAD);
+
+        let empty = final_aggregate(1, &signature, &[]);
+        let check = verify_final_aggregate(&empty, &PAYLOAD, &contributors, None, None);
+        assert_eq!(check, Err(AggregateRejection::NoSigners));
+
+        let mut malformed = final_aggregate(1, &signature, &[0]);
+        malformed.signature.truncate(8);
+        let check = verify_final_aggregate(&malformed, &PAYLOAD, &contributors, None, None);
+        assert_eq!(check, Err(AggregateRejection::Malformed));
+    }
+
+    #[test]
+    fn test_result_recorded_in_round_table() {
+        let mut rounds = RoundTable::new();
+        assert!(!rounds.record_final_aggregate(1, AggregateCheck::Match));
+
+        let signers = sorted_signers(1);
+        let signature = aggregate(&signers, &[0], &PAYLOAD);
+        let bitmap: ParticipationBitmap = [0].into_iter().collect();
+        rounds.start_round(1, PAYLOAD, None);
+        rounds.record_aggregate(1, signature.clone(), bitmap);
+        assert!(rounds.record_final_aggregate(1, AggregateCheck::Mismatch));
+        let state = rounds.get(1).unwrap();
+        assert_eq!(state.aggregate, Some((signature, bitmap)));
+        assert_eq!(state.final_aggregate, Some(AggregateCheck::Mismatch));
+    }
+
+    #[tokio::test]
+    async fn test_contributor_reports_mismatching_final_aggregate() {
+        let mut harness = Harness::new(3);
+        let mismatches = MismatchCounter::default();
+        let mut handles = vec![
+            harness.spawn(
+                harness
+                    .contributor(0, Some(3))
+                    .with_event_sink(Arc::new(mismatches.clone())),
+                0,
+            ),
+        ];
+        for i in 1..3 {
+            handles.push(harness.spawn(harness.contributor(i, None), i));
+        }
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+
+        let mut signers = harness.signers.clone();
+        signers.sort_by_key(|signer| signer.public_key());
+        let payload = digest_of(&start_message(1));
+
+        // Matching aggregate
+        let matching = aggregate(&signers, &[0, 1, 2], &payload);
+        harness
+            .broadcast(envelope(&final_aggregate(1, &matching, &[0, 1, 2])))
+            .await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(mismatches.0.load(Ordering::Relaxed), 0);
+
+        // Aggregate over another payload
+        let other = aggregate(&signers, &[0, 1, 2], &[0; 32]);
+        harness
+            .broadcast(envelope(&final_aggregate(1, &other, &[0, 1, 2])))
+            .await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(mismatches.0.load(Ordering::Relaxed), 1);
+
+        // Aggregate claiming a stranger is rejected without being recorded
+        harness
+            .broadcast(envelope(&final_aggregate(1, &other, &[0, 5])))
+            .await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(mismatches.0.load(Ordering::Relaxed), 1);
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
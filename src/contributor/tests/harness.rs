@@ -0,0 +1,652 @@
+use super::mock::{MockContributor, MockError};
+use crate::clock::MockClock;
+use crate::contributor::aggregated::Aggregated;
+#[cfg(not(feature = "observability"))]
+use crate::contributor::events::NoopEventSink;
+use crate::contributor::events::{EventSink, ThresholdCounter};
+use crate::contributor::replay::Capture;
+use crate::contributor::sync::SyncMessage;
+use crate::contributor::transcript::{Transcript, TranscriptSetup};
+use crate::contributor::{AggregationInput, Contribute, OutboundRouter};
+use crate::handlers::Contributor;
+#[cfg(feature = "observability")]
+use crate::metrics::Metrics;
+use crate::validation::{PayloadValidator, ValidatorFactory};
+use anyhow::Result;
+use bn254::{Bn254, PublicKey};
+use bytes::Bytes;
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::wire::{self, aggregation::Payload};
+use commonware_codec::{EncodeSize, ReadExt, Write};
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use futures::future::BoxFuture;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{Instrument, Span};
+
+/// Encode an aggregation message
+pub fn encode(message: &wire::Aggregation<CounterTaskData>) -> Bytes {
+    let mut buf = Vec::with_capacity(message.encode_size());
+    message.write(&mut buf);
+    Bytes::from(buf)
+}
+
+/// Decode an aggregation message, `None` for other frames
+pub fn decode(frame: &Bytes) -> Option<wire::Aggregation<CounterTaskData>> {
+    wire::Aggregation::read(&mut std::io::Cursor::new(frame.clone())).ok()
+}
+
+/// Start message for a round
+pub fn start_message(round: u64) -> wire::Aggregation<CounterTaskData> {
+    wire::Aggregation {
+        round,
+        metadata: Default::default(),
+        payload: Some(Payload::Start),
+    }
+}
+
+/// Signature message for a round
+pub fn signature_message(round: u64, signature: Vec<u8>) -> wire::Aggregation<CounterTaskData> {
+    wire::Aggregation {
+        round,
+        metadata: Default::default(),
+        payload: Some(Payload::Signature(signature)),
+    }
+}
+
+/// Aggregation input over `contributors` with `threshold`
+pub fn aggregation_input(contributors: &[PublicKey], threshold: usize) -> AggregationInput {
+    // G1 keys only feed the APK, placeholders are enough for the harness
+    let g1_map = contributors
+        .iter()
+        .map(|key| {
+            let g1 = MockContributor::placeholder_g1_key().expect("placeholder g1 key");
+            (key.clone(), g1)
+        })
+        .collect();
+    AggregationInput::new(threshold, g1_map)
+}
+
+/// Digest [MockValidator] returns for a message: the hash of the message without its payload
+pub fn digest_of(message: &wire::Aggregation<CounterTaskData>) -> [u8; 32] {
+    let unsigned = wire::Aggregation::<CounterTaskData> {
+        round: message.round,
+        metadata: message.metadata.clone(),
+        payload: None,
+    };
+    alloy_primitives::keccak256(encode(&unsigned)).0
+}
+
+/// Validator accepting every decodable message, independent of chain state
+#[derive(Clone, Debug, Default)]
+pub struct MockValidator;
+
+impl PayloadValidator for MockValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move {
+            let message = decode(&Bytes::copy_from_slice(message))
+                .ok_or_else(|| anyhow::anyhow!("undecodable message"))?;
+            Ok(digest_of(&message))
+        })
+    }
+}
+
+impl ValidatorFactory for MockValidator {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        let validator: Arc<dyn PayloadValidator> = Arc::new(self.clone());
+        Box::pin(async move { Ok(validator) })
+    }
+}
+
+/// Validator taking `delay` before accepting a message
+#[derive(Clone)]
+pub struct SlowValidator {
+    pub delay: Duration,
+}
+
+impl PayloadValidator for SlowValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move {
+            tokio::time::sleep(self.delay).await;
+            MockValidator.validate(message).await
+        })
+    }
+}
+
+impl ValidatorFactory for SlowValidator {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        let validator: Arc<dyn PayloadValidator> = Arc::new(self.clone());
+        Box::pin(async move { Ok(validator) })
+    }
+}
+
+type Inbox = mpsc::UnboundedSender<(PublicKey, Bytes)>;
+
+/// In-memory network connecting any number of peers, with partitions and cut links
+#[derive(Clone, Debug, Default)]
+pub struct MockNetwork {
+    inboxes: Arc<Mutex<HashMap<PublicKey, Inbox>>>,
+    partitioned: Arc<Mutex<HashSet<PublicKey>>>,
+    /// Links dropping traffic, in both directions
+    cut: Arc<Mutex<HashSet<(PublicKey, PublicKey)>>>,
+}
+
+impl MockNetwork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Connect a peer, returning its sender and receiver
+    pub fn register(&self, key: PublicKey) -> (NetworkSender, NetworkReceiver) {
+        let (inbox, receiver) = mpsc::unbounded_channel();
+        self.inboxes.lock().unwrap().insert(key.clone(), inbox);
+        (
+            NetworkSender {
+                me: key,
+                network: self.clone(),
+            },
+            NetworkReceiver { inbox: receiver },
+        )
+    }
+
+    /// Drop all traffic to and from a peer
+    pub fn partition(&self, key: &PublicKey) {
+        self.partitioned.lock().unwrap().insert(key.clone());
+    }
+
+    /// Restore traffic to and from a peer
+    pub fn heal(&self, key: &PublicKey) {
+        self.partitioned.lock().unwrap().remove(key);
+    }
+
+    /// Drop all traffic between two peers, each still reaching every other peer
+    pub fn cut(&self, a: &PublicKey, b: &PublicKey) {
+        let mut cut = self.cut.lock().unwrap();
+        cut.insert((a.clone(), b.clone()));
+        cut.insert((b.clone(), a.clone()));
+    }
+
+    fn deliver(
+        &self,
+        from: &PublicKey,
+        recipients: Recipients<PublicKey>,
+        message: Bytes,
+    ) -> Vec<PublicKey> {
+        let partitioned = self.partitioned.lock().unwrap();
+        if partitioned.contains(from) {
+            return Vec::new();
+        }
+        let cut = self.cut.lock().unwrap();
+        let inboxes = self.inboxes.lock().unwrap();
+        let targets: Vec<PublicKey> = match recipients {
+            Recipients::All => inboxes.keys().cloned().collect(),
+            Recipients::Some(keys) => keys,
+            Recipients::One(key) => vec![key],
+        };
+        targets
+            .into_iter()
+            .filter(|key| key != from && !partitioned.contains(key))
+            .filter(|key| !cut.contains(&(from.clone(), key.clone())))
+            .filter(|key| match inboxes.get(key) {
+                Some(inbox) => inbox.send((from.clone(), message.clone())).is_ok(),
+                None => false,
+            })
+            .collect()
+    }
+}
+
+/// Sending half of a [MockNetwork] peer
+#[derive(Clone, Debug)]
+pub struct NetworkSender {
+    me: PublicKey,
+    network: MockNetwork,
+}
+
+impl commonware_p2p::Sender for NetworkSender {
+    type Error = MockError;
+    type PublicKey = PublicKey;
+
+    async fn send(
+        &mut self,
+        recipients: Recipients<Self::PublicKey>,
+        message: Bytes,
+        _priority: bool,
+    ) -> Result<Vec<Self::PublicKey>, Self::Error> {
+        Ok(self.network.deliver(&self.me, recipients, message))
+    }
+}
+
+/// Receiving half of a [MockNetwork] peer
+#[derive(Debug)]
+pub struct NetworkReceiver {
+    inbox: mpsc::UnboundedReceiver<(PublicKey, Bytes)>,
+}
+
+impl NetworkReceiver {
+    /// Next aggregation message received within `timeout`, skipping other frames
+    pub async fn next_message(
+        &mut self,
+        timeout: Duration,
+    ) -> Option<(PublicKey, wire::Aggregation<CounterTaskData>)> {
+        tokio::time::timeout(timeout, async {
+            loop {
+                let (sender, frame) = self.inbox.recv().await?;
+                if SyncMessage::is_sync(&frame) || Aggregated::is_aggregated(&frame) {
+                    continue;
+                }
+                if let Some(message) = decode(&frame) {
+                    return Some((sender, message));
+                }
+            }
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+
+    /// Next aggregate returned within `timeout`, skipping other frames
+    pub async fn next_aggregated(&mut self, timeout: Duration) -> Option<(PublicKey, Aggregated)> {
+        tokio::time::timeout(timeout, async {
+            loop {
+                let (sender, frame) = self.inbox.recv().await?;
+                if let Ok(aggregated) = Aggregated::read(&mut std::io::Cursor::new(frame)) {
+                    return Some((sender, aggregated));
+                }
+            }
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+}
+
+impl commonware_p2p::Receiver for NetworkReceiver {
+    type Error = MockError;
+    type PublicKey = PublicKey;
+
+    async fn recv(&mut self) -> Result<(Self::PublicKey, Bytes), Self::Error> {
+        self.inbox
+            .recv()
+            .await
+            .ok_or_else(|| MockError("network closed".to_string()))
+    }
+}
+
+/// Contributors sharing a [MockNetwork] with an orchestrator driven by the test
+pub struct Harness {
+    pub network: MockNetwork,
+    pub orchestrator: Bn254,
+    pub signers: Vec<Bn254>,
+    pub orchestrator_sender: NetworkSender,
+    pub orchestrator_receiver: NetworkReceiver,
+    /// Clock of the contributors built by the harness
+    pub clock: Arc<MockClock>,
+}
+
+impl Harness {
+    /// Create a harness with `contributors` deterministic signers
+    pub fn new(contributors: u64) -> Self {
+        let network = MockNetwork::new();
+        let orchestrator = MockContributor::create_test_bn254(1000);
+        let signers = (0..contributors as usize)
+            .map(|i| MockContributor::create_test_bn254(Self::signer_seed(i)))
+            .collect();
+        let (orchestrator_sender, orchestrator_receiver) =
+            network.register(orchestrator.public_key());
+        Self {
+            network,
+            orchestrator,
+            signers,
+            orchestrator_sender,
+            orchestrator_receiver,
+            clock: Arc::new(MockClock::new(SystemTime::now())),
+        }
+    }
+
+    /// Move the clock of the contributors forward by `by` without sleeping
+    pub fn advance(&self, by: Duration) {
+        self.clock.advance(by);
+    }
+
+    /// Seed of the test key of the signer at `index`
+    pub fn signer_seed(index: usize) -> u64 {
+        2000 + index as u64
+    }
+
+    /// Public keys of all contributors
+    pub fn contributors(&self) -> Vec<PublicKey> {
+        self.signers
+            .iter()
+            .map(|signer| signer.public_key())
+            .collect()
+    }
+
+    /// Aggregation input over all contributors with `threshold`
+    pub fn aggregation_input(&self, threshold: usize) -> AggregationInput {
+        aggregation_input(&self.contributors(), threshold)
+    }
+
+    /// Contributor for the signer at `index`, aggregating with `threshold` if set
+    pub fn contributor(&self, index: usize, threshold: Option<usize>) -> Contributor {
+        Contributor::new(
+            self.orchestrator.public_key(),
+            self.signers[index].clone(),
+            self.contributors(),
+            threshold.map(|threshold| self.aggregation_input(threshold)),
+        )
+        .unwrap()
+        .with_validator_factory(Arc::new(MockValidator))
+        .with_clock(self.clock.clone())
+    }
+
+    /// Connect a contributor to the network and run it in the background, within the
+    /// current span
+    pub fn spawn(&self, contributor: Contributor, index: usize) -> JoinHandle<Result<()>> {
+        let (sender, receiver) = self.network.register(self.signers[index].public_key());
+        tokio::spawn(
+            contributor
+                .run(OutboundRouter::single(sender), receiver)
+                .instrument(Span::current()),
+        )
+    }
+
+    /// Broadcast a Start for `round` from the orchestrator
+    pub async fn start(&mut self, round: u64) {
+        self.broadcast(encode(&start_message(round))).await;
+    }
+
+    /// Broadcast a raw frame from the orchestrator
+    pub async fn broadcast(&mut self, frame: Bytes) {
+        commonware_p2p::Sender::send(&mut self.orchestrator_sender, Recipients::All, frame, true)
+            .await
+            .unwrap();
+    }
+
+    /// Record a transcript of the first signer aggregating `rounds` with `threshold`
+    ///
+    /// Every other signer runs as a plain contributor. Transcripts recorded this way
+    /// are the fixtures later releases are checked against.
+    pub async fn record_transcript(&mut self, rounds: u64, threshold: usize) -> Transcript {
+        let setup = TranscriptSetup::new(
+            Self::signer_seed(0),
+            &self.orchestrator.public_key(),
+            &self.contributors(),
+            Some(threshold),
+            SystemTime::now(),
+        );
+        let capture = Capture::new();
+        let aggregated = ThresholdCounter::new();
+        let aggregator = self
+            .contributor(0, Some(threshold))
+            .with_event_sink(Arc::new(aggregated.clone()))
+            .with_validator_factory(capture.validator_factory(Arc::new(MockValidator)));
+        let (sender, receiver) = self.network.register(self.signers[0].public_key());
+        let mut handles = vec![tokio::spawn(aggregator.run(
+            OutboundRouter::single(capture.sender(sender)),
+            capture.receiver(receiver),
+        ))];
+        for i in 1..self.signers.len() {
+            handles.push(self.spawn(self.contributor(i, None), i));
+        }
+
+        for round in 1..=rounds {
+            self.start(round).await;
+        }
+        self.signed_rounds(Duration::from_millis(200)).await;
+        for handle in handles {
+            handle.abort();
+        }
+
+        Transcript::record(setup, &capture, aggregated.get())
+    }
+
+    /// Collect the rounds each contributor signed, as seen by the orchestrator
+    pub async fn signed_rounds(&mut self, timeout: Duration) -> HashMap<PublicKey, HashSet<u64>> {
+        let mut signed: HashMap<PublicKey, HashSet<u64>> = HashMap::new();
+        while let Some((sender, message)) = self.orchestrator_receiver.next_message(timeout).await {
+            if let Some(Payload::Signature(_)) = message.payload {
+                signed.entry(sender).or_default().insert(message.round);
+            }
+        }
+        signed
+    }
+}
+
+/// Log sink capturing formatted tracing output
+#[derive(Clone, Debug, Default)]
+pub struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl LogBuffer {
+    /// Install a subscriber writing to this buffer for the current thread
+    pub fn install(&self) -> tracing::subscriber::DefaultGuard {
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(self.clone())
+            .finish();
+        tracing::subscriber::set_default(subscriber)
+    }
+
+    /// Everything logged so far
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+    }
+}
+
+impl std::io::Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for LogBuffer {
+    type Writer = LogBuffer;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// Sink recording the events a contributor reports by name and reason
+///
+/// With the `observability` feature the events also feed Prometheus [Metrics], so
+/// tests can check both. Cloning is cheap and clones share the record.
+#[derive(Clone, Default)]
+pub struct EventLog {
+    events: Arc<Mutex<Vec<(&'static str, Option<String>)>>>,
+    #[cfg(feature = "observability")]
+    metrics: Metrics,
+}
+
+impl EventLog {
+    /// Times `event` was reported, whatever its reason
+    pub fn count(&self, event: &str) -> usize {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(name, _)| *name == event)
+            .count()
+    }
+
+    /// Times `event` was reported for `reason`
+    pub fn count_reason(&self, event: &str, reason: &str) -> usize {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(name, logged)| *name == event && logged.as_deref() == Some(reason))
+            .count()
+    }
+
+    /// Prometheus metrics fed the same events
+    #[cfg(feature = "observability")]
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    fn record(
+        &self,
+        event: &'static str,
+        reason: Option<&str>,
+        forward: impl FnOnce(&dyn EventSink),
+    ) {
+        self.events
+            .lock()
+            .unwrap()
+            .push((event, reason.map(str::to_string)));
+        #[cfg(feature = "observability")]
+        forward(&self.metrics);
+        #[cfg(not(feature = "observability"))]
+        forward(&NoopEventSink);
+    }
+}
+
+impl EventSink for EventLog {
+    fn message_received(&self, quorum_id: u8) {
+        self.record("message_received", None, |sink| {
+            sink.message_received(quorum_id)
+        });
+    }
+
+    fn round_started(&self, quorum_id: u8) {
+        self.record("round_started", None, |sink| sink.round_started(quorum_id));
+    }
+
+    fn round_signed(&self, quorum_id: u8) {
+        self.record("round_signed", None, |sink| sink.round_signed(quorum_id));
+    }
+
+    fn signature_received(&self, quorum_id: u8) {
+        self.record("signature_received", None, |sink| {
+            sink.signature_received(quorum_id)
+        });
+    }
+
+    fn invalid_signature(&self, quorum_id: u8) {
+        self.record("invalid_signature", None, |sink| {
+            sink.invalid_signature(quorum_id)
+        });
+    }
+
+    fn duplicate_share(&self, quorum_id: u8) {
+        self.record("duplicate_share", None, |sink| {
+            sink.duplicate_share(quorum_id)
+        });
+    }
+
+    fn threshold_reached(&self, quorum_id: u8) {
+        self.record("threshold_reached", None, |sink| {
+            sink.threshold_reached(quorum_id)
+        });
+    }
+
+    fn aggregation_failed(&self, quorum_id: u8) {
+        self.record("aggregation_failed", None, |sink| {
+            sink.aggregation_failed(quorum_id)
+        });
+    }
+
+    fn observe_latency(&self, quorum_id: u8, duration: Duration) {
+        self.record("observe_latency", None, |sink| {
+            sink.observe_latency(quorum_id, duration)
+        });
+    }
+
+    fn observe_validation(&self, quorum_id: u8, duration: Duration) {
+        self.record("observe_validation", None, |sink| {
+            sink.observe_validation(quorum_id, duration)
+        });
+    }
+
+    fn validation_failed(&self, quorum_id: u8) {
+        self.record("validation_failed", None, |sink| {
+            sink.validation_failed(quorum_id)
+        });
+    }
+
+    fn round_timed_out(&self, quorum_id: u8) {
+        self.record("round_timed_out", None, |sink| {
+            sink.round_timed_out(quorum_id)
+        });
+    }
+
+    fn metadata_rejected(&self, quorum_id: u8, reason: &str) {
+        self.record("metadata_rejected", Some(reason), |sink| {
+            sink.metadata_rejected(quorum_id, reason)
+        });
+    }
+
+    fn share_dropped(&self, quorum_id: u8, reason: &str) {
+        self.record("share_dropped", Some(reason), |sink| {
+            sink.share_dropped(quorum_id, reason)
+        });
+    }
+
+    fn round_completed_on_chain(&self, quorum_id: u8) {
+        self.record("round_completed_on_chain", None, |sink| {
+            sink.round_completed_on_chain(quorum_id)
+        });
+    }
+
+    fn decode_failed(&self, quorum_id: u8, reason: &str) {
+        self.record("decode_failed", Some(reason), |sink| {
+            sink.decode_failed(quorum_id, reason)
+        });
+    }
+
+    fn peer_quarantined(&self, quorum_id: u8) {
+        self.record("peer_quarantined", None, |sink| {
+            sink.peer_quarantined(quorum_id)
+        });
+    }
+
+    fn peer_ignored(&self, quorum_id: u8) {
+        self.record("peer_ignored", None, |sink| sink.peer_ignored(quorum_id));
+    }
+
+    fn aggregate_mismatch(&self, quorum_id: u8) {
+        self.record("aggregate_mismatch", None, |sink| {
+            sink.aggregate_mismatch(quorum_id)
+        });
+    }
+
+    fn digest_disagreement(&self, quorum_id: u8) {
+        self.record("digest_disagreement", None, |sink| {
+            sink.digest_disagreement(quorum_id)
+        });
+    }
+
+    fn execution_failed(&self, quorum_id: u8) {
+        self.record("execution_failed", None, |sink| {
+            sink.execution_failed(quorum_id)
+        });
+    }
+
+    fn round_preempted(&self, quorum_id: u8) {
+        self.record("round_preempted", None, |sink| {
+            sink.round_preempted(quorum_id)
+        });
+    }
+
+    fn orchestrator_stale(&self, quorum_id: u8) {
+        self.record("orchestrator_stale", None, |sink| {
+            sink.orchestrator_stale(quorum_id)
+        });
+    }
+
+    fn round_rejected_capacity(&self, quorum_id: u8) {
+        self.record("round_rejected_capacity", None, |sink| {
+            sink.round_rejected_capacity(quorum_id)
+        });
+    }
+}
@@ -0,0 +1,125 @@
+use super::mock::MockContributor;
+use crate::chain::ChainSubmitter;
+use crate::contributor::sink::{AggregationResult, AggregationSink, IdempotentSink};
+use crate::handlers::VoteSubmitter;
+use alloy::rpc::types::TransactionRequest;
+use alloy_primitives::{Address, TxHash};
+use anyhow::{Result, anyhow};
+use commonware_cryptography::Signer;
+use futures::future::BoxFuture;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+const CONTRACT: Address = Address::repeat_byte(0xc);
+
+/// Submitter counting the transactions it is asked to send
+#[derive(Default)]
+struct CountingSubmitter {
+    calls: AtomicUsize,
+    failing: AtomicBool,
+}
+
+impl ChainSubmitter for CountingSubmitter {
+    fn submit(&self, _transaction: TransactionRequest) -> BoxFuture<'_, Result<TxHash>> {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        Box::pin(async move {
+            if self.failing.load(Ordering::Relaxed) {
+                return Err(anyhow!("transaction reverted"));
+            }
+            Ok(TxHash::ZERO)
+        })
+    }
+}
+
+/// Vote submitter behind a guard, with the submitter it calls
+fn guarded(log: Option<&PathBuf>) -> (IdempotentSink, Arc<CountingSubmitter>) {
+    let submitter = Arc::new(CountingSubmitter::default());
+    let inner = Arc::new(VoteSubmitter::new(submitter.clone(), CONTRACT));
+    let sink = match log {
+        Some(log) => IdempotentSink::with_log(inner, log).unwrap(),
+        None => IdempotentSink::new(inner),
+    };
+    (sink, submitter)
+}
+
+/// Aggregate of `round` over `payload`
+fn aggregate(round: u64, payload: [u8; 32]) -> AggregationResult {
+    let signer = MockContributor::create_test_bn254(1);
+    AggregationResult {
+        round,
+        payload,
+        signature: signer.sign(None, &payload),
+        signers: [0].into_iter().collect(),
+    }
+}
+
+/// Fresh log path under the system temp dir, unique to `name` and this process
+fn log_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("submitted-{name}-{}.log", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[cfg(test)]
+mod idempotent_sink_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_same_result_submitted_once() {
+        let (sink, submitter) = guarded(None);
+        let result = aggregate(1, [7; 32]);
+
+        sink.emit(&result).await.unwrap();
+        sink.emit(&result).await.unwrap();
+        assert_eq!(submitter.calls.load(Ordering::Relaxed), 1);
+        assert!(sink.is_submitted(&[7; 32]));
+
+        // Another payload is submitted as usual
+        sink.emit(&aggregate(2, [8; 32])).await.unwrap();
+        assert_eq!(submitter.calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_submission_retried() {
+        let (sink, submitter) = guarded(None);
+        let result = aggregate(1, [7; 32]);
+
+        submitter.failing.store(true, Ordering::Relaxed);
+        assert!(sink.emit(&result).await.is_err());
+        assert!(!sink.is_submitted(&[7; 32]));
+
+        submitter.failing.store(false, Ordering::Relaxed);
+        sink.emit(&result).await.unwrap();
+        assert_eq!(submitter.calls.load(Ordering::Relaxed), 2);
+        assert!(sink.is_submitted(&[7; 32]));
+    }
+
+    #[tokio::test]
+    async fn test_submitted_hashes_survive_restart() {
+        let log = log_path("restart");
+        let (sink, submitter) = guarded(Some(&log));
+        sink.emit(&aggregate(1, [7; 32])).await.unwrap();
+        assert_eq!(submitter.calls.load(Ordering::Relaxed), 1);
+        drop(sink);
+
+        let (restarted, submitter) = guarded(Some(&log));
+        restarted.emit(&aggregate(1, [7; 32])).await.unwrap();
+        assert_eq!(submitter.calls.load(Ordering::Relaxed), 0);
+
+        let _ = std::fs::remove_file(&log);
+    }
+
+    #[test]
+    fn test_corrupt_log_rejected() {
+        let log = log_path("corrupt");
+        std::fs::write(&log, "not a hash\n").unwrap();
+        let inner = Arc::new(VoteSubmitter::new(
+            Arc::new(CountingSubmitter::default()),
+            CONTRACT,
+        ));
+        assert!(IdempotentSink::with_log(inner, &log).is_err());
+
+        let _ = std::fs::remove_file(&log);
+    }
+}
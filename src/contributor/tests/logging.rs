@@ -0,0 +1,81 @@
+use super::harness::{Harness, LogBuffer, digest_of, start_message};
+use crate::handlers::RunConfig;
+use crate::logging::{Hex, LogPolicy, PREFIX_LEN, REDACTED, short};
+use std::time::Duration;
+
+const FULL: LogPolicy = LogPolicy { full_values: true };
+
+/// Line logged when signing `round`, from a single contributor logging under `policy`
+async fn signing_line(round: u64, policy: LogPolicy) -> String {
+    let logs = LogBuffer::default();
+    let _guard = logs.install();
+    let mut harness = Harness::new(1);
+    let contributor = harness.contributor(0, None).with_run_config(RunConfig {
+        log_policy: policy,
+        ..RunConfig::default()
+    });
+    let handle = harness.spawn(contributor, 0);
+    harness.start(round).await;
+    harness.signed_rounds(Duration::from_millis(200)).await;
+    handle.abort();
+
+    let contents = logs.contents();
+    contents
+        .lines()
+        .find(|line| line.contains("Generating signature for round"))
+        .unwrap_or_else(|| panic!("no signing logged in:\n{contents}"))
+        .to_string()
+}
+
+#[cfg(test)]
+mod logging_tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_writes_every_byte() {
+        assert_eq!(Hex(&[0x00, 0xab, 0x0f]).to_string(), "00ab0f");
+        assert_eq!(Hex(&[0x11; 16]).to_string(), "11".repeat(16));
+        assert_eq!(Hex(&[]).to_string(), "");
+    }
+
+    #[test]
+    fn test_values_truncated_by_default() {
+        let key = [0xab; 48];
+        let truncated = format!("{}..", "ab".repeat(PREFIX_LEN));
+        let policy = LogPolicy::default();
+        assert_eq!(policy.short(&key).to_string(), truncated);
+        assert_eq!(short(&key).to_string(), truncated);
+        assert_eq!(policy.short(&[1u8, 2, 3]).to_string(), "010203");
+        assert_eq!(format!("{:?}", policy.short(&key)), truncated);
+        assert_eq!(
+            policy.short_list(&[[1u8; 32], [2; 32]]).to_string(),
+            format!("[{}.., {}..]", "01".repeat(8), "02".repeat(8))
+        );
+        assert_eq!(policy.redacted("refused payload").to_string(), REDACTED);
+    }
+
+    #[test]
+    fn test_values_in_full() {
+        let key = [0xab; 48];
+        assert_eq!(FULL.short(&key).to_string(), "ab".repeat(48));
+        assert_eq!(
+            FULL.short_list(&[[1u8; 32]]).to_string(),
+            format!("[{}]", "01".repeat(32))
+        );
+        assert_eq!(
+            FULL.redacted("refused payload").to_string(),
+            "refused payload"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_contributor_logs_under_policy() {
+        let digest = digest_of(&start_message(1));
+        let line = signing_line(1, LogPolicy::default()).await;
+        assert!(line.contains(&short(&digest).to_string()), "{line}");
+        assert!(!line.contains(&Hex(&digest).to_string()), "{line}");
+
+        let line = signing_line(1, FULL).await;
+        assert!(line.contains(&Hex(&digest).to_string()), "{line}");
+    }
+}
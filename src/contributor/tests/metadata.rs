@@ -0,0 +1,284 @@
+use super::harness::{EventLog, Harness};
+use crate::handlers::RunConfig;
+#[cfg(feature = "observability")]
+use crate::metrics::RejectionLabel;
+use crate::validation::metadata::{
+    MetadataPolicy, MetadataReader, MetadataViolation, RoundMetadata,
+};
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_cryptography::Signer;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const NOW: u64 = 1_700_000_000;
+
+fn now() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(NOW)
+}
+
+fn valid_metadata() -> RoundMetadata {
+    RoundMetadata {
+        task_id: Some(b"task-1".to_vec()),
+        reference_block: Some(100),
+        deadline: Some(NOW + 60),
+    }
+}
+
+#[cfg(test)]
+mod metadata_policy_tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_metadata_passes() {
+        let policy = MetadataPolicy::default();
+        assert_eq!(policy.check(&valid_metadata(), now(), Some(100)), Ok(()));
+    }
+
+    #[test]
+    fn test_required_fields() {
+        let policy = MetadataPolicy::default();
+        let cases = [
+            (
+                RoundMetadata {
+                    task_id: None,
+                    ..valid_metadata()
+                },
+                "task_id",
+            ),
+            (
+                RoundMetadata {
+                    task_id: Some(Vec::new()),
+                    ..valid_metadata()
+                },
+                "task_id",
+            ),
+            (
+                RoundMetadata {
+                    reference_block: None,
+                    ..valid_metadata()
+                },
+                "reference_block",
+            ),
+            (
+                RoundMetadata {
+                    deadline: None,
+                    ..valid_metadata()
+                },
+                "deadline",
+            ),
+        ];
+        for (metadata, field) in cases {
+            assert_eq!(
+                policy.check(&metadata, now(), None),
+                Err(MetadataViolation::MissingField(field))
+            );
+        }
+
+        // Optional fields may be absent
+        let relaxed = MetadataPolicy {
+            require_task_id: false,
+            require_reference_block: false,
+            require_deadline: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            relaxed.check(&RoundMetadata::default(), now(), None),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_reference_block_tolerance() {
+        let policy = MetadataPolicy {
+            reference_block_tolerance: 2,
+            ..Default::default()
+        };
+        let metadata = RoundMetadata {
+            reference_block: Some(102),
+            ..valid_metadata()
+        };
+        assert_eq!(policy.check(&metadata, now(), Some(100)), Ok(()));
+        assert_eq!(
+            policy.check(&metadata, now(), Some(99)),
+            Err(MetadataViolation::ReferenceBlockInFuture {
+                reference_block: 102,
+                head: 99
+            })
+        );
+
+        // Without a known head the reference block cannot be bounded
+        assert_eq!(policy.check(&metadata, now(), None), Ok(()));
+    }
+
+    #[test]
+    fn test_deadline_must_be_in_the_future() {
+        let policy = MetadataPolicy::default();
+        let metadata = RoundMetadata {
+            deadline: Some(NOW),
+            ..valid_metadata()
+        };
+        assert_eq!(
+            policy.check(&metadata, now(), None),
+            Err(MetadataViolation::DeadlinePassed {
+                deadline: NOW,
+                now: NOW
+            })
+        );
+    }
+
+    #[test]
+    fn test_deadline_horizon() {
+        let policy = MetadataPolicy {
+            max_deadline_horizon: Duration::from_secs(600),
+            ..Default::default()
+        };
+        let at_horizon = RoundMetadata {
+            deadline: Some(NOW + 600),
+            ..valid_metadata()
+        };
+        assert_eq!(policy.check(&at_horizon, now(), None), Ok(()));
+
+        let beyond = RoundMetadata {
+            deadline: Some(NOW + 601),
+            ..valid_metadata()
+        };
+        assert_eq!(
+            policy.check(&beyond, now(), None),
+            Err(MetadataViolation::DeadlineTooFar {
+                deadline: NOW + 601,
+                max: NOW + 600
+            })
+        );
+    }
+
+    #[test]
+    fn test_deadline_checked_within_clock_skew() {
+        let policy = MetadataPolicy {
+            max_deadline_horizon: Duration::from_secs(600),
+            ..Default::default()
+        };
+        let skew = Duration::from_secs(5);
+        let check = |deadline: u64| {
+            let metadata = RoundMetadata {
+                deadline: Some(deadline),
+                ..valid_metadata()
+            };
+            policy.check_with_tolerance(&metadata, now(), None, skew)
+        };
+
+        // A clock ahead by the skew has not reached a deadline just passed
+        assert_eq!(check(NOW - 4), Ok(()));
+        assert_eq!(
+            check(NOW - 5),
+            Err(MetadataViolation::DeadlinePassed {
+                deadline: NOW - 5,
+                now: NOW
+            })
+        );
+
+        // A clock behind by the skew is not that far from a deadline beyond the horizon
+        assert_eq!(check(NOW + 605), Ok(()));
+        assert_eq!(
+            check(NOW + 606),
+            Err(MetadataViolation::DeadlineTooFar {
+                deadline: NOW + 606,
+                max: NOW + 605
+            })
+        );
+    }
+
+    #[test]
+    fn test_task_id_length() {
+        let policy = MetadataPolicy {
+            max_task_id_len: 4,
+            ..Default::default()
+        };
+        let metadata = RoundMetadata {
+            task_id: Some(b"too-long".to_vec()),
+            ..valid_metadata()
+        };
+        assert_eq!(
+            policy.check(&metadata, now(), None),
+            Err(MetadataViolation::TaskIdTooLong { len: 8, max: 4 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod metadata_harness_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_contributor_rejects_inconsistent_metadata() {
+        let mut harness = Harness::new(1);
+        let log = EventLog::default();
+
+        // The metadata the reader reports for the next Start
+        let current = Arc::new(Mutex::new(RoundMetadata::default()));
+        let reader: MetadataReader = {
+            let current = current.clone();
+            Arc::new(move |_: &CounterTaskData| current.lock().unwrap().clone())
+        };
+        let contributor = harness
+            .contributor(0, None)
+            .with_event_sink(Arc::new(log.clone()))
+            .with_run_config(RunConfig {
+                metadata_policy: Some((MetadataPolicy::default(), reader)),
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(contributor, 0);
+
+        let wall_clock = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let rounds = [
+            RoundMetadata {
+                deadline: Some(wall_clock + 60),
+                ..valid_metadata()
+            },
+            RoundMetadata {
+                task_id: None,
+                deadline: Some(wall_clock + 60),
+                ..valid_metadata()
+            },
+            RoundMetadata {
+                deadline: Some(wall_clock - 60),
+                ..valid_metadata()
+            },
+        ];
+        let mut signed = Vec::new();
+        for (round, metadata) in (1..).zip(rounds) {
+            *current.lock().unwrap() = metadata;
+            harness.start(round).await;
+            let rounds = harness.signed_rounds(Duration::from_millis(200)).await;
+            signed.extend(
+                rounds
+                    .get(&harness.signers[0].public_key())
+                    .into_iter()
+                    .flatten()
+                    .copied(),
+            );
+        }
+        assert_eq!(signed, vec![1]);
+
+        assert_eq!(log.count_reason("metadata_rejected", "missing_field"), 1);
+        assert_eq!(log.count_reason("metadata_rejected", "deadline_passed"), 1);
+        #[cfg(feature = "observability")]
+        {
+            let rejections = |reason: &str| {
+                log.metrics()
+                    .metadata_rejections
+                    .get_or_create(&RejectionLabel {
+                        quorum_id: 0,
+                        reason: reason.to_string(),
+                    })
+                    .get()
+            };
+            assert_eq!(rejections("missing_field"), 1);
+            assert_eq!(rejections("deadline_passed"), 1);
+        }
+
+        handle.abort();
+    }
+}
@@ -0,0 +1,137 @@
+use super::harness::{Harness, LogBuffer, SlowValidator};
+use crate::contributor::events::EventSink;
+use crate::handlers::RunConfig;
+use crate::metrics::{Metrics, QuorumLabel};
+use prometheus_client::encoding::text::encode;
+use prometheus_client::registry::Registry;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Encode the registry in the Prometheus text format
+fn encoded(metrics: &Metrics) -> String {
+    let mut registry = Registry::default();
+    metrics.register(&mut registry);
+    let mut buf = String::new();
+    encode(&mut buf, &registry).unwrap();
+    buf
+}
+
+#[cfg(test)]
+mod metrics_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_metrics_are_labelled_by_quorum() {
+        let metrics = Metrics::new();
+        metrics.round_started(0);
+        metrics.signature_received(0);
+        metrics.threshold_reached(0);
+        metrics.aggregation_failed(0);
+        metrics.observe_latency(0, Duration::from_millis(5));
+
+        let output = encoded(&metrics);
+        assert!(output.contains("aggregation_rounds_total{quorum_id=\"0\"} 1"));
+        assert!(output.contains("signatures_received_total{quorum_id=\"0\"} 1"));
+        assert!(output.contains("aggregation_threshold_reached_total{quorum_id=\"0\"} 1"));
+        assert!(output.contains("aggregation_failures_total{quorum_id=\"0\"} 1"));
+        assert!(output.contains("aggregation_latency_seconds_count{quorum_id=\"0\"} 1"));
+    }
+
+    #[test]
+    fn test_label_cardinality() {
+        let metrics = Metrics::new();
+        for quorum_id in [0, 1, 1, 2] {
+            metrics.round_started(quorum_id);
+        }
+
+        let output = encoded(&metrics);
+        let series = output
+            .lines()
+            .filter(|line| line.starts_with("aggregation_rounds_total{"))
+            .count();
+        assert_eq!(series, 3);
+    }
+
+    #[test]
+    fn test_quorums_are_independent() {
+        let metrics = Metrics::new();
+        metrics.signature_received(0);
+        metrics.signature_received(1);
+        metrics.signature_received(1);
+
+        let get = |quorum_id| {
+            metrics
+                .signatures_received
+                .get_or_create(&QuorumLabel { quorum_id })
+                .get()
+        };
+        assert_eq!(get(0), 1);
+        assert_eq!(get(1), 2);
+        assert_eq!(get(2), 0);
+    }
+}
+
+#[cfg(test)]
+mod validation_duration_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_validation_is_recorded_and_logged() {
+        let logs = LogBuffer::default();
+        let _guard = logs.install();
+
+        let mut harness = Harness::new(1);
+        let metrics = Metrics::new();
+        let contributor = harness
+            .contributor(0, None)
+            .with_validator_factory(Arc::new(SlowValidator {
+                delay: Duration::from_secs(2),
+            }))
+            .with_metrics(metrics.clone())
+            .with_run_config(RunConfig {
+                slow_validation_threshold: Duration::from_secs(1),
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(contributor, 0);
+
+        harness.start(1).await;
+        let signed = harness.signed_rounds(Duration::from_secs(5)).await;
+        assert_eq!(signed.len(), 1);
+
+        let output = encoded(&metrics);
+        assert!(output.contains("avs_validation_duration_seconds_count{quorum_id=\"0\"} 1"));
+        let sum: f64 = output
+            .lines()
+            .find_map(|line| {
+                line.strip_prefix("avs_validation_duration_seconds_sum{quorum_id=\"0\"} ")
+            })
+            .expect("validation duration sum")
+            .parse()
+            .unwrap();
+        assert!(sum > 1.0, "recorded {sum}");
+        assert!(logs.contents().contains("slow validation"));
+
+        handle.abort();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_fast_validation_does_not_warn() {
+        let logs = LogBuffer::default();
+        let _guard = logs.install();
+
+        let mut harness = Harness::new(1);
+        let metrics = Metrics::new();
+        let contributor = harness.contributor(0, None).with_metrics(metrics.clone());
+        let handle = harness.spawn(contributor, 0);
+
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_secs(1)).await;
+
+        assert!(
+            encoded(&metrics).contains("avs_validation_duration_seconds_count{quorum_id=\"0\"} 1")
+        );
+        assert!(!logs.contents().contains("slow validation"));
+
+        handle.abort();
+    }
+}
@@ -1,7 +1,12 @@
-use crate::contributor::{AggregationInput, Contribute, ContributorBase};
+pub use crate::contributor::replay::SentTo;
+use crate::contributor::types::assigned_contributors;
+use crate::contributor::{
+    AggregationInput, Assignment, Contribute, ContributeError, ContributorBase, OutboundRouter,
+};
+use crate::types::ContributorIndex;
 use anyhow::Result;
 use ark_bn254::Fr;
-use bn254::{Bn254, PrivateKey, PublicKey, Signature as Bn254Signature};
+use bn254::{Bn254, G1PublicKey, PrivateKey, PublicKey, Signature as Bn254Signature};
 use commonware_cryptography::Signer;
 use commonware_p2p::{Receiver, Sender};
 use std::collections::HashMap;
@@ -12,9 +17,10 @@ use std::fmt;
 pub struct MockContributor {
     pub orchestrator: PublicKey,
     pub signer: Bn254,
-    pub me: usize,
+    pub me: ContributorIndex,
     pub contributors: Vec<PublicKey>,
-    pub ordered_contributors: HashMap<PublicKey, usize>,
+    pub ordered_contributors: HashMap<PublicKey, ContributorIndex>,
+    pub assignment: Option<Assignment>,
     pub aggregation_data: Option<AggregationInput>,
 }
 
@@ -27,48 +33,80 @@ impl ContributorBase for MockContributor {
         &self.orchestrator == sender
     }
 
-    fn get_contributor_index(&self, public_key: &Self::PublicKey) -> Option<&usize> {
+    fn get_contributor_index(&self, public_key: &Self::PublicKey) -> Option<&ContributorIndex> {
         self.ordered_contributors.get(public_key)
     }
+
+    fn contributors_for_round(&self, round: u64) -> Vec<Self::PublicKey> {
+        assigned_contributors(self.assignment.as_ref(), round, &self.contributors)
+    }
 }
 
 impl Contribute for MockContributor {
     type AggregationInput = AggregationInput;
 
+    /// Panics on a threshold out of range, see [MockContributor::try_new]
     fn new(
+        orchestrator: PublicKey,
+        signer: Bn254,
+        contributors: Vec<PublicKey>,
+        aggregation_data: Option<AggregationInput>,
+    ) -> Result<Self, ContributeError> {
+        match Self::try_new(orchestrator, signer, contributors, aggregation_data) {
+            Ok(contributor) => Ok(contributor),
+            Err(MockConfigError::SignerNotContributor) => {
+                Err(ContributeError::SignerNotInContributors)
+            }
+            Err(err) => panic!("invalid mock contributor: {err}"),
+        }
+    }
+
+    async fn run<S, R>(self, _router: OutboundRouter<S>, _receiver: R) -> Result<()>
+    where
+        S: Sender<PublicKey = PublicKey>,
+        R: Receiver<PublicKey = PublicKey>,
+    {
+        // Mock implementation - just return success
+        Ok(())
+    }
+}
+
+impl MockContributor {
+    /// Mock contributor, rejecting configurations the handlers cannot run with
+    pub fn try_new(
         orchestrator: PublicKey,
         signer: Bn254,
         mut contributors: Vec<PublicKey>,
         aggregation_data: Option<AggregationInput>,
-    ) -> Self {
+    ) -> Result<Self, MockConfigError> {
         contributors.sort();
         let mut ordered_contributors = HashMap::new();
         for (idx, contributor) in contributors.iter().enumerate() {
-            ordered_contributors.insert(contributor.clone(), idx);
+            ordered_contributors.insert(contributor.clone(), idx.into());
+        }
+        let me = *ordered_contributors
+            .get(&signer.public_key())
+            .ok_or(MockConfigError::SignerNotContributor)?;
+        if let Some(input) = &aggregation_data
+            && !(1..=contributors.len()).contains(&input.threshold())
+        {
+            return Err(MockConfigError::ThresholdOutOfRange {
+                threshold: input.threshold(),
+                contributors: contributors.len(),
+            });
         }
-        let me = *ordered_contributors.get(&signer.public_key()).unwrap();
 
-        Self {
+        Ok(Self {
             orchestrator,
             signer,
             me,
             contributors,
             ordered_contributors,
+            assignment: None,
             aggregation_data,
-        }
+        })
     }
 
-    async fn run<S, R>(self, _sender: S, _receiver: R) -> Result<()>
-    where
-        S: Sender,
-        R: Receiver<PublicKey = PublicKey>,
-    {
-        // Mock implementation - just return success
-        Ok(())
-    }
-}
-
-impl MockContributor {
     /// Helper function to create Bn254 instances for testing using fixed values
     pub fn create_test_bn254(seed: u64) -> Bn254 {
         let fr = Fr::from(seed);
@@ -76,6 +114,11 @@ impl MockContributor {
         Bn254::new(private_key).expect("Failed to create Bn254 from private key")
     }
 
+    /// G1 key standing in for an operator key where only the G2 key is checked
+    pub fn placeholder_g1_key() -> Result<G1PublicKey, MockConfigError> {
+        G1PublicKey::create_from_g1_coordinates("0", "0").map_err(|_| MockConfigError::InvalidG1Key)
+    }
+
     /// Create a mock contributor with test data
     pub fn new_test_contributor() -> Self {
         let signer = Self::create_test_bn254(1);
@@ -98,6 +141,7 @@ impl MockContributor {
             contributors,
             Some(aggregation_input),
         )
+        .unwrap()
     }
 
     /// Create a mock contributor without aggregation data
@@ -106,13 +150,45 @@ impl MockContributor {
         let orchestrator = Self::create_test_bn254(6);
         let contributors = vec![signer.public_key(), orchestrator.public_key()];
 
-        Self::new(orchestrator.public_key(), signer, contributors, None)
+        Self::new(orchestrator.public_key(), signer, contributors, None).unwrap()
     }
 }
 
+/// Invalid configuration of a mock
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MockConfigError {
+    /// The signer is not one of the contributors, so it has no index
+    SignerNotContributor,
+    /// The threshold can never be reached, or is reached without signatures
+    ThresholdOutOfRange {
+        threshold: usize,
+        contributors: usize,
+    },
+    /// The placeholder G1 key could not be built
+    InvalidG1Key,
+}
+
+impl fmt::Display for MockConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MockConfigError::SignerNotContributor => write!(f, "signer is not a contributor"),
+            MockConfigError::ThresholdOutOfRange {
+                threshold,
+                contributors,
+            } => write!(
+                f,
+                "threshold {threshold} out of range for {contributors} contributors"
+            ),
+            MockConfigError::InvalidG1Key => write!(f, "invalid g1 placeholder key"),
+        }
+    }
+}
+
+impl StdError for MockConfigError {}
+
 // Custom error type for testing
 #[derive(Debug)]
-pub struct MockError(String);
+pub struct MockError(pub String);
 
 impl fmt::Display for MockError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -125,7 +201,7 @@ impl StdError for MockError {}
 // Mock implementations for testing async functionality
 #[derive(Debug, Clone)]
 pub struct MockSender {
-    sent_messages: std::sync::Arc<tokio::sync::Mutex<Vec<(String, bytes::Bytes, bool)>>>,
+    sent_messages: std::sync::Arc<tokio::sync::Mutex<Vec<(SentTo, bytes::Bytes, bool)>>>,
 }
 
 #[derive(Debug)]
@@ -141,6 +217,13 @@ impl MockSender {
     }
 }
 
+impl MockSender {
+    /// Messages sent so far, in order
+    pub async fn sent(&self) -> Vec<(SentTo, bytes::Bytes, bool)> {
+        self.sent_messages.lock().await.clone()
+    }
+}
+
 impl MockReceiver {
     pub fn new() -> Self {
         Self {
@@ -155,12 +238,17 @@ impl commonware_p2p::Sender for MockSender {
 
     async fn send(
         &mut self,
-        _recipients: commonware_p2p::Recipients<Self::PublicKey>,
+        recipients: commonware_p2p::Recipients<Self::PublicKey>,
         message: bytes::Bytes,
         reliable: bool,
     ) -> Result<Vec<Self::PublicKey>, Self::Error> {
+        let recipients = match recipients {
+            commonware_p2p::Recipients::All => SentTo::All,
+            commonware_p2p::Recipients::Some(keys) => SentTo::Some(keys),
+            commonware_p2p::Recipients::One(key) => SentTo::One(key),
+        };
         let mut messages = self.sent_messages.lock().await;
-        messages.push(("mock_recipients".to_string(), message, reliable));
+        messages.push((recipients, message, reliable));
         Ok(vec![]) // Return empty vector as required by the trait
     }
 }
@@ -1,2 +1,53 @@
+pub mod aggregated;
+pub mod aggregation;
+pub mod api_schema;
+pub mod bitmap;
+pub mod builder;
+pub mod certificate;
+pub mod clock;
+pub mod committee;
+pub mod completion;
+pub mod contributor_metrics;
+pub mod counter_cache;
+pub mod deadline;
+pub mod decode;
+pub mod digest;
+pub mod execution;
+pub mod export;
+pub mod fallback;
+pub mod file_sink;
+pub mod final_aggregate;
+pub mod harness;
+pub mod idempotent_sink;
+pub mod logging;
+pub mod metadata;
+#[cfg(feature = "observability")]
+pub mod metrics;
 pub mod mock;
+pub mod orchestrator_contributor;
+pub mod orchestrators;
+pub mod quarantine;
+pub mod quorum_channels;
+pub mod registration;
+pub mod relay;
+pub mod replay;
+pub mod reset;
+pub mod round_capacity;
+pub mod rounds;
+pub mod router;
+pub mod run_config;
+pub mod scores;
+pub mod signature_window;
+pub mod signing;
+pub mod spans;
+pub mod stale_orchestrator;
+pub mod start;
+pub mod sync;
 pub mod test_suite;
+pub mod threshold;
+pub mod unknown_peers;
+pub mod upgrade;
+pub mod validator_retry;
+pub mod voting;
+pub mod weights;
+pub mod wire_message;
@@ -0,0 +1,101 @@
+use super::harness::{
+    Harness, NetworkReceiver, NetworkSender, digest_of, encode, signature_message, start_message,
+};
+use crate::contributor::events::ContributorMetrics;
+use anyhow::Result;
+use bn254::Bn254;
+use bytes::Bytes;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// Aggregator at index 0 waiting for both harness signers, the second also being an
+/// orchestrator
+fn spawn_aggregator(harness: &Harness, metrics: &ContributorMetrics) -> JoinHandle<Result<()>> {
+    let contributor = harness
+        .contributor(0, Some(2))
+        .with_orchestrator(harness.signers[1].public_key())
+        .with_event_sink(Arc::new(metrics.clone()));
+    harness.spawn(contributor, 0)
+}
+
+/// The second harness signer, scripted as both orchestrator and contributor
+struct OrchestratingContributor {
+    signer: Bn254,
+    sender: NetworkSender,
+    /// Kept so the aggregator can still reach it
+    _receiver: NetworkReceiver,
+}
+
+impl OrchestratingContributor {
+    fn new(harness: &Harness) -> Self {
+        let signer = harness.signers[1].clone();
+        let (sender, receiver) = harness.network.register(signer.public_key());
+        Self {
+            signer,
+            sender,
+            _receiver: receiver,
+        }
+    }
+
+    async fn send(&mut self, frame: Bytes) {
+        commonware_p2p::Sender::send(&mut self.sender, Recipients::All, frame, true)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    async fn start(&mut self, round: u64) {
+        self.send(encode(&start_message(round))).await;
+    }
+
+    async fn share(&mut self, round: u64) {
+        let signature = self.signer.sign(None, &digest_of(&start_message(round)));
+        self.send(encode(&signature_message(round, signature.to_vec())))
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod orchestrator_contributor_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_orchestrator_share_counts_toward_threshold() {
+        let mut harness = Harness::new(2);
+        let metrics = ContributorMetrics::new();
+        let handle = spawn_aggregator(&harness, &metrics);
+        let mut orchestrator = OrchestratingContributor::new(&harness);
+
+        harness.start(1).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        orchestrator.share(1).await;
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.rounds_started, 1);
+        assert_eq!(snapshot.rounds_aggregated, 1);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_orchestrator_start_handled_as_control() {
+        let harness = Harness::new(2);
+        let metrics = ContributorMetrics::new();
+        let handle = spawn_aggregator(&harness, &metrics);
+        let mut orchestrator = OrchestratingContributor::new(&harness);
+
+        // Its Start opens the round, its share then completes it
+        orchestrator.start(1).await;
+        assert_eq!(metrics.snapshot().rounds_started, 1);
+        assert_eq!(metrics.snapshot().rounds_aggregated, 0);
+        orchestrator.share(1).await;
+        assert_eq!(metrics.snapshot().rounds_aggregated, 1);
+
+        // A repeated share from it is a duplicate like any other contributor's
+        orchestrator.share(1).await;
+        assert_eq!(metrics.snapshot().duplicate_shares, 1);
+        handle.abort();
+    }
+}
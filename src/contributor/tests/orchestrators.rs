@@ -0,0 +1,101 @@
+use super::harness::{Harness, NetworkReceiver, NetworkSender, encode, start_message};
+use super::mock::MockContributor;
+use crate::contributor::{ContributorBase, Destination, MessageClass, OutboundRouter};
+use crate::handlers::Contributor;
+use bn254::Bn254;
+use commonware_avs_router::wire::aggregation::Payload;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::time::Duration;
+
+/// Second orchestrator on the harness network
+fn second_orchestrator(harness: &Harness) -> (Bn254, NetworkSender, NetworkReceiver) {
+    let orchestrator = MockContributor::create_test_bn254(1001);
+    let (sender, receiver) = harness.network.register(orchestrator.public_key());
+    (orchestrator, sender, receiver)
+}
+
+async fn send_start(sender: &mut NetworkSender, round: u64) {
+    commonware_p2p::Sender::send(sender, Recipients::All, encode(&start_message(round)), true)
+        .await
+        .unwrap();
+}
+
+/// Rounds of the signatures `receiver` got, in arrival order
+async fn signatures(receiver: &mut NetworkReceiver) -> Vec<u64> {
+    let mut rounds = Vec::new();
+    while let Some((_, message)) = receiver.next_message(Duration::from_millis(200)).await {
+        if let Some(Payload::Signature(_)) = message.payload {
+            rounds.push(message.round);
+        }
+    }
+    rounds
+}
+
+#[cfg(test)]
+mod orchestrators_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_start_from_either_orchestrator_signed_once() {
+        let mut harness = Harness::new(1);
+        let (second, mut second_sender, _second_receiver) = second_orchestrator(&harness);
+        let contributor = harness
+            .contributor(0, None)
+            .with_orchestrator(second.public_key());
+        let handle = harness.spawn(contributor, 0);
+
+        // Each orchestrator starts a round, then repeats the other's
+        send_start(&mut second_sender, 1).await;
+        harness.start(2).await;
+        harness.start(1).await;
+        send_start(&mut second_sender, 2).await;
+
+        // Starts from anyone else are ignored
+        let (mut outsider, _) = harness
+            .network
+            .register(MockContributor::create_test_bn254(1002).public_key());
+        send_start(&mut outsider, 3).await;
+
+        let mut rounds = signatures(&mut harness.orchestrator_receiver).await;
+        rounds.sort();
+        assert_eq!(rounds, [1, 2]);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_reply_sent_to_issuing_orchestrator() {
+        let mut harness = Harness::new(1);
+        let (second, mut second_sender, mut second_receiver) = second_orchestrator(&harness);
+        let contributor = harness
+            .contributor(0, None)
+            .with_orchestrator(second.public_key());
+        let (sender, receiver) = harness.network.register(harness.signers[0].public_key());
+        let router = OutboundRouter::new(vec![sender])
+            .with_route(MessageClass::Reply, 0, Destination::Orchestrator)
+            .unwrap();
+        let handle = tokio::spawn(contributor.run(router, receiver));
+
+        harness.start(1).await;
+        send_start(&mut second_sender, 2).await;
+        assert_eq!(signatures(&mut harness.orchestrator_receiver).await, [1]);
+        assert_eq!(signatures(&mut second_receiver).await, [2]);
+        handle.abort();
+    }
+
+    #[test]
+    fn test_builder_accepts_orchestrators() {
+        let harness = Harness::new(1);
+        let second = MockContributor::create_test_bn254(1001).public_key();
+        let contributor = Contributor::builder()
+            .orchestrator(harness.orchestrator.public_key())
+            .orchestrator(second.clone())
+            .signer(harness.signers[0].clone())
+            .contributors(harness.contributors())
+            .build()
+            .unwrap();
+        assert!(contributor.is_orchestrator(&harness.orchestrator.public_key()));
+        assert!(contributor.is_orchestrator(&second));
+        assert!(!contributor.is_orchestrator(&harness.signers[0].public_key()));
+    }
+}
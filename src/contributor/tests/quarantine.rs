@@ -0,0 +1,164 @@
+use super::harness::{EventLog, Harness, digest_of, encode, signature_message, start_message};
+use crate::contributor::decode::{MalformedSignature, decode_signature};
+use crate::contributor::quarantine::{PeerQuarantine, QuarantineConfig};
+use crate::handlers::RunConfig;
+#[cfg(feature = "observability")]
+use crate::metrics::QuorumLabel;
+use bn254::Signature;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::cell::Cell;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Raw signature of the signer at `index` over round 1
+fn raw_signature(harness: &Harness, index: usize) -> Vec<u8> {
+    Signer::sign(&harness.signers[index], None, &digest_of(&start_message(1))).to_vec()
+}
+
+/// Decode `bytes`, returning the outcome and how many conversions were attempted
+fn counted_decode(bytes: &[u8]) -> (Result<Signature, MalformedSignature>, usize) {
+    let conversions = Cell::new(0);
+    let result = decode_signature(bytes, |bytes| {
+        conversions.set(conversions.get() + 1);
+        Signature::try_from(bytes).ok()
+    });
+    (result, conversions.get())
+}
+
+fn config(max_failures: u32) -> QuarantineConfig {
+    QuarantineConfig {
+        max_failures,
+        window: Duration::from_secs(10),
+        duration: Duration::from_secs(60),
+    }
+}
+
+#[cfg(test)]
+mod quarantine_tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_signatures_converted() {
+        let harness = Harness::new(16);
+        for index in 0..16 {
+            let raw = raw_signature(&harness, index);
+            let (result, conversions) = counted_decode(&raw);
+            assert!(result.is_ok(), "signer {index}: {result:?}");
+            assert_eq!(conversions, 1);
+        }
+    }
+
+    #[test]
+    fn test_malformed_rejected_before_conversion() {
+        let harness = Harness::new(1);
+        let raw = raw_signature(&harness, 0);
+        let mut oversized = raw.clone();
+        oversized.push(0);
+        let mut flags = raw.clone();
+        *flags.last_mut().unwrap() |= 0b1100_0000;
+
+        let cases = [
+            (
+                raw[..raw.len() - 1].to_vec(),
+                MalformedSignature::Length {
+                    expected: raw.len(),
+                    actual: raw.len() - 1,
+                },
+            ),
+            (
+                oversized,
+                MalformedSignature::Length {
+                    expected: raw.len(),
+                    actual: raw.len() + 1,
+                },
+            ),
+            (
+                Vec::new(),
+                MalformedSignature::Length {
+                    expected: raw.len(),
+                    actual: 0,
+                },
+            ),
+            (vec![0; raw.len()], MalformedSignature::Identity),
+            (flags, MalformedSignature::Flags),
+        ];
+        for (bytes, expected) in cases {
+            let (result, conversions) = counted_decode(&bytes);
+            assert_eq!(result.unwrap_err(), expected);
+            assert_eq!(conversions, 0, "{expected}");
+        }
+    }
+
+    #[test]
+    fn test_quarantine_after_max_failures() {
+        let mut quarantine = PeerQuarantine::new(config(3));
+        let now = Instant::now();
+        assert!(!quarantine.record_failure(&"peer", now));
+        assert!(!quarantine.record_failure(&"peer", now));
+        assert!(!quarantine.is_quarantined(&"peer", now));
+        assert!(quarantine.record_failure(&"peer", now));
+        assert!(quarantine.is_quarantined(&"peer", now));
+        assert!(!quarantine.is_quarantined(&"other", now));
+
+        // Quarantine expires
+        assert!(!quarantine.is_quarantined(&"peer", now + Duration::from_secs(61)));
+    }
+
+    #[test]
+    fn test_failures_counted_per_window() {
+        let mut quarantine = PeerQuarantine::new(config(3));
+        let now = Instant::now();
+        quarantine.record_failure(&"peer", now);
+        quarantine.record_failure(&"peer", now);
+        let later = now + Duration::from_secs(11);
+        assert!(!quarantine.record_failure(&"peer", later));
+        assert_eq!(quarantine.failures(&"peer"), 1);
+        assert!(!quarantine.is_quarantined(&"peer", later));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_shares_quarantine_peer() {
+        let mut harness = Harness::new(2);
+        let log = EventLog::default();
+        let aggregator = harness
+            .contributor(0, Some(2))
+            .with_event_sink(Arc::new(log.clone()))
+            .with_run_config(RunConfig {
+                quarantine: config(3),
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(aggregator, 0);
+        let (mut peer, _receiver) = harness.network.register(harness.signers[1].public_key());
+
+        harness.start(1).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let raw = raw_signature(&harness, 1);
+        for malformed in [vec![1; 3], vec![0; raw.len()], raw[1..].to_vec()] {
+            let frame = encode(&signature_message(1, malformed));
+            commonware_p2p::Sender::send(&mut peer, Recipients::All, frame, true)
+                .await
+                .unwrap();
+        }
+
+        // The valid share arrives once the peer is quarantined and is dropped
+        let frame = encode(&signature_message(1, raw));
+        commonware_p2p::Sender::send(&mut peer, Recipients::All, frame, true)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        handle.abort();
+
+        assert_eq!(log.count("peer_quarantined"), 1);
+        assert_eq!(log.count("threshold_reached"), 0);
+        #[cfg(feature = "observability")]
+        {
+            let label = QuorumLabel { quorum_id: 0 };
+            assert_eq!(
+                log.metrics().peers_quarantined.get_or_create(&label).get(),
+                1
+            );
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use super::harness::{
+    Harness, MockNetwork, NetworkSender, encode, signature_message, start_message,
+};
+use crate::contributor::quorum_channels::{
+    QuorumChannels, QuorumDispatcher, tag_share, untag_share,
+};
+use crate::contributor::{EventSink, OutboundRouter};
+use crate::handlers::SignatureExport;
+use bn254::PublicKey;
+use bytes::Bytes;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// Sink recording dropped shares as `(quorum, reason)`
+#[derive(Clone, Default)]
+struct DroppedShares(Arc<Mutex<Vec<(u8, String)>>>);
+
+impl EventSink for DroppedShares {
+    fn share_dropped(&self, quorum_id: u8, reason: &str) {
+        self.0.lock().unwrap().push((quorum_id, reason.to_string()));
+    }
+}
+
+impl DroppedShares {
+    fn get(&self) -> Vec<(u8, String)> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+/// Quorum 1 on channel 1 and quorum 2 on channel 2
+fn two_channels() -> QuorumChannels {
+    QuorumChannels::new(0)
+        .with_quorum_channel(1, 1)
+        .and_then(|channels| channels.with_quorum_channel(2, 2))
+        .unwrap()
+}
+
+async fn send_all(sender: &mut NetworkSender, frame: Bytes) {
+    commonware_p2p::Sender::send(sender, Recipients::All, frame, true)
+        .await
+        .unwrap();
+}
+
+/// Keys of all signers, sorted as contributors are indexed
+fn sorted_keys(harness: &Harness) -> Vec<PublicKey> {
+    let mut keys = harness.contributors();
+    keys.sort();
+    keys
+}
+
+/// Keys of the signatures collected for `round`
+async fn collected(export: &SignatureExport, round: u64) -> Vec<PublicKey> {
+    export
+        .collected_signatures(round)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect()
+}
+
+#[cfg(test)]
+mod quorum_channels_tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_round_trips() {
+        let frame = encode(&start_message(3));
+        let tagged = tag_share(7, &frame);
+        assert_eq!(untag_share(&tagged), Some((7, frame.clone())));
+
+        // Untagged frames and a bare prefix carry no quorum
+        assert_eq!(untag_share(&frame), None);
+        assert_eq!(untag_share(&tagged.slice(..4)), None);
+    }
+
+    #[test]
+    fn test_quorums_fall_back_to_shared_channel() {
+        let channels = QuorumChannels::new(0).with_quorum_channel(2, 5).unwrap();
+        assert_eq!(channels.channel(1), 0);
+        assert_eq!(channels.channel(2), 5);
+        assert_eq!(channels.channels(), [0, 5]);
+        assert!(channels.carries(0, 1));
+        assert!(!channels.carries(0, 2));
+        assert!(channels.carries(5, 2));
+
+        // Moving a quorum frees its previous channel
+        let channels = channels
+            .with_quorum_channel(2, 6)
+            .and_then(|channels| channels.with_quorum_channel(3, 5))
+            .unwrap();
+        assert_eq!(channels.channels(), [0, 6, 5]);
+    }
+
+    #[test]
+    fn test_channel_assigned_once() {
+        assert!(two_channels().with_quorum_channel(3, 1).is_err());
+    }
+
+    #[test]
+    fn test_shared_channel_not_dedicated() {
+        assert!(QuorumChannels::new(0).with_quorum_channel(1, 0).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_quorums_kept_apart_on_their_channels() {
+        let mut harness = Harness::new(2);
+        let second = MockNetwork::new();
+        let (mut orchestrator_second, _orchestrator_inbox) =
+            second.register(harness.orchestrator.public_key());
+        let dropped = DroppedShares::default();
+
+        // Every signer runs a contributor per quorum behind a dispatcher, the first
+        // aggregating both quorums
+        let mut handles: Vec<JoinHandle<anyhow::Result<()>>> = Vec::new();
+        let mut exports = Vec::new();
+        for i in 0..2 {
+            let key = harness.signers[i].public_key();
+            let (first_sender, first_receiver) = harness.network.register(key.clone());
+            let (second_sender, second_receiver) = second.register(key);
+            let mut dispatcher = QuorumDispatcher::new(two_channels())
+                .with_receiver(1, first_receiver)
+                .with_receiver(2, second_receiver)
+                .with_event_sink(Arc::new(dropped.clone()));
+            let threshold = (i == 0).then_some(2);
+            for (quorum_id, sender) in [(1, first_sender), (2, second_sender)] {
+                let mut contributor = harness
+                    .contributor(i, threshold)
+                    .with_quorum(quorum_id)
+                    .with_tagged_shares();
+                if i == 0 {
+                    exports.push(contributor.signature_export());
+                }
+                let receiver = dispatcher.quorum_receiver(quorum_id);
+                handles.push(tokio::spawn(
+                    contributor.run(OutboundRouter::single(sender), receiver),
+                ));
+            }
+            handles.push(tokio::spawn(dispatcher.run()));
+        }
+
+        // Round 1 runs on the first quorum's channel, round 2 on the second's
+        harness.start(1).await;
+        send_all(&mut orchestrator_second, encode(&start_message(2))).await;
+
+        // A share tagged for the second quorum sent over the first quorum's channel
+        let share = encode(&signature_message(2, vec![0; 64]));
+        harness.broadcast(tag_share(2, &share)).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+
+        let keys = sorted_keys(&harness);
+        assert_eq!(collected(&exports[0], 1).await, keys);
+        assert!(collected(&exports[0], 2).await.is_empty());
+        assert_eq!(collected(&exports[1], 2).await, keys);
+        assert!(collected(&exports[1], 1).await.is_empty());
+
+        // Dropped once by each signer's dispatcher, never reaching a contributor
+        assert_eq!(
+            dropped.get(),
+            [(2, "mis_tagged".to_string()), (2, "mis_tagged".to_string())]
+        );
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
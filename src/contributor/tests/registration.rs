@@ -0,0 +1,238 @@
+use super::harness::Harness;
+use crate::chain::{
+    AvsRegistrationStatus, RegistrationChecker, RegistrationConfig, RegistrationSource,
+};
+use crate::contributor::events::ThresholdCounter;
+use crate::validation::counter::ValidationError;
+use alloy_primitives::Address;
+use anyhow::{Result, anyhow};
+use commonware_cryptography::Signer;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const AVS: Address = Address::repeat_byte(0xaa);
+
+/// Delegation manager and AVS directory answering from settable registrations
+#[derive(Default)]
+struct MockRegistrations {
+    operators: Mutex<HashSet<Address>>,
+    avs_operators: Mutex<HashSet<(Address, Address)>>,
+    failing: Mutex<bool>,
+    operator_calls: Mutex<usize>,
+    status_calls: Mutex<usize>,
+}
+
+impl MockRegistrations {
+    /// Register `operator` with EigenLayer and the AVS
+    fn register(&self, operator: Address) {
+        self.operators.lock().unwrap().insert(operator);
+        self.avs_operators.lock().unwrap().insert((AVS, operator));
+    }
+
+    /// Deregister `operator` from the AVS, it stays an EigenLayer operator
+    fn deregister(&self, operator: Address) {
+        self.avs_operators.lock().unwrap().remove(&(AVS, operator));
+    }
+
+    fn calls(&self) -> (usize, usize) {
+        (
+            *self.operator_calls.lock().unwrap(),
+            *self.status_calls.lock().unwrap(),
+        )
+    }
+}
+
+impl RegistrationSource for MockRegistrations {
+    async fn is_operator(&self, operator: Address) -> Result<bool> {
+        *self.operator_calls.lock().unwrap() += 1;
+        if *self.failing.lock().unwrap() {
+            return Err(anyhow!("delegation manager unavailable"));
+        }
+        Ok(self.operators.lock().unwrap().contains(&operator))
+    }
+
+    async fn avs_operator_status(
+        &self,
+        avs: Address,
+        operator: Address,
+    ) -> Result<AvsRegistrationStatus> {
+        *self.status_calls.lock().unwrap() += 1;
+        let registered = self
+            .avs_operators
+            .lock()
+            .unwrap()
+            .contains(&(avs, operator));
+        Ok(if registered {
+            AvsRegistrationStatus::Registered
+        } else {
+            AvsRegistrationStatus::Unregistered
+        })
+    }
+}
+
+fn checker(
+    source: &Arc<MockRegistrations>,
+    ttl_secs: u64,
+) -> RegistrationChecker<MockRegistrations> {
+    RegistrationChecker::new(
+        source.clone(),
+        AVS,
+        RegistrationConfig {
+            registration_cache_ttl_secs: ttl_secs,
+        },
+    )
+}
+
+/// Operator address of the signer at `index`
+fn operator(index: usize) -> Address {
+    Address::repeat_byte(index as u8 + 1)
+}
+
+#[cfg(test)]
+mod registration_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_registration_cached_for_ttl() {
+        let source = Arc::new(MockRegistrations::default());
+        source.register(operator(0));
+        let checker = checker(&source, 60);
+
+        assert!(checker.is_registered(operator(0)).await.unwrap());
+        assert!(checker.is_registered(operator(0)).await.unwrap());
+        assert_eq!(source.calls(), (1, 1));
+
+        // Read again once the cached registration expired
+        tokio::time::advance(Duration::from_secs(61)).await;
+        assert!(checker.is_registered(operator(0)).await.unwrap());
+        assert_eq!(source.calls(), (2, 2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_unregistered_not_cached() {
+        let source = Arc::new(MockRegistrations::default());
+        let checker = checker(&source, 60);
+
+        // Not an operator, the AVS directory is not asked
+        assert!(!checker.is_registered(operator(0)).await.unwrap());
+        assert_eq!(source.calls(), (1, 0));
+
+        // An operator not registered with the AVS
+        source.register(operator(0));
+        source.deregister(operator(0));
+        assert!(!checker.is_registered(operator(0)).await.unwrap());
+        assert_eq!(source.calls(), (2, 1));
+
+        // Registering is picked up on the next check
+        source.register(operator(0));
+        assert!(checker.is_registered(operator(0)).await.unwrap());
+        assert_eq!(source.calls(), (3, 2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_deregistration_seen_after_ttl_or_invalidate() {
+        let source = Arc::new(MockRegistrations::default());
+        source.register(operator(0));
+        let checker = checker(&source, 60);
+        assert!(checker.is_registered(operator(0)).await.unwrap());
+
+        // Still trusted until the cache expires
+        source.deregister(operator(0));
+        assert!(checker.is_registered(operator(0)).await.unwrap());
+        tokio::time::advance(Duration::from_secs(61)).await;
+        assert!(!checker.is_registered(operator(0)).await.unwrap());
+
+        source.register(operator(0));
+        assert!(checker.is_registered(operator(0)).await.unwrap());
+        source.deregister(operator(0));
+        checker.invalidate(operator(0)).await;
+        assert!(!checker.is_registered(operator(0)).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_source_error_propagated() {
+        let source = Arc::new(MockRegistrations::default());
+        source.register(operator(0));
+        *source.failing.lock().unwrap() = true;
+        let checker = checker(&source, 60);
+        assert!(checker.is_registered(operator(0)).await.is_err());
+
+        *source.failing.lock().unwrap() = false;
+        assert!(checker.is_registered(operator(0)).await.unwrap());
+    }
+
+    #[test]
+    fn test_avs_status_from_contract_value() {
+        assert_eq!(
+            AvsRegistrationStatus::from(0),
+            AvsRegistrationStatus::Unregistered
+        );
+        assert_eq!(
+            AvsRegistrationStatus::from(1),
+            AvsRegistrationStatus::Registered
+        );
+        assert_eq!(
+            AvsRegistrationStatus::from(7),
+            AvsRegistrationStatus::Unregistered
+        );
+    }
+
+    #[test]
+    fn test_not_registered_error() {
+        let err = anyhow::Error::from(ValidationError::OperatorNotRegistered(Some(operator(0))));
+        assert_eq!(
+            err.downcast::<ValidationError>().unwrap(),
+            ValidationError::OperatorNotRegistered(Some(operator(0)))
+        );
+        assert_eq!(
+            ValidationError::OperatorNotRegistered(None).to_string(),
+            "operator address unknown"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shares_of_unregistered_contributor_not_counted() {
+        let mut harness = Harness::new(3);
+        let source = Arc::new(MockRegistrations::default());
+        source.register(operator(1));
+        let operators: HashMap<_, _> = harness
+            .signers
+            .iter()
+            .enumerate()
+            .map(|(i, signer)| (signer.public_key(), operator(i)))
+            .collect();
+        let aggregated = ThresholdCounter::new();
+        let mut handles = vec![
+            harness.spawn(
+                harness
+                    .contributor(0, Some(3))
+                    .with_event_sink(Arc::new(aggregated.clone()))
+                    .with_registration_checker(Arc::new(checker(&source, 60)), operators),
+                0,
+            ),
+        ];
+        for i in 1..3 {
+            handles.push(harness.spawn(harness.contributor(i, None), i));
+        }
+
+        // The share of the unregistered third signer is rejected
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+        assert_eq!(aggregated.get(), 0);
+
+        // Once registered, its shares count
+        source.register(operator(2));
+        harness.start(2).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+        assert_eq!(aggregated.get(), 1);
+
+        // The registered second signer was checked once for both rounds, the third
+        // signer on each of its shares until it registered
+        assert_eq!(source.calls(), (3, 2));
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
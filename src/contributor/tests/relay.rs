@@ -0,0 +1,200 @@
+use super::harness::{
+    Harness, NetworkSender, SlowValidator, digest_of, encode, signature_message, start_message,
+};
+use super::mock::MockContributor;
+use crate::contributor::events::{ContributorMetrics, ThresholdCounter};
+use crate::contributor::relay::{ForwardedShare, RelayConfig, ShareRelay};
+use crate::handlers::RunConfig;
+use bn254::PublicKey;
+use bytes::Bytes;
+use commonware_codec::{EncodeSize, ReadExt, Write};
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Encode a share of `origin` as forwarded by `forwarder`
+fn forwarded(origin: PublicKey, forwarder: PublicKey, share: Bytes) -> Bytes {
+    let forwarded = ForwardedShare {
+        origin,
+        forwarder,
+        share,
+    };
+    let mut buf = Vec::with_capacity(forwarded.encode_size());
+    forwarded.write(&mut buf);
+    Bytes::from(buf)
+}
+
+/// Share of the signer at `index` for `round`, as the harness validator digests it
+fn share(harness: &Harness, index: usize, round: u64) -> Bytes {
+    let signature = harness.signers[index].sign(None, &digest_of(&start_message(round)));
+    encode(&signature_message(round, signature.to_vec()))
+}
+
+/// Send `frame` from `peer` to `to` and let it be handled
+async fn send(peer: &mut NetworkSender, to: &PublicKey, frame: Bytes) {
+    commonware_p2p::Sender::send(peer, Recipients::One(to.clone()), frame, true)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+fn relay(budget_per_round: usize, max_rounds: usize) -> ShareRelay<&'static str> {
+    ShareRelay::new(RelayConfig {
+        budget_per_round,
+        max_rounds,
+    })
+}
+
+/// Rounds B aggregated when C reaches only the orchestrator and A, with A relaying if
+/// `relaying`
+///
+/// B validates slowly, so it has not shared yet when A receives C's share.
+async fn aggregated_behind_cut(relaying: bool, wait: Duration) -> u64 {
+    let mut harness = Harness::new(3);
+    let keys = harness.contributors();
+    harness.network.cut(&keys[1], &keys[2]);
+
+    let a = harness.contributor(0, Some(3)).with_run_config(RunConfig {
+        share_relay: relaying.then(RelayConfig::default),
+        ..RunConfig::default()
+    });
+    let aggregated = ThresholdCounter::new();
+    let b = harness
+        .contributor(1, Some(3))
+        .with_validator_factory(Arc::new(SlowValidator {
+            delay: Duration::from_millis(100),
+        }))
+        .with_event_sink(Arc::new(aggregated.clone()));
+    let c = harness.contributor(2, None);
+    let handles = [
+        harness.spawn(a, 0),
+        harness.spawn(b, 1),
+        harness.spawn(c, 2),
+    ];
+
+    harness.start(1).await;
+    let deadline = tokio::time::Instant::now() + wait;
+    while aggregated.get() == 0 && tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    for handle in handles {
+        handle.abort();
+    }
+    aggregated.get()
+}
+
+#[cfg(test)]
+mod relay_tests {
+    use super::*;
+
+    #[test]
+    fn test_forwarded_share_round_trip() {
+        let origin = MockContributor::create_test_bn254(1).public_key();
+        let forwarder = MockContributor::create_test_bn254(2).public_key();
+        let share = encode(&signature_message(4, vec![7; 64]));
+        let frame = forwarded(origin.clone(), forwarder.clone(), share.clone());
+        assert!(ForwardedShare::is_forwarded_share(&frame));
+        assert!(!ForwardedShare::is_forwarded_share(&share));
+
+        let decoded = ForwardedShare::read(&mut std::io::Cursor::new(&frame[..])).unwrap();
+        assert_eq!(decoded.origin, origin);
+        assert_eq!(decoded.forwarder, forwarder);
+        // The signed frame is carried byte for byte
+        assert_eq!(decoded.share, share);
+
+        let truncated = &frame[..frame.len() - 1];
+        assert!(ForwardedShare::read(&mut std::io::Cursor::new(truncated)).is_err());
+    }
+
+    #[test]
+    fn test_share_forwarded_once_per_round() {
+        let mut relay = relay(8, 8);
+        assert_eq!(relay.forward(1, &"c", ["b", "d"]), vec!["b", "d"]);
+        assert!(relay.forward(1, &"c", ["b", "d"]).is_empty());
+        assert_eq!(relay.forwarded(1), 2);
+
+        // Other signers and rounds are forwarded on their own
+        assert_eq!(relay.forward(1, &"b", ["d"]), vec!["d"]);
+        assert_eq!(relay.forward(2, &"c", ["b"]), vec!["b"]);
+        assert_eq!(relay.forwarded(1), 3);
+    }
+
+    #[test]
+    fn test_forwarding_bounded_by_round_budget() {
+        let mut relay = relay(3, 8);
+        assert_eq!(relay.forward(1, &"a", ["b", "c"]).len(), 2);
+        assert_eq!(relay.forward(1, &"b", ["c", "d"]), vec!["c"]);
+        assert!(relay.forward(1, &"c", ["d"]).is_empty());
+        assert_eq!(relay.forwarded(1), 3);
+
+        // A budget spent does not carry over to later rounds
+        assert_eq!(relay.forward(2, &"c", ["d"]), vec!["d"]);
+    }
+
+    #[test]
+    fn test_lowest_rounds_forgotten() {
+        let mut relay = relay(8, 2);
+        relay.forward(1, &"a", ["b"]);
+        relay.forward(2, &"a", ["b"]);
+        relay.forward(3, &"a", ["b"]);
+        assert_eq!(relay.forwarded(1), 0);
+        assert_eq!(relay.forwarded(3), 1);
+
+        relay.clear();
+        assert_eq!(relay.forwarded(3), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cut_off_share_reaches_aggregator_through_relay() {
+        let aggregated = aggregated_behind_cut(true, Duration::from_secs(2)).await;
+        assert_eq!(aggregated, 1);
+    }
+
+    #[tokio::test]
+    async fn test_cut_off_share_missing_without_relay() {
+        let aggregated = aggregated_behind_cut(false, Duration::from_millis(500)).await;
+        assert_eq!(aggregated, 0);
+    }
+
+    #[tokio::test]
+    async fn test_forwarded_share_verified_against_signer() {
+        let mut harness = Harness::new(3);
+        let keys = harness.contributors();
+        let metrics = ContributorMetrics::new();
+        let aggregator = harness
+            .contributor(1, Some(2))
+            .with_event_sink(Arc::new(metrics.clone()));
+        let handle = harness.spawn(aggregator, 1);
+        let (mut a, _a_receiver) = harness.network.register(keys[0].clone());
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+
+        // A share of the forwarder passed off as another signer's does not verify
+        let own = share(&harness, 0, 1);
+        send(
+            &mut a,
+            &keys[1],
+            forwarded(keys[2].clone(), keys[0].clone(), own),
+        )
+        .await;
+        assert_eq!(metrics.snapshot().invalid_signatures, 1);
+
+        // Nor is a share taken from a peer naming someone else as the forwarder
+        let relayed = share(&harness, 2, 1);
+        let spoofed = forwarded(keys[2].clone(), keys[2].clone(), relayed.clone());
+        send(&mut a, &keys[1], spoofed).await;
+        assert_eq!(metrics.snapshot().rounds_aggregated, 0);
+
+        send(
+            &mut a,
+            &keys[1],
+            forwarded(keys[2].clone(), keys[0].clone(), relayed),
+        )
+        .await;
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.invalid_signatures, 1);
+        assert_eq!(snapshot.rounds_aggregated, 1);
+        handle.abort();
+    }
+}
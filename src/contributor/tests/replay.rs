@@ -0,0 +1,100 @@
+use super::harness::{Harness, MockValidator};
+use crate::contributor::events::ThresholdCounter;
+use crate::contributor::replay::{Capture, ReplayConfig, replay, signed_rounds};
+use crate::contributor::{Contribute, OutboundRouter};
+use commonware_cryptography::Signer;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// Run an aggregator and two contributors for `rounds`, capturing the aggregator's traffic
+///
+/// Returns the capture with the number of rounds the aggregator aggregated.
+async fn capture_live_run(harness: &mut Harness, rounds: u64) -> (Capture, u64) {
+    let capture = Capture::new();
+    let aggregated = ThresholdCounter::new();
+    let aggregator = harness
+        .contributor(0, Some(3))
+        .with_event_sink(Arc::new(aggregated.clone()))
+        .with_validator_factory(capture.validator_factory(Arc::new(MockValidator)));
+    let (sender, receiver) = harness.network.register(harness.signers[0].public_key());
+    let mut handles = vec![tokio::spawn(aggregator.run(
+        OutboundRouter::single(capture.sender(sender)),
+        capture.receiver(receiver),
+    ))];
+    for i in 1..3 {
+        handles.push(harness.spawn(harness.contributor(i, None), i));
+    }
+
+    for round in 1..=rounds {
+        harness.start(round).await;
+    }
+    harness.signed_rounds(Duration::from_millis(200)).await;
+    for handle in handles {
+        handle.abort();
+    }
+
+    (capture, aggregated.get())
+}
+
+fn config(harness: &Harness, capture: &Capture) -> ReplayConfig {
+    ReplayConfig {
+        orchestrator: harness.orchestrator.public_key(),
+        signer: harness.signers[0].clone(),
+        contributors: harness.contributors(),
+        aggregation: Some(harness.aggregation_input(3)),
+        quorum_id: 0,
+        metadata_policy: None,
+        hashes: capture.hashes(),
+        start_time: SystemTime::now(),
+    }
+}
+
+#[cfg(test)]
+mod replay_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_replay_reproduces_live_run() {
+        let mut harness = Harness::new(3);
+        let (capture, aggregated) = capture_live_run(&mut harness, 3).await;
+        assert_eq!(signed_rounds(&capture.sent()), BTreeSet::from([1, 2, 3]));
+
+        let outcome = replay(capture.frames(), config(&harness, &capture))
+            .await
+            .unwrap();
+        assert_eq!(outcome.signed_rounds, signed_rounds(&capture.sent()));
+        assert_eq!(outcome.aggregated, aggregated);
+
+        // Same frames to the same recipients, signatures being deterministic
+        let mut live = capture.sent();
+        live.sort();
+        let mut replayed = outcome.sent.clone();
+        replayed.sort();
+        assert_eq!(replayed, live);
+    }
+
+    #[tokio::test]
+    async fn test_replay_is_deterministic() {
+        let mut harness = Harness::new(3);
+        let (capture, _) = capture_live_run(&mut harness, 2).await;
+
+        let first = replay(capture.frames(), config(&harness, &capture))
+            .await
+            .unwrap();
+        let second = replay(capture.frames(), config(&harness, &capture))
+            .await
+            .unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[tokio::test]
+    async fn test_replay_without_recorded_hash_fails() {
+        let mut harness = Harness::new(3);
+        let (capture, _) = capture_live_run(&mut harness, 1).await;
+
+        let mut config = config(&harness, &capture);
+        config.hashes.clear();
+        assert!(replay(capture.frames(), config).await.is_err());
+    }
+}
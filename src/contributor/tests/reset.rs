@@ -0,0 +1,61 @@
+use super::harness::Harness;
+use commonware_cryptography::Signer;
+use std::time::Duration;
+
+/// Rounds the signer at `index` signed, as seen by the orchestrator
+async fn signed_by(harness: &mut Harness, index: usize) -> Vec<u64> {
+    let key = harness.signers[index].public_key();
+    let mut rounds: Vec<u64> = harness
+        .signed_rounds(Duration::from_millis(200))
+        .await
+        .remove(&key)
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    rounds.sort();
+    rounds
+}
+
+#[cfg(test)]
+mod reset_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reset_clears_round_state() {
+        let mut harness = Harness::new(2);
+        let mut contributor = harness.contributor(0, Some(2));
+        let reset = contributor.reset_handle();
+        let handles = [
+            harness.spawn(contributor, 0),
+            harness.spawn(harness.contributor(1, None), 1),
+        ];
+
+        for round in 0..3 {
+            harness.start(round).await;
+        }
+        assert_eq!(signed_by(&mut harness, 0).await, vec![0, 1, 2]);
+
+        // Rounds are only signed once
+        harness.start(0).await;
+        assert!(signed_by(&mut harness, 0).await.is_empty());
+
+        let summary = reset.reset().await.unwrap();
+        assert!(summary.is_empty(), "{summary:?}");
+
+        harness.start(0).await;
+        assert_eq!(signed_by(&mut harness, 0).await, vec![0]);
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reset_fails_once_stopped() {
+        let harness = Harness::new(1);
+        let mut contributor = harness.contributor(0, None);
+        let reset = contributor.reset_handle();
+        drop(contributor);
+        assert!(reset.reset().await.is_err());
+    }
+}
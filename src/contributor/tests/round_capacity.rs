@@ -0,0 +1,116 @@
+use super::harness::{
+    Harness, NetworkReceiver, NetworkSender, digest_of, encode, signature_message, start_message,
+};
+use crate::contributor::events::ContributorMetrics;
+use crate::handlers::RunConfig;
+use bn254::Bn254;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::sync::Arc;
+use std::time::Duration;
+
+const MAX_ACTIVE: usize = 3;
+
+/// Contributor completing rounds with the aggregator by sending its shares
+struct Peer {
+    signer: Bn254,
+    sender: NetworkSender,
+    /// Kept so the aggregator can still reach the peer
+    _receiver: NetworkReceiver,
+}
+
+impl Peer {
+    fn new(harness: &Harness) -> Self {
+        let signer = harness.signers[1].clone();
+        let (sender, receiver) = harness.network.register(signer.public_key());
+        Self {
+            signer,
+            sender,
+            _receiver: receiver,
+        }
+    }
+
+    async fn share(&mut self, round: u64) {
+        let signature = self.signer.sign(None, &digest_of(&start_message(round)));
+        let frame = encode(&signature_message(round, signature.to_vec()));
+        commonware_p2p::Sender::send(&mut self.sender, Recipients::All, frame, true)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+async fn start(harness: &mut Harness, round: u64) {
+    harness.start(round).await;
+    tokio::time::sleep(Duration::from_millis(20)).await;
+}
+
+#[cfg(test)]
+mod round_capacity_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_starts_beyond_capacity_rejected() {
+        let mut harness = Harness::new(2);
+        let metrics = ContributorMetrics::new();
+        let mut aggregator = harness
+            .contributor(0, Some(2))
+            .with_event_sink(Arc::new(metrics.clone()))
+            .with_run_config(RunConfig {
+                max_active_rounds: MAX_ACTIVE,
+                ..RunConfig::default()
+            });
+        let reset = aggregator.reset_handle();
+        let handle = harness.spawn(aggregator, 0);
+        let mut peer = Peer::new(&harness);
+
+        for round in 1..=MAX_ACTIVE as u64 + 5 {
+            start(&mut harness, round).await;
+        }
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.rounds_started, MAX_ACTIVE as u64);
+        assert_eq!(snapshot.rounds_rejected_capacity, 5);
+
+        // Still full until a round completes
+        start(&mut harness, 20).await;
+        assert_eq!(metrics.snapshot().rounds_rejected_capacity, 6);
+        peer.share(1).await;
+        assert_eq!(metrics.snapshot().rounds_aggregated, 1);
+        start(&mut harness, 21).await;
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.rounds_started, MAX_ACTIVE as u64 + 1);
+        assert_eq!(snapshot.rounds_rejected_capacity, 6);
+
+        let summary = reset.reset().await.unwrap();
+        assert_eq!(summary.max_active_rounds, MAX_ACTIVE);
+        assert!(summary.is_empty(), "{summary:?}");
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_overdue_rounds_free_capacity() {
+        let mut harness = Harness::new(2);
+        let metrics = ContributorMetrics::new();
+        let aggregator = harness
+            .contributor(0, Some(2))
+            .with_event_sink(Arc::new(metrics.clone()))
+            .with_run_config(RunConfig {
+                max_active_rounds: MAX_ACTIVE,
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(aggregator, 0);
+
+        for round in 1..=MAX_ACTIVE as u64 {
+            start(&mut harness, round).await;
+        }
+
+        // Rounds past their deadline no longer count as active
+        harness.advance(Duration::from_secs(60));
+        start(&mut harness, 10).await;
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.rounds_started, MAX_ACTIVE as u64 + 1);
+        assert_eq!(snapshot.rounds_timed_out, MAX_ACTIVE as u64);
+        assert_eq!(snapshot.rounds_rejected_capacity, 0);
+        handle.abort();
+    }
+}
@@ -0,0 +1,319 @@
+use super::mock::MockContributor;
+use crate::contributor::rounds::{InvalidTransition, RoundStatus, RoundTable, ShareRejection};
+use crate::contributor::types::DroppedShare;
+use bn254::Signature as Bn254Signature;
+use commonware_cryptography::Signer;
+use std::time::Duration;
+use tokio::time::Instant;
+
+const HASH: [u8; 32] = [7; 32];
+
+const STATUSES: [RoundStatus; 6] = [
+    RoundStatus::Signing,
+    RoundStatus::Signed,
+    RoundStatus::Aborted,
+    RoundStatus::Aggregated,
+    RoundStatus::Expired,
+    RoundStatus::Retired,
+];
+
+// Signature of contributor `index` over a fixed payload
+fn share(index: u64) -> Bn254Signature {
+    MockContributor::create_test_bn254(100 + index).sign(None, b"payload")
+}
+
+// Statuses a round may move to from `from`
+fn reachable(from: RoundStatus) -> Vec<RoundStatus> {
+    STATUSES
+        .into_iter()
+        .filter(|to| from.can_become(*to))
+        .collect()
+}
+
+#[cfg(test)]
+mod rounds_tests {
+    use super::*;
+
+    #[test]
+    fn test_start_round() {
+        let mut rounds = RoundTable::new();
+        assert!(!rounds.is_signed(1));
+        assert!(rounds.start_round(1, HASH, None));
+        assert!(rounds.is_signed(1));
+        assert_eq!(rounds.status(1), Some(RoundStatus::Signing));
+        assert_eq!(rounds.get(1).unwrap().expected_hash, Some(HASH));
+        assert_eq!(rounds.len(), 1);
+        assert_eq!(rounds.open(), 1);
+        assert_eq!(rounds.awaiting_aggregation(), 1);
+    }
+
+    #[test]
+    fn test_round_started_once() {
+        let mut rounds = RoundTable::new();
+        assert!(rounds.start_round(1, HASH, None));
+        assert!(!rounds.start_round(1, [8; 32], None));
+        assert_eq!(rounds.get(1).unwrap().expected_hash, Some(HASH));
+        assert_eq!(rounds.len(), 1);
+    }
+
+    #[test]
+    fn test_own_signature_recorded_as_share() {
+        let mut rounds = RoundTable::new();
+        assert!(!rounds.record_own_signature(1, 0, share(0)));
+
+        rounds.start_round(1, HASH, None);
+        assert!(rounds.record_own_signature(1, 0, share(0)));
+        let state = rounds.get(1).unwrap();
+        assert_eq!(state.status, RoundStatus::Signed);
+        assert_eq!(state.our_signature, Some(share(0)));
+        assert_eq!(state.shares.get(&0), Some(&share(0)));
+    }
+
+    #[test]
+    fn test_share_before_start_dropped() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        assert_eq!(
+            rounds.record_share(1, 1, share(1), now).err(),
+            Some(ShareRejection::Dropped(DroppedShare::UnknownRound))
+        );
+        assert!(rounds.is_empty());
+    }
+
+    #[test]
+    fn test_share_accepted_while_signing() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        rounds.start_round(1, HASH, None);
+        let state = rounds.record_share(1, 1, share(1), now).unwrap();
+        assert_eq!(state.shares.len(), 1);
+
+        // Our signature completes after the share of a peer
+        rounds.record_own_signature(1, 0, share(0));
+        assert_eq!(rounds.get(1).unwrap().shares.len(), 2);
+    }
+
+    #[test]
+    fn test_duplicate_share_rejected() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        rounds.start_round(1, HASH, None);
+        rounds.record_share(1, 1, share(1), now).unwrap();
+        assert_eq!(
+            rounds.check_share(1, 1, now),
+            Err(ShareRejection::Duplicate)
+        );
+        assert_eq!(
+            rounds.record_share(1, 1, share(2), now).err(),
+            Some(ShareRejection::Duplicate)
+        );
+        assert_eq!(rounds.get(1).unwrap().shares[&1], share(1));
+    }
+
+    #[test]
+    fn test_share_after_deadline_expires_round() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        rounds.start_round(1, HASH, Some(now + Duration::from_secs(10)));
+        rounds.record_share(1, 1, share(1), now).unwrap();
+        assert!(!rounds.deadline_passed(1, now));
+
+        let late = now + Duration::from_secs(10);
+        assert!(rounds.deadline_passed(1, late));
+        assert_eq!(
+            rounds.record_share(1, 2, share(2), late).err(),
+            Some(ShareRejection::Dropped(DroppedShare::DeadlinePassed))
+        );
+        let state = rounds.get(1).unwrap();
+        assert_eq!(state.status, RoundStatus::Expired);
+        assert!(state.shares.is_empty());
+        assert_eq!(rounds.open(), 0);
+        assert_eq!(rounds.awaiting_aggregation(), 0);
+
+        // Expired rounds are still signed, and not signed again
+        assert!(rounds.is_signed(1));
+        assert!(!rounds.start_round(1, HASH, None));
+        assert!(!rounds.record_own_signature(1, 0, share(0)));
+    }
+
+    #[test]
+    fn test_share_after_retire_dropped() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        rounds.start_round(1, HASH, Some(now + Duration::from_secs(10)));
+        rounds.record_own_signature(1, 0, share(0));
+        rounds.record_share(1, 1, share(1), now).unwrap();
+
+        assert_eq!(rounds.retire(1), Some(2));
+        assert_eq!(rounds.retire(1), None);
+        assert!(rounds.is_retired(1));
+        assert!(!rounds.deadline_passed(1, now + Duration::from_secs(10)));
+        assert_eq!(
+            rounds.record_share(1, 2, share(2), now).err(),
+            Some(ShareRejection::Dropped(DroppedShare::CompletedOnChain))
+        );
+        assert_eq!(rounds.retired(), 1);
+        assert_eq!(rounds.open(), 0);
+    }
+
+    #[test]
+    fn test_retire_before_start() {
+        let mut rounds = RoundTable::new();
+        assert_eq!(rounds.retire(1), Some(0));
+        assert!(rounds.is_signed(1));
+        assert!(!rounds.start_round(1, HASH, None));
+        assert_eq!(rounds.get(1).unwrap().expected_hash, None);
+    }
+
+    #[test]
+    fn test_expiry_does_not_undo_retirement() {
+        let mut rounds = RoundTable::new();
+        rounds.start_round(1, HASH, None);
+        rounds.retire(1);
+        rounds.expire(1);
+        assert!(rounds.is_retired(1));
+    }
+
+    #[test]
+    fn test_latency_taken_once() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        rounds.start_round(1, HASH, None);
+        rounds.record_share(1, 1, share(1), now).unwrap();
+        assert!(rounds.mark_aggregated(1).is_some());
+        assert_eq!(rounds.status(1), Some(RoundStatus::Aggregated));
+        assert_eq!(rounds.awaiting_aggregation(), 0);
+
+        assert!(rounds.mark_aggregated(1).is_none());
+        assert!(rounds.mark_aggregated(2).is_none());
+    }
+
+    #[test]
+    fn test_reindex_moves_and_drops_shares() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        rounds.start_round(1, HASH, None);
+        for index in 0..3 {
+            rounds
+                .record_share(1, index as usize, share(index), now)
+                .unwrap();
+        }
+
+        // Contributor 0 left, the others moved down
+        rounds.reindex(|index| index.checked_sub(1));
+        let shares = &rounds.get(1).unwrap().shares;
+        assert_eq!(shares.len(), 2);
+        assert_eq!(shares[&0], share(1));
+        assert_eq!(shares[&1], share(2));
+    }
+
+    #[test]
+    fn test_transitions() {
+        use RoundStatus::*;
+        assert_eq!(
+            reachable(Signing),
+            vec![Signed, Aborted, Aggregated, Expired, Retired]
+        );
+        assert_eq!(reachable(Signed), vec![Aggregated, Expired, Retired]);
+        assert_eq!(reachable(Aborted), vec![Aggregated, Expired, Retired]);
+        assert_eq!(reachable(Aggregated), vec![Expired, Retired]);
+        assert_eq!(reachable(Expired), vec![Retired]);
+        assert!(reachable(Retired).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_transition_refused() {
+        let mut rounds = RoundTable::new();
+        rounds.start_round(1, HASH, None);
+        rounds.expire(1);
+
+        let state = rounds.get_mut(1).unwrap();
+        let err = state.transition(RoundStatus::Signed).unwrap_err();
+        assert_eq!(
+            err,
+            InvalidTransition {
+                from: RoundStatus::Expired,
+                to: RoundStatus::Signed,
+            }
+        );
+        assert_eq!(err.to_string(), "round cannot move from expired to signed");
+        assert_eq!(state.status, RoundStatus::Expired);
+        assert!(!rounds.record_own_signature(1, 0, share(0)));
+    }
+
+    #[test]
+    fn test_aggregated_round_refuses_shares() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        rounds.start_round(1, HASH, None);
+        rounds.record_share(1, 1, share(1), now).unwrap();
+        rounds.mark_aggregated(1);
+
+        let state = rounds.get_mut(1).unwrap();
+        let err = state.transition(RoundStatus::Aggregated).unwrap_err();
+        assert_eq!(
+            err,
+            InvalidTransition {
+                from: RoundStatus::Aggregated,
+                to: RoundStatus::Aggregated,
+            }
+        );
+        assert_eq!(
+            rounds.record_share(1, 2, share(2), now).unwrap_err(),
+            ShareRejection::Dropped(DroppedShare::Aggregated)
+        );
+        assert_eq!(rounds.get(1).unwrap().shares.len(), 1);
+    }
+
+    #[test]
+    fn test_aborted_round_collects_shares() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        assert!(!rounds.abort(1));
+        rounds.start_round(1, HASH, None);
+        assert!(rounds.abort(1));
+        assert_eq!(rounds.status(1), Some(RoundStatus::Aborted));
+        assert_eq!(rounds.active(), 0);
+        assert!(!rounds.abort(1));
+
+        // Our signature is never recorded, shares of peers still aggregate
+        assert!(!rounds.record_own_signature(1, 0, share(0)));
+        rounds.record_share(1, 1, share(1), now).unwrap();
+        assert!(rounds.mark_aggregated(1).is_some());
+        assert_eq!(rounds.status(1), Some(RoundStatus::Aggregated));
+    }
+
+    #[test]
+    fn test_signed_round_not_aborted() {
+        let mut rounds = RoundTable::new();
+        rounds.start_round(1, HASH, None);
+        rounds.record_own_signature(1, 0, share(0));
+        assert!(!rounds.abort(1));
+        assert_eq!(rounds.status(1), Some(RoundStatus::Signed));
+    }
+
+    #[test]
+    fn test_own_signature_after_aggregation() {
+        let mut rounds = RoundTable::new();
+        let now = Instant::now();
+        rounds.start_round(1, HASH, None);
+        rounds.record_share(1, 1, share(1), now).unwrap();
+        rounds.mark_aggregated(1);
+
+        // Peers aggregated the round first, it stays aggregated
+        assert!(rounds.record_own_signature(1, 0, share(0)));
+        let state = rounds.get(1).unwrap();
+        assert_eq!(state.status, RoundStatus::Aggregated);
+        assert_eq!(state.our_signature, Some(share(0)));
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut rounds = RoundTable::new();
+        rounds.start_round(1, HASH, None);
+        rounds.retire(2);
+        rounds.clear();
+        assert!(rounds.is_empty());
+        assert!(!rounds.is_signed(2));
+    }
+}
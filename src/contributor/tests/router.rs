@@ -0,0 +1,68 @@
+use super::mock::{MockContributor, MockSender, SentTo};
+use crate::contributor::{Destination, MessageClass, OutboundRouter};
+use bytes::Bytes;
+use commonware_cryptography::Signer;
+
+#[cfg(test)]
+mod router_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_single_sender_broadcasts_once() {
+        let orchestrator = MockContributor::create_test_bn254(1).public_key();
+        let sender = MockSender::new();
+        let mut router = OutboundRouter::single(sender.clone());
+
+        assert_eq!(router.route(MessageClass::Reply), None);
+        let message = Bytes::from_static(b"signature");
+        router
+            .send(MessageClass::Reply, &orchestrator, message.clone())
+            .await
+            .unwrap();
+        router
+            .send(MessageClass::Share, &orchestrator, message.clone())
+            .await
+            .unwrap();
+
+        // Same as the original single broadcast to all peers
+        assert_eq!(sender.sent().await, vec![(SentTo::All, message, true)]);
+    }
+
+    #[tokio::test]
+    async fn test_routes_per_message_class() {
+        let orchestrator = MockContributor::create_test_bn254(1).public_key();
+        let control = MockSender::new();
+        let data = MockSender::new();
+        let mut router = OutboundRouter::new(vec![control.clone(), data.clone()])
+            .with_route(MessageClass::Reply, 0, Destination::Orchestrator)
+            .and_then(|router| router.with_route(MessageClass::Share, 1, Destination::All))
+            .unwrap();
+
+        let reply = Bytes::from_static(b"reply");
+        let share = Bytes::from_static(b"share");
+        router
+            .send(MessageClass::Reply, &orchestrator, reply.clone())
+            .await
+            .unwrap();
+        router
+            .send(MessageClass::Share, &orchestrator, share.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            control.sent().await,
+            vec![(SentTo::One(orchestrator), reply, true)]
+        );
+        assert_eq!(data.sent().await, vec![(SentTo::All, share, true)]);
+    }
+
+    #[test]
+    fn test_route_to_missing_sender() {
+        let router = OutboundRouter::new(vec![MockSender::new()]).with_route(
+            MessageClass::Share,
+            1,
+            Destination::All,
+        );
+        assert!(router.is_err());
+    }
+}
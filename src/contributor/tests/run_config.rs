@@ -0,0 +1,58 @@
+use super::harness::Harness;
+use crate::contributor::OutboundRouter;
+use crate::handlers::{Contributor, RunConfig};
+use commonware_cryptography::Signer;
+use std::time::Duration;
+
+/// Whether the contributor signs an unsigned Start for round 1 when run with `config`
+async fn signs_unsigned_start(
+    configure: impl FnOnce(Contributor) -> Contributor,
+    config: RunConfig,
+) -> bool {
+    let mut harness = Harness::new(1);
+    let contributor = configure(harness.contributor(0, None));
+    let (sender, receiver) = harness.network.register(harness.signers[0].public_key());
+    let handle =
+        tokio::spawn(contributor.run_with_config(OutboundRouter::single(sender), receiver, config));
+
+    harness.start(1).await;
+    let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+    handle.abort();
+    signed
+        .get(&harness.signers[0].public_key())
+        .is_some_and(|rounds| rounds.contains(&1))
+}
+
+#[cfg(test)]
+mod run_config_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_config_applied() {
+        let config = RunConfig {
+            require_signed_starts: true,
+            ..RunConfig::default()
+        };
+        assert!(!signs_unsigned_start(|contributor| contributor, config).await);
+    }
+
+    #[tokio::test]
+    async fn test_run_config_replaces_configured_options() {
+        let signs = signs_unsigned_start(
+            |contributor| {
+                contributor.with_run_config(RunConfig {
+                    require_signed_starts: true,
+                    ..RunConfig::default()
+                })
+            },
+            RunConfig::default(),
+        )
+        .await;
+        assert!(signs);
+    }
+
+    #[tokio::test]
+    async fn test_default_run_config_signs() {
+        assert!(signs_unsigned_start(|contributor| contributor, RunConfig::default()).await);
+    }
+}
@@ -0,0 +1,144 @@
+use super::harness::{Harness, digest_of, encode, signature_message, start_message};
+use crate::contributor::events::EventSink;
+use crate::contributor::scores::{Offense, PeerScores, ScoreConfig};
+use crate::handlers::RunConfig;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::time::Instant;
+
+fn config() -> ScoreConfig {
+    ScoreConfig {
+        reward: 1,
+        invalid_signature_penalty: 10,
+        malformed_penalty: 5,
+        max_score: 5,
+        ignore_below: -25,
+        ignore_for: Duration::from_secs(60),
+    }
+}
+
+/// Sink counting the events scores act on
+#[derive(Clone, Default)]
+struct Counts {
+    invalid_signatures: Arc<AtomicU64>,
+    peers_ignored: Arc<AtomicU64>,
+    thresholds_reached: Arc<AtomicU64>,
+}
+
+impl EventSink for Counts {
+    fn invalid_signature(&self, _quorum_id: u8) {
+        self.invalid_signatures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn peer_ignored(&self, _quorum_id: u8) {
+        self.peers_ignored.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn threshold_reached(&self, _quorum_id: u8) {
+        self.thresholds_reached.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod scores_tests {
+    use super::*;
+
+    #[test]
+    fn test_peer_ignored_below_threshold() {
+        let mut scores = PeerScores::new(config());
+        let now = Instant::now();
+        assert!(!scores.penalize(&"peer", Offense::InvalidSignature, now));
+        assert!(!scores.penalize(&"peer", Offense::InvalidSignature, now));
+        assert!(!scores.penalize(&"peer", Offense::Malformed, now));
+        assert_eq!(scores.score(&"peer"), -25);
+        assert!(!scores.is_ignored(&"peer", now));
+
+        assert!(scores.penalize(&"peer", Offense::Malformed, now));
+        assert!(scores.is_ignored(&"peer", now));
+        assert!(!scores.is_ignored(&"other", now));
+        // Already ignored
+        assert!(!scores.penalize(&"peer", Offense::InvalidSignature, now));
+    }
+
+    #[test]
+    fn test_valid_shares_offset_penalties() {
+        let mut scores = PeerScores::new(config());
+        let now = Instant::now();
+        for _ in 0..10 {
+            scores.reward(&"peer", now);
+        }
+        // Capped, a peer turning bad is not shielded by its past shares
+        assert_eq!(scores.score(&"peer"), 5);
+        for _ in 0..3 {
+            assert!(!scores.penalize(&"peer", Offense::InvalidSignature, now));
+        }
+        assert_eq!(scores.score(&"peer"), -25);
+        scores.reward(&"peer", now);
+        assert_eq!(scores.score(&"peer"), -24);
+        assert!(!scores.is_ignored(&"peer", now));
+        assert!(scores.penalize(&"peer", Offense::Malformed, now));
+        assert_eq!(scores.score(&"peer"), -29);
+    }
+
+    #[test]
+    fn test_ignored_peer_recovers() {
+        let mut scores = PeerScores::new(config());
+        let now = Instant::now();
+        for _ in 0..3 {
+            scores.penalize(&"peer", Offense::InvalidSignature, now);
+        }
+        assert!(scores.is_ignored(&"peer", now));
+
+        let later = now + Duration::from_secs(61);
+        assert!(!scores.is_ignored(&"peer", later));
+        scores.reward(&"peer", later);
+        assert_eq!(scores.score(&"peer"), 1);
+        assert!(!scores.penalize(&"peer", Offense::InvalidSignature, later));
+        assert!(!scores.is_ignored(&"peer", later));
+    }
+
+    #[tokio::test]
+    async fn test_frames_of_ignored_peer_dropped_before_verification() {
+        let mut harness = Harness::new(2);
+        let counts = Counts::default();
+        let aggregator = harness
+            .contributor(0, Some(2))
+            .with_event_sink(Arc::new(counts.clone()))
+            .with_run_config(RunConfig {
+                scores: config(),
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(aggregator, 0);
+        let (mut peer, _receiver) = harness.network.register(harness.signers[1].public_key());
+
+        harness.start(1).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let invalid = harness.signers[1].sign(None, &digest_of(&start_message(2)));
+        for _ in 0..3 {
+            let frame = encode(&signature_message(1, invalid.to_vec()));
+            commonware_p2p::Sender::send(&mut peer, Recipients::All, frame, true)
+                .await
+                .unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(counts.invalid_signatures.load(Ordering::Relaxed), 3);
+        assert_eq!(counts.peers_ignored.load(Ordering::Relaxed), 1);
+
+        // Neither another invalid share nor a valid one reaches verification
+        let valid = harness.signers[1].sign(None, &digest_of(&start_message(1)));
+        for signature in [invalid, valid] {
+            let frame = encode(&signature_message(1, signature.to_vec()));
+            commonware_p2p::Sender::send(&mut peer, Recipients::All, frame, true)
+                .await
+                .unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        handle.abort();
+
+        assert_eq!(counts.invalid_signatures.load(Ordering::Relaxed), 3);
+        assert_eq!(counts.thresholds_reached.load(Ordering::Relaxed), 0);
+    }
+}
@@ -0,0 +1,119 @@
+use super::harness::{Harness, digest_of, encode, signature_message, start_message};
+use crate::contributor::deadline::BlockWindow;
+use crate::contributor::events::EventSink;
+use crate::contributor::types::DroppedShare;
+use crate::handlers::RunConfig;
+use crate::validation::counter::ValidationError;
+use crate::validation::metadata::{MetadataPolicy, MetadataReader, RoundMetadata};
+use crate::validation::window::{BlockProvider, SignatureWindowFilter};
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const START_BLOCK: u64 = 1000;
+
+const WINDOW_BLOCKS: u64 = 50;
+
+/// Provider stuck at a given block
+struct MockBlockProvider(u64);
+
+impl BlockProvider for MockBlockProvider {
+    fn current_block(&self) -> u64 {
+        self.0
+    }
+}
+
+fn filter_at(block: u64) -> SignatureWindowFilter {
+    SignatureWindowFilter::new(
+        START_BLOCK,
+        WINDOW_BLOCKS,
+        Arc::new(MockBlockProvider(block)),
+    )
+}
+
+/// Reader reporting tasks at [START_BLOCK] for every round
+fn reader() -> MetadataReader {
+    let deadline = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 60;
+    Arc::new(move |_: &CounterTaskData| RoundMetadata {
+        task_id: Some(b"task".to_vec()),
+        reference_block: Some(START_BLOCK),
+        deadline: Some(deadline),
+    })
+}
+
+/// Sink recording why shares were dropped
+#[derive(Clone, Default)]
+struct DroppedShares(Arc<Mutex<Vec<String>>>);
+
+impl EventSink for DroppedShares {
+    fn share_dropped(&self, _quorum_id: u8, reason: &str) {
+        self.0.lock().unwrap().push(reason.to_string());
+    }
+}
+
+#[cfg(test)]
+mod signature_window_tests {
+    use super::*;
+
+    #[test]
+    fn test_window_bounds() {
+        assert!(filter_at(START_BLOCK).is_within_window());
+        assert!(filter_at(START_BLOCK + WINDOW_BLOCKS - 1).is_within_window());
+        assert_eq!(
+            filter_at(START_BLOCK + WINDOW_BLOCKS).check(),
+            Err(ValidationError::OutsideWindow {
+                current_block: START_BLOCK + WINDOW_BLOCKS,
+                start_block: START_BLOCK,
+                end_block: START_BLOCK + WINDOW_BLOCKS,
+            })
+        );
+    }
+
+    #[test]
+    fn test_head_behind_start_accepted() {
+        assert!(filter_at(START_BLOCK - 1).is_within_window());
+    }
+
+    #[tokio::test]
+    async fn test_share_past_window_dropped() {
+        let mut harness = Harness::new(2);
+        let head = Arc::new(AtomicU64::new(START_BLOCK));
+        let dropped = DroppedShares::default();
+        let aggregator = harness
+            .contributor(0, Some(2))
+            .with_event_sink(Arc::new(dropped.clone()))
+            .with_chain_head(head.clone())
+            .with_run_config(RunConfig {
+                metadata_policy: Some((MetadataPolicy::default(), reader())),
+                block_window: Some(BlockWindow::new(WINDOW_BLOCKS, Duration::from_secs(12))),
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(aggregator, 0);
+        let (mut peer, _peer_receiver) = harness.network.register(harness.signers[1].public_key());
+
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+
+        // The chain moves past the window before the peer's share arrives
+        head.store(START_BLOCK + WINDOW_BLOCKS, Ordering::Relaxed);
+        let signature = harness.signers[1].sign(None, &digest_of(&start_message(1)));
+        let frame = encode(&signature_message(1, signature.to_vec()));
+        commonware_p2p::Sender::send(&mut peer, Recipients::All, frame, true)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(
+            *dropped.0.lock().unwrap(),
+            [DroppedShare::OutsideWindow.kind()]
+        );
+        handle.abort();
+    }
+}
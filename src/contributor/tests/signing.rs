@@ -0,0 +1,163 @@
+use super::harness::Harness;
+use crate::contributor::AsyncSigner;
+use crate::handlers::RunConfig;
+use anyhow::Result;
+use bn254::{Bn254, PublicKey, Signature};
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Signer taking `delay` for its first signature only
+struct SlowSigner {
+    inner: Bn254,
+    delay: Duration,
+    calls: AtomicUsize,
+}
+
+impl AsyncSigner for SlowSigner {
+    type PublicKey = PublicKey;
+    type Signature = Signature;
+
+    fn public_key(&self) -> PublicKey {
+        AsyncSigner::public_key(&self.inner)
+    }
+
+    fn sign<'a>(
+        &'a self,
+        namespace: Option<&'a [u8]>,
+        payload: &'a [u8],
+    ) -> BoxFuture<'a, Result<Signature>> {
+        let first = self.calls.fetch_add(1, Ordering::SeqCst) == 0;
+        Box::pin(async move {
+            if first {
+                tokio::time::sleep(self.delay).await;
+            }
+            AsyncSigner::sign(&self.inner, namespace, payload).await
+        })
+    }
+}
+
+/// Signer failing its first signature only
+struct FailingSigner {
+    inner: Bn254,
+    calls: AtomicUsize,
+}
+
+impl AsyncSigner for FailingSigner {
+    type PublicKey = PublicKey;
+    type Signature = Signature;
+
+    fn public_key(&self) -> PublicKey {
+        AsyncSigner::public_key(&self.inner)
+    }
+
+    fn sign<'a>(
+        &'a self,
+        namespace: Option<&'a [u8]>,
+        payload: &'a [u8],
+    ) -> BoxFuture<'a, Result<Signature>> {
+        let first = self.calls.fetch_add(1, Ordering::SeqCst) == 0;
+        Box::pin(async move {
+            if first {
+                return Err(anyhow::anyhow!("signer unavailable"));
+            }
+            AsyncSigner::sign(&self.inner, namespace, payload).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod signing_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_signature_does_not_starve_other_rounds() {
+        let mut harness = Harness::new(1);
+        let signer = SlowSigner {
+            inner: harness.signers[0].clone(),
+            delay: Duration::from_secs(5),
+            calls: AtomicUsize::new(0),
+        };
+        let contributor = harness
+            .contributor(0, None)
+            .with_signer(Arc::new(signer))
+            .unwrap();
+        let handle = harness.spawn(contributor, 0);
+
+        harness.start(1).await;
+        harness.start(2).await;
+
+        // Round 2 is signed while round 1 is still being signed
+        let (_, first) = harness
+            .orchestrator_receiver
+            .next_message(Duration::from_secs(1))
+            .await
+            .expect("round 2 signature");
+        assert_eq!(first.round, 2);
+        let (_, second) = harness
+            .orchestrator_receiver
+            .next_message(Duration::from_secs(10))
+            .await
+            .expect("round 1 signature");
+        assert_eq!(second.round, 1);
+
+        handle.abort();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_signature_times_out() {
+        let mut harness = Harness::new(1);
+        let signer = SlowSigner {
+            inner: harness.signers[0].clone(),
+            delay: Duration::from_secs(60),
+            calls: AtomicUsize::new(0),
+        };
+        let contributor = harness
+            .contributor(0, None)
+            .with_signer(Arc::new(signer))
+            .unwrap()
+            .with_run_config(RunConfig {
+                signing_timeout: Duration::from_secs(1),
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(contributor, 0);
+
+        harness.start(1).await;
+        let signed = harness.signed_rounds(Duration::from_secs(5)).await;
+        assert!(signed.is_empty());
+
+        // The contributor keeps serving later rounds
+        harness.start(2).await;
+        let signed = harness.signed_rounds(Duration::from_secs(1)).await;
+        let rounds = &signed[&harness.signers[0].public_key()];
+        assert_eq!(rounds.iter().copied().collect::<Vec<_>>(), vec![2]);
+        assert!(!handle.is_finished());
+
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_signing_error_skips_round() {
+        let mut harness = Harness::new(1);
+        let signer = FailingSigner {
+            inner: harness.signers[0].clone(),
+            calls: AtomicUsize::new(0),
+        };
+        let contributor = harness
+            .contributor(0, None)
+            .with_signer(Arc::new(signer))
+            .unwrap();
+        let handle = harness.spawn(contributor, 0);
+
+        harness.start(1).await;
+        harness.start(2).await;
+        let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+        let rounds = &signed[&harness.signers[0].public_key()];
+        assert!(!rounds.contains(&1));
+        assert!(rounds.contains(&2));
+        assert!(!handle.is_finished());
+
+        handle.abort();
+    }
+}
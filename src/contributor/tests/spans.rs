@@ -0,0 +1,115 @@
+use super::harness::{Harness, LogBuffer};
+use crate::chain::{CompletionWatcher, TaskEventSource, TaskResponded};
+use crate::runner::{BackgroundTask, ShutdownStage, TaskGroup};
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{Span, info, info_span};
+
+/// Event source whose RPC is down
+struct UnreachableSource;
+
+impl TaskEventSource for UnreachableSource {
+    async fn block_number(&self) -> Result<u64> {
+        Err(anyhow!("connection refused"))
+    }
+
+    async fn task_responses(&self, _from_block: u64, _to_block: u64) -> Result<Vec<TaskResponded>> {
+        Err(anyhow!("connection refused"))
+    }
+}
+
+/// Span of the node the tasks under test belong to
+fn node_span() -> Span {
+    info_span!("node", id = 7)
+}
+
+/// First logged line containing `message`
+fn line(logs: &LogBuffer, message: &str) -> String {
+    let contents = logs.contents();
+    contents
+        .lines()
+        .find(|line| line.contains(message))
+        .unwrap_or_else(|| panic!("{message:?} not logged in:\n{contents}"))
+        .to_string()
+}
+
+/// Let spawned tasks run
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+#[cfg(test)]
+mod spans_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_round_processing_logged_under_node_span() {
+        let logs = LogBuffer::default();
+        let _guard = logs.install();
+        let mut harness = Harness::new(1);
+        let contributor = harness.contributor(0, None);
+        let handle = node_span().in_scope(|| harness.spawn(contributor, 0));
+
+        harness.start(1).await;
+        harness.signed_rounds(Duration::from_millis(200)).await;
+        handle.abort();
+
+        let signing = line(&logs, "Generating signature for round");
+        assert!(
+            signing.contains("node{id=7}:sign_start{round=1}:"),
+            "{signing}"
+        );
+        let sending = line(&logs, "broadcast signature");
+        assert!(
+            sending.contains("node{id=7}:send_signature{round=1}:"),
+            "{sending}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_background_task_inherits_span() {
+        let logs = LogBuffer::default();
+        let _guard = logs.install();
+        let mut group = TaskGroup::new();
+        node_span().in_scope(|| {
+            group.spawn(BackgroundTask::new(
+                "inside",
+                ShutdownStage::Watchers,
+                |mut token| async move {
+                    info!("inside started");
+                    token.cancelled().await;
+                    Ok(())
+                },
+            ))
+        });
+        group.spawn(BackgroundTask::new(
+            "outside",
+            ShutdownStage::Watchers,
+            |mut token| async move {
+                info!("outside started");
+                token.cancelled().await;
+                Ok(())
+            },
+        ));
+
+        let summary = group.run_until(settle()).await;
+        assert!(summary.is_clean());
+        assert!(line(&logs, "inside started").contains("node{id=7}:"));
+        assert!(!line(&logs, "outside started").contains("node{"));
+    }
+
+    #[tokio::test]
+    async fn test_watcher_logs_under_spawning_span() {
+        let logs = LogBuffer::default();
+        let _guard = logs.install();
+        let watcher = CompletionWatcher::new(Arc::new(UnreachableSource), 0)
+            .with_poll_interval(Duration::from_secs(60));
+        let handle = node_span().in_scope(|| watcher.spawn());
+        settle().await;
+        handle.abort();
+
+        let failed = line(&logs, "failed to read task responses");
+        assert!(failed.contains("node{id=7}:"), "{failed}");
+    }
+}
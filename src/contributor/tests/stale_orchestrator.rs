@@ -0,0 +1,116 @@
+use super::harness::Harness;
+use crate::contributor::liveness::{OrchestratorStale, StaleDetector};
+use crate::handlers::RunConfig;
+use anyhow::Result;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+const THRESHOLD: Duration = Duration::from_secs(90);
+
+/// Contributor at index 0 alerting on a stale orchestrator
+fn spawn_watcher(
+    harness: &Harness,
+) -> (
+    JoinHandle<Result<()>>,
+    broadcast::Receiver<OrchestratorStale>,
+) {
+    let (events, alerts) = broadcast::channel(8);
+    let contributor = harness.contributor(0, None).with_run_config(RunConfig {
+        stale_alerts: Some((THRESHOLD, events)),
+        ..RunConfig::default()
+    });
+    (harness.spawn(contributor, 0), alerts)
+}
+
+/// Let the contributor handle what is pending
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+#[cfg(test)]
+mod stale_orchestrator_tests {
+    use super::*;
+
+    #[test]
+    fn test_silence_reported_once_until_next_start() {
+        let now = Instant::now();
+        let mut stale = StaleDetector::new(THRESHOLD, now);
+        assert_eq!(stale.deadline(), Some(now + THRESHOLD));
+        assert_eq!(stale.check(now + THRESHOLD / 2), None);
+
+        let late = now + THRESHOLD * 2;
+        assert_eq!(
+            stale.check(late),
+            Some(OrchestratorStale {
+                silent_for: THRESHOLD * 2,
+                last_round: None,
+            })
+        );
+        assert_eq!(stale.check(late), None);
+        assert_eq!(stale.deadline(), None);
+
+        // A Start re-arms the detector
+        stale.start_seen(4, late);
+        assert_eq!(stale.deadline(), Some(late + THRESHOLD));
+        assert_eq!(
+            stale.check(late + THRESHOLD),
+            Some(OrchestratorStale {
+                silent_for: THRESHOLD,
+                last_round: Some(4),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stale_orchestrator_alerted() {
+        let mut harness = Harness::new(2);
+        let (handle, mut alerts) = spawn_watcher(&harness);
+        settle().await;
+
+        harness.start(1).await;
+        settle().await;
+        harness.advance(THRESHOLD - Duration::from_secs(1));
+        settle().await;
+        assert!(alerts.try_recv().is_err());
+
+        harness.advance(Duration::from_secs(1));
+        settle().await;
+        let alert = alerts.try_recv().unwrap();
+        assert_eq!(alert.last_round, Some(1));
+        assert!(alert.silent_for >= THRESHOLD);
+
+        // Reported once per silence
+        harness.advance(THRESHOLD);
+        settle().await;
+        assert!(alerts.try_recv().is_err());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_start_rearms_alert() {
+        let mut harness = Harness::new(2);
+        let (handle, mut alerts) = spawn_watcher(&harness);
+        settle().await;
+
+        // Without any Start the silence counts from startup
+        harness.advance(THRESHOLD);
+        settle().await;
+        assert_eq!(alerts.try_recv().unwrap().last_round, None);
+
+        // Starts within the threshold keep the orchestrator live
+        for round in 1..=3 {
+            harness.start(round).await;
+            settle().await;
+            harness.advance(THRESHOLD / 2);
+            settle().await;
+        }
+        assert!(alerts.try_recv().is_err());
+
+        harness.advance(THRESHOLD);
+        settle().await;
+        assert_eq!(alerts.try_recv().unwrap().last_round, Some(3));
+        handle.abort();
+    }
+}
@@ -0,0 +1,94 @@
+use super::harness::{Harness, encode, start_message};
+use crate::contributor::start::SignedStart;
+use crate::handlers::RunConfig;
+use bn254::Bn254;
+use bytes::Bytes;
+use commonware_codec::{EncodeSize, ReadExt, Write};
+use std::time::Duration;
+
+/// Start for `round` signed by `signer`, wrapped in its envelope
+fn signed_start(signer: &Bn254, round: u64) -> Bytes {
+    envelope(&SignedStart::sign(signer, encode(&start_message(round))))
+}
+
+fn envelope(signed: &SignedStart) -> Bytes {
+    let mut buf = Vec::with_capacity(signed.encode_size());
+    signed.write(&mut buf);
+    Bytes::from(buf)
+}
+
+/// Whether a contributor requiring signed Starts signs round 1 after receiving `frame`
+async fn signs(frame: impl FnOnce(&Harness) -> Bytes) -> bool {
+    let mut harness = Harness::new(1);
+    let contributor = harness.contributor(0, None).with_run_config(RunConfig {
+        require_signed_starts: true,
+        ..RunConfig::default()
+    });
+    let handle = harness.spawn(contributor, 0);
+
+    let frame = frame(&harness);
+    harness.broadcast(frame).await;
+    let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+    handle.abort();
+    signed
+        .get(&harness.signers[0].public_key())
+        .is_some_and(|rounds| rounds.contains(&1))
+}
+
+#[cfg(test)]
+mod start_tests {
+    use super::*;
+
+    #[test]
+    fn test_envelope_roundtrip() {
+        let harness = Harness::new(1);
+        let signed = SignedStart::sign(&harness.orchestrator, encode(&start_message(3)));
+        let frame = envelope(&signed);
+        assert!(SignedStart::is_signed_start(&frame));
+        assert!(!SignedStart::is_signed_start(&encode(&start_message(3))));
+
+        let decoded = SignedStart::read(&mut std::io::Cursor::new(&frame[..])).unwrap();
+        assert_eq!(decoded, signed);
+        assert!(decoded.verify(&harness.orchestrator.public_key()));
+        assert!(!decoded.verify(&harness.signers[0].public_key()));
+    }
+
+    #[tokio::test]
+    async fn test_signs_start_signed_by_orchestrator() {
+        assert!(signs(|harness| signed_start(&harness.orchestrator, 1)).await);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_unsigned_start() {
+        assert!(!signs(|_| encode(&start_message(1))).await);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_forged_start() {
+        assert!(!signs(|harness| signed_start(&harness.signers[0], 1)).await);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_tampered_start() {
+        assert!(
+            !signs(|harness| {
+                let mut signed =
+                    SignedStart::sign(&harness.orchestrator, encode(&start_message(2)));
+                signed.start = encode(&start_message(1));
+                envelope(&signed)
+            })
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signed_start_accepted_when_not_required() {
+        let mut harness = Harness::new(1);
+        let handle = harness.spawn(harness.contributor(0, None), 0);
+        let frame = signed_start(&harness.orchestrator, 1);
+        harness.broadcast(frame).await;
+        let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+        handle.abort();
+        assert!(signed[&harness.signers[0].public_key()].contains(&1));
+    }
+}
@@ -0,0 +1,196 @@
+use super::harness::{Harness, encode, start_message};
+use crate::contributor::sync::{
+    RoundSummary, SyncConfig, SyncLog, SyncMessage, SyncRequest, SyncResponse,
+};
+use crate::handlers::RunConfig;
+use bytes::Bytes;
+use commonware_codec::{EncodeSize, ReadExt, Write};
+use commonware_cryptography::Signer;
+use std::time::Duration;
+
+fn roundtrip(message: &SyncMessage) -> SyncMessage {
+    let mut buf = Vec::with_capacity(message.encode_size());
+    message.write(&mut buf);
+    assert_eq!(buf.len(), message.encode_size());
+    assert!(SyncMessage::is_sync(&buf));
+    SyncMessage::read(&mut std::io::Cursor::new(buf)).unwrap()
+}
+
+#[cfg(test)]
+mod sync_log_tests {
+    use super::*;
+
+    #[test]
+    fn test_consecutive_rounds_do_not_sync() {
+        let mut log = SyncLog::new(SyncConfig::default());
+        assert_eq!(log.observe_round(1), None);
+        assert_eq!(log.observe_round(2), None);
+        assert_eq!(log.observe_round(2), None);
+    }
+
+    #[test]
+    fn test_gap_requests_missed_rounds() {
+        let mut log = SyncLog::new(SyncConfig::default());
+        log.observe_round(1);
+        assert_eq!(
+            log.observe_round(4),
+            Some(SyncRequest {
+                from_round: 2,
+                to_round: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_request_is_bounded() {
+        let mut log = SyncLog::new(SyncConfig {
+            max_rounds: 4,
+            ..Default::default()
+        });
+        log.observe_round(1);
+        assert_eq!(
+            log.observe_round(100),
+            Some(SyncRequest {
+                from_round: 96,
+                to_round: 99
+            })
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_respond_only_includes_open_rounds() {
+        let mut log = SyncLog::new(SyncConfig {
+            round_deadline: Duration::from_secs(10),
+            ..Default::default()
+        });
+        log.record_start(1, Bytes::from_static(b"one"), [1; 32]);
+        tokio::time::advance(Duration::from_secs(20)).await;
+        log.record_start(2, Bytes::from_static(b"two"), [2; 32]);
+        log.record_start(3, Bytes::from_static(b"three"), [3; 32]);
+        log.mark_aggregated(3);
+
+        let response = log.respond(&SyncRequest {
+            from_round: 1,
+            to_round: 3,
+        });
+        assert_eq!(
+            response.rounds,
+            vec![
+                RoundSummary {
+                    round: 1,
+                    payload_hash: [1; 32],
+                    aggregated: false,
+                    start: None,
+                },
+                RoundSummary {
+                    round: 2,
+                    payload_hash: [2; 32],
+                    aggregated: false,
+                    start: Some(Bytes::from_static(b"two")),
+                },
+                RoundSummary {
+                    round: 3,
+                    payload_hash: [3; 32],
+                    aggregated: true,
+                    start: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sync_message_roundtrip() {
+        let request = SyncMessage::Request(SyncRequest {
+            from_round: 5,
+            to_round: 9,
+        });
+        assert_eq!(roundtrip(&request), request);
+
+        let response = SyncMessage::Response(SyncResponse {
+            rounds: vec![
+                RoundSummary {
+                    round: 5,
+                    payload_hash: [5; 32],
+                    aggregated: true,
+                    start: None,
+                },
+                RoundSummary {
+                    round: 6,
+                    payload_hash: [6; 32],
+                    aggregated: false,
+                    start: Some(encode(&start_message(6))),
+                },
+            ],
+        });
+        assert_eq!(roundtrip(&response), response);
+    }
+
+    #[test]
+    fn test_truncated_sync_message_is_rejected() {
+        let response = SyncMessage::Response(SyncResponse {
+            rounds: vec![RoundSummary {
+                round: 6,
+                payload_hash: [6; 32],
+                aggregated: false,
+                start: Some(Bytes::from_static(b"start")),
+            }],
+        });
+        let mut buf = Vec::with_capacity(response.encode_size());
+        response.write(&mut buf);
+        buf.truncate(buf.len() - 1);
+        assert!(SyncMessage::read(&mut std::io::Cursor::new(buf)).is_err());
+    }
+}
+
+#[cfg(test)]
+mod sync_harness_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_partitioned_contributor_joins_open_round() {
+        let mut harness = Harness::new(3);
+        let config = SyncConfig {
+            round_deadline: Duration::from_secs(10),
+            ..Default::default()
+        };
+        let handles: Vec<_> = (0..3)
+            .map(|i| {
+                let contributor = harness.contributor(i, None).with_run_config(RunConfig {
+                    sync: config.clone(),
+                    ..RunConfig::default()
+                });
+                harness.spawn(contributor, i)
+            })
+            .collect();
+        let lagging = harness.signers[2].public_key();
+
+        // Everyone signs the first round
+        harness.start(1).await;
+        let signed = harness.signed_rounds(Duration::from_secs(1)).await;
+        assert_eq!(signed.len(), 3);
+        assert!(signed.values().all(|rounds| rounds.contains(&1)));
+
+        // Partition one node across two rounds, the first of which expires
+        harness.network.partition(&lagging);
+        harness.start(2).await;
+        tokio::time::sleep(Duration::from_secs(20)).await;
+        harness.start(3).await;
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        // Reconnect, the next Start reveals the gap
+        harness.network.heal(&lagging);
+        harness.start(4).await;
+        let signed = harness.signed_rounds(Duration::from_secs(1)).await;
+        let lagging_rounds = signed.get(&lagging).cloned().unwrap_or_default();
+        assert!(
+            lagging_rounds.contains(&3),
+            "did not contribute to open round"
+        );
+        assert!(lagging_rounds.contains(&4));
+        assert!(!lagging_rounds.contains(&2), "contributed to expired round");
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use super::mock::{MockContributor, MockReceiver, MockSender};
-use crate::contributor::{AggregationInput, Contribute, ContributorBase};
+use super::mock::{MockConfigError, MockContributor, MockReceiver, MockSender};
+use crate::contributor::{
+    AggregationInput, Contribute, ContributeError, ContributorBase, OutboundRouter,
+};
 use ark_bn254::Fr;
 use bn254::{Bn254, PrivateKey};
 use commonware_cryptography::Signer;
@@ -79,7 +81,8 @@ mod contribute_tests {
             signer,
             contributors,
             Some(aggregation_input),
-        );
+        )
+        .unwrap();
 
         assert_eq!(contributor.orchestrator, orchestrator.public_key());
         assert_eq!(contributor.contributors.len(), 3);
@@ -93,7 +96,7 @@ mod contribute_tests {
         let contributors = vec![signer.public_key(), orchestrator.public_key()];
 
         let contributor =
-            MockContributor::new(orchestrator.public_key(), signer, contributors, None);
+            MockContributor::new(orchestrator.public_key(), signer, contributors, None).unwrap();
 
         assert_eq!(contributor.orchestrator, orchestrator.public_key());
         assert_eq!(contributor.contributors.len(), 2);
@@ -116,7 +119,7 @@ mod contribute_tests {
         ];
 
         let contributor =
-            MockContributor::new(orchestrator.public_key(), signer, contributors, None);
+            MockContributor::new(orchestrator.public_key(), signer, contributors, None).unwrap();
 
         // Verify contributors are sorted
         let mut sorted_contributors = contributor.contributors.clone();
@@ -138,13 +141,72 @@ mod contribute_tests {
         ];
 
         let contributor =
-            MockContributor::new(orchestrator.public_key(), signer, contributors, None);
+            MockContributor::new(orchestrator.public_key(), signer, contributors, None).unwrap();
 
         // Verify that me index corresponds to the signer's position in sorted contributors
         let signer_index = contributor.get_contributor_index(&signer_pubkey).unwrap();
         assert_eq!(contributor.me, *signer_index);
     }
 
+    #[test]
+    fn test_new_rejects_signer_outside_contributors() {
+        let signer = create_test_bn254(43);
+        let orchestrator = create_test_bn254(44);
+        let contributors = vec![orchestrator.public_key()];
+
+        let result =
+            MockContributor::try_new(orchestrator.public_key(), signer, contributors, None);
+        assert_eq!(result.err(), Some(MockConfigError::SignerNotContributor));
+    }
+
+    #[test]
+    fn test_new_rejects_unreachable_threshold() {
+        let signer = create_test_bn254(45);
+        let orchestrator = create_test_bn254(46);
+        let contributors = vec![signer.public_key(), orchestrator.public_key()];
+
+        let result = MockContributor::try_new(
+            orchestrator.public_key(),
+            signer,
+            contributors,
+            Some(AggregationInput::new(3, HashMap::new())),
+        );
+        assert_eq!(
+            result.err(),
+            Some(MockConfigError::ThresholdOutOfRange {
+                threshold: 3,
+                contributors: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_new_fails_for_signer_outside_contributors() {
+        let signer = create_test_bn254(47);
+        let orchestrator = create_test_bn254(48);
+        let result = MockContributor::new(
+            orchestrator.public_key(),
+            signer,
+            vec![orchestrator.public_key()],
+            None,
+        );
+        assert_eq!(result.err(), Some(ContributeError::SignerNotInContributors));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid mock contributor: threshold 3 out of range")]
+    fn test_new_panics_on_invalid_configuration() {
+        let signer = create_test_bn254(49);
+        let orchestrator = create_test_bn254(50);
+        let contributors = vec![signer.public_key(), orchestrator.public_key()];
+        let _ = MockContributor::new(
+            orchestrator.public_key(),
+            signer,
+            contributors,
+            Some(AggregationInput::new(3, HashMap::new())),
+        );
+    }
+
     #[tokio::test]
     async fn test_run_method() {
         let contributor = MockContributor::new_test_contributor();
@@ -154,7 +216,9 @@ mod contribute_tests {
         let receiver = MockReceiver::new();
 
         // Test that the run method completes successfully
-        let result = contributor.run(sender, receiver).await;
+        let result = contributor
+            .run(OutboundRouter::single(sender), receiver)
+            .await;
         assert!(result.is_ok());
     }
 }
@@ -175,12 +239,11 @@ mod aggregation_input_tests {
     }
 
     #[test]
-    fn test_aggregation_input_with_g1_map() {
+    fn test_aggregation_input_with_g1_map() -> Result<(), MockConfigError> {
         let threshold = 2;
         let mut g1_map = HashMap::new();
         let signer = create_test_bn254(50);
-        // Create a simple G1 key for testing (using default coordinates)
-        let g1_key = bn254::G1PublicKey::create_from_g1_coordinates("0", "0").unwrap();
+        let g1_key = MockContributor::placeholder_g1_key()?;
         g1_map.insert(signer.public_key(), g1_key);
 
         let aggregation_input = AggregationInput::new(threshold, g1_map);
@@ -192,5 +255,53 @@ mod aggregation_input_tests {
                 .g1_map()
                 .contains_key(&signer.public_key())
         );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod contributors_for_round_tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_contributors_for_round_defaults_to_active_set() {
+        let contributor = MockContributor::new_test_contributor();
+
+        assert_eq!(
+            contributor.contributors_for_round(0),
+            contributor.contributors
+        );
+        assert_eq!(
+            contributor.contributors_for_round(7),
+            contributor.contributors
+        );
+    }
+
+    #[test]
+    fn test_contributors_for_round_with_assignment() {
+        let mut contributor = MockContributor::new_test_contributor();
+
+        // Rotate a window of two contributors through the sorted set
+        contributor.assignment = Some(Arc::new(|round: u64, contributors: &[bn254::PublicKey]| {
+            let start = (round as usize) % contributors.len();
+            (0..2)
+                .map(|offset| contributors[(start + offset) % contributors.len()].clone())
+                .collect()
+        }));
+
+        let set = contributor.contributors.clone();
+        assert_eq!(
+            contributor.contributors_for_round(0),
+            vec![set[0].clone(), set[1].clone()]
+        );
+        assert_eq!(
+            contributor.contributors_for_round(1),
+            vec![set[1].clone(), set[2].clone()]
+        );
+        assert_eq!(
+            contributor.contributors_for_round(3),
+            vec![set[3].clone(), set[0].clone()]
+        );
     }
 }
@@ -0,0 +1,110 @@
+use super::harness::{Harness, LogBuffer};
+use crate::chain::QuorumUpdated;
+use crate::contributor::events::ThresholdCounter;
+use crate::contributor::{Contribute, ContributeError, ContributorBase};
+use crate::handlers::Contributor;
+use commonware_cryptography::Signer;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Run one round with `signers` contributors online, the first aggregating with `threshold`
+///
+/// Returns the number of times the threshold was reached and the captured logs.
+async fn run_round(harness: &mut Harness, signers: usize, threshold: usize) -> (u64, String) {
+    let logs = LogBuffer::default();
+    let _guard = logs.install();
+
+    let reached = ThresholdCounter::new();
+    let mut handles = vec![
+        harness.spawn(
+            harness
+                .contributor(0, Some(threshold))
+                .with_event_sink(Arc::new(reached.clone())),
+            0,
+        ),
+    ];
+    for i in 1..signers {
+        handles.push(harness.spawn(harness.contributor(i, None), i));
+    }
+
+    harness.start(1).await;
+    let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+    assert_eq!(signed.len(), signers);
+    for handle in handles {
+        handle.abort();
+    }
+
+    (reached.get(), logs.contents())
+}
+
+#[cfg(test)]
+mod threshold_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_no_aggregation_below_threshold() {
+        let mut harness = Harness::new(3);
+        let (reached, logs) = run_round(&mut harness, 2, 3).await;
+        assert_eq!(reached, 0);
+        assert!(!logs.contains("aggregated signatures"));
+    }
+
+    #[tokio::test]
+    async fn test_aggregation_at_threshold() {
+        let mut harness = Harness::new(3);
+        let (reached, logs) = run_round(&mut harness, 3, 3).await;
+        assert_eq!(reached, 1);
+        assert!(logs.contains("aggregated signatures"));
+    }
+
+    #[tokio::test]
+    async fn test_threshold_counted_once() {
+        // Signatures past the threshold are dropped and do not reach it again
+        let mut harness = Harness::new(5);
+        let (reached, _) = run_round(&mut harness, 5, 2).await;
+        assert_eq!(reached, 1);
+    }
+
+    #[test]
+    fn test_update_for_other_quorum_ignored() {
+        let harness = Harness::new(3);
+        let initial = vec![
+            harness.signers[0].public_key(),
+            harness.signers[1].public_key(),
+        ];
+        let mut contributor = Contributor::new(
+            harness.orchestrator.public_key(),
+            harness.signers[0].clone(),
+            initial,
+            Some(harness.aggregation_input(2)),
+        )
+        .unwrap();
+        let before = contributor.contributors_for_round(1);
+
+        contributor.update_contributor_set(&QuorumUpdated {
+            quorum_id: 1,
+            added: vec![harness.signers[2].public_key()],
+            removed: vec![harness.signers[1].public_key()],
+            g1_keys: HashMap::new(),
+        });
+        assert_eq!(contributor.contributors_for_round(1), before);
+        assert!(
+            contributor
+                .get_contributor_index(&harness.signers[1].public_key())
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_signer_with_other_key_rejected() {
+        let harness = Harness::new(2);
+        let contributor = harness
+            .contributor(0, None)
+            .with_signer(Arc::new(harness.signers[1].clone()));
+        assert_eq!(
+            contributor.err(),
+            Some(ContributeError::SignerNotInContributors)
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::contributor::unknown_peers::{UnknownPeerConfig, UnknownPeers};
+use std::time::Duration;
+use tokio::time::Instant;
+
+const INTERVAL: Duration = Duration::from_secs(60);
+
+fn tracker(max_peers: usize) -> UnknownPeers<&'static str> {
+    UnknownPeers::new(UnknownPeerConfig {
+        max_peers,
+        refresh_interval: INTERVAL,
+    })
+}
+
+#[cfg(test)]
+mod unknown_peers_tests {
+    use super::*;
+
+    #[test]
+    fn test_only_first_message_flagged() {
+        let now = Instant::now();
+        let mut peers = tracker(8);
+        let sightings: Vec<_> = (0..3).map(|_| peers.record(&"new", now)).collect();
+        assert!(sightings[0].is_first());
+        assert!(!sightings[1].is_first());
+        assert_eq!(sightings[2].messages, 3);
+        assert_eq!(peers.messages(&"new"), 3);
+    }
+
+    #[test]
+    fn test_refresh_at_most_once_per_interval() {
+        let start = Instant::now();
+        let mut peers = tracker(8);
+        assert!(peers.record(&"new", start).refresh);
+        assert!(!peers.record(&"new", start + Duration::from_secs(1)).refresh);
+        assert!(!peers.record(&"new", start + INTERVAL / 2).refresh);
+
+        // Another peer is refreshed for on its own
+        assert!(peers.record(&"other", start + INTERVAL / 2).refresh);
+
+        assert!(peers.record(&"new", start + INTERVAL).refresh);
+        assert!(!peers.record(&"new", start + INTERVAL * 3 / 2).refresh);
+    }
+
+    #[test]
+    fn test_bounded_to_latest_peers() {
+        let now = Instant::now();
+        let mut peers = tracker(2);
+        peers.record(&"first", now);
+        peers.record(&"second", now);
+        peers.record(&"second", now);
+        peers.record(&"third", now);
+        assert_eq!(peers.len(), 2);
+        assert_eq!(peers.messages(&"first"), 0);
+        assert_eq!(peers.messages(&"second"), 2);
+
+        // A forgotten peer counts as new again
+        assert!(peers.record(&"first", now).is_first());
+        assert_eq!(peers.messages(&"second"), 0);
+    }
+
+    #[test]
+    fn test_removed_peer_forgotten() {
+        let now = Instant::now();
+        let mut peers = tracker(8);
+        peers.record(&"new", now);
+        peers.remove(&"new");
+        assert!(peers.is_empty());
+
+        let sighting = peers.record(&"new", now);
+        assert!(sighting.is_first());
+        assert!(sighting.refresh);
+    }
+}
@@ -0,0 +1,172 @@
+use super::harness::{Harness, MockValidator, aggregation_input, decode, encode};
+use super::mock::MockContributor;
+use crate::contributor::aggregated::Aggregated;
+use crate::contributor::decode::{MessageKind, classify};
+use crate::contributor::replay::{ReplayConfig, replay};
+use crate::contributor::transcript::Transcript;
+use crate::digest::{HashAlgorithm, PROTOCOL_VERSION, SigningDomain, compute_signing_digest};
+use anyhow::{Result, anyhow, ensure};
+use bn254::aggregate_verify;
+use std::path::{Path, PathBuf};
+
+/// Transcripts of previous releases, see [crate::contributor::transcript]
+const TRANSCRIPTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/transcripts");
+
+/// Checked-in transcripts with their paths
+fn transcripts() -> Vec<(PathBuf, Transcript)> {
+    let entries = std::fs::read_dir(TRANSCRIPTS)
+        .unwrap_or_else(|err| panic!("cannot list transcripts in {TRANSCRIPTS}: {err}"));
+    let mut transcripts: Vec<(PathBuf, Transcript)> = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .map(|path| {
+            let transcript = Transcript::read(&path).unwrap();
+            (path, transcript)
+        })
+        .collect();
+    transcripts.sort_by(|a, b| a.0.cmp(&b.0));
+    transcripts
+}
+
+/// Check the current contributor against a transcript of another release
+///
+/// Frames must encode to the same bytes, map to the recorded digests and carry
+/// signatures that verify, and replaying the received frames must complete the
+/// same rounds with the same frames sent.
+async fn check_compatible(transcript: &Transcript) -> Result<()> {
+    let frames = transcript.frames()?;
+    let sent = transcript.sent()?;
+    let hashes = transcript.hashes()?;
+
+    // Aggregates returned to the orchestrator are not aggregation frames
+    for frame in frames.iter().map(|(_, frame)| frame).chain(
+        sent.iter()
+            .map(|(_, frame)| frame)
+            .filter(|frame| !Aggregated::is_aggregated(frame)),
+    ) {
+        let message = decode(frame).ok_or_else(|| anyhow!("frame does not decode"))?;
+        ensure!(
+            encode(&message) == *frame,
+            "frame of round {} encodes differently",
+            message.round
+        );
+    }
+
+    for (sender, frame) in &frames {
+        let message = decode(frame).ok_or_else(|| anyhow!("frame does not decode"))?;
+        let digest = compute_signing_digest(
+            &message,
+            &MockValidator,
+            &SigningDomain::None,
+            HashAlgorithm::Keccak256,
+        )
+        .await?;
+        if let Some(hash) = hashes.get(&message.round) {
+            ensure!(digest == *hash, "digest of round {} changed", message.round);
+        }
+        if let MessageKind::Signature(signature) = classify(&message) {
+            ensure!(
+                aggregate_verify(std::slice::from_ref(sender), None, &digest, &signature),
+                "signature of round {} does not verify",
+                message.round
+            );
+        }
+    }
+
+    let setup = &transcript.setup;
+    let contributors = setup.contributors()?;
+    let config = ReplayConfig {
+        orchestrator: setup.orchestrator()?,
+        signer: MockContributor::create_test_bn254(setup.signer_seed),
+        aggregation: setup
+            .threshold
+            .map(|threshold| aggregation_input(&contributors, threshold)),
+        contributors,
+        quorum_id: 0,
+        metadata_policy: None,
+        hashes,
+        start_time: setup.start_time(),
+    };
+    let outcome = replay(frames, config).await?;
+    ensure!(
+        outcome.aggregated == transcript.aggregated,
+        "aggregated {} rounds, {} recorded",
+        outcome.aggregated,
+        transcript.aggregated
+    );
+    let mut recorded = sent;
+    recorded.sort();
+    let mut replayed = outcome.sent;
+    replayed.sort();
+    ensure!(
+        replayed == recorded,
+        "sent frames differ from the recorded ones"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod upgrade_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_previous_releases_interoperate() {
+        for (path, transcript) in transcripts() {
+            // Another protocol version is a declared incompatibility
+            if transcript.protocol_version != PROTOCOL_VERSION {
+                continue;
+            }
+            if let Err(err) = check_compatible(&transcript).await {
+                panic!(
+                    "release {} ({}) no longer interoperates: {err:#}; bump PROTOCOL_VERSION \
+                     if the encoding or digest changed on purpose",
+                    transcript.release,
+                    path.display()
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recorded_transcript_replays() {
+        let mut harness = Harness::new(3);
+        let transcript = harness.record_transcript(3, 3).await;
+        assert_eq!(transcript.protocol_version, PROTOCOL_VERSION);
+        assert_eq!(transcript.aggregated, 3);
+
+        let json = serde_json::to_string(&transcript).unwrap();
+        let parsed: Transcript = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, transcript);
+        check_compatible(&parsed).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_changed_digest_is_detected() {
+        let mut harness = Harness::new(3);
+        let mut transcript = harness.record_transcript(1, 3).await;
+        let hash = transcript.hashes.get_mut(&1).unwrap();
+        *hash = "00".repeat(32);
+        assert!(check_compatible(&transcript).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_changed_encoding_is_detected() {
+        let mut harness = Harness::new(3);
+        let mut transcript = harness.record_transcript(1, 3).await;
+        transcript.received[0].frame.push_str("00");
+        assert!(check_compatible(&transcript).await.is_err());
+    }
+
+    /// Record the transcript of this release, see `scripts/record-transcript.sh`
+    #[tokio::test]
+    #[ignore]
+    async fn record_transcript() {
+        let mut harness = Harness::new(3);
+        let transcript = harness.record_transcript(3, 3).await;
+        let path = match std::env::var("TRANSCRIPT_PATH") {
+            Ok(path) => PathBuf::from(path),
+            Err(_) => Path::new(TRANSCRIPTS).join(format!("{}.json", transcript.release)),
+        };
+        transcript.write(&path).unwrap();
+    }
+}
@@ -0,0 +1,240 @@
+use super::harness::{Harness, MockValidator, decode};
+use crate::handlers::RunConfig;
+use crate::validation::lazy::{LazyValidator, ValidatorRetryConfig, ValidatorStatus};
+use crate::validation::{PayloadValidator, ValidatorFactory};
+use anyhow::Result;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::time::Duration;
+
+/// Factory failing until it is made available
+#[derive(Clone, Default)]
+struct FlakyFactory {
+    /// Attempts failing before the factory becomes available on its own
+    failures: Option<u32>,
+    available: Arc<AtomicBool>,
+    attempts: Arc<AtomicU32>,
+}
+
+impl FlakyFactory {
+    fn failing(failures: u32) -> Self {
+        Self {
+            failures: Some(failures),
+            ..Self::default()
+        }
+    }
+
+    fn attempts(&self) -> u32 {
+        self.attempts.load(Ordering::Relaxed)
+    }
+
+    fn make_available(&self) {
+        self.available.store(true, Ordering::Relaxed);
+    }
+}
+
+impl ValidatorFactory for FlakyFactory {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        let attempt = self.attempts.fetch_add(1, Ordering::Relaxed) + 1;
+        let available = self.available.load(Ordering::Relaxed)
+            || self.failures.is_some_and(|failures| attempt > failures);
+        Box::pin(async move {
+            if !available {
+                anyhow::bail!("rpc unreachable");
+            }
+            let validator: Arc<dyn PayloadValidator> = Arc::new(MockValidator);
+            Ok(validator)
+        })
+    }
+}
+
+/// Validator rejecting the Start of one round and accepting every other
+#[derive(Clone)]
+struct RejectingValidator {
+    round: u64,
+}
+
+impl PayloadValidator for RejectingValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move {
+            let round = decode(&Bytes::copy_from_slice(message)).map(|message| message.round);
+            if round == Some(self.round) {
+                anyhow::bail!("task not found on chain");
+            }
+            MockValidator.validate(message).await
+        })
+    }
+}
+
+impl ValidatorFactory for RejectingValidator {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        let validator: Arc<dyn PayloadValidator> = Arc::new(self.clone());
+        Box::pin(async move { Ok(validator) })
+    }
+}
+
+/// Retries every 10ms, giving up after `give_up_after`
+fn retry_config(give_up_after: Duration) -> ValidatorRetryConfig {
+    ValidatorRetryConfig {
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(10),
+        give_up_after,
+        ..ValidatorRetryConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod validator_retry_tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let config = ValidatorRetryConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            ..ValidatorRetryConfig::default()
+        };
+        let backoffs: Vec<u64> = (1..=5)
+            .map(|failures| config.backoff(failures).as_millis() as u64)
+            .collect();
+        assert_eq!(backoffs, [100, 200, 400, 500, 500]);
+        assert_eq!(config.backoff(u32::MAX), Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_lazy_validator_rejects_until_set() {
+        let validator = LazyValidator::new();
+        assert!(!validator.is_ready());
+        let err = validator.validate(b"message").await.unwrap_err();
+        assert!(err.to_string().contains("initializing"));
+
+        validator.set(Arc::new(MockValidator));
+        assert!(validator.is_ready());
+    }
+
+    #[tokio::test]
+    async fn test_start_signed_once_validator_built() {
+        let mut harness = Harness::new(1);
+        let factory = FlakyFactory::failing(2);
+        let contributor = harness
+            .contributor(0, None)
+            .with_validator_factory(Arc::new(factory.clone()))
+            .with_run_config(RunConfig {
+                validator_retry: retry_config(Duration::from_secs(60)),
+                ..RunConfig::default()
+            });
+        let status = contributor.validator_status();
+        assert!(!status.borrow().is_ready());
+        let handle = harness.spawn(contributor, 0);
+
+        // Received while the validator is still failing to build
+        harness.start(1).await;
+        let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+        let rounds: Vec<u64> = signed.into_values().flatten().collect();
+        assert_eq!(rounds, [1]);
+        assert_eq!(factory.attempts(), 3);
+        assert_eq!(*status.borrow(), ValidatorStatus::Ready);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_run_continues_after_giving_up() {
+        let mut harness = Harness::new(1);
+        let factory = FlakyFactory::default();
+        let contributor = harness
+            .contributor(0, None)
+            .with_validator_factory(Arc::new(factory.clone()))
+            .with_run_config(RunConfig {
+                validator_retry: retry_config(Duration::from_millis(50)),
+                ..RunConfig::default()
+            });
+        let status = contributor.validator_status();
+        let handle = harness.spawn(contributor, 0);
+
+        harness.start(1).await;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(!handle.is_finished());
+        assert!(factory.attempts() > 1);
+        assert_eq!(*status.borrow(), ValidatorStatus::GaveUp);
+        assert!(
+            harness
+                .signed_rounds(Duration::from_millis(50))
+                .await
+                .is_empty()
+        );
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_invalid_start_skipped() {
+        let mut harness = Harness::new(1);
+        let contributor = harness
+            .contributor(0, None)
+            .with_validator_factory(Arc::new(RejectingValidator { round: 1 }));
+        let handle = harness.spawn(contributor, 0);
+
+        harness.start(1).await;
+        harness.start(2).await;
+        let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+        let rounds: Vec<u64> = signed.into_values().flatten().collect();
+        assert_eq!(rounds, [2]);
+        assert!(!handle.is_finished());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_oldest_buffered_start_dropped() {
+        let mut harness = Harness::new(1);
+        let factory = FlakyFactory::default();
+        let contributor = harness
+            .contributor(0, None)
+            .with_validator_factory(Arc::new(factory.clone()))
+            .with_run_config(RunConfig {
+                validator_retry: ValidatorRetryConfig {
+                    max_buffered_starts: 1,
+                    ..retry_config(Duration::from_secs(60))
+                },
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(contributor, 0);
+
+        harness.start(1).await;
+        harness.start(2).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        factory.make_available();
+
+        let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+        let rounds: Vec<u64> = signed.into_values().flatten().collect();
+        assert_eq!(rounds, [2]);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_expired_buffered_start_skipped() {
+        let mut harness = Harness::new(1);
+        let factory = FlakyFactory::default();
+        let contributor = harness
+            .contributor(0, None)
+            .with_validator_factory(Arc::new(factory.clone()))
+            .with_run_config(RunConfig {
+                validator_retry: retry_config(Duration::from_secs(u32::MAX as u64)),
+                ..RunConfig::default()
+            });
+        let handle = harness.spawn(contributor, 0);
+
+        harness.start(1).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        // Round 1 times out before the validator is built
+        harness.advance(Duration::from_secs(24 * 60 * 60));
+        harness.start(2).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        factory.make_available();
+
+        let signed = harness.signed_rounds(Duration::from_millis(200)).await;
+        let rounds: Vec<u64> = signed.into_values().flatten().collect();
+        assert_eq!(rounds, [2]);
+        handle.abort();
+    }
+}
@@ -0,0 +1,341 @@
+use super::harness::{Harness, NetworkReceiver, NetworkSender};
+use crate::chain::ChainSubmitter;
+use crate::contributor::{Contribute, OutboundRouter};
+use crate::digest::encode_message;
+use crate::handlers::{CAST_VOTE_FUNCTION, SubmissionMode, VotingContributor, VotingTaskData};
+use crate::validation::voting::{VoteError, VotingValidator};
+use alloy::rpc::types::TransactionRequest;
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy_json_abi::Function;
+use alloy_primitives::{Address, TxHash, TxKind, U256};
+use anyhow::Result;
+use bytes::Bytes;
+use commonware_avs_router::wire::{self, aggregation::Payload};
+use commonware_cryptography::Signer;
+use commonware_p2p::Recipients;
+use futures::future::BoxFuture;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+const CONTRACT: Address = Address::repeat_byte(0x42);
+
+/// Submitter recording transactions instead of sending them
+#[derive(Default)]
+struct MockSubmitter {
+    submitted: Mutex<Vec<TransactionRequest>>,
+}
+
+impl ChainSubmitter for MockSubmitter {
+    fn submit(&self, transaction: TransactionRequest) -> BoxFuture<'_, Result<TxHash>> {
+        self.submitted.lock().unwrap().push(transaction);
+        Box::pin(async move { Ok(TxHash::ZERO) })
+    }
+}
+
+fn vote(round: u64, option: u8) -> Bytes {
+    Bytes::from(encode_message(&wire::Aggregation {
+        round,
+        metadata: VotingTaskData {
+            proposal_id: 7,
+            option,
+        },
+        payload: Some(Payload::Start),
+    }))
+}
+
+/// Run voting contributors on the harness network, the first aggregating with `threshold`
+fn spawn_voters(
+    harness: &Harness,
+    threshold: usize,
+    submitter: &Arc<MockSubmitter>,
+) -> Vec<JoinHandle<Result<()>>> {
+    (0..harness.signers.len())
+        .map(|i| {
+            let aggregation = (i == 0).then(|| harness.aggregation_input(threshold));
+            let voter = VotingContributor::new(
+                harness.orchestrator.public_key(),
+                harness.signers[i].clone(),
+                harness.contributors(),
+                aggregation,
+            )
+            .unwrap()
+            .with_submitter(submitter.clone(), CONTRACT);
+            let (sender, receiver) = harness.network.register(harness.signers[i].public_key());
+            tokio::spawn(voter.run(OutboundRouter::single(sender), receiver))
+        })
+        .collect()
+}
+
+async fn start(harness: &mut Harness, frame: Bytes) {
+    commonware_p2p::Sender::send(
+        &mut harness.orchestrator_sender,
+        Recipients::All,
+        frame,
+        true,
+    )
+    .await
+    .unwrap();
+}
+
+// Signatures received by the orchestrator within `timeout`
+async fn signatures(receiver: &mut NetworkReceiver, timeout: Duration) -> usize {
+    let mut count = 0;
+    while tokio::time::timeout(timeout, commonware_p2p::Receiver::recv(receiver))
+        .await
+        .is_ok()
+    {
+        count += 1;
+    }
+    count
+}
+
+/// Spawn only the first signer of `harness`, aggregating with `threshold` in `mode`
+///
+/// The other signers are registered without running, their shares are sent by hand.
+fn spawn_aggregator(
+    harness: &Harness,
+    threshold: usize,
+    submitter: &Arc<MockSubmitter>,
+    mode: SubmissionMode,
+) -> (
+    JoinHandle<Result<()>>,
+    Vec<(NetworkSender, NetworkReceiver)>,
+) {
+    let aggregator = VotingContributor::new(
+        harness.orchestrator.public_key(),
+        harness.signers[0].clone(),
+        harness.contributors(),
+        Some(harness.aggregation_input(threshold)),
+    )
+    .unwrap()
+    .with_submitter(submitter.clone(), CONTRACT)
+    .with_submission_mode(mode);
+    let (sender, receiver) = harness.network.register(harness.signers[0].public_key());
+    let handle = tokio::spawn(aggregator.run(OutboundRouter::single(sender), receiver));
+    let peers = harness.signers[1..]
+        .iter()
+        .map(|signer| harness.network.register(signer.public_key()))
+        .collect();
+    (handle, peers)
+}
+
+/// Send the share of signer `index` for `vote(round, option)` to the aggregator
+async fn send_share(
+    harness: &Harness,
+    peers: &mut [(NetworkSender, NetworkReceiver)],
+    index: usize,
+    round: u64,
+    option: u8,
+) {
+    let payload = VotingValidator.check(&vote(round, option)).unwrap();
+    let signature = harness.signers[index].sign(None, &payload);
+    let frame = Bytes::from(encode_message(&wire::Aggregation {
+        round,
+        metadata: VotingTaskData {
+            proposal_id: 7,
+            option,
+        },
+        payload: Some(Payload::Signature(signature.to_vec())),
+    }));
+    commonware_p2p::Sender::send(
+        &mut peers[index - 1].0,
+        Recipients::One(harness.signers[0].public_key()),
+        frame,
+        true,
+    )
+    .await
+    .unwrap();
+}
+
+/// Signer bitmaps of the votes cast so far
+fn cast_bitmaps(submitter: &MockSubmitter) -> Vec<DynSolValue> {
+    let function = Function::parse(CAST_VOTE_FUNCTION).unwrap();
+    submitter
+        .submitted
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|transaction| {
+            let calldata = transaction.input.input().unwrap();
+            function.abi_decode_input(&calldata[4..], true).unwrap()[2].clone()
+        })
+        .collect()
+}
+
+/// Bitmap of the signers of `harness` at `indices`, by position in the sorted set
+fn bitmap(harness: &Harness, indices: &[usize]) -> DynSolValue {
+    let mut sorted = harness.contributors();
+    sorted.sort();
+    let mut bits = U256::ZERO;
+    for index in indices {
+        let key = harness.signers[*index].public_key();
+        let position = sorted.binary_search(&key).unwrap();
+        bits |= U256::from(1) << position;
+    }
+    DynSolValue::Uint(bits, 256)
+}
+
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+#[cfg(test)]
+mod voting_tests {
+    use super::*;
+
+    #[test]
+    fn test_validator_rejects_invalid_option() {
+        let validator = VotingValidator;
+        assert!(validator.check(&vote(1, 0)).is_ok());
+        assert!(validator.check(&vote(1, 1)).is_ok());
+        assert_eq!(
+            validator.check(&vote(1, 2)),
+            Err(VoteError::InvalidOption(2))
+        );
+        assert!(matches!(
+            validator.check(b"not a vote"),
+            Err(VoteError::ParseFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_vote_cast_after_threshold() {
+        let mut harness = Harness::new(3);
+        let submitter = Arc::new(MockSubmitter::default());
+        let handles = spawn_voters(&harness, 3, &submitter);
+
+        start(&mut harness, vote(1, 1)).await;
+        let signed = signatures(
+            &mut harness.orchestrator_receiver,
+            Duration::from_millis(200),
+        )
+        .await;
+        assert_eq!(signed, 3);
+
+        let submitted = submitter.submitted.lock().unwrap().clone();
+        assert_eq!(submitted.len(), 1);
+        let transaction = &submitted[0];
+        assert_eq!(transaction.to, Some(TxKind::Call(CONTRACT)));
+        let function = Function::parse(CAST_VOTE_FUNCTION).unwrap();
+        let calldata = transaction.input.input().unwrap();
+        assert_eq!(calldata[..4], function.selector()[..]);
+        let values = function.abi_decode_input(&calldata[4..], true).unwrap();
+        assert_eq!(values[0], DynSolValue::Uint(U256::from(1), 256));
+        assert_eq!(values[2], DynSolValue::Uint(U256::from(0b111), 256));
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_vote_below_threshold() {
+        let mut harness = Harness::new(3);
+        harness.network.partition(&harness.signers[2].public_key());
+        let submitter = Arc::new(MockSubmitter::default());
+        let handles = spawn_voters(&harness, 3, &submitter);
+
+        start(&mut harness, vote(1, 1)).await;
+        let signed = signatures(
+            &mut harness.orchestrator_receiver,
+            Duration::from_millis(200),
+        )
+        .await;
+        assert_eq!(signed, 2);
+        assert!(submitter.submitted.lock().unwrap().is_empty());
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_option_not_signed() {
+        let mut harness = Harness::new(2);
+        let submitter = Arc::new(MockSubmitter::default());
+        let handles = spawn_voters(&harness, 2, &submitter);
+
+        start(&mut harness, vote(1, 2)).await;
+        let signed = signatures(
+            &mut harness.orchestrator_receiver,
+            Duration::from_millis(200),
+        )
+        .await;
+        assert_eq!(signed, 0);
+        assert!(submitter.submitted.lock().unwrap().is_empty());
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fast_path_casts_at_threshold() {
+        let mut harness = Harness::new(4);
+        let submitter = Arc::new(MockSubmitter::default());
+        let (handle, mut peers) =
+            spawn_aggregator(&harness, 2, &submitter, SubmissionMode::FastPath);
+        start(&mut harness, vote(1, 1)).await;
+        settle().await;
+        assert!(cast_bitmaps(&submitter).is_empty());
+
+        // Our own signature and one share reach the threshold of two
+        send_share(&harness, &mut peers, 1, 1, 1).await;
+        settle().await;
+        assert_eq!(cast_bitmaps(&submitter), vec![bitmap(&harness, &[0, 1])]);
+
+        // Later shares are ignored
+        send_share(&harness, &mut peers, 2, 1, 1).await;
+        send_share(&harness, &mut peers, 3, 1, 1).await;
+        settle().await;
+        assert_eq!(cast_bitmaps(&submitter), vec![bitmap(&harness, &[0, 1])]);
+
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_grace_window_waits_for_more_signatures() {
+        let mut harness = Harness::new(4);
+        let submitter = Arc::new(MockSubmitter::default());
+        let window = Duration::from_millis(300);
+        let (handle, mut peers) =
+            spawn_aggregator(&harness, 2, &submitter, SubmissionMode::GraceWindow(window));
+        start(&mut harness, vote(1, 1)).await;
+        settle().await;
+
+        send_share(&harness, &mut peers, 1, 1, 1).await;
+        settle().await;
+        send_share(&harness, &mut peers, 2, 1, 1).await;
+        settle().await;
+        assert!(cast_bitmaps(&submitter).is_empty());
+
+        // Cast with every share received within the window
+        tokio::time::sleep(window).await;
+        assert_eq!(cast_bitmaps(&submitter), vec![bitmap(&harness, &[0, 1, 2])]);
+
+        send_share(&harness, &mut peers, 3, 1, 1).await;
+        settle().await;
+        assert_eq!(cast_bitmaps(&submitter).len(), 1);
+
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_grace_window_ends_once_all_signed() {
+        let mut harness = Harness::new(3);
+        let submitter = Arc::new(MockSubmitter::default());
+        let window = Duration::from_secs(60);
+        let (handle, mut peers) =
+            spawn_aggregator(&harness, 2, &submitter, SubmissionMode::GraceWindow(window));
+        start(&mut harness, vote(1, 1)).await;
+        settle().await;
+
+        send_share(&harness, &mut peers, 1, 1, 1).await;
+        send_share(&harness, &mut peers, 2, 1, 1).await;
+        settle().await;
+        assert_eq!(cast_bitmaps(&submitter), vec![bitmap(&harness, &[0, 1, 2])]);
+
+        handle.abort();
+    }
+}
@@ -0,0 +1,97 @@
+use super::harness::Harness;
+use crate::contributor::{AggregationInput, WeightError};
+use alloy_primitives::U256;
+use bn254::PublicKey;
+use std::collections::HashMap;
+
+/// Each of `operators` weighted by the matching entry of `values`
+fn weights(operators: &[PublicKey], values: &[u64]) -> HashMap<PublicKey, U256> {
+    operators
+        .iter()
+        .cloned()
+        .zip(values.iter().map(|value| U256::from(*value)))
+        .collect()
+}
+
+fn sorted(harness: &Harness) -> Vec<PublicKey> {
+    let mut operators = harness.contributors();
+    operators.sort();
+    operators
+}
+
+#[cfg(test)]
+mod weights_tests {
+    use super::*;
+
+    #[test]
+    fn test_unweighted_by_default() {
+        let harness = Harness::new(3);
+        let input = harness.aggregation_input(2);
+        assert!(input.weights().is_empty());
+        assert_eq!(input.threshold_weight(), None);
+    }
+
+    #[test]
+    fn test_reachable_weights_accepted() {
+        let harness = Harness::new(3);
+        let operators = sorted(&harness);
+        let input = harness
+            .aggregation_input(2)
+            .with_weights(weights(&operators, &[10, 20, 30]), U256::from(60))
+            .unwrap();
+        assert_eq!(input.threshold_weight(), Some(U256::from(60)));
+        assert_eq!(input.weights()[&operators[1]], U256::from(20));
+    }
+
+    #[test]
+    fn test_unreachable_weight_threshold() {
+        let harness = Harness::new(3);
+        let operators = sorted(&harness);
+        let err = harness
+            .aggregation_input(2)
+            .with_weights(weights(&operators, &[10, 20, 30]), U256::from(61))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            WeightError::UnreachableThreshold {
+                threshold_weight: U256::from(61),
+                total_weight: U256::from(60),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "threshold weight 61 exceeds total weight 60"
+        );
+    }
+
+    #[test]
+    fn test_zero_weight_operator() {
+        let harness = Harness::new(3);
+        let operators = sorted(&harness);
+        let err = harness
+            .aggregation_input(2)
+            .with_weights(weights(&operators, &[10, 0, 30]), U256::from(20))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            WeightError::ZeroWeight {
+                operator: operators[1].clone(),
+            }
+        );
+
+        // An operator missing from the weights has none either
+        let err = harness
+            .aggregation_input(2)
+            .with_weights(weights(&operators, &[10, 20]), U256::from(20))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            WeightError::ZeroWeight {
+                operator: operators[2].clone(),
+            }
+        );
+    }
+}
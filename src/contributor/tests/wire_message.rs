@@ -0,0 +1,155 @@
+use super::harness::{Harness, digest_of, signature_message, start_message};
+use crate::contributor::decode::MalformedSignature;
+use crate::types::ContributorIndex;
+use crate::wire::{ContributorMessage, Roles};
+use bn254::PublicKey;
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::wire;
+use commonware_cryptography::Signer;
+use std::collections::{HashMap, HashSet};
+
+/// Sets of an aggregating node: signer 0 orchestrates and signers 1 and 2 contribute
+fn sets(harness: &Harness) -> (HashSet<PublicKey>, HashMap<PublicKey, ContributorIndex>) {
+    let orchestrators = HashSet::from([harness.signers[0].public_key()]);
+    let contributors = (1..3)
+        .map(|i| {
+            (
+                harness.signers[i].public_key(),
+                ContributorIndex::from(i - 1),
+            )
+        })
+        .collect();
+    (orchestrators, contributors)
+}
+
+/// Share of signer `index` for round 3
+fn share(harness: &Harness, index: usize) -> wire::Aggregation<CounterTaskData> {
+    let signature = harness.signers[index].sign(None, &digest_of(&start_message(3)));
+    signature_message(3, signature.to_vec())
+}
+
+#[cfg(test)]
+mod wire_message_tests {
+    use super::*;
+
+    #[test]
+    fn test_start_from_orchestrator() {
+        let harness = Harness::new(3);
+        let (orchestrators, contributors) = sets(&harness);
+        let roles = Roles {
+            orchestrators: &orchestrators,
+            contributors: Some(&contributors),
+        };
+        let sender = harness.signers[0].public_key();
+        let parsed = ContributorMessage::try_from((sender.clone(), start_message(3), roles));
+        let Ok(ContributorMessage::FromOrchestrator(start)) = parsed else {
+            panic!("not a start: {parsed:?}");
+        };
+        assert_eq!(start.sender, sender);
+        assert_eq!(start.message.round, 3);
+    }
+
+    #[test]
+    fn test_start_from_peer() {
+        let harness = Harness::new(3);
+        let (orchestrators, contributors) = sets(&harness);
+        let roles = Roles {
+            orchestrators: &orchestrators,
+            contributors: Some(&contributors),
+        };
+        let sender = harness.signers[1].public_key();
+        let parsed = ContributorMessage::try_from((sender.clone(), start_message(3), roles));
+        let Ok(ContributorMessage::StartFromPeer(peer)) = parsed else {
+            panic!("not a start from a peer: {parsed:?}");
+        };
+        assert_eq!(peer, sender);
+    }
+
+    #[test]
+    fn test_share_from_contributor() {
+        let harness = Harness::new(3);
+        let (orchestrators, contributors) = sets(&harness);
+        let roles = Roles {
+            orchestrators: &orchestrators,
+            contributors: Some(&contributors),
+        };
+        let sender = harness.signers[1].public_key();
+        let signature = harness.signers[1].sign(None, &digest_of(&start_message(3)));
+        let parsed = ContributorMessage::try_from((sender.clone(), share(&harness, 1), roles));
+        let Ok(ContributorMessage::FromContributor(share)) = parsed else {
+            panic!("not a share: {parsed:?}");
+        };
+        assert_eq!(share.sender, sender);
+        assert_eq!(share.message.round, 3);
+        assert_eq!(share.signature, signature);
+    }
+
+    #[test]
+    fn test_share_not_collected() {
+        let harness = Harness::new(3);
+        let (mut orchestrators, contributors) = sets(&harness);
+
+        // Not aggregating
+        let roles = Roles {
+            orchestrators: &orchestrators,
+            contributors: None,
+        };
+        let sender = harness.signers[1].public_key();
+        let parsed = ContributorMessage::try_from((sender, share(&harness, 1), roles));
+        assert!(matches!(parsed, Ok(ContributorMessage::Uncollected)));
+
+        // Orchestrator outside the contributor set
+        let roles = Roles {
+            orchestrators: &orchestrators,
+            contributors: Some(&contributors),
+        };
+        let sender = harness.signers[0].public_key();
+        let parsed = ContributorMessage::try_from((sender, share(&harness, 0), roles));
+        assert!(matches!(parsed, Ok(ContributorMessage::Uncollected)));
+
+        // Orchestrator also contributing
+        orchestrators.insert(harness.signers[1].public_key());
+        let roles = Roles {
+            orchestrators: &orchestrators,
+            contributors: Some(&contributors),
+        };
+        let sender = harness.signers[1].public_key();
+        let parsed = ContributorMessage::try_from((sender, share(&harness, 1), roles));
+        assert!(matches!(parsed, Ok(ContributorMessage::FromContributor(_))));
+    }
+
+    #[test]
+    fn test_malformed_signature_rejected() {
+        let harness = Harness::new(3);
+        let (orchestrators, contributors) = sets(&harness);
+        let roles = Roles {
+            orchestrators: &orchestrators,
+            contributors: Some(&contributors),
+        };
+        let sender = harness.signers[1].public_key();
+        let parsed =
+            ContributorMessage::try_from((sender, signature_message(3, vec![1; 3]), roles));
+        assert!(matches!(
+            parsed,
+            Err(MalformedSignature::Length { actual: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_missing_payload_is_unknown() {
+        let harness = Harness::new(3);
+        let (orchestrators, contributors) = sets(&harness);
+        let roles = Roles {
+            orchestrators: &orchestrators,
+            contributors: Some(&contributors),
+        };
+        let message = wire::Aggregation {
+            round: 3,
+            metadata: Default::default(),
+            payload: None,
+        };
+        let parsed =
+            ContributorMessage::try_from((harness.signers[0].public_key(), message, roles));
+        assert!(matches!(parsed, Ok(ContributorMessage::Unknown)));
+    }
+}
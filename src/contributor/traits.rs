@@ -1,33 +1,77 @@
+//! Traits every contributor implements.
+
+use std::fmt;
 use std::hash::Hash;
 
 use anyhow::Result;
-use commonware_cryptography::{PublicKey, Signer};
+use commonware_cryptography::PublicKey;
 use commonware_p2p::{Receiver, Sender};
 
+use super::router::OutboundRouter;
+use super::signing::AsyncSigner;
+use crate::types::ContributorIndex;
+
+/// Reasons a contributor cannot be created
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContributeError {
+    /// The signer's key is not one of the contributors, so it has no index
+    SignerNotInContributors,
+}
+
+impl fmt::Display for ContributeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContributeError::SignerNotInContributors => {
+                write!(f, "signer is not one of the contributors")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContributeError {}
+
 /// Base trait for common contributor functionality
 pub trait ContributorBase {
+    /// Key identifying a contributor
     type PublicKey: PublicKey + Ord + Eq + Hash + Clone;
-    type Signer: Signer<PublicKey = Self::PublicKey>;
+    /// Signer producing our shares
+    type Signer: AsyncSigner<PublicKey = Self::PublicKey, Signature = Self::Signature>;
+    /// Signature shares and aggregates are made of
     type Signature: Clone;
 
     // Common functionality
+    /// Whether `sender` is the orchestrator
     fn is_orchestrator(&self, sender: &Self::PublicKey) -> bool;
-    fn get_contributor_index(&self, public_key: &Self::PublicKey) -> Option<&usize>;
+    /// Index of `public_key` in the sorted contributors, if it is one
+    fn get_contributor_index(&self, public_key: &Self::PublicKey) -> Option<&ContributorIndex>;
+
+    /// Contributors expected to sign the given round, the source for computing non-signers
+    fn contributors_for_round(&self, round: u64) -> Vec<Self::PublicKey>;
 }
 
 /// Main contributor trait that extends the base
 pub trait Contribute: ContributorBase {
+    /// Data needed to aggregate shares
     type AggregationInput;
 
+    /// Contributor signing with `signer`, aggregating if `aggregation_data` is given
     fn new(
         orchestrator: Self::PublicKey,
         signer: Self::Signer,
         contributors: Vec<Self::PublicKey>,
         aggregation_data: Option<Self::AggregationInput>,
-    ) -> Self;
+    ) -> Result<Self, ContributeError>
+    where
+        Self: Sized;
 
-    async fn run<S, R>(self, sender: S, receiver: R) -> Result<()>
+    /// Run until the receiver closes
+    ///
+    /// A receiver that stalled is returned as a
+    /// [WatchdogTimeout](crate::p2p::WatchdogTimeout) error, to be retried on a new
+    /// connection.
+    async fn run<S, R>(self, router: OutboundRouter<S>, receiver: R) -> Result<()>
     where
-        S: Sender,
-        R: Receiver<PublicKey = Self::PublicKey>;
+        S: Sender<PublicKey = Self::PublicKey>,
+        R: Receiver<PublicKey = Self::PublicKey>,
+        R::Error: std::error::Error + 'static;
 }
@@ -0,0 +1,247 @@
+//! Serializable record of a [Capture], replayed by later releases.
+//!
+//! Committee members upgrade one at a time, so a release must interoperate with
+//! the one before it: decode its frames, derive the same digests and produce
+//! signatures it verifies. A [Transcript] holds the setup of a contributor of one
+//! release and everything it received, sent and validated. One transcript per
+//! release is checked in under `tests/fixtures/transcripts` and replayed by the
+//! test suite. Transcripts of another [PROTOCOL_VERSION] are not expected to replay.
+
+use crate::contributor::replay::{Capture, Frame, SentTo};
+use crate::digest::PROTOCOL_VERSION;
+use alloy_primitives::hex;
+use anyhow::{Context, Result, anyhow};
+use bn254::PublicKey as PubKey;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Recipients of a sent frame, with keys in hex
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptRecipients {
+    /// Every peer
+    All,
+    /// The listed peers
+    Some(Vec<String>),
+    /// A single peer
+    One(String),
+}
+
+/// Frame received by the recording contributor
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceivedFrame {
+    /// Key of the sender
+    pub sender: String,
+    /// Frame in hex
+    pub frame: String,
+}
+
+/// Frame sent by the recording contributor
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SentFrame {
+    /// Recipients of the frame
+    pub to: TranscriptRecipients,
+    /// Frame in hex
+    pub frame: String,
+}
+
+/// Contributor a transcript was recorded with
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranscriptSetup {
+    /// Seed of the deterministic test key the recording contributor signed with
+    pub signer_seed: u64,
+    /// Key of the orchestrator
+    pub orchestrator: String,
+    /// Keys of the contributors
+    pub contributors: Vec<String>,
+    /// Aggregation threshold, if the recording contributor aggregated
+    pub threshold: Option<usize>,
+    /// Unix time in seconds when the capture started
+    pub start_time: u64,
+}
+
+impl TranscriptSetup {
+    /// Setup of a contributor recorded from `start_time`
+    pub fn new(
+        signer_seed: u64,
+        orchestrator: &PubKey,
+        contributors: &[PubKey],
+        threshold: Option<usize>,
+        start_time: SystemTime,
+    ) -> Self {
+        Self {
+            signer_seed,
+            orchestrator: encode_key(orchestrator),
+            contributors: contributors.iter().map(encode_key).collect(),
+            threshold,
+            start_time: start_time
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        }
+    }
+
+    /// Decoded key of the orchestrator
+    pub fn orchestrator(&self) -> Result<PubKey> {
+        decode_key(&self.orchestrator)
+    }
+
+    /// Decoded keys of the contributors
+    pub fn contributors(&self) -> Result<Vec<PubKey>> {
+        self.contributors
+            .iter()
+            .map(|key| decode_key(key))
+            .collect()
+    }
+
+    /// Time the capture started
+    pub fn start_time(&self) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(self.start_time)
+    }
+}
+
+/// Traffic of a contributor of one release, see the [module docs](self)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Transcript {
+    /// [PROTOCOL_VERSION] of the recording release
+    pub protocol_version: u32,
+    /// Crate version of the recording release
+    pub release: String,
+    /// Contributor the transcript was recorded with
+    #[serde(flatten)]
+    pub setup: TranscriptSetup,
+    /// Rounds that reached the aggregation threshold
+    pub aggregated: u64,
+    /// Hash validated for each round
+    pub hashes: BTreeMap<u64, String>,
+    /// Frames received, in order
+    pub received: Vec<ReceivedFrame>,
+    /// Frames sent, in order
+    pub sent: Vec<SentFrame>,
+}
+
+impl Transcript {
+    /// Transcript of what `capture` recorded for a contributor with `setup`
+    #[allow(
+        clippy::disallowed_methods,
+        reason = "hex encoded for storage, not logged"
+    )]
+    pub fn record(setup: TranscriptSetup, capture: &Capture, aggregated: u64) -> Self {
+        Self {
+            protocol_version: PROTOCOL_VERSION,
+            release: env!("CARGO_PKG_VERSION").to_string(),
+            setup,
+            aggregated,
+            hashes: capture
+                .hashes()
+                .into_iter()
+                .map(|(round, hash)| (round, hex::encode(hash)))
+                .collect(),
+            received: capture
+                .frames()
+                .into_iter()
+                .map(|(sender, frame)| ReceivedFrame {
+                    sender: encode_key(&sender),
+                    frame: hex::encode(&frame),
+                })
+                .collect(),
+            sent: capture
+                .sent()
+                .into_iter()
+                .map(|(to, frame)| SentFrame {
+                    to: encode_recipients(&to),
+                    frame: hex::encode(&frame),
+                })
+                .collect(),
+        }
+    }
+
+    /// Read the transcript at `path`
+    pub fn read(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Write the transcript to `path`
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let mut contents = serde_json::to_string_pretty(self)?;
+        contents.push('\n');
+        std::fs::write(path, contents)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Frames received, in order
+    pub fn frames(&self) -> Result<Vec<Frame>> {
+        self.received
+            .iter()
+            .map(|received| {
+                Ok((
+                    decode_key(&received.sender)?,
+                    decode_frame(&received.frame)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Frames sent, in order
+    pub fn sent(&self) -> Result<Vec<(SentTo, Bytes)>> {
+        self.sent
+            .iter()
+            .map(|sent| Ok((decode_recipients(&sent.to)?, decode_frame(&sent.frame)?)))
+            .collect()
+    }
+
+    /// Hash validated for each round
+    pub fn hashes(&self) -> Result<BTreeMap<u64, [u8; 32]>> {
+        self.hashes
+            .iter()
+            .map(|(round, hash)| {
+                let hash = hex::decode(hash)?
+                    .try_into()
+                    .map_err(|_| anyhow!("hash of round {round} is not 32 bytes"))?;
+                Ok((*round, hash))
+            })
+            .collect()
+    }
+}
+
+#[allow(
+    clippy::disallowed_methods,
+    reason = "hex encoded for storage, not logged"
+)]
+fn encode_key(key: &PubKey) -> String {
+    hex::encode(&key[..])
+}
+
+fn decode_key(key: &str) -> Result<PubKey> {
+    PubKey::try_from(hex::decode(key)?).map_err(|_| anyhow!("invalid public key {key}"))
+}
+
+fn decode_frame(frame: &str) -> Result<Bytes> {
+    Ok(Bytes::from(hex::decode(frame)?))
+}
+
+fn encode_recipients(to: &SentTo) -> TranscriptRecipients {
+    match to {
+        SentTo::All => TranscriptRecipients::All,
+        SentTo::Some(keys) => TranscriptRecipients::Some(keys.iter().map(encode_key).collect()),
+        SentTo::One(key) => TranscriptRecipients::One(encode_key(key)),
+    }
+}
+
+fn decode_recipients(to: &TranscriptRecipients) -> Result<SentTo> {
+    Ok(match to {
+        TranscriptRecipients::All => SentTo::All,
+        TranscriptRecipients::Some(keys) => SentTo::Some(
+            keys.iter()
+                .map(|key| decode_key(key))
+                .collect::<Result<_>>()?,
+        ),
+        TranscriptRecipients::One(key) => SentTo::One(decode_key(key)?),
+    })
+}
@@ -1,30 +1,533 @@
-use bn254::{G1PublicKey, PublicKey as PubKey};
+//! Types shared by the contributors: aggregation input, participation bitmaps and
+//! quorum certificates.
+
+use crate::contributor::committee::canonical_g1_map;
+use crate::contributor::decode::signature_from_slice;
+use crate::contributor::final_aggregate::MAX_SIGNATURE_LEN;
+use crate::types::ContributorIndex;
+use alloy_primitives::{U256, hex};
+use bn254::{G1PublicKey, PublicKey as PubKey, Signature as Sig, aggregate_verify};
+use bytes::{Buf, BufMut};
+use commonware_codec::{EncodeSize, Error, Read, ReadExt, Write};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde_with::{DeserializeAs, SerializeAs, serde_as};
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Selects the contributors expected to sign a round from the sorted active set
+pub type Assignment = Arc<dyn Fn(u64, &[PubKey]) -> Vec<PubKey> + Send + Sync>;
+
+/// Contributors expected to sign a round, the whole active set without an assignment
+pub fn assigned_contributors(
+    assignment: Option<&Assignment>,
+    round: u64,
+    contributors: &[PubKey],
+) -> Vec<PubKey> {
+    match assignment {
+        Some(assignment) => assignment(round, contributors),
+        None => contributors.to_vec(),
+    }
+}
 
 /// Input data for aggregation functionality
 pub struct AggregationInput {
     threshold: usize,
     g1_map: HashMap<PubKey, G1PublicKey>,
+    weights: HashMap<PubKey, U256>,
+    threshold_weight: Option<U256>,
 }
 
 impl AggregationInput {
+    /// Input aggregating once `threshold` shares verified against `g1_map`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use commonware_avs_node::contributor::AggregationInput;
+    /// use std::collections::HashMap;
+    ///
+    /// let input = AggregationInput::new(2, HashMap::new());
+    /// assert_eq!(input.threshold(), 2);
+    /// assert!(input.g1_map().is_empty());
+    /// ```
     pub fn new(threshold: usize, g1_map: HashMap<PubKey, G1PublicKey>) -> Self {
-        Self { threshold, g1_map }
+        Self {
+            threshold,
+            g1_map,
+            weights: HashMap::new(),
+            threshold_weight: None,
+        }
+    }
+
+    /// Weigh each operator by `weights`, aggregating once the signers' weight reaches
+    /// `threshold_weight`
+    ///
+    /// Every operator of the G1 map must have a non-zero weight, and the threshold
+    /// must be reachable with all of the weights provided.
+    pub fn with_weights(
+        mut self,
+        weights: HashMap<PubKey, U256>,
+        threshold_weight: U256,
+    ) -> Result<Self, WeightError> {
+        let mut operators: Vec<&PubKey> = self.g1_map.keys().collect();
+        operators.sort();
+        if let Some(operator) = operators
+            .into_iter()
+            .find(|operator| weights.get(*operator).is_none_or(|weight| weight.is_zero()))
+        {
+            return Err(WeightError::ZeroWeight {
+                operator: operator.clone(),
+            });
+        }
+        let total_weight = weights
+            .values()
+            .fold(U256::ZERO, |total, weight| total.saturating_add(*weight));
+        if threshold_weight > total_weight {
+            return Err(WeightError::UnreachableThreshold {
+                threshold_weight,
+                total_weight,
+            });
+        }
+        self.weights = weights;
+        self.threshold_weight = Some(threshold_weight);
+        Ok(self)
     }
 
+    /// Shares needed to aggregate
     pub fn threshold(&self) -> usize {
         self.threshold
     }
 
+    /// G1 key of each contributor
     pub fn g1_map(&self) -> &HashMap<PubKey, G1PublicKey> {
         &self.g1_map
     }
+
+    /// Weight of each operator, empty unless weighted
+    pub fn weights(&self) -> &HashMap<PubKey, U256> {
+        &self.weights
+    }
+
+    /// Weight the signers must reach, if weighted
+    pub fn threshold_weight(&self) -> Option<U256> {
+        self.threshold_weight
+    }
+}
+
+/// Weights an [AggregationInput] rejects
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WeightError {
+    /// The threshold weight exceeds the weight of every operator together
+    UnreachableThreshold {
+        /// Weight the signers must reach
+        threshold_weight: U256,
+        /// Sum of the weights provided
+        total_weight: U256,
+    },
+    /// An operator of the G1 map has no weight, or a zero one
+    ZeroWeight {
+        /// Operator without weight
+        operator: PubKey,
+    },
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::UnreachableThreshold {
+                threshold_weight,
+                total_weight,
+            } => write!(
+                f,
+                "threshold weight {threshold_weight} exceeds total weight {total_weight}"
+            ),
+            WeightError::ZeroWeight { operator } => {
+                write!(f, "operator {operator:?} has zero weight")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WeightError {}
+
+/// Reason a peer signature is dropped before it is verified
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DroppedShare {
+    /// The round was not started by this node
+    UnknownRound,
+    /// The round was retired after its response was submitted on-chain
+    CompletedOnChain,
+    /// The round was already aggregated
+    Aggregated,
+    /// The sender did not join the contributor set within the grace period
+    UnknownSender,
+    /// The response window of the round's task closed
+    DeadlinePassed,
+    /// The share was tagged for a quorum its channel does not carry, or its digest tag
+    /// does not match whether the round is executed
+    MisTagged,
+    /// The chain is past the block window of the round's task
+    OutsideWindow,
+}
+
+impl DroppedShare {
+    /// Short classification used to label metrics
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DroppedShare::UnknownRound => "unknown_round",
+            DroppedShare::CompletedOnChain => "completed_on_chain",
+            DroppedShare::Aggregated => "aggregated",
+            DroppedShare::UnknownSender => "unknown_sender",
+            DroppedShare::DeadlinePassed => "deadline_passed",
+            DroppedShare::MisTagged => "mis_tagged",
+            DroppedShare::OutsideWindow => "outside_window",
+        }
+    }
 }
 
 /// Internal aggregation data structure
 pub struct AggregationData {
+    /// Shares needed to aggregate
     pub threshold: usize,
+    /// G1 key of each contributor
     pub g1_map: HashMap<PubKey, G1PublicKey>,
+    /// Contributors, sorted
     pub contributors: Vec<PubKey>,
-    pub ordered_contributors: HashMap<PubKey, usize>,
+    /// Index of each contributor in `contributors`
+    pub ordered_contributors: HashMap<PubKey, ContributorIndex>,
+}
+
+impl From<(AggregationInput, Vec<PubKey>)> for AggregationData {
+    /// Aggregation over the sorted, canonical `contributors`, indexing each by position
+    fn from((input, contributors): (AggregationInput, Vec<PubKey>)) -> Self {
+        let ordered_contributors = contributors
+            .iter()
+            .enumerate()
+            .map(|(idx, contributor)| (contributor.clone(), idx.into()))
+            .collect();
+        Self {
+            threshold: input.threshold,
+            g1_map: canonical_g1_map(&input.g1_map),
+            contributors,
+            ordered_contributors,
+        }
+    }
+}
+
+/// Maximum number of contributors a [ParticipationBitmap] can track, the width of a `uint256`
+pub const MAX_BITMAP_CONTRIBUTORS: usize = 256;
+
+/// Bitmap of contributor indices matching the on-chain `uint256` bitmap ordering
+///
+/// Bit `i` (counting from the least significant bit) is set when the `i`-th key of the
+/// sorted contributors vector participated, the same convention `BitmapUtils` uses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParticipationBitmap(U256);
+
+impl ParticipationBitmap {
+    /// Bitmap with no participant
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bitmap of `participants` indexed by their position in the sorted `contributors`
+    ///
+    /// Returns `None` if a participant is not a contributor or the set is too large.
+    pub fn from_participants(contributors: &[PubKey], participants: &[PubKey]) -> Option<Self> {
+        debug_assert!(contributors.is_sorted(), "contributors must be sorted");
+        let mut bitmap = Self::new();
+        for participant in participants {
+            let index = contributors.binary_search(participant).ok()?;
+            bitmap.set(index).then_some(())?;
+        }
+        Some(bitmap)
+    }
+
+    /// Bitmap of the contributors at `indices`
+    ///
+    /// Returns `None` if an index is past [MAX_BITMAP_CONTRIBUTORS].
+    pub fn from_indices(indices: impl IntoIterator<Item = usize>) -> Option<Self> {
+        let mut bitmap = Self::new();
+        for index in indices {
+            bitmap.set(index).then_some(())?;
+        }
+        Some(bitmap)
+    }
+
+    /// Mark the contributor at `index` as participating
+    ///
+    /// Returns `false`, leaving the bitmap unchanged, if `index` is past
+    /// [MAX_BITMAP_CONTRIBUTORS].
+    pub fn set(&mut self, index: usize) -> bool {
+        if index >= MAX_BITMAP_CONTRIBUTORS {
+            return false;
+        }
+        self.0 |= U256::from(1) << index;
+        true
+    }
+
+    /// Whether the contributor at `index` participated
+    pub fn get(&self, index: usize) -> bool {
+        index < MAX_BITMAP_CONTRIBUTORS && self.0.bit(index)
+    }
+
+    /// Add every participant of `other`
+    pub fn merge(&mut self, other: &Self) {
+        self.0 |= other.0;
+    }
+
+    /// Number of participants
+    pub fn count(&self) -> usize {
+        self.0.count_ones()
+    }
+
+    /// Whether no participant is set
+    pub fn is_empty(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Participating indices in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..MAX_BITMAP_CONTRIBUTORS).filter(|index| self.0.bit(*index))
+    }
+
+    /// On-chain `uint256` value
+    pub fn to_u256(&self) -> U256 {
+        self.0
+    }
+
+    /// Bitmap of an on-chain `uint256`
+    pub fn from_u256(value: U256) -> Self {
+        Self(value)
+    }
+
+    /// ABI encoding of the `uint256` (32 bytes, big-endian)
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+
+    /// Bitmap of a big-endian `uint256`
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(U256::from_be_bytes(bytes))
+    }
+
+    /// Packed wire encoding, trailing zero bytes trimmed
+    ///
+    /// Bit `i` is bit `7 - i % 8` of byte `i / 8`, so the encoding only grows with
+    /// the highest participating index.
+    pub fn to_packed(&self) -> Vec<u8> {
+        let mut packed = vec![0u8; MAX_BITMAP_CONTRIBUTORS / 8];
+        for index in self.iter() {
+            if let Some(byte) = packed.get_mut(index / 8) {
+                *byte |= 0x80 >> (index % 8);
+            }
+        }
+        let len = packed
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |last| last + 1);
+        packed.truncate(len);
+        packed
+    }
+
+    /// Bitmap of a [ParticipationBitmap::to_packed] encoding
+    ///
+    /// Returns `None` if it is longer than the widest bitmap or has a trailing zero byte.
+    pub fn from_packed(packed: &[u8]) -> Option<Self> {
+        if packed.len() > MAX_BITMAP_CONTRIBUTORS / 8 || packed.last() == Some(&0) {
+            return None;
+        }
+        let mut bitmap = Self::new();
+        for (byte_index, byte) in packed.iter().enumerate() {
+            for bit in 0..8 {
+                if byte & (0x80 >> bit) != 0 {
+                    bitmap.set(byte_index * 8 + bit);
+                }
+            }
+        }
+        Some(bitmap)
+    }
+}
+
+/// Indices past [MAX_BITMAP_CONTRIBUTORS] are skipped
+impl FromIterator<usize> for ParticipationBitmap {
+    fn from_iter<I: IntoIterator<Item = usize>>(indices: I) -> Self {
+        let mut bitmap = Self::new();
+        for index in indices {
+            bitmap.set(index);
+        }
+        bitmap
+    }
+}
+
+/// Aggregate signature over a round payload with the contributors that produced it
+///
+/// On the wire the signers take a byte per eight contributors, see
+/// [ParticipationBitmap::to_packed]. In JSON the payload and signature are `0x`-prefixed
+/// hex strings and the signers their ascending indices.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuorumCertificate {
+    /// Round the certificate is for
+    pub round: u64,
+    /// Payload hash the contributors signed
+    #[serde_as(as = "PrefixedHex")]
+    pub payload: [u8; 32],
+    /// Aggregate signature over `payload`
+    #[serde_as(as = "HexSig")]
+    pub signature: Sig,
+    /// Signers, indexed into the sorted contributors vector
+    #[serde_as(as = "SignerIndices")]
+    pub signers: ParticipationBitmap,
+}
+
+/// `0x`-prefixed lowercase hex of a byte string
+struct PrefixedHex;
+
+impl<T: AsRef<[u8]>> SerializeAs<T> for PrefixedHex {
+    fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode_prefixed(source))
+    }
+}
+
+impl<'de, T: TryFrom<Vec<u8>>> DeserializeAs<'de, T> for PrefixedHex {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        let bytes = deserialize_prefixed_hex(deserializer)?;
+        let len = bytes.len();
+        T::try_from(bytes)
+            .map_err(|_| de::Error::invalid_length(len, &"a byte string of the expected length"))
+    }
+}
+
+/// Signature as the `0x`-prefixed hex of its wire encoding
+struct HexSig;
+
+impl SerializeAs<Sig> for HexSig {
+    fn serialize_as<S: Serializer>(source: &Sig, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode_prefixed(source.to_vec()))
+    }
+}
+
+impl<'de> DeserializeAs<'de, Sig> for HexSig {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Sig, D::Error> {
+        let bytes = deserialize_prefixed_hex(deserializer)?;
+        signature_from_slice(&bytes).map_err(de::Error::custom)
+    }
+}
+
+/// Participation as the ascending indices of the signers
+struct SignerIndices;
+
+impl SerializeAs<ParticipationBitmap> for SignerIndices {
+    fn serialize_as<S: Serializer>(
+        source: &ParticipationBitmap,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(source.iter())
+    }
+}
+
+impl<'de> DeserializeAs<'de, ParticipationBitmap> for SignerIndices {
+    fn deserialize_as<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ParticipationBitmap, D::Error> {
+        let indices = Vec::<usize>::deserialize(deserializer)?;
+        if let Some(index) = indices
+            .iter()
+            .find(|index| **index >= MAX_BITMAP_CONTRIBUTORS)
+        {
+            return Err(de::Error::custom(format!(
+                "signer index {index} out of range"
+            )));
+        }
+        Ok(indices.into_iter().collect())
+    }
+}
+
+/// Bytes of a hex string, which must carry the `0x` prefix
+fn deserialize_prefixed_hex<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<u8>, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+    let digits = encoded
+        .strip_prefix("0x")
+        .ok_or_else(|| de::Error::custom("hex string without 0x prefix"))?;
+    hex::decode(digits).map_err(de::Error::custom)
+}
+
+impl QuorumCertificate {
+    /// Signer keys, `None` if the bitmap references an unknown contributor
+    pub fn signer_keys(&self, contributors: &[PubKey]) -> Option<Vec<PubKey>> {
+        self.signers
+            .iter()
+            .map(|index| contributors.get(index).cloned())
+            .collect()
+    }
+
+    /// Verify the aggregate signature against the sorted `contributors`
+    pub fn verify(&self, contributors: &[PubKey]) -> bool {
+        match self.signer_keys(contributors) {
+            Some(signers) if !signers.is_empty() => {
+                aggregate_verify(&signers, None, &self.payload, &self.signature)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Write for QuorumCertificate {
+    fn write(&self, buf: &mut impl BufMut) {
+        self.round.write(buf);
+        buf.put_slice(&self.payload);
+        let signature = self.signature.to_vec();
+        (signature.len() as u16).write(buf);
+        buf.put_slice(&signature);
+        let signers = self.signers.to_packed();
+        (signers.len() as u16).write(buf);
+        buf.put_slice(&signers);
+    }
+}
+
+impl EncodeSize for QuorumCertificate {
+    fn encode_size(&self) -> usize {
+        8 + 32 + 2 + self.signature.to_vec().len() + 2 + self.signers.to_packed().len()
+    }
+}
+
+impl Read for QuorumCertificate {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _: &()) -> Result<Self, Error> {
+        let round = u64::read(buf)?;
+        let payload = <[u8; 32]>::read(buf)?;
+        let signature = read_prefixed(buf, MAX_SIGNATURE_LEN)?;
+        let signature = signature_from_slice(&signature)
+            .map_err(|_| Error::Invalid("QuorumCertificate", "malformed signature"))?;
+        let signers = read_prefixed(buf, MAX_BITMAP_CONTRIBUTORS / 8)?;
+        let signers = ParticipationBitmap::from_packed(&signers).ok_or(Error::Invalid(
+            "QuorumCertificate",
+            "non-canonical signer bitmap",
+        ))?;
+        Ok(Self {
+            round,
+            payload,
+            signature,
+            signers,
+        })
+    }
+}
+
+/// Bytes prefixed with their `u16` length, at most `max` of them
+fn read_prefixed(buf: &mut impl Buf, max: usize) -> Result<Vec<u8>, Error> {
+    let len = u16::read(buf)? as usize;
+    if len > max {
+        return Err(Error::InvalidLength(len));
+    }
+    if buf.remaining() < len {
+        return Err(Error::EndOfBuffer);
+    }
+    let mut bytes = vec![0; len];
+    buf.copy_to_slice(&mut bytes);
+    Ok(bytes)
 }
@@ -0,0 +1,133 @@
+//! Tracking of messages from peers missing from the contributor set.
+//!
+//! A peer sending shares while unknown is most often an operator registered since the
+//! last committee update. Each such peer is counted so only its first message is worth
+//! a warning, and a committee refresh is asked for at most once per
+//! [UnknownPeerConfig::refresh_interval] per peer. At most
+//! [UnknownPeerConfig::max_peers] peers are tracked, the one seen first is forgotten
+//! beyond that.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Configuration of [UnknownPeers]
+#[derive(Clone, Debug)]
+pub struct UnknownPeerConfig {
+    /// Peers tracked at once
+    pub max_peers: usize,
+    /// Time between two committee refreshes asked for the same peer
+    pub refresh_interval: Duration,
+}
+
+impl Default for UnknownPeerConfig {
+    fn default() -> Self {
+        Self {
+            max_peers: 256,
+            refresh_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+/// A message from an unknown peer, as counted by [UnknownPeers::record]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownSighting {
+    /// Messages from the peer so far, this one included
+    pub messages: u64,
+    /// Whether a committee refresh should check if the peer was just registered
+    pub refresh: bool,
+}
+
+impl UnknownSighting {
+    /// Whether this is the first message of the peer
+    pub fn is_first(&self) -> bool {
+        self.messages == 1
+    }
+}
+
+#[derive(Debug)]
+struct PeerRecord {
+    messages: u64,
+    last_refresh: Option<Instant>,
+}
+
+/// Message counts of unknown peers, bounded to the ones seen last
+#[derive(Debug)]
+pub struct UnknownPeers<K> {
+    config: UnknownPeerConfig,
+    peers: HashMap<K, PeerRecord>,
+    /// Tracked peers, first seen first
+    order: VecDeque<K>,
+}
+
+impl<K> Default for UnknownPeers<K> {
+    fn default() -> Self {
+        Self {
+            config: UnknownPeerConfig::default(),
+            peers: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> UnknownPeers<K> {
+    /// Tracker with no unknown peer
+    pub fn new(config: UnknownPeerConfig) -> Self {
+        Self {
+            config,
+            peers: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Count a message from `peer` at `now`
+    pub fn record(&mut self, peer: &K, now: Instant) -> UnknownSighting {
+        if !self.peers.contains_key(peer) {
+            while self.order.len() >= self.config.max_peers.max(1) {
+                let Some(oldest) = self.order.pop_front() else {
+                    break;
+                };
+                self.peers.remove(&oldest);
+            }
+            self.order.push_back(peer.clone());
+        }
+        let record = self.peers.entry(peer.clone()).or_insert(PeerRecord {
+            messages: 0,
+            last_refresh: None,
+        });
+        record.messages += 1;
+        let refresh = record
+            .last_refresh
+            .is_none_or(|last| now.duration_since(last) >= self.config.refresh_interval);
+        if refresh {
+            record.last_refresh = Some(now);
+        }
+        UnknownSighting {
+            messages: record.messages,
+            refresh,
+        }
+    }
+
+    /// Forget `peer`, once it joined the contributor set
+    pub fn remove(&mut self, peer: &K) {
+        if self.peers.remove(peer).is_some() {
+            self.order.retain(|tracked| tracked != peer);
+        }
+    }
+
+    /// Messages counted from `peer`
+    pub fn messages(&self, peer: &K) -> u64 {
+        self.peers.get(peer).map_or(0, |record| record.messages)
+    }
+
+    /// Peers tracked
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Whether no unknown peer is tracked
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+}
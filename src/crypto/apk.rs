@@ -0,0 +1,26 @@
+//! Aggregate G1 public key (APK) of the contributors behind an aggregate signature,
+//! the sum of their G1 keys.
+
+use ark_bn254::{G1Affine, G1Projective};
+use ark_ec::CurveGroup;
+use ark_ff::Zero;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use bn254::G1PublicKey;
+
+/// Sum of the G1 keys yielded by `keys`, without collecting them first
+///
+/// Returns `None` if a key is not a G1 point. The sum of no keys is the identity.
+pub fn aggregate_g1_iter<'a>(keys: impl Iterator<Item = &'a G1PublicKey>) -> Option<G1PublicKey> {
+    let mut apk = G1Projective::zero();
+    for key in keys {
+        apk += G1Affine::deserialize_compressed(&key[..]).ok()?;
+    }
+    let mut bytes = Vec::new();
+    apk.into_affine().serialize_compressed(&mut bytes).ok()?;
+    G1PublicKey::try_from(bytes).ok()
+}
+
+/// Sum of the G1 `keys`
+pub fn aggregate_g1(keys: &[G1PublicKey]) -> Option<G1PublicKey> {
+    aggregate_g1_iter(keys.iter())
+}
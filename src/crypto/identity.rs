@@ -0,0 +1,209 @@
+//! Identity file handed to the orchestrator when a node joins.
+//!
+//! The file lists the node's keys in the formats the router and the contracts expect:
+//! the G2 public key as encoded on the wire, and both the G2 and G1 public keys as
+//! decimal coordinates (G2 as `[c1, c0]` like the on-chain `BN254.G2Point`). It is
+//! signed by the node key under [IDENTITY_NAMESPACE], so the self-signature proves
+//! possession of the key and can never be mistaken for a round signature.
+//!
+//! Verification checks the self-signature, that the G2 coordinates match the wire
+//! key, and that the G1 and G2 keys share the same secret.
+
+use alloy_primitives::{Address, hex};
+use ark_bn254::{Bn254 as Curve, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup, pairing::Pairing};
+use ark_serialize::CanonicalSerialize;
+use bn254::{Bn254, PublicKey, Signature, aggregate_verify};
+use commonware_cryptography::Signer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+/// Namespace of the self-signature over an identity file
+pub const IDENTITY_NAMESPACE: &[u8] = b"_COMMONWARE_AVS_IDENTITY";
+
+/// Version of the identity file format
+pub const IDENTITY_VERSION: u8 = 1;
+
+/// G1 point as decimal coordinates
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct G1Point {
+    /// Decimal x coordinate
+    pub x: String,
+    /// Decimal y coordinate
+    pub y: String,
+}
+
+/// G2 point as decimal coordinates, each as `[c1, c0]`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct G2Point {
+    /// Decimal x coordinate
+    pub x: [String; 2],
+    /// Decimal y coordinate
+    pub y: [String; 2],
+}
+
+impl G2Point {
+    /// Public key at these coordinates, as encoded on the wire
+    pub fn to_public_key(&self) -> Option<PublicKey> {
+        let mut compressed = Vec::new();
+        g2_point(self)?.serialize_compressed(&mut compressed).ok()?;
+        PublicKey::try_from(compressed).ok()
+    }
+}
+
+/// Signed content of an identity file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdentityClaims {
+    /// Version of the identity format
+    pub version: u8,
+    /// Hex of the G2 public key as encoded on the wire
+    pub public_key: String,
+    /// G2 public key as a point
+    pub g2: G2Point,
+    /// G1 public key as a point
+    pub g1: G1Point,
+    /// Operator address registered with the AVS, if configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operator: Option<Address>,
+    /// Address the node listens on for peers
+    pub p2p_address: SocketAddr,
+}
+
+/// Identity file with the node's self-signature over its claims
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeIdentity {
+    /// Claims the signature is over
+    #[serde(flatten)]
+    pub claims: IdentityClaims,
+    /// Hex of the signature over the claims under [IDENTITY_NAMESPACE]
+    pub signature: String,
+}
+
+/// Reason an identity file was rejected
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdentityError {
+    /// The file was written by an unknown version of the format
+    UnsupportedVersion(u8),
+    /// A key, coordinate or signature could not be parsed
+    Malformed(&'static str),
+    /// The G2 coordinates are not those of the wire key
+    KeyMismatch,
+    /// The G1 key does not share the secret of the G2 key
+    G1Mismatch,
+    /// The self-signature does not verify against the key
+    InvalidSignature,
+}
+
+impl fmt::Display for IdentityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdentityError::UnsupportedVersion(version) => {
+                write!(f, "unsupported identity version: {version}")
+            }
+            IdentityError::Malformed(field) => write!(f, "malformed {field}"),
+            IdentityError::KeyMismatch => write!(f, "g2 coordinates do not match public key"),
+            IdentityError::G1Mismatch => write!(f, "g1 key does not match g2 key"),
+            IdentityError::InvalidSignature => write!(f, "invalid self-signature"),
+        }
+    }
+}
+
+impl std::error::Error for IdentityError {}
+
+impl IdentityClaims {
+    /// Bytes covered by the self-signature
+    pub fn message(&self) -> Result<Vec<u8>, IdentityError> {
+        serde_json::to_vec(self).map_err(|_| IdentityError::Malformed("claims"))
+    }
+}
+
+impl NodeIdentity {
+    /// Identity of the node holding `secret`, signed by `signer` over the same key
+    pub fn export(
+        signer: &Bn254,
+        secret: Fr,
+        operator: Option<Address>,
+        p2p_address: SocketAddr,
+    ) -> Result<Self, IdentityError> {
+        let g2 = (G2Affine::generator() * secret).into_affine();
+        let g1 = (G1Affine::generator() * secret).into_affine();
+        let claims = IdentityClaims {
+            version: IDENTITY_VERSION,
+            public_key: hex::encode(signer.public_key()),
+            g2: G2Point {
+                x: [g2.x.c1.to_string(), g2.x.c0.to_string()],
+                y: [g2.y.c1.to_string(), g2.y.c0.to_string()],
+            },
+            g1: G1Point {
+                x: g1.x.to_string(),
+                y: g1.y.to_string(),
+            },
+            operator,
+            p2p_address,
+        };
+        let signature = Signer::sign(signer, Some(IDENTITY_NAMESPACE), &claims.message()?);
+        Ok(Self {
+            claims,
+            signature: hex::encode(signature),
+        })
+    }
+
+    /// Checks the file and returns the key it proves possession of
+    pub fn verify(&self) -> Result<PublicKey, IdentityError> {
+        let claims = &self.claims;
+        if claims.version != IDENTITY_VERSION {
+            return Err(IdentityError::UnsupportedVersion(claims.version));
+        }
+        let public_key = hex::decode(&claims.public_key)
+            .ok()
+            .and_then(|bytes| PublicKey::try_from(bytes).ok())
+            .ok_or(IdentityError::Malformed("public key"))?;
+        let signature = hex::decode(&self.signature)
+            .ok()
+            .and_then(|bytes| Signature::try_from(bytes).ok())
+            .ok_or(IdentityError::Malformed("signature"))?;
+
+        // The coordinates are what gets registered, so they must be the wire key
+        let g2 = g2_point(&claims.g2).ok_or(IdentityError::Malformed("g2 coordinates"))?;
+        let mut compressed = Vec::new();
+        g2.serialize_compressed(&mut compressed)
+            .map_err(|_| IdentityError::Malformed("g2 coordinates"))?;
+        if compressed[..] != public_key[..] {
+            return Err(IdentityError::KeyMismatch);
+        }
+
+        // e(g1, G2) == e(G1, g2) holds only if both keys share the secret
+        let g1 = g1_point(&claims.g1).ok_or(IdentityError::Malformed("g1 coordinates"))?;
+        if Curve::pairing(g1, G2Affine::generator()) != Curve::pairing(G1Affine::generator(), g2) {
+            return Err(IdentityError::G1Mismatch);
+        }
+
+        if !aggregate_verify(
+            std::slice::from_ref(&public_key),
+            Some(IDENTITY_NAMESPACE),
+            &claims.message()?,
+            &signature,
+        ) {
+            return Err(IdentityError::InvalidSignature);
+        }
+        Ok(public_key)
+    }
+}
+
+fn field(value: &str) -> Option<Fq> {
+    Fq::from_str(value).ok()
+}
+
+fn g1_point(point: &G1Point) -> Option<G1Affine> {
+    let point = G1Affine::new_unchecked(field(&point.x)?, field(&point.y)?);
+    (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+}
+
+fn g2_point(point: &G2Point) -> Option<G2Affine> {
+    let x = Fq2::new(field(&point.x[1])?, field(&point.x[0])?);
+    let y = Fq2::new(field(&point.y[1])?, field(&point.y[0])?);
+    let point = G2Affine::new_unchecked(x, y);
+    (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+}
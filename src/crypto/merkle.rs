@@ -0,0 +1,129 @@
+//! Merkle root over a batch of task results, signed in place of each result.
+//!
+//! A contributor signs the root once, so the aggregate signature attests to every
+//! result of the batch, and a single result is proven part of it by its inclusion
+//! proof. The tree follows OpenZeppelin's `MerkleProof`:
+//!
+//! - a leaf is `keccak256(keccak256(result))`, hashing twice so no leaf can be passed
+//!   off as an inner node;
+//! - an inner node is the keccak256 of its two children, smaller first, so a proof
+//!   carries no left or right flags;
+//! - the last node of a level with an odd number of nodes moves up unchanged.
+
+use crate::contributor::AsyncSigner;
+use crate::digest::SigningDomain;
+use alloy_primitives::keccak256;
+use anyhow::Result;
+use bn254::{PublicKey, Signature};
+
+/// Leaf of `result` in a tree
+pub fn leaf_hash(result: &[u8]) -> [u8; 32] {
+    keccak256(keccak256(result)).0
+}
+
+/// Parent of the nodes `a` and `b`, whatever their order
+fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(first);
+    preimage[32..].copy_from_slice(second);
+    keccak256(preimage).0
+}
+
+/// Siblings from a leaf up to the root
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// Position of the result in the batch
+    pub index: usize,
+    /// Sibling hashes from the leaf up to the root
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl MerkleProof {
+    /// Root reached from `result` through the siblings
+    pub fn root_of(&self, result: &[u8]) -> [u8; 32] {
+        self.siblings
+            .iter()
+            .fold(leaf_hash(result), |node, sibling| node_hash(&node, sibling))
+    }
+
+    /// Whether `result` is part of the batch with `root`
+    pub fn verify(&self, root: &[u8; 32], result: &[u8]) -> bool {
+        self.root_of(result) == *root
+    }
+}
+
+/// Batch of task results attested to by signing their Merkle root
+#[derive(Clone, Debug)]
+pub struct MerkleTask {
+    /// Nodes by level below the root, leaves first, none for a single result
+    levels: Vec<Vec<[u8; 32]>>,
+    root: [u8; 32],
+}
+
+impl MerkleTask {
+    /// Tree over `results` in order, `None` for an empty batch
+    pub fn new<R: AsRef<[u8]>>(results: impl IntoIterator<Item = R>) -> Option<Self> {
+        let mut level: Vec<[u8; 32]> = results
+            .into_iter()
+            .map(|result| leaf_hash(result.as_ref()))
+            .collect();
+        let mut levels = Vec::new();
+        while level.len() > 1 {
+            // A pair is hashed, the odd node left at the end moves up as is
+            let parents = level
+                .chunks(2)
+                .filter_map(|pair| pair.iter().copied().reduce(|a, b| node_hash(&a, &b)))
+                .collect();
+            levels.push(std::mem::replace(&mut level, parents));
+        }
+        let root = *level.first()?;
+        Some(Self { levels, root })
+    }
+
+    /// Root committing to every result of the batch
+    pub fn root(&self) -> [u8; 32] {
+        self.root
+    }
+
+    /// Number of results in the batch
+    pub fn len(&self) -> usize {
+        self.levels.first().map_or(1, Vec::len)
+    }
+
+    /// Whether the batch has no result
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inclusion proof of the result at `index`, `None` if out of the batch
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut siblings = Vec::new();
+        let mut position = index;
+        for level in &self.levels {
+            // The last node of an odd level has no sibling and moves up as is
+            if let Some(sibling) = level.get(position ^ 1) {
+                siblings.push(*sibling);
+            }
+            position /= 2;
+        }
+        Some(MerkleProof { index, siblings })
+    }
+
+    /// Digest contributors sign for the batch, the root under `domain`
+    pub fn signing_digest(&self, domain: &SigningDomain) -> [u8; 32] {
+        domain.apply(self.root())
+    }
+
+    /// Sign the root under `domain` the way a contributor signs a round
+    pub async fn sign(
+        &self,
+        signer: &dyn AsyncSigner<PublicKey = PublicKey, Signature = Signature>,
+        domain: &SigningDomain,
+    ) -> Result<Signature> {
+        signer.sign(None, &self.signing_digest(domain)).await
+    }
+}
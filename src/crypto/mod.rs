@@ -0,0 +1,18 @@
+//! Hashes contributors sign, as computed on-chain, the node's signed identity,
+//! threshold signature reconstruction, G1 key aggregation and Merkle roots of
+//! batched results.
+
+#[cfg(test)]
+mod tests;
+
+pub mod apk;
+pub mod identity;
+pub mod merkle;
+pub mod task_hash;
+pub mod threshold;
+
+pub use apk::{aggregate_g1, aggregate_g1_iter};
+pub use identity::{IDENTITY_NAMESPACE, IdentityError, NodeIdentity};
+pub use merkle::{MerkleProof, MerkleTask};
+pub use task_hash::{TaskHashDomain, compute_avs_task_hash};
+pub use threshold::{ThresholdAggregator, ThresholdError};
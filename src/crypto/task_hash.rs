@@ -0,0 +1,65 @@
+//! Hash of an AVS task as checked by `BLSSignatureChecker`.
+//!
+//! The task hash is
+//!
+//! ```text
+//! keccak256(abi.encode(TaskData taskData, uint256 round, uint256 chainId, address contract))
+//! ```
+//!
+//! where `taskData` is the task data carried in the Start as its ABI tuple, the
+//! `(string var1, string var2, string var3)` of [counter_task_abi] for the counter task.
+//! Binding the chain id and the task contract keeps a signature from being replayed
+//! against another deployment of the same task.
+//!
+//! The deployed Counter contract neither exposes this hash nor checks it: `increment`
+//! takes the message hash from its caller and rejects one other than the hash it
+//! expects with `InvalidHash`. Contributors therefore only sign the task hash when a
+//! [TaskHashDomain] is configured, see [crate::validation::CounterPayloadValidator].
+
+use alloy_dyn_abi::DynSolValue;
+use alloy_primitives::{Address, U256, keccak256};
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+
+/// Deployment a task hash is bound to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TaskHashDomain {
+    /// Chain the contract is deployed on
+    pub chain_id: u64,
+    /// Address of the task contract
+    pub contract: Address,
+}
+
+/// Hash of the counter task `task_data` for `round` on `contract` of `chain_id`
+pub fn compute_avs_task_hash(
+    task_data: &CounterTaskData,
+    round: u64,
+    chain_id: u64,
+    contract: Address,
+) -> [u8; 32] {
+    compute_task_hash(counter_task_abi(task_data), round, chain_id, contract)
+}
+
+/// ABI tuple `(string var1, string var2, string var3)` of the counter task data
+pub fn counter_task_abi(task_data: &CounterTaskData) -> DynSolValue {
+    DynSolValue::Tuple(vec![
+        DynSolValue::String(task_data.var1.clone()),
+        DynSolValue::String(task_data.var2.clone()),
+        DynSolValue::String(task_data.var3.clone()),
+    ])
+}
+
+/// Hash of the task data ABI tuple `task_data` for `round` on `contract` of `chain_id`
+pub fn compute_task_hash(
+    task_data: DynSolValue,
+    round: u64,
+    chain_id: u64,
+    contract: Address,
+) -> [u8; 32] {
+    let params = DynSolValue::Tuple(vec![
+        task_data,
+        DynSolValue::Uint(U256::from(round), 256),
+        DynSolValue::Uint(U256::from(chain_id), 256),
+        DynSolValue::Address(contract),
+    ]);
+    keccak256(params.abi_encode_params()).0
+}
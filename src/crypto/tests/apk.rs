@@ -0,0 +1,38 @@
+use crate::crypto::{aggregate_g1, aggregate_g1_iter};
+use ark_bn254::{Fr, G1Affine};
+use ark_ec::{AffineRepr, CurveGroup};
+use bn254::G1PublicKey;
+
+/// G1 key of the secret `secret`, built from its coordinates as read from the chain
+fn g1_key(secret: u64) -> G1PublicKey {
+    let point = (G1Affine::generator() * Fr::from(secret)).into_affine();
+    G1PublicKey::create_from_g1_coordinates(&point.x.to_string(), &point.y.to_string())
+        .expect("valid G1 coordinates")
+}
+
+#[cfg(test)]
+mod apk_tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_streamed_apk_matches_collected_apk() {
+        let keys: Vec<G1PublicKey> = [3, 5, 7].into_iter().map(g1_key).collect();
+        let collected = aggregate_g1(&keys).unwrap();
+        let streamed = aggregate_g1_iter(keys.iter()).unwrap();
+        assert_eq!(collected[..], streamed[..]);
+
+        // The sum of the keys is the key of the sum of the secrets
+        assert_eq!(streamed[..], g1_key(15)[..]);
+    }
+
+    #[test]
+    fn test_apk_streamed_from_map() {
+        let g1_map: HashMap<u8, G1PublicKey> = [(0, g1_key(3)), (1, g1_key(5)), (2, g1_key(7))]
+            .into_iter()
+            .collect();
+        let participants = [0, 2];
+        let apk = aggregate_g1_iter(participants.iter().map(|i| &g1_map[i])).unwrap();
+        assert_eq!(apk[..], g1_key(10)[..]);
+    }
+}
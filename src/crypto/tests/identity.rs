@@ -0,0 +1,133 @@
+use crate::crypto::identity::IdentityClaims;
+use crate::crypto::{IDENTITY_NAMESPACE, IdentityError, NodeIdentity};
+use alloy_primitives::{Address, hex};
+use ark_bn254::Fr;
+use bn254::{Bn254, PrivateKey};
+use commonware_cryptography::Signer;
+use std::net::SocketAddr;
+
+fn node(seed: u64) -> (Bn254, Fr) {
+    let secret = Fr::from(seed);
+    let signer = Bn254::new(PrivateKey::from(secret)).expect("Failed to create signer");
+    (signer, secret)
+}
+
+fn export(seed: u64) -> NodeIdentity {
+    let (signer, secret) = node(seed);
+    NodeIdentity::export(
+        &signer,
+        secret,
+        Some(Address::repeat_byte(0x11)),
+        "10.0.0.1:3000".parse().unwrap(),
+    )
+    .unwrap()
+}
+
+/// Identity file as written to disk and read back
+fn round_trip(identity: &NodeIdentity) -> NodeIdentity {
+    serde_json::from_str(&serde_json::to_string_pretty(identity).unwrap()).unwrap()
+}
+
+fn resign(signer: &Bn254, namespace: Option<&[u8]>, claims: IdentityClaims) -> NodeIdentity {
+    let signature = Signer::sign(signer, namespace, &claims.message().unwrap());
+    NodeIdentity {
+        claims,
+        signature: hex::encode(signature),
+    }
+}
+
+#[cfg(test)]
+mod identity_tests {
+    use super::*;
+
+    #[test]
+    fn test_export_verify_round_trip() {
+        let (signer, _) = node(7);
+        let identity = round_trip(&export(7));
+        assert_eq!(identity, export(7));
+        assert_eq!(identity.verify(), Ok(signer.public_key()));
+        assert_eq!(identity.claims.operator, Some(Address::repeat_byte(0x11)));
+        assert_eq!(
+            identity.claims.p2p_address,
+            "10.0.0.1:3000".parse::<SocketAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_operator_is_optional() {
+        let (signer, secret) = node(7);
+        let identity =
+            NodeIdentity::export(&signer, secret, None, "10.0.0.1:3000".parse().unwrap()).unwrap();
+        let json = serde_json::to_string(&identity).unwrap();
+        assert!(!json.contains("operator"));
+        assert_eq!(round_trip(&identity).verify(), Ok(signer.public_key()));
+    }
+
+    #[test]
+    fn test_tampered_claims_rejected() {
+        let mut identity = round_trip(&export(7));
+        identity.claims.p2p_address = "10.0.0.2:3000".parse().unwrap();
+        assert_eq!(identity.verify(), Err(IdentityError::InvalidSignature));
+
+        let mut identity = round_trip(&export(7));
+        identity.claims.operator = Some(Address::repeat_byte(0x22));
+        assert_eq!(identity.verify(), Err(IdentityError::InvalidSignature));
+    }
+
+    #[test]
+    fn test_tampered_keys_rejected() {
+        // Another node's G1 key does not pair with this node's G2 key
+        let mut identity = export(7);
+        identity.claims.g1 = export(8).claims.g1;
+        assert_eq!(identity.verify(), Err(IdentityError::G1Mismatch));
+
+        // Another node's coordinates are not the wire key
+        let mut identity = export(7);
+        identity.claims.g2 = export(8).claims.g2;
+        assert_eq!(identity.verify(), Err(IdentityError::KeyMismatch));
+
+        // Another node's key and coordinates, still signed by this node
+        let other = export(8);
+        let mut identity = export(7);
+        identity.claims.public_key = other.claims.public_key;
+        identity.claims.g2 = other.claims.g2;
+        identity.claims.g1 = other.claims.g1;
+        assert_eq!(identity.verify(), Err(IdentityError::InvalidSignature));
+    }
+
+    #[test]
+    fn test_malformed_fields_rejected() {
+        let mut identity = export(7);
+        identity.signature = "zz".to_string();
+        assert_eq!(
+            identity.verify(),
+            Err(IdentityError::Malformed("signature"))
+        );
+
+        let mut identity = export(7);
+        identity.claims.g1.x = "1".to_string();
+        assert_eq!(
+            identity.verify(),
+            Err(IdentityError::Malformed("g1 coordinates"))
+        );
+
+        let mut identity = export(7);
+        identity.claims.version += 1;
+        assert!(matches!(
+            identity.verify(),
+            Err(IdentityError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_signature_is_domain_separated() {
+        let (signer, _) = node(7);
+        let claims = export(7).claims;
+
+        // The same claims signed like a round are not a self-signature
+        let unsigned = resign(&signer, None, claims.clone());
+        assert_eq!(unsigned.verify(), Err(IdentityError::InvalidSignature));
+        let identity = resign(&signer, Some(IDENTITY_NAMESPACE), claims);
+        assert_eq!(identity.verify(), Ok(signer.public_key()));
+    }
+}
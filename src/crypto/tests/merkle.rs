@@ -0,0 +1,90 @@
+use crate::contributor::tests::mock::MockContributor;
+use crate::crypto::merkle::leaf_hash;
+use crate::crypto::{MerkleProof, MerkleTask};
+use crate::digest::SigningDomain;
+use alloy_primitives::keccak256;
+use bn254::{Bn254, aggregate_signatures, aggregate_verify};
+use commonware_cryptography::Signer;
+
+/// Results of a batch of `n` tasks
+fn results(n: usize) -> Vec<Vec<u8>> {
+    (0..n)
+        .map(|i| format!("task {i} result").into_bytes())
+        .collect()
+}
+
+/// Parent of two nodes, computed independently of the tree
+fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    keccak256([first, second].concat()).0
+}
+
+#[cfg(test)]
+mod merkle_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_signed_root_attests_to_included_leaf() {
+        let results = results(4);
+        let tree = MerkleTask::new(&results).unwrap();
+        assert_eq!(tree.len(), 4);
+
+        let leaves: Vec<[u8; 32]> = results.iter().map(|result| leaf_hash(result)).collect();
+        let root = parent(parent(leaves[0], leaves[1]), parent(leaves[2], leaves[3]));
+        assert_eq!(tree.root(), root);
+
+        // Every contributor signs the root, the aggregate attests to the batch
+        let signers: Vec<Bn254> = (1..=3).map(MockContributor::create_test_bn254).collect();
+        let domain = SigningDomain::Tagged(b"batch".to_vec());
+        let mut signatures = Vec::new();
+        for signer in &signers {
+            signatures.push(tree.sign(signer, &domain).await.unwrap());
+        }
+        let aggregate = aggregate_signatures(&signatures).unwrap();
+        let keys: Vec<_> = signers.iter().map(Signer::public_key).collect();
+        assert!(aggregate_verify(
+            &keys,
+            None,
+            &tree.signing_digest(&domain),
+            &aggregate
+        ));
+
+        // A single result is proven part of the signed root
+        let proof = tree.proof(2).unwrap();
+        assert_eq!(
+            proof.siblings,
+            vec![leaves[3], parent(leaves[0], leaves[1])]
+        );
+        assert!(proof.verify(&tree.root(), &results[2]));
+        assert!(!proof.verify(&tree.root(), &results[1]));
+        assert!(!proof.verify(&tree.root(), b"forged result"));
+    }
+
+    #[test]
+    fn test_odd_node_moves_up_unchanged() {
+        let results = results(5);
+        let tree = MerkleTask::new(&results).unwrap();
+        for (index, result) in results.iter().enumerate() {
+            assert!(tree.proof(index).unwrap().verify(&tree.root(), result));
+        }
+
+        // The fifth leaf is only paired at the top level
+        let proof = tree.proof(4).unwrap();
+        assert_eq!(proof.siblings.len(), 1);
+        assert_eq!(tree.proof(5), None);
+    }
+
+    #[test]
+    fn test_single_and_empty_batches() {
+        let tree = MerkleTask::new([b"only result"]).unwrap();
+        assert_eq!(tree.root(), leaf_hash(b"only result"));
+        assert_eq!(
+            tree.proof(0),
+            Some(MerkleProof {
+                index: 0,
+                siblings: vec![],
+            })
+        );
+        assert!(MerkleTask::new(Vec::<Vec<u8>>::new()).is_none());
+    }
+}
@@ -0,0 +1,5 @@
+mod apk;
+mod identity;
+mod merkle;
+mod task_hash;
+mod threshold_signature;
@@ -0,0 +1,91 @@
+use crate::contributor::tests::harness::start_message;
+use crate::crypto::task_hash::{compute_task_hash, counter_task_abi};
+use crate::crypto::{TaskHashDomain, compute_avs_task_hash};
+use alloy_dyn_abi::DynSolValue;
+use alloy_primitives::{Address, hex};
+use serde::Deserialize;
+
+/// Task hashes computed independently of the node
+const VECTORS: &str = include_str!("../../../tests/fixtures/task_hash.json");
+
+#[derive(Deserialize)]
+struct Fixture {
+    vectors: Vec<Vector>,
+}
+
+#[derive(Deserialize)]
+struct Vector {
+    name: String,
+    task_data: Vec<String>,
+    round: u64,
+    chain_id: u64,
+    contract: String,
+    hash: String,
+}
+
+fn anvil() -> TaskHashDomain {
+    TaskHashDomain {
+        chain_id: 31337,
+        contract: Address::repeat_byte(0x5f),
+    }
+}
+
+#[cfg(test)]
+mod task_hash_tests {
+    use super::*;
+
+    #[test]
+    fn test_vectors() {
+        let fixture: Fixture = serde_json::from_str(VECTORS).unwrap();
+        assert!(!fixture.vectors.is_empty());
+        for vector in fixture.vectors {
+            let contract = Address::from_slice(&hex::decode(&vector.contract).unwrap());
+            let task_data = vector
+                .task_data
+                .into_iter()
+                .map(DynSolValue::String)
+                .collect();
+            let hash = compute_task_hash(
+                DynSolValue::Tuple(task_data),
+                vector.round,
+                vector.chain_id,
+                contract,
+            );
+            assert_eq!(hex::encode(hash), vector.hash, "{}", vector.name);
+        }
+    }
+
+    #[test]
+    fn test_hashes_abi_tuple() {
+        let task_data = start_message(1).metadata;
+        let domain = anvil();
+        assert_eq!(
+            compute_avs_task_hash(&task_data, 1, domain.chain_id, domain.contract),
+            compute_task_hash(
+                counter_task_abi(&task_data),
+                1,
+                domain.chain_id,
+                domain.contract
+            )
+        );
+    }
+
+    #[test]
+    fn test_bound_to_round_and_deployment() {
+        let task_data = start_message(1).metadata;
+        let domain = anvil();
+        let hash = compute_avs_task_hash(&task_data, 1, domain.chain_id, domain.contract);
+        assert_ne!(
+            hash,
+            compute_avs_task_hash(&task_data, 2, domain.chain_id, domain.contract)
+        );
+        assert_ne!(
+            hash,
+            compute_avs_task_hash(&task_data, 1, 1, domain.contract)
+        );
+        assert_ne!(
+            hash,
+            compute_avs_task_hash(&task_data, 1, domain.chain_id, Address::ZERO)
+        );
+    }
+}
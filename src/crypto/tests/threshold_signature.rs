@@ -0,0 +1,92 @@
+use crate::crypto::threshold::lagrange_at_zero;
+use crate::crypto::{ThresholdAggregator, ThresholdError};
+use ark_bn254::Fr;
+use bn254::{Bn254, PrivateKey, Signature, aggregate_verify};
+use commonware_cryptography::Signer;
+use std::num::NonZeroUsize;
+
+const NAMESPACE: &[u8] = b"_THRESHOLD_TEST";
+const MESSAGE: &[u8] = b"task response";
+
+fn signer(secret: Fr) -> Bn254 {
+    Bn254::new(PrivateKey::from(secret)).expect("Failed to create signer")
+}
+
+/// Group signer and the shares of a 2-of-3 scheme, `f(x) = 7 + 5x`
+fn two_of_three() -> (Bn254, Vec<(u32, Bn254)>) {
+    let polynomial = |x: u32| Fr::from(7u64) + Fr::from(5u64) * Fr::from(x);
+    let shares = (1..=3).map(|index| (index, signer(polynomial(index))));
+    (signer(polynomial(0)), shares.collect())
+}
+
+fn partials(shares: &[(u32, Bn254)], indices: &[u32]) -> Vec<(u32, Signature)> {
+    shares
+        .iter()
+        .filter(|(index, _)| indices.contains(index))
+        .map(|(index, share)| (*index, share.sign(Some(NAMESPACE), MESSAGE)))
+        .collect()
+}
+
+#[cfg(test)]
+mod threshold_signature_tests {
+    use super::*;
+
+    #[test]
+    fn test_two_of_three_verifies_against_group_key() {
+        let (group, shares) = two_of_three();
+        let aggregator = ThresholdAggregator::new(NonZeroUsize::new(2).unwrap());
+
+        for indices in [[1, 2], [1, 3], [2, 3]] {
+            let signature = aggregator
+                .reconstruct(&partials(&shares, &indices))
+                .unwrap();
+            assert!(aggregate_verify(
+                std::slice::from_ref(&group.public_key()),
+                Some(NAMESPACE),
+                MESSAGE,
+                &signature,
+            ));
+            assert_eq!(signature, group.sign(Some(NAMESPACE), MESSAGE));
+        }
+    }
+
+    #[test]
+    fn test_extra_partials_ignored() {
+        let (group, shares) = two_of_three();
+        let signature = ThresholdAggregator::new(NonZeroUsize::new(2).unwrap())
+            .reconstruct(&partials(&shares, &[3, 1, 2]))
+            .unwrap();
+        assert_eq!(signature, group.sign(Some(NAMESPACE), MESSAGE));
+    }
+
+    #[test]
+    fn test_invalid_partial_sets_rejected() {
+        let (_, shares) = two_of_three();
+        let aggregator = ThresholdAggregator::new(NonZeroUsize::new(2).unwrap());
+        assert_eq!(
+            aggregator.reconstruct(&partials(&shares, &[1])),
+            Err(ThresholdError::NotEnoughPartials { have: 1, need: 2 })
+        );
+
+        let mut duplicated = partials(&shares, &[1, 2]);
+        duplicated[1].0 = 1;
+        assert_eq!(
+            aggregator.reconstruct(&duplicated),
+            Err(ThresholdError::DuplicateIndex(1))
+        );
+
+        let mut zero = partials(&shares, &[1, 2]);
+        zero[0].0 = 0;
+        assert_eq!(
+            aggregator.reconstruct(&zero),
+            Err(ThresholdError::ZeroIndex)
+        );
+    }
+
+    #[test]
+    fn test_lagrange_coefficients_sum_to_one() {
+        // Interpolating the constant polynomial 1 gives 1 at zero
+        let coefficients = lagrange_at_zero(&[2, 5, 9]).unwrap();
+        assert_eq!(coefficients.iter().sum::<Fr>(), Fr::from(1u64));
+    }
+}
@@ -0,0 +1,135 @@
+//! Reconstruction of threshold BLS signatures.
+//!
+//! With a threshold scheme, the group secret is shared with a polynomial of degree
+//! `threshold - 1` and each operator signs with its share, the polynomial at its
+//! index. Any `threshold` partial signatures determine the group signature by
+//! Lagrange interpolation at zero, which verifies against the single group key,
+//! unlike the n-of-n aggregate whose key depends on who signed.
+
+use ark_bn254::{Fr, G1Affine, G1Projective};
+use ark_ec::CurveGroup;
+use ark_ff::{Field, One, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use bn254::Signature as Sig;
+use std::collections::HashSet;
+use std::fmt;
+use std::num::NonZeroUsize;
+
+/// Reason partial signatures cannot be combined
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ThresholdError {
+    /// Fewer partial signatures than the threshold
+    NotEnoughPartials {
+        /// Partial signatures given
+        have: usize,
+        /// Partial signatures needed
+        need: usize,
+    },
+    /// Share indices start at 1, the group secret is the polynomial at 0
+    ZeroIndex,
+    /// Two partial signatures share this index
+    DuplicateIndex(u32),
+    /// The partial signature of this index is not a G1 point
+    MalformedPartial(u32),
+    /// The partials combined into a point that is not a valid signature
+    InvalidSignature,
+}
+
+impl fmt::Display for ThresholdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThresholdError::NotEnoughPartials { have, need } => {
+                write!(f, "not enough partial signatures: {have} of {need}")
+            }
+            ThresholdError::ZeroIndex => write!(f, "share index 0 is the group secret"),
+            ThresholdError::DuplicateIndex(index) => write!(f, "duplicate share index: {index}"),
+            ThresholdError::MalformedPartial(index) => {
+                write!(f, "malformed partial signature of share {index}")
+            }
+            ThresholdError::InvalidSignature => write!(f, "reconstructed signature is invalid"),
+        }
+    }
+}
+
+impl std::error::Error for ThresholdError {}
+
+/// Reconstructs the group signature of a `threshold`-of-n scheme
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThresholdAggregator {
+    threshold: usize,
+}
+
+impl ThresholdAggregator {
+    /// Aggregator combining `threshold` partial signatures
+    pub fn new(threshold: NonZeroUsize) -> Self {
+        Self {
+            threshold: threshold.get(),
+        }
+    }
+
+    /// Partial signatures needed to reconstruct a signature
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Group signature from partial signatures, each with the index of its share
+    ///
+    /// Only the `threshold` partials with the lowest indices are combined, so the
+    /// result does not depend on which extra partials were collected.
+    pub fn reconstruct(&self, partials: &[(u32, Sig)]) -> Result<Sig, ThresholdError> {
+        if partials.len() < self.threshold {
+            return Err(ThresholdError::NotEnoughPartials {
+                have: partials.len(),
+                need: self.threshold,
+            });
+        }
+        let mut seen = HashSet::new();
+        for (index, _) in partials {
+            if *index == 0 {
+                return Err(ThresholdError::ZeroIndex);
+            }
+            if !seen.insert(*index) {
+                return Err(ThresholdError::DuplicateIndex(*index));
+            }
+        }
+
+        let mut partials: Vec<&(u32, Sig)> = partials.iter().collect();
+        partials.sort_by_key(|(index, _)| *index);
+        partials.truncate(self.threshold);
+        let indices: Vec<u32> = partials.iter().map(|(index, _)| *index).collect();
+
+        let coefficients = lagrange_at_zero(&indices).ok_or(ThresholdError::InvalidSignature)?;
+        let mut signature = G1Projective::zero();
+        for ((index, partial), coefficient) in partials.iter().zip(coefficients) {
+            let point = G1Affine::deserialize_compressed(&partial[..])
+                .map_err(|_| ThresholdError::MalformedPartial(*index))?;
+            signature += point * coefficient;
+        }
+        let mut bytes = Vec::new();
+        signature
+            .into_affine()
+            .serialize_compressed(&mut bytes)
+            .map_err(|_| ThresholdError::InvalidSignature)?;
+        Sig::try_from(bytes).map_err(|_| ThresholdError::InvalidSignature)
+    }
+}
+
+/// Lagrange coefficients at zero of the distinct, non-zero `indices`
+///
+/// `None` if a denominator is zero, which distinct indices rule out.
+pub fn lagrange_at_zero(indices: &[u32]) -> Option<Vec<Fr>> {
+    indices
+        .iter()
+        .map(|&i| {
+            let xi = Fr::from(i);
+            let (numerator, denominator) = indices.iter().filter(|&&j| j != i).fold(
+                (Fr::one(), Fr::one()),
+                |(numerator, denominator), &j| {
+                    let xj = Fr::from(j);
+                    (numerator * xj, denominator * (xj - xi))
+                },
+            );
+            denominator.inverse().map(|inverse| numerator * inverse)
+        })
+        .collect()
+}
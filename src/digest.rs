@@ -0,0 +1,191 @@
+//! Canonical definition of the digest contributors sign for a round.
+//!
+//! The router, the node and any external verifier must agree byte for byte on
+//! how a message maps to the signed digest:
+//!
+//! 1. the [wire::Aggregation] message is encoded with its codec,
+//! 2. the [PayloadValidator] maps the encoding to a 32-byte hash. The counter
+//!    validator hashes the message without its payload, so the Start and the
+//!    signatures of a round map to the same hash. With a task hash domain it
+//!    returns the [crate::crypto::compute_avs_task_hash] of the round instead,
+//! 3. the [SigningDomain] is applied to the hash, under the namespace of the
+//!    round's epoch if the domain rotates.
+//!
+//! Rounds of tasks computed by an [Executor](crate::execution::Executor) sign the
+//! [compute_execution_digest] of the contributor's response instead: the domain is
+//! applied to the hash of the response.
+//!
+//! Every hash the node computes on the way is taken with the same [HashAlgorithm],
+//! `keccak256` unless configured otherwise, which must match the one the on-chain
+//! checker expects. Validators hash the message themselves: the chain validators
+//! always use `keccak256`, as the contracts do.
+//!
+//! `tests/fixtures/signing_digest.json` holds vectors for the last step. The
+//! router checks in the same file, so drift between the two crates is caught by
+//! either test suite.
+
+use crate::validation::PayloadValidator;
+use anyhow::{Result, bail};
+use commonware_avs_router::wire;
+use commonware_codec::{EncodeSize, Write};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+
+/// Version of the message encoding and of the digest contributors sign
+///
+/// Bump whenever either changes, as nodes of different versions can no longer
+/// verify each other's signatures. Transcripts of previous releases with the same
+/// version are replayed by the test suite, see [crate::contributor::transcript].
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Hash function applied to the payloads a node signs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// `keccak256`, as computed by the EVM
+    #[default]
+    Keccak256,
+    /// `sha256`
+    Sha256,
+}
+
+impl HashAlgorithm {
+    /// Hash of `data`
+    pub fn hash(&self, data: &[u8]) -> [u8; 32] {
+        match self {
+            HashAlgorithm::Keccak256 => alloy_primitives::keccak256(data).0,
+            HashAlgorithm::Sha256 => Sha256::digest(data).into(),
+        }
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashAlgorithm::Keccak256 => write!(f, "keccak256"),
+            HashAlgorithm::Sha256 => write!(f, "sha256"),
+        }
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "keccak256" => Ok(HashAlgorithm::Keccak256),
+            "sha256" => Ok(HashAlgorithm::Sha256),
+            _ => bail!("unknown hash algorithm {s}, expected keccak256 or sha256"),
+        }
+    }
+}
+
+/// Domain separation applied to a validated hash before it is signed
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SigningDomain {
+    /// Sign the validated hash as is
+    #[default]
+    None,
+    /// Sign `H(tag || hash)`, `H` being the [HashAlgorithm] of the node
+    Tagged(Vec<u8>),
+    /// Sign `H(tag || epoch || hash)`, with the big-endian `u64` epoch of the round, so
+    /// a signature of one epoch does not verify in any other
+    Rotating {
+        /// Tag prefixed to every epoch
+        tag: Vec<u8>,
+        /// Rounds in an epoch
+        rounds_per_epoch: u64,
+    },
+}
+
+impl SigningDomain {
+    /// Epoch of `round`, `None` unless the domain rotates
+    pub fn epoch(&self, round: u64) -> Option<u64> {
+        match self {
+            SigningDomain::Rotating {
+                rounds_per_epoch, ..
+            } => Some(round / (*rounds_per_epoch).max(1)),
+            _ => None,
+        }
+    }
+
+    /// Namespace hashed in front of the validated hash of `round`, if any
+    pub fn namespace(&self, round: u64) -> Option<Vec<u8>> {
+        match self {
+            SigningDomain::None => None,
+            SigningDomain::Tagged(tag) => Some(tag.clone()),
+            SigningDomain::Rotating { tag, .. } => {
+                let epoch = self.epoch(round).unwrap_or_default();
+                Some([&tag[..], &epoch.to_be_bytes()].concat())
+            }
+        }
+    }
+
+    /// Digest to sign for a validated `hash` of `round`, namespaced with `keccak256`
+    pub fn apply_in_round(&self, round: u64, hash: [u8; 32]) -> [u8; 32] {
+        self.apply_in_round_with(HashAlgorithm::Keccak256, round, hash)
+    }
+
+    /// Digest to sign for a validated `hash` of `round`, namespaced with `algorithm`
+    pub fn apply_in_round_with(
+        &self,
+        algorithm: HashAlgorithm,
+        round: u64,
+        hash: [u8; 32],
+    ) -> [u8; 32] {
+        match self.namespace(round) {
+            None => hash,
+            Some(namespace) => {
+                let mut preimage = Vec::with_capacity(namespace.len() + hash.len());
+                preimage.extend_from_slice(&namespace);
+                preimage.extend_from_slice(&hash);
+                algorithm.hash(&preimage)
+            }
+        }
+    }
+
+    /// Digest to sign for a validated `hash` outside of a round, in the first epoch
+    /// of a rotating domain
+    pub fn apply(&self, hash: [u8; 32]) -> [u8; 32] {
+        self.apply_in_round(0, hash)
+    }
+}
+
+/// Encode a message the way it is passed to the validator
+pub fn encode_message<T>(message: &wire::Aggregation<T>) -> Vec<u8>
+where
+    wire::Aggregation<T>: EncodeSize + Write,
+{
+    let mut buf = Vec::with_capacity(message.encode_size());
+    message.write(&mut buf);
+    buf
+}
+
+/// Digest signed for `message`, as validated by `validator` under `domain` hashed
+/// with `algorithm`
+///
+/// Along with [compute_execution_digest], this is the only place the node derives
+/// what it signs and what it verifies peer signatures against.
+pub async fn compute_signing_digest<T>(
+    message: &wire::Aggregation<T>,
+    validator: &dyn PayloadValidator,
+    domain: &SigningDomain,
+    algorithm: HashAlgorithm,
+) -> Result<[u8; 32]>
+where
+    wire::Aggregation<T>: EncodeSize + Write,
+{
+    let hash = validator.validate(&encode_message(message)).await?;
+    Ok(domain.apply_in_round_with(algorithm, message.round, hash))
+}
+
+/// Digest signed for the `response` an executor computed for `round`, under `domain`
+/// hashed with `algorithm`
+pub fn compute_execution_digest(
+    round: u64,
+    response: &[u8],
+    domain: &SigningDomain,
+    algorithm: HashAlgorithm,
+) -> [u8; 32] {
+    domain.apply_in_round_with(algorithm, round, algorithm.hash(response))
+}
@@ -0,0 +1,56 @@
+//! Execution of tasks whose result each contributor computes itself.
+//!
+//! A [PayloadValidator](crate::validation::PayloadValidator) checks a payload produced
+//! elsewhere, every contributor signing the same hash. Tasks such as fetching data or
+//! running a computation instead need each contributor to produce the result: an
+//! [Executor] maps the task of a Start to response bytes, whose
+//! [compute_execution_digest](crate::digest::compute_execution_digest) is signed in
+//! place of the validated hash. Contributors reaching different results sign
+//! different digests, and only shares over the same digest are aggregated.
+
+use anyhow::Result;
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Computes the response of a contributor to a task
+pub trait Executor: Send + Sync {
+    /// Response to the task of `round`, hashed into the digest signed
+    fn execute<'a>(
+        &'a self,
+        round: u64,
+        task: &'a CounterTaskData,
+    ) -> BoxFuture<'a, Result<Vec<u8>>>;
+}
+
+/// Reads the kind of a task from its metadata, `None` for tasks only validated
+pub type TaskKindReader = Arc<dyn Fn(&CounterTaskData) -> Option<String> + Send + Sync>;
+
+/// Executors by the kind of task they compute
+pub struct ExecutorRegistry {
+    reader: TaskKindReader,
+    executors: HashMap<String, Arc<dyn Executor>>,
+}
+
+impl ExecutorRegistry {
+    /// Registry without executors, reading task kinds with `reader`
+    pub fn new(reader: TaskKindReader) -> Self {
+        Self {
+            reader,
+            executors: HashMap::new(),
+        }
+    }
+
+    /// Compute tasks of `kind` with `executor`
+    pub fn with_executor(mut self, kind: impl Into<String>, executor: Arc<dyn Executor>) -> Self {
+        self.executors.insert(kind.into(), executor);
+        self
+    }
+
+    /// Executor of `task`, `None` if its kind has none and the task is only validated
+    pub fn executor_for(&self, task: &CounterTaskData) -> Option<&Arc<dyn Executor>> {
+        let kind = (self.reader)(task)?;
+        self.executors.get(&kind)
+    }
+}
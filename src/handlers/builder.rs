@@ -0,0 +1,178 @@
+use super::Contributor;
+use crate::contributor::committee::canonicalize_key;
+use crate::contributor::types::MAX_BITMAP_CONTRIBUTORS;
+use crate::contributor::{
+    AggregationInput, Contribute, ContributeError, DuplicatePolicy, canonicalize_contributors,
+};
+use crate::validation::ValidatorFactory;
+use bn254::{Bn254, PublicKey as PubKey};
+use commonware_cryptography::Signer;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+/// Reasons a [ContributorBuilder] cannot build a [Contributor]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// No orchestrator was set
+    MissingOrchestrator,
+    /// No signer was set
+    MissingSigner,
+    /// No contributors were set, or the set is empty
+    MissingContributors,
+    /// More contributors than a [ParticipationBitmap](crate::contributor::ParticipationBitmap)
+    /// can track
+    TooManyContributors(usize),
+    /// A contributor appears more than once, in any encoding
+    DuplicateContributor(PubKey),
+    /// The signer's key is not one of the contributors
+    SignerNotContributor,
+    /// The aggregation threshold is zero or above the number of contributors
+    InvalidThreshold {
+        /// Threshold set
+        threshold: usize,
+        /// Number of contributors set
+        contributors: usize,
+    },
+    /// The aggregation input has no G1 key for a contributor
+    MissingG1Key(PubKey),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MissingOrchestrator => write!(f, "orchestrator not set"),
+            BuildError::MissingSigner => write!(f, "signer not set"),
+            BuildError::MissingContributors => write!(f, "no contributors set"),
+            BuildError::TooManyContributors(contributors) => write!(
+                f,
+                "{contributors} contributors, at most {MAX_BITMAP_CONTRIBUTORS} are supported"
+            ),
+            BuildError::DuplicateContributor(key) => write!(f, "duplicate contributor: {key:?}"),
+            BuildError::SignerNotContributor => write!(f, "signer is not a contributor"),
+            BuildError::InvalidThreshold {
+                threshold,
+                contributors,
+            } => write!(
+                f,
+                "invalid threshold {threshold} for {contributors} contributors"
+            ),
+            BuildError::MissingG1Key(key) => write!(f, "missing g1 key for contributor: {key:?}"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+impl From<ContributeError> for BuildError {
+    fn from(err: ContributeError) -> Self {
+        match err {
+            ContributeError::SignerNotInContributors => BuildError::SignerNotContributor,
+        }
+    }
+}
+
+/// Assembles a [Contributor], checking its configuration before it runs
+///
+/// Options not covered here are set on the built contributor with its `with_*` methods,
+/// runtime options with [Contributor::with_run_config].
+#[derive(Default)]
+pub struct ContributorBuilder {
+    orchestrators: Vec<PubKey>,
+    signer: Option<Bn254>,
+    contributors: Option<Vec<PubKey>>,
+    aggregation: Option<AggregationInput>,
+    validator: Option<Arc<dyn ValidatorFactory>>,
+    duplicates: DuplicatePolicy,
+}
+
+impl ContributorBuilder {
+    /// Builder with nothing set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Orchestrator allowed to issue Starts, set more than one for redundancy
+    pub fn orchestrator(mut self, orchestrator: PubKey) -> Self {
+        self.orchestrators.push(orchestrator);
+        self
+    }
+
+    /// Sign our shares with `signer`
+    pub fn signer(mut self, signer: Bn254) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Contributors of the quorum, in any order, including the signer
+    pub fn contributors(mut self, contributors: Vec<PubKey>) -> Self {
+        self.contributors = Some(contributors);
+        self
+    }
+
+    /// How a contributor listed more than once is handled, rejected by default
+    pub fn duplicates(mut self, policy: DuplicatePolicy) -> Self {
+        self.duplicates = policy;
+        self
+    }
+
+    /// Aggregate signatures of peers, without it the contributor only signs
+    pub fn aggregation(mut self, aggregation: AggregationInput) -> Self {
+        self.aggregation = Some(aggregation);
+        self
+    }
+
+    /// Use a custom validator instead of the counter validator
+    pub fn validator(mut self, validator: Arc<dyn ValidatorFactory>) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Check the configuration and build the contributor
+    pub fn build(self) -> Result<Contributor, BuildError> {
+        let mut orchestrators = self.orchestrators.into_iter();
+        let orchestrator = orchestrators
+            .next()
+            .ok_or(BuildError::MissingOrchestrator)?;
+        let signer = self.signer.ok_or(BuildError::MissingSigner)?;
+        let contributors = self
+            .contributors
+            .filter(|contributors| !contributors.is_empty())
+            .ok_or(BuildError::MissingContributors)?;
+
+        let contributors = canonicalize_contributors(contributors, self.duplicates)
+            .map_err(BuildError::DuplicateContributor)?;
+        if contributors.len() > MAX_BITMAP_CONTRIBUTORS {
+            return Err(BuildError::TooManyContributors(contributors.len()));
+        }
+        if contributors
+            .binary_search(&canonicalize_key(&signer.public_key()))
+            .is_err()
+        {
+            return Err(BuildError::SignerNotContributor);
+        }
+        if let Some(aggregation) = &self.aggregation {
+            let threshold = aggregation.threshold();
+            if threshold == 0 || threshold > contributors.len() {
+                return Err(BuildError::InvalidThreshold {
+                    threshold,
+                    contributors: contributors.len(),
+                });
+            }
+            let g1_keys: HashSet<PubKey> =
+                aggregation.g1_map().keys().map(canonicalize_key).collect();
+            if let Some(key) = contributors.iter().find(|key| !g1_keys.contains(*key)) {
+                return Err(BuildError::MissingG1Key(key.clone()));
+            }
+        }
+
+        let contributor = orchestrators.fold(
+            Contributor::new(orchestrator, signer, contributors, self.aggregation)?,
+            Contributor::with_orchestrator,
+        );
+        Ok(match self.validator {
+            Some(validator) => contributor.with_validator_factory(validator),
+            None => contributor,
+        })
+    }
+}
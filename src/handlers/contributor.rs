@@ -1,28 +1,2033 @@
-use crate::contributor::types::AggregationData;
-use crate::contributor::{AggregationInput, Contribute, ContributorBase};
+use crate::chain::{
+    ClockSkew, OperatorRegistry, QuorumUpdated, RefreshTrigger, RetireRound, RoundCompletedOnChain,
+    RoundRef,
+};
+use crate::clock::{self, Clock, SystemClock};
+use crate::collections::TaskPriorityQueue;
+use crate::collections::task_queue::DEFAULT_MAX_WAIT_ROUNDS;
+use crate::contributor::aggregated::Aggregated;
+use crate::contributor::aggregation::{AggregationOutcome, aggregate_round};
+use crate::contributor::committee::{canonicalize_key, deduplicate_contributors};
+use crate::contributor::decode::{
+    DecodeFailure, MessageKind, classify, log_decode_error, try_classify,
+};
+use crate::contributor::digest_shares::{tag_digest, untag_digest};
+use crate::contributor::events::{EventSink, NoopEventSink};
+use crate::contributor::fallback::FallbackOrchestrator;
+use crate::contributor::final_aggregate::{
+    AggregateCheck, AggregateRejection, FinalAggregate, verify_final_aggregate,
+};
+use crate::contributor::liveness::StaleDetector;
+use crate::contributor::quarantine::PeerQuarantine;
+use crate::contributor::quorum_channels::{tag_share, untag_share};
+use crate::contributor::relay::{ForwardedShare, ShareRelay};
+use crate::contributor::rounds::{RoundState, RoundStatus, RoundTable, ShareRejection};
+use crate::contributor::scores::{Offense, PeerScores};
+use crate::contributor::sink::{AggregationResult, AggregationSink};
+use crate::contributor::start::SignedStart;
+use crate::contributor::sync::{SyncLog, SyncMessage, SyncResponse};
+use crate::contributor::types::{
+    AggregationData, Assignment, DroppedShare, MAX_BITMAP_CONTRIBUTORS, ParticipationBitmap,
+    assigned_contributors,
+};
+use crate::contributor::unknown_peers::UnknownPeers;
+use crate::contributor::{
+    AggregationInput, Contribute, ContributeError, ContributorBase, MessageClass, OutboundRouter,
+    SharedSigner,
+};
+use crate::crypto::aggregate_g1_iter;
+use crate::digest::{
+    HashAlgorithm, SigningDomain, compute_execution_digest, compute_signing_digest, encode_message,
+};
+use crate::execution::ExecutorRegistry;
+use crate::handlers::{ContributorBuilder, RunConfig};
+use crate::logging::Short;
+#[cfg(feature = "observability")]
+use crate::metrics::Metrics;
+use crate::p2p::watchdog::watchdog_timeout;
+use crate::pipeline::RoundPipelineController;
+use crate::types::ContributorIndex;
+use crate::validation::counter::ValidationError;
+use crate::validation::lazy::{LazyValidator, ValidatorStatus, build_with_retry};
+use crate::validation::window::SignatureWindowFilter;
+use crate::validation::{CounterValidatorFactory, PayloadValidator, ValidatorFactory};
+use crate::wire::{ContributorMessage, ContributorSig, Roles};
+use alloy_primitives::Address;
 use anyhow::Result;
 use bn254::{
-    self, Bn254 as EllipticCurve, PublicKey as PubKey, Signature as Sig, aggregate_signatures,
+    self, Bn254 as EllipticCurve, G1PublicKey, PublicKey as PubKey, Signature as Sig,
     aggregate_verify,
 };
 use bytes::Bytes;
 use commonware_avs_router::usecases::counter::creator::CounterTaskData;
-use commonware_avs_router::usecases::counter::validator::CounterValidator;
-use commonware_avs_router::validator::Validator;
 use commonware_avs_router::wire::{self, aggregation::Payload};
 use commonware_codec::{EncodeSize, ReadExt, Write};
 use commonware_cryptography::Signer;
 use commonware_p2p::{Receiver, Sender};
-use commonware_utils::hex;
-use dotenv::dotenv;
-use std::collections::{HashMap, HashSet};
-use tracing::info;
+use futures::future::BoxFuture;
+use futures::stream::FuturesUnordered;
+use futures::{FutureExt, StreamExt};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::broadcast::{
+    self,
+    error::{RecvError, TryRecvError},
+};
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::time::Instant;
+use tracing::{Instrument, Span, debug, error, info, instrument, warn};
+
+/// Time allowed to produce a signature before the round is skipped
+pub const DEFAULT_SIGNING_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Validation time above which a warning is logged
+pub const DEFAULT_SLOW_VALIDATION_THRESHOLD: Duration = Duration::from_secs(1);
+
+/// Time a share from an unknown sender is held for a contributor set update, one
+/// poll of the quorum updater
+pub const DEFAULT_UNKNOWN_SENDER_GRACE: Duration = Duration::from_secs(12);
+
+/// Shares from unknown senders held at once, the oldest is dropped beyond it
+pub const MAX_HELD_SHARES: usize = 256;
+
+/// Largest frame parsed by default, well above a full sync response
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 1024 * 1024;
+
+/// Rounds started and not yet aggregated, expired or retired at once, by default
+///
+/// Well above the rounds a healthy orchestrator has in flight within a round deadline,
+/// low enough that an orchestrator opening rounds faster than they complete cannot pin
+/// unbounded state.
+pub const DEFAULT_MAX_ACTIVE_ROUNDS: usize = 64;
+
+/// Priority of the task a Start carries, lower values are signed first
+pub type PriorityReader = Arc<dyn Fn(&wire::Aggregation<CounterTaskData>) -> u8 + Send + Sync>;
+
+/// Contributor of the counter use case, signing the Starts of its orchestrators and
+/// aggregating the shares of its peers when given [AggregationInput]
+///
+/// # Examples
+///
+/// ```
+/// use ark_bn254::Fr;
+/// use bn254::{Bn254, PrivateKey};
+/// use commonware_avs_node::handlers::{BuildError, Contributor};
+/// use commonware_cryptography::Signer;
+///
+/// let key = |seed: u64| Bn254::new(PrivateKey::from(Fr::from(seed))).unwrap();
+/// let (orchestrator, signer, peer) = (key(1), key(2), key(3));
+/// let contributors = vec![signer.public_key(), peer.public_key()];
+///
+/// let contributor = Contributor::builder()
+///     .orchestrator(orchestrator.public_key())
+///     .contributors(contributors.clone())
+///     .signer(signer)
+///     .build();
+/// assert!(contributor.is_ok());
+///
+/// let unsigned = Contributor::builder()
+///     .orchestrator(orchestrator.public_key())
+///     .contributors(contributors)
+///     .build();
+/// assert!(matches!(unsigned, Err(BuildError::MissingSigner)));
+/// ```
+pub struct Contributor {
+    orchestrators: HashSet<PubKey>,
+    signer: SharedSigner,
+    config: RunConfig,
+    signing_domain: SigningDomain,
+    payload_hash: HashAlgorithm,
+    me: ContributorIndex,
+    contributors: Vec<PubKey>,
+    assignment: Option<Assignment>,
+    aggregation_data: Option<AggregationData>,
+    quorum_id: u8,
+    tag_shares: bool,
+    events: Arc<dyn EventSink>,
+    aggregation_sink: Option<Arc<dyn AggregationSink>>,
+    validator_factory: Arc<dyn ValidatorFactory>,
+    executors: Option<ExecutorRegistry>,
+    validator_status: Arc<watch::Sender<ValidatorStatus>>,
+    committee_refresh: Option<RefreshTrigger>,
+    chain_head: Option<Arc<AtomicU64>>,
+    clock: Arc<dyn Clock>,
+    clock_skew: Option<watch::Receiver<Option<ClockSkew>>>,
+    quorum_updates: Option<broadcast::Receiver<QuorumUpdated>>,
+    retirements: Option<broadcast::Receiver<RetireRound>>,
+    completion_events: Option<broadcast::Sender<RoundCompletedOnChain>>,
+    resets: Option<mpsc::UnboundedReceiver<oneshot::Sender<RoundStateSummary>>>,
+    exports: Option<mpsc::UnboundedReceiver<ExportRequest>>,
+    registration: Option<(Arc<dyn OperatorRegistry>, HashMap<PubKey, Address>)>,
+}
+
+/// State of the receive loop
+#[derive(Default)]
+struct RunState {
+    rounds: RoundTable,
+    /// Shares from senders not yet in the contributor set, oldest first
+    held: VecDeque<HeldShare>,
+    /// Messages per sender missing from the contributor set
+    unknown_peers: UnknownPeers<PubKey>,
+    /// Shares forwarded to silent peers, when relaying
+    relay: Option<ShareRelay<PubKey>>,
+    /// Decode failures per peer
+    quarantine: PeerQuarantine<PubKey>,
+    /// Scores of the peers shares and frames are received from
+    scores: PeerScores<PubKey>,
+    pending: FuturesUnordered<BoxFuture<'static, SignedRound>>,
+    /// Contributors found registered with the AVS since the loop started
+    registered: HashSet<PubKey>,
+    /// Starts waiting to be signed, with task priorities
+    starts: TaskPriorityQueue<QueuedStart>,
+    /// Starts received while the validator is built, oldest first
+    buffered: VecDeque<BufferedStart>,
+    /// Rounds in flight, when bounded
+    pipeline: Option<RoundPipelineController>,
+    /// Liveness of the primary orchestrators, with a fallback configured
+    fallback: Option<FallbackOrchestrator>,
+    /// Contributor accepted as orchestrator while the primary is silent
+    promoted_orchestrator: Option<PubKey>,
+    /// Orchestrator that sent the Start of each round not yet aggregated
+    issuers: HashMap<u64, PubKey>,
+    /// Time since the last Start, with stale alerts configured
+    stale: Option<StaleDetector>,
+}
+
+impl RunState {
+    fn summary(&self, sync: &SyncLog, max_active_rounds: usize) -> RoundStateSummary {
+        RoundStateSummary {
+            signed: self.rounds.len(),
+            signatures: self.rounds.open(),
+            started: self.rounds.awaiting_aggregation(),
+            retired: self.rounds.retired(),
+            held_shares: self.held.len(),
+            pending_signatures: self.pending.len(),
+            sync_rounds: sync.len(),
+            active_rounds: self.rounds.active(),
+            max_active_rounds,
+        }
+    }
+}
+
+/// Start from the orchestrator waiting for its turn to be signed
+struct QueuedStart {
+    issuer: PubKey,
+    frame: Bytes,
+    message: wire::Aggregation<CounterTaskData>,
+}
+
+/// Start received before the validator was built
+struct BufferedStart {
+    issuer: PubKey,
+    frame: Bytes,
+    message: wire::Aggregation<CounterTaskData>,
+    /// End of the time the round could still be signed in
+    deadline: Instant,
+}
+
+/// Share from an unknown sender, re-evaluated after contributor set updates
+struct HeldShare {
+    share: ContributorSig,
+    digest: Option<[u8; 32]>,
+    received: Instant,
+}
+
+/// Size of the round state of a running contributor
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoundStateSummary {
+    /// Rounds signed or being signed
+    pub signed: usize,
+    /// Rounds collecting signatures
+    pub signatures: usize,
+    /// Rounds started and not retired
+    pub started: usize,
+    /// Rounds retired but still tracked
+    pub retired: usize,
+    /// Shares held for rounds not started yet
+    pub held_shares: usize,
+    /// Signatures of our own being produced
+    pub pending_signatures: usize,
+    /// Rounds kept to answer sync requests
+    pub sync_rounds: usize,
+    /// Rounds started and not yet aggregated, expired or retired
+    pub active_rounds: usize,
+    /// Active rounds beyond which Starts are rejected
+    pub max_active_rounds: usize,
+}
+
+impl RoundStateSummary {
+    /// Whether no round state is kept, whatever the limits
+    pub fn is_empty(&self) -> bool {
+        *self
+            == Self {
+                max_active_rounds: self.max_active_rounds,
+                ..Self::default()
+            }
+    }
+}
+
+/// Requests a running contributor to clear its round state
+///
+/// Each request is answered with the [RoundStateSummary] left after the reset.
+#[derive(Clone, Debug)]
+pub struct ResetHandle(mpsc::UnboundedSender<oneshot::Sender<RoundStateSummary>>);
+
+impl ResetHandle {
+    /// Clear the round state of the contributor, once it handled the messages before
+    pub async fn reset(&self) -> Result<RoundStateSummary> {
+        let (reply, summary) = oneshot::channel();
+        self.0
+            .send(reply)
+            .map_err(|_| anyhow::anyhow!("contributor stopped"))?;
+        Ok(summary.await?)
+    }
+}
+
+/// Request for the signatures collected for a round, answered by the receive loop
+type ExportRequest = (u64, oneshot::Sender<Vec<(PubKey, Sig)>>);
+
+/// Reads the individual signatures a running contributor verified for a round
+///
+/// Lets external tools aggregate a round independently or compare it across nodes.
+#[derive(Clone, Debug)]
+pub struct SignatureExport(mpsc::UnboundedSender<ExportRequest>);
+
+impl SignatureExport {
+    /// Verified signatures of `round` with their signer, ordered by contributor index
+    ///
+    /// Includes our own signature. Empty once the round is retired or unknown.
+    pub async fn collected_signatures(&self, round: u64) -> Result<Vec<(PubKey, Sig)>> {
+        let (reply, signatures) = oneshot::channel();
+        self.0
+            .send((round, reply))
+            .map_err(|_| anyhow::anyhow!("contributor stopped"))?;
+        Ok(signatures.await?)
+    }
+}
+
+/// Outcome of signing a round off the receive loop
+struct SignedRound {
+    round: u64,
+    /// Orchestrator that sent the Start, unknown for rounds caught up from peers
+    issuer: Option<PubKey>,
+    metadata: CounterTaskData,
+    signature: Result<Sig>,
+}
+
+impl Contributor {
+    /// Builder checking the configuration of a contributor
+    pub fn builder() -> ContributorBuilder {
+        ContributorBuilder::new()
+    }
+
+    /// Restrict the expected signers of each round with an assignment function
+    pub fn with_assignment(mut self, assignment: Assignment) -> Self {
+        self.assignment = Some(assignment);
+        self
+    }
+
+    /// Also accept Starts from `orchestrator`, for deployments running several
+    pub fn with_orchestrator(mut self, orchestrator: PubKey) -> Self {
+        self.orchestrators.insert(orchestrator);
+        self
+    }
+
+    /// Set the quorum this contributor signs for, used to label metrics
+    pub fn with_quorum(mut self, quorum_id: u8) -> Self {
+        self.quorum_id = quorum_id;
+        self
+    }
+
+    /// Record round metrics into the given (registered) metrics
+    #[cfg(feature = "observability")]
+    pub fn with_metrics(self, metrics: Metrics) -> Self {
+        self.with_event_sink(Arc::new(metrics))
+    }
+
+    /// Report round events to `sink`, replacing the sink set before
+    pub fn with_event_sink(mut self, sink: Arc<dyn EventSink>) -> Self {
+        self.events = sink;
+        self
+    }
+
+    /// Hand every aggregate to `sink`, aggregates are only logged by default
+    pub fn with_aggregation_sink(mut self, sink: Arc<dyn AggregationSink>) -> Self {
+        self.aggregation_sink = Some(sink);
+        self
+    }
+
+    /// Use a custom validator instead of the counter validator
+    pub fn with_validator_factory(mut self, factory: Arc<dyn ValidatorFactory>) -> Self {
+        self.validator_factory = factory;
+        self
+    }
+
+    /// Compute tasks of the kinds in `executors` instead of only validating them
+    ///
+    /// Their rounds sign the digest of our own response, and only shares over the same
+    /// digest aggregate. Such shares are tagged with their digest, relaying them is not
+    /// supported.
+    pub fn with_executors(mut self, executors: ExecutorRegistry) -> Self {
+        self.executors = Some(executors);
+        self
+    }
+
+    /// Apply the runtime options of `config` once the receive loop starts
+    pub fn with_run_config(mut self, config: RunConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Progress of the validator construction, `Ready` once Starts are signed
+    pub fn validator_status(&self) -> watch::Receiver<ValidatorStatus> {
+        self.validator_status.subscribe()
+    }
+
+    /// Ask for a committee refresh through `trigger` when an unknown sender shares, in
+    /// case it was just registered. Asked at most once per refresh interval per sender, see
+    /// [UnknownPeerConfig](crate::contributor::unknown_peers::UnknownPeerConfig)
+    pub fn with_committee_refresh(mut self, trigger: RefreshTrigger) -> Self {
+        self.committee_refresh = Some(trigger);
+        self
+    }
+
+    /// Latest known block, used to bound the reference block of a Start
+    pub fn with_chain_head(mut self, head: Arc<AtomicU64>) -> Self {
+        self.chain_head = Some(head);
+        self
+    }
+
+    /// Read time from `clock`, for metadata deadlines, round deadlines and timeouts
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Tolerate the clock skew estimated by `skew` in metadata deadline checks
+    ///
+    /// A deadline is only considered passed, or too far, if it is so however the local
+    /// clock is off by the estimated skew.
+    pub fn with_clock_skew(mut self, skew: watch::Receiver<Option<ClockSkew>>) -> Self {
+        self.clock_skew = Some(skew);
+        self
+    }
+
+    /// Sign with a remote or otherwise slow signer instead of the local key
+    ///
+    /// Fails if the signer does not hold the key the contributor was created with.
+    pub fn with_signer(mut self, signer: SharedSigner) -> Result<Self, ContributeError> {
+        if signer.public_key() != self.signer.public_key() {
+            return Err(ContributeError::SignerNotInContributors);
+        }
+        self.signer = signer;
+        Ok(self)
+    }
+
+    /// Apply domain separation to every digest signed or verified
+    pub fn with_signing_domain(mut self, domain: SigningDomain) -> Self {
+        self.signing_domain = domain;
+        self
+    }
+
+    /// Hash signed payloads with `algorithm` instead of `keccak256`
+    ///
+    /// Applies to the signing domain and to executed responses. The validator hashes
+    /// the message itself and must be configured alike, see
+    /// [with_hash](crate::validation::counter::InMemoryCounterValidatorFactory::with_hash).
+    pub fn with_payload_hash(mut self, algorithm: HashAlgorithm) -> Self {
+        self.payload_hash = algorithm;
+        self
+    }
+
+    /// Tag shares with our quorum, for peers dispatching quorums over their channels
+    pub fn with_tagged_shares(mut self) -> Self {
+        self.tag_shares = true;
+        self
+    }
+
+    /// Apply quorum membership changes, checked before every received message
+    pub fn with_quorum_updates(mut self, updates: broadcast::Receiver<QuorumUpdated>) -> Self {
+        self.quorum_updates = Some(updates);
+        self
+    }
+
+    /// Retire rounds once their response is submitted on-chain
+    pub fn with_retirements(mut self, retirements: broadcast::Receiver<RetireRound>) -> Self {
+        self.retirements = Some(retirements);
+        self
+    }
+
+    /// Emit a [RoundCompletedOnChain] for every retired round
+    pub fn with_completion_events(
+        mut self,
+        events: broadcast::Sender<RoundCompletedOnChain>,
+    ) -> Self {
+        self.completion_events = Some(events);
+        self
+    }
+
+    /// Only count shares of contributors registered with the AVS according to `registry`
+    ///
+    /// Each contributor is checked on its first share, by the operator address listed
+    /// for its key in `operators`. Contributors without an address are never counted.
+    pub fn with_registration_checker(
+        mut self,
+        registry: Arc<dyn OperatorRegistry>,
+        operators: HashMap<PubKey, Address>,
+    ) -> Self {
+        let operators = operators
+            .into_iter()
+            .map(|(key, address)| (canonicalize_key(&key), address))
+            .collect();
+        self.registration = Some((registry, operators));
+        self
+    }
+
+    /// Handle clearing the round state of this contributor once it runs
+    pub fn reset_handle(&mut self) -> ResetHandle {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.resets = Some(receiver);
+        ResetHandle(sender)
+    }
+
+    /// Handle exporting the signatures this contributor collects once it runs
+    pub fn signature_export(&mut self) -> SignatureExport {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.exports = Some(receiver);
+        SignatureExport(sender)
+    }
+
+    /// G1 keys of the operators this contributor aggregates for, sorted by G2 key
+    ///
+    /// Empty when the contributor does not aggregate.
+    pub fn g1_operators(&self) -> Vec<(PubKey, G1PublicKey)> {
+        let Some(data) = &self.aggregation_data else {
+            return Vec::new();
+        };
+        let mut operators: Vec<_> = data
+            .g1_map
+            .iter()
+            .map(|(key, g1)| (key.clone(), g1.clone()))
+            .collect();
+        operators.sort_by(|(a, _), (b, _)| a.cmp(b));
+        operators
+    }
+
+    /// Add and remove contributors after a quorum membership change
+    ///
+    /// Returns `false`, leaving the set unchanged, if the update is for another quorum
+    /// or would grow the set past [MAX_BITMAP_CONTRIBUTORS].
+    pub fn update_contributor_set(&mut self, update: &QuorumUpdated) -> bool {
+        if update.quorum_id != self.quorum_id {
+            return false;
+        }
+        let removed: HashSet<PubKey> = update.removed.iter().map(canonicalize_key).collect();
+        let contributors = self
+            .contributors
+            .iter()
+            .filter(|contributor| !removed.contains(*contributor))
+            .chain(&update.added)
+            .cloned();
+        let contributors = deduplicate_contributors(contributors);
+        if contributors.len() > MAX_BITMAP_CONTRIBUTORS {
+            error!(
+                quorum_id = update.quorum_id,
+                contributors = contributors.len(),
+                max = MAX_BITMAP_CONTRIBUTORS,
+                "quorum update exceeds the participation bitmap, not applying it"
+            );
+            return false;
+        }
+        self.contributors = contributors;
+        match self.contributors.binary_search(&self.own_key()) {
+            Ok(me) => self.me = me.into(),
+            Err(_) => warn!("removed from quorum: {}", update.quorum_id),
+        }
+
+        if let Some(data) = self.aggregation_data.as_mut() {
+            for removed in &removed {
+                data.g1_map.remove(removed);
+            }
+            for (key, g1) in &update.g1_keys {
+                data.g1_map.insert(canonicalize_key(key), g1.clone());
+            }
+            data.contributors = self.contributors.clone();
+            data.ordered_contributors = self
+                .contributors
+                .iter()
+                .enumerate()
+                .map(|(idx, contributor)| (contributor.clone(), idx.into()))
+                .collect();
+        }
+        info!(
+            quorum_id = update.quorum_id,
+            contributors = self.contributors.len(),
+            "updated contributor set"
+        );
+        if !self.own_index_valid() {
+            error!(
+                quorum_id = update.quorum_id,
+                me = %self.me,
+                "own index does not match signer key after set update, refusing to sign"
+            );
+        }
+        true
+    }
+
+    /// Whether `me` still points at the signer's key in the sorted contributors
+    ///
+    /// A stale index would attribute our signatures to another contributor.
+    fn own_index_valid(&self) -> bool {
+        self.contributors.get(usize::from(self.me)) == Some(&self.own_key())
+    }
+
+    /// Key, signature or hash logged under the configured policy
+    fn short<'a, T: AsRef<[u8]> + ?Sized>(&self, value: &'a T) -> Short<'a> {
+        self.config.log_policy.short(value)
+    }
+
+    /// Sets the senders of aggregation messages are classified against
+    fn roles(&self) -> Roles<'_> {
+        Roles {
+            orchestrators: &self.orchestrators,
+            contributors: self
+                .aggregation_data
+                .as_ref()
+                .map(|data| &data.ordered_contributors),
+        }
+    }
+
+    /// The signer's key in the canonical encoding contributors are indexed by
+    fn own_key(&self) -> PubKey {
+        canonicalize_key(&self.signer.public_key())
+    }
+
+    /// Apply pending quorum updates, returning whether any was applied
+    fn apply_quorum_updates(
+        &mut self,
+        updates: &mut broadcast::Receiver<QuorumUpdated>,
+        state: &mut RunState,
+    ) -> bool {
+        let mut applied = false;
+        loop {
+            let update = match updates.try_recv() {
+                Ok(update) => update,
+                Err(TryRecvError::Lagged(missed)) => {
+                    warn!(missed, "missed quorum updates");
+                    continue;
+                }
+                Err(TryRecvError::Empty | TryRecvError::Closed) => return applied,
+            };
+            self.apply_quorum_update(&update, state);
+            applied = true;
+        }
+    }
+
+    /// Apply a quorum update, re-indexing signatures of in-flight rounds
+    fn apply_quorum_update(&mut self, update: &QuorumUpdated, state: &mut RunState) {
+        let previous = self.contributors.clone();
+        if !self.update_contributor_set(update) {
+            return;
+        }
+        for added in &update.added {
+            state.unknown_peers.remove(&canonicalize_key(added));
+        }
+        state.rounds.reindex(|idx| {
+            let contributor = previous.get(idx)?;
+            self.contributors.binary_search(contributor).ok()
+        });
+    }
+
+    /// Hold a share from a sender missing from the contributor set, in case a set update
+    /// adding it is about to land
+    fn hold_share(&self, state: &mut RunState, share: ContributorSig, digest: Option<[u8; 32]>) {
+        self.note_unknown_sender(state, &share.sender, share.message.round);
+        if self.config.unknown_sender_grace.is_zero() {
+            self.events
+                .share_dropped(self.quorum_id, DroppedShare::UnknownSender.kind());
+            return;
+        }
+        let now = self.clock.monotonic_now();
+        self.expire_held_shares(state, now);
+        if state.held.len() >= MAX_HELD_SHARES {
+            state.held.pop_front();
+            self.events
+                .share_dropped(self.quorum_id, DroppedShare::UnknownSender.kind());
+        }
+        debug!(
+            round = share.message.round,
+            sender = %self.short(&share.sender),
+            "holding share from unknown sender"
+        );
+        state.held.push_back(HeldShare {
+            share,
+            digest,
+            received: now,
+        });
+    }
+
+    /// Count a message from a sender missing from the contributor set, warning on its
+    /// first one and asking for a committee refresh if due
+    fn note_unknown_sender(&self, state: &mut RunState, sender: &PubKey, round: u64) {
+        let sighting = state
+            .unknown_peers
+            .record(sender, self.clock.monotonic_now());
+        if sighting.is_first() {
+            warn!(round, sender = %self.short(sender), "message from peer not in contributor set");
+        } else {
+            debug!(
+                round,
+                sender = %self.short(sender),
+                messages = sighting.messages,
+                "message from peer not in contributor set"
+            );
+        }
+        if sighting.refresh
+            && let Some(trigger) = &self.committee_refresh
+        {
+            debug!(sender = %self.short(sender), "checking whether unknown peer was registered");
+            trigger.notify();
+        }
+    }
+
+    /// Drop held shares older than the grace period
+    fn expire_held_shares(&self, state: &mut RunState, now: Instant) {
+        while state.held.front().is_some_and(|held| {
+            now.duration_since(held.received) > self.config.unknown_sender_grace
+        }) {
+            let Some(held) = state.held.pop_front() else {
+                break;
+            };
+            self.events
+                .share_dropped(self.quorum_id, DroppedShare::UnknownSender.kind());
+            info!(
+                round = held.share.message.round,
+                sender = %self.short(&held.share.sender),
+                "contributor not found within grace period"
+            );
+        }
+    }
+
+    /// Collect the held shares whose sender joined the contributor set
+    async fn release_held_shares<S>(
+        &self,
+        state: &mut RunState,
+        router: &mut OutboundRouter<S>,
+        sync: &mut SyncLog,
+        validator: &dyn PayloadValidator,
+    ) where
+        S: Sender<PublicKey = PubKey>,
+    {
+        self.expire_held_shares(state, self.clock.monotonic_now());
+        let held = std::mem::take(&mut state.held);
+        for held in held {
+            if self.get_contributor_index(&held.share.sender).is_none() {
+                state.held.push_back(held);
+                continue;
+            }
+            debug!(
+                round = held.share.message.round,
+                sender = %self.short(&held.share.sender),
+                "releasing held share"
+            );
+            let from = held.share.sender.clone();
+            self.collect_share(
+                state,
+                router,
+                sync,
+                validator,
+                held.share,
+                &from,
+                held.digest,
+            )
+            .await;
+        }
+    }
+
+    /// Clear every round, returning the node to the state it started in
+    ///
+    /// Signatures still being produced are dropped. Contributor set updates and peer
+    /// quarantines are kept, they do not depend on rounds.
+    fn reset(&self, state: &mut RunState, sync: &mut SyncLog) -> RoundStateSummary {
+        let cleared = state.summary(sync, self.config.max_active_rounds);
+        state.rounds.clear();
+        state.issuers.clear();
+        state.held.clear();
+        state.starts.clear();
+        state.buffered.clear();
+        if let Some(pipeline) = state.pipeline.as_mut() {
+            pipeline.clear();
+        }
+        if let Some(relay) = state.relay.as_mut() {
+            relay.clear();
+        }
+        state.pending = FuturesUnordered::new();
+        sync.clear();
+        info!(?cleared, "reset round state");
+        state.summary(sync, self.config.max_active_rounds)
+    }
+
+    /// Signatures collected for `round` with the key of each contributor
+    fn collected_signatures(&self, state: &RunState, round: u64) -> Vec<(PubKey, Sig)> {
+        let Some(round_state) = state.rounds.get(round) else {
+            return Vec::new();
+        };
+        round_state
+            .shares
+            .iter()
+            .filter_map(|(index, signature)| {
+                let contributor = self.contributors.get(*index)?;
+                Some((contributor.clone(), signature.clone()))
+            })
+            .collect()
+    }
+
+    /// Drop the state of a round whose response was submitted on-chain
+    fn retire_round(&self, state: &mut RunState, sync: &mut SyncLog, retire: RetireRound) {
+        let round = match retire.round {
+            RoundRef::Round(round) => round,
+            RoundRef::PayloadHash(payload_hash) => match sync.round_of_payload(&payload_hash) {
+                Some(round) => round,
+                None => {
+                    debug!(
+                        payload_hash = %self.short(&payload_hash),
+                        "completed task not signed by this node"
+                    );
+                    return;
+                }
+            },
+        };
+        let Some(dropped_signatures) = state.rounds.retire(round) else {
+            return;
+        };
+        sync.mark_completed_on_chain(round);
+        self.events.round_completed_on_chain(self.quorum_id);
+        info!(
+            round,
+            transaction_hash = %retire.transaction_hash,
+            dropped_signatures,
+            "round completed on-chain"
+        );
+        if let Some(events) = &self.completion_events {
+            // No subscribers is not an error, the event is simply dropped
+            let _ = events.send(RoundCompletedOnChain {
+                round,
+                transaction_hash: retire.transaction_hash,
+                dropped_signatures,
+            });
+        }
+    }
+
+    /// Unwrap a received Start frame, verifying the orchestrator's signature if it
+    /// carries one
+    ///
+    /// Returns the encoded Start, or `None` if the signature is invalid or the Start is
+    /// unsigned while signed Starts are required.
+    fn open_start(&self, sender: &PubKey, frame: &Bytes) -> Option<Bytes> {
+        if !SignedStart::is_signed_start(frame) {
+            if self.config.require_signed_starts {
+                warn!(sender = %self.short(sender), "unsigned start, not signing");
+                return None;
+            }
+            return Some(frame.clone());
+        }
+        let signed = match SignedStart::read(&mut std::io::Cursor::new(&frame[..])) {
+            Ok(signed) => signed,
+            Err(err) => {
+                log_decode_error(self.config.log_policy, sender, "signed start", frame, &err);
+                return None;
+            }
+        };
+        if !self
+            .orchestrators
+            .iter()
+            .any(|orchestrator| signed.verify(orchestrator))
+        {
+            warn!(
+                sender = %self.short(sender),
+                "start not signed by orchestrator, not signing"
+            );
+            return None;
+        }
+        Some(signed.start)
+    }
+
+    /// Report a share of `contributor` the round table did not record
+    fn log_rejected_share(&self, round: u64, contributor: usize, rejection: ShareRejection) {
+        match rejection {
+            ShareRejection::Dropped(dropped) => {
+                self.events.share_dropped(self.quorum_id, dropped.kind());
+                info!(round, reason = dropped.kind(), "dropped share");
+            }
+            ShareRejection::Duplicate => {
+                self.events.duplicate_share(self.quorum_id);
+                info!("contributor already signed: {:?}", contributor);
+            }
+        }
+    }
+
+    /// Check `sender` is registered with the AVS, once per run of the receive loop
+    ///
+    /// Only registered contributors are remembered, an unregistered one is checked again
+    /// on its next share.
+    async fn check_registration(&self, state: &mut RunState, sender: &PubKey) -> Result<()> {
+        let Some((registry, operators)) = &self.registration else {
+            return Ok(());
+        };
+        if state.registered.contains(sender) {
+            return Ok(());
+        }
+        let Some(operator) = operators.get(sender) else {
+            return Err(ValidationError::OperatorNotRegistered(None).into());
+        };
+        if !registry.is_registered(*operator).await? {
+            return Err(ValidationError::OperatorNotRegistered(Some(*operator)).into());
+        }
+        state.registered.insert(sender.clone());
+        Ok(())
+    }
+
+    /// Count a frame or signature from `peer` that failed to decode, quarantining the peer
+    /// once it sent too many
+    fn record_decode_failure(&self, state: &mut RunState, peer: &PubKey, reason: &str) {
+        if self.is_orchestrator(peer) {
+            return;
+        }
+        self.events.decode_failed(self.quorum_id, reason);
+        self.penalize_peer(state, peer, Offense::Malformed);
+        if state
+            .quarantine
+            .record_failure(peer, self.clock.monotonic_now())
+        {
+            self.events.peer_quarantined(self.quorum_id);
+            warn!(
+                peer = %self.short(peer),
+                reason,
+                "quarantined peer after repeated decode failures"
+            );
+        }
+    }
+
+    /// Lower the score of `peer` for `offense`, ignoring the peer once it scores too low
+    fn penalize_peer(&self, state: &mut RunState, peer: &PubKey, offense: Offense) {
+        if self.is_orchestrator(peer) {
+            return;
+        }
+        if state
+            .scores
+            .penalize(peer, offense, self.clock.monotonic_now())
+        {
+            self.events.peer_ignored(self.quorum_id);
+            warn!(
+                peer = %self.short(peer),
+                score = state.scores.score(peer),
+                "ignoring peer with a low score"
+            );
+        }
+    }
+
+    /// Time a round accepts contributions, from its task's block window if known
+    fn round_deadline(&self, metadata: &CounterTaskData) -> Duration {
+        let derived = self.config.block_window.and_then(|window| {
+            let reference_block = self.reference_block(metadata)?;
+            let head = self.chain_head.as_ref()?.load(Ordering::Relaxed);
+            Some(window.deadline(reference_block, head))
+        });
+        derived.unwrap_or(self.config.sync.round_deadline)
+    }
+
+    /// Reference block of a task, read with the reader of the metadata policy
+    fn reference_block(&self, metadata: &CounterTaskData) -> Option<u64> {
+        let (_, reader) = self.config.metadata_policy.as_ref()?;
+        reader(metadata).reference_block
+    }
+
+    /// Block window in which shares of `round` are accepted, with a block window and
+    /// chain head configured and the reference block of the round known
+    fn signature_window(&self, state: &RunState, round: u64) -> Option<SignatureWindowFilter> {
+        let window = self.config.block_window?;
+        let head = self.chain_head.clone()?;
+        let start_block = state.rounds.get(round)?.start_block?;
+        Some(SignatureWindowFilter::new(
+            start_block,
+            window.response_window_blocks,
+            head,
+        ))
+    }
+
+    /// Signing digest of a round message, recording how long validation took
+    async fn validate(
+        &self,
+        validator: &dyn PayloadValidator,
+        message: &wire::Aggregation<CounterTaskData>,
+    ) -> Result<[u8; 32]> {
+        let round = message.round;
+        let start = self.clock.monotonic_now();
+        let result =
+            compute_signing_digest(message, validator, &self.signing_domain, self.payload_hash)
+                .await;
+        let elapsed = self.clock.elapsed(start);
+        self.events.observe_validation(self.quorum_id, elapsed);
+        if result.is_err() {
+            self.events.validation_failed(self.quorum_id);
+        }
+        if elapsed > self.config.slow_validation_threshold {
+            warn!(
+                round,
+                ?elapsed,
+                threshold = ?self.config.slow_validation_threshold,
+                "slow validation"
+            );
+        }
+        result
+    }
+
+    /// Validate a Start and start signing its payload.
+    ///
+    /// The signature is produced off the receive loop and sent once ready, to `issuer`
+    /// or every orchestrator if unknown. Tasks with an executor sign the digest of our
+    /// response instead of the validated payload hash. Returns the hash signed, or `None`
+    /// if the round was already signed, too many rounds are active, its metadata was
+    /// rejected, it failed validation or its task failed to execute.
+    #[instrument(skip_all, fields(round = message.round))]
+    async fn sign_start(
+        &self,
+        state: &mut RunState,
+        sync: &mut SyncLog,
+        validator: &dyn PayloadValidator,
+        issuer: Option<PubKey>,
+        frame: Bytes,
+        message: wire::Aggregation<CounterTaskData>,
+    ) -> Option<[u8; 32]> {
+        let round = message.round;
+
+        // Check metadata before spending a validation on the round
+        if let Some((policy, reader)) = &self.config.metadata_policy {
+            let head = self
+                .chain_head
+                .as_ref()
+                .map(|head| head.load(Ordering::Relaxed));
+            let tolerance = self
+                .clock_skew
+                .as_ref()
+                .and_then(|skew| *skew.borrow())
+                .map_or(Duration::ZERO, |skew| skew.magnitude());
+            let metadata = reader(&message.metadata);
+            if let Err(violation) =
+                policy.check_with_tolerance(&metadata, self.clock.now(), head, tolerance)
+            {
+                self.events
+                    .metadata_rejected(self.quorum_id, violation.kind());
+                warn!(
+                    round,
+                    reason = violation.kind(),
+                    violation = %self.config.log_policy.redacted(&violation),
+                    "rejected start metadata"
+                );
+                return None;
+            }
+        }
+
+        if !self.own_index_valid() {
+            error!(
+                round,
+                me = %self.me,
+                "own index does not match signer key, not signing"
+            );
+            return None;
+        }
+
+        // Check if already signed at round
+        if state.rounds.is_signed(round) {
+            info!("already signed at round: {:?}", round);
+            return None;
+        }
+        if !self.has_capacity(state) {
+            self.events.round_rejected_capacity(self.quorum_id);
+            warn!(
+                round,
+                max_active = self.config.max_active_rounds,
+                "too many active rounds, rejecting start"
+            );
+            return None;
+        }
+        // A Start failing validation is skipped, it must not stop the node
+        let mut payload = match self.validate(validator, &message).await {
+            Ok(payload) => payload,
+            Err(err) => {
+                warn!(
+                    round,
+                    err = %self.config.log_policy.redacted(&err),
+                    "failed to validate start, not signing"
+                );
+                return None;
+            }
+        };
+
+        // Tasks with an executor sign the digest of our own response
+        let executor = self
+            .executors
+            .as_ref()
+            .and_then(|executors| executors.executor_for(&message.metadata));
+        if let Some(executor) = executor {
+            let timeout = self.config.signing_timeout;
+            let executed = clock::timeout(
+                self.clock.as_ref(),
+                timeout,
+                executor.execute(round, &message.metadata),
+            )
+            .await
+            .unwrap_or_else(|| Err(anyhow::anyhow!("execution timed out after {timeout:?}")));
+            let response = match executed {
+                Ok(response) => response,
+                Err(err) => {
+                    self.events.execution_failed(self.quorum_id);
+                    warn!(round, ?err, "failed to execute task, not signing");
+                    return None;
+                }
+            };
+            payload =
+                compute_execution_digest(round, &response, &self.signing_domain, self.payload_hash);
+        }
+        info!(
+            "Generating signature for round: {}, payload hash: {}",
+            round,
+            self.short(&payload)
+        );
+        self.events.round_started(self.quorum_id);
+
+        // Accept signatures from peers while ours is being produced
+        let deadline = self.clock.monotonic_now() + self.round_deadline(&message.metadata);
+        state.rounds.start_round(round, payload, Some(deadline));
+        if executor.is_some() {
+            state.rounds.mark_executed(round);
+        }
+        if let Some(issuer) = &issuer {
+            state.issuers.insert(round, issuer.clone());
+        }
+        if let Some(round_state) = state.rounds.get_mut(round) {
+            round_state.start_block = self.reference_block(&message.metadata);
+        }
+        if let Some(fallback) = state.fallback.as_mut() {
+            fallback.observe_round(round);
+        }
+        sync.record_start_until(round, frame, payload, deadline);
+        self.admit_round(state, round);
+
+        let signer = self.signer.clone();
+        let clock = self.clock.clone();
+        let timeout = self.config.signing_timeout;
+        let metadata = message.metadata;
+        // Polled by the run loop, so signing logs keep the span of this round explicitly
+        let signing = async move {
+            let signed = clock::timeout(clock.as_ref(), timeout, signer.sign(None, &payload)).await;
+            let signature = match signed {
+                Some(signature) => signature,
+                None => Err(anyhow::anyhow!("signing timed out after {timeout:?}")),
+            };
+            SignedRound {
+                round,
+                issuer,
+                metadata,
+                signature,
+            }
+        };
+        state
+            .pending
+            .push(Box::pin(signing.instrument(Span::current())));
+        Some(payload)
+    }
+
+    /// Start `round` as the promoted orchestrator: broadcast its Start, then sign it
+    #[instrument(skip_all, fields(round = round))]
+    async fn start_round<S>(
+        &self,
+        state: &mut RunState,
+        sync: &mut SyncLog,
+        router: &mut OutboundRouter<S>,
+        validator: &dyn PayloadValidator,
+        round: u64,
+        task_data: CounterTaskData,
+    ) -> Result<()>
+    where
+        S: Sender<PublicKey = PubKey>,
+    {
+        let message = wire::Aggregation {
+            round,
+            metadata: task_data,
+            payload: Some(Payload::Start),
+        };
+        let frame = Bytes::from(encode_message(&message));
+        info!(round, "starting round as fallback orchestrator");
+        router
+            .send(MessageClass::Start, &self.own_key(), frame.clone())
+            .await?;
+        self.sign_start(state, sync, validator, None, frame, message)
+            .await;
+        Ok(())
+    }
+
+    /// Promote the fallback once the primary orchestrators are silent, and start the
+    /// next round if we were promoted
+    async fn run_fallback<S>(
+        &mut self,
+        state: &mut RunState,
+        sync: &mut SyncLog,
+        router: &mut OutboundRouter<S>,
+        validator: &LazyValidator,
+    ) -> Result<()>
+    where
+        S: Sender<PublicKey = PubKey>,
+    {
+        self.promote_if_silent(state);
+        let now = self.clock.monotonic_now();
+        let Some(round) = state
+            .fallback
+            .as_mut()
+            .and_then(|fallback| fallback.next_round(now))
+        else {
+            return Ok(());
+        };
+        if !validator.is_ready() {
+            info!(round, "validator initializing, not starting round");
+            return Ok(());
+        }
+        self.start_round(state, sync, router, validator, round, Default::default())
+            .await
+    }
+
+    /// Accept the fallback as orchestrator once the primary orchestrators are silent
+    ///
+    /// Also checked on every frame, so a Start from the fallback is not dropped because
+    /// our own timer has yet to fire.
+    fn promote_if_silent(&mut self, state: &mut RunState) {
+        let now = self.clock.monotonic_now();
+        let own_key = self.own_key();
+        let Some(promotion) = state
+            .fallback
+            .as_mut()
+            .and_then(|fallback| fallback.check_silence(&self.contributors, &own_key, now))
+        else {
+            return;
+        };
+        warn!(
+            key = %self.short(&promotion.key),
+            promoted = promotion.is_self,
+            "orchestrator silent, promoting fallback"
+        );
+        if self.orchestrators.insert(promotion.key.clone()) {
+            state.promoted_orchestrator = Some(promotion.key);
+        }
+    }
+
+    /// End the promotion of the fallback once a primary orchestrator is heard again
+    fn heard_from(&mut self, state: &mut RunState, sender: &PubKey) {
+        let Some(fallback) = state.fallback.as_mut() else {
+            return;
+        };
+        if !self.is_orchestrator(sender) || state.promoted_orchestrator.as_ref() == Some(sender) {
+            return;
+        }
+        if fallback.heard_primary(self.clock.monotonic_now()).is_some() {
+            if let Some(key) = state.promoted_orchestrator.take() {
+                self.orchestrators.remove(&key);
+            }
+            info!(sender = %self.short(sender), "orchestrator back, fallback demoted");
+        }
+    }
+
+    /// Alert once the orchestrators sent no Start for longer than the stale threshold
+    fn alert_if_stale(&self, state: &mut RunState) {
+        let now = self.clock.monotonic_now();
+        let Some(stale) = state.stale.as_mut().and_then(|stale| stale.check(now)) else {
+            return;
+        };
+        warn!(
+            silent_for = ?stale.silent_for,
+            last_round = ?stale.last_round,
+            "no start from orchestrator, orchestrator may be stale"
+        );
+        self.events.orchestrator_stale(self.quorum_id);
+        if let Some((_, events)) = &self.config.stale_alerts {
+            // No subscribers is not an error, the event is simply dropped
+            let _ = events.send(stale);
+        }
+    }
+
+    /// Whether another round can start, expiring active rounds past their deadline
+    fn has_capacity(&self, state: &mut RunState) -> bool {
+        if state.rounds.active() < self.config.max_active_rounds {
+            return true;
+        }
+        for round in state.rounds.overdue(self.clock.monotonic_now()) {
+            self.time_out_overdue(state, round);
+        }
+        state.rounds.active() < self.config.max_active_rounds
+    }
+
+    /// Give `round` a pipeline slot, preempting the oldest round in flight if none is free
+    fn admit_round(&self, state: &mut RunState, round: u64) {
+        let Some(pipeline) = state.pipeline.as_mut() else {
+            return;
+        };
+        let rounds = &state.rounds;
+        pipeline.retain(|round| {
+            matches!(
+                rounds.status(round),
+                Some(RoundStatus::Signing | RoundStatus::Signed)
+            )
+        });
+        if let Some(preempted) = pipeline.admit(round) {
+            state.rounds.expire(preempted.round);
+            self.events.round_preempted(self.quorum_id);
+            warn!(
+                round = preempted.round,
+                by = preempted.by,
+                "preempted round in flight"
+            );
+        }
+    }
+
+    /// Expire `round` if its deadline passed, returning whether it did
+    ///
+    /// Rounds still collecting shares are reported as timed out the first time.
+    fn time_out_overdue(&self, state: &mut RunState, round: u64) -> bool {
+        if !state
+            .rounds
+            .deadline_passed(round, self.clock.monotonic_now())
+        {
+            return false;
+        }
+        if matches!(
+            state.rounds.status(round),
+            Some(RoundStatus::Signing | RoundStatus::Signed)
+        ) {
+            state.rounds.expire(round);
+            self.events.round_timed_out(self.quorum_id);
+            info!(round, "round timed out");
+        }
+        true
+    }
+
+    /// Store our signature for a round and send it to the orchestrator and peers
+    #[instrument(skip_all, fields(round = signed.round))]
+    async fn send_signature<S>(
+        &self,
+        state: &mut RunState,
+        router: &mut OutboundRouter<S>,
+        signed: SignedRound,
+    ) -> Result<()>
+    where
+        S: Sender<PublicKey = PubKey>,
+    {
+        let round = signed.round;
+        if state.rounds.is_retired(round) {
+            info!(round, "round completed on-chain, not sending signature");
+            return Ok(());
+        }
+        if self.time_out_overdue(state, round) {
+            info!(round, "round deadline passed, not sending signature");
+            return Ok(());
+        }
+        let signature = match signed.signature {
+            Ok(signature) => signature,
+            Err(err) => {
+                warn!(round, ?err, "failed to sign, skipping round");
+                state.rounds.abort(round);
+                return Ok(());
+            }
+        };
+        self.events.round_signed(self.quorum_id);
+
+        // Store signature
+        state
+            .rounds
+            .record_own_signature(round, self.me.into(), signature.clone());
+
+        // Return signature to orchestrator
+        let message = wire::Aggregation::<CounterTaskData> {
+            round,
+            metadata: signed.metadata,
+            payload: Some(Payload::Signature(signature.to_vec())),
+        };
+        let mut buf = Bytes::from(encode_message(&message));
+        info!("Sending signature for round: {}", round);
+
+        // Peers cannot derive the digest of an executed round, it travels with the share
+        if let Some(round_state) = state.rounds.get(round).filter(|state| state.executed)
+            && let Some(digest) = round_state.expected_hash
+        {
+            buf = tag_digest(&digest, &buf);
+        }
+
+        // Reply to the orchestrator and share with peers (a single broadcast by default)
+        let orchestrators = match signed.issuer {
+            Some(issuer) => vec![issuer],
+            None => self.orchestrators.iter().cloned().collect(),
+        };
+        for orchestrator in &orchestrators {
+            router
+                .send(MessageClass::Reply, orchestrator, buf.clone())
+                .await?;
+        }
+        let share = if self.tag_shares {
+            tag_share(self.quorum_id, &buf)
+        } else {
+            buf
+        };
+        let Some(orchestrator) = orchestrators.first() else {
+            anyhow::bail!("no orchestrator to share the signature of round {round} with");
+        };
+        router
+            .send(MessageClass::Share, orchestrator, share)
+            .await?;
+        info!(round, "broadcast signature");
+        Ok(())
+    }
+
+    /// Keep a Start until the validator is built, dropping the oldest beyond the bound
+    fn buffer_start(
+        &self,
+        state: &mut RunState,
+        issuer: PubKey,
+        frame: Bytes,
+        message: wire::Aggregation<CounterTaskData>,
+    ) {
+        let deadline = self.clock.monotonic_now() + self.round_deadline(&message.metadata);
+        debug!(
+            round = message.round,
+            "validator initializing, buffering start"
+        );
+        self.drop_expired_starts(state);
+        state.buffered.push_back(BufferedStart {
+            issuer,
+            frame,
+            message,
+            deadline,
+        });
+        if state.buffered.len() > self.config.validator_retry.max_buffered_starts
+            && let Some(dropped) = state.buffered.pop_front()
+        {
+            warn!(
+                round = dropped.message.round,
+                "start buffer full, dropped oldest start"
+            );
+        }
+    }
+
+    /// Drop the buffered Starts whose rounds are past their deadline
+    fn drop_expired_starts(&self, state: &mut RunState) {
+        let now = self.clock.monotonic_now();
+        state.buffered.retain(|start| {
+            let live = now < start.deadline;
+            if !live {
+                info!(
+                    round = start.message.round,
+                    "buffered start past its deadline"
+                );
+            }
+            live
+        });
+    }
+
+    /// Sign the Starts buffered while the validator was built, skipping expired rounds
+    async fn sign_buffered_starts(
+        &self,
+        state: &mut RunState,
+        sync: &mut SyncLog,
+        validator: &dyn PayloadValidator,
+    ) {
+        while let Some(start) = state.buffered.pop_front() {
+            if self.clock.monotonic_now() >= start.deadline {
+                info!(
+                    round = start.message.round,
+                    "buffered start past its deadline"
+                );
+                continue;
+            }
+            self.sign_start(
+                state,
+                sync,
+                validator,
+                Some(start.issuer),
+                start.frame,
+                start.message,
+            )
+            .await;
+        }
+    }
 
-pub struct Contributor {
-    orchestrator: PubKey,
-    signer: EllipticCurve,
-    me: usize,
-    aggregation_data: Option<AggregationData>,
+    /// Sign the queued Start of `round` ahead of its turn, if there is one
+    async fn sign_queued_start(
+        &self,
+        state: &mut RunState,
+        sync: &mut SyncLog,
+        validator: &dyn PayloadValidator,
+        round: u64,
+    ) {
+        if let Some(start) = state.starts.take(|start| start.message.round == round) {
+            debug!(round, "signing queued start ahead of its turn");
+            self.sign_start(
+                state,
+                sync,
+                validator,
+                Some(start.issuer),
+                start.frame,
+                start.message,
+            )
+            .await;
+        }
+    }
+
+    /// Verify a peer's signature for a round and aggregate once the threshold is reached,
+    /// returning whether the share was recorded
+    ///
+    /// Shares of executed rounds are verified against the `digest` they are tagged with,
+    /// and aggregate only with the shares over the same digest. The share is scored for
+    /// `from`, the peer it was received from, which relayed it if forwarded.
+    #[instrument(
+        skip_all,
+        fields(round = share.message.round, sender = %self.short(&share.sender))
+    )]
+    async fn collect_share<S>(
+        &self,
+        state: &mut RunState,
+        router: &mut OutboundRouter<S>,
+        sync: &mut SyncLog,
+        validator: &dyn PayloadValidator,
+        share: ContributorSig,
+        from: &PubKey,
+        digest: Option<[u8; 32]>,
+    ) -> bool
+    where
+        S: Sender<PublicKey = PubKey>,
+    {
+        let Some(AggregationData {
+            threshold,
+            g1_map,
+            contributors,
+            ..
+        }) = &self.aggregation_data
+        else {
+            return false;
+        };
+        let ContributorSig {
+            sender,
+            message,
+            signature,
+        } = share;
+        let sender = &sender;
+        let round = message.round;
+
+        // Get contributor
+        let Some(contributor) = self.get_contributor_index(sender).copied().map(usize::from) else {
+            info!("contributor not found: {}", self.short(sender));
+            self.events
+                .share_dropped(self.quorum_id, DroppedShare::UnknownSender.kind());
+            return false;
+        };
+        self.time_out_overdue(state, round);
+
+        // Stop collecting once the task can no longer be responded to, and check if
+        // contributor already signed
+        if let Err(rejection) =
+            state
+                .rounds
+                .check_share(round, contributor, self.clock.monotonic_now())
+        {
+            self.log_rejected_share(round, contributor, rejection);
+            return false;
+        }
+        if let Some(filter) = self.signature_window(state, round)
+            && let Err(err) = filter.check()
+        {
+            info!(round, contributor, %err, "dropped share");
+            self.events
+                .share_dropped(self.quorum_id, DroppedShare::OutsideWindow.kind());
+            return false;
+        }
+
+        let executed = state.rounds.get(round).is_some_and(|state| state.executed);
+        let payload = match digest {
+            Some(digest) if executed => digest,
+            None if !executed => match self.validate(validator, &message).await {
+                Ok(payload) => payload,
+                Err(_) => {
+                    info!(
+                        "failed to validate payload for contributor: {:?}",
+                        contributor
+                    );
+                    return false;
+                }
+            },
+            _ => {
+                info!(
+                    round,
+                    contributor, executed, "share digest tag does not match the round"
+                );
+                self.events
+                    .share_dropped(self.quorum_id, DroppedShare::MisTagged.kind());
+                return false;
+            }
+        };
+        // Verify signature from contributor using aggregate_verify with single public key
+        if !aggregate_verify(std::slice::from_ref(sender), None, &payload, &signature) {
+            info!("invalid signature from contributor: {:?}", contributor);
+            self.events.invalid_signature(self.quorum_id);
+            self.penalize_peer(state, from, Offense::InvalidSignature);
+            return false;
+        }
+        if let Err(err) = self.check_registration(state, sender).await {
+            info!(contributor, %err, "registration not confirmed, rejected share");
+            return false;
+        }
+        state.scores.reward(from, self.clock.monotonic_now());
+
+        // Insert signature, the deadline may have passed while validating
+        let round_state = match state.rounds.record_share(
+            round,
+            contributor,
+            signature,
+            self.clock.monotonic_now(),
+        ) {
+            Ok(round_state) => round_state,
+            Err(rejection) => {
+                self.log_rejected_share(round, contributor, rejection);
+                return false;
+            }
+        };
+        self.events.signature_received(self.quorum_id);
+
+        // Executed rounds aggregate only the shares over the digest of this one
+        let mut group;
+        let signatures = if executed {
+            round_state.share_digests.insert(contributor, payload);
+            if round_state.expected_hash != Some(payload) {
+                self.events.digest_disagreement(self.quorum_id);
+                warn!(
+                    round,
+                    contributor,
+                    digest = %self.short(&payload),
+                    "share signs a digest other than ours"
+                );
+            }
+            group = round_state.shares_over(&payload);
+            &mut group
+        } else {
+            &mut round_state.shares
+        };
+
+        // Check if should aggregate
+        if signatures.len() < *threshold {
+            info!(
+                "current signatures aggregated: {:?}, needed: {:?}, continuing aggregation",
+                signatures.len(),
+                threshold
+            );
+            return true;
+        }
+        if signatures.len() == *threshold {
+            self.events.threshold_reached(self.quorum_id);
+        }
+
+        // Enough signatures, aggregate
+        let (agg_signature, participants) = match aggregate_round(signatures) {
+            AggregationOutcome::Aggregated {
+                signature,
+                participants,
+            } => (signature, participants),
+            AggregationOutcome::Empty => {
+                info!("no signatures to aggregate: {:?}", round);
+                return true;
+            }
+            AggregationOutcome::Evicted(evicted) => {
+                self.events.aggregation_failed(self.quorum_id);
+                warn!(
+                    round,
+                    ?evicted,
+                    "failed to aggregate signatures, evicted malformed signatures"
+                );
+                return true;
+            }
+        };
+        let Some(signers) = ParticipationBitmap::from_indices(participants.iter().copied()) else {
+            self.events.aggregation_failed(self.quorum_id);
+            error!(
+                round,
+                ?participants,
+                "participant past the participation bitmap, not aggregating"
+            );
+            return true;
+        };
+        let Some(participating) = participants
+            .iter()
+            .map(|&i| contributors.get(i).cloned())
+            .collect::<Option<Vec<PubKey>>>()
+        else {
+            self.events.aggregation_failed(self.quorum_id);
+            error!(
+                round,
+                ?participants,
+                "participant not a contributor, not aggregating"
+            );
+            return true;
+        };
+        // A member added without its G1 key cannot be accounted for in the aggregate
+        let Some(g1_keys) = participating
+            .iter()
+            .map(|key| g1_map.get(key))
+            .collect::<Option<Vec<_>>>()
+        else {
+            self.events.aggregation_failed(self.quorum_id);
+            error!(round, "missing G1 key of a participant, not aggregating");
+            return true;
+        };
+        // Logged when every G1 key is a point, placeholder keys are not
+        let apk = aggregate_g1_iter(g1_keys.into_iter());
+
+        // Verify aggregated signature (already verified individual signatures so should never fail)
+        if !aggregate_verify(&participating, None, &payload, &agg_signature) {
+            self.events.aggregation_failed(self.quorum_id);
+            error!(
+                round,
+                "aggregated signature does not verify, not recording it"
+            );
+            return true;
+        }
+        state
+            .rounds
+            .record_aggregate(round, agg_signature.clone(), signers);
+        if let Some(latency) = state.rounds.mark_aggregated(round) {
+            self.events.observe_latency(self.quorum_id, latency);
+        }
+        sync.mark_aggregated(round);
+        info!(
+            round,
+            msg = %self.short(&payload),
+            ?participants,
+            signature = %self.short(&agg_signature),
+            apk = ?apk.as_ref().map(|apk| self.short(&apk[..])),
+            "aggregated signatures",
+        );
+        if let Some(sink) = &self.aggregation_sink {
+            let result = AggregationResult {
+                round,
+                payload,
+                signature: agg_signature,
+                signers,
+            };
+            if let Err(err) = sink.emit(&result).await {
+                warn!(round, ?err, "failed to emit aggregate");
+            }
+        }
+        self.return_aggregate(state, router, round, &agg_signature, signers)
+            .await;
+        true
+    }
+
+    /// Send the first aggregate of a round to the orchestrator that started it
+    ///
+    /// Rounds caught up from peers have no known issuer and are not returned.
+    async fn return_aggregate<S>(
+        &self,
+        state: &mut RunState,
+        router: &mut OutboundRouter<S>,
+        round: u64,
+        signature: &Sig,
+        participants: ParticipationBitmap,
+    ) where
+        S: Sender<PublicKey = PubKey>,
+    {
+        let Some(orchestrator) = state.issuers.remove(&round) else {
+            return;
+        };
+        let aggregated = Aggregated {
+            round,
+            signature: signature.to_vec(),
+            participants,
+        };
+        let mut buf = Vec::with_capacity(aggregated.encode_size());
+        aggregated.write(&mut buf);
+        if let Err(err) = router
+            .send(MessageClass::Aggregate, &orchestrator, Bytes::from(buf))
+            .await
+        {
+            warn!(round, ?err, "failed to return aggregate to orchestrator");
+            return;
+        }
+        info!(
+            round,
+            orchestrator = %self.short(&orchestrator),
+            "returned aggregate to orchestrator"
+        );
+    }
+
+    /// Open a share `sender` forwarded, returning the frame it relayed and the share of its
+    /// signer
+    ///
+    /// The relaying peer must be a contributor naming itself as the forwarder, and the
+    /// signer another contributor.
+    fn open_forwarded(
+        &self,
+        state: &mut RunState,
+        sender: &PubKey,
+        frame: &[u8],
+    ) -> Option<(Bytes, ContributorSig)> {
+        let forwarded = match ForwardedShare::read(&mut std::io::Cursor::new(frame)) {
+            Ok(forwarded) => forwarded,
+            Err(err) => {
+                log_decode_error(
+                    self.config.log_policy,
+                    sender,
+                    "forwarded share",
+                    frame,
+                    &err,
+                );
+                let failure = DecodeFailure::classify(&err).to_string();
+                self.record_decode_failure(state, sender, &failure);
+                return None;
+            }
+        };
+        let origin = canonicalize_key(&forwarded.origin);
+        if canonicalize_key(&forwarded.forwarder) != *sender
+            || self.get_contributor_index(sender).is_none()
+        {
+            info!(sender = %self.short(sender), "forwarded share not relayed by its sender");
+            return None;
+        }
+        if origin == *sender || origin == self.own_key() || self.is_orchestrator(&origin) {
+            debug!(
+                sender = %self.short(sender),
+                origin = %self.short(&origin),
+                "ignoring forwarded share"
+            );
+            return None;
+        }
+        if self.get_contributor_index(&origin).is_none() {
+            info!(
+                sender = %self.short(sender),
+                origin = %self.short(&origin),
+                "forwarded share from unknown contributor"
+            );
+            self.events
+                .share_dropped(self.quorum_id, DroppedShare::UnknownSender.kind());
+            return None;
+        }
+        let message = match untag_share(&forwarded.share) {
+            Some((quorum_id, share)) if quorum_id == self.quorum_id => share,
+            Some((quorum_id, _)) => {
+                debug!(
+                    quorum_id,
+                    sender = %self.short(sender),
+                    "dropping forwarded share tagged for another quorum"
+                );
+                self.events
+                    .share_dropped(quorum_id, DroppedShare::MisTagged.kind());
+                return None;
+            }
+            None => forwarded.share.clone(),
+        };
+        let message = match wire::Aggregation::read(&mut std::io::Cursor::new(message)) {
+            Ok(message) => message,
+            Err(err) => {
+                log_decode_error(
+                    self.config.log_policy,
+                    sender,
+                    "forwarded share",
+                    &forwarded.share,
+                    &err,
+                );
+                let failure = DecodeFailure::classify(&err).to_string();
+                self.record_decode_failure(state, sender, &failure);
+                return None;
+            }
+        };
+        // Malformed signatures count against the forwarder, not the signer
+        match try_classify(&message) {
+            Ok(MessageKind::Signature(signature)) => Some((
+                forwarded.share,
+                ContributorSig {
+                    sender: origin,
+                    message,
+                    signature,
+                },
+            )),
+            Ok(_) => {
+                info!(sender = %self.short(sender), "forwarded frame is not a share");
+                None
+            }
+            Err(err) => {
+                info!(
+                    sender = %self.short(sender),
+                    %err,
+                    "forwarded share is not a valid signature"
+                );
+                self.record_decode_failure(state, sender, err.kind());
+                None
+            }
+        }
+    }
+
+    /// Forward the share of `origin` we just recorded to the contributors we have no
+    /// share from for its round, other than the peer it came from
+    async fn relay_share<S>(
+        &self,
+        state: &mut RunState,
+        router: &mut OutboundRouter<S>,
+        sender: &PubKey,
+        origin: &PubKey,
+        round: u64,
+        share: Bytes,
+    ) -> Result<()>
+    where
+        S: Sender<PublicKey = PubKey>,
+    {
+        let (Some(relay), Some(data)) = (state.relay.as_mut(), &self.aggregation_data) else {
+            return Ok(());
+        };
+        let Some(round_state) = state.rounds.get(round) else {
+            return Ok(());
+        };
+        let silent = data
+            .contributors
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                *index != usize::from(self.me) && !round_state.shares.contains_key(index)
+            })
+            .map(|(_, key)| key.clone())
+            .filter(|key| key != sender && key != origin);
+        let peers = relay.forward(round, origin, silent);
+        if peers.is_empty() {
+            return Ok(());
+        }
+        let forwarded = ForwardedShare {
+            origin: origin.clone(),
+            forwarder: self.own_key(),
+            share,
+        };
+        let mut buf = Vec::with_capacity(forwarded.encode_size());
+        forwarded.write(&mut buf);
+        let frame = Bytes::from(buf);
+        for peer in &peers {
+            router
+                .send(MessageClass::Forward, peer, frame.clone())
+                .await?;
+        }
+        debug!(
+            round,
+            origin = %self.short(origin),
+            peers = peers.len(),
+            "forwarded share to silent peers"
+        );
+        Ok(())
+    }
+
+    /// Verify the final aggregate of a round broadcast by the orchestrator and record
+    /// how it compares with ours
+    fn check_final_aggregate(&self, state: &mut RunState, aggregate: FinalAggregate) {
+        let round = aggregate.round;
+        let (contributors, g1_map) = match &self.aggregation_data {
+            Some(data) => (&data.contributors, Some(&data.g1_map)),
+            None => (&self.contributors, None),
+        };
+        let checked = match state.rounds.get(round) {
+            Some(RoundState {
+                expected_hash: Some(expected_hash),
+                aggregate: local,
+                ..
+            }) => verify_final_aggregate(
+                &aggregate,
+                expected_hash,
+                contributors,
+                g1_map,
+                local.as_ref(),
+            ),
+            _ => Err(AggregateRejection::UnknownRound),
+        };
+        let check = match checked {
+            Ok(check) => check,
+            Err(err) => {
+                warn!(round, %err, "rejected final aggregate");
+                return;
+            }
+        };
+        state.rounds.record_final_aggregate(round, check);
+        if check == AggregateCheck::Mismatch {
+            self.events.aggregate_mismatch(self.quorum_id);
+            warn!(
+                round,
+                signers = aggregate.signers.count(),
+                "final aggregate does not match our payload or aggregate"
+            );
+            return;
+        }
+        info!(round, result = check.kind(), "checked final aggregate");
+    }
+
+    /// Contribute to the still-open rounds reported by a peer
+    async fn apply_sync_response(
+        &self,
+        state: &mut RunState,
+        sync: &mut SyncLog,
+        validator: &dyn PayloadValidator,
+        peer: &PubKey,
+        response: SyncResponse,
+    ) {
+        let max_rounds = sync.config().max_rounds;
+        for summary in response.rounds.into_iter().take(max_rounds) {
+            let Some(frame) = summary.start else {
+                continue;
+            };
+            if state.rounds.is_signed(summary.round) {
+                continue;
+            }
+            let Some(start) = self.open_start(peer, &frame) else {
+                continue;
+            };
+            let message: wire::Aggregation<CounterTaskData> =
+                match wire::Aggregation::read(&mut std::io::Cursor::new(start)) {
+                    Ok(message) => message,
+                    Err(err) => {
+                        log_decode_error(
+                            self.config.log_policy,
+                            peer,
+                            "synced start",
+                            &frame,
+                            &err,
+                        );
+                        let failure = DecodeFailure::classify(&err).to_string();
+                        self.record_decode_failure(state, peer, &failure);
+                        continue;
+                    }
+                };
+            if message.round != summary.round || classify(&message) != MessageKind::Start {
+                warn!(
+                    round = summary.round,
+                    peer = %self.short(peer),
+                    "synced frame is not a start"
+                );
+                continue;
+            }
+
+            // Validate exactly like a start received from the orchestrator
+            if let Some(payload) = self
+                .sign_start(state, sync, validator, None, frame, message)
+                .await
+            {
+                if payload != summary.payload_hash {
+                    warn!(
+                        round = summary.round,
+                        peer = %self.short(peer),
+                        "peer reported a different payload hash"
+                    );
+                }
+                info!(round = summary.round, "contributed to synced round");
+            }
+        }
+    }
 }
 
 impl crate::contributor::ContributorBase for Contributor {
@@ -31,15 +2036,19 @@ impl crate::contributor::ContributorBase for Contributor {
     type Signature = Sig;
 
     fn is_orchestrator(&self, sender: &Self::PublicKey) -> bool {
-        &self.orchestrator == sender
+        self.orchestrators.contains(sender)
     }
 
-    fn get_contributor_index(&self, public_key: &Self::PublicKey) -> Option<&usize> {
+    fn get_contributor_index(&self, public_key: &Self::PublicKey) -> Option<&ContributorIndex> {
         match &self.aggregation_data {
             Some(data) => data.ordered_contributors.get(public_key),
             None => None,
         }
     }
+
+    fn contributors_for_round(&self, round: u64) -> Vec<Self::PublicKey> {
+        assigned_contributors(self.assignment.as_ref(), round, &self.contributors)
+    }
 }
 
 impl Contribute for Contributor {
@@ -48,204 +2057,608 @@ impl Contribute for Contributor {
     fn new(
         orchestrator: PubKey,
         signer: EllipticCurve,
-        mut contributors: Vec<PubKey>,
+        contributors: Vec<PubKey>,
         aggregation_input: Option<AggregationInput>,
-    ) -> Self {
-        dotenv().ok();
-        contributors.sort();
-        let mut ordered_contributors = HashMap::new();
-        for (idx, contributor) in contributors.iter().enumerate() {
-            ordered_contributors.insert(contributor.clone(), idx);
-        }
-        let me = *ordered_contributors.get(&signer.public_key()).unwrap();
-        if let Some(aggregation_input) = aggregation_input {
-            let threshold = aggregation_input.threshold();
-            let g1_map = aggregation_input.g1_map().clone();
-            Self {
-                orchestrator,
-                signer,
-                me,
-                aggregation_data: Some(AggregationData {
-                    threshold,
-                    g1_map,
-                    contributors,
-                    ordered_contributors,
-                }),
-            }
-        } else {
-            Self {
-                orchestrator,
-                signer,
-                me,
-                aggregation_data: None,
-            }
-        }
+    ) -> Result<Self, ContributeError> {
+        let contributors = deduplicate_contributors(contributors);
+        let own_key = canonicalize_key(&Signer::public_key(&signer));
+        let me = contributors
+            .iter()
+            .position(|contributor| *contributor == own_key)
+            .map(ContributorIndex::from)
+            .ok_or(ContributeError::SignerNotInContributors)?;
+        let aggregation_data = aggregation_input.map(|aggregation_input| {
+            AggregationData::from((aggregation_input, contributors.clone()))
+        });
+        Ok(Self {
+            orchestrators: HashSet::from([orchestrator]),
+            signer: Arc::new(signer),
+            config: RunConfig::default(),
+            signing_domain: SigningDomain::default(),
+            payload_hash: HashAlgorithm::default(),
+            me,
+            contributors,
+            assignment: None,
+            aggregation_data,
+            quorum_id: 0,
+            tag_shares: false,
+            events: Arc::new(NoopEventSink),
+            aggregation_sink: None,
+            validator_factory: Arc::new(CounterValidatorFactory::default()),
+            executors: None,
+            validator_status: Arc::new(watch::Sender::new(ValidatorStatus::Initializing {
+                attempts: 0,
+            })),
+            committee_refresh: None,
+            chain_head: None,
+            clock: Arc::new(SystemClock),
+            clock_skew: None,
+            quorum_updates: None,
+            retirements: None,
+            completion_events: None,
+            resets: None,
+            exports: None,
+            registration: None,
+        })
+    }
+
+    async fn run<S, R>(self, router: OutboundRouter<S>, receiver: R) -> Result<()>
+    where
+        S: Sender<PublicKey = PubKey>,
+        R: Receiver<PublicKey = PubKey>,
+        R::Error: std::error::Error + 'static,
+    {
+        let config = self.config.clone();
+        self.run_with_config(router, receiver, config).await
     }
+}
 
-    async fn run<S, R>(self, mut sender: S, mut receiver: R) -> Result<()>
+impl Contributor {
+    /// Run until the receiver closes with `config`, replacing the one set with
+    /// [Contributor::with_run_config]
+    ///
+    /// A receiver that stalled is returned as a
+    /// [WatchdogTimeout](crate::p2p::WatchdogTimeout) error, to be retried on a new
+    /// connection.
+    pub async fn run_with_config<S, R>(
+        mut self,
+        mut router: OutboundRouter<S>,
+        mut receiver: R,
+        config: RunConfig,
+    ) -> Result<()>
     where
-        S: Sender,
+        S: Sender<PublicKey = PubKey>,
         R: Receiver<PublicKey = PubKey>,
+        R::Error: std::error::Error + 'static,
     {
-        let mut signed = HashSet::new();
-        let mut signatures: HashMap<u64, HashMap<usize, Sig>> = HashMap::new();
+        self.config = config;
+        let max_wait_rounds = self
+            .config
+            .task_priority
+            .as_ref()
+            .map_or(DEFAULT_MAX_WAIT_ROUNDS, |(_, max_wait_rounds)| {
+                *max_wait_rounds
+            });
+        let mut state =
+            RunState {
+                rounds: RoundTable::with_clock(self.clock.clone()),
+                quarantine: PeerQuarantine::new(self.config.quarantine.clone()),
+                scores: PeerScores::new(self.config.scores.clone()),
+                unknown_peers: UnknownPeers::new(self.config.unknown_peers.clone()),
+                relay: self.config.share_relay.clone().map(ShareRelay::new),
+                starts: TaskPriorityQueue::new(max_wait_rounds),
+                pipeline: self
+                    .config
+                    .max_concurrent_rounds
+                    .map(RoundPipelineController::new),
+                fallback: self
+                    .config
+                    .fallback
+                    .clone()
+                    .map(|config| FallbackOrchestrator::new(config, self.clock.monotonic_now())),
+                stale: self.config.stale_alerts.as_ref().map(|(threshold, _)| {
+                    StaleDetector::new(*threshold, self.clock.monotonic_now())
+                }),
+                ..RunState::default()
+            };
+        let mut sync = SyncLog::new(self.config.sync.clone()).with_clock(self.clock.clone());
+        let contributing: Vec<&PubKey> = self
+            .contributors
+            .iter()
+            .filter(|key| self.is_orchestrator(key))
+            .collect();
+        if !contributing.is_empty() {
+            info!(
+                orchestrators = %self.config.log_policy.short_list(&contributing),
+                "orchestrator is also a contributor, its shares count toward the threshold"
+            );
+        }
 
-        let counter_validator = CounterValidator::new().await?;
-        let validator = Validator::new(counter_validator);
+        // Build the validator while receiving, Starts are buffered until it is ready
+        let validator = Arc::new(LazyValidator::new());
+        let mut building = Some(
+            build_with_retry(
+                self.validator_factory.clone(),
+                self.config.validator_retry.clone(),
+                self.clock.clone(),
+                self.validator_status.clone(),
+            )
+            .boxed(),
+        );
+        let mut quorum_updates = self.quorum_updates.take();
+        let mut retirements = self.retirements.take();
+        let mut resets = self.resets.take();
+        let mut exports = self.exports.take();
 
-        while let Ok((s, message)) = receiver.recv().await {
-            // Parse message
-            let Ok(message): Result<wire::Aggregation<CounterTaskData>, _> =
-                wire::Aggregation::read(&mut std::io::Cursor::new(message))
-            else {
-                continue;
+        loop {
+            let fallback_deadline = state
+                .fallback
+                .as_ref()
+                .and_then(FallbackOrchestrator::deadline);
+            let stale_deadline = state.stale.as_ref().and_then(StaleDetector::deadline);
+            let heartbeat_deadline = self.config.heartbeat.as_ref().map(|(interval, heartbeat)| {
+                let now = self.clock.monotonic_now();
+                heartbeat.send_replace(now);
+                now + *interval
+            });
+
+            // Send signatures as they complete, without blocking unrelated messages
+            // Queued Starts are only signed once no other event is ready
+            let (s, message) = tokio::select! {
+                biased;
+                Some(signed) = state.pending.next(), if !state.pending.is_empty() => {
+                    self.send_signature(&mut state, &mut router, signed).await?;
+                    continue;
+                }
+                retire = next_retirement(&mut retirements) => {
+                    self.retire_round(&mut state, &mut sync, retire);
+                    continue;
+                }
+                reply = next_reset(&mut resets) => {
+                    let summary = self.reset(&mut state, &mut sync);
+                    // The requester may have given up waiting
+                    let _ = reply.send(summary);
+                    continue;
+                }
+                (round, reply) = next_export(&mut exports) => {
+                    let _ = reply.send(self.collected_signatures(&state, round));
+                    continue;
+                }
+                update = next_quorum_update(&mut quorum_updates) => {
+                    self.apply_quorum_update(&update, &mut state);
+                    self.release_held_shares(&mut state, &mut router, &mut sync, validator.as_ref())
+                        .await;
+                    continue;
+                }
+                built = next_validator(&mut building) => {
+                    match built {
+                        Ok(built) => {
+                            validator.set(built);
+                            self.sign_buffered_starts(&mut state, &mut sync, validator.as_ref())
+                                .await;
+                        }
+                        // The status reports the failure, Starts stay buffered until
+                        // their rounds expire
+                        Err(err) => {
+                            error!(
+                                err = %self.config.log_policy.redacted(&err),
+                                "validator unavailable, not signing"
+                            );
+                            self.drop_expired_starts(&mut state);
+                        }
+                    }
+                    continue;
+                }
+                () = sleep_until_deadline(self.clock.as_ref(), fallback_deadline) => {
+                    self.run_fallback(&mut state, &mut sync, &mut router, validator.as_ref())
+                        .await?;
+                    continue;
+                }
+                () = sleep_until_deadline(self.clock.as_ref(), stale_deadline) => {
+                    self.alert_if_stale(&mut state);
+                    continue;
+                }
+                () = sleep_until_deadline(self.clock.as_ref(), heartbeat_deadline) => continue,
+                received = receiver.recv() => match received {
+                    Ok(received) => received,
+                    // A stalled receiver is restarted by the supervisor, a closed one
+                    // ends the loop
+                    Err(err) => match watchdog_timeout(&err) {
+                        Some(timeout) => return Err((*timeout).into()),
+                        None => break,
+                    },
+                },
+                () = std::future::ready(()), if !state.starts.is_empty() => {
+                    if let Some(start) = state.starts.pop() {
+                        self.sign_start(
+                            &mut state,
+                            &mut sync,
+                            validator.as_ref(),
+                            Some(start.issuer),
+                            start.frame,
+                            start.message,
+                        )
+                        .await;
+                    }
+                    continue;
+                }
             };
-            let round = message.round;
 
-            if let Some(AggregationData {
-                threshold,
-                ref g1_map,
-                ref contributors,
-                ..
-            }) = self.aggregation_data
-                && !self.is_orchestrator(&s)
+            self.events.message_received(self.quorum_id);
+
+            // Oversized frames are dropped before anything reads them
+            if message.len() > self.config.max_message_size {
+                warn!(
+                    sender = %self.short(&s),
+                    len = message.len(),
+                    max = self.config.max_message_size,
+                    "dropping oversized frame"
+                );
+                let failure = DecodeFailure::Oversized.to_string();
+                self.record_decode_failure(&mut state, &s, &failure);
+                continue;
+            }
+            self.heard_from(&mut state, &s);
+            self.promote_if_silent(&mut state);
+
+            // Frames from quarantined peers are dropped unread
+            if state
+                .quarantine
+                .is_quarantined(&s, self.clock.monotonic_now())
+            {
+                debug!(sender = %self.short(&s), "dropping frame from quarantined peer");
+                continue;
+            }
+
+            // Frames from peers scoring too low are dropped before verification
+            if state.scores.is_ignored(&s, self.clock.monotonic_now()) {
+                debug!(sender = %self.short(&s), "dropping frame from ignored peer");
+                continue;
+            }
+
+            // Membership changes take effect before the next message is handled
+            if let Some(updates) = quorum_updates.as_mut()
+                && self.apply_quorum_updates(updates, &mut state)
             {
-                // Get contributor
-                let Some(contributor) = self.get_contributor_index(&s) else {
-                    info!("contributor not found: {:?}", s);
+                self.release_held_shares(&mut state, &mut router, &mut sync, validator.as_ref())
+                    .await;
+            }
+
+            // Shares tagged for another quorum are dropped, relayed shares keep their tag
+            let received = message.clone();
+            let message = match untag_share(&message) {
+                Some((quorum_id, share)) if quorum_id == self.quorum_id => share,
+                Some((quorum_id, _)) => {
+                    debug!(
+                        quorum_id,
+                        sender = %self.short(&s),
+                        "dropping share tagged for another quorum"
+                    );
+                    self.events
+                        .share_dropped(quorum_id, DroppedShare::MisTagged.kind());
                     continue;
-                };
+                }
+                None => message,
+            };
+
+            // Shares of executed rounds carry the digest they sign
+            let (digest, message) = match untag_digest(&message) {
+                Some((digest, share)) => (Some(digest), share),
+                None => (None, message),
+            };
 
-                // Check if contributor already signed
-                let Some(signatures) = signatures.get_mut(&round) else {
-                    info!("signatures not found: {:?}", round);
+            // Verify relayed shares against their signer, not the peer forwarding them
+            if ForwardedShare::is_forwarded_share(&message) {
+                if self.aggregation_data.is_none() {
                     continue;
-                };
-                if signatures.contains_key(contributor) {
-                    info!("contributor already signed: {:?}", contributor);
+                }
+                let Some((frame, share)) = self.open_forwarded(&mut state, &s, &message) else {
                     continue;
+                };
+                let round = share.message.round;
+                let origin = share.sender.clone();
+                self.sign_queued_start(&mut state, &mut sync, validator.as_ref(), round)
+                    .await;
+                if self
+                    .collect_share(
+                        &mut state,
+                        &mut router,
+                        &mut sync,
+                        validator.as_ref(),
+                        share,
+                        &s,
+                        None,
+                    )
+                    .await
+                {
+                    self.relay_share(&mut state, &mut router, &s, &origin, round, frame)
+                        .await?;
                 }
+                continue;
+            }
 
-                // Extract signature
-                let signature = match message.clone().payload {
-                    Some(Payload::Signature(signature)) => signature,
-                    _ => {
-                        info!("signature not found: {:?}", message.clone().payload);
+            // Handle catch-up synchronization
+            if SyncMessage::is_sync(&message) {
+                let sync_message = match SyncMessage::read(&mut std::io::Cursor::new(&message[..]))
+                {
+                    Ok(sync_message) => sync_message,
+                    Err(err) => {
+                        log_decode_error(self.config.log_policy, &s, "sync", &message, &err);
+                        let failure = DecodeFailure::classify(&err).to_string();
+                        self.record_decode_failure(&mut state, &s, &failure);
                         continue;
                     }
                 };
-                let Ok(signature) = Sig::try_from(signature.clone()) else {
-                    info!("not a valid signature: {:?}", signature);
-                    continue;
-                };
-                let mut buf = Vec::with_capacity(message.encode_size());
-                message.write(&mut buf);
-                let Ok(payload) = validator.validate_and_return_expected_hash(&buf).await else {
-                    info!(
-                        "failed to validate payload for contributor: {:?}",
-                        contributor
-                    );
-                    continue;
-                };
-                // Verify signature from contributor using aggregate_verify with single public key
-                if !aggregate_verify(std::slice::from_ref(&s), None, &payload, &signature) {
-                    info!("invalid signature from contributor: {:?}", contributor);
-                    continue;
+                match sync_message {
+                    SyncMessage::Request(request) => {
+                        let response = sync.respond(&request);
+                        if response.rounds.is_empty() {
+                            continue;
+                        }
+                        let response = SyncMessage::Response(response);
+                        let mut buf = Vec::with_capacity(response.encode_size());
+                        response.write(&mut buf);
+                        router
+                            .send(MessageClass::SyncResponse, &s, Bytes::from(buf))
+                            .await?;
+                    }
+                    SyncMessage::Response(response) => {
+                        if !validator.is_ready() {
+                            debug!("validator initializing, ignoring sync response");
+                            continue;
+                        }
+                        if !self.contributors.contains(&s) && !self.is_orchestrator(&s) {
+                            info!("sync response from unknown peer: {}", self.short(&s));
+                            continue;
+                        }
+                        self.apply_sync_response(
+                            &mut state,
+                            &mut sync,
+                            validator.as_ref(),
+                            &s,
+                            response,
+                        )
+                        .await;
+                    }
                 }
+                continue;
+            }
 
-                // Insert signature
-                signatures.insert(*contributor, signature);
+            // Aggregates are returned to orchestrators, a contributor has no use for them
+            if Aggregated::is_aggregated(&message) {
+                debug!(sender = %self.short(&s), "ignoring aggregate returned by a peer");
+                continue;
+            }
 
-                // Check if should aggregate
-                if signatures.len() < threshold {
-                    info!(
-                        "current signatures aggregated: {:?}, needed: {:?}, continuing aggregation",
-                        signatures.len(),
-                        threshold
-                    );
+            // Check the aggregate the orchestrator settled on
+            if FinalAggregate::is_final_aggregate(&message) {
+                if !self.is_orchestrator(&s) {
+                    info!("final aggregate not from orchestrator: {}", self.short(&s));
                     continue;
                 }
+                match FinalAggregate::read(&mut std::io::Cursor::new(&message[..])) {
+                    Ok(aggregate) => self.check_final_aggregate(&mut state, aggregate),
+                    Err(err) => {
+                        log_decode_error(
+                            self.config.log_policy,
+                            &s,
+                            "final aggregate",
+                            &message,
+                            &err,
+                        );
+                        let failure = DecodeFailure::classify(&err).to_string();
+                        self.record_decode_failure(&mut state, &s, &failure);
+                    }
+                }
+                continue;
+            }
 
-                // Enough signatures, aggregate
-                let mut participating = Vec::new();
-                let mut participating_g1 = Vec::new();
-                let mut sigs = Vec::new();
-                for (i, contributor) in contributors.iter().enumerate() {
-                    let Some(signature) = signatures.get(&i) else {
+            // Parse message, unwrapping Starts signed by the orchestrator
+            let frame = message.clone();
+            let signed_start = SignedStart::is_signed_start(&frame);
+            let message = if signed_start {
+                match self.open_start(&s, &frame) {
+                    Some(start) => start,
+                    None => continue,
+                }
+            } else {
+                message
+            };
+            let message: wire::Aggregation<CounterTaskData> =
+                match wire::Aggregation::read(&mut std::io::Cursor::new(message)) {
+                    Ok(message) => message,
+                    Err(err) => {
+                        log_decode_error(self.config.log_policy, &s, "aggregation", &frame, &err);
+                        let failure = DecodeFailure::classify(&err).to_string();
+                        self.record_decode_failure(&mut state, &s, &failure);
+                        continue;
+                    }
+                };
+            let round = message.round;
+            let message = match ContributorMessage::try_from((s.clone(), message, self.roles())) {
+                // Collect signatures from peers when aggregating
+                Ok(ContributorMessage::FromContributor(share)) => {
+                    self.sign_queued_start(&mut state, &mut sync, validator.as_ref(), round)
+                        .await;
+                    if self.get_contributor_index(&s).is_none() {
+                        self.hold_share(&mut state, share, digest);
                         continue;
-                    };
-                    participating.push(contributor.clone());
-                    participating_g1.push(g1_map[contributor].clone());
-                    sigs.push(signature.clone());
+                    }
+                    // Shares tagged with their digest are not relayed
+                    if self
+                        .collect_share(
+                            &mut state,
+                            &mut router,
+                            &mut sync,
+                            validator.as_ref(),
+                            share,
+                            &s,
+                            digest,
+                        )
+                        .await
+                        && digest.is_none()
+                    {
+                        self.relay_share(&mut state, &mut router, &s, &s, round, received)
+                            .await?;
+                    }
+                    continue;
                 }
-                let Some(agg_signature) = aggregate_signatures(&sigs) else {
-                    info!("failed to aggregate signatures");
+                // Handle message from orchestrator
+                Ok(ContributorMessage::FromOrchestrator(start)) => start.message,
+                Ok(ContributorMessage::StartFromPeer(sender)) => {
+                    info!("not from orchestrator: {}", self.short(&sender));
+                    continue;
+                }
+                Ok(ContributorMessage::Uncollected | ContributorMessage::Unknown) => continue,
+                Err(err) => {
+                    info!(sender = %self.short(&s), %err, "not a valid signature");
+                    self.record_decode_failure(&mut state, &s, err.kind());
                     continue;
-                };
-
-                // Verify aggregated signature (already verified individual signatures so should never fail)
-                if !aggregate_verify(&participating, None, &payload, &agg_signature) {
-                    panic!("failed to verify aggregated signature");
                 }
+            };
+            if self.config.require_signed_starts && !signed_start {
+                warn!(round, "unsigned start, not signing");
+                continue;
+            }
+            if let Some(stale) = state.stale.as_mut() {
+                stale.start_seen(round, self.clock.monotonic_now());
+            }
+
+            // Ask peers for rounds missed while partitioned
+            if let Some(request) = sync.observe_round(round) {
                 info!(
-                    round,
-                    msg = hex(&payload),
-                    ?participating,
-                    signature = hex(&agg_signature),
-                    "aggregated signatures",
+                    from = request.from_round,
+                    to = request.to_round,
+                    "requesting missed rounds"
                 );
-                continue;
+                let request = SyncMessage::Request(request);
+                let mut buf = Vec::with_capacity(request.encode_size());
+                request.write(&mut buf);
+                router
+                    .send(MessageClass::SyncRequest, &s, Bytes::from(buf))
+                    .await?;
             }
 
-            // Handle message from orchestrator
-            match message.payload {
-                Some(Payload::Start) => (),
-                _ => continue,
-            };
-            if !self.is_orchestrator(&s) {
-                info!("not from orchestrator: {:?}", s);
+            if !validator.is_ready() {
+                self.buffer_start(&mut state, s, frame, message);
                 continue;
             }
 
-            // Check if already signed at round
-            if !signed.insert(round) {
-                info!("already signed at round: {:?}", round);
+            // With task priorities, wait for the queue to reach this Start
+            if let Some((reader, _)) = &self.config.task_priority {
+                let priority = reader(&message);
+                let start = QueuedStart {
+                    issuer: s,
+                    frame,
+                    message,
+                };
+                state.starts.push(start, priority);
                 continue;
             }
-            let mut buf = Vec::with_capacity(message.encode_size());
-            message.write(&mut buf);
-            let payload = validator.validate_and_return_expected_hash(&buf).await?;
-            info!(
-                "Generating signature for round: {}, payload hash: {}",
-                round,
-                hex(&payload)
-            );
-            let signature = self.signer.sign(None, &payload);
+            self.sign_start(
+                &mut state,
+                &mut sync,
+                validator.as_ref(),
+                Some(s),
+                frame,
+                message,
+            )
+            .await;
+        }
 
-            // Store signature
-            signatures
-                .entry(round)
-                .or_default()
-                .insert(self.me, signature.clone());
+        // Sign the Starts still queued when the receiver closed
+        while let Some(start) = state.starts.pop() {
+            self.sign_start(
+                &mut state,
+                &mut sync,
+                validator.as_ref(),
+                Some(start.issuer),
+                start.frame,
+                start.message,
+            )
+            .await;
+        }
 
-            // Return signature to orchestrator
-            let message = wire::Aggregation::<CounterTaskData> {
-                round,
-                metadata: message.metadata.clone(),
-                payload: Some(Payload::Signature(signature.to_vec())),
-            };
-            let mut buf = Vec::with_capacity(message.encode_size());
-            message.write(&mut buf);
-            info!("Sending signature for round: {}", round);
+        // Flush signatures still being produced when the receiver closed
+        while let Some(signed) = state.pending.next().await {
+            self.send_signature(&mut state, &mut router, signed).await?;
+        }
+        Ok(())
+    }
+}
 
-            // Broadcast to all (including orchestrator)
-            sender
-                .send(commonware_p2p::Recipients::All, Bytes::from(buf), true)
-                .await
-                .map_err(|e| anyhow::anyhow!("Failed to broadcast signature: {}", e))?;
-            info!(round, "broadcast signature");
+/// Sleep until `deadline`, pending forever without one
+async fn sleep_until_deadline(clock: &dyn Clock, deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => clock.sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Next retirement, pending forever without a watcher
+async fn next_retirement(
+    retirements: &mut Option<broadcast::Receiver<RetireRound>>,
+) -> RetireRound {
+    if let Some(receiver) = retirements {
+        loop {
+            match receiver.recv().await {
+                Ok(retire) => return retire,
+                Err(RecvError::Lagged(missed)) => warn!(missed, "missed round retirements"),
+                Err(RecvError::Closed) => break,
+            }
         }
+    }
+    std::future::pending().await
+}
 
-        Ok(())
+/// Next quorum update, pending forever without an updater
+async fn next_quorum_update(
+    updates: &mut Option<broadcast::Receiver<QuorumUpdated>>,
+) -> QuorumUpdated {
+    if let Some(receiver) = updates {
+        loop {
+            match receiver.recv().await {
+                Ok(update) => return update,
+                Err(RecvError::Lagged(missed)) => warn!(missed, "missed quorum updates"),
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+    std::future::pending().await
+}
+
+/// Next reset request, pending forever without a handle
+async fn next_reset(
+    resets: &mut Option<mpsc::UnboundedReceiver<oneshot::Sender<RoundStateSummary>>>,
+) -> oneshot::Sender<RoundStateSummary> {
+    if let Some(receiver) = resets
+        && let Some(reply) = receiver.recv().await
+    {
+        return reply;
+    }
+    std::future::pending().await
+}
+
+/// Validator once built, pending forever after it was
+async fn next_validator(
+    building: &mut Option<BoxFuture<'static, Result<Arc<dyn PayloadValidator>>>>,
+) -> Result<Arc<dyn PayloadValidator>> {
+    let Some(build) = building.as_mut() else {
+        return std::future::pending().await;
+    };
+    let built = build.await;
+    *building = None;
+    built
+}
+
+/// Next signature export request, pending forever without a handle
+async fn next_export(
+    exports: &mut Option<mpsc::UnboundedReceiver<ExportRequest>>,
+) -> ExportRequest {
+    if let Some(receiver) = exports
+        && let Some(request) = receiver.recv().await
+    {
+        return request;
     }
+    std::future::pending().await
 }
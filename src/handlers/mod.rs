@@ -1,2 +1,16 @@
+//! Contributors running a use case end to end.
+#![deny(clippy::disallowed_methods)]
+
+mod builder;
 mod contributor;
-pub use contributor::Contributor;
+mod run_config;
+mod voting_contributor;
+pub use builder::{BuildError, ContributorBuilder};
+pub use contributor::{
+    Contributor, PriorityReader, ResetHandle, RoundStateSummary, SignatureExport,
+};
+pub use run_config::RunConfig;
+pub use voting_contributor::{
+    CAST_VOTE_FUNCTION, SubmissionMode, VoteSubmitter, VotingContributor, VotingTaskData,
+    cast_vote_transaction,
+};
@@ -0,0 +1,161 @@
+use super::contributor::{
+    DEFAULT_MAX_ACTIVE_ROUNDS, DEFAULT_MAX_MESSAGE_SIZE, DEFAULT_SIGNING_TIMEOUT,
+    DEFAULT_SLOW_VALIDATION_THRESHOLD, DEFAULT_UNKNOWN_SENDER_GRACE, PriorityReader,
+};
+use crate::contributor::deadline::BlockWindow;
+use crate::contributor::fallback::FallbackConfig;
+use crate::contributor::liveness::OrchestratorStale;
+use crate::contributor::quarantine::QuarantineConfig;
+use crate::contributor::relay::RelayConfig;
+use crate::contributor::scores::ScoreConfig;
+use crate::contributor::sync::SyncConfig;
+use crate::contributor::unknown_peers::UnknownPeerConfig;
+use crate::logging::LogPolicy;
+use crate::validation::lazy::ValidatorRetryConfig;
+use crate::validation::metadata::{MetadataPolicy, MetadataReader};
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+use tokio::time::Instant;
+
+/// Runtime options of a [Contributor](super::Contributor), applied when its receive loop
+/// starts
+///
+/// Set with [Contributor::with_run_config](super::Contributor::with_run_config), or
+/// passed to [Contributor::run_with_config](super::Contributor::run_with_config).
+#[derive(Clone)]
+pub struct RunConfig {
+    /// Time allowed to execute a task, then to produce a signature, before the round is
+    /// skipped
+    pub signing_timeout: Duration,
+    /// Validation time above which a warning is logged
+    pub slow_validation_threshold: Duration,
+    /// Time a share from an unknown sender is held for a contributor set update, zero
+    /// drops them immediately
+    pub unknown_sender_grace: Duration,
+    /// Tracking of senders missing from the contributor set
+    pub unknown_peers: UnknownPeerConfig,
+    /// Forwarding of verified shares to silent peers, none by default
+    pub share_relay: Option<RelayConfig>,
+    /// Largest frame parsed, larger ones count as decode failures
+    pub max_message_size: usize,
+    /// Only sign Starts carrying a valid orchestrator signature, see
+    /// [SignedStart](crate::contributor::start::SignedStart)
+    pub require_signed_starts: bool,
+    /// Quarantine of peers repeatedly sending frames that fail to decode
+    pub quarantine: QuarantineConfig,
+    /// Scoring of peers by the shares they send, ignoring those scoring too low
+    pub scores: ScoreConfig,
+    /// Retries of the validator construction
+    pub validator_retry: ValidatorRetryConfig,
+    /// Catch-up synchronization with peers
+    pub sync: SyncConfig,
+    /// Rounds in flight at once, the oldest is preempted beyond it, unbounded by default
+    pub max_concurrent_rounds: Option<NonZeroUsize>,
+    /// Active rounds beyond which Starts are rejected
+    ///
+    /// Unlike with `max_concurrent_rounds`, rounds in flight are kept and the new round
+    /// is refused. Rounds past their deadline are expired before counting.
+    pub max_active_rounds: usize,
+    /// Promotion of a contributor while the orchestrator is silent, none by default
+    ///
+    /// Every contributor of the quorum needs it to accept the Starts of the promoted
+    /// one. Its Starts are unsigned, so contributors requiring signed Starts ignore them.
+    pub fallback: Option<FallbackConfig>,
+    /// What the logs show of keys, hashes and refused values
+    pub log_policy: LogPolicy,
+    /// Policy Starts are rejected by, checked against the metadata the reader reads
+    /// from their task, none by default
+    pub metadata_policy: Option<(MetadataPolicy, MetadataReader)>,
+    /// Response window each round's deadline is derived from, none by default
+    ///
+    /// The reference block is read with the reader of the metadata policy and
+    /// compared to the chain head, so both must be configured. Rounds without a
+    /// reference block or head keep the deadline of the [SyncConfig]. Shares arriving
+    /// once the head is past the window are dropped.
+    pub block_window: Option<BlockWindow>,
+    /// Priority the reader gives the task of a Start, lower signed first, and the
+    /// signed Starts a Start is passed over for before being promoted. Starts are
+    /// signed as they arrive by default
+    ///
+    /// Starts are queued while other frames are waiting and the most urgent is signed
+    /// once none is. A share for a queued round gets its Start signed first.
+    pub task_priority: Option<(PriorityReader, u64)>,
+    /// Silence after which an [OrchestratorStale] is sent, none by default
+    ///
+    /// Sent once per silence, the next Start re-arms it. Also logged as a warning and
+    /// counted by the event sink.
+    pub stale_alerts: Option<(Duration, broadcast::Sender<OrchestratorStale>)>,
+    /// Longest interval between beats of the receive loop, and where it beats, none by
+    /// default
+    ///
+    /// The loop beats on every iteration and every interval while no message arrives,
+    /// so a health probe tells an idle contributor from a stuck one.
+    pub heartbeat: Option<(Duration, Arc<watch::Sender<Instant>>)>,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            signing_timeout: DEFAULT_SIGNING_TIMEOUT,
+            slow_validation_threshold: DEFAULT_SLOW_VALIDATION_THRESHOLD,
+            unknown_sender_grace: DEFAULT_UNKNOWN_SENDER_GRACE,
+            unknown_peers: UnknownPeerConfig::default(),
+            share_relay: None,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            require_signed_starts: false,
+            quarantine: QuarantineConfig::default(),
+            scores: ScoreConfig::default(),
+            validator_retry: ValidatorRetryConfig::default(),
+            sync: SyncConfig::default(),
+            max_concurrent_rounds: None,
+            max_active_rounds: DEFAULT_MAX_ACTIVE_ROUNDS,
+            fallback: None,
+            log_policy: LogPolicy::default(),
+            metadata_policy: None,
+            block_window: None,
+            task_priority: None,
+            stale_alerts: None,
+            heartbeat: None,
+        }
+    }
+}
+
+impl fmt::Debug for RunConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunConfig")
+            .field("signing_timeout", &self.signing_timeout)
+            .field("slow_validation_threshold", &self.slow_validation_threshold)
+            .field("unknown_sender_grace", &self.unknown_sender_grace)
+            .field("unknown_peers", &self.unknown_peers)
+            .field("share_relay", &self.share_relay)
+            .field("max_message_size", &self.max_message_size)
+            .field("require_signed_starts", &self.require_signed_starts)
+            .field("quarantine", &self.quarantine)
+            .field("scores", &self.scores)
+            .field("validator_retry", &self.validator_retry)
+            .field("sync", &self.sync)
+            .field("max_concurrent_rounds", &self.max_concurrent_rounds)
+            .field("max_active_rounds", &self.max_active_rounds)
+            .field("fallback", &self.fallback)
+            .field("log_policy", &self.log_policy)
+            // Readers are closures, only their settings are shown
+            .field(
+                "metadata_policy",
+                &self.metadata_policy.as_ref().map(|(policy, _)| policy),
+            )
+            .field("block_window", &self.block_window)
+            .field(
+                "task_priority",
+                &self
+                    .task_priority
+                    .as_ref()
+                    .map(|(_, max_wait_rounds)| max_wait_rounds),
+            )
+            .field("stale_alerts", &self.stale_alerts)
+            .field("heartbeat", &self.heartbeat)
+            .finish()
+    }
+}
@@ -0,0 +1,510 @@
+use crate::chain::ChainSubmitter;
+use crate::clock::{Clock, SystemClock};
+use crate::contributor::aggregation::{AggregationOutcome, aggregate_round};
+use crate::contributor::committee::{canonicalize_key, deduplicate_contributors};
+use crate::contributor::decode::{MessageKind, classify, log_decode_error, try_classify};
+use crate::contributor::rounds::{RoundTable, ShareRejection};
+use crate::contributor::sink::{AggregationResult, AggregationSink};
+use crate::contributor::types::AggregationData;
+use crate::contributor::{
+    AggregationInput, Contribute, ContributeError, ContributorBase, MessageClass, OutboundRouter,
+    ParticipationBitmap, SharedSigner,
+};
+use crate::digest::encode_message;
+use crate::logging::LogPolicy;
+use crate::types::ContributorIndex;
+use crate::validation::PayloadValidator;
+use crate::validation::voting::VotingValidator;
+use alloy::rpc::types::TransactionRequest;
+use alloy_dyn_abi::{DynSolValue, JsonAbiExt};
+use alloy_json_abi::Function;
+use alloy_primitives::{Address, U256};
+use anyhow::Result;
+use bn254::{Bn254, PublicKey as PubKey, Signature as Sig, aggregate_verify};
+use bytes::{Buf, BufMut, Bytes};
+use commonware_avs_router::wire::{self, aggregation::Payload};
+use commonware_codec::{EncodeSize, Error, Read, ReadExt, Write};
+use commonware_cryptography::Signer;
+use commonware_p2p::{Receiver, Sender};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::{error, info, warn};
+
+/// Entrypoint called with the aggregated vote of a round
+///
+/// The generated `VotingContract` bindings predate this entrypoint, so the call is
+/// encoded from its signature instead.
+pub const CAST_VOTE_FUNCTION: &str =
+    "function castVote(uint256 round, bytes aggregatedSignature, uint256 quorumBitmap)";
+
+/// Task data of a voting round, carried as the metadata of its Start
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VotingTaskData {
+    /// Proposal voted on
+    pub proposal_id: u64,
+    /// Option voted for, see [crate::validation::voting::VOTE_OPTIONS]
+    pub option: u8,
+}
+
+impl Write for VotingTaskData {
+    fn write(&self, buf: &mut impl BufMut) {
+        self.proposal_id.write(buf);
+        self.option.write(buf);
+    }
+}
+
+impl EncodeSize for VotingTaskData {
+    fn encode_size(&self) -> usize {
+        8 + 1
+    }
+}
+
+impl Read for VotingTaskData {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _: &()) -> Result<Self, Error> {
+        Ok(Self {
+            proposal_id: u64::read(buf)?,
+            option: u8::read(buf)?,
+        })
+    }
+}
+
+/// Transaction casting the aggregated vote of `round` on `contract`
+pub fn cast_vote_transaction(
+    contract: Address,
+    round: u64,
+    signature: &Sig,
+    signers: ParticipationBitmap,
+) -> Result<TransactionRequest> {
+    let function = Function::parse(CAST_VOTE_FUNCTION)?;
+    let calldata = function.abi_encode_input(&[
+        DynSolValue::Uint(U256::from(round), 256),
+        DynSolValue::Bytes(signature.to_vec()),
+        DynSolValue::Uint(signers.to_u256(), 256),
+    ])?;
+    Ok(TransactionRequest::default()
+        .to(contract)
+        .input(calldata.into()))
+}
+
+/// Sink casting each aggregated vote on `contract` through a [ChainSubmitter]
+pub struct VoteSubmitter {
+    submitter: Arc<dyn ChainSubmitter>,
+    contract: Address,
+}
+
+impl VoteSubmitter {
+    /// Submitter casting votes on `contract`
+    pub fn new(submitter: Arc<dyn ChainSubmitter>, contract: Address) -> Self {
+        Self {
+            submitter,
+            contract,
+        }
+    }
+}
+
+impl AggregationSink for VoteSubmitter {
+    fn emit<'a>(&'a self, result: &'a AggregationResult) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let transaction = cast_vote_transaction(
+                self.contract,
+                result.round,
+                &result.signature,
+                result.signers,
+            )?;
+            let hash = self.submitter.submit(transaction).await?;
+            info!(round = result.round, %hash, "cast vote");
+            Ok(())
+        })
+    }
+}
+
+/// When an aggregator casts the vote of a round that reached the threshold
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SubmissionMode {
+    /// Cast the vote the instant the threshold is reached, ignoring later signatures
+    ///
+    /// Fastest, but the aggregate key only covers the first signers.
+    #[default]
+    FastPath,
+    /// Keep collecting signatures for the window after the threshold is reached, then
+    /// cast the vote with every signature received. The vote is cast early once every
+    /// contributor signed.
+    GraceWindow(Duration),
+}
+
+/// Contributor signing voting rounds, casting the vote once the threshold is reached
+///
+/// Unlike the counter [super::Contributor], rounds carry [VotingTaskData] and the
+/// aggregator hands each aggregated vote to an [AggregationSink], usually a
+/// [VoteSubmitter] casting it on-chain.
+pub struct VotingContributor {
+    orchestrator: PubKey,
+    signer: SharedSigner,
+    me: ContributorIndex,
+    contributors: Vec<PubKey>,
+    aggregation_data: Option<AggregationData>,
+    validator: Arc<dyn PayloadValidator>,
+    sink: Option<Arc<dyn AggregationSink>>,
+    submission_mode: SubmissionMode,
+    clock: Arc<dyn Clock>,
+    log_policy: LogPolicy,
+}
+
+/// State of the receive loop
+#[derive(Default)]
+struct RunState {
+    rounds: RoundTable,
+    /// End of the grace window of rounds that reached the threshold
+    windows: HashMap<u64, Instant>,
+}
+
+impl RunState {
+    /// Round whose grace window ends first, with the end of its window
+    fn next_window(&self) -> Option<(u64, Instant)> {
+        self.windows
+            .iter()
+            .map(|(round, deadline)| (*round, *deadline))
+            .min_by_key(|(round, deadline)| (*deadline, *round))
+    }
+}
+
+impl VotingContributor {
+    /// Cast aggregated votes on `contract` through `submitter`
+    pub fn with_submitter(self, submitter: Arc<dyn ChainSubmitter>, contract: Address) -> Self {
+        self.with_aggregation_sink(Arc::new(VoteSubmitter::new(submitter, contract)))
+    }
+
+    /// Hand aggregated votes to `sink` instead of casting them, e.g. a [FileSink]
+    ///
+    /// [FileSink]: crate::contributor::FileSink
+    pub fn with_aggregation_sink(mut self, sink: Arc<dyn AggregationSink>) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    /// Choose when an aggregated vote is cast, immediately at the threshold by default
+    pub fn with_submission_mode(mut self, mode: SubmissionMode) -> Self {
+        self.submission_mode = mode;
+        self
+    }
+
+    /// Use a custom validator instead of the [VotingValidator]
+    pub fn with_validator(mut self, validator: Arc<dyn PayloadValidator>) -> Self {
+        self.validator = validator;
+        self
+    }
+
+    /// Measure grace windows and round deadlines with `clock`
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Log keys, hashes and refused votes as `policy` allows
+    pub fn with_log_policy(mut self, policy: LogPolicy) -> Self {
+        self.log_policy = policy;
+        self
+    }
+
+    /// Validate a Start, then sign it and send the signature to the orchestrator and peers
+    async fn sign_start<S>(
+        &self,
+        state: &mut RunState,
+        router: &mut OutboundRouter<S>,
+        frame: &[u8],
+        message: wire::Aggregation<VotingTaskData>,
+    ) -> Result<()>
+    where
+        S: Sender<PublicKey = PubKey>,
+    {
+        let round = message.round;
+        if state.rounds.is_signed(round) {
+            info!(round, "already signed");
+            return Ok(());
+        }
+        let payload = match self.validator.validate(frame).await {
+            Ok(payload) => payload,
+            Err(err) => {
+                warn!(round, err = %self.log_policy.redacted(&err), "rejected vote");
+                return Ok(());
+            }
+        };
+        state.rounds.start_round(round, payload, None);
+        let signature = match self.signer.sign(None, &payload).await {
+            Ok(signature) => signature,
+            Err(err) => {
+                warn!(round, ?err, "failed to sign, skipping round");
+                state.rounds.abort(round);
+                return Ok(());
+            }
+        };
+        info!(
+            round,
+            proposal_id = message.metadata.proposal_id,
+            option = message.metadata.option,
+            payload = %self.log_policy.short(&payload),
+            "signed vote"
+        );
+        state
+            .rounds
+            .record_own_signature(round, self.me.into(), signature.clone());
+
+        let message = wire::Aggregation::<VotingTaskData> {
+            round,
+            metadata: message.metadata,
+            payload: Some(Payload::Signature(signature.to_vec())),
+        };
+        let buf = Bytes::from(encode_message(&message));
+        router
+            .send(MessageClass::Reply, &self.orchestrator, buf.clone())
+            .await?;
+        router
+            .send(MessageClass::Share, &self.orchestrator, buf)
+            .await?;
+        Ok(())
+    }
+
+    /// Verify a peer's signature and cast the vote once the submission mode allows
+    async fn collect_signature(
+        &self,
+        state: &mut RunState,
+        sender: &PubKey,
+        frame: &[u8],
+        message: wire::Aggregation<VotingTaskData>,
+    ) {
+        let Some(data) = &self.aggregation_data else {
+            return;
+        };
+        let round = message.round;
+        let Some(contributor) = data
+            .ordered_contributors
+            .get(sender)
+            .copied()
+            .map(usize::from)
+        else {
+            info!(sender = %self.log_policy.short(sender), "contributor not found");
+            return;
+        };
+        if let Err(rejection) =
+            state
+                .rounds
+                .check_share(round, contributor, self.clock.monotonic_now())
+        {
+            log_rejected_share(round, contributor, rejection);
+            return;
+        }
+        let signature = match try_classify(&message) {
+            Ok(MessageKind::Signature(signature)) => signature,
+            Ok(_) => return,
+            Err(err) => {
+                info!(contributor, %err, "not a valid signature");
+                return;
+            }
+        };
+        let Ok(payload) = self.validator.validate(frame).await else {
+            info!(contributor, "failed to validate vote");
+            return;
+        };
+        if !aggregate_verify(std::slice::from_ref(sender), None, &payload, &signature) {
+            info!(contributor, "invalid signature from contributor");
+            return;
+        }
+        let signatures = match state.rounds.record_share(
+            round,
+            contributor,
+            signature,
+            self.clock.monotonic_now(),
+        ) {
+            Ok(round_state) => round_state.shares.len(),
+            Err(rejection) => {
+                log_rejected_share(round, contributor, rejection);
+                return;
+            }
+        };
+        if signatures < data.threshold {
+            return;
+        }
+        if let SubmissionMode::GraceWindow(window) = self.submission_mode
+            && signatures < data.contributors.len()
+        {
+            state.windows.entry(round).or_insert_with(|| {
+                info!(round, ?window, "threshold reached, waiting for more votes");
+                self.clock.monotonic_now() + window
+            });
+            return;
+        }
+        self.cast_vote(state, round).await;
+    }
+
+    /// Aggregate the signatures of `round` and cast the vote
+    async fn cast_vote(&self, state: &mut RunState, round: u64) {
+        state.windows.remove(&round);
+        let Some(round_state) = state.rounds.get_mut(round) else {
+            return;
+        };
+        let Some(payload) = round_state.expected_hash else {
+            return;
+        };
+        let (signature, participants) = match aggregate_round(&mut round_state.shares) {
+            AggregationOutcome::Aggregated {
+                signature,
+                participants,
+            } => (signature, participants),
+            AggregationOutcome::Empty => return,
+            AggregationOutcome::Evicted(evicted) => {
+                warn!(round, ?evicted, "failed to aggregate votes");
+                return;
+            }
+        };
+        let Some(signers) = ParticipationBitmap::from_indices(participants) else {
+            error!(
+                round,
+                "participant past the participation bitmap, not casting the vote"
+            );
+            return;
+        };
+        round_state.shares.clear();
+        state.rounds.mark_aggregated(round);
+        info!(round, signers = signers.count(), "aggregated vote");
+
+        let Some(sink) = &self.sink else {
+            return;
+        };
+        let result = AggregationResult {
+            round,
+            payload,
+            signature,
+            signers,
+        };
+        if let Err(err) = sink.emit(&result).await {
+            warn!(round, ?err, "failed to cast vote");
+        }
+    }
+}
+
+impl ContributorBase for VotingContributor {
+    type PublicKey = PubKey;
+    type Signer = Bn254;
+    type Signature = Sig;
+
+    fn is_orchestrator(&self, sender: &Self::PublicKey) -> bool {
+        &self.orchestrator == sender
+    }
+
+    fn get_contributor_index(&self, public_key: &Self::PublicKey) -> Option<&ContributorIndex> {
+        match &self.aggregation_data {
+            Some(data) => data.ordered_contributors.get(public_key),
+            None => None,
+        }
+    }
+
+    fn contributors_for_round(&self, _round: u64) -> Vec<Self::PublicKey> {
+        self.contributors.clone()
+    }
+}
+
+impl Contribute for VotingContributor {
+    type AggregationInput = AggregationInput;
+
+    fn new(
+        orchestrator: PubKey,
+        signer: Bn254,
+        contributors: Vec<PubKey>,
+        aggregation_input: Option<AggregationInput>,
+    ) -> Result<Self, ContributeError> {
+        let contributors = deduplicate_contributors(contributors);
+        let own_key = canonicalize_key(&signer.public_key());
+        let me = contributors
+            .iter()
+            .position(|contributor| *contributor == own_key)
+            .map(ContributorIndex::from)
+            .ok_or(ContributeError::SignerNotInContributors)?;
+        let aggregation_data = aggregation_input.map(|aggregation_input| {
+            AggregationData::from((aggregation_input, contributors.clone()))
+        });
+        Ok(Self {
+            orchestrator,
+            signer: Arc::new(signer),
+            me,
+            contributors,
+            aggregation_data,
+            validator: Arc::new(VotingValidator),
+            sink: None,
+            submission_mode: SubmissionMode::default(),
+            clock: Arc::new(SystemClock),
+            log_policy: LogPolicy::default(),
+        })
+    }
+
+    async fn run<S, R>(self, mut router: OutboundRouter<S>, mut receiver: R) -> Result<()>
+    where
+        S: Sender<PublicKey = PubKey>,
+        R: Receiver<PublicKey = PubKey>,
+    {
+        let mut state = RunState {
+            rounds: RoundTable::with_clock(self.clock.clone()),
+            ..RunState::default()
+        };
+        loop {
+            let window = state.next_window();
+            let (sender, frame) = tokio::select! {
+                result = receiver.recv() => match result {
+                    Ok(received) => received,
+                    Err(_) => break,
+                },
+                round = window_end(self.clock.as_ref(), window) => {
+                    self.cast_vote(&mut state, round).await;
+                    continue;
+                }
+            };
+            let message: wire::Aggregation<VotingTaskData> =
+                match wire::Aggregation::read(&mut std::io::Cursor::new(&frame[..])) {
+                    Ok(message) => message,
+                    Err(err) => {
+                        log_decode_error(self.log_policy, &sender, "vote", &frame, &err);
+                        continue;
+                    }
+                };
+            // An orchestrator may also contribute, only its Starts are control
+            if self.is_orchestrator(&sender) {
+                if classify(&message) == MessageKind::Start {
+                    self.sign_start(&mut state, &mut router, &frame, message)
+                        .await?;
+                    continue;
+                }
+                if self.get_contributor_index(&sender).is_none() {
+                    continue;
+                }
+            }
+            self.collect_signature(&mut state, &sender, &frame, message)
+                .await;
+        }
+        Ok(())
+    }
+}
+
+/// Round whose grace window ended, never resolving if no window is open
+async fn window_end(clock: &dyn Clock, window: Option<(u64, Instant)>) -> u64 {
+    match window {
+        Some((round, deadline)) => {
+            clock.sleep_until(deadline).await;
+            round
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Report a share the round table did not record
+fn log_rejected_share(round: u64, contributor: usize, rejection: ShareRejection) {
+    match rejection {
+        ShareRejection::Dropped(dropped) => {
+            info!(round, contributor, reason = dropped.kind(), "dropped share");
+        }
+        ShareRejection::Duplicate => info!(contributor, "contributor already signed"),
+    }
+}
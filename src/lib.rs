@@ -0,0 +1,36 @@
+//! Contributor node aggregating BN254 signatures for EigenLayer AVS tasks.
+#![deny(missing_docs)]
+// Denied where time and logging go through the clock and the log policy, see clippy.toml
+#![allow(clippy::disallowed_methods)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::unreachable,
+        clippy::indexing_slicing
+    )
+)]
+pub mod api_types;
+pub mod bindings;
+pub mod chain;
+pub mod clock;
+pub mod collections;
+pub mod config;
+pub mod contributor;
+pub mod crypto;
+pub mod digest;
+pub mod execution;
+pub mod handlers;
+pub mod logging;
+#[cfg(feature = "observability")]
+pub mod metrics;
+pub mod p2p;
+pub mod pipeline;
+pub mod runner;
+#[cfg(feature = "http-api")]
+pub mod server;
+pub mod types;
+pub mod validation;
+pub mod wire;
@@ -0,0 +1,135 @@
+//! Formatting of logged values under the node's logging policy.
+//!
+//! Keys, signatures and hashes are logged through [LogPolicy::short], as the hex of
+//! their first [PREFIX_LEN] bytes: enough to tell them apart without filling the logs.
+//! Values the policy keeps out of the logs altogether, such as the contents of a
+//! payload the node refused to sign, go through [LogPolicy::redacted]. Both are written
+//! in full under a policy with [full_values](LogPolicy::full_values), which
+//! `--log-full-values` sets and the node hands to its contributor through
+//! [RunConfig](crate::handlers::RunConfig). Code logging outside a contributor uses
+//! [short], the default policy.
+//!
+//! Every formatter implements [Display](fmt::Display) over borrowed bytes, so they are
+//! passed as tracing fields with `%` without allocating.
+
+use std::fmt;
+
+/// Number of leading bytes of a key, signature or hash logged by default
+pub const PREFIX_LEN: usize = 8;
+
+/// Placeholder written in place of a redacted value
+pub const REDACTED: &str = "[redacted]";
+
+/// What the logs show of keys, signatures, hashes and refused values
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LogPolicy {
+    /// Log keys, signatures and hashes in full, and redacted values as they are
+    pub full_values: bool,
+}
+
+impl LogPolicy {
+    /// Log `value` as the hex of its first [PREFIX_LEN] bytes, or in full
+    pub fn short<'a, T: AsRef<[u8]> + ?Sized>(&self, value: &'a T) -> Short<'a> {
+        Short {
+            value: value.as_ref(),
+            full: self.full_values,
+        }
+    }
+
+    /// Log `values` as a list of [short](Self::short) values
+    pub fn short_list<'a, T>(&self, values: &'a [T]) -> ShortList<'a, T> {
+        ShortList {
+            values,
+            policy: *self,
+        }
+    }
+
+    /// Keep `value` out of the logs, unless logging in full
+    pub fn redacted<T>(&self, value: T) -> Redacted<T> {
+        Redacted {
+            value,
+            full: self.full_values,
+        }
+    }
+}
+
+/// Bytes written in hex, whatever the policy
+///
+/// Only for values already bounded, such as the logged prefix of a dropped frame.
+#[derive(Clone, Copy)]
+pub struct Hex<'a>(pub &'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Key, signature or hash written in hex, truncated to [PREFIX_LEN] bytes by default
+#[derive(Clone, Copy)]
+pub struct Short<'a> {
+    value: &'a [u8],
+    full: bool,
+}
+
+/// Log `value` as the hex of its first [PREFIX_LEN] bytes, under the default policy
+pub fn short<T: AsRef<[u8]> + ?Sized>(value: &T) -> Short<'_> {
+    LogPolicy::default().short(value)
+}
+
+impl fmt::Display for Short<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.get(..PREFIX_LEN) {
+            Some(prefix) if !self.full && self.value.len() > PREFIX_LEN => {
+                write!(f, "{}..", Hex(prefix))
+            }
+            _ => fmt::Display::fmt(&Hex(self.value), f),
+        }
+    }
+}
+
+impl fmt::Debug for Short<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Keys written as a list of [short] values
+#[derive(Clone, Copy)]
+pub struct ShortList<'a, T> {
+    values: &'a [T],
+    policy: LogPolicy,
+}
+
+impl<T: AsRef<[u8]>> fmt::Display for ShortList<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.values.iter().map(|value| self.policy.short(value)))
+            .finish()
+    }
+}
+
+/// Value kept out of the logs, written only when values are logged in full
+#[derive(Clone, Copy)]
+pub struct Redacted<T> {
+    value: T,
+    full: bool,
+}
+
+impl<T: fmt::Display> fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.full {
+            return self.value.fmt(f);
+        }
+        f.write_str(REDACTED)
+    }
+}
@@ -1,12 +1,24 @@
 //! Aggregate signatures from multiple contributors over the BN254 curve.
 //!
 //! # Usage (3 of 4 Threshold)
-mod bindings;
-mod contributor;
-mod handlers;
+#![allow(clippy::disallowed_methods)]
+use alloy_primitives::Address;
 use ark_bn254::Fr;
 use bn254::{Bn254, PrivateKey};
 use clap::{Arg, Command};
+use commonware_avs_node::chain::{
+    BlockTimeSource, ClockSkewMonitor, HttpDateSource, ServiceManagerClient,
+};
+use commonware_avs_node::clock::{Clock, SystemClock};
+use commonware_avs_node::config::NodeConfig;
+use commonware_avs_node::crypto::NodeIdentity;
+use commonware_avs_node::logging::LogPolicy;
+use commonware_avs_node::runner::{NodeRunner, StartupTask};
+#[cfg(feature = "http-api")]
+use commonware_avs_node::server::HealthCheckServer;
+#[cfg(feature = "http-api")]
+use commonware_avs_node::server::health::DEFAULT_HEARTBEAT_INTERVAL;
+use commonware_avs_node::{contributor, handlers};
 use commonware_eigenlayer::network_configuration::{EigenStakingClient, QuorumInfo};
 use commonware_p2p::authenticated::lookup::{self, Network};
 use commonware_runtime::{
@@ -14,7 +26,10 @@ use commonware_runtime::{
     tokio::{self},
 };
 use commonware_utils::NZU32;
-use contributor::{AggregationInput, Contribute};
+use contributor::archive::parse_date;
+use contributor::{
+    AggregationInput, ArchiveConfig, Contribute, FileSink, OutboundRouter, RoundArchive,
+};
 use eigen_logging::log_level::LogLevel;
 use governor::Quota;
 use serde::{Deserialize, Serialize};
@@ -23,6 +38,12 @@ use std::env;
 use std::fs;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::Instrument;
+
+#[cfg(feature = "http-api")]
+use ::tokio::sync::watch;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[allow(non_snake_case)]
@@ -39,9 +60,12 @@ struct OrchestratorConfig {
     port: String,
 }
 
+fn get_secret(key: &str) -> Fr {
+    Fr::from_str(key).expect("Invalid decimal string for private key")
+}
+
 fn get_signer(key: &str) -> Bn254 {
-    let fr = Fr::from_str(key).expect("Invalid decimal string for private key");
-    let key = PrivateKey::from(fr);
+    let key = PrivateKey::from(get_secret(key));
     Bn254::new(key).expect("Failed to create signer")
 }
 
@@ -90,9 +114,112 @@ fn configure_orchestrator(matches: &clap::ArgMatches) -> OrchestratorConfig {
     load_orchestrator_config(orchestrator_file)
 }
 
-async fn get_operator_states() -> Result<Vec<QuorumInfo>, Box<dyn std::error::Error>> {
-    dotenv::dotenv().ok();
+fn export_identity(matches: &clap::ArgMatches) {
+    let key = load_key_from_file(
+        matches
+            .get_one::<String>("key-file")
+            .expect("Please provide key file"),
+    );
+    let p2p_address = matches
+        .get_one::<String>("p2p-address")
+        .expect("Please provide p2p address")
+        .parse::<SocketAddr>()
+        .expect("p2p address not well-formed");
+    let operator = matches
+        .get_one::<String>("operator")
+        .map(|operator| Address::from_str(operator).expect("operator address not well-formed"));
+
+    let identity = NodeIdentity::export(&get_signer(&key), get_secret(&key), operator, p2p_address)
+        .expect("Could not export identity");
+    let contents = serde_json::to_string_pretty(&identity).expect("identity serializes");
+    match matches.get_one::<String>("output") {
+        Some(path) => fs::write(path, contents).expect("Could not write identity file"),
+        None => println!("{contents}"),
+    }
+}
+
+fn verify_identity(matches: &clap::ArgMatches) {
+    let path = matches
+        .get_one::<String>("file")
+        .expect("Please provide identity file");
+    let contents = fs::read_to_string(path).expect("Could not read identity file");
+    let identity: NodeIdentity =
+        serde_json::from_str(&contents).expect("Could not parse identity file");
+    if let Err(err) = identity.verify() {
+        eprintln!("identity rejected: {err}");
+        std::process::exit(1);
+    }
+
+    let claims = &identity.claims;
+    println!("public key:  {}", claims.public_key);
+    println!("g2_x1:       {}", claims.g2.x[0]);
+    println!("g2_x2:       {}", claims.g2.x[1]);
+    println!("g2_y1:       {}", claims.g2.y[0]);
+    println!("g2_y2:       {}", claims.g2.y[1]);
+    println!("g1_x:        {}", claims.g1.x);
+    println!("g1_y:        {}", claims.g1.y);
+    if let Some(operator) = claims.operator {
+        println!("operator:    {operator}");
+    }
+    println!("p2p address: {}", claims.p2p_address);
+}
+
+fn archive_rounds(matches: &clap::ArgMatches) {
+    let dir = matches
+        .get_one::<String>("data-dir")
+        .expect("Please provide data dir");
+    let before = matches
+        .get_one::<String>("before")
+        .expect("Please provide a date");
+    let before = parse_date(before).expect("before is not a YYYY-MM-DD date");
+    let mut config = ArchiveConfig::default();
+    if let Some(rounds) = matches.get_one::<String>("rounds-per-bundle") {
+        config.rounds_per_bundle = rounds.parse().expect("rounds per bundle not a number");
+    }
+    let bundles = RoundArchive::new(dir, config)
+        .archive(before)
+        .expect("Could not archive rounds");
+    for bundle in bundles {
+        println!(
+            "{}: rounds {} to {} ({} rounds)",
+            bundle.file, bundle.first_round, bundle.last_round, bundle.rounds
+        );
+    }
+}
+
+fn list_rounds(matches: &clap::ArgMatches) {
+    let dir = matches
+        .get_one::<String>("data-dir")
+        .expect("Please provide data dir");
+    let include_archived = matches.get_flag("include-archived");
+    let rounds = RoundArchive::new(dir, ArchiveConfig::default())
+        .rounds(include_archived)
+        .expect("Could not read rounds");
+    for round in rounds {
+        let record = serde_json::to_string(&round.to_record()).expect("record serializes");
+        println!("{record}");
+    }
+}
+
+/// Log the name and version of the AVS, if its `ServiceManager` is configured
+async fn log_avs_metadata() -> anyhow::Result<()> {
+    let Ok(service_manager) = env::var("SERVICE_MANAGER_ADDRESS") else {
+        tracing::info!("SERVICE_MANAGER_ADDRESS not set, skipping avs metadata");
+        return Ok(());
+    };
+    let http_rpc = env::var("HTTP_RPC").expect("HTTP_RPC must be set");
+    let client = ServiceManagerClient::new(&http_rpc, service_manager.parse()?)?;
+    let metadata = client.get_avs_metadata().await?;
+    tracing::info!(
+        name = %metadata.name,
+        version = %metadata.version,
+        service_manager = %client.address(),
+        "loaded avs metadata"
+    );
+    Ok(())
+}
 
+async fn get_operator_states() -> Result<Vec<QuorumInfo>, Box<dyn std::error::Error>> {
     let http_rpc = env::var("HTTP_RPC").expect("HTTP_RPC must be set");
     let ws_rpc = env::var("WS_RPC").expect("WS_RPC must be set");
     let avs_deployment_path =
@@ -104,6 +231,9 @@ async fn get_operator_states() -> Result<Vec<QuorumInfo>, Box<dyn std::error::Er
 }
 
 fn main() {
+    // Read the .env file, if any, before anything reads the environment
+    dotenv::dotenv().ok();
+
     // Initialize runtime
     let runtime_cfg = tokio::Config::default();
     let runner = tokio::Runner::new(runtime_cfg.clone());
@@ -111,6 +241,89 @@ fn main() {
     // Parse arguments
     let matches = Command::new("commonware-aggregation")
         .about("generate and verify BN254 Multi-Signatures")
+        .subcommand_negates_reqs(true)
+        .subcommand(
+            Command::new("identity")
+                .about("export or verify the identity file handed to the orchestrator")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("export")
+                        .about("write the node's signed identity file")
+                        .arg(
+                            Arg::new("key-file")
+                                .long("key-file")
+                                .required(true)
+                                .help("Path to the YAML file containing the private key"),
+                        )
+                        .arg(
+                            Arg::new("p2p-address")
+                                .long("p2p-address")
+                                .required(true)
+                                .help("Address other nodes reach the p2p listener on"),
+                        )
+                        .arg(
+                            Arg::new("operator")
+                                .long("operator")
+                                .required(false)
+                                .help("Operator address registered with the AVS"),
+                        )
+                        .arg(
+                            Arg::new("output")
+                                .long("output")
+                                .short('o')
+                                .required(false)
+                                .help("Path to write the identity file to, stdout if omitted"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("verify")
+                        .about("check an identity file's self-signature and print its keys")
+                        .arg(
+                            Arg::new("file")
+                                .required(true)
+                                .help("Path to the identity file"),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("archive")
+                .about("move aggregates written before a date into compressed bundles")
+                .arg(
+                    Arg::new("data-dir")
+                        .long("data-dir")
+                        .required(true)
+                        .help("Directory the aggregates are written to"),
+                )
+                .arg(
+                    Arg::new("before")
+                        .long("before")
+                        .required(true)
+                        .help("Archive aggregates written before this UTC date, YYYY-MM-DD"),
+                )
+                .arg(
+                    Arg::new("rounds-per-bundle")
+                        .long("rounds-per-bundle")
+                        .required(false)
+                        .help("Rounds per bundle"),
+                ),
+        )
+        .subcommand(
+            Command::new("rounds")
+                .about("print the aggregates as JSON lines, by round")
+                .arg(
+                    Arg::new("data-dir")
+                        .long("data-dir")
+                        .required(true)
+                        .help("Directory the aggregates are written to"),
+                )
+                .arg(
+                    Arg::new("include-archived")
+                        .long("include-archived")
+                        .num_args(0)
+                        .action(clap::ArgAction::SetTrue)
+                        .help("Read the archived bundles as well"),
+                ),
+        )
         .arg(
             Arg::new("key-file")
                 .long("key-file")
@@ -136,25 +349,123 @@ fn main() {
                 .num_args(0)
                 .help("turn on aggregation"),
         )
+        .arg(
+            Arg::new("log-full-values")
+                .long("log-full-values")
+                .num_args(0)
+                .action(clap::ArgAction::SetTrue)
+                .help("Log keys, signatures and hashes in full, and values redacted by default"),
+        )
         .get_matches();
 
+    match matches.subcommand() {
+        Some(("identity", identity)) => {
+            match identity.subcommand() {
+                Some(("export", matches)) => export_identity(matches),
+                Some(("verify", matches)) => verify_identity(matches),
+                _ => unreachable!("identity requires a subcommand"),
+            }
+            return;
+        }
+        Some(("archive", matches)) => return archive_rounds(matches),
+        Some(("rounds", matches)) => return list_rounds(matches),
+        _ => {}
+    }
+
+    // Keys and signatures are truncated in logs unless asked otherwise
+    let log_policy = LogPolicy {
+        full_values: matches.get_flag("log-full-values"),
+    };
+
     // Configure my identity
     let (signer, port) = configure_identity(&matches);
     let orchestrator_config = configure_orchestrator(&matches);
     let aggregation: bool = matches.contains_id("aggregation");
 
-    // Get operator states
-
     // Start runtime
     runner.start(|context: tokio::Context| async move {
         let mut recipients: Vec<(bn254::PublicKey, SocketAddr)> = Vec::new();
         // Scoped to avoid configuring two loggers
         let orchestrator_pub_key;
+        let quorum_infos;
+        // Beaten by the receive loop, and the p2p network once started
+        #[cfg(feature = "http-api")]
+        let (heartbeat, heartbeat_receiver) = watch::channel(::tokio::time::Instant::now());
+        #[cfg(feature = "http-api")]
+        let (p2p_started, p2p) = watch::channel(false);
+        let clock_skew;
         {
             eigen_logging::init_logger(LogLevel::Debug);
-            let quorum_infos = get_operator_states()
-                .await
-                .expect("Failed to get operator states");
+            let node_config = NodeConfig::from_env().expect("invalid node configuration");
+            let http_rpc = env::var("HTTP_RPC").expect("HTTP_RPC must be set");
+            node_config
+                .check_rpc_url(&http_rpc)
+                .expect("HTTP_RPC must be served over TLS");
+
+            // A skewed clock refuses valid rounds or signs expired ones
+            let mut monitor = ClockSkewMonitor::new(node_config.clock_skew.clone()).with_source(
+                Arc::new(BlockTimeSource::from_url(&http_rpc).expect("invalid HTTP_RPC")),
+            );
+            if let Some(url) = &node_config.time_url {
+                let source = HttpDateSource::new(reqwest::Client::new(), url.clone());
+                monitor = monitor.with_source(Arc::new(source));
+            }
+            let monitor = Arc::new(monitor);
+            clock_skew = monitor.subscribe();
+
+            // Load chain state before joining the network
+            let operator_states = Arc::new(Mutex::new(None));
+            let mut startup = NodeRunner::new()
+                .with_task(StartupTask::new("operator_state", {
+                    let operator_states = operator_states.clone();
+                    move || async move {
+                        let states = get_operator_states().await.map_err(|err| {
+                            anyhow::anyhow!("failed to get operator states: {err}")
+                        })?;
+                        *operator_states.lock().unwrap() = Some(states);
+                        Ok(())
+                    }
+                }))
+                .with_task(StartupTask::new("avs_metadata", log_avs_metadata).optional())
+                .with_task(StartupTask::new("clock_skew", {
+                    let monitor = monitor.clone();
+                    move || async move { monitor.ensure_within_tolerance().await.map(|_| ()) }
+                }));
+
+            // Probes are served during startup, readiness reports its progress
+            #[cfg(feature = "http-api")]
+            if let Some(health_port) = node_config.health_port {
+                let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), health_port);
+                let listener = ::tokio::net::TcpListener::bind(addr)
+                    .await
+                    .expect("failed to bind HEALTH_PORT");
+                let server = HealthCheckServer::new(heartbeat_receiver)
+                    .with_startup(startup.readiness())
+                    .with_p2p(p2p)
+                    .with_clock_skew(monitor.subscribe(), node_config.clock_skew.hard_threshold);
+                let span = tracing::Span::current();
+                context.with_label("health").spawn(|_| {
+                    async move {
+                        if let Err(err) = server.serve(listener).await {
+                            tracing::error!(?err, "health server stopped");
+                        }
+                    }
+                    .instrument(span)
+                });
+            }
+            if let Err(err) = startup.start().await {
+                panic!("{err}");
+            }
+            let span = tracing::Span::current();
+            context
+                .with_label("clock_skew")
+                .spawn(|_| async move { monitor.run().await }.instrument(span));
+            quorum_infos = operator_states
+                .lock()
+                .unwrap()
+                .take()
+                .expect("operator state loaded");
+
             // Configure allowed peers
             let participants = quorum_infos[0].operators.clone(); //TODO: Fix hardcoded quorum_number
             if participants.is_empty() {
@@ -162,7 +473,7 @@ fn main() {
             }
             for participant in &participants {
                 let verifier = participant.pub_keys.as_ref().unwrap().g2_pub_key.clone();
-                tracing::info!(key = ?verifier, "registered authorized key",);
+                tracing::info!(key = %log_policy.short(&verifier), "registered authorized key",);
                 if let Some(socket) = &participant.socket {
                     let socket_addr =
                         SocketAddr::from_str(socket).expect("contributor address not well-formed");
@@ -210,9 +521,6 @@ fn main() {
         // Parse contributors from operator states
         let mut contributors = Vec::new();
         let mut contributors_map = HashMap::new();
-        let quorum_infos = get_operator_states()
-            .await
-            .expect("Failed to get operator states");
         let operators = &quorum_infos[0].operators;
         if operators.is_empty() {
             panic!("Please provide at least one contributor");
@@ -220,31 +528,70 @@ fn main() {
         for operator in operators {
             let verifier = operator.pub_keys.as_ref().unwrap().g2_pub_key.clone();
             let verifier_g1 = operator.pub_keys.as_ref().unwrap().g1_pub_key.clone();
-            tracing::info!(key = ?verifier, "registered contributor",);
+            tracing::info!(key = %log_policy.short(&verifier), "registered contributor",);
             contributors.push(verifier.clone());
             contributors_map.insert(verifier, verifier_g1);
         }
 
         // Check if I am the orchestrator
         const DEFAULT_MESSAGE_BACKLOG: usize = 256;
+        const ARCHIVE_INTERVAL: Duration = Duration::from_secs(60 * 60);
 
         // Create contributor
         let (sender, receiver) =
             network.register(0, Quota::per_second(NZU32!(1)), DEFAULT_MESSAGE_BACKLOG);
 
-        let mut aggregation_input: Option<AggregationInput> = None;
+        let mut builder = handlers::Contributor::builder()
+            .orchestrator(orchestrator_pub_key)
+            .signer(signer);
         if aggregation {
             let signatures_needed = contributors.len();
-            aggregation_input = Some(AggregationInput::new(signatures_needed, contributors_map));
+            builder =
+                builder.aggregation(AggregationInput::new(signatures_needed, contributors_map));
         }
-        let contributor = handlers::Contributor::new(
-            orchestrator_pub_key,
-            signer,
-            contributors,
-            aggregation_input,
-        );
-        context.spawn(|_| async move { contributor.run(sender, receiver).await });
+        // The archive reads time from the same clock as the contributor
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let run_config = handlers::RunConfig {
+            log_policy,
+            #[cfg(feature = "http-api")]
+            heartbeat: Some((DEFAULT_HEARTBEAT_INTERVAL, Arc::new(heartbeat))),
+            ..handlers::RunConfig::default()
+        };
+        let mut contributor = builder
+            .contributors(contributors)
+            .build()
+            .expect("invalid contributor configuration")
+            .with_clock(clock.clone())
+            .with_run_config(run_config);
+        // Air-gapped setups write aggregates to files submitted from elsewhere
+        if let Ok(dir) = env::var("AGGREGATE_OUTPUT_DIR") {
+            let sink = FileSink::new(&dir).expect("invalid AGGREGATE_OUTPUT_DIR");
+            contributor = contributor.with_aggregation_sink(Arc::new(sink));
+            // Aggregates past the retention are moved into bundles under `archive/`
+            if let Ok(days) = env::var("ARCHIVE_RETENTION_DAYS") {
+                let days: u64 = days.parse().expect("ARCHIVE_RETENTION_DAYS not a number");
+                let config = ArchiveConfig {
+                    retention: Duration::from_secs(days * 24 * 60 * 60),
+                    ..ArchiveConfig::default()
+                };
+                let archive = RoundArchive::new(dir, config).with_clock(clock);
+                let span = tracing::Span::current();
+                context
+                    .with_label("archive")
+                    .spawn(|_| archive.run(ARCHIVE_INTERVAL).instrument(span));
+            }
+        }
+        let contributor = contributor.with_clock_skew(clock_skew);
+        let span = tracing::Span::current();
+        context.spawn(|_| {
+            contributor
+                .run(OutboundRouter::single(sender), receiver)
+                .instrument(span)
+        });
 
-        let _ = network.start().await;
+        let network = network.start();
+        #[cfg(feature = "http-api")]
+        p2p_started.send_replace(true);
+        let _ = network.await;
     });
 }
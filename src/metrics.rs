@@ -0,0 +1,465 @@
+//! Prometheus metrics for aggregation rounds, labelled by quorum.
+
+use crate::contributor::events::EventSink;
+use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::histogram::{Histogram, exponential_buckets};
+use prometheus_client::registry::Registry;
+use std::time::Duration;
+
+/// Label attached to every round-level metric
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct QuorumLabel {
+    /// Quorum the round belongs to
+    pub quorum_id: u8,
+}
+
+/// Label of Starts rejected before validation, or of peer signatures dropped
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RejectionLabel {
+    /// Quorum the round belongs to
+    pub quorum_id: u8,
+    /// Short classification of the rejection
+    pub reason: String,
+}
+
+fn latency_histogram() -> Histogram {
+    // 1ms to ~65s
+    Histogram::new(exponential_buckets(0.001, 2.0, 17))
+}
+
+/// Round-level metrics of a contributor
+///
+/// Cloning is cheap and clones share the underlying values.
+#[derive(Clone, Debug)]
+pub struct Metrics {
+    /// Aggregation rounds started
+    pub aggregation_rounds: Family<QuorumLabel, Counter>,
+    /// Valid signatures received from contributors
+    pub signatures_received: Family<QuorumLabel, Counter>,
+    /// Rounds that collected enough signatures to aggregate
+    pub aggregation_threshold_reached: Family<QuorumLabel, Counter>,
+    /// Failed attempts to aggregate signatures
+    pub aggregation_failures: Family<QuorumLabel, Counter>,
+    /// Time from signing a round to aggregating its signatures
+    pub aggregation_latency: Family<QuorumLabel, Histogram>,
+    /// Time spent validating a round payload
+    pub validation_duration: Family<QuorumLabel, Histogram>,
+    /// Starts rejected for inconsistent metadata
+    pub metadata_rejections: Family<RejectionLabel, Counter>,
+    /// Peer signatures dropped before verification
+    pub shares_dropped: Family<RejectionLabel, Counter>,
+    /// Rounds retired after their response was submitted on-chain
+    pub rounds_completed_on_chain: Family<QuorumLabel, Counter>,
+    /// Peer frames or signatures that failed to decode
+    pub decode_failures: Family<RejectionLabel, Counter>,
+    /// Times a peer was quarantined for repeated decode failures
+    pub peers_quarantined: Family<QuorumLabel, Counter>,
+    /// Times a peer was ignored for scoring too low
+    pub peers_ignored: Family<QuorumLabel, Counter>,
+    /// Final aggregates from the orchestrator not matching ours
+    pub aggregate_mismatches: Family<QuorumLabel, Counter>,
+    /// Shares of executed rounds signing another digest than ours
+    pub digest_disagreements: Family<QuorumLabel, Counter>,
+    /// Tasks with an executor failing or timing out
+    pub executions_failed: Family<QuorumLabel, Counter>,
+    /// Rounds in flight preempted to admit a newer round
+    pub rounds_preempted: Family<QuorumLabel, Counter>,
+    /// Times no Start was received for longer than the stale threshold
+    pub orchestrator_stale_alerts: Family<QuorumLabel, Counter>,
+    /// Starts rejected at the maximum number of active rounds
+    pub rounds_rejected_capacity: Family<QuorumLabel, Counter>,
+    /// Frames received from peers and the orchestrator
+    pub messages_received: Family<QuorumLabel, Counter>,
+    /// Round payloads signed
+    pub signatures_produced: Family<QuorumLabel, Counter>,
+    /// Peer signatures failing verification
+    pub invalid_signatures: Family<QuorumLabel, Counter>,
+    /// Repeated signatures from a peer for a round
+    pub duplicate_shares: Family<QuorumLabel, Counter>,
+    /// Round payloads failing validation
+    pub validation_failures: Family<QuorumLabel, Counter>,
+    /// Rounds passing their deadline before aggregating
+    pub rounds_timed_out: Family<QuorumLabel, Counter>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    /// Metrics with every value at zero
+    pub fn new() -> Self {
+        Self {
+            aggregation_rounds: Family::default(),
+            signatures_received: Family::default(),
+            aggregation_threshold_reached: Family::default(),
+            aggregation_failures: Family::default(),
+            aggregation_latency:
+                Family::<QuorumLabel, Histogram, fn() -> Histogram>::new_with_constructor(
+                    latency_histogram,
+                ),
+            validation_duration:
+                Family::<QuorumLabel, Histogram, fn() -> Histogram>::new_with_constructor(
+                    latency_histogram,
+                ),
+            metadata_rejections: Family::default(),
+            shares_dropped: Family::default(),
+            rounds_completed_on_chain: Family::default(),
+            decode_failures: Family::default(),
+            peers_quarantined: Family::default(),
+            peers_ignored: Family::default(),
+            aggregate_mismatches: Family::default(),
+            digest_disagreements: Family::default(),
+            executions_failed: Family::default(),
+            rounds_preempted: Family::default(),
+            orchestrator_stale_alerts: Family::default(),
+            rounds_rejected_capacity: Family::default(),
+            messages_received: Family::default(),
+            signatures_produced: Family::default(),
+            invalid_signatures: Family::default(),
+            duplicate_shares: Family::default(),
+            validation_failures: Family::default(),
+            rounds_timed_out: Family::default(),
+        }
+    }
+
+    /// Register all metrics with the given registry
+    pub fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "aggregation_rounds",
+            "Number of aggregation rounds started",
+            self.aggregation_rounds.clone(),
+        );
+        registry.register(
+            "signatures_received",
+            "Number of valid signatures received from contributors",
+            self.signatures_received.clone(),
+        );
+        registry.register(
+            "aggregation_threshold_reached",
+            "Number of rounds that collected enough signatures to aggregate",
+            self.aggregation_threshold_reached.clone(),
+        );
+        registry.register(
+            "aggregation_failures",
+            "Number of failed attempts to aggregate signatures",
+            self.aggregation_failures.clone(),
+        );
+        registry.register(
+            "aggregation_latency_seconds",
+            "Time from signing a round to aggregating its signatures",
+            self.aggregation_latency.clone(),
+        );
+        registry.register(
+            "avs_validation_duration_seconds",
+            "Time spent validating a round payload",
+            self.validation_duration.clone(),
+        );
+        registry.register(
+            "metadata_rejections",
+            "Number of Starts rejected for inconsistent metadata",
+            self.metadata_rejections.clone(),
+        );
+        registry.register(
+            "shares_dropped",
+            "Number of peer signatures dropped before verification",
+            self.shares_dropped.clone(),
+        );
+        registry.register(
+            "rounds_completed_on_chain",
+            "Number of rounds retired after their response was submitted on-chain",
+            self.rounds_completed_on_chain.clone(),
+        );
+        registry.register(
+            "decode_failures",
+            "Number of peer frames or signatures that failed to decode",
+            self.decode_failures.clone(),
+        );
+        registry.register(
+            "peers_quarantined",
+            "Number of times a peer was quarantined for repeated decode failures",
+            self.peers_quarantined.clone(),
+        );
+        registry.register(
+            "peers_ignored",
+            "Number of times a peer was ignored for scoring too low",
+            self.peers_ignored.clone(),
+        );
+        registry.register(
+            "aggregate_mismatches",
+            "Number of final aggregates from the orchestrator not matching ours",
+            self.aggregate_mismatches.clone(),
+        );
+        registry.register(
+            "digest_disagreements",
+            "Number of shares of executed rounds signing another digest than ours",
+            self.digest_disagreements.clone(),
+        );
+        registry.register(
+            "executions_failed",
+            "Number of tasks with an executor failing or timing out",
+            self.executions_failed.clone(),
+        );
+        registry.register(
+            "rounds_preempted",
+            "Number of rounds in flight preempted to admit a newer round",
+            self.rounds_preempted.clone(),
+        );
+        registry.register(
+            "orchestrator_stale_alerts",
+            "Number of times no Start was received for longer than the stale threshold",
+            self.orchestrator_stale_alerts.clone(),
+        );
+        registry.register(
+            "rounds_rejected_capacity",
+            "Number of Starts rejected at the maximum number of active rounds",
+            self.rounds_rejected_capacity.clone(),
+        );
+        registry.register(
+            "messages_received",
+            "Number of frames received from peers and the orchestrator",
+            self.messages_received.clone(),
+        );
+        registry.register(
+            "signatures_produced",
+            "Number of round payloads signed",
+            self.signatures_produced.clone(),
+        );
+        registry.register(
+            "invalid_signatures",
+            "Number of peer signatures failing verification",
+            self.invalid_signatures.clone(),
+        );
+        registry.register(
+            "duplicate_shares",
+            "Number of repeated signatures from a peer for a round",
+            self.duplicate_shares.clone(),
+        );
+        registry.register(
+            "validation_failures",
+            "Number of round payloads failing validation",
+            self.validation_failures.clone(),
+        );
+        registry.register(
+            "rounds_timed_out",
+            "Number of rounds passing their deadline before aggregating",
+            self.rounds_timed_out.clone(),
+        );
+    }
+}
+
+impl EventSink for Metrics {
+    fn round_started(&self, quorum_id: u8) {
+        self.aggregation_rounds
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn signature_received(&self, quorum_id: u8) {
+        self.signatures_received
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn threshold_reached(&self, quorum_id: u8) {
+        self.aggregation_threshold_reached
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn aggregation_failed(&self, quorum_id: u8) {
+        self.aggregation_failures
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn observe_latency(&self, quorum_id: u8, latency: Duration) {
+        self.aggregation_latency
+            .get_or_create(&QuorumLabel { quorum_id })
+            .observe(latency.as_secs_f64());
+    }
+
+    fn observe_validation(&self, quorum_id: u8, duration: Duration) {
+        self.validation_duration
+            .get_or_create(&QuorumLabel { quorum_id })
+            .observe(duration.as_secs_f64());
+    }
+
+    fn metadata_rejected(&self, quorum_id: u8, reason: &str) {
+        self.metadata_rejections
+            .get_or_create(&RejectionLabel {
+                quorum_id,
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
+
+    fn share_dropped(&self, quorum_id: u8, reason: &str) {
+        self.shares_dropped
+            .get_or_create(&RejectionLabel {
+                quorum_id,
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
+
+    fn round_completed_on_chain(&self, quorum_id: u8) {
+        self.rounds_completed_on_chain
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn decode_failed(&self, quorum_id: u8, reason: &str) {
+        self.decode_failures
+            .get_or_create(&RejectionLabel {
+                quorum_id,
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
+
+    fn peer_quarantined(&self, quorum_id: u8) {
+        self.peers_quarantined
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn peer_ignored(&self, quorum_id: u8) {
+        self.peers_ignored
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn aggregate_mismatch(&self, quorum_id: u8) {
+        self.aggregate_mismatches
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn digest_disagreement(&self, quorum_id: u8) {
+        self.digest_disagreements
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn execution_failed(&self, quorum_id: u8) {
+        self.executions_failed
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn round_preempted(&self, quorum_id: u8) {
+        self.rounds_preempted
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn orchestrator_stale(&self, quorum_id: u8) {
+        self.orchestrator_stale_alerts
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn round_rejected_capacity(&self, quorum_id: u8) {
+        self.rounds_rejected_capacity
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn message_received(&self, quorum_id: u8) {
+        self.messages_received
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn round_signed(&self, quorum_id: u8) {
+        self.signatures_produced
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn invalid_signature(&self, quorum_id: u8) {
+        self.invalid_signatures
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn duplicate_share(&self, quorum_id: u8) {
+        self.duplicate_shares
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn validation_failed(&self, quorum_id: u8) {
+        self.validation_failures
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+
+    fn round_timed_out(&self, quorum_id: u8) {
+        self.rounds_timed_out
+            .get_or_create(&QuorumLabel { quorum_id })
+            .inc();
+    }
+}
+
+/// Label of requests sent to one RPC endpoint
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct EndpointLabel {
+    /// URL of the endpoint
+    pub endpoint: String,
+}
+
+/// Request-level metrics of the RPC endpoints a node talks to
+///
+/// Cloning is cheap and clones share the underlying values.
+#[derive(Clone, Debug, Default)]
+pub struct RpcMetrics {
+    /// Requests an endpoint answered
+    pub requests_succeeded: Family<EndpointLabel, Counter>,
+    /// Requests that failed on an endpoint
+    pub requests_failed: Family<EndpointLabel, Counter>,
+}
+
+impl RpcMetrics {
+    /// Metrics with every value at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register all metrics with the given registry
+    pub fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "rpc_request_success",
+            "Number of requests an RPC endpoint answered",
+            self.requests_succeeded.clone(),
+        );
+        registry.register(
+            "rpc_request_failure",
+            "Number of requests that failed on an RPC endpoint",
+            self.requests_failed.clone(),
+        );
+    }
+
+    /// Count a request `endpoint` answered
+    pub fn request_succeeded(&self, endpoint: &str) {
+        self.requests_succeeded
+            .get_or_create(&EndpointLabel {
+                endpoint: endpoint.to_string(),
+            })
+            .inc();
+    }
+
+    /// Count a request that failed on `endpoint`
+    pub fn request_failed(&self, endpoint: &str) {
+        self.requests_failed
+            .get_or_create(&EndpointLabel {
+                endpoint: endpoint.to_string(),
+            })
+            .inc();
+    }
+}
@@ -0,0 +1,8 @@
+//! Wrappers around the p2p channels a contributor runs on.
+
+#[cfg(test)]
+mod tests;
+
+pub mod watchdog;
+
+pub use watchdog::{ReceiverError, ReceiverWatchdog, WatchdogTimeout, is_retryable};
@@ -0,0 +1,115 @@
+use crate::contributor::tests::harness::{Harness, NetworkReceiver, NetworkSender};
+use crate::contributor::tests::mock::MockError;
+use crate::contributor::{Contribute, OutboundRouter};
+use crate::p2p::watchdog::{is_retryable, watchdog_timeout};
+use crate::p2p::{ReceiverError, ReceiverWatchdog, WatchdogTimeout};
+use anyhow::Result;
+use bytes::Bytes;
+use commonware_cryptography::Signer;
+use commonware_p2p::{Receiver, Recipients};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Channels of the contributor at index 0, its receiver watched with the harness clock
+fn watched(
+    harness: &Harness,
+) -> (
+    NetworkSender,
+    ReceiverWatchdog<NetworkReceiver>,
+    mpsc::UnboundedReceiver<WatchdogTimeout>,
+) {
+    let (sender, receiver) = harness.network.register(harness.signers[0].public_key());
+    let (supervisor, timeouts) = mpsc::unbounded_channel();
+    let watchdog = ReceiverWatchdog::new(receiver, TIMEOUT)
+        .with_supervisor(supervisor)
+        .with_clock(harness.clock.clone());
+    (sender, watchdog, timeouts)
+}
+
+/// Let the receiver handle what is pending
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+#[cfg(test)]
+mod watchdog_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_silent_receiver_times_out() {
+        let harness = Harness::new(2);
+        let (_sender, mut watchdog, mut timeouts) = watched(&harness);
+        let recv = tokio::spawn(async move { watchdog.recv().await.map(|_| ()) });
+        settle().await;
+
+        harness.advance(TIMEOUT - Duration::from_secs(1));
+        settle().await;
+        assert!(!recv.is_finished());
+
+        harness.advance(Duration::from_secs(1));
+        let err = recv.await.unwrap().unwrap_err();
+        assert!(err.is_retryable());
+        let ReceiverError::WatchdogTimeout(timeout) = err else {
+            panic!("expected a watchdog timeout, got {err}");
+        };
+        assert!(timeout.silent_for >= TIMEOUT);
+        assert_eq!(timeouts.try_recv(), Ok(timeout));
+    }
+
+    #[tokio::test]
+    async fn test_frames_rearm_watchdog() {
+        let mut harness = Harness::new(2);
+        let (_sender, mut watchdog, mut timeouts) = watched(&harness);
+
+        // Each frame arrives within the timeout of the previous one
+        for _ in 0..3 {
+            harness.advance(TIMEOUT - Duration::from_secs(1));
+            commonware_p2p::Sender::send(
+                &mut harness.orchestrator_sender,
+                Recipients::All,
+                Bytes::from_static(b"frame"),
+                true,
+            )
+            .await
+            .unwrap();
+            let (_, frame) = watchdog.recv().await.unwrap();
+            assert_eq!(frame, Bytes::from_static(b"frame"));
+        }
+        assert!(timeouts.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_contributor_returns_timeout_as_retryable() {
+        let harness = Harness::new(2);
+        let (sender, watchdog, mut timeouts) = watched(&harness);
+        let contributor = harness.contributor(0, None);
+        let handle: JoinHandle<Result<()>> =
+            tokio::spawn(contributor.run(OutboundRouter::single(sender), watchdog));
+        settle().await;
+
+        harness.advance(TIMEOUT);
+        let err = handle.await.unwrap().unwrap_err();
+        assert!(is_retryable(&err));
+        assert!(timeouts.try_recv().is_ok());
+    }
+
+    #[test]
+    fn test_closed_receiver_not_retryable() {
+        let closed = ReceiverError::Receiver(MockError("network closed".to_string()));
+        assert!(!closed.is_retryable());
+        assert!(watchdog_timeout(&closed).is_none());
+
+        let stalled: ReceiverError<MockError> = ReceiverError::WatchdogTimeout(WatchdogTimeout {
+            silent_for: TIMEOUT,
+        });
+        assert_eq!(
+            watchdog_timeout(&stalled),
+            Some(&WatchdogTimeout {
+                silent_for: TIMEOUT,
+            })
+        );
+    }
+}
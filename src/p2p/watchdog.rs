@@ -0,0 +1,167 @@
+//! Watchdog on a p2p receiver that stopped yielding frames.
+//!
+//! A stalled transport leaves `recv` pending forever, so the node looks healthy but
+//! takes part in no round. A [ReceiverWatchdog] fails `recv` once no frame arrived for
+//! its timeout and reports a [WatchdogTimeout] to its supervisor. The timeout runs from
+//! the last frame rather than the last call, as the receive loop drops and recreates
+//! `recv` whenever another event wins its select.
+//!
+//! The contributor returns the [WatchdogTimeout] as its error instead of stopping as on
+//! a closed channel. A supervisor tells the two apart with [is_retryable] and runs a new
+//! contributor on a new connection.
+
+use crate::clock::{Clock, SystemClock};
+use bytes::Bytes;
+use commonware_p2p::Receiver;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tracing::warn;
+
+/// Silence after which a receiver is considered stalled, unless configured otherwise
+///
+/// Well above the interval between Starts of a live orchestrator.
+pub const DEFAULT_WATCHDOG_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A receiver yielded no frame for longer than its watchdog timeout
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WatchdogTimeout {
+    /// Time since the last frame, or since the watchdog started without one
+    pub silent_for: Duration,
+}
+
+impl fmt::Display for WatchdogTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no frame received for {:?}", self.silent_for)
+    }
+}
+
+impl Error for WatchdogTimeout {}
+
+/// Error of a [ReceiverWatchdog]
+#[derive(Debug)]
+pub enum ReceiverError<E> {
+    /// The wrapped receiver failed
+    Receiver(E),
+    /// The wrapped receiver stalled
+    WatchdogTimeout(WatchdogTimeout),
+}
+
+impl<E> ReceiverError<E> {
+    /// Whether the connection can be restarted, rather than being closed for good
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ReceiverError::WatchdogTimeout(_))
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ReceiverError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReceiverError::Receiver(err) => write!(f, "receiver failed: {err}"),
+            ReceiverError::WatchdogTimeout(timeout) => write!(f, "receiver stalled: {timeout}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ReceiverError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReceiverError::Receiver(err) => Some(err),
+            ReceiverError::WatchdogTimeout(timeout) => Some(timeout),
+        }
+    }
+}
+
+/// The [WatchdogTimeout] behind `err`, if any
+pub fn watchdog_timeout(err: &(dyn Error + 'static)) -> Option<&WatchdogTimeout> {
+    std::iter::successors(Some(err), |err| err.source()).find_map(|err| err.downcast_ref())
+}
+
+/// Whether a contributor stopped with `err` because its receiver stalled
+pub fn is_retryable(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<WatchdogTimeout>().is_some()
+}
+
+/// Receiver failing once no frame arrived for `timeout`
+pub struct ReceiverWatchdog<R> {
+    inner: R,
+    timeout: Duration,
+    last_frame: Instant,
+    supervisor: Option<mpsc::UnboundedSender<WatchdogTimeout>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<R> ReceiverWatchdog<R> {
+    /// Watch `inner`, as if a frame was just received
+    pub fn new(inner: R, timeout: Duration) -> Self {
+        Self {
+            inner,
+            timeout,
+            last_frame: Instant::now(),
+            supervisor: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Report every timeout to `supervisor`
+    pub fn with_supervisor(mut self, supervisor: mpsc::UnboundedSender<WatchdogTimeout>) -> Self {
+        self.supervisor = Some(supervisor);
+        self
+    }
+
+    /// Measure the silence with `clock`, starting now
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.last_frame = clock.monotonic_now();
+        self.clock = clock;
+        self
+    }
+
+    /// Watched receiver
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for ReceiverWatchdog<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReceiverWatchdog")
+            .field("inner", &self.inner)
+            .field("timeout", &self.timeout)
+            .field("last_frame", &self.last_frame)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R> Receiver for ReceiverWatchdog<R>
+where
+    R: Receiver,
+    R::Error: Error + 'static,
+{
+    type Error = ReceiverError<R::Error>;
+    type PublicKey = R::PublicKey;
+
+    async fn recv(&mut self) -> Result<(Self::PublicKey, Bytes), Self::Error> {
+        let deadline = self.last_frame + self.timeout;
+        tokio::select! {
+            received = self.inner.recv() => {
+                let frame = received.map_err(ReceiverError::Receiver)?;
+                self.last_frame = self.clock.monotonic_now();
+                Ok(frame)
+            }
+            () = self.clock.sleep_until(deadline) => {
+                let timeout = WatchdogTimeout {
+                    silent_for: self.clock.elapsed(self.last_frame),
+                };
+                warn!(silent_for = ?timeout.silent_for, "receiver stalled");
+                if let Some(supervisor) = &self.supervisor {
+                    // The supervisor may have stopped already
+                    let _ = supervisor.send(timeout);
+                }
+                Err(ReceiverError::WatchdogTimeout(timeout))
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! Bound on the rounds a contributor works on at once.
+//!
+//! A [RoundPipelineController] has `max_concurrent` slots. A Start takes a free slot
+//! and its round leaves the slot once it completes. When every slot is taken, the round
+//! admitted the longest ago is preempted to make room, so a stuck round cannot hold
+//! back the rounds behind it.
+
+use std::num::NonZeroUsize;
+
+/// Rounds in flight at once, two by default
+pub const DEFAULT_MAX_CONCURRENT_ROUNDS: NonZeroUsize = NonZeroUsize::MIN.saturating_add(1);
+
+/// A round in flight was preempted to admit a newer one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoundPreempted {
+    /// Round preempted
+    pub round: u64,
+    /// Round admitted in its place
+    pub by: u64,
+}
+
+/// Round holding a slot
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PipelineSlot {
+    /// Round in flight
+    pub round: u64,
+    /// Admission order, the lowest is preempted first
+    admitted: u64,
+}
+
+/// Slots of the rounds in flight
+#[derive(Clone, Debug)]
+pub struct RoundPipelineController {
+    slots: Vec<Option<PipelineSlot>>,
+    max_concurrent: usize,
+    next_admission: u64,
+}
+
+impl Default for RoundPipelineController {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONCURRENT_ROUNDS)
+    }
+}
+
+impl RoundPipelineController {
+    /// Controller with `max_concurrent` slots
+    pub fn new(max_concurrent: NonZeroUsize) -> Self {
+        Self {
+            slots: vec![None; max_concurrent.get()],
+            max_concurrent: max_concurrent.get(),
+            next_admission: 0,
+        }
+    }
+
+    /// Number of slots
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Admit `round` into a free slot, preempting the oldest round if none is free
+    ///
+    /// A round already in flight keeps its slot.
+    pub fn admit(&mut self, round: u64) -> Option<RoundPreempted> {
+        if self.contains(round) {
+            return None;
+        }
+        let admitted = self.next_admission;
+        self.next_admission += 1;
+        let slot = PipelineSlot { round, admitted };
+        if let Some(free) = self.slots.iter_mut().find(|slot| slot.is_none()) {
+            *free = Some(slot);
+            return None;
+        }
+        let oldest = self
+            .slots
+            .iter_mut()
+            .flatten()
+            .min_by_key(|slot| slot.admitted)?;
+        let preempted = std::mem::replace(oldest, slot);
+        Some(RoundPreempted {
+            round: preempted.round,
+            by: round,
+        })
+    }
+
+    /// Free the slot of `round`, returning whether it was in flight
+    pub fn complete(&mut self, round: u64) -> bool {
+        match self
+            .slots
+            .iter_mut()
+            .find(|slot| slot.is_some_and(|slot| slot.round == round))
+        {
+            Some(slot) => {
+                *slot = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Free the slots of the rounds `in_flight` rejects
+    pub fn retain(&mut self, mut in_flight: impl FnMut(u64) -> bool) {
+        for slot in &mut self.slots {
+            if slot.is_some_and(|slot| !in_flight(slot.round)) {
+                *slot = None;
+            }
+        }
+    }
+
+    /// Whether `round` is in flight
+    pub fn contains(&self, round: u64) -> bool {
+        self.slots
+            .iter()
+            .any(|slot| slot.is_some_and(|slot| slot.round == round))
+    }
+
+    /// Rounds in flight, oldest first
+    pub fn in_flight(&self) -> Vec<u64> {
+        let mut slots: Vec<PipelineSlot> = self.slots.iter().flatten().copied().collect();
+        slots.sort_by_key(|slot| slot.admitted);
+        slots.into_iter().map(|slot| slot.round).collect()
+    }
+
+    /// Number of rounds in flight
+    pub fn len(&self) -> usize {
+        self.slots.iter().flatten().count()
+    }
+
+    /// Whether no round is in flight
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether every slot is taken
+    pub fn is_full(&self) -> bool {
+        self.len() == self.max_concurrent
+    }
+
+    /// Free every slot
+    pub fn clear(&mut self) {
+        self.slots.fill(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RoundPipelineController, RoundPreempted};
+    use crate::contributor::EventSink;
+    use crate::contributor::tests::harness::Harness;
+    use crate::handlers::RunConfig;
+    use std::num::NonZeroUsize;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::Duration;
+
+    /// Sink counting aggregated and preempted rounds
+    #[derive(Clone, Default)]
+    struct PipelineEvents {
+        aggregated: Arc<AtomicU64>,
+        preempted: Arc<AtomicU64>,
+    }
+
+    impl EventSink for PipelineEvents {
+        fn threshold_reached(&self, _quorum_id: u8) {
+            self.aggregated.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn round_preempted(&self, _quorum_id: u8) {
+            self.preempted.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Admit `rounds` in succession, collecting the preemptions
+    fn admit_all(
+        pipeline: &mut RoundPipelineController,
+        rounds: impl IntoIterator<Item = u64>,
+    ) -> Vec<RoundPreempted> {
+        rounds
+            .into_iter()
+            .filter_map(|round| pipeline.admit(round))
+            .collect()
+    }
+
+    #[test]
+    fn test_oldest_rounds_preempted_when_full() {
+        let mut pipeline = RoundPipelineController::new(NonZeroUsize::new(4).unwrap());
+        assert!(admit_all(&mut pipeline, 1..=4).is_empty());
+        assert!(pipeline.is_full());
+
+        let preempted = admit_all(&mut pipeline, 5..=8);
+        let expected: Vec<RoundPreempted> = (1..=4)
+            .map(|round| RoundPreempted {
+                round,
+                by: round + 4,
+            })
+            .collect();
+        assert_eq!(preempted, expected);
+        assert_eq!(pipeline.in_flight(), [5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_completed_rounds_free_their_slot() {
+        let mut pipeline = RoundPipelineController::new(NonZeroUsize::new(4).unwrap());
+        let mut preempted = Vec::new();
+        for round in 1..=8 {
+            preempted.extend(pipeline.admit(round));
+            // Even rounds complete right after their Start
+            if round % 2 == 0 {
+                assert!(pipeline.complete(round));
+            }
+        }
+
+        // Odd rounds filled the slots, the last Start preempted the oldest
+        assert_eq!(preempted, [RoundPreempted { round: 1, by: 8 }]);
+        assert_eq!(pipeline.in_flight(), [3, 5, 7]);
+        assert!(!pipeline.complete(1));
+    }
+
+    #[test]
+    fn test_round_admitted_once() {
+        let mut pipeline = RoundPipelineController::new(NonZeroUsize::new(2).unwrap());
+        assert!(admit_all(&mut pipeline, [1, 2, 1, 2]).is_empty());
+        assert_eq!(pipeline.len(), 2);
+
+        pipeline.retain(|round| round != 1);
+        assert_eq!(pipeline.in_flight(), [2]);
+        pipeline.clear();
+        assert!(pipeline.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rounds_complete_or_preempted() {
+        let mut harness = Harness::new(2);
+        let events = PipelineEvents::default();
+        let handles = [
+            harness.spawn(
+                harness
+                    .contributor(0, Some(2))
+                    .with_event_sink(Arc::new(events.clone()))
+                    .with_run_config(RunConfig {
+                        max_concurrent_rounds: Some(NonZeroUsize::new(4).unwrap()),
+                        ..RunConfig::default()
+                    }),
+                0,
+            ),
+            harness.spawn(harness.contributor(1, None), 1),
+        ];
+
+        for round in 1..=8 {
+            harness.start(round).await;
+        }
+        harness.signed_rounds(Duration::from_millis(200)).await;
+
+        // Every round either aggregated or made room for a later one
+        let aggregated = events.aggregated.load(Ordering::Relaxed);
+        let preempted = events.preempted.load(Ordering::Relaxed);
+        assert_eq!(aggregated + preempted, 8);
+        assert!(preempted <= 4);
+
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
@@ -0,0 +1,1132 @@
+//! Startup of the chain-dependent components of a node.
+//!
+//! Startup tasks run concurrently as soon as the tasks they depend on are ready,
+//! each bounded by its own timeout, so a cold start takes as long as the slowest
+//! chain of dependencies rather than the sum of all tasks. Outcomes are published
+//! to a shared [Readiness]. The first required task to fail stops the startup
+//! with a report of every task.
+//!
+//! Once started, the node runs its background tasks in a [TaskGroup]. Shutdown
+//! walks the [ShutdownStage]s in order, cancelling a stage only once the previous
+//! one stopped, so rounds stop coming in before the queues they feed are drained
+//! and the stores they write to are flushed.
+
+use anyhow::Result;
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use futures::stream::FuturesUnordered;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::{AbortHandle, JoinError};
+use tokio::time::Instant;
+use tracing::{Instrument, Span, error, info, warn};
+
+/// Time a startup task may take unless configured otherwise
+pub const DEFAULT_STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Time a background task may take to stop once cancelled unless configured otherwise
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+type TaskFn = Box<dyn FnOnce() -> BoxFuture<'static, Result<()>> + Send>;
+
+/// Unit of work run once when the node starts
+pub struct StartupTask {
+    name: &'static str,
+    dependencies: Vec<&'static str>,
+    timeout: Duration,
+    required: bool,
+    run: TaskFn,
+}
+
+impl StartupTask {
+    /// Required task named `name`, failing after the default timeout
+    pub fn new<F, Fut>(name: &'static str, run: F) -> Self
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        Self {
+            name,
+            dependencies: Vec::new(),
+            timeout: DEFAULT_STARTUP_TIMEOUT,
+            required: true,
+            run: Box::new(move || Box::pin(run())),
+        }
+    }
+
+    /// Only start once the task named `dependency` is ready
+    pub fn after(mut self, dependency: &'static str) -> Self {
+        self.dependencies.push(dependency);
+        self
+    }
+
+    /// Fail the task if it is not ready within `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Let the node become ready even if this task fails
+    pub fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+}
+
+/// Progress of a startup task
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// Waiting for its dependencies
+    Pending,
+    /// Started and not finished
+    Running,
+    /// Finished successfully
+    Ready {
+        /// Time the task took
+        elapsed: Duration,
+    },
+    /// Returned an error
+    Failed {
+        /// Error returned
+        error: String,
+    },
+    /// Did not finish within its timeout
+    TimedOut {
+        /// Timeout of the task
+        timeout: Duration,
+    },
+    /// Not run because a dependency did not become ready
+    Skipped {
+        /// Dependency that did not become ready
+        dependency: &'static str,
+    },
+    /// Stopped because a required task failed
+    Cancelled,
+}
+
+impl TaskStatus {
+    /// Whether the task finished successfully
+    pub fn is_ready(&self) -> bool {
+        matches!(self, TaskStatus::Ready { .. })
+    }
+
+    /// Whether the task failed, timed out or was skipped
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            TaskStatus::Failed { .. } | TaskStatus::TimedOut { .. } | TaskStatus::Skipped { .. }
+        )
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskStatus::Pending => write!(f, "pending"),
+            TaskStatus::Running => write!(f, "running"),
+            TaskStatus::Ready { elapsed } => write!(f, "ready after {elapsed:?}"),
+            TaskStatus::Failed { error } => write!(f, "failed: {error}"),
+            TaskStatus::TimedOut { timeout } => write!(f, "timed out after {timeout:?}"),
+            TaskStatus::Skipped { dependency } => write!(f, "skipped, {dependency} not ready"),
+            TaskStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct TaskState {
+    required: bool,
+    status: TaskStatus,
+}
+
+/// Readiness of a node, shared with anything reporting it
+///
+/// Cloning is cheap and clones observe the same state.
+#[derive(Clone, Debug, Default)]
+pub struct Readiness(Arc<Mutex<BTreeMap<&'static str, TaskState>>>);
+
+impl Readiness {
+    /// Whether every required startup task is ready
+    pub fn is_ready(&self) -> bool {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .all(|task| !task.required || task.status.is_ready())
+    }
+
+    /// Status of the task named `name`, if registered
+    pub fn status(&self, name: &str) -> Option<TaskStatus> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .map(|task| task.status.clone())
+    }
+
+    /// Status of every startup task
+    pub fn report(&self) -> StartupReport {
+        StartupReport {
+            tasks: self
+                .0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .map(|(name, task)| (*name, task.status.clone()))
+                .collect(),
+        }
+    }
+
+    /// Register the task named `name`, returning `false` if one already is
+    fn register(&self, name: &'static str, required: bool) -> bool {
+        let mut tasks = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if tasks.contains_key(name) {
+            return false;
+        }
+        tasks.insert(
+            name,
+            TaskState {
+                required,
+                status: TaskStatus::Pending,
+            },
+        );
+        true
+    }
+
+    fn set(&self, name: &'static str, status: TaskStatus) {
+        if let Some(task) = self
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(name)
+        {
+            task.status = status;
+        }
+    }
+
+    /// Mark every task not done yet as cancelled
+    fn cancel(&self) {
+        for task in self
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values_mut()
+        {
+            if matches!(task.status, TaskStatus::Pending | TaskStatus::Running) {
+                task.status = TaskStatus::Cancelled;
+            }
+        }
+    }
+}
+
+/// Status of every startup task, by name
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StartupReport {
+    /// Status of each task, in registration order
+    pub tasks: Vec<(&'static str, TaskStatus)>,
+}
+
+impl StartupReport {
+    /// Status of the task named `name`, if registered
+    pub fn status(&self, name: &str) -> Option<&TaskStatus> {
+        self.tasks
+            .iter()
+            .find(|(task, _)| *task == name)
+            .map(|(_, status)| status)
+    }
+
+    /// Tasks that failed, timed out or were skipped
+    pub fn failures(&self) -> impl Iterator<Item = &(&'static str, TaskStatus)> {
+        self.tasks.iter().filter(|(_, status)| status.is_failure())
+    }
+}
+
+impl fmt::Display for StartupReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, status) in &self.tasks {
+            writeln!(f, "  {name}: {status}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Startup stopped by a required task failing
+#[derive(Debug)]
+pub struct StartupError(pub StartupReport);
+
+impl fmt::Display for StartupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failed: Vec<&str> = self.0.failures().map(|(name, _)| *name).collect();
+        write!(f, "startup failed ({}):\n{}", failed.join(", "), self.0)
+    }
+}
+
+impl std::error::Error for StartupError {}
+
+/// Runs the startup tasks of a node and tracks its readiness, then supervises its
+/// background tasks
+#[derive(Default)]
+pub struct NodeRunner {
+    tasks: Vec<StartupTask>,
+    duplicates: Vec<&'static str>,
+    background: Vec<BackgroundTask>,
+    readiness: Readiness,
+}
+
+impl NodeRunner {
+    /// Runner with no task
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a startup task, names must be unique
+    ///
+    /// A task reusing the name of another fails the startup.
+    pub fn with_task(mut self, task: StartupTask) -> Self {
+        if self.readiness.register(task.name, task.required) {
+            self.tasks.push(task);
+        } else {
+            self.duplicates.push(task.name);
+        }
+        self
+    }
+
+    /// Add a task run in the background once the node started
+    pub fn with_background_task(mut self, task: BackgroundTask) -> Self {
+        self.background.push(task);
+        self
+    }
+
+    /// Readiness of the startup tasks, updated as they run
+    pub fn readiness(&self) -> Readiness {
+        self.readiness.clone()
+    }
+
+    /// Run the background tasks until `shutdown` resolves or one of them fails, then
+    /// stop them in order
+    pub async fn run(&mut self, shutdown: impl Future<Output = ()>) -> ShutdownSummary {
+        let mut group = TaskGroup::new();
+        for task in std::mem::take(&mut self.background) {
+            group.spawn(task);
+        }
+        group.run_until(shutdown).await
+    }
+
+    /// Run every startup task, each as soon as its dependencies are ready
+    ///
+    /// Returns once all tasks are done, or as soon as a required task fails.
+    pub async fn start(&mut self) -> Result<StartupReport, StartupError> {
+        let mut pending = std::mem::take(&mut self.tasks);
+        let mut running = FuturesUnordered::new();
+        let mut done: HashMap<&'static str, bool> = HashMap::new();
+        for name in std::mem::take(&mut self.duplicates) {
+            let status = TaskStatus::Failed {
+                error: "duplicate startup task".to_string(),
+            };
+            self.finish(&mut done, name, true, status)?;
+        }
+
+        loop {
+            // Skip tasks whose dependencies failed and start those whose dependencies are ready
+            let mut i = 0;
+            while let Some(task) = pending.get(i) {
+                if let Some(dependency) = task
+                    .dependencies
+                    .iter()
+                    .find(|dependency| done.get(*dependency) == Some(&false))
+                    .copied()
+                {
+                    let task = pending.remove(i);
+                    let status = TaskStatus::Skipped { dependency };
+                    self.finish(&mut done, task.name, task.required, status)?;
+                    // A skipped task may in turn skip tasks already scanned
+                    i = 0;
+                    continue;
+                }
+                if task
+                    .dependencies
+                    .iter()
+                    .all(|dependency| done.get(dependency) == Some(&true))
+                {
+                    let task = pending.remove(i);
+                    self.readiness.set(task.name, TaskStatus::Running);
+                    running.push(run_task(task));
+                    continue;
+                }
+                i += 1;
+            }
+
+            let Some((name, required, status)) = running.next().await else {
+                break;
+            };
+            self.finish(&mut done, name, required, status)?;
+        }
+
+        // Dependencies that are unknown or form a cycle never become ready
+        for task in pending {
+            let status = TaskStatus::Failed {
+                error: format!("unresolved dependencies: {}", task.dependencies.join(", ")),
+            };
+            self.finish(&mut done, task.name, task.required, status)?;
+        }
+        Ok(self.readiness.report())
+    }
+
+    /// Record the outcome of a task, failing the startup if it was required and not ready
+    fn finish(
+        &self,
+        done: &mut HashMap<&'static str, bool>,
+        name: &'static str,
+        required: bool,
+        status: TaskStatus,
+    ) -> Result<(), StartupError> {
+        let ready = status.is_ready();
+        if ready {
+            info!(task = name, %status, "startup task done");
+        } else {
+            warn!(task = name, required, %status, "startup task not ready");
+        }
+        done.insert(name, ready);
+        self.readiness.set(name, status);
+        if required && !ready {
+            self.readiness.cancel();
+            return Err(StartupError(self.readiness.report()));
+        }
+        Ok(())
+    }
+}
+
+async fn run_task(task: StartupTask) -> (&'static str, bool, TaskStatus) {
+    let start = Instant::now();
+    let status = match tokio::time::timeout(task.timeout, (task.run)()).await {
+        Ok(Ok(())) => TaskStatus::Ready {
+            elapsed: start.elapsed(),
+        },
+        Ok(Err(err)) => TaskStatus::Failed {
+            error: format!("{err:#}"),
+        },
+        Err(_) => TaskStatus::TimedOut {
+            timeout: task.timeout,
+        },
+    };
+    (task.name, task.required, status)
+}
+
+/// Order in which background tasks are cancelled, earlier stages first
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ShutdownStage {
+    /// Stop accepting new rounds
+    Intake,
+    /// Drain the rounds being verified
+    Verification,
+    /// Flush the audit log and the results store
+    Persistence,
+    /// Stop the HTTP server
+    Serving,
+    /// Stop the chain watchers
+    Watchers,
+}
+
+/// Cancellation signal handed to a background task
+#[derive(Clone, Debug)]
+pub struct ShutdownToken(watch::Receiver<bool>);
+
+impl ShutdownToken {
+    /// Whether the task was asked to stop
+    pub fn is_cancelled(&self) -> bool {
+        *self.0.borrow()
+    }
+
+    /// Wait until the task is asked to stop
+    pub async fn cancelled(&mut self) {
+        // A dropped group cancels its tasks as well
+        let _ = self.0.wait_for(|cancelled| *cancelled).await;
+    }
+}
+
+type BackgroundFn = Box<dyn FnOnce(ShutdownToken) -> BoxFuture<'static, Result<()>> + Send>;
+
+/// Long-running task of a node, stopped when its [ShutdownToken] is cancelled
+pub struct BackgroundTask {
+    name: &'static str,
+    stage: ShutdownStage,
+    shutdown_timeout: Duration,
+    run: BackgroundFn,
+}
+
+impl BackgroundTask {
+    /// Task named `name`, stopped at `stage` of the shutdown
+    pub fn new<F, Fut>(name: &'static str, stage: ShutdownStage, run: F) -> Self
+    where
+        F: FnOnce(ShutdownToken) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        Self {
+            name,
+            stage,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            run: Box::new(move |token| Box::pin(run(token))),
+        }
+    }
+
+    /// Abort the task if it did not stop within `timeout` of being cancelled
+    pub fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+}
+
+/// How a background task ended
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TaskExit {
+    /// Returned on its own before shutdown
+    Finished,
+    /// Stopped after being cancelled
+    Stopped {
+        /// Time the task took to stop
+        elapsed: Duration,
+    },
+    /// Returned an error
+    Failed {
+        /// Error returned
+        error: String,
+    },
+    /// Panicked
+    Panicked {
+        /// Panic message
+        message: String,
+    },
+    /// Aborted after not stopping within its shutdown timeout
+    Aborted {
+        /// Shutdown timeout of the task
+        timeout: Duration,
+    },
+}
+
+impl TaskExit {
+    /// Whether the task failed, panicked or was aborted
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            TaskExit::Failed { .. } | TaskExit::Panicked { .. } | TaskExit::Aborted { .. }
+        )
+    }
+}
+
+impl fmt::Display for TaskExit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskExit::Finished => write!(f, "finished"),
+            TaskExit::Stopped { elapsed } => write!(f, "stopped after {elapsed:?}"),
+            TaskExit::Failed { error } => write!(f, "failed: {error}"),
+            TaskExit::Panicked { message } => write!(f, "panicked: {message}"),
+            TaskExit::Aborted { timeout } => {
+                write!(f, "aborted, not stopped within {timeout:?}")
+            }
+        }
+    }
+}
+
+/// Why a [TaskGroup] shut down
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShutdownCause {
+    /// Shutdown was requested
+    Requested,
+    /// A background task failed or panicked
+    TaskFailed {
+        /// Task that failed
+        task: &'static str,
+        /// How the task ended
+        exit: TaskExit,
+    },
+}
+
+impl fmt::Display for ShutdownCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShutdownCause::Requested => write!(f, "requested"),
+            ShutdownCause::TaskFailed { task, exit } => write!(f, "{task} {exit}"),
+        }
+    }
+}
+
+/// Outcome of a shutdown, with each task in the order it was stopped
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShutdownSummary {
+    /// Why the group shut down
+    pub cause: ShutdownCause,
+    /// How each task ended
+    pub tasks: Vec<(&'static str, TaskExit)>,
+}
+
+impl ShutdownSummary {
+    /// How the task named `name` ended, if it ran
+    pub fn exit(&self, name: &str) -> Option<&TaskExit> {
+        self.tasks
+            .iter()
+            .find(|(task, _)| *task == name)
+            .map(|(_, exit)| exit)
+    }
+
+    /// Whether every task stopped cleanly after a requested shutdown
+    pub fn is_clean(&self) -> bool {
+        self.cause == ShutdownCause::Requested
+            && self.tasks.iter().all(|(_, exit)| !exit.is_failure())
+    }
+}
+
+impl fmt::Display for ShutdownSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "shutdown ({}):", self.cause)?;
+        for (name, exit) in &self.tasks {
+            writeln!(f, "  {name}: {exit}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Background task spawned by a [TaskGroup]
+struct Member {
+    name: &'static str,
+    stage: ShutdownStage,
+    shutdown_timeout: Duration,
+    cancel: watch::Sender<bool>,
+    cancelled: Option<Instant>,
+    abort: AbortHandle,
+    exit: Option<TaskExit>,
+}
+
+type ExitFuture = BoxFuture<'static, (usize, Result<Result<()>, JoinError>)>;
+
+/// Supervises the background tasks of a node and stops them in [ShutdownStage] order
+#[derive(Default)]
+pub struct TaskGroup {
+    members: Vec<Member>,
+    exits: FuturesUnordered<ExitFuture>,
+    cause: Option<ShutdownCause>,
+}
+
+impl TaskGroup {
+    /// Group with no task
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start running `task` in the background, within the current span
+    pub fn spawn(&mut self, task: BackgroundTask) {
+        let (cancel, token) = watch::channel(false);
+        let handle = tokio::spawn((task.run)(ShutdownToken(token)).instrument(Span::current()));
+        let index = self.members.len();
+        self.members.push(Member {
+            name: task.name,
+            stage: task.stage,
+            shutdown_timeout: task.shutdown_timeout,
+            cancel,
+            cancelled: None,
+            abort: handle.abort_handle(),
+            exit: None,
+        });
+        self.exits
+            .push(Box::pin(async move { (index, handle.await) }));
+    }
+
+    /// Run until `shutdown` resolves or a task fails, then stop every task
+    pub async fn run_until(mut self, shutdown: impl Future<Output = ()>) -> ShutdownSummary {
+        let mut shutdown = std::pin::pin!(shutdown);
+        while self.cause.is_none() {
+            tokio::select! {
+                _ = &mut shutdown => {
+                    info!("shutdown requested");
+                    self.cause = Some(ShutdownCause::Requested);
+                }
+                Some((index, result)) = self.exits.next(), if !self.exits.is_empty() => {
+                    self.record_exit(index, result);
+                }
+            }
+        }
+        self.shutdown().await
+    }
+
+    /// Cancel the tasks stage by stage, aborting those exceeding their shutdown timeout
+    async fn shutdown(mut self) -> ShutdownSummary {
+        let mut order = Vec::with_capacity(self.members.len());
+        let mut stages: Vec<ShutdownStage> =
+            self.members.iter().map(|member| member.stage).collect();
+        stages.sort();
+        stages.dedup();
+        for stage in stages {
+            let cancelled = Instant::now();
+            let mut stopping = 0;
+            for (index, member) in self.members.iter_mut().enumerate() {
+                if member.stage != stage {
+                    continue;
+                }
+                order.push(index);
+                if member.exit.is_none() {
+                    // The task may already be gone, its exit is collected below
+                    let _ = member.cancel.send(true);
+                    member.cancelled = Some(cancelled);
+                    stopping += 1;
+                }
+            }
+            info!(?stage, tasks = stopping, "stopping background tasks");
+
+            loop {
+                let Some(deadline) = self
+                    .members
+                    .iter()
+                    .filter(|member| member.stage == stage && member.exit.is_none())
+                    .map(|member| cancelled + member.shutdown_timeout)
+                    .min()
+                else {
+                    break;
+                };
+                tokio::select! {
+                    Some((index, result)) = self.exits.next() => {
+                        self.record_exit(index, result);
+                    }
+                    _ = tokio::time::sleep_until(deadline) => {
+                        for member in &mut self.members {
+                            if member.stage == stage
+                                && member.exit.is_none()
+                                && cancelled + member.shutdown_timeout <= deadline
+                            {
+                                warn!(
+                                    task = member.name,
+                                    timeout = ?member.shutdown_timeout,
+                                    "background task did not stop in time, aborting"
+                                );
+                                member.abort.abort();
+                                member.exit = Some(TaskExit::Aborted {
+                                    timeout: member.shutdown_timeout,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let summary = ShutdownSummary {
+            cause: self.cause.take().unwrap_or(ShutdownCause::Requested),
+            tasks: order
+                .into_iter()
+                .filter_map(|index| self.members.get(index))
+                .map(|member| {
+                    (
+                        member.name,
+                        member.exit.clone().unwrap_or(TaskExit::Finished),
+                    )
+                })
+                .collect(),
+        };
+        if summary.is_clean() {
+            info!("{summary}");
+        } else {
+            warn!("{summary}");
+        }
+        summary
+    }
+
+    /// Record how a task ended, starting the shutdown if it failed
+    fn record_exit(&mut self, index: usize, result: Result<Result<()>, JoinError>) {
+        let Some(member) = self.members.get_mut(index) else {
+            return;
+        };
+        if member.exit.is_some() {
+            // Already aborted
+            return;
+        }
+        let exit = match result {
+            Ok(Ok(())) => match member.cancelled {
+                Some(cancelled) => TaskExit::Stopped {
+                    elapsed: cancelled.elapsed(),
+                },
+                None => TaskExit::Finished,
+            },
+            Ok(Err(err)) => TaskExit::Failed {
+                error: format!("{err:#}"),
+            },
+            Err(err) if err.is_panic() => TaskExit::Panicked {
+                message: panic_message(err),
+            },
+            Err(_) => TaskExit::Aborted {
+                timeout: member.shutdown_timeout,
+            },
+        };
+        if exit.is_failure() {
+            error!(task = member.name, %exit, "background task failed");
+            if self.cause.is_none() {
+                self.cause = Some(ShutdownCause::TaskFailed {
+                    task: member.name,
+                    exit: exit.clone(),
+                });
+            }
+        } else {
+            info!(task = member.name, %exit, "background task done");
+        }
+        member.exit = Some(exit);
+    }
+}
+
+/// Message a task panicked with
+fn panic_message(err: JoinError) -> String {
+    let panic = err.into_panic();
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BackgroundTask, NodeRunner, ShutdownCause, ShutdownStage, StartupTask, TaskExit, TaskGroup,
+        TaskStatus,
+    };
+    use anyhow::anyhow;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    /// Task sleeping for `secs` seconds, then succeeding
+    fn sleeping(name: &'static str, secs: u64) -> StartupTask {
+        StartupTask::new(name, move || async move {
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+            Ok(())
+        })
+    }
+
+    /// Task sleeping for `secs` seconds, then failing
+    fn failing(name: &'static str, secs: u64) -> StartupTask {
+        StartupTask::new(name, move || async move {
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+            Err(anyhow!("{name} unavailable"))
+        })
+    }
+
+    /// Task recording when it started, then sleeping for `secs` seconds
+    fn recorded(
+        name: &'static str,
+        secs: u64,
+        starts: &Arc<Mutex<Vec<(&'static str, Instant)>>>,
+    ) -> StartupTask {
+        let starts = starts.clone();
+        StartupTask::new(name, move || async move {
+            starts.lock().unwrap().push((name, Instant::now()));
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+            Ok(())
+        })
+    }
+
+    type Events = Arc<Mutex<Vec<(&'static str, Instant)>>>;
+
+    /// Background task recording when it is cancelled, then taking `secs` seconds to stop
+    fn stopping(
+        name: &'static str,
+        stage: ShutdownStage,
+        secs: u64,
+        events: &Events,
+    ) -> BackgroundTask {
+        let events = events.clone();
+        BackgroundTask::new(name, stage, move |mut token| async move {
+            token.cancelled().await;
+            events.lock().unwrap().push((name, Instant::now()));
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+            Ok(())
+        })
+    }
+
+    /// Background task failing after `secs` seconds
+    fn crashing(name: &'static str, secs: u64) -> BackgroundTask {
+        BackgroundTask::new(name, ShutdownStage::Watchers, move |_| async move {
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+            Err(anyhow!("{name} disconnected"))
+        })
+    }
+
+    /// Time each task was cancelled, relative to `start`
+    fn cancelled_at(events: &Events, start: Instant) -> Vec<(&'static str, Duration)> {
+        events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, at)| (*name, *at - start))
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_startup_bounded_by_slowest_task() {
+        let mut runner = NodeRunner::new()
+            .with_task(sleeping("keys", 1))
+            .with_task(sleeping("rpc", 2))
+            .with_task(sleeping("operator_state", 3));
+
+        let start = Instant::now();
+        let report = runner.start().await.unwrap();
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+        assert!(report.tasks.iter().all(|(_, status)| status.is_ready()));
+        assert!(runner.readiness().is_ready());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dependencies_start_once_ready() {
+        let starts = Arc::new(Mutex::new(Vec::new()));
+        let mut runner = NodeRunner::new()
+            .with_task(recorded("keys", 1, &starts))
+            .with_task(recorded("registration", 1, &starts).after("keys"))
+            .with_task(recorded("rpc", 2, &starts))
+            .with_task(recorded("operator_state", 1, &starts).after("rpc"));
+
+        let start = Instant::now();
+        runner.start().await.unwrap();
+        // Two chains of 2s and 3s run side by side
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+
+        let starts = starts.lock().unwrap();
+        let started = |name: &str| {
+            starts
+                .iter()
+                .find(|(task, _)| *task == name)
+                .map(|(_, at)| *at - start)
+                .unwrap()
+        };
+        assert_eq!(started("keys"), Duration::ZERO);
+        assert_eq!(started("rpc"), Duration::ZERO);
+        assert_eq!(started("registration"), Duration::from_secs(1));
+        assert_eq!(started("operator_state"), Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_required_failure_fails_fast() {
+        let mut runner = NodeRunner::new()
+            .with_task(failing("rpc", 1))
+            .with_task(sleeping("operator_state", 1).after("rpc"))
+            .with_task(sleeping("keys", 10));
+        let readiness = runner.readiness();
+
+        let start = Instant::now();
+        let err = runner.start().await.unwrap_err();
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+        assert_eq!(
+            err.0.status("rpc"),
+            Some(&TaskStatus::Failed {
+                error: "rpc unavailable".to_string()
+            })
+        );
+        assert_eq!(err.0.status("keys"), Some(&TaskStatus::Cancelled));
+        assert_eq!(err.0.status("operator_state"), Some(&TaskStatus::Cancelled));
+        assert!(err.to_string().contains("rpc unavailable"));
+        assert!(!readiness.is_ready());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_task_timeout() {
+        let mut runner =
+            NodeRunner::new().with_task(sleeping("rpc", 60).with_timeout(Duration::from_secs(5)));
+
+        let start = Instant::now();
+        let err = runner.start().await.unwrap_err();
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+        assert_eq!(
+            err.0.status("rpc"),
+            Some(&TaskStatus::TimedOut {
+                timeout: Duration::from_secs(5)
+            })
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_optional_failure_skips_dependents() {
+        let mut runner = NodeRunner::new()
+            .with_task(failing("metrics", 1).optional())
+            .with_task(sleeping("dashboard", 1).after("metrics").optional())
+            .with_task(sleeping("keys", 2));
+
+        let report = runner.start().await.unwrap();
+        assert_eq!(
+            report.status("dashboard"),
+            Some(&TaskStatus::Skipped {
+                dependency: "metrics"
+            })
+        );
+        assert_eq!(report.failures().count(), 2);
+        assert!(runner.readiness().is_ready());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_readiness_gated_on_required_tasks() {
+        let mut runner = NodeRunner::new()
+            .with_task(sleeping("keys", 1))
+            .with_task(sleeping("registration", 1).after("keys"));
+        let readiness = runner.readiness();
+        assert!(!readiness.is_ready());
+        assert_eq!(readiness.status("keys"), Some(TaskStatus::Pending));
+
+        let handle = tokio::spawn(async move { runner.start().await.map(|_| ()) });
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert!(readiness.status("keys").unwrap().is_ready());
+        assert_eq!(readiness.status("registration"), Some(TaskStatus::Running));
+        assert!(!readiness.is_ready());
+
+        handle.await.unwrap().unwrap();
+        assert!(readiness.is_ready());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_unknown_dependency_fails() {
+        let mut runner = NodeRunner::new().with_task(sleeping("registration", 1).after("keys"));
+
+        let err = runner.start().await.unwrap_err();
+        assert!(matches!(
+            err.0.status("registration"),
+            Some(TaskStatus::Failed { .. })
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_duplicate_task_fails() {
+        let mut runner = NodeRunner::new()
+            .with_task(sleeping("keys", 1))
+            .with_task(sleeping("keys", 2));
+
+        let err = runner.start().await.unwrap_err();
+        assert!(matches!(
+            err.0.status("keys"),
+            Some(TaskStatus::Failed { .. })
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_shutdown_in_stage_order() {
+        let events = Events::default();
+        let mut runner = NodeRunner::new()
+            .with_background_task(stopping("http", ShutdownStage::Serving, 1, &events))
+            .with_background_task(stopping("chain_head", ShutdownStage::Watchers, 1, &events))
+            .with_background_task(stopping("audit", ShutdownStage::Persistence, 3, &events))
+            .with_background_task(stopping("rounds", ShutdownStage::Intake, 1, &events))
+            .with_background_task(stopping(
+                "verifier",
+                ShutdownStage::Verification,
+                2,
+                &events,
+            ));
+
+        let start = Instant::now();
+        let summary = runner.run(tokio::time::sleep(Duration::from_secs(5))).await;
+        assert!(summary.is_clean(), "{summary}");
+        assert_eq!(
+            cancelled_at(&events, start),
+            vec![
+                ("rounds", Duration::from_secs(5)),
+                ("verifier", Duration::from_secs(6)),
+                ("audit", Duration::from_secs(8)),
+                ("http", Duration::from_secs(11)),
+                ("chain_head", Duration::from_secs(12)),
+            ]
+        );
+        let order: Vec<&str> = summary.tasks.iter().map(|(name, _)| *name).collect();
+        assert_eq!(order, ["rounds", "verifier", "audit", "http", "chain_head"]);
+        assert_eq!(
+            summary.exit("audit"),
+            Some(&TaskExit::Stopped {
+                elapsed: Duration::from_secs(3)
+            })
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_shutdown_timeout_aborts_task() {
+        let events = Events::default();
+        let mut group = TaskGroup::new();
+        group.spawn(
+            stopping("verifier", ShutdownStage::Verification, 60, &events)
+                .with_shutdown_timeout(Duration::from_secs(5)),
+        );
+        group.spawn(stopping("http", ShutdownStage::Serving, 0, &events));
+
+        let start = Instant::now();
+        let summary = group.run_until(std::future::ready(())).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+        assert_eq!(
+            summary.exit("verifier"),
+            Some(&TaskExit::Aborted {
+                timeout: Duration::from_secs(5)
+            })
+        );
+        // The next stage is only cancelled once the timed out task was aborted
+        assert_eq!(
+            cancelled_at(&events, start),
+            vec![
+                ("verifier", Duration::ZERO),
+                ("http", Duration::from_secs(5))
+            ]
+        );
+        assert!(!summary.is_clean());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_failed_task_shuts_down_group() {
+        let events = Events::default();
+        let mut group = TaskGroup::new();
+        group.spawn(stopping("rounds", ShutdownStage::Intake, 1, &events));
+        group.spawn(crashing("registry_events", 2));
+
+        let start = Instant::now();
+        let summary = group.run_until(std::future::pending()).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+        assert_eq!(
+            summary.cause,
+            ShutdownCause::TaskFailed {
+                task: "registry_events",
+                exit: TaskExit::Failed {
+                    error: "registry_events disconnected".to_string()
+                },
+            }
+        );
+        assert_eq!(
+            cancelled_at(&events, start),
+            vec![("rounds", Duration::from_secs(2))]
+        );
+        assert!(summary.to_string().contains("registry_events disconnected"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_panicked_task_shuts_down_group() {
+        let events = Events::default();
+        let mut group = TaskGroup::new();
+        group.spawn(stopping("http", ShutdownStage::Serving, 0, &events));
+        group.spawn(BackgroundTask::new(
+            "heartbeat",
+            ShutdownStage::Watchers,
+            |_| async move {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                panic!("heartbeat lost");
+            },
+        ));
+
+        let summary = group.run_until(std::future::pending()).await;
+        assert_eq!(
+            summary.cause,
+            ShutdownCause::TaskFailed {
+                task: "heartbeat",
+                exit: TaskExit::Panicked {
+                    message: "heartbeat lost".to_string()
+                },
+            }
+        );
+        assert_eq!(
+            summary.exit("http"),
+            Some(&TaskExit::Stopped {
+                elapsed: Duration::ZERO
+            })
+        );
+    }
+}
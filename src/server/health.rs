@@ -0,0 +1,182 @@
+//! Liveness and readiness probes of a node, for orchestrators such as Kubernetes.
+//!
+//! `GET /health/live` fails once the receive loop stopped beating, so a deadlocked
+//! node is restarted. `GET /health/ready` fails until the startup tasks reading the
+//! chain are ready and the p2p network is started, so a node still connecting is not
+//! counted as serving, and while the local clock is skewed beyond tolerance. A failing
+//! probe is answered with 503 and an [Unhealthy] body.
+
+use crate::chain::ClockSkew;
+use crate::clock::{Clock, SystemClock};
+use crate::runner::Readiness;
+use anyhow::Result;
+use axum::Json;
+use axum::Router;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::watch;
+use tokio::time::Instant;
+
+/// Time the receive loop beats at least once while idle, unless configured otherwise
+pub const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Age of the last heartbeat past which the node is not live, unless configured otherwise
+pub const DEFAULT_LIVENESS_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Body of a failing probe
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Unhealthy {
+    /// Why the probe fails
+    pub reason: String,
+}
+
+impl Unhealthy {
+    fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Serves the probes of a node from its heartbeat and readiness
+#[derive(Clone)]
+pub struct HealthCheckServer {
+    heartbeat: watch::Receiver<Instant>,
+    liveness_timeout: Duration,
+    startup: Option<Readiness>,
+    p2p: Option<watch::Receiver<bool>>,
+    clock_skew: Option<(watch::Receiver<Option<ClockSkew>>, Duration)>,
+    clock: Arc<dyn Clock>,
+}
+
+impl HealthCheckServer {
+    /// Probes of a node whose receive loop beats `heartbeat`
+    pub fn new(heartbeat: watch::Receiver<Instant>) -> Self {
+        Self {
+            heartbeat,
+            liveness_timeout: DEFAULT_LIVENESS_TIMEOUT,
+            startup: None,
+            p2p: None,
+            clock_skew: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Report the node not live after `timeout` without progress
+    pub fn with_liveness_timeout(mut self, timeout: Duration) -> Self {
+        self.liveness_timeout = timeout;
+        self
+    }
+
+    /// Only be ready once the required tasks of `startup` are
+    pub fn with_startup(mut self, startup: Readiness) -> Self {
+        self.startup = Some(startup);
+        self
+    }
+
+    /// Only be ready once `p2p` reports the network started
+    pub fn with_p2p(mut self, p2p: watch::Receiver<bool>) -> Self {
+        self.p2p = Some(p2p);
+        self
+    }
+
+    /// Only be ready while the clock skew estimated by `skew` is at most `max`
+    pub fn with_clock_skew(
+        mut self,
+        skew: watch::Receiver<Option<ClockSkew>>,
+        max: Duration,
+    ) -> Self {
+        self.clock_skew = Some((skew, max));
+        self
+    }
+
+    /// Age heartbeats with `clock`, the one of the contributor beating them
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Check the receive loop is running and beat within the liveness timeout
+    pub fn live(&self) -> Result<(), Unhealthy> {
+        if self.heartbeat.has_changed().is_err() {
+            return Err(Unhealthy::new("receive loop stopped"));
+        }
+        let silent_for = self.clock.elapsed(*self.heartbeat.borrow());
+        if silent_for > self.liveness_timeout {
+            return Err(Unhealthy::new(format!(
+                "receive loop silent for {}s",
+                silent_for.as_secs()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check the node finished starting, joined the p2p network and has a clock within
+    /// tolerance
+    pub fn ready(&self) -> Result<(), Unhealthy> {
+        if let Some(startup) = &self.startup
+            && !startup.is_ready()
+        {
+            let waiting: Vec<String> = startup
+                .report()
+                .tasks
+                .iter()
+                .filter(|(_, status)| !status.is_ready())
+                .map(|(name, status)| format!("{name} {status}"))
+                .collect();
+            return Err(Unhealthy::new(format!(
+                "waiting for startup: {}",
+                waiting.join(", ")
+            )));
+        }
+        if let Some(p2p) = &self.p2p
+            && !*p2p.borrow()
+        {
+            return Err(Unhealthy::new("p2p network not started"));
+        }
+        if let Some((skew, max)) = &self.clock_skew
+            && let Some(skew) = *skew.borrow()
+            && skew.magnitude() > *max
+        {
+            return Err(Unhealthy::new(format!(
+                "clock skew of {skew} exceeds {max:?}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Routes of the probes
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/health/live", get(live))
+            .route("/health/ready", get(ready))
+            .with_state(Arc::new(self))
+    }
+
+    /// Serve the probes on `listener` until the listener fails
+    pub async fn serve(self, listener: TcpListener) -> Result<()> {
+        axum::serve(listener, self.router()).await?;
+        Ok(())
+    }
+}
+
+async fn live(State(server): State<Arc<HealthCheckServer>>) -> Response {
+    respond(server.live())
+}
+
+async fn ready(State(server): State<Arc<HealthCheckServer>>) -> Response {
+    respond(server.ready())
+}
+
+fn respond(probe: Result<(), Unhealthy>) -> Response {
+    match probe {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(unhealthy) => (StatusCode::SERVICE_UNAVAILABLE, Json(unhealthy)).into_response(),
+    }
+}
@@ -0,0 +1,7 @@
+//! HTTP endpoints a node serves next to its p2p listener.
+#[cfg(test)]
+mod tests;
+
+pub mod health;
+
+pub use health::{HealthCheckServer, Unhealthy};
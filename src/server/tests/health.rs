@@ -0,0 +1,145 @@
+use crate::clock::Clock;
+use crate::contributor::tests::harness::Harness;
+use crate::handlers::RunConfig;
+use crate::runner::{NodeRunner, StartupTask};
+use crate::server::{HealthCheckServer, Unhealthy};
+use anyhow::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+const INTERVAL: Duration = Duration::from_secs(5);
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Contributor at index 0 beating a heartbeat, with the server probing it
+fn spawn_beating(harness: &Harness) -> (JoinHandle<Result<()>>, HealthCheckServer) {
+    let (heartbeat, receiver) = watch::channel(harness.clock.monotonic_now());
+    let contributor = harness.contributor(0, None).with_run_config(RunConfig {
+        heartbeat: Some((INTERVAL, Arc::new(heartbeat))),
+        ..RunConfig::default()
+    });
+    let server = HealthCheckServer::new(receiver)
+        .with_liveness_timeout(TIMEOUT)
+        .with_clock(harness.clock.clone());
+    (harness.spawn(contributor, 0), server)
+}
+
+/// Let the contributor handle what is pending
+async fn settle() {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
+
+/// Status line and body of `GET path` on the server at `port`
+async fn get(port: u16, path: &str) -> (String, String) {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    let request = format!("GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n");
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let status = response.lines().next().unwrap_or_default().to_string();
+    let body = response
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body.to_string())
+        .unwrap_or_default();
+    (status, body)
+}
+
+#[cfg(test)]
+mod health_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_live_while_idle_loop_beats() {
+        let harness = Harness::new(2);
+        let (handle, server) = spawn_beating(&harness);
+        settle().await;
+
+        // No message arrives, the loop still beats every interval
+        for _ in 0..12 {
+            harness.advance(INTERVAL);
+            settle().await;
+            assert_eq!(server.live(), Ok(()));
+        }
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_not_live_once_loop_stops() {
+        let harness = Harness::new(2);
+        let (handle, server) = spawn_beating(&harness);
+        settle().await;
+        assert_eq!(server.live(), Ok(()));
+
+        handle.abort();
+        settle().await;
+        assert_eq!(
+            server.live(),
+            Err(Unhealthy {
+                reason: "receive loop stopped".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_not_live_without_beats() {
+        let harness = Harness::new(2);
+        let (_heartbeat, receiver) = watch::channel(harness.clock.monotonic_now());
+        let server = HealthCheckServer::new(receiver)
+            .with_liveness_timeout(TIMEOUT)
+            .with_clock(harness.clock.clone());
+
+        harness.advance(TIMEOUT);
+        assert_eq!(server.live(), Ok(()));
+        harness.advance(Duration::from_secs(1));
+        assert_eq!(
+            server.live(),
+            Err(Unhealthy {
+                reason: "receive loop silent for 31s".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ready_once_started_and_connected() {
+        let mut startup =
+            NodeRunner::new().with_task(StartupTask::new("operator_state", || async { Ok(()) }));
+        let (_heartbeat, receiver) = watch::channel(Instant::now());
+        let (p2p_started, p2p) = watch::channel(false);
+        let server = HealthCheckServer::new(receiver)
+            .with_startup(startup.readiness())
+            .with_p2p(p2p);
+
+        let reason = server.ready().unwrap_err().reason;
+        assert_eq!(reason, "waiting for startup: operator_state pending");
+
+        startup.start().await.unwrap();
+        let reason = server.ready().unwrap_err().reason;
+        assert_eq!(reason, "p2p network not started");
+
+        p2p_started.send_replace(true);
+        assert_eq!(server.ready(), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_probes_served_over_http() {
+        let (_heartbeat, receiver) = watch::channel(Instant::now());
+        let (_p2p_started, p2p) = watch::channel(false);
+        let server = HealthCheckServer::new(receiver).with_p2p(p2p);
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = tokio::spawn(server.serve(listener));
+
+        let (status, _) = get(port, "/health/live").await;
+        assert!(status.contains("200"), "{status}");
+
+        let (status, body) = get(port, "/health/ready").await;
+        assert!(status.contains("503"), "{status}");
+        let body: Unhealthy = serde_json::from_str(&body).unwrap();
+        assert_eq!(body.reason, "p2p network not started");
+        handle.abort();
+    }
+}
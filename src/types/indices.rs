@@ -0,0 +1,28 @@
+//! Indices kept apart from the other `usize` values they would otherwise be mixed with.
+
+use std::fmt;
+
+/// Position of a contributor in the sorted contributor set
+///
+/// Also the position of its bit in a
+/// [ParticipationBitmap](crate::contributor::ParticipationBitmap).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ContributorIndex(usize);
+
+impl From<usize> for ContributorIndex {
+    fn from(index: usize) -> Self {
+        Self(index)
+    }
+}
+
+impl From<ContributorIndex> for usize {
+    fn from(index: ContributorIndex) -> Self {
+        index.0
+    }
+}
+
+impl fmt::Display for ContributorIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,5 @@
+//! Types shared across the node.
+
+pub mod indices;
+
+pub use indices::ContributorIndex;
@@ -0,0 +1,301 @@
+//! In-memory validation of counter rounds, for local networks without an RPC endpoint.
+
+use super::{PayloadValidator, ValidatorFactory};
+use crate::digest::HashAlgorithm;
+use alloy_primitives::Address;
+use anyhow::Result;
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::wire;
+use commonware_codec::{EncodeSize, ReadExt, Write};
+use futures::future::BoxFuture;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::debug;
+
+/// Largest counter value accepted
+pub const MAX_COUNTER: u64 = u64::MAX / 2;
+
+/// Reasons a counter round is rejected
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The message is not an encoded counter round
+    ParseFailed(String),
+    /// The counter is above [MAX_COUNTER]
+    OutOfRange(u64),
+    /// The counter does not increase on the last validated counter
+    NotIncreasing {
+        /// Last validated counter
+        last: u64,
+        /// Counter of the round
+        counter: u64,
+    },
+    /// The counter is further ahead of the confirmed counter than allowed
+    SkipsAhead {
+        /// Counter confirmed on-chain
+        confirmed: u64,
+        /// Counter of the round
+        counter: u64,
+    },
+    /// The signer is not a registered operator of the AVS, or has no known address
+    OperatorNotRegistered(Option<Address>),
+    /// The chain is past the block window of the round, which ends before `end_block`
+    OutsideWindow {
+        /// Current block
+        current_block: u64,
+        /// Start block of the round
+        start_block: u64,
+        /// First block past the window
+        end_block: u64,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::ParseFailed(err) => write!(f, "failed to parse counter: {err}"),
+            ValidationError::OutOfRange(counter) => {
+                write!(f, "counter out of range: {counter}")
+            }
+            ValidationError::NotIncreasing { last, counter } => {
+                write!(f, "counter not increasing: {counter} after {last}")
+            }
+            ValidationError::SkipsAhead { confirmed, counter } => {
+                write!(
+                    f,
+                    "counter skips ahead: {counter} after confirmed {confirmed}"
+                )
+            }
+            ValidationError::OperatorNotRegistered(Some(operator)) => {
+                write!(f, "operator {operator} not registered")
+            }
+            ValidationError::OperatorNotRegistered(None) => {
+                write!(f, "operator address unknown")
+            }
+            ValidationError::OutsideWindow {
+                current_block,
+                start_block,
+                end_block,
+            } => write!(
+                f,
+                "block {current_block} outside window [{start_block}, {end_block})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Validates that the round counter strictly increases, without chain access
+///
+/// The expected hash is the keccak256 of the round message without its payload, so it
+/// only depends on the counter and its metadata. Another [HashAlgorithm] can be set
+/// with [InMemoryCounterValidator::with_hash].
+#[derive(Debug, Default)]
+pub struct InMemoryCounterValidator {
+    last: Mutex<Option<u64>>,
+    hash: HashAlgorithm,
+}
+
+impl InMemoryCounterValidator {
+    /// Validator with no counter validated
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash the round message with `algorithm` instead of keccak256
+    pub fn with_hash(mut self, algorithm: HashAlgorithm) -> Self {
+        self.hash = algorithm;
+        self
+    }
+
+    /// Validate an encoded round message, returning the hash to sign
+    pub fn check(&self, message: &[u8]) -> Result<[u8; 32], ValidationError> {
+        let message =
+            wire::Aggregation::<CounterTaskData>::read(&mut std::io::Cursor::new(message))
+                .map_err(|err| ValidationError::ParseFailed(err.to_string()))?;
+        let counter = message.round;
+        if counter > MAX_COUNTER {
+            return Err(ValidationError::OutOfRange(counter));
+        }
+        let mut last = self.last.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(last) = *last
+            && counter <= last
+        {
+            return Err(ValidationError::NotIncreasing { last, counter });
+        }
+        *last = Some(counter);
+
+        let unsigned = wire::Aggregation::<CounterTaskData> {
+            round: message.round,
+            metadata: message.metadata,
+            payload: None,
+        };
+        let mut buf = Vec::with_capacity(unsigned.encode_size());
+        unsigned.write(&mut buf);
+        Ok(self.hash.hash(&buf))
+    }
+}
+
+impl PayloadValidator for InMemoryCounterValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move { Ok(self.check(message)?) })
+    }
+}
+
+/// Factory building a fresh [InMemoryCounterValidator]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InMemoryCounterValidatorFactory {
+    hash: HashAlgorithm,
+}
+
+impl InMemoryCounterValidatorFactory {
+    /// Build validators hashing round messages with `algorithm`
+    pub fn with_hash(mut self, algorithm: HashAlgorithm) -> Self {
+        self.hash = algorithm;
+        self
+    }
+}
+
+impl ValidatorFactory for InMemoryCounterValidatorFactory {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        Box::pin(async move {
+            let validator: Arc<dyn PayloadValidator> =
+                Arc::new(InMemoryCounterValidator::new().with_hash(self.hash));
+            Ok(validator)
+        })
+    }
+}
+
+/// Source of the last counter value confirmed on-chain
+pub trait CounterSource: Send + Sync + 'static {
+    /// Last counter confirmed on-chain
+    fn confirmed_counter(&self) -> impl Future<Output = Result<u64>> + Send;
+}
+
+/// Configuration of a [CachedCounterValidator]
+#[derive(Clone, Debug)]
+pub struct CounterCacheConfig {
+    /// Age after which the confirmed counter is read again
+    pub ttl: Duration,
+    /// Number of increments the cached counter may lag behind the chain, e.g. while
+    /// the block confirming the previous round propagates
+    pub tolerance: u64,
+}
+
+impl Default for CounterCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(12),
+            tolerance: 1,
+        }
+    }
+}
+
+/// Cached confirmed counter, as reported by [CachedCounterValidator::status]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CounterCacheStatus {
+    /// Confirmed counter
+    pub confirmed: u64,
+    /// Time since it was read
+    pub age: Duration,
+}
+
+/// Validator checking that a round increments the confirmed counter before the
+/// (RPC-backed) inner validator is consulted
+///
+/// The confirmed counter is cached for [CounterCacheConfig::ttl], or until
+/// [CachedCounterValidator::refresh] is called on a new block. A round must propose
+/// `confirmed + 1`, or up to [CounterCacheConfig::tolerance] more while the cache
+/// lags behind. A round further ahead is checked once more against a fresh read
+/// before it is rejected.
+pub struct CachedCounterValidator<S: CounterSource> {
+    source: Arc<S>,
+    inner: Arc<dyn PayloadValidator>,
+    config: CounterCacheConfig,
+    cache: tokio::sync::Mutex<Option<(u64, Instant)>>,
+}
+
+impl<S: CounterSource> CachedCounterValidator<S> {
+    /// Validator checking `source` before `inner`
+    pub fn new(
+        source: Arc<S>,
+        inner: Arc<dyn PayloadValidator>,
+        config: CounterCacheConfig,
+    ) -> Self {
+        Self {
+            source,
+            inner,
+            config,
+            cache: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Read the confirmed counter again, e.g. on every new block
+    pub async fn refresh(&self) -> Result<u64> {
+        let confirmed = self.source.confirmed_counter().await?;
+        *self.cache.lock().await = Some((confirmed, Instant::now()));
+        Ok(confirmed)
+    }
+
+    /// Cached confirmed counter and its age, `None` before the first read
+    pub async fn status(&self) -> Option<CounterCacheStatus> {
+        self.cache
+            .lock()
+            .await
+            .map(|(confirmed, read)| CounterCacheStatus {
+                confirmed,
+                age: read.elapsed(),
+            })
+    }
+
+    /// Check `counter` against the confirmed counter, reading it if the cache expired
+    pub async fn check(&self, counter: u64) -> Result<()> {
+        let mut cache = self.cache.lock().await;
+        let (mut confirmed, mut fresh) = match *cache {
+            Some((confirmed, read)) if read.elapsed() < self.config.ttl => (confirmed, false),
+            _ => (self.source.confirmed_counter().await?, true),
+        };
+        if fresh {
+            *cache = Some((confirmed, Instant::now()));
+        }
+        loop {
+            if counter <= confirmed {
+                return Err(ValidationError::NotIncreasing {
+                    last: confirmed,
+                    counter,
+                }
+                .into());
+            }
+            if counter - confirmed <= 1 + self.config.tolerance {
+                return Ok(());
+            }
+            if fresh {
+                return Err(ValidationError::SkipsAhead { confirmed, counter }.into());
+            }
+            // The cache may be behind by more than the tolerance, read the chain once
+            debug!(
+                counter,
+                confirmed, "counter ahead of cache, reading confirmed counter"
+            );
+            confirmed = self.source.confirmed_counter().await?;
+            *cache = Some((confirmed, Instant::now()));
+            fresh = true;
+        }
+    }
+}
+
+impl<S: CounterSource> PayloadValidator for CachedCounterValidator<S> {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move {
+            let counter =
+                wire::Aggregation::<CounterTaskData>::read(&mut std::io::Cursor::new(message))
+                    .map_err(|err| ValidationError::ParseFailed(err.to_string()))?
+                    .round;
+            self.check(counter).await?;
+            self.inner.validate(message).await
+        })
+    }
+}
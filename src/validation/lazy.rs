@@ -0,0 +1,159 @@
+//! Validator built in the background while the contributor already receives.
+//!
+//! Building a validator may need an RPC that is briefly unavailable when the node
+//! starts. [build_with_retry] retries with exponential backoff, publishing its
+//! [ValidatorStatus], until it succeeds or gives up. Until then a [LazyValidator]
+//! rejects every payload, so callers check [LazyValidator::is_ready] and keep the
+//! Starts they receive for later.
+
+use super::{PayloadValidator, ValidatorFactory};
+use crate::clock::Clock;
+use anyhow::Result;
+use futures::future::BoxFuture;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+/// Wait before the first retry unless configured otherwise
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Upper bound of the wait between retries as consecutive failures double it
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Time spent retrying before giving up unless configured otherwise
+pub const DEFAULT_GIVE_UP_AFTER: Duration = Duration::from_secs(300);
+
+/// Starts kept while the validator is built, the oldest is dropped beyond it
+pub const DEFAULT_MAX_BUFFERED_STARTS: usize = 64;
+
+/// Retries of the validator construction
+#[derive(Clone, Debug)]
+pub struct ValidatorRetryConfig {
+    /// Wait after the first failure
+    pub initial_backoff: Duration,
+    /// Longest wait between attempts
+    pub max_backoff: Duration,
+    /// Time after which construction stops being retried and Starts go unsigned
+    pub give_up_after: Duration,
+    /// Starts kept while the validator is built
+    pub max_buffered_starts: usize,
+}
+
+impl Default for ValidatorRetryConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            give_up_after: DEFAULT_GIVE_UP_AFTER,
+            max_buffered_starts: DEFAULT_MAX_BUFFERED_STARTS,
+        }
+    }
+}
+
+impl ValidatorRetryConfig {
+    /// Wait after `failures` consecutive failures
+    pub fn backoff(&self, failures: u32) -> Duration {
+        let factor = 1u32 << failures.saturating_sub(1).min(16);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Progress of the validator construction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidatorStatus {
+    /// Being built, `attempts` made so far
+    Initializing {
+        /// Attempts made
+        attempts: u32,
+    },
+    /// Built and validating
+    Ready,
+    /// Construction was retried until the give-up deadline
+    GaveUp,
+}
+
+impl ValidatorStatus {
+    /// Whether the validator is built
+    pub fn is_ready(&self) -> bool {
+        *self == ValidatorStatus::Ready
+    }
+}
+
+impl fmt::Display for ValidatorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidatorStatus::Initializing { attempts } => {
+                write!(f, "validator initializing ({attempts} attempts)")
+            }
+            ValidatorStatus::Ready => write!(f, "validator ready"),
+            ValidatorStatus::GaveUp => write!(f, "validator unavailable"),
+        }
+    }
+}
+
+/// Validator set once built, rejecting every payload before
+#[derive(Default)]
+pub struct LazyValidator(OnceLock<Arc<dyn PayloadValidator>>);
+
+impl LazyValidator {
+    /// Validator not built yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the built validator, ignored once set
+    pub fn set(&self, validator: Arc<dyn PayloadValidator>) {
+        let _ = self.0.set(validator);
+    }
+
+    /// Whether the built validator was set
+    pub fn is_ready(&self) -> bool {
+        self.0.get().is_some()
+    }
+}
+
+impl PayloadValidator for LazyValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        match self.0.get() {
+            Some(validator) => validator.validate(message),
+            None => Box::pin(async { Err(anyhow::anyhow!("validator initializing")) }),
+        }
+    }
+}
+
+/// Build a validator with `factory`, retrying with backoff until `give_up_after`
+///
+/// Every attempt and the outcome are published to `status`. Returns the last
+/// construction error once giving up.
+pub async fn build_with_retry(
+    factory: Arc<dyn ValidatorFactory>,
+    config: ValidatorRetryConfig,
+    clock: Arc<dyn Clock>,
+    status: Arc<watch::Sender<ValidatorStatus>>,
+) -> Result<Arc<dyn PayloadValidator>> {
+    let started = clock.monotonic_now();
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        status.send_replace(ValidatorStatus::Initializing { attempts });
+        let err = match factory.build().await {
+            Ok(validator) => {
+                info!(attempts, "validator ready");
+                status.send_replace(ValidatorStatus::Ready);
+                return Ok(validator);
+            }
+            Err(err) => err,
+        };
+        let backoff = config.backoff(attempts);
+        if clock.elapsed(started) + backoff > config.give_up_after {
+            status.send_replace(ValidatorStatus::GaveUp);
+            return Err(err.context(format!("validator unavailable after {attempts} attempts")));
+        }
+        warn!(attempts, ?backoff, %err, "failed to build validator, retrying");
+        clock.sleep_until(clock.monotonic_now() + backoff).await;
+    }
+}
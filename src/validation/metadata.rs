@@ -0,0 +1,191 @@
+//! Sanity checks on round metadata, run before the payload is validated.
+
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Metadata fields of a round the policy checks, `None` when absent
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoundMetadata {
+    /// Identifier of the task
+    pub task_id: Option<Vec<u8>>,
+    /// Block the task refers to
+    pub reference_block: Option<u64>,
+    /// Deadline as a unix timestamp in seconds
+    pub deadline: Option<u64>,
+}
+
+/// Extracts the checked fields from the metadata of a task kind
+pub type MetadataReader = Arc<dyn Fn(&CounterTaskData) -> RoundMetadata + Send + Sync>;
+
+/// Reason a Start was rejected by a [MetadataPolicy]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MetadataViolation {
+    /// A required field is absent
+    MissingField(&'static str),
+    /// The reference block is further ahead of the local head than tolerated
+    ReferenceBlockInFuture {
+        /// Reference block of the round
+        reference_block: u64,
+        /// Local chain head
+        head: u64,
+    },
+    /// The deadline is in the past
+    DeadlinePassed {
+        /// Deadline of the round, in unix seconds
+        deadline: u64,
+        /// Current time, in unix seconds
+        now: u64,
+    },
+    /// The deadline is beyond the accepted horizon
+    DeadlineTooFar {
+        /// Deadline of the round, in unix seconds
+        deadline: u64,
+        /// Latest deadline accepted, in unix seconds
+        max: u64,
+    },
+    /// The task id is longer than accepted
+    TaskIdTooLong {
+        /// Length of the task id, in bytes
+        len: usize,
+        /// Longest accepted, in bytes
+        max: usize,
+    },
+}
+
+impl MetadataViolation {
+    /// Short classification used to label rejection metrics
+    pub fn kind(&self) -> &'static str {
+        match self {
+            MetadataViolation::MissingField(_) => "missing_field",
+            MetadataViolation::ReferenceBlockInFuture { .. } => "reference_block_in_future",
+            MetadataViolation::DeadlinePassed { .. } => "deadline_passed",
+            MetadataViolation::DeadlineTooFar { .. } => "deadline_too_far",
+            MetadataViolation::TaskIdTooLong { .. } => "task_id_too_long",
+        }
+    }
+}
+
+impl fmt::Display for MetadataViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetadataViolation::MissingField(field) => write!(f, "missing {field}"),
+            MetadataViolation::ReferenceBlockInFuture {
+                reference_block,
+                head,
+            } => write!(f, "reference block {reference_block} ahead of head {head}"),
+            MetadataViolation::DeadlinePassed { deadline, now } => {
+                write!(f, "deadline {deadline} passed at {now}")
+            }
+            MetadataViolation::DeadlineTooFar { deadline, max } => {
+                write!(f, "deadline {deadline} after {max}")
+            }
+            MetadataViolation::TaskIdTooLong { len, max } => {
+                write!(f, "task id of {len} bytes exceeds {max}")
+            }
+        }
+    }
+}
+
+/// Rules a round's metadata must satisfy, tunable per task kind
+#[derive(Clone, Debug)]
+pub struct MetadataPolicy {
+    /// Reject rounds without a task id
+    pub require_task_id: bool,
+    /// Reject rounds without a reference block
+    pub require_reference_block: bool,
+    /// Reject rounds without a deadline
+    pub require_deadline: bool,
+    /// Blocks the reference block may be ahead of the local chain head
+    pub reference_block_tolerance: u64,
+    /// Furthest a deadline may be in the future
+    pub max_deadline_horizon: Duration,
+    /// Longest task id accepted, in bytes
+    pub max_task_id_len: usize,
+}
+
+impl Default for MetadataPolicy {
+    fn default() -> Self {
+        Self {
+            require_task_id: true,
+            require_reference_block: true,
+            require_deadline: true,
+            reference_block_tolerance: 2,
+            max_deadline_horizon: Duration::from_secs(3600),
+            max_task_id_len: 64,
+        }
+    }
+}
+
+impl MetadataPolicy {
+    /// Check metadata at time `now`, against the chain `head` if known
+    pub fn check(
+        &self,
+        metadata: &RoundMetadata,
+        now: SystemTime,
+        head: Option<u64>,
+    ) -> Result<(), MetadataViolation> {
+        self.check_with_tolerance(metadata, now, head, Duration::ZERO)
+    }
+
+    /// Check metadata at time `now`, which may be off by up to `tolerance`, such as the
+    /// measured skew of the local clock
+    ///
+    /// A deadline is only passed, or too far, once it is for every time within
+    /// `tolerance` of `now`.
+    pub fn check_with_tolerance(
+        &self,
+        metadata: &RoundMetadata,
+        now: SystemTime,
+        head: Option<u64>,
+        tolerance: Duration,
+    ) -> Result<(), MetadataViolation> {
+        // Required fields
+        if self.require_task_id && metadata.task_id.as_ref().is_none_or(|id| id.is_empty()) {
+            return Err(MetadataViolation::MissingField("task_id"));
+        }
+        if self.require_reference_block && metadata.reference_block.is_none() {
+            return Err(MetadataViolation::MissingField("reference_block"));
+        }
+        if self.require_deadline && metadata.deadline.is_none() {
+            return Err(MetadataViolation::MissingField("deadline"));
+        }
+
+        // Task identifier length
+        if let Some(task_id) = &metadata.task_id
+            && task_id.len() > self.max_task_id_len
+        {
+            return Err(MetadataViolation::TaskIdTooLong {
+                len: task_id.len(),
+                max: self.max_task_id_len,
+            });
+        }
+
+        // Reference block
+        if let (Some(reference_block), Some(head)) = (metadata.reference_block, head)
+            && reference_block > head.saturating_add(self.reference_block_tolerance)
+        {
+            return Err(MetadataViolation::ReferenceBlockInFuture {
+                reference_block,
+                head,
+            });
+        }
+
+        // Deadline
+        if let Some(deadline) = metadata.deadline {
+            let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+            let tolerance = tolerance.as_secs();
+            if deadline.saturating_add(tolerance) <= now {
+                return Err(MetadataViolation::DeadlinePassed { deadline, now });
+            }
+            let max = now
+                .saturating_add(self.max_deadline_horizon.as_secs())
+                .saturating_add(tolerance);
+            if deadline > max {
+                return Err(MetadataViolation::DeadlineTooFar { deadline, max });
+            }
+        }
+        Ok(())
+    }
+}
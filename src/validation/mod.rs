@@ -0,0 +1,115 @@
+//! Validation of round payloads before they are signed or verified.
+
+pub mod counter;
+pub mod lazy;
+pub mod metadata;
+pub mod voting;
+pub mod window;
+
+use crate::crypto::{TaskHashDomain, compute_avs_task_hash};
+use anyhow::Result;
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::usecases::counter::validator::CounterValidator;
+use commonware_avs_router::validator::Validator;
+use commonware_avs_router::wire;
+use commonware_codec::ReadExt;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+/// Validates an encoded round message and returns the hash contributors sign
+pub trait PayloadValidator: Send + Sync {
+    /// Validate `message`, returning the hash to sign
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>>;
+}
+
+/// Builds the [PayloadValidator] used by a contributor once its run loop starts
+pub trait ValidatorFactory: Send + Sync {
+    /// Build the validator
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>>;
+}
+
+/// Convert a hash returned by a validator into a fixed-size digest
+pub fn digest_from_slice(hash: &[u8]) -> Result<[u8; 32]> {
+    hash.try_into()
+        .map_err(|_| anyhow::anyhow!("unexpected payload hash length: {}", hash.len()))
+}
+
+/// Validator for the counter use case, backed by the router's [CounterValidator]
+///
+/// With a [TaskHashDomain], the returned hash is the [compute_avs_task_hash] of the
+/// round, bound to that deployment, instead of the router's expected hash.
+///
+/// Without a domain it signs the router's expected hash, which is not bound to a
+/// deployment. That is the default because the Counter contract rejects any other
+/// message hash on `increment`; signing the task hash unconditionally needs a task
+/// contract checking it, and its address and chain id in the node config.
+pub struct CounterPayloadValidator {
+    validator: Validator<CounterValidator>,
+    task_hash: Option<TaskHashDomain>,
+}
+
+impl CounterPayloadValidator {
+    /// Validator returning the router's expected hash
+    pub fn new(validator: Validator<CounterValidator>) -> Self {
+        Self {
+            validator,
+            task_hash: None,
+        }
+    }
+
+    /// Sign the task hash of each round bound to `domain`
+    pub fn with_task_hash(mut self, domain: TaskHashDomain) -> Self {
+        self.task_hash = Some(domain);
+        self
+    }
+}
+
+impl PayloadValidator for CounterPayloadValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move {
+            let hash = self
+                .validator
+                .validate_and_return_expected_hash(message)
+                .await?;
+            let Some(domain) = self.task_hash else {
+                return digest_from_slice(&hash[..]);
+            };
+            let message =
+                wire::Aggregation::<CounterTaskData>::read(&mut std::io::Cursor::new(message))?;
+            Ok(compute_avs_task_hash(
+                &message.metadata,
+                message.round,
+                domain.chain_id,
+                domain.contract,
+            ))
+        })
+    }
+}
+
+/// Factory connecting a [CounterPayloadValidator] to the configured chain
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CounterValidatorFactory {
+    task_hash: Option<TaskHashDomain>,
+}
+
+impl CounterValidatorFactory {
+    /// Build validators signing the task hash bound to `domain`
+    pub fn with_task_hash(mut self, domain: TaskHashDomain) -> Self {
+        self.task_hash = Some(domain);
+        self
+    }
+}
+
+impl ValidatorFactory for CounterValidatorFactory {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        Box::pin(async move {
+            let counter_validator = CounterValidator::new().await?;
+            let mut validator = CounterPayloadValidator::new(Validator::new(counter_validator));
+            if let Some(domain) = self.task_hash {
+                validator = validator.with_task_hash(domain);
+            }
+            let validator: Arc<dyn PayloadValidator> = Arc::new(validator);
+            Ok(validator)
+        })
+    }
+}
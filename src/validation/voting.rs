@@ -0,0 +1,75 @@
+//! Validation of voting rounds.
+
+use super::{PayloadValidator, ValidatorFactory};
+use crate::handlers::VotingTaskData;
+use anyhow::Result;
+use commonware_avs_router::wire;
+use commonware_codec::{EncodeSize, ReadExt, Write};
+use futures::future::BoxFuture;
+use std::fmt;
+use std::sync::Arc;
+
+/// Options a vote can be cast for
+pub const VOTE_OPTIONS: [u8; 2] = [0, 1];
+
+/// Reasons a voting round is rejected
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VoteError {
+    /// The message is not an encoded voting round
+    ParseFailed(String),
+    /// The option is not one of [VOTE_OPTIONS]
+    InvalidOption(u8),
+}
+
+impl fmt::Display for VoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VoteError::ParseFailed(err) => write!(f, "failed to parse vote: {err}"),
+            VoteError::InvalidOption(option) => write!(f, "invalid vote option: {option}"),
+        }
+    }
+}
+
+impl std::error::Error for VoteError {}
+
+/// Validates the option of a voting round
+///
+/// The expected hash is the keccak256 of the round message without its payload, so the
+/// Start and the signatures of a round map to the same hash.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct VotingValidator;
+
+impl VotingValidator {
+    /// Validate an encoded round message, returning the hash to sign
+    pub fn check(&self, message: &[u8]) -> Result<[u8; 32], VoteError> {
+        let message = wire::Aggregation::<VotingTaskData>::read(&mut std::io::Cursor::new(message))
+            .map_err(|err| VoteError::ParseFailed(err.to_string()))?;
+        if !VOTE_OPTIONS.contains(&message.metadata.option) {
+            return Err(VoteError::InvalidOption(message.metadata.option));
+        }
+
+        let unsigned = wire::Aggregation::<VotingTaskData> {
+            round: message.round,
+            metadata: message.metadata,
+            payload: None,
+        };
+        let mut buf = Vec::with_capacity(unsigned.encode_size());
+        unsigned.write(&mut buf);
+        Ok(alloy_primitives::keccak256(&buf).0)
+    }
+}
+
+impl PayloadValidator for VotingValidator {
+    fn validate<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<[u8; 32]>> {
+        Box::pin(async move { Ok(self.check(message)?) })
+    }
+}
+
+impl ValidatorFactory for VotingValidator {
+    fn build(&self) -> BoxFuture<'_, Result<Arc<dyn PayloadValidator>>> {
+        Box::pin(async move {
+            let validator: Arc<dyn PayloadValidator> = Arc::new(VotingValidator);
+            Ok(validator)
+        })
+    }
+}
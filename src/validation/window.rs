@@ -0,0 +1,74 @@
+//! Block window in which a round's signatures can still be verified on-chain.
+//!
+//! The contract accepts the response to a task for a fixed number of blocks after its
+//! reference block. A signature arriving once the chain is past that window can no
+//! longer be part of an accepted response, so it is rejected before it is recorded.
+
+use super::counter::ValidationError;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Source of the latest block number
+pub trait BlockProvider: Send + Sync {
+    /// Latest block number
+    fn current_block(&self) -> u64;
+}
+
+/// The chain head a watcher keeps up to date
+impl BlockProvider for AtomicU64 {
+    fn current_block(&self) -> u64 {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+/// Accepts signatures of a round from its start block until `window_blocks` later
+#[derive(Clone)]
+pub struct SignatureWindowFilter {
+    /// Block the round started at
+    pub start_block: u64,
+    /// Blocks signatures are accepted for
+    pub window_blocks: u64,
+    /// Source of the current block
+    pub block_provider: Arc<dyn BlockProvider>,
+}
+
+impl SignatureWindowFilter {
+    /// Filter of the `window_blocks` blocks from `start_block`
+    pub fn new(
+        start_block: u64,
+        window_blocks: u64,
+        block_provider: Arc<dyn BlockProvider>,
+    ) -> Self {
+        Self {
+            start_block,
+            window_blocks,
+            block_provider,
+        }
+    }
+
+    /// First block past the window
+    pub fn end_block(&self) -> u64 {
+        self.start_block.saturating_add(self.window_blocks)
+    }
+
+    /// Check the current block is before `end_block`
+    ///
+    /// A current block behind `start_block` is a head not caught up yet, not a late
+    /// signature, so it is accepted.
+    pub fn check(&self) -> Result<(), ValidationError> {
+        let current_block = self.block_provider.current_block();
+        if current_block < self.end_block() {
+            return Ok(());
+        }
+        Err(ValidationError::OutsideWindow {
+            current_block,
+            start_block: self.start_block,
+            end_block: self.end_block(),
+        })
+    }
+
+    /// Whether the current block is before `end_block`
+    pub fn is_within_window(&self) -> bool {
+        self.check().is_ok()
+    }
+}
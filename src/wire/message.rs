@@ -0,0 +1,96 @@
+//! Aggregation messages classified by the role their sender plays in a round.
+//!
+//! A decoded [Aggregation](wire::Aggregation) is either the Start of a round or a
+//! contributor's share. [ContributorMessage::try_from] tells them apart, checks the
+//! sender may play that role against the node's [Roles], and decodes the share's
+//! signature, failing on a malformed one, so the receive loop only dispatches.
+
+use crate::contributor::decode::{MalformedSignature, MessageKind, try_classify};
+use crate::types::ContributorIndex;
+use bn254::{PublicKey as PubKey, Signature as Sig};
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::wire;
+use std::collections::{HashMap, HashSet};
+
+/// Start of a round, from the peer starting it
+#[derive(Debug)]
+pub struct Start {
+    /// Peer the Start was received from
+    pub sender: PubKey,
+    /// Round, task metadata and payload of the Start
+    pub message: wire::Aggregation<CounterTaskData>,
+}
+
+/// Share of a round from a contributor
+#[derive(Debug)]
+pub struct ContributorSig {
+    /// Peer the share was received from
+    pub sender: PubKey,
+    /// Message carrying the share
+    pub message: wire::Aggregation<CounterTaskData>,
+    /// Decoded signature of the share, not verified yet
+    pub signature: Sig,
+}
+
+/// Aggregation message received by a contributor
+#[derive(Debug)]
+pub enum ContributorMessage {
+    /// A Start, to be signed if its sender is an orchestrator
+    FromOrchestrator(Start),
+    /// A share, to be collected if its sender is a contributor
+    FromContributor(ContributorSig),
+    /// A Start from a peer that is not an orchestrator
+    StartFromPeer(PubKey),
+    /// A share this node does not collect: it does not aggregate, or the sender is an
+    /// orchestrator outside the contributor set
+    Uncollected,
+    /// No payload, or a payload this node does not handle
+    Unknown,
+}
+
+/// Sets of a node the sender of a message is classified against
+#[derive(Clone, Copy, Debug)]
+pub struct Roles<'a> {
+    /// Peers allowed to start rounds
+    pub orchestrators: &'a HashSet<PubKey>,
+    /// Contributors shares are collected from, `None` unless the node aggregates
+    pub contributors: Option<&'a HashMap<PubKey, ContributorIndex>>,
+}
+
+impl Roles<'_> {
+    /// Whether a share from `sender` is collected
+    ///
+    /// An orchestrator may also contribute: its Starts are control, its shares are
+    /// collected under its contributor index. Other senders are collected even outside
+    /// the contributor set, which may not have caught up with them yet.
+    pub fn collects_from(&self, sender: &PubKey) -> bool {
+        let Some(contributors) = self.contributors else {
+            return false;
+        };
+        !self.orchestrators.contains(sender) || contributors.contains_key(sender)
+    }
+}
+
+impl TryFrom<(PubKey, wire::Aggregation<CounterTaskData>, Roles<'_>)> for ContributorMessage {
+    type Error = MalformedSignature;
+
+    fn try_from(
+        (sender, message, roles): (PubKey, wire::Aggregation<CounterTaskData>, Roles<'_>),
+    ) -> Result<Self, Self::Error> {
+        Ok(match try_classify(&message)? {
+            MessageKind::Start if roles.orchestrators.contains(&sender) => {
+                Self::FromOrchestrator(Start { sender, message })
+            }
+            MessageKind::Start => Self::StartFromPeer(sender),
+            MessageKind::Signature(signature) if roles.collects_from(&sender) => {
+                Self::FromContributor(ContributorSig {
+                    sender,
+                    message,
+                    signature,
+                })
+            }
+            MessageKind::Signature(_) => Self::Uncollected,
+            MessageKind::Unknown => Self::Unknown,
+        })
+    }
+}
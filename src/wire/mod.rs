@@ -0,0 +1,5 @@
+//! Messages of the aggregation protocol, as handled by the node.
+
+pub mod message;
+
+pub use message::{ContributorMessage, ContributorSig, Roles, Start};
@@ -0,0 +1,183 @@
+//! Full aggregation rounds over an unreliable network dropping, delaying and
+//! duplicating the messages contributors receive.
+//!
+//! Every run prints its seed; set `CHAOS_SEED` to reproduce a failing run.
+#![allow(clippy::disallowed_methods)]
+
+mod common;
+
+use bytes::Bytes;
+use common::{ROUND_TIMEOUT, connection, create_test_bn254, setup_with};
+use commonware_cryptography::Signer;
+use commonware_p2p::Receiver;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Seed used when `CHAOS_SEED` is not set
+const DEFAULT_SEED: u64 = 0x00c4_a05e;
+
+/// Seed of this run, printed so a failure can be reproduced
+fn seed() -> u64 {
+    let seed = std::env::var("CHAOS_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED);
+    println!("chaos seed: {seed} (set CHAOS_SEED={seed} to reproduce)");
+    seed
+}
+
+/// Faults injected by a [ChaoticReceiver]
+#[derive(Clone, Copy, Debug)]
+struct Chaos {
+    /// Probability a message is dropped
+    p_drop: f64,
+    /// Probability a delivered message is delivered twice
+    p_dup: f64,
+    /// Upper bound of the uniform delay added to each delivery
+    max_delay_ms: u64,
+}
+
+/// Receiver dropping, delaying and duplicating the messages of another receiver
+///
+/// Delayed messages are held by the receiver itself, so a `recv` cancelled while
+/// waiting loses nothing.
+#[derive(Debug)]
+struct ChaoticReceiver<R: Receiver> {
+    inner: R,
+    chaos: Chaos,
+    rng: StdRng,
+    queue: VecDeque<(Instant, (R::PublicKey, Bytes))>,
+}
+
+impl<R: Receiver> ChaoticReceiver<R> {
+    fn new(inner: R, chaos: Chaos, seed: u64) -> Self {
+        Self {
+            inner,
+            chaos,
+            rng: StdRng::seed_from_u64(seed),
+            queue: VecDeque::new(),
+        }
+    }
+
+    fn delay(&mut self) -> Instant {
+        Instant::now() + Duration::from_millis(self.rng.random_range(0..=self.chaos.max_delay_ms))
+    }
+}
+
+impl<R: Receiver> Receiver for ChaoticReceiver<R> {
+    type Error = R::Error;
+    type PublicKey = R::PublicKey;
+
+    async fn recv(&mut self) -> Result<(Self::PublicKey, Bytes), Self::Error> {
+        loop {
+            if let Some((at, _)) = self.queue.front() {
+                tokio::time::sleep_until(*at).await;
+                let (_, message) = self.queue.pop_front().expect("queue is not empty");
+                return Ok(message);
+            }
+
+            let message = self.inner.recv().await?;
+            if self.rng.random_bool(self.chaos.p_drop) {
+                continue;
+            }
+            let at = self.delay();
+            if self.rng.random_bool(self.chaos.p_dup) {
+                let again = self.delay().max(at);
+                self.queue.push_back((at, message.clone()));
+                self.queue.push_back((again, message));
+            } else {
+                self.queue.push_back((at, message));
+            }
+        }
+    }
+}
+
+/// Send `count` numbered frames through a chaotic receiver and collect what arrives
+async fn deliver(chaos: Chaos, count: u8) -> Vec<u8> {
+    let key = create_test_bn254(1).public_key();
+    let (mut sender, receiver) = connection(key.clone(), key.clone());
+    let mut receiver = ChaoticReceiver::new(receiver, chaos, seed());
+    for i in 0..count {
+        commonware_p2p::Sender::send(
+            &mut sender,
+            commonware_p2p::Recipients::One(key.clone()),
+            Bytes::from(vec![i]),
+            true,
+        )
+        .await
+        .unwrap();
+    }
+    drop(sender);
+
+    let mut received = Vec::new();
+    while let Ok((_, frame)) = receiver.recv().await {
+        received.push(frame[0]);
+    }
+    received
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_drops_everything() {
+    let chaos = Chaos {
+        p_drop: 1.0,
+        p_dup: 0.0,
+        max_delay_ms: 0,
+    };
+    assert!(deliver(chaos, 10).await.is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_duplicates_everything() {
+    let chaos = Chaos {
+        p_drop: 0.0,
+        p_dup: 1.0,
+        max_delay_ms: 0,
+    };
+    let received = deliver(chaos, 3).await;
+    assert_eq!(received, vec![0, 0, 1, 1, 2, 2]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_delays_are_bounded() {
+    let chaos = Chaos {
+        p_drop: 0.0,
+        p_dup: 0.0,
+        max_delay_ms: 100,
+    };
+    let start = Instant::now();
+    let received = deliver(chaos, 10).await;
+    assert_eq!(received, (0..10).collect::<Vec<_>>());
+    assert!(start.elapsed() <= Duration::from_millis(10 * 100));
+}
+
+#[tokio::test]
+async fn test_rounds_are_certified_under_chaos() {
+    let chaos = Chaos {
+        p_drop: 0.1,
+        p_dup: 0.05,
+        max_delay_ms: 100,
+    };
+    let seed = seed();
+    let (mut orchestrator, handles) = setup_with(4, 3, |index, receiver| {
+        ChaoticReceiver::new(receiver, chaos, seed.wrapping_add(index as u64))
+    });
+
+    for round in 1..=10 {
+        let started = Instant::now();
+        let certificate = orchestrator
+            .run_round(round)
+            .await
+            .unwrap_or_else(|err| panic!("round {round} failed with seed {seed}: {err}"));
+        assert!(started.elapsed() < ROUND_TIMEOUT);
+        assert_eq!(certificate.round, round);
+        assert!(certificate.verify(&orchestrator.contributors));
+    }
+    assert_eq!(orchestrator.certificates.len(), 10);
+
+    for handle in handles {
+        handle.abort();
+    }
+}
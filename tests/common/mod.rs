@@ -0,0 +1,284 @@
+//! Mock orchestrator driving full aggregation rounds against real contributors.
+
+#![allow(dead_code)]
+
+use anyhow::{Result, anyhow};
+use ark_bn254::Fr;
+use bn254::{Bn254, PrivateKey, PublicKey, Signature, aggregate_signatures, aggregate_verify};
+use bytes::Bytes;
+use commonware_avs_node::contributor::{
+    Contribute, OutboundRouter, ParticipationBitmap, QuorumCertificate,
+};
+use commonware_avs_node::handlers::Contributor;
+use commonware_avs_node::validation::counter::InMemoryCounterValidatorFactory;
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::wire::{self, aggregation::Payload};
+use commonware_codec::{EncodeSize, ReadExt, Write};
+use commonware_cryptography::Signer;
+use commonware_p2p::{Receiver, Recipients, Sender};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+pub const ROUND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Interval at which a Start is sent again to contributors that did not sign yet
+pub const START_RETRY_INTERVAL: Duration = Duration::from_millis(500);
+
+pub fn create_test_bn254(seed: u64) -> Bn254 {
+    Bn254::new(PrivateKey::from(Fr::from(seed))).expect("Failed to create Bn254 from private key")
+}
+
+pub fn encode(message: &wire::Aggregation<CounterTaskData>) -> Bytes {
+    let mut buf = Vec::with_capacity(message.encode_size());
+    message.write(&mut buf);
+    Bytes::from(buf)
+}
+
+#[derive(Debug)]
+pub struct MockError(pub String);
+
+impl fmt::Display for MockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MockError: {}", self.0)
+    }
+}
+
+impl std::error::Error for MockError {}
+
+pub type Frame = (PublicKey, Bytes);
+
+/// Sending half of a connection, every frame reaches the peer whatever the recipients
+#[derive(Clone, Debug)]
+pub struct MockSender {
+    me: PublicKey,
+    peer: PublicKey,
+    outbox: mpsc::UnboundedSender<Frame>,
+}
+
+impl Sender for MockSender {
+    type Error = MockError;
+    type PublicKey = PublicKey;
+
+    async fn send(
+        &mut self,
+        _recipients: Recipients<Self::PublicKey>,
+        message: Bytes,
+        _priority: bool,
+    ) -> Result<Vec<Self::PublicKey>, Self::Error> {
+        self.outbox
+            .send((self.me.clone(), message))
+            .map_err(|_| MockError("connection closed".to_string()))?;
+        Ok(vec![self.peer.clone()])
+    }
+}
+
+/// Receiving half of a connection
+#[derive(Debug)]
+pub struct MockReceiver {
+    inbox: mpsc::UnboundedReceiver<Frame>,
+}
+
+impl Receiver for MockReceiver {
+    type Error = MockError;
+    type PublicKey = PublicKey;
+
+    async fn recv(&mut self) -> Result<(Self::PublicKey, Bytes), Self::Error> {
+        self.inbox
+            .recv()
+            .await
+            .ok_or_else(|| MockError("connection closed".to_string()))
+    }
+}
+
+/// One-way connection from `me` to `peer`
+pub fn connection(me: PublicKey, peer: PublicKey) -> (MockSender, MockReceiver) {
+    let (outbox, inbox) = mpsc::unbounded_channel();
+    (MockSender { me, peer, outbox }, MockReceiver { inbox })
+}
+
+/// Orchestrator starting rounds and aggregating the returned signatures
+pub struct MockOrchestrator {
+    pub signer: Bn254,
+    pub contributors: Vec<PublicKey>,
+    pub threshold: usize,
+    senders: Vec<MockSender>,
+    receiver: MockReceiver,
+    outbox: mpsc::UnboundedSender<Frame>,
+    pub certificates: Vec<QuorumCertificate>,
+}
+
+impl MockOrchestrator {
+    pub fn new(signer: Bn254, mut contributors: Vec<PublicKey>, threshold: usize) -> Self {
+        contributors.sort();
+        let (outbox, inbox) = mpsc::unbounded_channel();
+        Self {
+            signer,
+            contributors,
+            threshold,
+            senders: Vec::new(),
+            receiver: MockReceiver { inbox },
+            outbox,
+            certificates: Vec::new(),
+        }
+    }
+
+    /// Open a connection to a contributor, returning the contributor's halves
+    pub fn connect(&mut self, contributor: PublicKey) -> (MockSender, MockReceiver) {
+        let (sender, receiver) = connection(self.signer.public_key(), contributor.clone());
+        self.senders.push(sender);
+        let sender = MockSender {
+            me: contributor,
+            peer: self.signer.public_key(),
+            outbox: self.outbox.clone(),
+        };
+        (sender, receiver)
+    }
+
+    /// Send a Start to every contributor whose signature is not in `signed`
+    async fn send_start(
+        &mut self,
+        frame: &Bytes,
+        signed: &HashMap<usize, Signature>,
+    ) -> Result<()> {
+        for sender in &mut self.senders {
+            let peer = sender.peer.clone();
+            if let Ok(index) = self.contributors.binary_search(&peer)
+                && signed.contains_key(&index)
+            {
+                continue;
+            }
+            sender
+                .send(Recipients::One(peer), frame.clone(), true)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Start a round, wait for a threshold of signatures and certify their aggregate
+    ///
+    /// The Start is sent again to contributors that did not sign yet every
+    /// [START_RETRY_INTERVAL], in case it was lost.
+    pub fn run_round(
+        &mut self,
+        round: u64,
+    ) -> impl Future<Output = Result<QuorumCertificate>> + '_ {
+        async move {
+            let start = wire::Aggregation::<CounterTaskData> {
+                round,
+                metadata: Default::default(),
+                payload: Some(Payload::Start),
+            };
+            let frame = encode(&start);
+            let mut signatures: HashMap<usize, Signature> = HashMap::new();
+            self.send_start(&frame, &signatures).await?;
+
+            // Contributors sign the hash of the message without its payload
+            let unsigned = wire::Aggregation::<CounterTaskData> {
+                payload: None,
+                ..start
+            };
+            let payload = alloy_primitives::keccak256(encode(&unsigned)).0;
+
+            let deadline = tokio::time::Instant::now() + ROUND_TIMEOUT;
+            let mut retry = tokio::time::Instant::now() + START_RETRY_INTERVAL;
+            while signatures.len() < self.threshold {
+                let Ok(received) =
+                    tokio::time::timeout_at(retry.min(deadline), self.receiver.recv()).await
+                else {
+                    if tokio::time::Instant::now() >= deadline {
+                        return Err(anyhow!("round {round} timed out"));
+                    }
+                    self.send_start(&frame, &signatures).await?;
+                    retry += START_RETRY_INTERVAL;
+                    continue;
+                };
+                let (sender, reply) = received?;
+                let Ok(message) =
+                    wire::Aggregation::<CounterTaskData>::read(&mut std::io::Cursor::new(reply))
+                else {
+                    continue;
+                };
+                let Some(Payload::Signature(signature)) = message.payload else {
+                    continue;
+                };
+                if message.round != round {
+                    continue;
+                }
+                let Ok(index) = self.contributors.binary_search(&sender) else {
+                    continue;
+                };
+                let Ok(signature) = Signature::try_from(signature) else {
+                    continue;
+                };
+                if !aggregate_verify(std::slice::from_ref(&sender), None, &payload, &signature) {
+                    continue;
+                }
+                signatures.entry(index).or_insert(signature);
+            }
+
+            let mut participants: Vec<usize> = signatures.keys().copied().collect();
+            participants.sort();
+            let ordered: Vec<Signature> = participants
+                .iter()
+                .map(|index| signatures[index].clone())
+                .collect();
+            let signature =
+                aggregate_signatures(&ordered).ok_or_else(|| anyhow!("failed to aggregate"))?;
+            let certificate = QuorumCertificate {
+                round,
+                payload,
+                signature,
+                signers: participants.into_iter().collect::<ParticipationBitmap>(),
+            };
+            if !certificate.verify(&self.contributors) {
+                return Err(anyhow!("invalid aggregate signature for round {round}"));
+            }
+            self.certificates.push(certificate.clone());
+            Ok(certificate)
+        }
+    }
+}
+
+/// Connect `count` contributors to a new orchestrator and run them in the background
+pub fn setup(count: u64, threshold: usize) -> (MockOrchestrator, Vec<JoinHandle<Result<()>>>) {
+    setup_with(count, threshold, |_, receiver| receiver)
+}
+
+/// Like [setup], wrapping the receiver of the contributor at each index with `wrap`
+pub fn setup_with<R, F>(
+    count: u64,
+    threshold: usize,
+    mut wrap: F,
+) -> (MockOrchestrator, Vec<JoinHandle<Result<()>>>)
+where
+    R: Receiver<PublicKey = PublicKey>,
+    R::Error: std::error::Error + 'static,
+    F: FnMut(usize, MockReceiver) -> R,
+{
+    let signers: Vec<Bn254> = (0..count).map(|i| create_test_bn254(3000 + i)).collect();
+    let contributors: Vec<PublicKey> = signers.iter().map(|signer| signer.public_key()).collect();
+    let mut orchestrator =
+        MockOrchestrator::new(create_test_bn254(4000), contributors.clone(), threshold);
+
+    let handles = signers
+        .into_iter()
+        .enumerate()
+        .map(|(index, signer)| {
+            let (sender, receiver) = orchestrator.connect(signer.public_key());
+            let receiver = wrap(index, receiver);
+            let contributor = Contributor::new(
+                orchestrator.signer.public_key(),
+                signer,
+                contributors.clone(),
+                None,
+            )
+            .with_validator_factory(Arc::new(InMemoryCounterValidatorFactory::default()));
+            tokio::spawn(contributor.run(OutboundRouter::single(sender), receiver))
+        })
+        .collect();
+    (orchestrator, handles)
+}
@@ -0,0 +1,95 @@
+use commonware_avs_node::validation::PayloadValidator;
+use commonware_avs_node::validation::counter::{
+    InMemoryCounterValidator, MAX_COUNTER, ValidationError,
+};
+use commonware_avs_router::usecases::counter::creator::CounterTaskData;
+use commonware_avs_router::wire::{self, aggregation::Payload};
+use commonware_codec::{EncodeSize, Write};
+
+// Encoded Start for a counter value
+fn counter_message(counter: u64) -> Vec<u8> {
+    let message = wire::Aggregation::<CounterTaskData> {
+        round: counter,
+        metadata: Default::default(),
+        payload: Some(Payload::Start),
+    };
+    let mut buf = Vec::with_capacity(message.encode_size());
+    message.write(&mut buf);
+    buf
+}
+
+// Validation error behind a validator failure
+async fn validation_error(validator: &InMemoryCounterValidator, message: &[u8]) -> ValidationError {
+    let err = validator
+        .validate(message)
+        .await
+        .expect_err("message should be rejected");
+    err.downcast::<ValidationError>()
+        .expect("error should be a validation error")
+}
+
+#[tokio::test]
+async fn test_valid_counter_returns_deterministic_hash() {
+    let message = counter_message(42);
+    let first = InMemoryCounterValidator::new()
+        .validate(&message)
+        .await
+        .unwrap();
+    let second = InMemoryCounterValidator::new()
+        .validate(&message)
+        .await
+        .unwrap();
+    assert_eq!(first, second);
+
+    let other = InMemoryCounterValidator::new()
+        .validate(&counter_message(43))
+        .await
+        .unwrap();
+    assert_ne!(first, other);
+}
+
+#[tokio::test]
+async fn test_zero_counter_is_valid() {
+    let validator = InMemoryCounterValidator::new();
+    assert!(validator.validate(&counter_message(0)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_counter_above_limit_is_invalid() {
+    let validator = InMemoryCounterValidator::new();
+    assert!(
+        validator
+            .validate(&counter_message(MAX_COUNTER))
+            .await
+            .is_ok()
+    );
+
+    let validator = InMemoryCounterValidator::new();
+    assert_eq!(
+        validation_error(&validator, &counter_message(MAX_COUNTER + 1)).await,
+        ValidationError::OutOfRange(MAX_COUNTER + 1)
+    );
+}
+
+#[tokio::test]
+async fn test_malformed_bytes_fail_to_parse() {
+    let validator = InMemoryCounterValidator::new();
+    let err = validation_error(&validator, &[0xde, 0xad]).await;
+    assert!(matches!(err, ValidationError::ParseFailed(_)), "{err:?}");
+}
+
+#[tokio::test]
+async fn test_repeated_counter_is_not_increasing() {
+    let validator = InMemoryCounterValidator::new();
+    validator.validate(&counter_message(7)).await.unwrap();
+    assert_eq!(
+        validation_error(&validator, &counter_message(7)).await,
+        ValidationError::NotIncreasing {
+            last: 7,
+            counter: 7
+        }
+    );
+
+    // The rejected counter does not advance the state
+    assert!(validator.validate(&counter_message(8)).await.is_ok());
+}
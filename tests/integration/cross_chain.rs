@@ -0,0 +1,69 @@
+use alloy_primitives::{Address, address};
+use alloy_provider::{Provider, ProviderBuilder};
+use alloy_signer_local::PrivateKeySigner;
+use ark_bn254::Fr;
+use bn254::{Bn254, PrivateKey};
+use commonware_avs_node::chain::CrossChainAggregationRouter;
+use commonware_avs_node::contributor::QuorumCertificate;
+use commonware_cryptography::Signer;
+use std::env;
+
+// First default Anvil account, funded on every Anvil instance
+const SENDER_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+// Receives the certificates; an account without code accepts any calldata
+const DESTINATION: Address = address!("90F79bf6EB2c4f870365E785982E1f101E93b906");
+// Nothing listens there, submissions to it always fail
+const UNREACHABLE_RPC: &str = "http://127.0.0.1:1";
+
+fn certificate() -> QuorumCertificate {
+    let signer = Bn254::new(PrivateKey::from(Fr::from(1u64))).expect("Failed to create signer");
+    QuorumCertificate {
+        round: 1,
+        payload: [1; 32],
+        signature: signer.sign(None, &[1; 32]),
+        signers: [0].into_iter().collect(),
+    }
+}
+
+// Submits a certificate to two chains, e.g. `anvil --port 8545` and
+// `anvil --port 8546 --chain-id 31338`, at CROSS_CHAIN_RPC_A and CROSS_CHAIN_RPC_B.
+// Skipped when either is unset.
+#[tokio::test]
+async fn test_routes_certificate_to_two_chains() {
+    let (Ok(rpc_a), Ok(rpc_b)) = (env::var("CROSS_CHAIN_RPC_A"), env::var("CROSS_CHAIN_RPC_B"))
+    else {
+        eprintln!("CROSS_CHAIN_RPC_A or CROSS_CHAIN_RPC_B not set, skipping cross-chain test");
+        return;
+    };
+    let provider_a = ProviderBuilder::new().on_http(rpc_a.parse().unwrap());
+    let provider_b = ProviderBuilder::new().on_http(rpc_b.parse().unwrap());
+    let chain_a = provider_a.get_chain_id().await.unwrap();
+    let chain_b = provider_b.get_chain_id().await.unwrap();
+    assert_ne!(chain_a, chain_b, "the two nodes must run different chains");
+
+    let mut router = CrossChainAggregationRouter::new()
+        .with_signer(SENDER_KEY.parse::<PrivateKeySigner>().unwrap());
+    router.add_destination(chain_a, DESTINATION, rpc_a).unwrap();
+    router.add_destination(chain_b, DESTINATION, rpc_b).unwrap();
+    // A third chain that is down must not keep the others from receiving the certificate
+    router
+        .add_destination(
+            chain_a.max(chain_b) + 1,
+            DESTINATION,
+            UNREACHABLE_RPC.to_string(),
+        )
+        .unwrap();
+
+    let results = router.submit(&certificate()).await;
+    assert_eq!(results.len(), 3);
+    assert!(results[2].1.is_err());
+
+    for ((_, result), provider) in results.into_iter().zip([provider_a, provider_b]) {
+        let hash = result.unwrap();
+        let transaction = provider.get_transaction_by_hash(hash).await.unwrap();
+        assert!(
+            transaction.is_some(),
+            "certificate not on the destination chain"
+        );
+    }
+}
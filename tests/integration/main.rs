@@ -0,0 +1,7 @@
+//! Integration tests, run with `cargo test --features integration-tests`.
+#![allow(clippy::disallowed_methods)]
+
+mod counter_validator;
+mod cross_chain;
+mod rpc_pool;
+mod service_manager;
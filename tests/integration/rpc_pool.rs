@@ -0,0 +1,56 @@
+use alloy_provider::{Provider, ProviderBuilder};
+use commonware_avs_node::chain::{PoolConfig, RpcConnectionPool};
+use std::env;
+use std::time::{Duration, Instant};
+
+const CALLS: usize = 100;
+
+// Time taken by `CALLS` sequential calls to `call`
+async fn time_calls<F, Fut>(mut call: F) -> Duration
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    let start = Instant::now();
+    for _ in 0..CALLS {
+        call().await;
+    }
+    start.elapsed()
+}
+
+// Compares sequential round trips with a provider per call against pooled providers.
+// Needs a node at HTTP_RPC, e.g. `anvil`, and is skipped otherwise.
+#[tokio::test]
+async fn bench_sequential_calls_with_and_without_pool() {
+    let Ok(http_rpc) = env::var("HTTP_RPC") else {
+        eprintln!("HTTP_RPC not set, skipping rpc pool benchmark");
+        return;
+    };
+    let url: url::Url = http_rpc.parse().unwrap();
+
+    let unpooled = time_calls(|| {
+        let url = url.clone();
+        async move {
+            let provider = ProviderBuilder::new().on_http(url);
+            provider.get_block_number().await.unwrap();
+        }
+    })
+    .await;
+
+    let pool = RpcConnectionPool::new(PoolConfig::default(), {
+        let url = url.clone();
+        move || Ok(ProviderBuilder::new().on_http(url.clone()).erased())
+    });
+    let pooled = time_calls(|| async {
+        let provider = pool.acquire().await.unwrap();
+        provider.get_block_number().await.unwrap();
+    })
+    .await;
+
+    eprintln!(
+        "{CALLS} sequential calls: {unpooled:?} without pool ({:?}/call), {pooled:?} with pool ({:?}/call)",
+        unpooled / CALLS as u32,
+        pooled / CALLS as u32,
+    );
+    assert_eq!(pool.idle(), 1);
+}
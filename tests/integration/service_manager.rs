@@ -0,0 +1,48 @@
+use alloy_primitives::{Address, address};
+use commonware_avs_node::chain::{AvsMetadata, ServiceManagerClient};
+use std::env;
+
+// Constructor arguments of the stub deployed by `scripts/deploy-service-manager-stub.sh`
+const TASK_MANAGER: Address = address!("70997970C51812dc3A010C7d01b50e0d17dc79C8");
+const OPERATOR: Address = address!("3C44CdDdB6a900fa2b585dd299e03d12FA4293BC");
+const AVS_NAME: &str = "commonware-avs";
+const AVS_VERSION: &str = "0.1.0";
+
+// Client of the stub at SERVICE_MANAGER_ADDRESS on the node at HTTP_RPC, e.g. `anvil`
+fn client() -> Option<ServiceManagerClient> {
+    let (Ok(http_rpc), Ok(service_manager)) =
+        (env::var("HTTP_RPC"), env::var("SERVICE_MANAGER_ADDRESS"))
+    else {
+        eprintln!("HTTP_RPC or SERVICE_MANAGER_ADDRESS not set, skipping service manager test");
+        return None;
+    };
+    Some(ServiceManagerClient::new(&http_rpc, service_manager.parse().unwrap()).unwrap())
+}
+
+#[tokio::test]
+async fn test_reads_task_manager_address() {
+    let Some(client) = client() else { return };
+    assert_eq!(
+        client.get_task_manager_address().await.unwrap(),
+        TASK_MANAGER
+    );
+}
+
+#[tokio::test]
+async fn test_reads_avs_metadata() {
+    let Some(client) = client() else { return };
+    assert_eq!(
+        client.get_avs_metadata().await.unwrap(),
+        AvsMetadata {
+            name: AVS_NAME.to_string(),
+            version: AVS_VERSION.to_string(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_checks_operator_registration() {
+    let Some(client) = client() else { return };
+    assert!(client.is_operator_registered(OPERATOR).await.unwrap());
+    assert!(!client.is_operator_registered(TASK_MANAGER).await.unwrap());
+}
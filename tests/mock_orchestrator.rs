@@ -0,0 +1,41 @@
+//! Full aggregation rounds driven by a mock orchestrator against real contributors.
+#![allow(clippy::disallowed_methods)]
+
+mod common;
+
+use common::setup;
+
+#[tokio::test]
+async fn test_consecutive_rounds_are_certified() {
+    let (mut orchestrator, handles) = setup(4, 3);
+
+    for round in 1..=10 {
+        let certificate = orchestrator.run_round(round).await.unwrap();
+        assert_eq!(certificate.round, round);
+        assert!(certificate.signers.count() >= 3);
+    }
+
+    assert_eq!(orchestrator.certificates.len(), 10);
+    for (certificate, round) in orchestrator.certificates.iter().zip(1..) {
+        assert_eq!(certificate.round, round);
+        assert!(certificate.verify(&orchestrator.contributors));
+    }
+    for handle in &handles {
+        assert!(!handle.is_finished());
+    }
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[tokio::test]
+async fn test_round_times_out_without_threshold() {
+    let (mut orchestrator, handles) = setup(2, 3);
+    tokio::time::pause();
+
+    assert!(orchestrator.run_round(1).await.is_err());
+
+    for handle in handles {
+        handle.abort();
+    }
+}